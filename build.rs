@@ -1,10 +1,93 @@
+use std::fs;
+use std::path::Path;
+
 use anyhow::{Ok, Result};
 use substreams_ethereum::Abigen;
 
+// Every `abi/<name>.json` gets its decoders generated into `src/abi/<name>.rs`
+const ABI_DIR: &str = "abi";
+const OUT_DIR: &str = "src/abi";
+// `abi/selectors.txt` becomes the sorted table `src/selectors.rs` binary searches
+const SELECTORS: &str = "abi/selectors.txt";
+
 fn main() -> Result<(), anyhow::Error> {
-    Abigen::new("ERC721", "abi/erc721.json")?
-        .generate()?
-        .write_to_file("src/abi/erc721.rs")?;
+    println!("cargo:rerun-if-changed={}", ABI_DIR);
+
+    let mut modules = vec![];
+    for entry in fs::read_dir(ABI_DIR)? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+
+        let module = path.file_stem().unwrap().to_string_lossy().to_string();
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        Abigen::new(contract_name(&module), path.to_string_lossy().to_string())?
+            .generate()?
+            .write_to_file(format!("{}/{}.rs", OUT_DIR, module))?;
+
+        modules.push(module);
+    }
+    modules.sort();
+
+    let registry = modules
+        .iter()
+        .map(|module| format!("pub mod {};", module))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let registry_path = Path::new(OUT_DIR).join("mod.rs");
+    if fs::read_to_string(&registry_path).ok() != Some(registry.clone()) {
+        fs::write(registry_path, registry)?;
+    }
+
+    selector_table()?;
 
     Ok(())
 }
+
+/// Writes the `(selector, signature)` pairs of the selector table, sorted by selector, to `$OUT_DIR/selectors.rs`
+fn selector_table() -> Result<(), anyhow::Error> {
+    println!("cargo:rerun-if-changed={}", SELECTORS);
+
+    let mut entries = vec![];
+    for line in fs::read_to_string(SELECTORS)?.lines() {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        let (selector, signature) = line
+            .split_once(' ')
+            .ok_or_else(|| anyhow::anyhow!("{}: expected `<selector> <signature>`, got {}", SELECTORS, line))?;
+        if selector.len() != 8 {
+            anyhow::bail!("{}: {} is not a 4 byte selector", SELECTORS, line);
+        }
+        entries.push((u32::from_str_radix(selector, 16)?.to_be_bytes(), signature.to_string()));
+    }
+    entries.sort();
+    if let Some(pair) = entries.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        anyhow::bail!("{}: {} and {} share a selector", SELECTORS, pair[0].1, pair[1].1);
+    }
+
+    let table = entries
+        .iter()
+        .map(|(selector, signature)| format!("    ({:?}, {:?}),", selector, signature))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let out_dir = std::env::var("OUT_DIR")?;
+    fs::write(Path::new(&out_dir).join("selectors.rs"), format!("&[\n{}\n]\n", table))?;
+    Ok(())
+}
+
+/// `sudoswap_pair` -> `SudoswapPair`
+fn contract_name(module: &str) -> String {
+    module
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
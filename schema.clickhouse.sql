@@ -0,0 +1,49 @@
+-- Tables written by db_out_clickhouse. Rows are append only, a row written twice (a block processed again)
+-- collapses to its highest `version` on merge. The tokens of a bundle share their ordinal, `token_id` tells the
+-- sales apart.
+
+CREATE TABLE IF NOT EXISTS nft_transfers (
+    version         UInt64,
+    block_number    UInt64,
+    block_timestamp DateTime,
+    trx_hash        String,
+    ordinal         UInt64,
+    contract        LowCardinality(String),
+    from_address    String,
+    to_address      String,
+    token_id        UInt64
+) ENGINE = ReplacingMergeTree(version)
+PARTITION BY toYYYYMM(block_timestamp)
+ORDER BY (contract, block_number, trx_hash, ordinal);
+
+CREATE TABLE IF NOT EXISTS erc20_transfers (
+    version         UInt64,
+    block_number    UInt64,
+    block_timestamp DateTime,
+    trx_hash        String,
+    ordinal         UInt64,
+    standard        LowCardinality(String),
+    token           LowCardinality(String),
+    from_address    String,
+    to_address      String,
+    amount          UInt256
+) ENGINE = ReplacingMergeTree(version)
+PARTITION BY toYYYYMM(block_timestamp)
+ORDER BY (token, block_number, trx_hash, ordinal);
+
+CREATE TABLE IF NOT EXISTS nft_sales (
+    version         UInt64,
+    block_number    UInt64,
+    block_timestamp DateTime,
+    trx_hash        String,
+    ordinal         UInt64,
+    marketplace     LowCardinality(String),
+    collection      LowCardinality(String),
+    token_id        UInt64,
+    seller          String,
+    buyer           String,
+    price           UInt256,
+    currency        LowCardinality(String)
+) ENGINE = ReplacingMergeTree(version)
+PARTITION BY toYYYYMM(block_timestamp)
+ORDER BY (collection, block_number, trx_hash, ordinal, token_id);
@@ -0,0 +1,119 @@
+    const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+    /// Contract's functions.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod functions {
+        use super::INTERNAL_ERR;
+    }
+    /// Contract's events.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod events {
+        use super::INTERNAL_ERR;
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct DepositEvent {
+            pub pubkey: Vec<u8>,
+            pub withdrawal_credentials: Vec<u8>,
+            pub amount: Vec<u8>,
+            pub signature: Vec<u8>,
+            pub index: Vec<u8>,
+        }
+        impl DepositEvent {
+            const TOPIC_ID: [u8; 32] = [
+                100u8,
+                155u8,
+                188u8,
+                98u8,
+                208u8,
+                227u8,
+                19u8,
+                66u8,
+                175u8,
+                234u8,
+                78u8,
+                92u8,
+                216u8,
+                45u8,
+                64u8,
+                73u8,
+                231u8,
+                225u8,
+                238u8,
+                145u8,
+                47u8,
+                192u8,
+                136u8,
+                154u8,
+                167u8,
+                144u8,
+                128u8,
+                59u8,
+                227u8,
+                144u8,
+                56u8,
+                197u8,
+            ];
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 1usize {
+                    return false;
+                }
+                if log.data.len() < 320usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, String> {
+                let mut values = ethabi::decode(
+                        &[
+                            ethabi::ParamType::Bytes,
+                            ethabi::ParamType::Bytes,
+                            ethabi::ParamType::Bytes,
+                            ethabi::ParamType::Bytes,
+                            ethabi::ParamType::Bytes,
+                        ],
+                        log.data.as_ref(),
+                    )
+                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                values.reverse();
+                Ok(Self {
+                    pubkey: values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_bytes()
+                        .expect(INTERNAL_ERR),
+                    withdrawal_credentials: values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_bytes()
+                        .expect(INTERNAL_ERR),
+                    amount: values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_bytes()
+                        .expect(INTERNAL_ERR),
+                    signature: values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_bytes()
+                        .expect(INTERNAL_ERR),
+                    index: values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_bytes()
+                        .expect(INTERNAL_ERR),
+                })
+            }
+        }
+        impl substreams_ethereum::Event for DepositEvent {
+            const NAME: &'static str = "DepositEvent";
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, String> {
+                Self::decode(log)
+            }
+        }
+    }
\ No newline at end of file
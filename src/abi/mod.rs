@@ -1 +1,24 @@
+pub mod bayc;
+pub mod cryptokitties;
+pub mod erc1155;
+pub mod erc1363;
+pub mod erc20;
+pub mod erc20_votes;
 pub mod erc721;
+pub mod erc777;
+pub mod eth2_deposit;
+pub mod governor;
+pub mod lido_steth;
+pub mod ownable;
+pub mod payment_splitter;
+pub mod sablier_v1;
+pub mod sudoswap_factory;
+pub mod sudoswap_pair;
+pub mod team_finance_locker;
+pub mod unicrypt_locker;
+pub mod uniswap_v2_factory;
+pub mod uniswap_v2_pair;
+pub mod uniswap_v3_factory;
+pub mod uniswap_v3_pool;
+pub mod uniswap_v3_positions;
+pub mod vesting_wallet;
\ No newline at end of file
@@ -0,0 +1,251 @@
+    const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+    /// Contract's functions.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod functions {
+        use super::INTERNAL_ERR;
+    }
+    /// Contract's events.
+    #[allow(dead_code, unused_imports, unused_variables)]
+    pub mod events {
+        use super::INTERNAL_ERR;
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct NftDeposit {
+            pub pool_address: Vec<u8>,
+        }
+        impl NftDeposit {
+            const TOPIC_ID: [u8; 32] = [
+                79u8,
+                208u8,
+                205u8,
+                124u8,
+                20u8,
+                186u8,
+                218u8,
+                196u8,
+                95u8,
+                240u8,
+                190u8,
+                230u8,
+                112u8,
+                169u8,
+                216u8,
+                221u8,
+                128u8,
+                232u8,
+                121u8,
+                7u8,
+                175u8,
+                207u8,
+                44u8,
+                18u8,
+                30u8,
+                15u8,
+                212u8,
+                184u8,
+                180u8,
+                176u8,
+                4u8,
+                127u8,
+            ];
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 1usize {
+                    return false;
+                }
+                if log.data.len() != 32usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, String> {
+                let mut values = ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.data.as_ref(),
+                    )
+                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                values.reverse();
+                Ok(Self {
+                    pool_address: values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_address()
+                        .expect(INTERNAL_ERR)
+                        .as_bytes()
+                        .to_vec(),
+                })
+            }
+        }
+        impl substreams_ethereum::Event for NftDeposit {
+            const NAME: &'static str = "NFTDeposit";
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, String> {
+                Self::decode(log)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct NewPair {
+            pub pool_address: Vec<u8>,
+        }
+        impl NewPair {
+            const TOPIC_ID: [u8; 32] = [
+                245u8,
+                189u8,
+                193u8,
+                3u8,
+                195u8,
+                230u8,
+                138u8,
+                32u8,
+                213u8,
+                249u8,
+                125u8,
+                45u8,
+                70u8,
+                121u8,
+                45u8,
+                63u8,
+                221u8,
+                223u8,
+                164u8,
+                239u8,
+                235u8,
+                103u8,
+                97u8,
+                248u8,
+                20u8,
+                30u8,
+                106u8,
+                123u8,
+                147u8,
+                108u8,
+                166u8,
+                108u8,
+            ];
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 1usize {
+                    return false;
+                }
+                if log.data.len() != 32usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, String> {
+                let mut values = ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.data.as_ref(),
+                    )
+                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                values.reverse();
+                Ok(Self {
+                    pool_address: values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_address()
+                        .expect(INTERNAL_ERR)
+                        .as_bytes()
+                        .to_vec(),
+                })
+            }
+        }
+        impl substreams_ethereum::Event for NewPair {
+            const NAME: &'static str = "NewPair";
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, String> {
+                Self::decode(log)
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct TokenDeposit {
+            pub pool_address: Vec<u8>,
+        }
+        impl TokenDeposit {
+            const TOPIC_ID: [u8; 32] = [
+                131u8,
+                31u8,
+                175u8,
+                63u8,
+                187u8,
+                117u8,
+                201u8,
+                252u8,
+                102u8,
+                227u8,
+                24u8,
+                222u8,
+                126u8,
+                149u8,
+                144u8,
+                93u8,
+                36u8,
+                192u8,
+                67u8,
+                8u8,
+                34u8,
+                218u8,
+                22u8,
+                140u8,
+                28u8,
+                6u8,
+                253u8,
+                73u8,
+                203u8,
+                168u8,
+                245u8,
+                247u8,
+            ];
+            pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                if log.topics.len() != 1usize {
+                    return false;
+                }
+                if log.data.len() != 32usize {
+                    return false;
+                }
+                return log.topics.get(0).expect("bounds already checked").as_ref()
+                    == Self::TOPIC_ID;
+            }
+            pub fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, String> {
+                let mut values = ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.data.as_ref(),
+                    )
+                    .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+                values.reverse();
+                Ok(Self {
+                    pool_address: values
+                        .pop()
+                        .expect(INTERNAL_ERR)
+                        .into_address()
+                        .expect(INTERNAL_ERR)
+                        .as_bytes()
+                        .to_vec(),
+                })
+            }
+        }
+        impl substreams_ethereum::Event for TokenDeposit {
+            const NAME: &'static str = "TokenDeposit";
+            fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+                Self::match_log(log)
+            }
+            fn decode(
+                log: &substreams_ethereum::pb::eth::v2::Log,
+            ) -> Result<Self, String> {
+                Self::decode(log)
+            }
+        }
+    }
\ No newline at end of file
@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+
+use substreams::Hex;
+use substreams_ethereum::NULL_ADDRESS;
+
+use crate::keys;
+use crate::params::Params;
+use crate::pb::erc721;
+
+/// Entries kept per token id and per holder, read from the `recent` param.
+pub fn size(params: &Params) -> u64 {
+    params
+        .get("recent")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(20)
+        .max(1)
+}
+
+/// Token and holders a transfer is recent activity of: `nft:<token_id>:<contract>` and `holder:<address>` of both
+/// sides, the null address left out.
+pub fn subjects(transfer: &erc721::Transfer) -> Vec<keys::Key> {
+    let mut subjects = vec![keys::key(keys::NFT).number(transfer.token_id).hex(&transfer.contract)];
+    for holder in [&transfer.from, &transfer.to] {
+        if holder.as_slice() != NULL_ADDRESS {
+            subjects.push(keys::key(keys::HOLDER).hex(holder));
+        }
+    }
+    subjects
+}
+
+/// Key the entries of a subject are appended to, one per generation of `size` entries:
+/// `recent.v2:nft:<token_id>:<contract>:<generation>`.
+pub fn recent_key(subject: keys::Key, generation: u64) -> String {
+    // generations are zero padded so deleting the prefix of one never matches another one
+    keys::key(keys::RECENT).key(subject).block(generation).into()
+}
+
+/// Compact entry of a transfer: `<block>,<token_id>,<from>,<to>,<trx_hash>`, without `;` as append stores split on it.
+pub fn entry(block: u64, transfer: &erc721::Transfer) -> String {
+    format!(
+        "{},{},{},{},{}",
+        block,
+        transfer.token_id,
+        Hex(&transfer.from),
+        Hex(&transfer.to),
+        Hex(&transfer.trx_hash)
+    )
+}
+
+/// Write of a block to store_recent_activity.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Write {
+    /// Generation that fell out of the last two, deleted by prefix.
+    Expire { ordinal: u64, key: String },
+    /// Entry appended to the current generation of a subject.
+    Append { ordinal: u64, key: String, entry: String },
+}
+
+/// Writes of the block, in ordinal order. `counts` returns the number of transfers of a subject at the end of the
+/// block, the n-th transfer of a subject goes to generation n / `size`. A subject starting generation g drops
+/// generation g - 2, so only the current generation and the one before it are kept. The drop comes right before the
+/// first entry of generation g, after the entries of generation g - 2 written earlier in the block.
+pub fn writes(block: u64, transfers: &[erc721::Transfer], counts: impl Fn(&str) -> i64, size: u64) -> Vec<Write> {
+    let mut in_block: BTreeMap<String, u64> = BTreeMap::new();
+    for transfer in transfers {
+        for subject in subjects(transfer) {
+            *in_block.entry(subject.into()).or_default() += 1;
+        }
+    }
+
+    // position of the next transfer of every subject since the start block
+    let mut next: BTreeMap<String, u64> = in_block
+        .into_iter()
+        .map(|(subject, count)| {
+            let total = counts(&subject).max(0) as u64;
+            let start = total.saturating_sub(count);
+            (subject, start)
+        })
+        .collect();
+
+    let mut writes = vec![];
+    for transfer in transfers {
+        let entry = entry(block, transfer);
+        for subject in subjects(transfer) {
+            let position = next.get_mut(subject.as_ref()).map_or(0, |next| {
+                *next += 1;
+                *next - 1
+            });
+            let generation = position / size;
+            if position % size == 0 && generation >= 2 {
+                let key = recent_key(subject.clone(), generation - 2);
+                writes.push(Write::Expire { ordinal: transfer.ordinal, key });
+            }
+            let key = recent_key(subject, generation);
+            writes.push(Write::Append { ordinal: transfer.ordinal, key, entry: entry.clone() });
+        }
+    }
+    writes
+}
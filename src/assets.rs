@@ -0,0 +1,115 @@
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi;
+use crate::erc20;
+use crate::eth_utils::{self, BloomBits};
+use crate::pb::portfolio::{asset_transfer::Standard, AssetTransfer};
+use crate::pb::tokens::transfer::Standard as FungibleStandard;
+use crate::transfers;
+
+/// `(id, value)` pairs moved by an ERC1155 event.
+type Moves = Vec<(BigInt, BigInt)>;
+
+/// Sender, receiver and moves of an ERC1155 `TransferSingle` or `TransferBatch` event.
+pub fn erc1155_moves(log: &eth::Log) -> Option<(Vec<u8>, Vec<u8>, Moves)> {
+    use abi::erc1155::events as erc1155;
+
+    if let Some(transfer) = erc1155::TransferSingle::match_and_decode(log) {
+        Some((transfer.from, transfer.to, vec![(transfer.id, transfer.value)]))
+    } else {
+        erc1155::TransferBatch::match_and_decode(log)
+            .map(|transfer| (transfer.from, transfer.to, transfer.ids.into_iter().zip(transfer.values).collect()))
+    }
+}
+
+/// Transfers of the transaction across standards in execution order: ETH sent by its calls, fungible transfers,
+/// transfers of `collection` and of the `erc1155` contracts. Fungible transfers of `collection` are left out, its
+/// unindexed Transfer events look like ERC20 ones. `fungible` are the bloom bits of erc20::TOPICS.
+pub fn transfers(
+    trx: &eth::TransactionTrace,
+    collection: &[u8],
+    erc1155: &[Vec<u8>],
+    fungible: &[BloomBits],
+) -> Vec<AssetTransfer> {
+    let transfer = |standard: Standard, contract: &[u8], token_id: String, amount: String| AssetTransfer {
+        standard: standard as i32,
+        contract: contract.to_vec(),
+        token_id,
+        amount,
+        trx_hash: trx.hash.clone(),
+        ..Default::default()
+    };
+    let mut assets = vec![];
+
+    // delegate calls run in the context of their caller and move no ETH
+    let moves_value = |call: &&eth::Call| !call.state_reverted && call.call_type != eth::CallType::Delegate as i32;
+    for call in trx.calls.iter().filter(moves_value) {
+        let value = call.value.as_ref().map_or_else(BigInt::zero, |value| BigInt::from_unsigned_bytes_be(&value.bytes));
+        if !value.is_zero() {
+            assets.push(AssetTransfer {
+                from: call.caller.clone(),
+                to: call.address.clone(),
+                ordinal: call.begin_ordinal,
+                id: eth_utils::call_record_id(&trx.hash, call.index),
+                ..transfer(Standard::Native, &[], String::new(), value.to_string())
+            });
+        }
+    }
+
+    // the calls and logs are only gone through for fungible moves when the receipt bloom may hold one of their topics
+    let fungible_moves = trx.receipt.as_ref().map_or(false, |receipt| {
+        fungible.iter().any(|bits| eth_utils::bloom_may_contain(&receipt.logs_bloom, bits))
+    });
+    let decoded = if fungible_moves { erc20::decode(trx).0 } else { vec![] };
+    for fungible in decoded.into_iter().filter(|fungible| fungible.token != collection) {
+        let standard = match FungibleStandard::from_i32(fungible.standard) {
+            Some(FungibleStandard::Erc777) => Standard::Erc777,
+            Some(FungibleStandard::Erc1363) => Standard::Erc1363,
+            _ => Standard::Erc20,
+        };
+        assets.push(AssetTransfer {
+            from: fungible.from,
+            to: fungible.to,
+            ordinal: fungible.ordinal,
+            id: fungible.id,
+            ..transfer(standard, &fungible.token, String::new(), fungible.amount)
+        });
+    }
+
+    for log in trx.receipt.as_ref().map(|receipt| receipt.logs.as_slice()).unwrap_or_default() {
+        if log.address == collection {
+            if let Some(nft) = transfers::decode(log) {
+                assets.push(AssetTransfer {
+                    from: nft.from,
+                    to: nft.to,
+                    ordinal: log.ordinal,
+                    id: eth_utils::record_id(&trx.hash, log.block_index),
+                    ..transfer(Standard::Erc721, collection, nft.token_id.to_string(), "1".to_string())
+                });
+            }
+        } else if erc1155.contains(&log.address) {
+            let (from, to, moves) = match erc1155_moves(log) {
+                Some(moves) => moves,
+                None => continue,
+            };
+            for (id, value) in moves {
+                assets.push(AssetTransfer {
+                    from: from.clone(),
+                    to: to.clone(),
+                    ordinal: log.ordinal,
+                    id: eth_utils::record_id(&trx.hash, log.block_index),
+                    ..transfer(Standard::Erc1155, &log.address, id.to_string(), value.to_string())
+                });
+            }
+        }
+    }
+
+    // several tokens can move in a single log, the token id sets them apart
+    for asset in assets.iter_mut().filter(|asset| !asset.token_id.is_empty()) {
+        asset.id = format!("{}-{}", asset.id, asset.token_id);
+    }
+    assets.sort_by_key(|asset| asset.ordinal);
+    assets
+}
@@ -0,0 +1,76 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use substreams_ethereum::NULL_ADDRESS;
+
+use crate::keys;
+use crate::TRACKED_CONTRACT;
+
+/// Number of holders of the tracked collection, kept incrementally by store_holder_count.
+pub fn holder_count_key() -> String {
+    keys::key(keys::COLLECTION).hex(&TRACKED_CONTRACT).text("holders").into()
+}
+
+/// Change in the number of holders when a balance moves from `old` to `new`: +1 when it becomes positive, -1 when it
+/// stops being.
+pub fn holder_count_change(old: i64, new: i64) -> i64 {
+    match (old > 0, new > 0) {
+        (false, true) => 1,
+        (true, false) => -1,
+        _ => 0,
+    }
+}
+
+/// Owners of the token ids store_token_ids recorded, read with `owner`. Burnt tokens are kept, owned by the null
+/// address, so that recount leaves them out.
+pub fn owners(token_ids: &[String], owner: impl Fn(u64) -> Option<Vec<u8>>) -> Vec<Vec<u8>> {
+    token_ids.iter().filter_map(|token_id| token_id.parse().ok()).filter_map(owner).collect()
+}
+
+/// Holder count of the collection recounted from the owner of every token, checked against the balances.
+#[derive(Debug, Default, PartialEq)]
+pub struct Recount {
+    /// Distinct owners of the tokens, burnt tokens left out.
+    pub holders: i64,
+    /// Addresses whose balance went below zero.
+    pub negative_balances: u64,
+    /// Addresses whose balance differs from the number of tokens they own.
+    pub mismatched_balances: u64,
+}
+
+/// Recounts the holders from the owners of the tokens, which store_token_owners follows token by token independently
+/// of the balances, and compares the tokens every address owns with its balance. `holders` are the addresses that ever
+/// held a token, so balances left to addresses that no longer own any are compared too.
+pub fn recount<'a>(
+    owners: impl IntoIterator<Item = &'a [u8]>,
+    holders: &[String],
+    balance: impl Fn(&[u8]) -> i64,
+) -> Recount {
+    let mut owned: BTreeMap<&[u8], i64> = BTreeMap::new();
+    for owner in owners {
+        if owner != NULL_ADDRESS.as_slice() {
+            *owned.entry(owner).or_default() += 1;
+        }
+    }
+
+    let mut recount = Recount {
+        holders: owned.len() as i64,
+        ..Default::default()
+    };
+    let mut compared = BTreeSet::new();
+    for holder in holders.iter().filter_map(|holder| hex::decode(holder).ok()) {
+        let balance = balance(&holder);
+        if balance < 0 {
+            recount.negative_balances += 1;
+        }
+        if balance != owned.get(holder.as_slice()).copied().unwrap_or(0) {
+            recount.mismatched_balances += 1;
+        }
+        compared.insert(holder);
+    }
+    for (owner, tokens) in &owned {
+        if !compared.contains(*owner) && balance(*owner) != *tokens {
+            recount.mismatched_balances += 1;
+        }
+    }
+    recount
+}
@@ -0,0 +1,209 @@
+use std::collections::BTreeSet;
+
+use crate::crypto;
+use crate::keys;
+use crate::logging;
+use crate::params::Params;
+
+/// Functions minting any amount to any address, only callable by an owner or a minter role on the tokens that
+/// have them.
+const MINT_FUNCTIONS: [&str; 4] = [
+    "mint(address,uint256)",
+    "mint(uint256)",
+    "mintTo(address,uint256)",
+    "issue(uint256)",
+];
+
+/// Functions freezing the balance of an address.
+const BLACKLIST_FUNCTIONS: [&str; 6] = [
+    "blacklist(address)",
+    "addBlackList(address)",
+    "addToBlacklist(address)",
+    "blacklistAddress(address)",
+    "setBlacklist(address,bool)",
+    "freeze(address)",
+];
+
+/// Template of the tokens trusted by the `templates` param of store_token_templates.
+pub const KNOWN: &str = "known";
+
+/// External functions of the OpenZeppelin 4.x ERC20.
+const OPENZEPPELIN_ERC20_V4: [&str; 11] = [
+    "name()",
+    "symbol()",
+    "decimals()",
+    "totalSupply()",
+    "balanceOf(address)",
+    "transfer(address,uint256)",
+    "allowance(address,address)",
+    "approve(address,uint256)",
+    "transferFrom(address,address,uint256)",
+    "increaseAllowance(address,uint256)",
+    "decreaseAllowance(address,uint256)",
+];
+
+/// External functions of the OpenZeppelin 5.x ERC20, along with the custom errors it reverts with.
+const OPENZEPPELIN_ERC20_V5: [&str; 15] = [
+    "name()",
+    "symbol()",
+    "decimals()",
+    "totalSupply()",
+    "balanceOf(address)",
+    "transfer(address,uint256)",
+    "allowance(address,address)",
+    "approve(address,uint256)",
+    "transferFrom(address,address,uint256)",
+    "ERC20InsufficientBalance(address,uint256,uint256)",
+    "ERC20InvalidSender(address)",
+    "ERC20InvalidReceiver(address)",
+    "ERC20InsufficientAllowance(address,uint256,uint256)",
+    "ERC20InvalidApprover(address)",
+    "ERC20InvalidSpender(address)",
+];
+
+/// External functions ERC20Burnable adds.
+const BURNABLE: [&str; 2] = ["burn(uint256)", "burnFrom(address,uint256)"];
+
+/// External functions the OpenZeppelin 4.x Ownable adds.
+const OWNABLE: [&str; 3] = ["owner()", "renounceOwnership()", "transferOwnership(address)"];
+
+/// Fingerprints of widely deployed token templates: the exact set of selectors their dispatcher compares the call
+/// data with, which does not depend on the compiler version and settings as their code hash does. Templates with
+/// extra functions, such as a mint callable by the owner, don't match.
+pub const TEMPLATES: [(&str, &[&[&str]]); 5] = [
+    ("openzeppelin_erc20", &[&OPENZEPPELIN_ERC20_V4]),
+    ("openzeppelin_erc20_burnable", &[&OPENZEPPELIN_ERC20_V4, &BURNABLE]),
+    ("openzeppelin_erc20_ownable", &[&OPENZEPPELIN_ERC20_V4, &OWNABLE]),
+    ("openzeppelin_erc20_burnable_ownable", &[&OPENZEPPELIN_ERC20_V4, &BURNABLE, &OWNABLE]),
+    ("openzeppelin_erc20_v5", &[&OPENZEPPELIN_ERC20_V5]),
+];
+
+/// Selectors solc pushes whatever the contract: the `Panic(uint256)` and `Error(string)` revert reasons, and the
+/// mask older dispatchers applied to the call data.
+const COMPILER_SELECTORS: [[u8; 4]; 3] = [[0x4e, 0x48, 0x7b, 0x71], [0x08, 0xc3, 0x79, 0xa0], [0xff, 0xff, 0xff, 0xff]];
+
+/// Code of a contract without the CBOR encoded metadata solc and vyper append to it: a map whose byte length is
+/// given by the last two bytes. Code without such a trailer is returned whole.
+pub fn strip_metadata(code: &[u8]) -> &[u8] {
+    if code.len() < 2 {
+        return code;
+    }
+    let length = u16::from_be_bytes([code[code.len() - 2], code[code.len() - 1]]) as usize;
+    let start = match code.len().checked_sub(length + 2) {
+        Some(start) if length > 0 => start,
+        _ => return code,
+    };
+    // CBOR maps of up to 23 entries start with 0xa0 to 0xb7
+    match code[start] {
+        0xa0..=0xb7 => &code[..start],
+        _ => code,
+    }
+}
+
+/// Fingerprint of the code a contract was created with, the same for every clone of a template whatever the
+/// compiler metadata. Empty without code.
+pub fn code_hash(code: &[u8]) -> Vec<u8> {
+    if code.is_empty() {
+        return vec![];
+    }
+    crypto::keccak256(strip_metadata(code)).to_vec()
+}
+
+/// Number of tokens created with the code of `code_hash`.
+pub fn family_key(code_hash: &[u8]) -> String {
+    keys::key(keys::FAMILY).hex(code_hash).into()
+}
+
+/// Membership of `token` in the family of `code_hash`, all members are listed under `family:<code_hash>:`.
+pub fn member_key(code_hash: &[u8], token: &[u8]) -> String {
+    keys::key(keys::FAMILY).hex(code_hash).hex(token).into()
+}
+
+/// Name of the template of TEMPLATES whose fingerprint `code` matches, if any.
+pub fn template(code: &[u8]) -> Option<&'static str> {
+    let mut selectors = selectors(code);
+    selectors.retain(|selector| !COMPILER_SELECTORS.contains(selector));
+    if selectors.is_empty() {
+        return None;
+    }
+
+    TEMPLATES.iter().find_map(|(name, functions)| {
+        let fingerprint: BTreeSet<[u8; 4]> = functions
+            .iter()
+            .flat_map(|group| group.iter())
+            .map(|function| {
+                let hash = crypto::keccak256(function.as_bytes());
+                [hash[0], hash[1], hash[2], hash[3]]
+            })
+            .collect();
+        (fingerprint == selectors).then_some(*name)
+    })
+}
+
+/// Templates trusted by the `templates` param: names of TEMPLATES, and code hashes as `0x` prefixed hex for the
+/// templates whose sources were verified. Invalid entries are logged and skipped.
+pub struct Allowlist {
+    names: Vec<String>,
+    code_hashes: Vec<Vec<u8>>,
+}
+
+impl Allowlist {
+    pub fn from_params(params: &Params) -> Allowlist {
+        let mut allowlist = Allowlist { names: vec![], code_hashes: vec![] };
+        for entry in params.list("templates") {
+            if TEMPLATES.iter().any(|(name, _)| *name == entry) {
+                allowlist.names.push(entry.to_string());
+                continue;
+            }
+            match hex::decode(entry.strip_prefix("0x").unwrap_or(entry)) {
+                Ok(code_hash) if code_hash.len() == 32 => allowlist.code_hashes.push(code_hash),
+                _ => logging::warning!("tokens", "skipping invalid templates entry {}", entry),
+            }
+        }
+        allowlist
+    }
+
+    /// Whether the token matches a trusted template, by name or by code hash.
+    pub fn trusts(&self, template: &str, code_hash: &[u8]) -> bool {
+        (!template.is_empty() && self.names.iter().any(|name| name == template))
+            || (!code_hash.is_empty() && self.code_hashes.iter().any(|trusted| trusted == code_hash))
+    }
+}
+
+pub fn template_key(token: &[u8]) -> String {
+    keys::key(keys::TEMPLATE).hex(token).into()
+}
+
+/// Selectors the function dispatcher of `code` compares the call data with: the operands of its PUSH4 opcodes.
+/// The operands of the other PUSH opcodes are skipped, so constants holding the same bytes are not mistaken for
+/// selectors.
+pub fn selectors(code: &[u8]) -> BTreeSet<[u8; 4]> {
+    let code = strip_metadata(code);
+    let mut selectors = BTreeSet::new();
+    let mut at = 0;
+    while at < code.len() {
+        let opcode = code[at];
+        // PUSH1 to PUSH32
+        let operand = if (0x60..=0x7f).contains(&opcode) { (opcode - 0x5f) as usize } else { 0 };
+        if opcode == 0x63 && at + 5 <= code.len() {
+            selectors.insert([code[at + 1], code[at + 2], code[at + 3], code[at + 4]]);
+        }
+        at += 1 + operand;
+    }
+    selectors
+}
+
+/// Whether the selector of one of the `functions` is among `selectors`.
+fn dispatches(selectors: &BTreeSet<[u8; 4]>, functions: &[&str]) -> bool {
+    functions.iter().any(|function| {
+        let hash = crypto::keccak256(function.as_bytes());
+        selectors.contains(&[hash[0], hash[1], hash[2], hash[3]])
+    })
+}
+
+/// `can_mint_arbitrarily` and `has_blacklist` capabilities of a token from the functions its code dispatches. Only
+/// the selectors are looked at, whether the functions are restricted to an owner is not checked.
+pub fn capabilities(code: &[u8]) -> (bool, bool) {
+    let selectors = selectors(code);
+    (dispatches(&selectors, &MINT_FUNCTIONS), dispatches(&selectors, &BLACKLIST_FUNCTIONS))
+}
@@ -0,0 +1,115 @@
+use substreams::scalar::BigInt;
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Function;
+
+use crate::abi;
+use crate::eth_utils;
+use crate::selectors;
+use crate::pb::erc721::{CallParam, ContractCall};
+
+/// Decodes the ERC721 and collection specific functions called on a tracked contract, inputs and
+/// outputs are exposed by their ABI parameter names.
+pub fn decode(trx: &eth::TransactionTrace, call: &eth::Call) -> Option<ContractCall> {
+    use abi::erc721::functions as erc721;
+
+    let (method, inputs, outputs) = if let Some(f) = erc721::TransferFrom::match_and_decode(call) {
+        (
+            erc721::TransferFrom::NAME,
+            vec![bytes("from", &f.from), bytes("to", &f.to), uint("tokenId", &f.token_id)],
+            vec![],
+        )
+    } else if let Some(f) = erc721::SafeTransferFrom1::match_and_decode(call) {
+        (
+            erc721::SafeTransferFrom1::NAME,
+            vec![bytes("from", &f.from), bytes("to", &f.to), uint("tokenId", &f.token_id)],
+            vec![],
+        )
+    } else if let Some(f) = erc721::SafeTransferFrom2::match_and_decode(call) {
+        (
+            erc721::SafeTransferFrom2::NAME,
+            vec![
+                bytes("from", &f.from),
+                bytes("to", &f.to),
+                uint("tokenId", &f.token_id),
+                bytes("_data", &f.data),
+            ],
+            vec![],
+        )
+    } else if let Some(f) = erc721::Approve::match_and_decode(call) {
+        (
+            erc721::Approve::NAME,
+            vec![bytes("to", &f.to), uint("tokenId", &f.token_id)],
+            vec![],
+        )
+    } else if let Some(f) = erc721::SetApprovalForAll::match_and_decode(call) {
+        (
+            erc721::SetApprovalForAll::NAME,
+            vec![bytes("operator", &f.operator), param("approved", f.approved.to_string())],
+            vec![],
+        )
+    } else if let Some(f) = erc721::OwnerOf::match_and_decode(call) {
+        let outputs = match erc721::OwnerOf::output_call(call) {
+            Ok(owner) => vec![bytes("owner", &owner)],
+            Err(_) => vec![],
+        };
+        (erc721::OwnerOf::NAME, vec![uint("tokenId", &f.token_id)], outputs)
+    } else if let Some(f) = erc721::BalanceOf::match_and_decode(call) {
+        let outputs = match erc721::BalanceOf::output_call(call) {
+            Ok(balance) => vec![uint("balance", &balance)],
+            Err(_) => vec![],
+        };
+        (erc721::BalanceOf::NAME, vec![bytes("owner", &f.owner)], outputs)
+    } else if let Some(f) = abi::bayc::functions::MintApe::match_and_decode(call) {
+        (
+            abi::bayc::functions::MintApe::NAME,
+            vec![uint("numberOfTokens", &f.number_of_tokens)],
+            vec![],
+        )
+    } else if call.input.len() >= 4 {
+        // functions not decoded above are reported by signature, inputs are left raw
+        let method = selectors::describe(&call.input);
+        return Some(contract_call(trx, call, &method, vec![bytes("input", &call.input[4..])], vec![]));
+    } else {
+        return None;
+    };
+
+    Some(contract_call(trx, call, method, inputs, outputs))
+}
+
+fn contract_call(
+    trx: &eth::TransactionTrace,
+    call: &eth::Call,
+    method: &str,
+    inputs: Vec<CallParam>,
+    outputs: Vec<CallParam>,
+) -> ContractCall {
+    ContractCall {
+        method: method.to_string(),
+        inputs,
+        outputs,
+        caller: call.caller.clone(),
+        value: match &call.value {
+            Some(value) => BigInt::from_unsigned_bytes_be(&value.bytes).to_string(),
+            None => "0".to_string(),
+        },
+        trx_hash: trx.hash.clone(),
+        ordinal: call.begin_ordinal,
+        id: eth_utils::call_record_id(&trx.hash, call.index),
+    }
+}
+
+fn param(name: &str, value: String) -> CallParam {
+    CallParam {
+        name: name.to_string(),
+        value,
+    }
+}
+
+fn bytes(name: &str, value: &[u8]) -> CallParam {
+    param(name, format!("0x{}", Hex(value)))
+}
+
+fn uint(name: &str, value: &BigInt) -> CallParam {
+    param(name, value.to_string())
+}
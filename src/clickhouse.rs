@@ -0,0 +1,108 @@
+use substreams::Hex;
+
+use crate::eth_utils;
+use crate::pb::database::table_change::Operation;
+use crate::pb::database::{Field, TableChange};
+use crate::pb::tokens::transfer::Standard;
+use crate::pb::{erc721, tokens};
+
+/// Rows are only ever inserted, keyed by the record `id`, `version` (the block number) lets a ReplacingMergeTree
+/// keep the last copy of a row written twice.
+struct Row {
+    change: TableChange,
+}
+
+impl Row {
+    fn new(table: &str, id: &str, trx_hash: &[u8], ordinal: u64, block: u64, timestamp: i64) -> Row {
+        let change = TableChange {
+            table: table.to_string(),
+            pk: id.to_string(),
+            ordinal,
+            operation: Operation::Create as i32,
+            fields: vec![],
+        };
+        Row { change }
+            .field("version", block)
+            .field("block_number", block)
+            .field("block_timestamp", timestamp)
+            .field("trx_hash", format!("0x{}", Hex(trx_hash)))
+            .field("ordinal", ordinal)
+    }
+
+    fn field<V: ToString>(mut self, name: &str, value: V) -> Row {
+        self.change.fields.push(Field {
+            name: name.to_string(),
+            new_value: value.to_string(),
+            old_value: String::new(),
+        });
+        self
+    }
+}
+
+/// Low cardinality name of a transfer standard.
+pub fn standard(standard: i32) -> &'static str {
+    match Standard::from_i32(standard) {
+        Some(Standard::Erc20) => "erc20",
+        Some(Standard::Erc777) => "erc777",
+        Some(Standard::Erc1363) => "erc1363",
+        _ => "unknown",
+    }
+}
+
+/// Currency of a sale, `ETH` when paid in native ETH.
+pub fn currency(sale: &erc721::Sale) -> String {
+    if sale.currency.is_empty() {
+        "ETH".to_string()
+    } else {
+        eth_utils::format_address(&sale.currency)
+    }
+}
+
+pub fn nft_transfers(block: u64, timestamp: i64, transfers: &erc721::Transfers) -> Vec<TableChange> {
+    transfers
+        .transfers
+        .iter()
+        .map(|transfer| {
+            Row::new("nft_transfers", &transfer.id, &transfer.trx_hash, transfer.ordinal, block, timestamp)
+                .field("contract", eth_utils::format_address(&transfer.contract))
+                .field("from_address", eth_utils::format_address(&transfer.from))
+                .field("to_address", eth_utils::format_address(&transfer.to))
+                .field("token_id", transfer.token_id)
+                .change
+        })
+        .collect()
+}
+
+pub fn erc20_transfers(block: u64, timestamp: i64, transfers: &tokens::Transfers) -> Vec<TableChange> {
+    transfers
+        .transfers
+        .iter()
+        .map(|transfer| {
+            Row::new("erc20_transfers", &transfer.id, &transfer.trx_hash, transfer.ordinal, block, timestamp)
+                .field("standard", standard(transfer.standard))
+                .field("token", eth_utils::format_address(&transfer.token))
+                .field("from_address", eth_utils::format_address(&transfer.from))
+                .field("to_address", eth_utils::format_address(&transfer.to))
+                .field("amount", &transfer.amount)
+                .change
+        })
+        .collect()
+}
+
+pub fn nft_sales(block: u64, timestamp: i64, sales: &erc721::Sales) -> Vec<TableChange> {
+    sales
+        .sales
+        .iter()
+        .map(|sale| {
+            Row::new("nft_sales", &sale.id, &sale.trx_hash, sale.ordinal, block, timestamp)
+                .field("marketplace", &sale.marketplace)
+                .field("collection", eth_utils::format_address(&sale.collection))
+                .field("token_id", sale.token_id)
+                .field("seller", eth_utils::format_address(&sale.seller))
+                .field("buyer", eth_utils::format_address(&sale.buyer))
+                .field("price", &sale.price)
+                .field("currency", currency(sale))
+                .change
+        })
+        .collect()
+}
@@ -0,0 +1,33 @@
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::keys;
+use crate::params::Params;
+
+pub fn cluster_key(address: &[u8]) -> String {
+    keys::key(keys::CLUSTER).hex(address).into()
+}
+
+pub fn size_key(cluster: &str) -> String {
+    keys::key(keys::SIZE).text(cluster).into()
+}
+
+/// Blocks grouped together when looking for wallets funded by the same sender, read from the `window` param.
+pub fn window(params: &Params) -> u64 {
+    params.get("window").and_then(|value| value.parse().ok()).unwrap_or(300).max(1)
+}
+
+/// Wallets funded by the same sender within a window of blocks share a cluster, named after the sender and
+/// the first block of the window.
+pub fn cluster_id(funder: &[u8], block: u64, window: u64) -> String {
+    format!("{}:{}", Hex(funder), block - block % window)
+}
+
+/// Recipient of a plain ETH transfer, a transaction sending value without calldata.
+pub fn funded(trx: &eth::TransactionTrace) -> Option<&[u8]> {
+    let sends_value = trx.value.as_ref().map_or(false, |value| value.bytes.iter().any(|byte| *byte != 0));
+    if !sends_value || !trx.input.is_empty() || trx.to == trx.from || trx.to.is_empty() {
+        return None;
+    }
+    Some(&trx.to)
+}
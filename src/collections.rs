@@ -0,0 +1,113 @@
+use hex_literal::hex;
+use substreams_ethereum::pb::eth::rpc::{RpcCalls, RpcResponse};
+
+use crate::eth_utils;
+use crate::metadata;
+use crate::pb::erc721::{collection_metadata::Standard, CollectionMetadata};
+use crate::rpc_utils::{self, create_rpc_calls, EthCall, RpcCounters};
+
+// ERC165 interface ids
+const ERC721_INTERFACE: [u8; 4] = hex!("80ac58cd");
+const ERC1155_INTERFACE: [u8; 4] = hex!("d9b67a26");
+
+/// Sale price royaltyInfo is asked about, the royalty it returns is then in basis points.
+const ROYALTY_SALE_PRICE: u64 = 10_000;
+
+/// Calls of `method_signature` on `address`, with the ABI encoded `args` appended.
+fn calls_with_args(address: &[u8], method_signature: &str, args: &[[u8; 32]]) -> RpcCalls {
+    let mut calls = create_rpc_calls(&address.to_vec(), vec![method_signature]);
+    for call in &mut calls.calls {
+        for arg in args {
+            call.data.extend_from_slice(arg);
+        }
+    }
+    calls
+}
+
+fn word(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+fn interface_word(interface: [u8; 4]) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[..4].copy_from_slice(&interface);
+    word
+}
+
+fn is_true(response: &RpcResponse) -> bool {
+    !response.failed && response.raw.len() == 32 && response.raw[31] == 1 && response.raw[..31].iter().all(|b| *b == 0)
+}
+
+/// NFT standard of each contract through ERC165, probed in a single batch of calls.
+pub fn standards(client: &impl EthCall, contracts: &[Vec<u8>], rpc: &mut RpcCounters) -> Vec<Option<Standard>> {
+    if contracts.is_empty() {
+        return vec![];
+    }
+
+    let mut calls = RpcCalls { calls: vec![] };
+    for contract in contracts {
+        for interface in [ERC721_INTERFACE, ERC1155_INTERFACE] {
+            let supports = calls_with_args(contract, rpc_utils::SUPPORTS_INTERFACE, &[interface_word(interface)]);
+            calls.calls.extend(supports.calls);
+        }
+    }
+    let responses = client.eth_call(&calls).responses;
+    rpc.record(&responses);
+
+    responses
+        .chunks(2)
+        .map(|pair| match pair {
+            [erc721, _] if is_true(erc721) => Some(Standard::Erc721),
+            [_, erc1155] if is_true(erc1155) => Some(Standard::Erc1155),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collection level metadata of the contract: name, symbol, owner, ERC-7572 URIs and EIP-2981 default royalty.
+pub fn metadata(
+    client: &impl EthCall,
+    contract: &[u8],
+    standard: Standard,
+    implementation: Option<&[u8]>,
+    rpc: &mut RpcCounters,
+) -> CollectionMetadata {
+    let mut calls = create_rpc_calls(&contract.to_vec(), vec![rpc_utils::NAME, rpc_utils::SYMBOL, rpc_utils::OWNER]);
+    let royalty = calls_with_args(contract, rpc_utils::ROYALTY_INFO, &[word(0), word(ROYALTY_SALE_PRICE)]);
+    calls.calls.extend(royalty.calls);
+    let responses = client.eth_call(&calls).responses;
+    rpc.record(&responses);
+
+    let ok = |index: usize| {
+        responses
+            .get(index)
+            .filter(|response| !response.failed)
+            .map(|response| &response.raw)
+    };
+    let text = |index: usize| {
+        ok(index)
+            .and_then(|raw| eth_utils::read_string_bytes(raw).ok())
+            .map(|raw| eth_utils::sanitize_metadata(&raw))
+            .unwrap_or_default()
+    };
+
+    let mut collection = CollectionMetadata {
+        address: contract.to_vec(),
+        standard: standard as i32,
+        name: text(0),
+        symbol: text(1),
+        ..Default::default()
+    };
+    if let Some(raw) = ok(2).filter(|raw| raw.len() == 32) {
+        collection.owner = raw[12..].to_vec();
+    }
+    if let Some(raw) = ok(3).filter(|raw| raw.len() == 64) {
+        collection.royalty_receiver = raw[12..32].to_vec();
+        collection.royalty_bps = eth_utils::read_uint32(&raw[32..64]).unwrap_or(0);
+    }
+
+    (collection.contract_uri, collection.logo_uri) = metadata::uris(client, contract, implementation, rpc);
+    collection
+}
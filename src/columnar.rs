@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use crate::pb::{erc721, tokens};
+
+/// Positions of the entries of a dictionary column, so a busy block looks every row up in constant time.
+#[derive(Default)]
+struct Dictionary(HashMap<Vec<u8>, u32>);
+
+impl Dictionary {
+    /// Position of `value` in `entries`, added at the end when missing.
+    fn index(&mut self, entries: &mut Vec<Vec<u8>>, value: &[u8]) -> u32 {
+        if let Some(position) = self.0.get(value) {
+            return *position;
+        }
+        entries.push(value.to_vec());
+        let position = (entries.len() - 1) as u32;
+        self.0.insert(value.to_vec(), position);
+        position
+    }
+}
+
+pub fn transfers(transfers: erc721::Transfers) -> erc721::TransferColumns {
+    let mut columns = erc721::TransferColumns {
+        timestamp: transfers.transfers.first().and_then(|transfer| transfer.timestamp.clone()),
+        ..Default::default()
+    };
+    let (mut contract_positions, mut trx_positions) = (Dictionary::default(), Dictionary::default());
+    for transfer in transfers.transfers {
+        let contract = contract_positions.index(&mut columns.contracts, &transfer.contract);
+        let trx = trx_positions.index(&mut columns.trx_hashes, &transfer.trx_hash);
+        columns.contract_index.push(contract);
+        columns.trx_index.push(trx);
+        columns.from.push(transfer.from);
+        columns.to.push(transfer.to);
+        columns.token_id.push(transfer.token_id);
+        columns.ordinal.push(transfer.ordinal);
+    }
+    columns
+}
+
+pub fn erc20_transfers(transfers: tokens::Transfers) -> tokens::TransferColumns {
+    let mut columns = tokens::TransferColumns::default();
+    let (mut token_positions, mut trx_positions) = (Dictionary::default(), Dictionary::default());
+    for transfer in transfers.transfers {
+        let token = token_positions.index(&mut columns.tokens, &transfer.token);
+        let trx = trx_positions.index(&mut columns.trx_hashes, &transfer.trx_hash);
+        columns.token_index.push(token);
+        columns.trx_index.push(trx);
+        columns.standard.push(transfer.standard);
+        columns.from.push(transfer.from);
+        columns.to.push(transfer.to);
+        columns.amount.push(transfer.amount);
+        columns.operator.push(transfer.operator);
+        columns.ordinal.push(transfer.ordinal);
+    }
+    columns
+}
@@ -0,0 +1,70 @@
+use substreams::scalar::BigInt;
+use tiny_keccak::{Hasher, Keccak};
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    let mut hasher = Keccak::v256();
+    hasher.update(data);
+    hasher.finalize(&mut hash);
+    hash
+}
+
+/// EIP-712 `hashStruct`: the hash of the struct type followed by its encoded members.
+pub fn hash_struct(type_hash: &[u8; 32], members: &[[u8; 32]]) -> [u8; 32] {
+    let mut encoded = Vec::with_capacity(32 * (members.len() + 1));
+    encoded.extend_from_slice(type_hash);
+    for member in members {
+        encoded.extend_from_slice(member);
+    }
+    keccak256(&encoded)
+}
+
+/// EIP-712 encoding of an array member, the hash of its concatenated encoded items.
+pub fn hash_array(items: &[[u8; 32]]) -> [u8; 32] {
+    keccak256(&items.concat())
+}
+
+pub fn address_word(address: &[u8]) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[32 - address.len()..].copy_from_slice(address);
+    word
+}
+
+pub fn uint_word(value: &BigInt) -> [u8; 32] {
+    let bytes = value.to_bytes_be().1;
+    let mut word = [0u8; 32];
+    word[32 - bytes.len()..].copy_from_slice(&bytes);
+    word
+}
+
+pub fn bytes32_word(value: &[u8]) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[..value.len()].copy_from_slice(value);
+    word
+}
+
+/// Topic of an event, from its canonical signature such as `Transfer(address,address,uint256)`.
+/// Whitespace is ignored.
+pub fn event_topic(signature: &str) -> [u8; 32] {
+    let canonical: String = signature.chars().filter(|c| !c.is_whitespace()).collect();
+    keccak256(canonical.as_bytes())
+}
+
+/// EIP-55 mixed case checksum display of an address: a hex letter is uppercased when the matching
+/// nibble of the keccak256 of the lowercase hex address is 8 or more.
+pub fn checksum_address(address: &[u8]) -> String {
+    let lowercase = hex::encode(address);
+    let hash = keccak256(lowercase.as_bytes());
+
+    let mut checksummed = String::with_capacity(2 + lowercase.len());
+    checksummed.push_str("0x");
+    for (index, c) in lowercase.chars().enumerate() {
+        let nibble = if index % 2 == 0 { hash[index / 2] >> 4 } else { hash[index / 2] & 0x0f };
+        if nibble >= 8 {
+            checksummed.push(c.to_ascii_uppercase());
+        } else {
+            checksummed.push(c);
+        }
+    }
+    checksummed
+}
@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use substreams::scalar::BigInt;
+
+use crate::keys;
+use crate::pb::dex::liquidity_change::Kind;
+use crate::pb::dex::LiquidityChanges;
+use crate::pb::tokens::DeployerReputation;
+
+/// Share of the liquidity added to a token that has to be removed for it to count as rugged, in percent.
+const RUG_THRESHOLD: u64 = 90;
+
+pub fn deployer_key(token: &[u8]) -> String {
+    keys::key(keys::TOKEN).hex(token).into()
+}
+
+pub fn deployed_key(deployer: &[u8]) -> String {
+    keys::key(keys::DEPLOYER).hex(deployer).text("deployed").into()
+}
+
+pub fn rugged_key(deployer: &[u8]) -> String {
+    keys::key(keys::DEPLOYER).hex(deployer).text("rugged").into()
+}
+
+pub fn added_key(token: &[u8]) -> String {
+    keys::key(keys::ADDED).hex(token).into()
+}
+
+pub fn removed_key(token: &[u8]) -> String {
+    keys::key(keys::REMOVED).hex(token).into()
+}
+
+/// Block in which the ownership of a token was renounced.
+pub fn renounced_key(token: &[u8]) -> String {
+    keys::key(keys::RENOUNCED).hex(token).into()
+}
+
+/// Amount of each token of the pools added or removed by the changes, keyed by token.
+pub fn token_amounts(changes: &LiquidityChanges, kind: Kind) -> BTreeMap<Vec<u8>, BigInt> {
+    let mut amounts: BTreeMap<Vec<u8>, BigInt> = BTreeMap::new();
+    for change in &changes.changes {
+        if change.kind != kind as i32 {
+            continue;
+        }
+
+        for (token, amount) in [(&change.token0, &change.amount0), (&change.token1, &change.amount1)] {
+            if let Ok(amount) = BigInt::from_str(amount) {
+                let total = amounts.entry(token.clone()).or_insert_with(BigInt::zero);
+                *total = total.clone() + amount;
+            }
+        }
+    }
+    amounts
+}
+
+/// Whether removing `removed_now` more of a token crossed the rug threshold, given the totals after the removal.
+pub fn rugged_by(added: &BigInt, removed: &BigInt, removed_now: &BigInt) -> bool {
+    if added.is_zero() {
+        return false;
+    }
+
+    let threshold = added.clone() * BigInt::from(RUG_THRESHOLD);
+    let before = removed.clone() - removed_now.clone();
+    before * BigInt::from(100) <= threshold && removed.clone() * BigInt::from(100) > threshold
+}
+
+pub fn reputation(deployed: u64, rugged: u64) -> DeployerReputation {
+    let score = if deployed == 0 {
+        100.0
+    } else {
+        100.0 - rugged.min(deployed) as f64 / deployed as f64 * 100.0
+    };
+
+    DeployerReputation { deployed, rugged, score }
+}
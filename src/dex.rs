@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+
+use crate::keys;
+use crate::pb::dex::liquidity_change::Kind;
+use crate::pb::dex::LiquidityChange;
+
+pub const UNISWAP_V2_FACTORY: [u8; 20] = hex!("5c69bee701ef814a2b6a3edd4b1652cb9cc5aa6f");
+pub const UNISWAP_V3_FACTORY: [u8; 20] = hex!("1f98431c8ad98523631ae4a59f267346ea31f984");
+// Uniswap V3 NonfungiblePositionManager
+pub const UNISWAP_V3_POSITIONS: [u8; 20] = hex!("c36442b4a4522e871399cd717abdd847ab11fe88");
+
+// lockers holding Uniswap V2 LP tokens until an unlock time: Unicrypt UniswapV2Locker and Team.Finance LockToken
+pub const UNICRYPT_LOCKER: [u8; 20] = hex!("663a5c229c09b049e36dcc11a9b0d4a8eb9db214");
+pub const TEAM_FINANCE_LOCKER: [u8; 20] = hex!("e2fe530c047f2d85298b07d9333c05737f1435fb");
+
+pub const UNISWAP_V2: &str = "uniswap_v2";
+pub const UNISWAP_V3: &str = "uniswap_v3";
+pub const UNICRYPT: &str = "unicrypt";
+pub const TEAM_FINANCE: &str = "team_finance";
+
+pub fn pool_key(pool: &[u8]) -> String {
+    keys::key(keys::POOL).hex(pool).into()
+}
+
+/// Pools are stored as their `token0` followed by their `token1`.
+pub fn encode_pool(token0: &[u8], token1: &[u8]) -> Vec<u8> {
+    [token0, token1].concat()
+}
+
+pub fn decode_pool(value: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+    if value.len() != 40 {
+        return None;
+    }
+    Some((value[..20].to_vec(), value[20..].to_vec()))
+}
+
+pub fn position_key(id: u64) -> String {
+    keys::key(keys::POSITION).number(id).into()
+}
+
+/// Owner of a position in store_positions, `owner:position:<id>`.
+pub fn position_owner_key(id: u64) -> String {
+    keys::key(keys::OWNER).key(keys::key(keys::POSITION).number(id)).into()
+}
+
+/// Pool of a position in store_positions, `pool:position:<id>`.
+pub fn position_pool_key(id: u64) -> String {
+    keys::key(keys::POOL).key(keys::key(keys::POSITION).number(id)).into()
+}
+
+pub fn owner_key(owner: &[u8], pool: &[u8]) -> String {
+    keys::key(keys::OWNER).hex(owner).hex(pool).into()
+}
+
+/// Liquidity a V3 change adds to its position, negative when it is removed.
+pub fn signed_liquidity(change: &LiquidityChange) -> Option<BigInt> {
+    if change.protocol != UNISWAP_V3 {
+        return None;
+    }
+
+    let liquidity = BigInt::from_str(&change.liquidity).ok()?;
+    if change.kind == Kind::Remove as i32 {
+        return Some(liquidity.neg());
+    }
+    Some(liquidity)
+}
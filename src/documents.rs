@@ -0,0 +1,153 @@
+use prost_types::Timestamp;
+use substreams::Hex;
+
+use crate::clickhouse;
+use crate::eth_utils;
+use crate::pb::database::table_change::Operation;
+use crate::pb::database::{Field, TableChange};
+use crate::pb::documents::{Erc20Transfer, NftTransfer, Sale, TransactionDocument};
+use crate::pb::{erc721, tokens};
+
+/// Collection the MongoDB sink writes the transaction documents to.
+pub const COLLECTION: &str = "transactions";
+
+/// Documents of the block, one per transaction in the order of their first transfer or sale.
+pub struct Documents {
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    documents: Vec<TransactionDocument>,
+}
+
+impl Documents {
+    pub fn new(block_number: u64, timestamp: Option<Timestamp>) -> Documents {
+        Documents {
+            block_number,
+            timestamp,
+            documents: vec![],
+        }
+    }
+
+    fn document(&mut self, trx_hash: &[u8]) -> &mut TransactionDocument {
+        let id = format!("0x{}", Hex(trx_hash));
+        let position = match self.documents.iter().position(|document| document.id == id) {
+            Some(position) => position,
+            None => {
+                self.documents.push(TransactionDocument {
+                    id,
+                    block_number: self.block_number,
+                    timestamp: self.timestamp.clone(),
+                    ..Default::default()
+                });
+                self.documents.len() - 1
+            }
+        };
+        &mut self.documents[position]
+    }
+
+    pub fn nft_transfers(&mut self, transfers: &erc721::Transfers) {
+        for transfer in &transfers.transfers {
+            self.document(&transfer.trx_hash).nft_transfers.push(NftTransfer {
+                contract: eth_utils::format_address(&transfer.contract),
+                from: eth_utils::format_address(&transfer.from),
+                to: eth_utils::format_address(&transfer.to),
+                token_id: transfer.token_id,
+                ordinal: transfer.ordinal,
+            });
+        }
+    }
+
+    pub fn erc20_transfers(&mut self, transfers: &tokens::Transfers) {
+        for transfer in &transfers.transfers {
+            self.document(&transfer.trx_hash).erc20_transfers.push(Erc20Transfer {
+                token: eth_utils::format_address(&transfer.token),
+                from: eth_utils::format_address(&transfer.from),
+                to: eth_utils::format_address(&transfer.to),
+                amount: transfer.amount.clone(),
+                standard: clickhouse::standard(transfer.standard).to_string(),
+                ordinal: transfer.ordinal,
+            });
+        }
+    }
+
+    pub fn sales(&mut self, sales: &erc721::Sales) {
+        for sale in &sales.sales {
+            self.document(&sale.trx_hash).sales.push(Sale {
+                marketplace: sale.marketplace.clone(),
+                collection: eth_utils::format_address(&sale.collection),
+                token_id: sale.token_id,
+                seller: eth_utils::format_address(&sale.seller),
+                buyer: eth_utils::format_address(&sale.buyer),
+                price: sale.price.clone(),
+                currency: clickhouse::currency(sale),
+                ordinal: sale.ordinal,
+            });
+        }
+    }
+
+    /// One row of COLLECTION per document, keyed by its transaction hash, with the transfers and sales embedded as
+    /// JSON arrays since database changes only carry string fields.
+    pub fn into_table_changes(self) -> Vec<TableChange> {
+        self.documents.iter().map(table_change).collect()
+    }
+}
+
+fn table_change(document: &TransactionDocument) -> TableChange {
+    let ordinals = document
+        .nft_transfers
+        .iter()
+        .map(|transfer| transfer.ordinal)
+        .chain(document.erc20_transfers.iter().map(|transfer| transfer.ordinal))
+        .chain(document.sales.iter().map(|sale| sale.ordinal));
+    let timestamp = document.timestamp.as_ref().map(|timestamp| timestamp.seconds).unwrap_or_default();
+    let field = |name: &str, value: String| Field {
+        name: name.to_string(),
+        new_value: value,
+        old_value: String::new(),
+    };
+
+    TableChange {
+        table: COLLECTION.to_string(),
+        pk: document.id.clone(),
+        ordinal: ordinals.min().unwrap_or_default(),
+        operation: Operation::Create as i32,
+        fields: vec![
+            field("block_number", document.block_number.to_string()),
+            field("block_timestamp", timestamp.to_string()),
+            field("nft_transfers", json_array(document.nft_transfers.iter().map(nft_transfer_json))),
+            field("erc20_transfers", json_array(document.erc20_transfers.iter().map(erc20_transfer_json))),
+            field("sales", json_array(document.sales.iter().map(sale_json))),
+        ],
+    }
+}
+
+fn json_array(values: impl Iterator<Item = String>) -> String {
+    format!("[{}]", values.collect::<Vec<_>>().join(","))
+}
+
+fn nft_transfer_json(transfer: &NftTransfer) -> String {
+    format!(
+        r#"{{"contract":"{}","from":"{}","to":"{}","token_id":{},"ordinal":{}}}"#,
+        transfer.contract, transfer.from, transfer.to, transfer.token_id, transfer.ordinal,
+    )
+}
+
+fn erc20_transfer_json(transfer: &Erc20Transfer) -> String {
+    format!(
+        r#"{{"token":"{}","from":"{}","to":"{}","amount":"{}","standard":"{}","ordinal":{}}}"#,
+        transfer.token, transfer.from, transfer.to, transfer.amount, transfer.standard, transfer.ordinal,
+    )
+}
+
+fn sale_json(sale: &Sale) -> String {
+    format!(
+        r#"{{"marketplace":{},"collection":"{}","token_id":{},"seller":"{}","buyer":"{}","price":"{}","currency":"{}","ordinal":{}}}"#,
+        eth_utils::json_string(&sale.marketplace),
+        sale.collection,
+        sale.token_id,
+        sale.seller,
+        sale.buyer,
+        sale.price,
+        sale.currency,
+        sale.ordinal,
+    )
+}
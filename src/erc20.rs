@@ -0,0 +1,311 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::{Event, NULL_ADDRESS};
+
+use crate::abi;
+use crate::eth_utils;
+use crate::params::Params;
+use crate::pb::tokens::transfer::Standard;
+use crate::pb::tokens::{NetChange, Transfer, TransferAggregate, Transfers};
+
+/// Topics of the events decoded as fungible moves: ERC20 Transfer and ERC777 Sent, Minted and Burned.
+pub const TOPICS: [[u8; 32]; 4] = [
+    eth_utils::TRANSFER_TOPIC,
+    hex!("06b541ddaa720db2b10a4d0cdac39b8d360425fc073085fac19bc82614677987"),
+    hex!("2fe5be0146f74c5bce36c0b80911af6c7d86ff27e89d5cfa61fc681327954e5d"),
+    hex!("a78a9be3a7b862d26933ad85fb11d80ef66b8f972d7cbba06621d583943a4098"),
+];
+
+/// Bloom bits of the TOPICS, worked out once per block by the modules going through every transaction.
+pub fn topic_bits() -> Vec<eth_utils::BloomBits> {
+    TOPICS.iter().map(|topic| eth_utils::BloomBits::of(topic)).collect()
+}
+
+/// Output volume of map_sampled_erc20_transfers, read from the `mode` param.
+pub enum Mode {
+    /// Every transfer.
+    All,
+    /// One transfer out of `every`, along with the per token aggregates.
+    Sample(usize),
+    /// Per token aggregates only.
+    Aggregate,
+}
+
+pub fn mode(params: &Params) -> Mode {
+    match params.get("mode") {
+        Some("sample") => Mode::Sample(params.get("every").and_then(|value| value.parse().ok()).unwrap_or(10).max(1)),
+        Some("aggregate") => Mode::Aggregate,
+        _ => Mode::All,
+    }
+}
+
+/// Transfers of the block reduced according to `mode`.
+pub fn reduce(transfers: Vec<Transfer>, mode: Mode) -> Transfers {
+    match mode {
+        Mode::All => Transfers {
+            transfers,
+            ..Default::default()
+        },
+        Mode::Sample(every) => Transfers {
+            aggregates: aggregate(&transfers),
+            transfers: transfers.into_iter().step_by(every).collect(),
+            ..Default::default()
+        },
+        Mode::Aggregate => Transfers {
+            aggregates: aggregate(&transfers),
+            ..Default::default()
+        },
+    }
+}
+
+struct Totals<'a> {
+    transfer_count: u64,
+    volume: BigInt,
+    changes: BTreeMap<&'a [u8], BigInt>,
+    ordinal: u64,
+}
+
+fn aggregate(transfers: &[Transfer]) -> Vec<TransferAggregate> {
+    // keyed by borrowed addresses, they are only copied once per token and holder into the aggregates
+    let mut totals: BTreeMap<&[u8], Totals> = BTreeMap::new();
+    for transfer in transfers {
+        let amount = match BigInt::from_str(&transfer.amount) {
+            Ok(amount) => amount,
+            Err(_) => continue,
+        };
+
+        let token = totals.entry(&transfer.token).or_insert_with(|| Totals {
+            transfer_count: 0,
+            volume: BigInt::zero(),
+            changes: BTreeMap::new(),
+            ordinal: 0,
+        });
+        token.transfer_count += 1;
+        token.ordinal = transfer.ordinal;
+        token.volume = token.volume.clone() + amount.clone();
+
+        let sent = token.changes.entry(&transfer.from).or_insert_with(BigInt::zero);
+        *sent = sent.clone() - amount.clone();
+        let received = token.changes.entry(&transfer.to).or_insert_with(BigInt::zero);
+        *received = received.clone() + amount;
+    }
+
+    totals
+        .into_iter()
+        .map(|(token, totals)| TransferAggregate {
+            token: token.to_vec(),
+            transfer_count: totals.transfer_count,
+            volume: totals.volume.to_string(),
+            changes: totals
+                .changes
+                .into_iter()
+                .filter(|(_, amount)| !amount.is_zero())
+                .map(|(holder, amount)| NetChange {
+                    holder: holder.to_vec(),
+                    amount: amount.to_string(),
+                })
+                .collect(),
+            ordinal: totals.ordinal,
+        })
+        .collect()
+}
+
+pub struct BalanceChange {
+    pub token: Vec<u8>,
+    pub holder: Vec<u8>,
+    pub amount: BigInt,
+    pub ordinal: u64,
+}
+
+/// Balance changes carried by the transfers of a block, from the aggregates when present as map_sampled_erc20_transfers
+/// emits them, so they stay exact whatever its mode.
+pub fn balance_changes(transfers: &Transfers) -> Vec<BalanceChange> {
+    let mut changes = vec![];
+    if !transfers.aggregates.is_empty() {
+        for aggregate in &transfers.aggregates {
+            for change in &aggregate.changes {
+                if let Ok(amount) = BigInt::from_str(&change.amount) {
+                    changes.push(BalanceChange {
+                        token: aggregate.token.clone(),
+                        holder: change.holder.clone(),
+                        amount,
+                        ordinal: aggregate.ordinal,
+                    });
+                }
+            }
+        }
+        return changes;
+    }
+
+    for transfer in &transfers.transfers {
+        let amount = match BigInt::from_str(&transfer.amount) {
+            Ok(amount) => amount,
+            Err(_) => continue,
+        };
+        changes.push(BalanceChange {
+            token: transfer.token.clone(),
+            holder: transfer.from.clone(),
+            amount: amount.neg(),
+            ordinal: transfer.ordinal,
+        });
+        changes.push(BalanceChange {
+            token: transfer.token.clone(),
+            holder: transfer.to.clone(),
+            amount,
+            ordinal: transfer.ordinal,
+        });
+    }
+    changes
+}
+
+/// Fungible token transfers of the transaction. ERC777 tokens report their moves through `Sent`,
+/// `Minted` and `Burned`, the ERC20 compatible Transfer event they emit along is dropped so the
+/// move is only counted once. Transfer events emitted while handling an ERC1363 `transferAndCall`
+/// or `transferFromAndCall` are flagged as such. Transfer events a token emits twice for a single
+/// movement are left out, see `suppress_duplicates`, and counted in the returned number.
+pub fn decode(trx: &eth::TransactionTrace) -> (Vec<Transfer>, u64) {
+    use abi::erc777::events as erc777;
+
+    let log_count = trx.receipt.as_ref().map_or(0, |receipt| receipt.logs.len());
+    let mut transfers = Vec::with_capacity(log_count);
+    let mut log_indexes = Vec::with_capacity(log_count);
+    for call in trx.calls.iter().filter(|call| !call.state_reverted) {
+        for log in &call.logs {
+            let (standard, from, to, amount, operator) = if let Some(sent) = erc777::Sent::match_and_decode(log) {
+                (Standard::Erc777, sent.from, sent.to, sent.amount, sent.operator)
+            } else if let Some(minted) = erc777::Minted::match_and_decode(log) {
+                (Standard::Erc777, NULL_ADDRESS.to_vec(), minted.to, minted.amount, minted.operator)
+            } else if let Some(burned) = erc777::Burned::match_and_decode(log) {
+                (Standard::Erc777, burned.from, NULL_ADDRESS.to_vec(), burned.amount, burned.operator)
+            } else if let Some(transfer) = abi::erc20::events::Transfer::match_and_decode(log) {
+                let standard = if call.address == log.address && is_erc1363_call(call) {
+                    Standard::Erc1363
+                } else {
+                    Standard::Erc20
+                };
+                (standard, transfer.from, transfer.to, transfer.value, vec![])
+            } else {
+                continue;
+            };
+
+            transfers.push(Transfer {
+                id: eth_utils::record_id(&trx.hash, log.block_index),
+                standard: standard as i32,
+                token: log.address.clone(),
+                from,
+                to,
+                amount: amount.to_string(),
+                operator,
+                trx_hash: trx.hash.clone(),
+                ordinal: log.ordinal,
+                decimals: 0,
+                value: String::new(),
+            });
+            log_indexes.push(log.index);
+        }
+    }
+    let suppressed = suppress_duplicates(trx, &mut transfers, &log_indexes);
+
+    let is_erc777 = |transfer: &Transfer| transfer.standard == Standard::Erc777 as i32;
+    if transfers.iter().any(is_erc777) {
+        // flagged through borrowed moves, the transfers are not cloned
+        let erc777_moves: Vec<&Transfer> = transfers.iter().filter(|transfer| is_erc777(transfer)).collect();
+        let duplicates: Vec<bool> = transfers
+            .iter()
+            .map(|transfer| {
+                !is_erc777(transfer)
+                    && erc777_moves.iter().any(|moved| {
+                        moved.token == transfer.token
+                            && moved.from == transfer.from
+                            && moved.to == transfer.to
+                            && moved.amount == transfer.amount
+                    })
+            })
+            .collect();
+        let mut index = 0;
+        transfers.retain(|_| {
+            index += 1;
+            !duplicates[index - 1]
+        });
+    }
+    transfers.sort_by_key(|transfer| transfer.ordinal);
+    (transfers, suppressed)
+}
+
+/// Drops the Transfer events of runs of adjacent logs carrying the same token, sides and amount that the storage
+/// changes of the token do not corroborate: every genuine movement lowers a balance slot by the amount, a duplicate
+/// event does not. Mints and burns also move the total supply, and transactions recorded without the storage
+/// changes of the token cannot be corroborated, both are kept as they are.
+fn suppress_duplicates(trx: &eth::TransactionTrace, transfers: &mut Vec<Transfer>, log_indexes: &[u32]) -> u64 {
+    let is_plain = |transfer: &Transfer| {
+        transfer.standard != Standard::Erc777 as i32
+            && transfer.from != NULL_ADDRESS
+            && transfer.to != NULL_ADDRESS
+            && transfer.from != transfer.to
+    };
+    let same = |a: &Transfer, b: &Transfer| {
+        a.token == b.token && a.from == b.from && a.to == b.to && a.amount == b.amount
+    };
+
+    let mut keep = vec![true; transfers.len()];
+    let mut start = 0;
+    while start < transfers.len() {
+        let mut end = start + 1;
+        while end < transfers.len()
+            && log_indexes[end] == log_indexes[end - 1] + 1
+            && same(&transfers[start], &transfers[end])
+        {
+            end += 1;
+        }
+
+        let run = (end - start) as u64;
+        if run > 1 && is_plain(&transfers[start]) {
+            let movements = balance_decreases(trx, &transfers[start]);
+            if movements > 0 && movements < run {
+                for kept in keep.iter_mut().take(end).skip(start + movements as usize) {
+                    *kept = false;
+                }
+            }
+        }
+        start = end;
+    }
+
+    let suppressed = keep.iter().filter(|kept| !**kept).count() as u64;
+    let mut index = 0;
+    transfers.retain(|_| {
+        index += 1;
+        keep[index - 1]
+    });
+    suppressed
+}
+
+/// Storage slots of the token lowered by the amount of the transfer during the transaction.
+fn balance_decreases(trx: &eth::TransactionTrace, transfer: &Transfer) -> u64 {
+    let amount = match BigInt::from_str(&transfer.amount) {
+        Ok(amount) => amount,
+        Err(_) => return 0,
+    };
+    trx.calls
+        .iter()
+        .filter(|call| !call.state_reverted)
+        .flat_map(|call| call.storage_changes.iter())
+        .filter(|change| change.address == transfer.token)
+        .filter(|change| {
+            BigInt::from_unsigned_bytes_be(&change.old_value) - BigInt::from_unsigned_bytes_be(&change.new_value)
+                == amount
+        })
+        .count() as u64
+}
+
+fn is_erc1363_call(call: &eth::Call) -> bool {
+    use abi::erc1363::functions as erc1363;
+
+    erc1363::TransferAndCall1::match_call(call)
+        || erc1363::TransferAndCall2::match_call(call)
+        || erc1363::TransferFromAndCall1::match_call(call)
+        || erc1363::TransferFromAndCall2::match_call(call)
+}
@@ -1,4 +1,17 @@
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
+
+use hex_literal::hex;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::crypto;
+
+// keccak256("Transfer(address,address,uint256)"), shared by ERC20 and ERC721
+pub const TRANSFER_TOPIC: [u8; 32] = hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+/// Longest name or symbol kept, in characters.
+pub const MAX_METADATA_CHARS: usize = 64;
 
 pub fn read_uint32(input: &[u8]) -> Result<u32, String> {
     if input.len() != 32 {
@@ -8,8 +21,25 @@ pub fn read_uint32(input: &[u8]) -> Result<u32, String> {
     Ok(u32::from_be_bytes(as_array))
 }
 
-pub fn read_string(input: &[u8]) -> Result<String, String> {
-    if input.len() < 96 {
+/// Raw bytes of an ABI encoded `string` return value.
+pub fn read_string_bytes(input: &[u8]) -> Result<Vec<u8>, String> {
+    // early tokens (MKR, SAI) return their name and symbol as a zero padded `bytes32`. Other 32 bytes returns, such
+    // as numbers or addresses, are told apart by requiring printable ASCII followed by NUL padding only
+    if input.len() == 32 {
+        let end = input.iter().position(|byte| *byte == 0).unwrap_or(32);
+        if end == 0 {
+            return Err("empty bytes32 string".to_string());
+        }
+        if !input[..end].iter().all(|byte| (0x20..=0x7e).contains(byte)) {
+            return Err(format!("bytes32 string not printable: {}", hex::encode(input)));
+        }
+        if input[end..].iter().any(|byte| *byte != 0) {
+            return Err(format!("bytes32 string not NUL padded: {}", hex::encode(input)));
+        }
+        return Ok(input[..end].to_vec());
+    }
+    // an empty string is only the offset and the length words
+    if input.len() < 64 {
         return Err(format!("string invalid length: {}", input.len()));
     }
 
@@ -19,9 +49,10 @@ pub fn read_string(input: &[u8]) -> Result<String, String> {
     };
 
     let size = read_uint32(&input[32..64])?;
-    let end: usize = (size as usize) + 64;
+    // computed as u64, a length close to u32::MAX would overflow the 32 bits usize of wasm32
+    let end = size as u64 + 64;
 
-    if end > input.len() {
+    if end > input.len() as u64 {
         return Err(format!(
             "invalid input: end {:?}, length: {:?}, next: {:?}, size: {:?}, whole: {:?}",
             end,
@@ -32,5 +63,178 @@ pub fn read_string(input: &[u8]) -> Result<String, String> {
         ));
     }
 
-    Ok(String::from_utf8_lossy(&input[64..end]).to_string())
-}
\ No newline at end of file
+    Ok(input[64..end as usize].to_vec())
+}
+
+/// Metadata read from contracts, a name or a symbol, made safe to store: NFC normalized, without control and
+/// bidirectional override characters, trimmed and cut to MAX_METADATA_CHARS characters. Bytes that are not valid
+/// UTF-8 are rendered as `0x` prefixed hex instead, see `is_lossy`.
+pub fn sanitize_metadata(raw: &[u8]) -> String {
+    use unicode_normalization::UnicodeNormalization;
+
+    let text = match std::str::from_utf8(raw) {
+        Ok(text) => text,
+        Err(_) => {
+            let shown = &raw[..raw.len().min((MAX_METADATA_CHARS - 2) / 2)];
+            return format!("0x{}", Hex(shown));
+        }
+    };
+
+    let is_bidi_control = |c: char| matches!(c, '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}');
+    let sanitized: String = text.nfc().filter(|c| !c.is_control() && !is_bidi_control(*c)).collect();
+    sanitized.trim().chars().take(MAX_METADATA_CHARS).collect()
+}
+
+/// Whether `sanitize_metadata` had to fall back to hex for the bytes.
+pub fn is_lossy(raw: &[u8]) -> bool {
+    std::str::from_utf8(raw).is_err()
+}
+
+/// `value` as u64, None when negative or too large, as hash based ERC721 token ids (ENS) are.
+pub fn to_u64(value: &BigInt) -> Option<u64> {
+    u64::try_from(value).ok()
+}
+
+/// Plain rendering of an amount in whole tokens, without the trailing zeros of its scale.
+pub fn decimal_string(value: &BigDecimal) -> String {
+    let text = value.to_string();
+    if !text.contains('.') {
+        return text;
+    }
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Standard or URL safe base64, padding optional, None on any other character.
+pub fn base64_decode(input: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(input.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for c in input.bytes().filter(|c| !c.is_ascii_whitespace() && *c != b'=') {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+pub fn parse_address(input: &str) -> Result<Vec<u8>, String> {
+    let input = input.trim();
+    let input = input.strip_prefix("0x").unwrap_or(input);
+    let address = hex::decode(input).map_err(|e| format!("invalid address {}: {}", input, e))?;
+    if address.len() != 20 {
+        return Err(format!("address invalid length: {}", address.len()));
+    }
+    Ok(address)
+}
+
+/// Stable id of a record decoded from a log, `<transaction hash>-<log index>` with the index of the log in the block,
+/// as subgraph mappings id event entities, so sinks can upsert records replayed after a restart or a reorg. Records
+/// sharing a log add the token id as a third segment.
+pub fn record_id(trx_hash: &[u8], log_index: u32) -> String {
+    format!("0x{}-{}", Hex(trx_hash), log_index)
+}
+
+/// Stable id of a record decoded from a call, `<transaction hash>-call-<call index>` with the index of the call in its
+/// transaction. The `call` segment keeps it apart from the ids of logs sharing the number.
+pub fn call_record_id(trx_hash: &[u8], call_index: u32) -> String {
+    format!("0x{}-call-{}", Hex(trx_hash), call_index)
+}
+
+/// `0x` prefixed hex display of an address kept as bytes.
+pub fn format_address(address: &[u8]) -> String {
+    format!("0x{}", Hex(address))
+}
+
+/// Bits a log address or topic sets in a logs bloom, worked out once per input rather than once per bloom.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BloomBits([usize; 3]);
+
+impl BloomBits {
+    pub fn of(input: &[u8]) -> BloomBits {
+        let hash = crypto::keccak256(input);
+        let bit = |i: usize| (((hash[2 * i] as usize) << 8) | hash[2 * i + 1] as usize) & 2047;
+        BloomBits([bit(0), bit(1), bit(2)])
+    }
+}
+
+/// Whether a logs bloom, of a block header or of a receipt, may hold the input of `bits`: a log address or topic.
+/// Blooms that are not 2048 bits, as in blocks recorded without them, may hold anything.
+pub fn bloom_may_contain(bloom: &[u8], bits: &BloomBits) -> bool {
+    if bloom.len() != 256 {
+        return true;
+    }
+    bits.0.iter().all(|bit| bloom[255 - bit / 8] & (1 << (bit % 8)) != 0)
+}
+
+/// Successful transactions whose receipt bloom may hold all the `inputs`. When the block bloom rules one of them
+/// out, no transaction is gone through at all.
+pub fn transactions_matching<'a>(
+    blk: &'a eth::Block,
+    inputs: &[&[u8]],
+) -> impl Iterator<Item = &'a eth::TransactionTrace> + 'a {
+    let bits: Vec<BloomBits> = inputs.iter().map(|input| BloomBits::of(input)).collect();
+    matching(blk, move |bloom| bits.iter().all(|bits| bloom_may_contain(bloom, bits)))
+}
+
+/// Successful transactions whose receipt bloom may hold one of the `inputs`, such as the topics of the events of a
+/// standard.
+pub fn transactions_matching_any<'a>(
+    blk: &'a eth::Block,
+    inputs: &[&[u8]],
+) -> impl Iterator<Item = &'a eth::TransactionTrace> + 'a {
+    let bits: Vec<BloomBits> = inputs.iter().map(|input| BloomBits::of(input)).collect();
+    matching(blk, move |bloom| bits.iter().any(|bits| bloom_may_contain(bloom, bits)))
+}
+
+fn matching<'a>(
+    blk: &'a eth::Block,
+    may_contain: impl Fn(&[u8]) -> bool + 'a,
+) -> impl Iterator<Item = &'a eth::TransactionTrace> + 'a {
+    let block_matches = blk.header.as_ref().map_or(true, |header| may_contain(&header.logs_bloom));
+    blk.transactions()
+        .filter(move |_| block_matches)
+        .filter(move |trx| trx.receipt.as_ref().map_or(false, |receipt| may_contain(&receipt.logs_bloom)))
+}
+
+/// Implementation a proxy delegated to in the transaction, the code behind the calls made to `proxy`.
+pub fn proxy_implementation(trx: &eth::TransactionTrace, proxy: &[u8]) -> Option<Vec<u8>> {
+    trx.calls
+        .iter()
+        .filter(|call| !call.state_reverted && call.call_type == eth::CallType::Delegate as i32)
+        .find(|call| call.caller == proxy)
+        .map(|call| call.address.clone())
+}
+
+pub fn block_timestamp(blk: &eth::Block) -> Option<prost_types::Timestamp> {
+    blk.header.as_ref().and_then(|header| header.timestamp.clone())
+}
+
+/// Quoted JSON string, token names and symbols come from contracts and may hold anything.
+pub fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,215 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use substreams::scalar::BigInt;
+
+use crate::erc20;
+use crate::eth_utils;
+use crate::logging;
+use crate::keys;
+use crate::params::Params;
+use crate::pb::tokens::flow_signal::Direction;
+use crate::pb::tokens::{ExchangeFlow, FlowSignal, Transfers};
+
+/// Deposit and hot wallets of the largest centralized exchanges, used when the `exchanges` param is not set.
+const KNOWN_WALLETS: [(&str, &str); 7] = [
+    ("0x28c6c06298d514db089934071355e5743bf21d60", "binance"),
+    ("0x21a31ee1afc51d94c2efccaa2092ad1028285549", "binance"),
+    ("0xdfd5293d8e347dfe59e90efd55b2956a1343963d", "binance"),
+    ("0xf977814e90da44bfa03b6295a0616a897441acec", "binance"),
+    ("0xa9d1e08c7793af67e9d92fe308d5697fb81d3e43", "coinbase"),
+    ("0x267be1c1d684f78cb4f6a176c4911b741e4ffdc0", "kraken"),
+    ("0x6cc5f688a315f3dc28a7781717a9a798a59fda7b", "okx"),
+];
+
+/// Exchange wallets by address, from the `<address>:<exchange>` entries of the `exchanges` param, the known wallets
+/// of the largest exchanges by default. The entries of the `extra_exchanges` param are added to either.
+pub struct Wallets(BTreeMap<Vec<u8>, String>);
+
+impl Wallets {
+    pub fn from_params(params: &Params) -> Wallets {
+        let (replaced, extra) = (params.list("exchanges"), params.list("extra_exchanges"));
+        let mut entries: Vec<_> = if replaced.is_empty() {
+            KNOWN_WALLETS.iter().map(|(address, exchange)| (*address, *exchange)).collect()
+        } else {
+            replaced.iter().map(|entry| entry.split_once(':').unwrap_or((entry, ""))).collect()
+        };
+        entries.extend(extra.iter().map(|entry| entry.split_once(':').unwrap_or((entry, ""))));
+
+        let mut wallets = BTreeMap::new();
+        for (address, exchange) in entries {
+            match eth_utils::parse_address(address) {
+                Ok(address) if !exchange.is_empty() => {
+                    wallets.insert(address, exchange.to_string());
+                }
+                _ => logging::warning!("params", "skipping invalid exchange entry {}:{}", address, exchange),
+            }
+        }
+        Wallets(wallets)
+    }
+
+    /// Exchange `address` is a wallet of, if any.
+    pub fn exchange(&self, address: &[u8]) -> Option<&str> {
+        self.0.get(address).map(String::as_str)
+    }
+}
+
+/// Amounts of every token deposited to and withdrawn from every exchange in the block. Moves between two wallets of
+/// the same exchange are internal and left out. Whenever the transfers carry aggregates, as map_sampled_erc20_transfers
+/// emits in `sample` and `aggregate` mode, the transfers kept are only a sample, so the net change of the wallets of
+/// each exchange is counted instead and the deposit and withdrawal counts are left at zero.
+pub fn flows(block: u64, transfers: &Transfers, wallets: &Wallets) -> Vec<ExchangeFlow> {
+    let mut flows: BTreeMap<(Vec<u8>, String), ExchangeFlow> = BTreeMap::new();
+    let mut flow = |token: &[u8], exchange: &str, amount: BigInt, deposit: bool| {
+        let flow = flows.entry((token.to_vec(), exchange.to_string())).or_insert_with(|| ExchangeFlow {
+            block,
+            token: token.to_vec(),
+            exchange: exchange.to_string(),
+            inflow: "0".to_string(),
+            outflow: "0".to_string(),
+            ..Default::default()
+        });
+        let (total, count) = if deposit {
+            (&mut flow.inflow, &mut flow.deposits)
+        } else {
+            (&mut flow.outflow, &mut flow.withdrawals)
+        };
+        *total = (BigInt::from_str(total).unwrap_or_else(|_| BigInt::zero()) + amount).to_string();
+        *count += 1;
+    };
+
+    if !transfers.aggregates.is_empty() {
+        // wallets of the same exchange are netted together, so internal moves cancel out
+        let mut changes: BTreeMap<(Vec<u8>, &str), BigInt> = BTreeMap::new();
+        for change in erc20::balance_changes(transfers) {
+            if let Some(exchange) = wallets.exchange(&change.holder) {
+                let total = changes.entry((change.token, exchange)).or_insert_with(BigInt::zero);
+                *total = total.clone() + change.amount;
+            }
+        }
+        for ((token, exchange), amount) in changes {
+            match amount.cmp(&BigInt::zero()) {
+                Ordering::Greater => flow(&token, exchange, amount, true),
+                Ordering::Less => flow(&token, exchange, amount.neg(), false),
+                Ordering::Equal => {}
+            }
+        }
+        for flow in flows.values_mut() {
+            flow.deposits = 0;
+            flow.withdrawals = 0;
+        }
+        return flows.into_values().collect();
+    }
+
+    for transfer in &transfers.transfers {
+        let (from, to) = (wallets.exchange(&transfer.from), wallets.exchange(&transfer.to));
+        if from == to {
+            continue;
+        }
+        let amount = match BigInt::from_str(&transfer.amount) {
+            Ok(amount) => amount,
+            Err(_) => continue,
+        };
+        if let Some(exchange) = to {
+            flow(&transfer.token, exchange, amount.clone(), true);
+        }
+        if let Some(exchange) = from {
+            flow(&transfer.token, exchange, amount, false);
+        }
+    }
+    flows.into_values().collect()
+}
+
+/// Rolling window of net flows, summed over its last `buckets` buckets of `bucket_seconds`.
+pub struct Window {
+    pub name: &'static str,
+    pub bucket_seconds: i64,
+    pub buckets: i64,
+}
+
+pub const WINDOWS: [Window; 2] = [
+    Window {
+        name: "1h",
+        bucket_seconds: 300,
+        buckets: 12,
+    },
+    Window {
+        name: "24h",
+        bucket_seconds: 3600,
+        buckets: 24,
+    },
+];
+
+impl Window {
+    pub fn bucket(&self, timestamp: i64) -> i64 {
+        timestamp.div_euclid(self.bucket_seconds)
+    }
+}
+
+/// Net flow of `token` into `exchange` during `bucket` of `window`. The bucket comes first so a bucket is dropped
+/// for every token and exchange at once, see `bucket_prefix`.
+pub fn net_flow_key(token: &[u8], exchange: &str, window: &Window, bucket: i64) -> String {
+    bucket_key(window, bucket).hex(token).text(exchange).into()
+}
+
+/// Prefix of the net flows of every token and exchange during `bucket` of `window`.
+pub fn bucket_prefix(window: &Window, bucket: i64) -> String {
+    bucket_key(window, bucket).into()
+}
+
+fn bucket_key(window: &Window, bucket: i64) -> keys::Key {
+    // buckets count from the epoch, zero padded so the prefix of a bucket never matches another one
+    keys::key(keys::NETFLOW).text(window.name).block(bucket.max(0) as u64)
+}
+
+/// Inflow less outflow of the block, negative when more left the exchange than entered it.
+pub fn net(flow: &ExchangeFlow) -> BigInt {
+    let amount = |amount: &str| BigInt::from_str(amount).unwrap_or_else(|_| BigInt::zero());
+    amount(&flow.inflow) - amount(&flow.outflow)
+}
+
+/// Net flow, in the smallest unit of the token, past which a signal is emitted, from the `<token>:<amount>` entries
+/// of the `thresholds` param. Tokens without a threshold emit no signal.
+pub fn thresholds(params: &Params) -> BTreeMap<Vec<u8>, BigInt> {
+    let mut thresholds = BTreeMap::new();
+    for entry in params.list("thresholds") {
+        let parsed = entry.split_once(':').and_then(|(token, amount)| {
+            Some((eth_utils::parse_address(token).ok()?, BigInt::from_str(amount).ok()?))
+        });
+        match parsed {
+            Some((token, amount)) if amount > BigInt::zero() => {
+                thresholds.insert(token, amount);
+            }
+            _ => logging::warning!("params", "skipping invalid threshold entry {}", entry),
+        }
+    }
+    thresholds
+}
+
+/// Signal of the rolling net flow of `window` going from `previous` to `current`, emitted when it crosses
+/// `threshold` in either direction.
+pub fn signal(
+    flow: &ExchangeFlow,
+    window: &Window,
+    previous: &BigInt,
+    current: BigInt,
+    threshold: &BigInt,
+) -> Option<FlowSignal> {
+    let direction = if current >= *threshold && previous < threshold {
+        Direction::Inflow
+    } else if current <= threshold.neg() && *previous > threshold.neg() {
+        Direction::Outflow
+    } else {
+        return None;
+    };
+    Some(FlowSignal {
+        block: flow.block,
+        token: flow.token.clone(),
+        exchange: flow.exchange.clone(),
+        window: window.name.to_string(),
+        direction: direction as i32,
+        net_flow: current.to_string(),
+        threshold: threshold.to_string(),
+    })
+}
@@ -0,0 +1,77 @@
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::pb::blocks::{BlobBlock, BlobGas};
+
+// EIP-1559 parameters
+const ELASTICITY_MULTIPLIER: u64 = 2;
+const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+// EIP-4844 gas of a blob, unchanged by the forks raising the blob target and limit
+const GAS_PER_BLOB: u64 = 1 << 17;
+
+pub fn big_int(value: &Option<eth::BigInt>) -> BigInt {
+    match value {
+        Some(value) => BigInt::from_unsigned_bytes_be(&value.bytes),
+        None => BigInt::zero(),
+    }
+}
+
+/// Base fee of the next block, raised or lowered by up to 1/8 depending on how far the block gas
+/// usage is from its target.
+pub fn next_base_fee(base_fee: &BigInt, gas_used: u64, gas_limit: u64) -> BigInt {
+    let target = gas_limit / ELASTICITY_MULTIPLIER;
+    if target == 0 || gas_used == target {
+        return base_fee.clone();
+    }
+
+    let denominator = BigInt::from(target) * BigInt::from(BASE_FEE_MAX_CHANGE_DENOMINATOR);
+    if gas_used > target {
+        let delta = base_fee.clone() * BigInt::from(gas_used - target) / denominator;
+        let delta = if delta.is_zero() { BigInt::from(1u64) } else { delta };
+        base_fee.clone() + delta
+    } else {
+        let delta = base_fee.clone() * BigInt::from(target - gas_used) / denominator;
+        base_fee.clone() - delta
+    }
+}
+
+/// Priority fee paid per gas by each transaction, the effective gas price above the base fee.
+pub fn priority_fees(blk: &eth::Block, base_fee: &BigInt) -> Vec<BigInt> {
+    let mut fees: Vec<BigInt> = blk
+        .transaction_traces
+        .iter()
+        .map(|trx| big_int(&trx.gas_price) - base_fee.clone())
+        .map(|fee| if fee < BigInt::zero() { BigInt::zero() } else { fee })
+        .collect();
+    fees.sort();
+    fees
+}
+
+/// Nearest rank percentile of sorted values, zero when there are none.
+pub fn percentile(sorted: &[BigInt], percent: usize) -> BigInt {
+    if sorted.is_empty() {
+        return BigInt::zero();
+    }
+
+    let rank = (percent * sorted.len() + 99) / 100;
+    sorted[rank.clamp(1, sorted.len()) - 1].clone()
+}
+
+/// Blob gas used by the block and the blob base fee its blob transactions paid, read from the receipts rather than
+/// derived from the excess blob gas as the update fraction changes with the forks.
+pub fn blob_gas(blk: &BlobBlock) -> BlobGas {
+    let header = blk.header.clone().unwrap_or_default();
+    let blob_gas_used = header.blob_gas_used.unwrap_or_default();
+    let blob_base_fee = blk
+        .transaction_traces
+        .iter()
+        .find_map(|trx| trx.receipt.as_ref()?.blob_gas_price.as_ref())
+        .map(|price| BigInt::from_unsigned_bytes_be(&price.bytes).to_string())
+        .unwrap_or_default();
+    BlobGas {
+        blob_gas_used,
+        blob_count: blob_gas_used / GAS_PER_BLOB,
+        excess_blob_gas: header.excess_blob_gas.unwrap_or_default(),
+        blob_base_fee,
+    }
+}
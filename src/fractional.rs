@@ -0,0 +1,31 @@
+use crate::eth_utils;
+use crate::logging;
+use crate::params::Params;
+
+/// A fractionalizer vault (NFTX vault, Tessera vault, Sudoswap pool, ...) holding tracked tokens.
+pub struct Vault {
+    pub address: Vec<u8>,
+    pub token: Vec<u8>,
+}
+
+/// Reads the `vaults` param, a comma separated list of `<vault>[:<vault_token>]` entries. When no
+/// token is given the vault contract is itself the fractions token, like NFTX vaults are.
+pub fn vaults_from_params(params: &Params) -> Vec<Vault> {
+    let mut vaults = vec![];
+    for entry in params.list("vaults") {
+        let (vault, token) = match entry.split_once(':') {
+            Some((vault, token)) => (vault, token),
+            None => (entry, entry),
+        };
+
+        match (eth_utils::parse_address(vault), eth_utils::parse_address(token)) {
+            (Ok(address), Ok(token)) => vaults.push(Vault { address, token }),
+            _ => logging::warning!("params", "skipping invalid vault entry {}", entry),
+        }
+    }
+    vaults
+}
+
+pub fn find<'a>(vaults: &'a [Vault], address: &[u8]) -> Option<&'a Vault> {
+    vaults.iter().find(|vault| vault.address == address)
+}
@@ -0,0 +1,109 @@
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::erc20_votes::events as votes;
+use crate::abi::governor::events as governor;
+use crate::eth_utils;
+use crate::keys;
+use crate::pb::tokens::proposal_event::Kind;
+use crate::pb::tokens::{Delegation, GovernanceEvents, ProposalEvent, VotingCheckpoint};
+
+/// Voting power of `delegate` for `token`, as of its last checkpoint.
+pub fn votes_key(token: &[u8], delegate: &[u8]) -> String {
+    keys::key(keys::VOTES).hex(token).hex(delegate).into()
+}
+
+/// Votes cast on proposal `proposal_id` of `governor` with `support`: 0 against, 1 for and 2 abstain.
+pub fn tally_key(governor: &[u8], proposal_id: &str, support: u32) -> String {
+    keys::key(keys::PROPOSAL).hex(governor).text(proposal_id).number(support).into()
+}
+
+/// ERC20Votes delegation changes and voting power checkpoints of the `governance` tokens in the transaction.
+pub fn decode(
+    trx: &eth::TransactionTrace,
+    block: u64,
+    governance: impl Fn(&[u8]) -> bool,
+    events: &mut GovernanceEvents,
+) {
+    let logs = trx.receipt.as_ref().map(|receipt| receipt.logs.as_slice()).unwrap_or_default();
+    for log in logs.iter().filter(|log| governance(&log.address)) {
+        if let Some(changed) = votes::DelegateChanged::match_and_decode(log) {
+            events.delegations.push(Delegation {
+                token: log.address.clone(),
+                delegator: changed.delegator,
+                from_delegate: changed.from_delegate,
+                to_delegate: changed.to_delegate,
+                trx_hash: trx.hash.clone(),
+                ordinal: log.ordinal,
+                id: eth_utils::record_id(&trx.hash, log.block_index),
+            });
+        } else if let Some(changed) = votes::DelegateVotesChanged::match_and_decode(log) {
+            events.checkpoints.push(VotingCheckpoint {
+                token: log.address.clone(),
+                delegate: changed.delegate,
+                previous_votes: changed.previous_balance.to_string(),
+                votes: changed.new_balance.to_string(),
+                block,
+                trx_hash: trx.hash.clone(),
+                ordinal: log.ordinal,
+                id: eth_utils::record_id(&trx.hash, log.block_index),
+            });
+        }
+    }
+}
+
+/// Lifecycle and vote events of the proposals of the `governors` in the transaction. Governor Bravo and OpenZeppelin
+/// Governor log them with the same signatures.
+pub fn proposal_events(trx: &eth::TransactionTrace, governors: &[Vec<u8>]) -> Vec<ProposalEvent> {
+    let logs = trx.receipt.as_ref().map(|receipt| receipt.logs.as_slice()).unwrap_or_default();
+    let mut events = vec![];
+    for log in logs.iter().filter(|log| governors.contains(&log.address)) {
+        let event = |kind: Kind, proposal_id: String| ProposalEvent {
+            kind: kind as i32,
+            governor: log.address.clone(),
+            proposal_id,
+            trx_hash: trx.hash.clone(),
+            ordinal: log.ordinal,
+            id: eth_utils::record_id(&trx.hash, log.block_index),
+            ..Default::default()
+        };
+
+        if let Some(created) = governor::ProposalCreated::match_and_decode(log) {
+            events.push(ProposalEvent {
+                proposer: created.proposer,
+                targets: created.targets,
+                description: created.description,
+                start_block: eth_utils::to_u64(&created.vote_start).unwrap_or(u64::MAX),
+                end_block: eth_utils::to_u64(&created.vote_end).unwrap_or(u64::MAX),
+                ..event(Kind::Created, created.proposal_id.to_string())
+            });
+        } else if let Some(queued) = governor::ProposalQueued::match_and_decode(log) {
+            events.push(ProposalEvent {
+                eta: eth_utils::to_u64(&queued.eta).unwrap_or(u64::MAX),
+                ..event(Kind::Queued, queued.proposal_id.to_string())
+            });
+        } else if let Some(executed) = governor::ProposalExecuted::match_and_decode(log) {
+            events.push(event(Kind::Executed, executed.proposal_id.to_string()));
+        } else if let Some(canceled) = governor::ProposalCanceled::match_and_decode(log) {
+            events.push(event(Kind::Canceled, canceled.proposal_id.to_string()));
+        } else if let Some(vote) = governor::VoteCast::match_and_decode(log) {
+            events.push(ProposalEvent {
+                voter: vote.voter,
+                support: eth_utils::to_u64(&vote.support).unwrap_or_default() as u32,
+                weight: vote.weight.to_string(),
+                reason: vote.reason,
+                ..event(Kind::VoteCast, vote.proposal_id.to_string())
+            });
+        } else if let Some(vote) = governor::VoteCastWithParams::match_and_decode(log) {
+            events.push(ProposalEvent {
+                voter: vote.voter,
+                support: eth_utils::to_u64(&vote.support).unwrap_or_default() as u32,
+                weight: vote.weight.to_string(),
+                reason: vote.reason,
+                params: vote.params,
+                ..event(Kind::VoteCast, vote.proposal_id.to_string())
+            });
+        }
+    }
+    events
+}
@@ -0,0 +1,97 @@
+use std::collections::BTreeSet;
+
+use substreams::scalar::BigInt;
+use substreams_ethereum::NULL_ADDRESS;
+
+use crate::erc20;
+use crate::keys;
+use crate::params::Params;
+use crate::pb::erc721::{self, DormantAddress};
+use crate::pb::tokens;
+
+/// Last block `address` sent a tracked token at.
+pub fn last_active_key(address: &[u8]) -> String {
+    keys::key(keys::HEARTBEAT).hex(address).into()
+}
+
+/// Addresses that sent a tracked token during `period`, a period lasting `every` blocks. Zero padded like a block
+/// so that deleting a period never deletes the periods it prefixes.
+pub fn period_key(period: u64) -> String {
+    keys::key(keys::ACTIVE).block(period).into()
+}
+
+/// Addresses sending tokens of the tracked collection or ERC20 tokens in the block. Receiving tokens doesn't count as
+/// activity, anyone can airdrop to a dormant address.
+pub fn senders(transfers: &erc721::Transfers, erc20_transfers: &tokens::Transfers) -> BTreeSet<Vec<u8>> {
+    let erc721_senders = transfers.transfers.iter().map(|transfer| transfer.from.clone());
+    let erc20_senders = erc20::balance_changes(erc20_transfers)
+        .into_iter()
+        .filter(|change| change.amount < BigInt::zero())
+        .map(|change| change.holder);
+    erc721_senders.chain(erc20_senders).filter(|sender| sender != &NULL_ADDRESS).collect()
+}
+
+/// Senders of the block holding at least `min_balance` tokens of the tracked collection, by the start or the end of
+/// the block according to `balance`. They are the only addresses the dormancy reports can list.
+pub fn holder_senders(
+    transfers: &erc721::Transfers,
+    erc20_transfers: &tokens::Transfers,
+    min_balance: i64,
+    balance: impl Fn(&[u8]) -> i64,
+) -> BTreeSet<Vec<u8>> {
+    let mut senders = senders(transfers, erc20_transfers);
+    senders.retain(|sender| balance(sender) >= min_balance);
+    senders
+}
+
+/// Dormancy settings read from the `dormancy`, `every` and `min_balance` params.
+pub struct Dormancy {
+    /// Blocks without activity after which an address is dormant, about a year by default.
+    pub blocks: u64,
+    /// Blocks between dormancy reports, about a day by default.
+    pub every: u64,
+    /// Tokens of the tracked collection a dormant address holds at least to be reported.
+    pub min_balance: i64,
+}
+
+impl Dormancy {
+    pub fn from_params(params: &Params) -> Dormancy {
+        let every = params.get("every").and_then(|value| value.parse().ok()).unwrap_or(7200);
+        Dormancy {
+            blocks: params.get("dormancy").and_then(|value| value.parse().ok()).unwrap_or(2_628_000),
+            every: if every == 0 { 7200 } else { every },
+            min_balance: params.get("min_balance").and_then(|value| value.parse().ok()).unwrap_or(50),
+        }
+    }
+
+    pub fn period(&self, block: u64) -> u64 {
+        block / self.every
+    }
+
+    /// Period whose last active addresses went dormant since the previous report, when `block` is a report block.
+    pub fn report_period(&self, block: u64) -> Option<u64> {
+        if block % self.every != 0 {
+            return None;
+        }
+        self.period(block.checked_sub(self.blocks)?).checked_sub(1)
+    }
+
+    /// Period no report reads anymore once `block` is reached, the one before `report_period`.
+    pub fn expired_period(&self, block: u64) -> Option<u64> {
+        self.report_period(block)?.checked_sub(1)
+    }
+
+    /// Dormant address, if `address` was last active more than `blocks` blocks before `block` and holds enough tokens.
+    pub fn dormant(&self, address: &[u8], block: u64, last_active: u64, balance: i64) -> Option<DormantAddress> {
+        let inactive_blocks = block.checked_sub(last_active)?;
+        if inactive_blocks <= self.blocks || balance < self.min_balance {
+            return None;
+        }
+        Some(DormantAddress {
+            address: address.to_vec(),
+            last_active_block: last_active,
+            inactive_blocks,
+            balance,
+        })
+    }
+}
@@ -0,0 +1,48 @@
+use substreams::scalar::BigInt;
+
+use crate::keys;
+
+/// Buckets per doubling of the price, a bucket spans about 19% of its lower bound.
+pub const BUCKETS_PER_OCTAVE: u32 = 4;
+/// Prices below 2^40 wei (about 0.000001 ETH) share the first bucket.
+const MIN_OCTAVE: u32 = 40;
+/// Prices above 2^88 wei share the last bucket.
+const OCTAVES: u32 = 48;
+pub const BUCKETS: u32 = OCTAVES * BUCKETS_PER_OCTAVE;
+
+pub fn bucket_key(window: u64, bucket: u32) -> String {
+    keys::key(keys::HISTOGRAM).number(window).number(bucket).into()
+}
+
+/// Bucket of a price in wei, None when it is not positive.
+pub fn bucket(price: &BigInt) -> Option<u32> {
+    let wei: f64 = price.to_string().parse().ok()?;
+    if wei <= 0.0 {
+        return None;
+    }
+    let index = ((wei.log2() - MIN_OCTAVE as f64) * BUCKETS_PER_OCTAVE as f64).floor();
+    Some(index.clamp(0.0, (BUCKETS - 1) as f64) as u32)
+}
+
+/// Price a bucket stands for, the geometric middle of its bounds, in wei.
+pub fn value(bucket: u32) -> f64 {
+    2f64.powf(MIN_OCTAVE as f64 + (bucket as f64 + 0.5) / BUCKETS_PER_OCTAVE as f64)
+}
+
+/// Estimated `q` quantile of the prices counted in `counts`, by bucket in ascending order. None without any price.
+pub fn quantile(counts: &[(u32, i64)], q: f64) -> Option<f64> {
+    let total: i64 = counts.iter().map(|(_, count)| count).sum();
+    if total <= 0 {
+        return None;
+    }
+
+    let rank = ((q * total as f64).ceil() as i64).max(1);
+    let mut seen = 0;
+    for (bucket, count) in counts {
+        seen += count;
+        if seen >= rank {
+            return Some(value(*bucket));
+        }
+    }
+    counts.last().map(|(bucket, _)| value(*bucket))
+}
@@ -0,0 +1,28 @@
+use substreams::Hex;
+
+use crate::crypto;
+use crate::eth_utils;
+use crate::pb::{erc721, tokens};
+
+pub fn transfer(transfer: &erc721::Transfer) -> String {
+    format!(
+        r#"{{"type":"transfer","id":"{}","contract":"{}","from":"{}","to":"{}","token_id":{},"trx_hash":"0x{}","ordinal":{}}}"#,
+        transfer.id,
+        crypto::checksum_address(&transfer.contract),
+        crypto::checksum_address(&transfer.from),
+        crypto::checksum_address(&transfer.to),
+        transfer.token_id,
+        Hex(&transfer.trx_hash),
+        transfer.ordinal,
+    )
+}
+
+pub fn token(token: &tokens::Token) -> String {
+    format!(
+        r#"{{"type":"token","address":"{}","name":{},"symbol":{},"decimals":{}}}"#,
+        crypto::checksum_address(&token.address),
+        eth_utils::json_string(&token.name),
+        eth_utils::json_string(&token.symbol),
+        token.decimals,
+    )
+}
@@ -0,0 +1,358 @@
+use std::fmt;
+
+use substreams::Hex;
+
+/// Separator between the prefix of a store key and its segments, escaped inside text segments.
+pub const SEPARATOR: char = ':';
+
+/// Family of store keys, named by the first segment of its keys. Families whose encoding changed are
+/// versioned, their prefix carries the version: `tag.v2`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Prefix {
+    pub name: &'static str,
+    pub version: u32,
+}
+
+impl Prefix {
+    const fn new(name: &'static str, version: u32) -> Prefix {
+        Prefix { name, version }
+    }
+
+    pub fn encoded(&self) -> String {
+        if self.version <= 1 {
+            return self.name.to_string();
+        }
+        format!("{}.v{}", self.name, self.version)
+    }
+}
+
+pub const ACTIVE: Prefix = Prefix::new("active", 1);
+pub const ADDED: Prefix = Prefix::new("added", 1);
+pub const AIRDROPPED: Prefix = Prefix::new("airdropped", 1);
+pub const ALLOWANCES: Prefix = Prefix::new("allowances", 1);
+pub const BALANCES: Prefix = Prefix::new("balances", 1);
+pub const BOUGHT: Prefix = Prefix::new("bought", 1);
+pub const BUYER: Prefix = Prefix::new("buyer", 1);
+pub const BUYS: Prefix = Prefix::new("buys", 1);
+pub const CLOSING: Prefix = Prefix::new("closing", 1);
+pub const CLUSTER: Prefix = Prefix::new("cluster", 1);
+pub const COLLECTION: Prefix = Prefix::new("collection", 1);
+pub const CONTRACT: Prefix = Prefix::new("contract", 1);
+pub const COST_BASIS: Prefix = Prefix::new("cost_basis", 1);
+pub const CUSTODY: Prefix = Prefix::new("custody", 1);
+pub const DEPLOYER: Prefix = Prefix::new("deployer", 1);
+pub const ERC1155: Prefix = Prefix::new("erc1155", 1);
+pub const ERC20: Prefix = Prefix::new("erc20", 1);
+pub const EXCLUDED: Prefix = Prefix::new("excluded", 1);
+pub const FAMILY: Prefix = Prefix::new("family", 1);
+pub const FIRST: Prefix = Prefix::new("first", 1);
+pub const FLOOR: Prefix = Prefix::new("floor", 1);
+pub const HEARTBEAT: Prefix = Prefix::new("heartbeat", 1);
+pub const HISTOGRAM: Prefix = Prefix::new("histogram", 1);
+pub const HOLDER: Prefix = Prefix::new("holder", 1);
+pub const LAUNCH: Prefix = Prefix::new("launch", 1);
+pub const MARKETPLACE: Prefix = Prefix::new("marketplace", 1);
+pub const MIXER: Prefix = Prefix::new("mixer", 1);
+pub const NETFLOW: Prefix = Prefix::new("netflow", 2);
+/// Token of a collection, `nft:<token_id>:<contract>`.
+pub const NFT: Prefix = Prefix::new("nft", 1);
+pub const OWNER: Prefix = Prefix::new("owner", 1);
+pub const PENDING: Prefix = Prefix::new("pending", 1);
+pub const POOL: Prefix = Prefix::new("pool", 1);
+pub const POSITION: Prefix = Prefix::new("position", 1);
+pub const PRESALE: Prefix = Prefix::new("presale", 1);
+pub const PROPOSAL: Prefix = Prefix::new("proposal", 1);
+pub const REALIZED: Prefix = Prefix::new("realized", 1);
+pub const RECEIVED: Prefix = Prefix::new("received", 1);
+pub const RECENT: Prefix = Prefix::new("recent", 2);
+pub const RENOUNCED: Prefix = Prefix::new("renounced", 1);
+pub const REMOVED: Prefix = Prefix::new("removed", 1);
+pub const REVENUE: Prefix = Prefix::new("revenue", 1);
+pub const REWARD: Prefix = Prefix::new("reward", 1);
+pub const SEAPORT_COUNTER: Prefix = Prefix::new("seaport_counter", 1);
+pub const SELLER: Prefix = Prefix::new("seller", 1);
+pub const SHARES: Prefix = Prefix::new("shares", 1);
+/// Members of a wallet cluster, keyed by the escaped cluster id since version 2.
+pub const SIZE: Prefix = Prefix::new("size", 2);
+pub const STREAM: Prefix = Prefix::new("stream", 1);
+pub const SUPPLY: Prefix = Prefix::new("supply", 1);
+pub const SYMBOL: Prefix = Prefix::new("symbol", 1);
+/// Tags of a wallet, the tag name is escaped since version 2.
+pub const TAG: Prefix = Prefix::new("tag", 2);
+pub const TEMPLATE: Prefix = Prefix::new("template", 1);
+/// Token contract, `token:<address>`.
+pub const TOKEN: Prefix = Prefix::new("token", 1);
+pub const TOTAL: Prefix = Prefix::new("total", 1);
+pub const TRADER: Prefix = Prefix::new("trader", 1);
+pub const VAULT: Prefix = Prefix::new("vault", 1);
+pub const VESTING: Prefix = Prefix::new("vesting", 1);
+pub const VOTES: Prefix = Prefix::new("votes", 1);
+
+/// Balance family kept under both orderings of its holder and contract segments, so that sinks scan the balances
+/// of a holder (`erc20:<holder>:`) as well as the holders of a contract (`erc20_holders:<contract>:`) by prefix.
+#[derive(Clone, Copy, Debug)]
+pub struct Balances {
+    pub by_holder: Prefix,
+    pub by_contract: Prefix,
+}
+
+pub const ERC20_BALANCES: Balances = Balances {
+    by_holder: ERC20,
+    by_contract: Prefix::new("erc20_holders", 1),
+};
+pub const ERC1155_BALANCES: Balances = Balances {
+    by_holder: ERC1155,
+    by_contract: Prefix::new("erc1155_holders", 1),
+};
+
+impl Balances {
+    /// Keys of the balance of `holder` in `contract`, holder first then contract first. Segments added to both,
+    /// as an ERC1155 token id, follow the holder and the contract.
+    pub fn keys(&self, holder: &[u8], contract: &[u8]) -> [Key; 2] {
+        [
+            key(self.by_holder).hex(holder).hex(contract),
+            key(self.by_contract).hex(contract).hex(holder),
+        ]
+    }
+}
+
+/// Store key being built, segment by segment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Key(String);
+
+pub fn key(prefix: Prefix) -> Key {
+    Key(prefix.encoded())
+}
+
+/// Start of every key of `prefix`, as taken by `delete_prefix`.
+pub fn prefix(prefix: Prefix) -> String {
+    format!("{}{}", prefix.encoded(), SEPARATOR)
+}
+
+impl Key {
+    /// Bytes, an address or a hash, as lowercase hex.
+    pub fn hex(self, bytes: &[u8]) -> Key {
+        self.push(&Hex(bytes).to_string())
+    }
+
+    /// Decimal number, a block or a token id.
+    pub fn number(self, number: impl fmt::Display) -> Key {
+        self.push(&number.to_string())
+    }
+
+    /// Block zero padded to 20 digits, so the keys of a block never prefix the keys of another one.
+    pub fn block(self, block: u64) -> Key {
+        self.push(&format!("{:020}", block))
+    }
+
+    /// Free form text, with the separator and the escape character escaped.
+    pub fn text(self, text: &str) -> Key {
+        self.push(&escape(text))
+    }
+
+    /// Segments of another key, as nested keys of a position: `owner:position:<id>`.
+    pub fn key(self, inner: Key) -> Key {
+        self.push(&inner.0)
+    }
+
+    fn push(mut self, segment: &str) -> Key {
+        self.0.push(SEPARATOR);
+        self.0.push_str(segment);
+        self
+    }
+}
+
+impl AsRef<str> for Key {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Key> for String {
+    fn from(key: Key) -> String {
+        key.0
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '%' => escaped.push_str("%25"),
+            SEPARATOR => escaped.push_str("%3A"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape(segment: &str) -> Option<String> {
+    let mut text = String::with_capacity(segment.len());
+    let mut rest = segment;
+    while let Some(at) = rest.find('%') {
+        text.push_str(&rest[..at]);
+        match rest.get(at + 1..at + 3)? {
+            "25" => text.push('%'),
+            "3A" => text.push(SEPARATOR),
+            _ => return None,
+        }
+        rest = &rest[at + 3..];
+    }
+    text.push_str(rest);
+    Some(text)
+}
+
+/// Store key split back into its prefix and segments.
+pub struct Parsed<'a> {
+    pub name: &'a str,
+    pub version: u32,
+    segments: Vec<&'a str>,
+}
+
+pub fn parse(key: &str) -> Option<Parsed> {
+    let mut segments = key.split(SEPARATOR);
+    let prefix = segments.next()?;
+    let (name, version) = match prefix.rsplit_once(".v") {
+        Some((name, version)) => (name, version.parse().ok()?),
+        None => (prefix, 1),
+    };
+    Some(Parsed {
+        name,
+        version,
+        segments: segments.collect(),
+    })
+}
+
+impl<'a> Parsed<'a> {
+    /// Whether the key belongs to `prefix`, at its current version.
+    pub fn is(&self, prefix: Prefix) -> bool {
+        self.name == prefix.name && self.version == prefix.version
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn hex(&self, index: usize) -> Option<Vec<u8>> {
+        hex::decode(self.segments.get(index)?).ok()
+    }
+
+    pub fn text(&self, index: usize) -> Option<String> {
+        unescape(self.segments.get(index)?)
+    }
+
+    /// Segment as written, hex or a number.
+    pub fn raw(&self, index: usize) -> Option<&'a str> {
+        self.segments.get(index).copied()
+    }
+}
+
+/// Segment of a legacy key layout.
+#[derive(Clone, Copy, Debug)]
+pub enum Segment {
+    Hex,
+    /// Single text segment, written unescaped before.
+    Text,
+    /// Decimal number, zero padded since.
+    Block,
+    /// Recent activity subject: `holder:<address>` as it is, `token:<token_id>` now keyed with the collection under
+    /// `nft:<token_id>:<contract>`.
+    Subject,
+    /// Free form text running to the end of the key, separators included.
+    Rest,
+}
+
+/// Layout of the keys a family had before its current version.
+pub struct Migration {
+    pub legacy: &'static str,
+    pub prefix: Prefix,
+    pub segments: &'static [Segment],
+    /// Positions of the legacy segments, in the order the current key writes them.
+    pub order: &'static [usize],
+}
+
+/// Families that changed encoding, by their legacy prefix. Keys of the other families are written as before.
+// only sinks that persisted store keys migrate them, no module reads legacy keys
+#[cfg_attr(not(test), allow(dead_code))]
+pub const MIGRATIONS: [Migration; 4] = [
+    Migration {
+        legacy: "netflow",
+        prefix: NETFLOW,
+        segments: &[Segment::Hex, Segment::Text, Segment::Text, Segment::Block],
+        order: &[2, 3, 0, 1],
+    },
+    Migration {
+        legacy: "recent",
+        prefix: RECENT,
+        segments: &[Segment::Subject, Segment::Block],
+        order: &[0, 1],
+    },
+    Migration {
+        legacy: "size",
+        prefix: SIZE,
+        segments: &[Segment::Rest],
+        order: &[0],
+    },
+    Migration {
+        legacy: "tag",
+        prefix: TAG,
+        segments: &[Segment::Hex, Segment::Rest],
+        order: &[0, 1],
+    },
+];
+
+/// Key written by an earlier version, as its family encodes it now. `collection` is the contract the legacy
+/// `token:<token_id>` subjects belong to. Keys of families that did not change are returned as they are, None when
+/// a legacy key does not match its layout.
+#[cfg_attr(not(test), allow(dead_code))]
+pub fn migrate(legacy: &str, collection: &[u8]) -> Option<String> {
+    let (name, rest) = legacy.split_once(SEPARATOR)?;
+    let migration = match MIGRATIONS.iter().find(|migration| migration.legacy == name) {
+        Some(migration) => migration,
+        None => return Some(legacy.to_string()),
+    };
+
+    let mut segments = Vec::with_capacity(migration.segments.len());
+    let mut rest = Some(rest);
+    for segment in migration.segments {
+        let remaining = rest?;
+        let (value, next) = match segment {
+            Segment::Rest => (remaining, None),
+            Segment::Subject => {
+                let mut parts = remaining.splitn(3, SEPARATOR);
+                let subject = (parts.next()?, parts.next()?);
+                (&remaining[..subject.0.len() + 1 + subject.1.len()], parts.next())
+            }
+            _ => match remaining.split_once(SEPARATOR) {
+                Some((value, next)) => (value, Some(next)),
+                None => (remaining, None),
+            },
+        };
+        rest = next;
+        segments.push(match segment {
+            Segment::Hex => Hex(hex::decode(value).ok()?).to_string(),
+            Segment::Text | Segment::Rest => escape(value),
+            Segment::Block => format!("{:020}", value.parse::<u64>().ok()?),
+            Segment::Subject => match value.split_once(SEPARATOR)? {
+                ("token", token_id) => key(NFT).number(token_id.parse::<u64>().ok()?).hex(collection).into(),
+                ("holder", address) => key(HOLDER).hex(&hex::decode(address).ok()?).into(),
+                _ => return None,
+            },
+        });
+    }
+    if rest.is_some() {
+        return None;
+    }
+
+    let mut migrated = key(migration.prefix);
+    for &position in migration.order {
+        migrated = migrated.push(segments.get(position)?);
+    }
+    Some(migrated.into())
+}
@@ -0,0 +1,107 @@
+use substreams::scalar::BigInt;
+use substreams::store::{StoreGet, StoreGetInt64, StoreGetRaw};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::{Event, NULL_ADDRESS};
+
+use crate::abi;
+use crate::dex;
+use crate::keys;
+use crate::params::Params;
+use crate::pb::tokens::LaunchQuality;
+
+pub fn launch_key(token: &[u8]) -> String {
+    keys::key(keys::LAUNCH).hex(token).into()
+}
+
+pub fn supply_key(token: &[u8]) -> String {
+    keys::key(keys::SUPPLY).hex(token).into()
+}
+
+pub fn buys_key(token: &[u8]) -> String {
+    keys::key(keys::BUYS).hex(token).into()
+}
+
+/// Tokens whose launch window closes on `block`.
+pub fn closing_key(block: u64) -> String {
+    keys::key(keys::CLOSING).number(block).into()
+}
+
+/// Blocks after the first pool creation whose buys are counted, read from the `window` param.
+pub fn window(params: &Params) -> u64 {
+    params.get("window").and_then(|value| value.parse().ok()).unwrap_or(10)
+}
+
+pub struct Buy {
+    pub ordinal: u64,
+    pub token: Vec<u8>,
+    pub buyer: Vec<u8>,
+    pub amount: BigInt,
+    pub launch_block: u64,
+}
+
+/// Tokens sent out of one of their pools within `window` blocks of their launch.
+pub fn buys(blk: &eth::Block, window: u64, pools: &StoreGetRaw, launches: &StoreGetInt64) -> Vec<Buy> {
+    let mut buys = vec![];
+    for log in blk.logs() {
+        let transfer = match abi::erc20::events::Transfer::match_and_decode(log.log) {
+            Some(transfer) => transfer,
+            None => continue,
+        };
+        if transfer.to == NULL_ADDRESS {
+            continue;
+        }
+
+        let launch_block = match launches.get_last(launch_key(log.address())) {
+            Some(launch_block) => launch_block as u64,
+            None => continue,
+        };
+        if blk.number > launch_block + window {
+            continue;
+        }
+
+        let traded = match pools.get_last(dex::pool_key(&transfer.from)).and_then(|pool| dex::decode_pool(&pool)) {
+            Some((token0, token1)) => token0 == log.address() || token1 == log.address(),
+            None => false,
+        };
+        if !traded {
+            continue;
+        }
+
+        buys.push(Buy {
+            ordinal: log.ordinal(),
+            token: log.address().to_vec(),
+            buyer: transfer.to,
+            amount: transfer.value,
+            launch_block,
+        });
+    }
+    buys
+}
+
+/// Scores a launch from the amounts bought by each buyer within the window: the share of the supply
+/// bought by the `snipers` largest buyers, flagged when above `threshold` percent.
+pub fn quality(
+    launch_block: u64,
+    window: u64,
+    mut amounts: Vec<BigInt>,
+    supply: &BigInt,
+    snipers: usize,
+    threshold: f64,
+) -> LaunchQuality {
+    let buyers = amounts.len() as u64;
+    amounts.sort_by(|a, b| b.cmp(a));
+    let top = amounts.into_iter().take(snipers).fold(BigInt::zero(), |total, amount| total + amount);
+
+    let supply: f64 = supply.to_string().parse().unwrap_or(0.0);
+    let top: f64 = top.to_string().parse().unwrap_or(0.0);
+    let share = if supply > 0.0 { (top / supply * 100.0).min(100.0) } else { 0.0 };
+
+    LaunchQuality {
+        launch_block,
+        window,
+        buyers,
+        top_buyers_share: share,
+        sniped: share > threshold,
+        score: 100.0 - share,
+    }
+}
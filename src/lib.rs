@@ -1,214 +1,4047 @@
 mod abi;
 mod pb;
+mod activity;
+mod assets;
+mod audit;
+mod bytecode;
+mod calls;
+mod clickhouse;
+mod clusters;
+mod collections;
+mod columnar;
+mod crypto;
+mod deployers;
+mod dex;
+mod documents;
+mod erc20;
 mod eth_utils;
+mod exchanges;
+mod fees;
+mod fractional;
+mod governance;
+mod heartbeat;
+mod histogram;
+#[cfg(feature = "jsonl")]
+mod jsonl;
+mod keys;
+mod launches;
+mod logging;
+mod messari;
+mod metadata;
+mod metrics;
+mod mints;
+mod params;
+mod payouts;
+mod perf;
+mod portfolio;
+mod presales;
+mod projection;
+mod rebasing;
+mod registry;
+#[cfg(any(test, feature = "reorg"))]
+mod reorg;
+mod revenue;
 mod rpc_utils;
+mod seaport;
+mod selectors;
+mod snapshot;
+mod spam;
+mod staking;
+mod storage;
+mod subgraph;
+mod sudoswap;
+mod supply;
+mod tags;
+mod taxes;
+mod tokenlist;
+mod traces;
+mod transfers;
+mod vesting;
+mod webhooks;
+mod whale;
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeMap;
 
 use hex_literal::hex;
 use pb::erc721;
+use substreams::pb::substreams::Clock;
 use substreams::prelude::*;
-use substreams::{log, store::StoreAddInt64, Hex, proto, store};
-use substreams_ethereum::{pb::eth::v2 as eth, NULL_ADDRESS};
-use crate::rpc_utils::create_rpc_calls;
+use substreams::store::{DeltaProto, StoreGetArray};
+use substreams::{store::StoreAddInt64, Hex, proto, store};
+use substreams_ethereum::{pb::eth::v2 as eth, Event, NULL_ADDRESS};
+use crate::params::Params;
+use crate::rpc_utils::{create_rpc_calls, EthCall, RpcCounters, Substreams};
 
 // Bored Ape Club Contract
 const TRACKED_CONTRACT: [u8; 20] = hex!("bc4ca0eda7647a8ab7c2061c2e118a18a936f13d");
 
+// `TRX_TYPE_BLOB`, not part of the block model this package is built against
+const BLOB_TRX_TYPE: i32 = 3;
+
 substreams_ethereum::init!();
 
+/// Extracts the index keys of the block, `evt_addr:` for logs of the contract and `evt_sig:` for logs of the events
+/// whose signatures are listed, `;` separated, in the `events` param (Transfer by default)
+#[substreams::handlers::map]
+fn index_tracked_logs(params: Params, blk: eth::Block) -> Result<pb::index::Keys, substreams::errors::Error> {
+    let topics: Vec<[u8; 32]> = match params.get("events") {
+        Some(events) => events
+            .split(';')
+            .map(|event| event.trim())
+            .filter(|event| !event.is_empty())
+            .map(crypto::event_topic)
+            .collect(),
+        None => vec![eth_utils::TRANSFER_TOPIC],
+    };
+
+    let mut keys = vec![];
+    for log in blk.logs() {
+        if log.address() == TRACKED_CONTRACT {
+            keys.push(format!("evt_addr:{}", eth_utils::format_address(log.address())));
+        }
+        if let Some(topic) = log.topics().first() {
+            if topics.iter().any(|tracked| tracked == topic.as_slice()) {
+                keys.push(format!("evt_sig:0x{}", Hex(topic)));
+            }
+        }
+    }
+    keys.sort();
+    keys.dedup();
+
+    Ok(pb::index::Keys { keys })
+}
+
 /// Extracts transfers events from the contract
 #[substreams::handlers::map]
 fn map_transfers(blk: eth::Block) -> Result<erc721::Transfers, substreams::errors::Error> {
     Ok(erc721::Transfers {
-        transfers: blk
-            .events::<abi::erc721::events::Transfer>(&[&TRACKED_CONTRACT])
-            .map(|(transfer, log)| {
-                substreams::log::info!("NFT Transfer seen");
-
-                erc721::Transfer {
-                    trx_hash: log.receipt.transaction.hash.clone(),
-                    from: transfer.from,
-                    to: transfer.to,
-                    token_id: transfer.token_id.to_u64(),
-                    ordinal: log.block_index() as u64,
-                }
+        transfers: block_transfers(&blk),
+    })
+}
+
+fn block_transfers(blk: &eth::Block) -> Vec<erc721::Transfer> {
+    let timestamp = eth_utils::block_timestamp(blk);
+    eth_utils::transactions_matching(blk, &[&TRACKED_CONTRACT, &eth_utils::TRANSFER_TOPIC])
+        .flat_map(|trx| trx.receipt().logs())
+        .filter(|log| log.address() == TRACKED_CONTRACT)
+        .filter_map(|log| transfers::decode(log.log).map(|transfer| (transfer, log)))
+        .filter_map(|(transfer, log)| {
+            logging::debug!("nft", "NFT Transfer seen");
+
+            Some(erc721::Transfer {
+                id: eth_utils::record_id(&log.receipt.transaction.hash, log.block_index()),
+                trx_hash: log.receipt.transaction.hash.clone(),
+                from: transfer.from,
+                to: transfer.to,
+                token_id: token_id(&transfer.token_id, &log.receipt.transaction.hash)?,
+                ordinal: log.block_index() as u64,
+                contract: TRACKED_CONTRACT.to_vec(),
+                timestamp: timestamp.clone(),
+                data: transfers::safe_transfer_data(log.receipt.transaction, log.log),
+            })
+        })
+        .collect()
+}
+
+/// Extracts the safe transfers of the contract undone because the receiver hook rejected the token
+#[substreams::handlers::map]
+fn map_failed_transfers(blk: eth::Block) -> Result<erc721::FailedTransferAttempts, substreams::errors::Error> {
+    // failed transactions are not in blk.transactions()
+    let attempts = blk.transaction_traces.iter().flat_map(failed_transfer_attempts).collect();
+    Ok(erc721::FailedTransferAttempts { attempts })
+}
+
+fn failed_transfer_attempts(trx: &eth::TransactionTrace) -> Vec<erc721::FailedTransferAttempt> {
+    let mut attempts = vec![];
+    for failed in transfers::failed_hooks(trx, &TRACKED_CONTRACT) {
+        let token_id = match token_id(&failed.token_id, &trx.hash) {
+            Some(token_id) => token_id,
+            None => continue,
+        };
+        attempts.push(erc721::FailedTransferAttempt {
+            id: eth_utils::call_record_id(&trx.hash, failed.hook.index),
+            trx_hash: trx.hash.clone(),
+            trx_failed: trx.status != eth::TransactionTraceStatus::Succeeded as i32,
+            operator: failed.safe_transfer.caller.clone(),
+            from: failed.from,
+            to: failed.hook.address.clone(),
+            token_id,
+            reason: failed.reason,
+            ordinal: failed.hook.begin_ordinal,
+        });
+    }
+    attempts
+}
+
+/// Extracts the mints of the contract grouped by the call that paid for them, with their price and whether they were
+/// allowlist or public mints. Selectors of the `allowlist` param are counted as allowlist mints
+#[substreams::handlers::map]
+fn map_mint_purchases(params: Params, blk: eth::Block) -> Result<erc721::MintPurchases, substreams::errors::Error> {
+    let allowlist = params.list("allowlist");
+    let timestamp = eth_utils::block_timestamp(&blk);
+    let purchases = eth_utils::transactions_matching(&blk, &[&TRACKED_CONTRACT, &eth_utils::TRANSFER_TOPIC])
+        .flat_map(|trx| mints::purchases(trx, &TRACKED_CONTRACT, &allowlist))
+        .map(|purchase| erc721::MintPurchase {
+            timestamp: timestamp.clone(),
+            ..purchase
+        })
+        .collect();
+    Ok(erc721::MintPurchases { purchases })
+}
+
+/// Store the ETH revenue of every collection, from its mints (`revenue:<contract>:primary`) and from the royalties of
+/// its secondary sales (`revenue:<contract>:secondary`), of all time and by UTC day
+/// (`revenue:<contract>:<source>:<day>`)
+#[substreams::handlers::store]
+fn store_collection_revenue(purchases: erc721::MintPurchases, sales: erc721::Sales, s: StoreAddBigInt) {
+    for (ordinal, key, amount) in revenue::entries(&purchases, &sales) {
+        s.add(ordinal, key, amount);
+    }
+}
+
+/// Extracts the payouts of the revenue of the contract, releases of the PaymentSplitter `splitters` or of the contract
+/// itself and ETH withdrawn from the contract, along with the revenue of the collection so far
+#[substreams::handlers::map]
+fn map_creator_payouts(
+    params: Params,
+    blk: eth::Block,
+    revenue: StoreGetBigInt,
+) -> Result<erc721::CreatorPayouts, substreams::errors::Error> {
+    let splitters = params.addresses("splitters");
+    let timestamp = eth_utils::block_timestamp(&blk);
+    let mut payouts = vec![];
+    for trx in blk.transactions() {
+        for payout in payouts::decode(trx, &TRACKED_CONTRACT, &splitters) {
+            let revenue_to_date = [revenue::PRIMARY, revenue::SECONDARY]
+                .iter()
+                .map(|source| revenue::revenue_key(&TRACKED_CONTRACT, source, None))
+                .filter_map(|key| revenue.get_at(payout.ordinal, key))
+                .fold(substreams::scalar::BigInt::zero(), |total, amount| total + amount);
+            payouts.push(erc721::CreatorPayout {
+                collection: TRACKED_CONTRACT.to_vec(),
+                revenue_to_date: revenue_to_date.to_string(),
+                timestamp: timestamp.clone(),
+                ..payout
+            });
+        }
+    }
+    Ok(erc721::CreatorPayouts { payouts })
+}
+
+/// Store the contracts allowed by the registry configured through the `registry` and `getter` params
+#[substreams::handlers::store]
+fn store_tracked_contracts(params: Params, clock: Clock, s: StoreSetInt64) {
+    let registry = match registry::from_params(&params) {
+        Some(registry) => registry,
+        None => return,
+    };
+    if !registry.should_refresh(clock.number) {
+        return;
+    }
+
+    let mut rpc = RpcCounters::default();
+    if let Some(contracts) = registry.contracts(&Substreams, &mut rpc) {
+        logging::info!("registry", "registry {} lists {} contracts", Hex(&registry.address), contracts.len());
+        s.delete_prefix(0, &keys::prefix(keys::CONTRACT));
+        for contract in contracts {
+            s.set(1, contract_key(&contract), &(clock.number as i64));
+        }
+    }
+    logging::debug!("registry", "{} rpc calls, {} failed", rpc.calls, rpc.failures);
+}
+
+/// Extracts transfers events from the contracts currently listed by the registry
+#[substreams::handlers::map]
+fn map_registry_transfers(
+    blk: eth::Block,
+    contracts: StoreGetInt64,
+) -> Result<erc721::Transfers, substreams::errors::Error> {
+    let timestamp = eth_utils::block_timestamp(&blk);
+    let mut tracked: Vec<Vec<u8>> = vec![];
+    let mut untracked: Vec<Vec<u8>> = vec![];
+    let mut transfers = vec![];
+
+    let logs = eth_utils::transactions_matching(&blk, &[&eth_utils::TRANSFER_TOPIC]);
+    for log in logs.flat_map(|trx| trx.receipt().logs()) {
+        if log.topics().first().map(|topic| topic.as_slice()) != Some(&eth_utils::TRANSFER_TOPIC[..]) {
+            continue;
+        }
+
+        let address = log.address().to_vec();
+        if untracked.contains(&address) {
+            continue;
+        }
+        if !tracked.contains(&address) {
+            if contracts.get_last(contract_key(&address)).is_none() {
+                untracked.push(address);
+                continue;
+            }
+            tracked.push(address.clone());
+        }
+
+        if let Some(transfer) = transfers::decode(log.log) {
+            let token_id = match token_id(&transfer.token_id, &log.receipt.transaction.hash) {
+                Some(token_id) => token_id,
+                None => continue,
+            };
+            transfers.push(erc721::Transfer {
+                id: eth_utils::record_id(&log.receipt.transaction.hash, log.block_index()),
+                trx_hash: log.receipt.transaction.hash.clone(),
+                from: transfer.from,
+                to: transfer.to,
+                token_id,
+                ordinal: log.block_index() as u64,
+                contract: address,
+                timestamp: timestamp.clone(),
+                data: transfers::safe_transfer_data(log.receipt.transaction, log.log),
+            });
+        }
+    }
+
+    Ok(erc721::Transfers { transfers })
+}
+
+/// Extracts the range of blocks set by the `start_block` and `stop_block` params, read by every exporter so that a
+/// bounded export only has to set them once
+#[substreams::handlers::map]
+fn map_export_range(params: Params, clock: Clock) -> Result<erc721::ExportRange, substreams::errors::Error> {
+    let range = params.block_range();
+    Ok(erc721::ExportRange {
+        start_block: range.start,
+        stop_block: range.stop.unwrap_or_default(),
+        contains: range.contains(clock.number),
+        ends: range.ends_at(clock.number),
+    })
+}
+
+/// Store the number of rows exported by map_flat_transfers (`rows`) and of blocks with rows (`blocks`)
+#[substreams::handlers::store]
+fn store_export_stats(rows: erc721::FlatTransfers, s: StoreAddInt64) {
+    if !rows.rows.is_empty() {
+        s.add(0, "rows", rows.rows.len() as i64);
+        s.add(0, "blocks", 1);
+    }
+}
+
+/// Extracts at the last block of the export range a summary of the rows exported by map_flat_transfers, nothing at
+/// the other blocks
+#[substreams::handlers::map]
+fn map_export_summary(
+    range: erc721::ExportRange,
+    clock: Clock,
+    stats: StoreGetInt64,
+) -> Result<erc721::ExportSummary, substreams::errors::Error> {
+    if !range.ends {
+        return Ok(erc721::ExportSummary::default());
+    }
+
+    Ok(erc721::ExportSummary {
+        start_block: range.start_block,
+        stop_block: range.stop_block,
+        rows: stats.get_last("rows").unwrap_or(0) as u64,
+        blocks_with_rows: stats.get_last("blocks").unwrap_or(0) as u64,
+        completed_at: clock.timestamp,
+    })
+}
+
+/// Converts the transfers to parallel arrays, smaller than one message per transfer on busy blocks
+#[substreams::handlers::map]
+fn map_transfer_columns(transfers: erc721::Transfers) -> Result<erc721::TransferColumns, substreams::errors::Error> {
+    Ok(columnar::transfers(transfers))
+}
+
+/// Flattens the transfers to rows of scalar columns, ready for substreams-sink-files to land as Parquet or CSV. Blocks
+/// outside of the export range get no rows
+#[substreams::handlers::map]
+fn map_flat_transfers(
+    range: erc721::ExportRange,
+    clock: Clock,
+    transfers: erc721::Transfers,
+) -> Result<erc721::FlatTransfers, substreams::errors::Error> {
+    if !range.contains {
+        return Ok(erc721::FlatTransfers::default());
+    }
+
+    let block_timestamp = clock.timestamp.map(|timestamp| timestamp.seconds).unwrap_or_default();
+    Ok(erc721::FlatTransfers {
+        rows: transfers
+            .transfers
+            .into_iter()
+            .map(|transfer| erc721::FlatTransfer {
+                id: transfer.id,
+                block_number: clock.number,
+                block_timestamp,
+                trx_hash: format!("0x{}", Hex(&transfer.trx_hash)),
+                ordinal: transfer.ordinal,
+                contract: eth_utils::format_address(&transfer.contract),
+                from_address: eth_utils::format_address(&transfer.from),
+                to_address: eth_utils::format_address(&transfer.to),
+                token_id: transfer.token_id,
             })
             .collect(),
     })
 }
 
-/// Store the total balance of NFT tokens for the specific TRACKED_CONTRACT by holder
+/// Store the number of tokens of each collection in circulation, from its mints and burns
 #[substreams::handlers::store]
-fn store_transfers(transfers: erc721::Transfers, s: StoreAddInt64) {
-    log::info!("NFT holders state builder");
+fn store_collection_supply(transfers: erc721::Transfers, s: StoreAddInt64) {
     for transfer in transfers.transfers {
+        if transfer.from == NULL_ADDRESS {
+            s.add(transfer.ordinal, collection_supply_key(&transfer.contract), 1);
+        }
+        if transfer.to == NULL_ADDRESS {
+            s.add(transfer.ordinal, collection_supply_key(&transfer.contract), -1);
+        }
+    }
+}
+
+fn contract_key(address: &[u8]) -> String {
+    keys::key(keys::CONTRACT).hex(address).into()
+}
+
+/// Name of a token of store_tokens, keyed by its address
+fn token_address_key(address: &[u8]) -> String {
+    keys::key(keys::TOKEN).hex(address).into()
+}
+
+fn collection_supply_key(contract: &[u8]) -> String {
+    keys::key(keys::SUPPLY).hex(contract).into()
+}
+
+/// Store the total balance of NFT tokens for the specific TRACKED_CONTRACT by holder, starting from the `snapshot`
+/// param
+#[substreams::handlers::store]
+fn store_transfers(params: Params, clock: Clock, transfers: erc721::Transfers, s: StoreAddInt64) {
+    logging::debug!("nft", "NFT holders state builder");
+    for balance in snapshot::balances(&params, clock.number) {
+        match balance.balance.parse::<i64>() {
+            Ok(count) if balance.contract == TRACKED_CONTRACT => s.add(0, generate_key(&balance.holder), count),
+            _ => logging::warning!(
+                "snapshot",
+                "skipping snapshot balance {} of {}",
+                balance.balance,
+                Hex(&balance.contract)
+            ),
+        }
+    }
+    for (ordinal, key, delta) in holder_deltas(&transfers) {
+        s.add(ordinal, key, delta);
+    }
+}
+
+/// Holder balance changes of the transfers as `(ordinal, key, delta)`, mints and burns only move one side
+fn holder_deltas(transfers: &erc721::Transfers) -> Vec<(u64, String, i64)> {
+    let mut deltas = Vec::with_capacity(transfers.transfers.len() * 2);
+    for transfer in &transfers.transfers {
         if transfer.from != NULL_ADDRESS {
-            log::info!("Found a transfer out {}", Hex(&transfer.trx_hash));
-            s.add(transfer.ordinal, generate_key(&transfer.from), -1);
+            logging::debug!("nft", "Found a transfer out {}", Hex(&transfer.trx_hash));
+            deltas.push((transfer.ordinal, generate_key(&transfer.from), -1));
         }
 
         if transfer.to != NULL_ADDRESS {
-            log::info!("Found a transfer in {}", Hex(&transfer.trx_hash));
-            s.add(transfer.ordinal, generate_key(&transfer.to), 1);
+            logging::debug!("nft", "Found a transfer in {}", Hex(&transfer.trx_hash));
+            deltas.push((transfer.ordinal, generate_key(&transfer.to), 1));
         }
     }
+    deltas
 }
 
 fn generate_key(holder: &Vec<u8>) -> String {
-    return format!("total:{}:{}", Hex(holder), Hex(TRACKED_CONTRACT));
+    return keys::key(keys::TOTAL).hex(holder).hex(&TRACKED_CONTRACT).into();
 }
 
-const INITIALIZE_METHOD_HASH: [u8; 4] = hex!("1459457a");
+/// Token id as stored in the erc721 messages, None with a diagnostic for the ids that do not fit a u64
+fn token_id(id: &substreams::scalar::BigInt, trx_hash: &[u8]) -> Option<u64> {
+    let token_id = eth_utils::to_u64(id);
+    if token_id.is_none() {
+        logging::warning!("nft", "skipping token id {} out of the u64 range in {}", id, Hex(trx_hash));
+    }
+    token_id
+}
 
+/// Extracts the fungible token transfers of the block, ERC20 Transfer events along with ERC777 and ERC1363 moves
 #[substreams::handlers::map]
-fn map_tokens(blk: eth::Block) -> Result<pb::tokens::Tokens, substreams::errors::Error> {
-    let mut tokens = vec![];
-    for trx in blk.transaction_traces {
-        for call in trx.calls {
-            if call.state_reverted {
+fn map_erc20_transfers(blk: eth::Block) -> Result<pb::tokens::Transfers, substreams::errors::Error> {
+    let mut transfers = vec![];
+    let mut duplicate_suppressed = 0;
+    let topics: Vec<&[u8]> = erc20::TOPICS.iter().map(|topic| topic.as_slice()).collect();
+    for trx in eth_utils::transactions_matching_any(&blk, &topics) {
+        let (decoded, suppressed) = erc20::decode(trx);
+        transfers.extend(decoded);
+        duplicate_suppressed += suppressed;
+    }
+
+    Ok(pb::tokens::Transfers {
+        transfers,
+        duplicate_suppressed,
+        ..Default::default()
+    })
+}
+
+/// Reduces the transfers of map_erc20_transfers for constrained sinks, trading them for per token aggregates as the
+/// `mode` param sets. No module reads it, so the stores and exporters keep every transfer
+#[substreams::handlers::map]
+fn map_sampled_erc20_transfers(
+    params: Params,
+    transfers: pb::tokens::Transfers,
+) -> Result<pb::tokens::Transfers, substreams::errors::Error> {
+    let mut reduced = erc20::reduce(transfers.transfers, erc20::mode(&params));
+    reduced.duplicate_suppressed = transfers.duplicate_suppressed;
+    Ok(reduced)
+}
+
+/// Extracts the transfers of the block across standards, ETH, fungible tokens, the contract and the ERC1155 collections
+/// listed in the `erc1155` param, in a single stream for sinks that don't need the standard specific modules. Only the
+/// fields listed in the `fields` param are kept
+#[substreams::handlers::map]
+fn map_asset_transfers(
+    params: Params,
+    blk: eth::Block,
+) -> Result<pb::portfolio::AssetTransfers, substreams::errors::Error> {
+    let erc1155 = params.addresses("erc1155");
+    let fungible = erc20::topic_bits();
+    let mut transfers: Vec<_> = blk
+        .transactions()
+        .flat_map(|trx| assets::transfers(trx, &TRACKED_CONTRACT, &erc1155, &fungible))
+        .collect();
+    projection::project(&params, &mut transfers);
+    Ok(pb::portfolio::AssetTransfers { transfers })
+}
+
+/// Converts the ERC20 transfers to parallel arrays, smaller than one message per transfer on busy blocks
+#[substreams::handlers::map]
+fn map_erc20_transfer_columns(
+    transfers: pb::tokens::Transfers,
+) -> Result<pb::tokens::TransferColumns, substreams::errors::Error> {
+    Ok(columnar::erc20_transfers(transfers))
+}
+
+/// Store the balances of the ERC20 tokens listed in the `erc20` param by holder, starting from the `snapshot` param
+#[substreams::handlers::store]
+fn store_erc20_balances(params: Params, clock: Clock, transfers: pb::tokens::Transfers, s: StoreAddBigInt) {
+    let contracts = params.addresses("erc20");
+    if contracts.is_empty() {
+        return;
+    }
+
+    for balance in snapshot::balances(&params, clock.number) {
+        let amount = match balance.balance.parse::<substreams::scalar::BigInt>() {
+            Ok(amount) if contracts.contains(&balance.contract) => amount,
+            _ => {
+                logging::warning!(
+                    "snapshot",
+                    "skipping snapshot balance {} of {}",
+                    balance.balance,
+                    Hex(&balance.contract)
+                );
                 continue;
             }
-            if call.call_type == eth::CallType::Create as i32 || call.call_type == eth::CallType::Call as i32 {
-                let call_input_len = call.input.len();
-                if call.call_type == eth::CallType::Call as i32
-                    && (call_input_len < 4 || call.input[0..4] != INITIALIZE_METHOD_HASH)
-                {
-                    // this will check if a proxy contract has been called to create a ERC20 contract.
-                    // if that is the case the Proxy contract will call the initialize function on the ERC20 contract
-                    // this is part of the OpenZeppelin Proxy contract standard
-                    continue;
-                }
+        };
+        for key in portfolio::erc20_keys(&balance.holder, &balance.contract) {
+            s.add(0, key, &amount);
+        }
+    }
 
-                if call.call_type == eth::CallType::Create as i32 {
-                    let mut code_change_len = 0;
-                    for code_change in &call.code_changes {
-                        code_change_len += code_change.new_code.len()
-                    }
+    for change in erc20::balance_changes(&transfers) {
+        if change.holder == NULL_ADDRESS || !contracts.iter().any(|contract| contract == &change.token) {
+            continue;
+        }
+        for key in portfolio::erc20_keys(&change.holder, &change.token) {
+            s.add(change.ordinal, key, &change.amount);
+        }
+    }
+}
+
+/// Store the storage slots of the `balances` and `allowances` mappings of the tokens of store_tokens, inferred from the
+/// storage changes made along with their Transfer and Approval events
+#[substreams::handlers::store]
+fn store_storage_layouts(blk: eth::Block, tokens: StoreGetString, s: StoreSetInt64) {
+    use abi::erc20::events as erc20;
 
-                    // log::debug!(
-                    //     "found contract creation: {}, caller {}, code change {}, input {}",
-                    //     Hex(&call.address),
-                    //     Hex(&call.caller),
-                    //     code_change_len,
-                    //     call_input_len,
-                    // );
+    for trx in blk.transactions() {
+        let changes: Vec<_> = trx
+            .calls
+            .iter()
+            .filter(|call| !call.state_reverted)
+            .flat_map(|call| call.storage_changes.iter())
+            .collect();
+        if changes.is_empty() {
+            continue;
+        }
 
-                    if code_change_len <= 150 {
-                        // optimization to skip none viable SC
-                        // log::info!(
-                        //     "skipping too small code to be a token contract: {}",
-                        //     Hex(&call.address)
-                        // );
-                        continue;
-                    }
-                } else {
-                    // log::debug!(
-                    //     "found proxy initialization: contract {}, caller {}",
-                    //     Hex(&call.address),
-                    //     Hex(&call.caller)
-                    // );
-                }
+        for log in trx.receipt().logs() {
+            let token = log.address();
+            let token_changes: Vec<_> = changes.iter().copied().filter(|change| change.address == token).collect();
+            if token_changes.is_empty() || tokens.get_last(token_address_key(token)).is_none() {
+                continue;
+            }
 
-                if call.caller == hex!("0000000000004946c0e9f43f4dee607b0ef1fa1c")
-                    || call.caller == hex!("00000000687f5b66638856396bee28c1db0178d1")
-                {
-                    // log::debug!("skipping known caller address");
-                    continue;
+            if let Some(transfer) = erc20::Transfer::match_and_decode(log.log) {
+                let holder = if transfer.to != NULL_ADDRESS { transfer.to } else { transfer.from };
+                if let Some(slot) = storage::infer_slot(&token_changes, |slot| storage::balance_key(&holder, slot)) {
+                    s.set(log.ordinal(), storage::balances_layout_key(token), &(slot as i64));
                 }
-
-                let rpc_call_decimal = create_rpc_calls(&call.address, vec![rpc_utils::DECIMALS]);
-                let rpc_responses_unmarshalled_decimal: substreams_ethereum::pb::eth::rpc::RpcResponses =
-                    substreams_ethereum::rpc::eth_call(&rpc_call_decimal);
-                let response_decimal = rpc_responses_unmarshalled_decimal.responses;
-                if response_decimal[0].failed {
-                    let decimals_error = String::from_utf8_lossy(response_decimal[0].raw.as_ref());
-                    // log::debug!(
-                    //     "{} is not an ERC20 token contract because of 'eth_call' failures [decimals: {}]",
-                    //     Hex(&call.address),
-                    //     decimals_error,
-                    // );
-                    continue;
+            } else if let Some(approval) = erc20::Approval::match_and_decode(log.log) {
+                let key = |slot| storage::allowance_key(&approval.owner, &approval.spender, slot);
+                if let Some(slot) = storage::infer_slot(&token_changes, key) {
+                    s.set(log.ordinal(), storage::allowances_layout_key(token), &(slot as i64));
                 }
+            }
+        }
+    }
+}
 
-                let decoded_decimals = eth_utils::read_uint32(response_decimal[0].raw.as_ref());
-                if decoded_decimals.is_err() {
-                    // log::debug!(
-                    //     "{} is not an ERC20 token contract decimal `eth_call` failed: {}",
-                    //     Hex(&call.address),
-                    //     decoded_decimals.err().unwrap(),
-                    // );
-                    continue;
+/// Store the balances of the ERC20 tokens listed in the `erc20` param by holder, read from the storage changes of
+/// their `balances` mapping instead of their Transfer events
+#[substreams::handlers::store]
+fn store_balances_from_storage(params: Params, blk: eth::Block, layouts: StoreGetInt64, s: StoreSetBigInt) {
+    let slots = storage::balance_slots(&params, &layouts);
+    if slots.is_empty() {
+        return;
+    }
+
+    for trx in blk.transactions() {
+        let changes: Vec<_> = trx
+            .calls
+            .iter()
+            .filter(|call| !call.state_reverted)
+            .flat_map(|call| call.storage_changes.iter())
+            .filter(|change| slots.contains_key(&change.address))
+            .collect();
+        if changes.is_empty() {
+            continue;
+        }
+
+        for holder in storage::candidate_holders(trx) {
+            for (token, slot) in &slots {
+                let key = storage::balance_key(&holder, *slot);
+                for change in changes.iter().filter(|change| &change.address == token && change.key == key) {
+                    let balance = substreams::scalar::BigInt::from_unsigned_bytes_be(&change.new_value);
+                    for key in portfolio::erc20_keys(&holder, token) {
+                        s.set(change.ordinal, key, &balance);
+                    }
                 }
+            }
+        }
+    }
+}
+
+/// Store the balances of the ERC1155 tokens of the collections listed in the `erc1155` param by holder, starting from
+/// the `snapshot` param
+#[substreams::handlers::store]
+fn store_erc1155_balances(params: Params, blk: eth::Block, s: StoreAddBigInt) {
+    let contracts = params.addresses("erc1155");
+    if contracts.is_empty() {
+        return;
+    }
 
-                let rpc_call_name_symbol = create_rpc_calls(&call.address, vec![rpc_utils::NAME, rpc_utils::SYMBOL]);
-                let rpc_responses_unmarshalled: substreams_ethereum::pb::eth::rpc::RpcResponses =
-                    substreams_ethereum::rpc::eth_call(&rpc_call_name_symbol);
-                let responses = rpc_responses_unmarshalled.responses;
-                log::debug!(
-                    "responses length: {}",
-                    responses.len()
+    for balance in snapshot::balances(&params, blk.number) {
+        let parsed = (
+            balance.token_id.parse::<substreams::scalar::BigInt>(),
+            balance.balance.parse::<substreams::scalar::BigInt>(),
+        );
+        let (id, amount) = match parsed {
+            (Ok(id), Ok(amount)) if contracts.contains(&balance.contract) => (id, amount),
+            _ => {
+                logging::warning!(
+                    "snapshot",
+                    "skipping snapshot balance {} of {}",
+                    balance.balance,
+                    Hex(&balance.contract)
                 );
-                if responses[0].failed || responses[1].failed {
-                    let name_error = String::from_utf8_lossy(responses[0].raw.as_ref());
-                    let symbol_error = String::from_utf8_lossy(responses[1].raw.as_ref());
-                    // log::debug!(
-                    //     "{} is not an ERC20 token contract because of 'eth_call' failures [name: {}, symbol: {}]",
-                    //     Hex(&call.address),
-                    //     name_error,
-                    //     symbol_error,
-                    // );
-                    continue;
-                };
+                continue;
+            }
+        };
+        for key in portfolio::erc1155_keys(&balance.holder, &balance.contract, &id) {
+            s.add(0, key, &amount);
+        }
+    }
 
-                let decoded_name = eth_utils::read_string(responses[0].raw.as_ref());
-                if decoded_name.is_err() {
-                    // log::debug!(
-                    //     "{} is not an ERC20 token contract name `eth_call` failed: {}",
-                    //     Hex(&call.address),
-                    //     decoded_name.err().unwrap(),
-                    // );
-                    continue;
-                }
+    for log in blk.logs() {
+        if !contracts.iter().any(|contract| contract == log.address()) {
+            continue;
+        }
 
-                let decoded_symbol = eth_utils::read_string(responses[1].raw.as_ref());
-                if decoded_symbol.is_err() {
-                    // log::debug!(
-                    //     "{} is not an ERC20 token contract symbol `eth_call` failed: {}",
-                    //     Hex(&call.address),
-                    //     decoded_symbol.err().unwrap(),
-                    // );
-                    continue;
+        let (from, to, amounts) = match assets::erc1155_moves(log.log) {
+            Some(moves) => moves,
+            None => continue,
+        };
+
+        for (id, value) in amounts {
+            if from != NULL_ADDRESS {
+                for key in portfolio::erc1155_keys(&from, log.address(), &id) {
+                    s.add(log.ordinal(), key, value.neg());
+                }
+            }
+            if to != NULL_ADDRESS {
+                for key in portfolio::erc1155_keys(&to, log.address(), &id) {
+                    s.add(log.ordinal(), key, &value);
                 }
+            }
+        }
+    }
+}
 
-                let decimals = decoded_decimals.unwrap() as u64;
-                let symbol = decoded_symbol.unwrap();
-                let name = decoded_name.unwrap();
-                log::debug!(
-                    "{} is an ERC20 token contract with name {}",
-                    Hex(&call.address),
-                    name,
-                );
-                let token = pb::tokens::Token {
-                    address: Hex(&call.address).to_string(),
-                    name,
-                    symbol,
-                    decimals,
-                };
+/// Store the shares of the share based rebasing tokens listed in the `rebasing` param by holder, such as stETH
+#[substreams::handlers::store]
+fn store_rebasing_shares(params: Params, blk: eth::Block, s: StoreAddBigInt) {
+    let tokens = params.addresses("rebasing");
+    if tokens.is_empty() {
+        return;
+    }
 
-                tokens.push(token);
+    for log in blk.logs() {
+        if !tokens.iter().any(|token| token == log.address()) {
+            continue;
+        }
+
+        if let Some(transfer) = abi::lido_steth::events::TransferShares::match_and_decode(log.log) {
+            if transfer.from != NULL_ADDRESS {
+                s.add(log.ordinal(), rebasing::shares_key(&transfer.from, log.address()), transfer.shares_value.neg());
+            }
+            if transfer.to != NULL_ADDRESS {
+                s.add(log.ordinal(), rebasing::shares_key(&transfer.to, log.address()), &transfer.shares_value);
             }
         }
     }
-    Ok(pb::tokens::Tokens { tokens })
 }
 
+/// Extracts the holdings changed in the block by holder, across the ERC721, ERC1155 and ERC20 balance stores and the
+/// rebasing token shares
+#[substreams::handlers::map]
+fn map_portfolios(
+    erc721: Deltas<DeltaInt64>,
+    erc1155: Deltas<DeltaBigInt>,
+    erc20: Deltas<DeltaBigInt>,
+    shares: Deltas<DeltaBigInt>,
+    decimals: StoreGetInt64,
+) -> Result<pb::portfolio::Portfolios, substreams::errors::Error> {
+    let decimals = |token: &[u8]| token_decimals(&decimals, token);
+    let mut rpc = RpcCounters::default();
+    let shares = rebasing::balances(&Substreams, shares, &mut rpc);
+    logging::debug!("balances", "{} rpc calls, {} failed", rpc.calls, rpc.failures);
+    Ok(portfolio::build(erc721, erc1155, erc20, shares, decimals))
+}
+
+/// Extracts the balances of the ERC721, ERC1155 and ERC20 balance stores that went below zero in the block, which
+/// map_portfolios reports as 0
+#[substreams::handlers::map]
+fn map_balance_anomalies(
+    erc721: Deltas<DeltaInt64>,
+    erc1155: Deltas<DeltaBigInt>,
+    erc20: Deltas<DeltaBigInt>,
+) -> Result<pb::portfolio::BalanceAnomalies, substreams::errors::Error> {
+    Ok(portfolio::anomalies(erc721, erc1155, erc20))
+}
+
+/// Store the transfers of each block until they get the number of confirmations set by the `confirmations` param
 #[substreams::handlers::store]
-fn store_tokens(tokens: pb::tokens::Tokens, store: store::StoreSetString) {
-    for token in tokens.tokens {
-        log::debug!(
-            "Storing token: {}",
-            token.name
-        );
-        let key = format!("token:{}", token.address);
-        store.set(1, key, &token.name);
+fn store_pending(params: Params, clock: Clock, transfers: erc721::Transfers, s: StoreSetProto<erc721::Transfers>) {
+    if !transfers.transfers.is_empty() {
+        s.set(0, pending_key(clock.number), &transfers);
+    }
+
+    // map_finality reads the block reaching its confirmations, anything older is settled
+    let confirmations = confirmations(&params);
+    if clock.number > confirmations {
+        s.delete_prefix(1, &pending_key(clock.number - confirmations - 1));
+    }
+}
+
+/// Extracts the transfers of the block flagged as provisional, along with the transfers of the block that
+/// just got the number of confirmations set by the `confirmations` param
+#[substreams::handlers::map]
+fn map_finality(
+    params: Params,
+    clock: Clock,
+    transfers: erc721::Transfers,
+    pending: StoreGetProto<erc721::Transfers>,
+) -> Result<erc721::FinalityUpdate, substreams::errors::Error> {
+    let confirmations = confirmations(&params);
+    let mut update = erc721::FinalityUpdate {
+        block: clock.number,
+        provisional: transfers.transfers,
+        ..Default::default()
+    };
+
+    if clock.number >= confirmations {
+        update.confirmed_block = clock.number - confirmations;
+        if let Some(confirmed) = pending.get_last(pending_key(update.confirmed_block)) {
+            update.confirmed = confirmed.transfers;
+        }
+    }
+
+    Ok(update)
+}
+
+fn confirmations(params: &Params) -> u64 {
+    params.get("confirmations").and_then(|value| value.parse().ok()).unwrap_or(12)
+}
+
+fn pending_key(block: u64) -> String {
+    keys::key(keys::PENDING).block(block).into()
+}
+
+/// Store the block each holder first acquired a TRACKED_CONTRACT token at
+#[substreams::handlers::store]
+fn store_first_acquisition(clock: Clock, transfers: erc721::Transfers, s: StoreMinInt64) {
+    for (ordinal, key, block) in first_acquisitions(&transfers, clock.number) {
+        s.min(ordinal, key, block);
+    }
+}
+
+/// Candidate first acquisitions of the transfers as `(ordinal, key, block)`, the store keeps the earliest block
+fn first_acquisitions(transfers: &erc721::Transfers, block: u64) -> Vec<(u64, String, i64)> {
+    transfers
+        .transfers
+        .iter()
+        .filter(|transfer| transfer.to != NULL_ADDRESS)
+        .map(|transfer| (transfer.ordinal, first_acquisition_key(&transfer.to), block as i64))
+        .collect()
+}
+
+/// Extracts the number of new holders, returning buyers and exiting holders of the block
+#[substreams::handlers::map]
+fn map_cohorts(
+    clock: Clock,
+    transfers: erc721::Transfers,
+    first_acquisitions: StoreGetInt64,
+    balances: Deltas<DeltaInt64>,
+) -> Result<erc721::CohortStats, substreams::errors::Error> {
+    let mut stats = erc721::CohortStats {
+        block: clock.number,
+        ..Default::default()
+    };
+
+    let mut buyers: Vec<&Vec<u8>> = vec![];
+    for transfer in &transfers.transfers {
+        if transfer.to != NULL_ADDRESS && !buyers.contains(&&transfer.to) {
+            buyers.push(&transfer.to);
+        }
+    }
+    for buyer in buyers {
+        match first_acquisitions.get_last(first_acquisition_key(buyer)) {
+            Some(block) if block as u64 == clock.number => stats.new_holders += 1,
+            Some(_) => stats.returning_buyers += 1,
+            None => {}
+        }
+    }
+
+    // the last delta of a holder carries its balance at the end of the block
+    let mut closing: BTreeMap<String, (i64, i64)> = BTreeMap::new();
+    for delta in balances.deltas {
+        let opening = closing.get(&delta.key).map(|(opening, _)| *opening).unwrap_or(delta.old_value);
+        closing.insert(delta.key, (opening, delta.new_value));
+    }
+    stats.exiting_holders = closing.values().filter(|(opening, closing)| *opening > 0 && *closing <= 0).count() as u64;
+
+    Ok(stats)
+}
+
+fn first_acquisition_key(holder: &[u8]) -> String {
+    keys::key(keys::FIRST).hex(holder).hex(&TRACKED_CONTRACT).into()
+}
+
+/// Extracts alerts for holders of at least `min_balance` tokens sending more than `min_tokens` tokens in a block,
+/// and for ERC20 transfers worth more than `min_usd` from the tokens priced in `erc20_prices`
+#[substreams::handlers::map]
+fn map_whale_alerts(
+    params: Params,
+    blk: eth::Block,
+    transfers: erc721::Transfers,
+    balances: StoreGetInt64,
+) -> Result<erc721::WhaleAlerts, substreams::errors::Error> {
+    let min_balance: i64 = params.get("min_balance").and_then(|value| value.parse().ok()).unwrap_or(50);
+    let min_tokens: u64 = params.get("min_tokens").and_then(|value| value.parse().ok()).unwrap_or(5);
+    let min_usd: f64 = params.get("min_usd").and_then(|value| value.parse().ok()).unwrap_or(1_000_000.0);
+    let mut alerts = vec![];
+
+    let mut sent: BTreeMap<Vec<u8>, u64> = BTreeMap::new();
+    for transfer in transfers.transfers {
+        if transfer.from != NULL_ADDRESS {
+            *sent.entry(transfer.from).or_default() += 1;
+        }
     }
-}
\ No newline at end of file
+    for (holder, amount) in sent {
+        if amount <= min_tokens {
+            continue;
+        }
+
+        let balance = balances.get_first(generate_key(&holder)).unwrap_or(0);
+        if balance >= min_balance {
+            alerts.push(erc721::WhaleAlert {
+                holder,
+                contract: TRACKED_CONTRACT.to_vec(),
+                balance: balance.to_string(),
+                amount: amount.to_string(),
+                usd_value: 0.0,
+                block: blk.number,
+            });
+        }
+    }
+
+    let priced = whale::priced_tokens(&params);
+    if !priced.is_empty() {
+        let mut sent: BTreeMap<(Vec<u8>, Vec<u8>), substreams::scalar::BigInt> = BTreeMap::new();
+        for log in blk.logs() {
+            if !priced.iter().any(|token| token.address == log.address()) {
+                continue;
+            }
+            if let Some(transfer) = abi::erc20::events::Transfer::match_and_decode(log.log) {
+                let total = sent
+                    .entry((transfer.from, log.address().to_vec()))
+                    .or_insert_with(substreams::scalar::BigInt::zero);
+                *total = total.clone() + transfer.value;
+            }
+        }
+
+        for ((holder, contract), amount) in sent {
+            let token = match priced.iter().find(|token| token.address == contract) {
+                Some(token) => token,
+                None => continue,
+            };
+            let usd_value = token.usd_value(&amount);
+            if holder != NULL_ADDRESS && usd_value > min_usd {
+                alerts.push(erc721::WhaleAlert {
+                    holder,
+                    contract,
+                    balance: String::new(),
+                    amount: token.units(&amount),
+                    usd_value,
+                    block: blk.number,
+                });
+            }
+        }
+    }
+
+    Ok(erc721::WhaleAlerts { alerts })
+}
+
+/// Store the last block every address holding at least `min_balance` tokens of the tracked collection sent tokens of
+/// the collection or ERC20 tokens at, `heartbeat:<address>`
+#[substreams::handlers::store]
+fn store_last_active(
+    params: Params,
+    clock: Clock,
+    transfers: erc721::Transfers,
+    erc20_transfers: pb::tokens::Transfers,
+    balances: StoreGetInt64,
+    s: StoreSetInt64,
+) {
+    let dormancy = heartbeat::Dormancy::from_params(&params);
+    let balance = |address: &[u8]| holder_balance(&balances, address);
+    for sender in heartbeat::holder_senders(&transfers, &erc20_transfers, dormancy.min_balance, balance) {
+        s.set(0, heartbeat::last_active_key(&sender), &(clock.number as i64));
+    }
+}
+
+/// Store the addresses holding at least `min_balance` tokens active during every period of `every` blocks, once per
+/// period, `active:<period>`, the candidates of the dormancy reports. Periods no report reads anymore are deleted.
+#[substreams::handlers::store]
+fn store_active_periods(
+    params: Params,
+    clock: Clock,
+    transfers: erc721::Transfers,
+    erc20_transfers: pb::tokens::Transfers,
+    balances: StoreGetInt64,
+    last_active: StoreGetInt64,
+    s: StoreAppend<String>,
+) {
+    let dormancy = heartbeat::Dormancy::from_params(&params);
+    if let Some(expired) = dormancy.expired_period(clock.number) {
+        s.delete_prefix(0, &heartbeat::period_key(expired));
+    }
+
+    let period = dormancy.period(clock.number);
+    let balance = |address: &[u8]| holder_balance(&balances, address);
+    for sender in heartbeat::holder_senders(&transfers, &erc20_transfers, dormancy.min_balance, balance) {
+        // store_last_active applies the same filter, an address it saw earlier in the period is already listed
+        let previous = last_active.get_first(heartbeat::last_active_key(&sender));
+        if previous.map_or(false, |block| dormancy.period(block as u64) == period) {
+            continue;
+        }
+        s.append(1, heartbeat::period_key(period), Hex(&sender).to_string());
+    }
+}
+
+/// Tokens of the tracked collection `address` holds at the start or at the end of the block, whichever is larger
+fn holder_balance(balances: &StoreGetInt64, address: &[u8]) -> i64 {
+    let key = generate_key(&address.to_vec());
+    balances.get_first(&key).unwrap_or(0).max(balances.get_last(&key).unwrap_or(0))
+}
+
+/// Extracts the dormant addresses holding at least `min_balance` tokens that sent tokens again, and every `every`
+/// blocks the ones that went without activity for more than `dormancy` blocks
+#[substreams::handlers::map]
+fn map_dormancy_report(
+    params: Params,
+    clock: Clock,
+    transfers: erc721::Transfers,
+    erc20_transfers: pb::tokens::Transfers,
+    last_active: StoreGetInt64,
+    periods: StoreGetArray<String>,
+    balances: StoreGetInt64,
+) -> Result<erc721::DormancyReport, substreams::errors::Error> {
+    let dormancy = heartbeat::Dormancy::from_params(&params);
+    let mut report = erc721::DormancyReport {
+        block: clock.number,
+        ..Default::default()
+    };
+
+    // read as of the start of the block, before the transfers of the block moved them
+    for sender in heartbeat::senders(&transfers, &erc20_transfers) {
+        let previous = match last_active.get_first(heartbeat::last_active_key(&sender)) {
+            Some(previous) => previous as u64,
+            None => continue,
+        };
+        let balance = balances.get_first(generate_key(&sender)).unwrap_or(0);
+        report.awakened.extend(dormancy.dormant(&sender, clock.number, previous, balance));
+    }
+
+    if let Some(period) = dormancy.report_period(clock.number) {
+        report.reported = true;
+        let mut candidates = periods.get_last(heartbeat::period_key(period)).unwrap_or_default();
+        candidates.sort();
+        candidates.dedup();
+        for address in candidates.iter().filter_map(|address| hex::decode(address).ok()) {
+            let last = last_active.get_last(heartbeat::last_active_key(&address)).unwrap_or_default() as u64;
+            // addresses active again since then are reported by a later period
+            if dormancy.period(last) != period {
+                continue;
+            }
+            let balance = balances.get_last(generate_key(&address)).unwrap_or(0);
+            report.dormant.extend(dormancy.dormant(&address, clock.number, last, balance));
+        }
+    }
+    Ok(report)
+}
+
+/// Store the ETH price each holder last paid for a TRACKED_CONTRACT token
+#[substreams::handlers::store]
+fn store_buy_prices(sales: erc721::Sales, s: StoreSetBigInt) {
+    for sale in sales.sales {
+        if !sale.currency.is_empty() {
+            continue;
+        }
+        if let Ok(price) = sale.price.parse::<substreams::scalar::BigInt>() {
+            s.set(sale.ordinal, tags::bought_key(&sale.buyer, sale.token_id), &price);
+        }
+    }
+}
+
+/// Store the wallets that minted before the `early_mint_until` param, with the block they first minted at. Kept
+/// apart from store_tags, which starts with the sales once the collection was minted out.
+#[substreams::handlers::store]
+fn store_early_minters(params: Params, clock: Clock, transfers: erc721::Transfers, s: StoreMinInt64) {
+    for transfer in &transfers.transfers {
+        if let Some(address) = tags::early_minter(&params, clock.number, transfer) {
+            s.min(transfer.ordinal, tags::key(address, tags::EARLY_MINTER), clock.number as i64);
+        }
+    }
+}
+
+/// Store the tags of the wallets matching a tagging rule, with the block they were first tagged at
+#[substreams::handlers::store]
+fn store_tags(clock: Clock, sales: erc721::Sales, buy_prices: StoreGetBigInt, s: StoreMinInt64) {
+    for sale in &sales.sales {
+        let bought = buy_prices.get_at(sale.ordinal, tags::bought_key(&sale.seller, sale.token_id));
+        if let Some(address) = tags::profitable_flipper(sale, bought) {
+            s.min(sale.ordinal, tags::key(address, tags::PROFITABLE_FLIPPER), clock.number as i64);
+        }
+    }
+}
+
+/// Store the last block each wallet deposited to or withdrew from one of the mixers listed by the `mixers` param
+#[substreams::handlers::store]
+fn store_mixer_interactions(params: Params, blk: eth::Block, s: StoreSetInt64) {
+    let mixers = tags::mixers(&params);
+    for trx in blk.transactions() {
+        for address in tags::mixer_counterparties(trx, &mixers) {
+            s.set(trx.end_ordinal, tags::mixer_key(&address), &(blk.number as i64));
+        }
+    }
+}
+
+/// Extracts the transfers and sales of the block with the tags of the wallets involved, wallets that interacted
+/// with a mixer within the `lookback` param are tagged `mixer_interaction`
+#[substreams::handlers::map]
+fn map_tagged_activity(
+    params: Params,
+    clock: Clock,
+    transfers: erc721::Transfers,
+    sales: erc721::Sales,
+    wallet_tags: StoreGetInt64,
+    early_minters: StoreGetInt64,
+    mixer_interactions: StoreGetInt64,
+) -> Result<erc721::TaggedActivity, substreams::errors::Error> {
+    let lookback = tags::lookback(&params);
+    let wallet_tags = tags::WalletTags {
+        tags: &wallet_tags,
+        early_minters: &early_minters,
+    };
+    let lookup =
+        |address: &[u8]| tags::lookup_with_mixers(&wallet_tags, &mixer_interactions, lookback, clock.number, address);
+
+    Ok(erc721::TaggedActivity {
+        transfers: transfers
+            .transfers
+            .into_iter()
+            .map(|transfer| erc721::TaggedTransfer {
+                from_tags: lookup(&transfer.from),
+                to_tags: lookup(&transfer.to),
+                transfer: Some(transfer),
+            })
+            .collect(),
+        sales: sales
+            .sales
+            .into_iter()
+            .map(|sale| erc721::TaggedSale {
+                seller_tags: lookup(&sale.seller),
+                buyer_tags: lookup(&sale.buyer),
+                sale: Some(sale),
+            })
+            .collect(),
+    })
+}
+
+/// Store the cluster of every wallet first funded with ETH, wallets funded by the same sender within the `window`
+/// param share a cluster
+#[substreams::handlers::store]
+fn store_clusters(params: Params, blk: eth::Block, s: StoreSetIfNotExistsRaw) {
+    let window = clusters::window(&params);
+    for trx in blk.transactions() {
+        if let Some(address) = clusters::funded(trx) {
+            let cluster = clusters::cluster_id(&trx.from, blk.number, window);
+            s.set_if_not_exists(trx.end_ordinal, clusters::cluster_key(address), &cluster);
+        }
+    }
+}
+
+/// Store the number of wallets in each cluster
+#[substreams::handlers::store]
+fn store_cluster_sizes(clusters: Deltas<DeltaString>, s: StoreAddInt64) {
+    for delta in clusters.deltas.iter() {
+        if delta.operation == substreams::pb::substreams::store_delta::Operation::Create {
+            s.add(delta.ordinal, clusters::size_key(&delta.new_value), 1);
+        }
+    }
+}
+
+/// Extracts the transfers of the block with the clusters of the wallets involved, clusters smaller than the
+/// `min_size` param are left out
+#[substreams::handlers::map]
+fn map_clustered_transfers(
+    params: Params,
+    transfers: erc721::Transfers,
+    wallet_clusters: StoreGetString,
+    sizes: StoreGetInt64,
+) -> Result<erc721::ClusteredTransfers, substreams::errors::Error> {
+    let min_size: i64 = params.get("min_size").and_then(|value| value.parse().ok()).unwrap_or(3);
+    let cluster = |address: &[u8]| {
+        wallet_clusters
+            .get_last(clusters::cluster_key(address))
+            .filter(|cluster| sizes.get_last(clusters::size_key(cluster)).unwrap_or(0) >= min_size)
+            .unwrap_or_default()
+    };
+
+    Ok(erc721::ClusteredTransfers {
+        transfers: transfers
+            .transfers
+            .into_iter()
+            .map(|transfer| erc721::ClusteredTransfer {
+                from_cluster: cluster(&transfer.from),
+                to_cluster: cluster(&transfer.to),
+                transfer: Some(transfer),
+            })
+            .collect(),
+    })
+}
+
+/// Extracts the realized profit of the ETH sales whose seller bought the token earlier. An ERC721 holder owns a
+/// token id at most once, so FIFO matching per token id pairs a sale with the last buy of that token.
+#[substreams::handlers::map]
+fn map_pnl(sales: erc721::Sales, buy_prices: StoreGetBigInt) -> Result<erc721::Pnl, substreams::errors::Error> {
+    let mut realized = vec![];
+    for sale in sales.sales {
+        if !sale.currency.is_empty() {
+            continue;
+        }
+
+        let proceeds = match sale.price.parse::<substreams::scalar::BigInt>() {
+            Ok(proceeds) => proceeds,
+            Err(_) => continue,
+        };
+        let cost = match buy_prices.get_at(sale.ordinal, tags::bought_key(&sale.seller, sale.token_id)) {
+            Some(cost) => cost,
+            None => continue,
+        };
+
+        realized.push(erc721::RealizedPnl {
+            id: sale.id,
+            holder: sale.seller,
+            token_id: sale.token_id,
+            profit: (proceeds.clone() - cost.clone()).to_string(),
+            cost: cost.to_string(),
+            proceeds: proceeds.to_string(),
+            trx_hash: sale.trx_hash,
+            ordinal: sale.ordinal,
+        });
+    }
+
+    Ok(erc721::Pnl { realized })
+}
+
+/// Store the realized profit of each holder, in wei
+#[substreams::handlers::store]
+fn store_realized_pnl(pnl: erc721::Pnl, s: StoreAddBigInt) {
+    for record in pnl.realized {
+        if let Ok(profit) = record.profit.parse::<substreams::scalar::BigInt>() {
+            s.add(record.ordinal, keys::key(keys::REALIZED).hex(&record.holder), profit);
+        }
+    }
+}
+
+/// Store the cost basis of the tokens each holder still owns from ETH purchases, in wei
+#[substreams::handlers::store]
+fn store_cost_basis(sales: erc721::Sales, pnl: erc721::Pnl, s: StoreAddBigInt) {
+    for sale in sales.sales {
+        if !sale.currency.is_empty() {
+            continue;
+        }
+        if let Ok(price) = sale.price.parse::<substreams::scalar::BigInt>() {
+            s.add(sale.ordinal, cost_basis_key(&sale.buyer), price);
+        }
+    }
+
+    for record in pnl.realized {
+        if let Ok(cost) = record.cost.parse::<substreams::scalar::BigInt>() {
+            s.add(record.ordinal, cost_basis_key(&record.holder), cost.neg());
+        }
+    }
+}
+
+fn cost_basis_key(holder: &[u8]) -> String {
+    keys::key(keys::COST_BASIS).hex(holder).into()
+}
+
+/// Store the lowest ETH sale price of every window of `window` blocks
+#[substreams::handlers::store]
+fn store_floor_price(params: Params, clock: Clock, sales: erc721::Sales, s: StoreMinBigInt) {
+    for (ordinal, key, price) in floor_prices(&sales, floor_window(&params), clock.number) {
+        s.min(ordinal, key, price);
+    }
+}
+
+/// Candidate floor prices of the ETH sales as `(ordinal, key, price)`, keyed by the window of the block
+fn floor_prices(sales: &erc721::Sales, window: u64, block: u64) -> Vec<(u64, String, substreams::scalar::BigInt)> {
+    sales
+        .sales
+        .iter()
+        .filter(|sale| sale.currency.is_empty())
+        .filter_map(|sale| {
+            let price = sale.price.parse::<substreams::scalar::BigInt>().ok()?;
+            Some((sale.ordinal, floor_key(block / window), price))
+        })
+        .collect()
+}
+
+/// Store the highest ETH sale price of every collection and of every token
+#[substreams::handlers::store]
+fn store_max_sale_price(sales: erc721::Sales, s: StoreMaxBigInt) {
+    for sale in sales.sales {
+        if let Some(price) = eth_sale_price(&sale) {
+            for key in sale_price_keys(&sale) {
+                s.max(sale.ordinal, key, &price);
+            }
+        }
+    }
+}
+
+/// Store the lowest ETH sale price of every collection and of every token
+#[substreams::handlers::store]
+fn store_min_sale_price(sales: erc721::Sales, s: StoreMinBigInt) {
+    for sale in sales.sales {
+        if let Some(price) = eth_sale_price(&sale) {
+            for key in sale_price_keys(&sale) {
+                s.min(sale.ordinal, key, &price);
+            }
+        }
+    }
+}
+
+/// Price of a sale paid in ETH, prices in other currencies cannot be compared with it
+fn eth_sale_price(sale: &erc721::Sale) -> Option<substreams::scalar::BigInt> {
+    if !sale.currency.is_empty() {
+        return None;
+    }
+    sale.price.parse().ok()
+}
+
+/// Keys of the price records of the collection, `collection:<contract>`, and of the token, `nft:<token_id>:<contract>`
+fn sale_price_keys(sale: &erc721::Sale) -> [String; 2] {
+    [
+        keys::key(keys::COLLECTION).hex(&sale.collection).into(),
+        keys::key(keys::NFT).number(sale.token_id).hex(&sale.collection).into(),
+    ]
+}
+
+/// Store every address that ever held a TRACKED_CONTRACT token, appended on its first acquisition
+#[substreams::handlers::store]
+fn store_holders(first_acquisitions: Deltas<DeltaInt64>, s: StoreAppend<String>) {
+    for delta in first_acquisitions.deltas {
+        if delta.operation != substreams::pb::substreams::store_delta::Operation::Create {
+            continue;
+        }
+        if let Some(holder) = keys::parse(&delta.key).and_then(|key| key.raw(0)) {
+            s.append(delta.ordinal, "holders", holder.to_string());
+        }
+    }
+}
+
+/// Store every TRACKED_CONTRACT token id store_token_owners recorded, appended when it first receives an owner
+#[substreams::handlers::store]
+fn store_token_ids(owners: Deltas<DeltaProto<erc721::TokenOwner>>, s: StoreAppend<String>) {
+    for delta in owners.deltas {
+        if delta.operation != substreams::pb::substreams::store_delta::Operation::Create {
+            continue;
+        }
+        if let Some(token_id) = keys::parse(&delta.key).filter(|key| key.is(keys::NFT)).and_then(|key| key.raw(0)) {
+            s.append(delta.ordinal, "tokens", token_id.to_string());
+        }
+    }
+}
+
+/// Extracts every `every` blocks the holder inventories marked to the floor price, with their unrealized profit
+#[substreams::handlers::map]
+fn map_valuations(
+    params: Params,
+    clock: Clock,
+    holders: StoreGetArray<String>,
+    balances: StoreGetInt64,
+    cost_basis: StoreGetBigInt,
+    floor: StoreGetBigInt,
+) -> Result<erc721::Valuations, substreams::errors::Error> {
+    use substreams::scalar::BigInt;
+
+    let every: u64 = params.get("every").and_then(|value| value.parse().ok()).unwrap_or(7200);
+    let mut valuations = erc721::Valuations {
+        block: clock.number,
+        ..Default::default()
+    };
+    if every == 0 || clock.number % every != 0 {
+        return Ok(valuations);
+    }
+
+    let window = clock.number / floor_window(&params);
+    let floor_price = match floor
+        .get_last(floor_key(window.saturating_sub(1)))
+        .or_else(|| floor.get_last(floor_key(window)))
+    {
+        Some(floor_price) => floor_price,
+        None => return Ok(valuations),
+    };
+    valuations.floor_price = floor_price.to_string();
+
+    for holder in holders.get_last("holders").unwrap_or_default() {
+        let holder = match hex::decode(&holder) {
+            Ok(holder) => holder,
+            Err(_) => continue,
+        };
+        let balance = balances.get_last(generate_key(&holder)).unwrap_or(0);
+        if balance <= 0 {
+            continue;
+        }
+
+        let value = floor_price.clone() * BigInt::from(balance);
+        let cost = cost_basis.get_last(cost_basis_key(&holder)).unwrap_or_else(BigInt::zero);
+        valuations.holders.push(erc721::HolderValuation {
+            holder,
+            balance,
+            unrealized_pnl: (value.clone() - cost.clone()).to_string(),
+            value: value.to_string(),
+            cost_basis: cost.to_string(),
+        });
+    }
+
+    Ok(valuations)
+}
+
+/// Store the number of holders of the tracked collection, updated from the balance changes of store_transfers
+#[substreams::handlers::store]
+fn store_holder_count(balances: Deltas<DeltaInt64>, s: StoreAddInt64) {
+    for delta in balances.deltas {
+        let change = audit::holder_count_change(delta.old_value, delta.new_value);
+        if change != 0 {
+            s.add(delta.ordinal, audit::holder_count_key(), change);
+        }
+    }
+}
+
+/// Extracts every `every` blocks the holder count recomputed from the balances of every address that ever held a
+/// token, along with its drift from the incremental store_holder_count
+#[substreams::handlers::map]
+fn map_holder_audit(
+    params: Params,
+    clock: Clock,
+    holder_count: StoreGetInt64,
+    owners: StoreGetProto<erc721::TokenOwner>,
+    token_ids: StoreGetArray<String>,
+    holders: StoreGetArray<String>,
+    balances: StoreGetInt64,
+) -> Result<erc721::HolderAudit, substreams::errors::Error> {
+    let every: u64 = params.get("every").and_then(|value| value.parse().ok()).unwrap_or(7200);
+    let mut audit = erc721::HolderAudit {
+        block: clock.number,
+        ..Default::default()
+    };
+    if every == 0 || clock.number % every != 0 {
+        return Ok(audit);
+    }
+
+    let token_ids = token_ids.get_last("tokens").unwrap_or_default();
+    let owners = audit::owners(&token_ids, |token_id| owners.get_last(token_key(token_id)).map(|owner| owner.owner));
+    let holders = holders.get_last("holders").unwrap_or_default();
+    let balance = |holder: &[u8]| balances.get_last(generate_key(&holder.to_vec())).unwrap_or(0);
+    let recount = audit::recount(owners.iter().map(Vec::as_slice), &holders, balance);
+    audit.audited = true;
+    audit.incremental = holder_count.get_last(audit::holder_count_key()).unwrap_or(0);
+    audit.recomputed = recount.holders;
+    audit.drift = audit.incremental - recount.holders;
+    audit.negative_balances = recount.negative_balances;
+    audit.mismatched_balances = recount.mismatched_balances;
+    if audit.drift != 0 || audit.mismatched_balances != 0 {
+        logging::warning!(
+            "nft",
+            "holder count drifted by {} with {} mismatched balances at block {}",
+            audit.drift,
+            audit.mismatched_balances,
+            clock.number
+        );
+    }
+    Ok(audit)
+}
+
+/// Store the number of ETH sales of every window of `window` blocks by price bucket, a histogram map_market_stats
+/// estimates price quantiles from
+#[substreams::handlers::store]
+fn store_sale_histogram(params: Params, clock: Clock, sales: erc721::Sales, s: StoreAddInt64) {
+    let window = floor_window(&params);
+    for sale in sales.sales {
+        if let Some(bucket) = eth_sale_price(&sale).as_ref().and_then(histogram::bucket) {
+            s.add(sale.ordinal, histogram::bucket_key(clock.number / window, bucket), 1);
+        }
+    }
+}
+
+/// Extracts the sale count, median and p90 ETH sale prices of a window of `window` blocks once it is complete
+#[substreams::handlers::map]
+fn map_market_stats(
+    params: Params,
+    clock: Clock,
+    histogram: StoreGetInt64,
+) -> Result<erc721::MarketStats, substreams::errors::Error> {
+    let window = floor_window(&params);
+    let mut stats = erc721::MarketStats {
+        block: clock.number,
+        ..Default::default()
+    };
+    if clock.number % window != 0 || clock.number < window {
+        return Ok(stats);
+    }
+
+    stats.window = clock.number / window - 1;
+    let counts: Vec<(u32, i64)> = (0..histogram::BUCKETS)
+        .filter_map(|bucket| {
+            let count = histogram.get_last(histogram::bucket_key(stats.window, bucket))?;
+            Some((bucket, count))
+        })
+        .collect();
+    stats.sales = counts.iter().map(|(_, count)| count).sum();
+    let price = |q| histogram::quantile(&counts, q).map(|price| format!("{:.0}", price)).unwrap_or_default();
+    stats.median_price = price(0.5);
+    stats.p90_price = price(0.9);
+    Ok(stats)
+}
+
+fn floor_window(params: &Params) -> u64 {
+    params.get("window").and_then(|value| value.parse().ok()).filter(|window| *window > 0).unwrap_or(7200)
+}
+
+fn floor_key(window: u64) -> String {
+    keys::key(keys::FLOOR).number(window).into()
+}
+
+/// Extracts the decoded function calls made to the contract, including internal calls from other contracts, with the
+/// fields listed in the `fields` param
+#[substreams::handlers::map]
+fn map_contract_calls(params: Params, blk: eth::Block) -> Result<erc721::ContractCalls, substreams::errors::Error> {
+    let mut calls: Vec<_> = blk
+        .calls()
+        .filter(|view| view.call.address == TRACKED_CONTRACT && !view.call.state_reverted)
+        .filter_map(|view| calls::decode(view.transaction, view.call))
+        .collect();
+    projection::project(&params, &mut calls);
+    Ok(erc721::ContractCalls { calls })
+}
+
+/// Extracts the TRACKED_CONTRACT transfer logs whose receipt disagrees with the call trace, to verify
+/// no log from a reverted call leaks into map_transfers
+#[substreams::handlers::map]
+fn map_revert_audit(blk: eth::Block) -> Result<erc721::RevertAudit, substreams::errors::Error> {
+    use erc721::log_discrepancy::Kind;
+
+    let mut discrepancies = vec![];
+    for trx in &blk.transaction_traces {
+        let receipt = match &trx.receipt {
+            Some(receipt) => receipt,
+            None => continue,
+        };
+
+        for log in &receipt.logs {
+            if log.address != TRACKED_CONTRACT || transfers::decode(log).is_none() {
+                continue;
+            }
+
+            let kind = if trx.status != eth::TransactionTraceStatus::Succeeded as i32 {
+                Kind::FailedTransaction
+            } else {
+                let call = trx
+                    .calls
+                    .iter()
+                    .find(|call| call.logs.iter().any(|call_log| call_log.block_index == log.block_index));
+                match call {
+                    Some(call) if call.state_reverted => Kind::RevertedCall,
+                    Some(_) => continue,
+                    None => Kind::MissingCall,
+                }
+            };
+
+            logging::warning!("audit", "log discrepancy in {} at {}", Hex(&trx.hash), log.block_index);
+            discrepancies.push(erc721::LogDiscrepancy {
+                kind: kind as i32,
+                trx_hash: trx.hash.clone(),
+                block_index: log.block_index,
+                trx_status: trx.status,
+            });
+        }
+    }
+
+    Ok(erc721::RevertAudit { discrepancies })
+}
+
+/// Extracts the transfers left once the no-op transfers selected by the `drop_self_transfers` and
+/// `drop_round_trips` params are dropped
+#[substreams::handlers::map]
+fn map_transfers_filtered(
+    params: Params,
+    transfers: erc721::Transfers,
+) -> Result<erc721::FilteredTransfers, substreams::errors::Error> {
+    let drop_self_transfers = params.flag("drop_self_transfers");
+    let drop_round_trips = params.flag("drop_round_trips");
+    let mut filtered = erc721::FilteredTransfers::default();
+
+    let mut round_trips: Vec<(Vec<u8>, u64)> = vec![];
+    if drop_round_trips {
+        for transfer in &transfers.transfers {
+            let key = (transfer.trx_hash.clone(), transfer.token_id);
+            if round_trips.contains(&key) {
+                continue;
+            }
+
+            let moves: Vec<&erc721::Transfer> = transfers
+                .transfers
+                .iter()
+                .filter(|other| other.trx_hash == key.0 && other.token_id == key.1)
+                .collect();
+            if moves.len() > 1 && moves[0].from == moves[moves.len() - 1].to {
+                round_trips.push(key);
+            }
+        }
+    }
+
+    for transfer in transfers.transfers {
+        if drop_self_transfers && transfer.from == transfer.to {
+            filtered.dropped_self_transfers += 1;
+            continue;
+        }
+
+        if round_trips.contains(&(transfer.trx_hash.clone(), transfer.token_id)) {
+            filtered.dropped_round_trips += 1;
+            continue;
+        }
+
+        filtered.transfers.push(transfer);
+    }
+
+    Ok(filtered)
+}
+
+/// Store the number of transfers dropped by map_transfers_filtered, by reason
+#[substreams::handlers::store]
+fn store_dropped_transfers(filtered: erc721::FilteredTransfers, s: StoreAddInt64) {
+    if filtered.dropped_self_transfers > 0 {
+        s.add(0, "dropped:self_transfers", filtered.dropped_self_transfers as i64);
+    }
+    if filtered.dropped_round_trips > 0 {
+        s.add(0, "dropped:round_trips", filtered.dropped_round_trips as i64);
+    }
+}
+
+/// Store the number of transfers of each TRACKED_CONTRACT token
+#[substreams::handlers::store]
+fn store_token_transfer_count(transfers: erc721::Transfers, s: StoreAddInt64) {
+    for transfer in transfers.transfers {
+        s.add(transfer.ordinal, token_key(transfer.token_id), 1);
+    }
+}
+
+/// Extracts the cumulative transfer count and last activity block of the tokens transferred in the block
+#[substreams::handlers::map]
+fn map_token_activity(
+    clock: Clock,
+    transfers: erc721::Transfers,
+    counts: StoreGetInt64,
+) -> Result<erc721::TokenActivities, substreams::errors::Error> {
+    let mut activities: Vec<erc721::TokenActivity> = vec![];
+    for transfer in transfers.transfers {
+        if activities.iter().any(|activity| activity.token_id == transfer.token_id) {
+            continue;
+        }
+
+        activities.push(erc721::TokenActivity {
+            token_id: transfer.token_id,
+            transfer_count: counts.get_last(token_key(transfer.token_id)).unwrap_or(0),
+            last_block: clock.number,
+            last_timestamp: clock.timestamp.clone(),
+        });
+    }
+
+    Ok(erc721::TokenActivities { activities })
+}
+
+fn token_key(token_id: u64) -> String {
+    keys::key(keys::NFT).number(token_id).hex(&TRACKED_CONTRACT).into()
+}
+
+/// Store the number of transfers of every token id and every holder, which places their transfers in
+/// store_recent_activity
+#[substreams::handlers::store]
+fn store_activity_counts(transfers: erc721::Transfers, s: StoreAddInt64) {
+    for transfer in transfers.transfers {
+        for subject in activity::subjects(&transfer) {
+            s.add(transfer.ordinal, subject, 1);
+        }
+    }
+}
+
+/// Store the last transfers of every token id and every holder as compact entries, in generations of `recent`
+/// entries per key
+#[substreams::handlers::store]
+fn store_recent_activity(
+    params: Params,
+    clock: Clock,
+    transfers: erc721::Transfers,
+    counts: StoreGetInt64,
+    s: StoreAppend<String>,
+) {
+    let size = activity::size(&params);
+    let count = |subject: &str| counts.get_last(subject).unwrap_or(0);
+    for write in activity::writes(clock.number, &transfers.transfers, count, size) {
+        match write {
+            activity::Write::Expire { ordinal, key } => s.delete_prefix(ordinal as i64, &key),
+            activity::Write::Append { ordinal, key, entry } => s.append(ordinal, key, entry),
+        }
+    }
+}
+
+/// Store the owner of every TRACKED_CONTRACT token, with the block and time it received the token at
+#[substreams::handlers::store]
+fn store_token_owners(clock: Clock, transfers: erc721::Transfers, s: StoreSetProto<erc721::TokenOwner>) {
+    for transfer in transfers.transfers {
+        let owner = erc721::TokenOwner {
+            owner: transfer.to,
+            since_block: clock.number,
+            since: clock.timestamp.clone(),
+        };
+        s.set(transfer.ordinal, token_key(transfer.token_id), &owner);
+    }
+}
+
+/// Extracts the transfers of the block joined with store_token_owners and store_collection_names: the previous owner
+/// of every token, how long it held it and the name of its collection
+#[substreams::handlers::map]
+fn map_transfers_enriched(
+    clock: Clock,
+    transfers: erc721::Transfers,
+    owners: StoreGetProto<erc721::TokenOwner>,
+    names: StoreGetString,
+) -> Result<erc721::EnrichedTransfers, substreams::errors::Error> {
+    // owners are read as of the start of the block, then followed through the transfers of the block
+    let mut moved: BTreeMap<u64, erc721::TokenOwner> = BTreeMap::new();
+    let mut enriched = Vec::with_capacity(transfers.transfers.len());
+    for transfer in transfers.transfers {
+        let previous = match moved.remove(&transfer.token_id) {
+            Some(owner) => Some(owner),
+            None => owners.get_first(token_key(transfer.token_id)),
+        };
+        let previous = previous.filter(|_| transfer.from != NULL_ADDRESS);
+
+        let seconds = |timestamp: &Option<prost_types::Timestamp>| timestamp.as_ref().map_or(0, |time| time.seconds);
+        let (previous_owner, holding_blocks, holding_seconds) = match previous {
+            Some(owner) => (
+                owner.owner,
+                clock.number.saturating_sub(owner.since_block),
+                (seconds(&clock.timestamp) - seconds(&owner.since)).max(0),
+            ),
+            None => (vec![], 0, 0),
+        };
+
+        moved.insert(
+            transfer.token_id,
+            erc721::TokenOwner {
+                owner: transfer.to.clone(),
+                since_block: clock.number,
+                since: clock.timestamp.clone(),
+            },
+        );
+        enriched.push(erc721::EnrichedTransfer {
+            collection_name: names.get_last(collection_name_key(&transfer.contract)).unwrap_or_default(),
+            transfer: Some(transfer),
+            previous_owner,
+            holding_blocks,
+            holding_seconds,
+        });
+    }
+
+    Ok(erc721::EnrichedTransfers { transfers: enriched })
+}
+
+/// Extracts tokens moving in and out of the fractionalizer vaults listed in the `vaults` param
+#[substreams::handlers::map]
+fn map_fractional_events(
+    params: Params,
+    transfers: erc721::Transfers,
+) -> Result<erc721::FractionalEvents, substreams::errors::Error> {
+    let vaults = fractional::vaults_from_params(&params);
+    let mut events = vec![];
+
+    for transfer in transfers.transfers {
+        if let Some(vault) = fractional::find(&vaults, &transfer.to) {
+            events.push(erc721::FractionalEvent {
+                id: format!("{}-fractionalized", transfer.id),
+                kind: erc721::fractional_event::Kind::Fractionalized as i32,
+                vault: vault.address.clone(),
+                vault_token: vault.token.clone(),
+                holder: transfer.from.clone(),
+                token_id: transfer.token_id,
+                trx_hash: transfer.trx_hash.clone(),
+                ordinal: transfer.ordinal,
+            });
+        }
+
+        if let Some(vault) = fractional::find(&vaults, &transfer.from) {
+            events.push(erc721::FractionalEvent {
+                id: format!("{}-redeemed", transfer.id),
+                kind: erc721::fractional_event::Kind::Redeemed as i32,
+                vault: vault.address.clone(),
+                vault_token: vault.token.clone(),
+                holder: transfer.to.clone(),
+                token_id: transfer.token_id,
+                trx_hash: transfer.trx_hash.clone(),
+                ordinal: transfer.ordinal,
+            });
+        }
+    }
+
+    Ok(erc721::FractionalEvents { events })
+}
+
+/// Store the number of TRACKED_CONTRACT tokens locked in each fractionalizer vault
+#[substreams::handlers::store]
+fn store_fractionalized(events: erc721::FractionalEvents, s: StoreAddInt64) {
+    for event in events.events {
+        let key = keys::key(keys::VAULT).hex(&event.vault).hex(&TRACKED_CONTRACT);
+        if event.kind == erc721::fractional_event::Kind::Fractionalized as i32 {
+            s.add(event.ordinal, key, 1);
+        } else if event.kind == erc721::fractional_event::Kind::Redeemed as i32 {
+            s.add(event.ordinal, key, -1);
+        }
+    }
+}
+
+/// Extracts tokens deposited into and withdrawn from the custodial contracts listed in the `custodians` param
+#[substreams::handlers::map]
+fn map_custody_events(
+    params: Params,
+    transfers: erc721::Transfers,
+) -> Result<erc721::CustodyEvents, substreams::errors::Error> {
+    let custodians = params.addresses("custodians");
+    let mut events = vec![];
+
+    for transfer in transfers.transfers {
+        if custodians.contains(&transfer.from) {
+            events.push(erc721::CustodyEvent {
+                id: format!("{}-withdrawal", transfer.id),
+                kind: erc721::custody_event::Kind::Withdrawal as i32,
+                custodian: transfer.from.clone(),
+                account: transfer.to.clone(),
+                token_id: transfer.token_id,
+                trx_hash: transfer.trx_hash.clone(),
+                ordinal: transfer.ordinal,
+            });
+        }
+
+        if custodians.contains(&transfer.to) {
+            events.push(erc721::CustodyEvent {
+                id: format!("{}-deposit", transfer.id),
+                kind: erc721::custody_event::Kind::Deposit as i32,
+                custodian: transfer.to.clone(),
+                account: transfer.from.clone(),
+                token_id: transfer.token_id,
+                trx_hash: transfer.trx_hash.clone(),
+                ordinal: transfer.ordinal,
+            });
+        }
+    }
+
+    Ok(erc721::CustodyEvents { events })
+}
+
+/// Store the depositor of each TRACKED_CONTRACT token held by a custodial contract
+#[substreams::handlers::store]
+fn store_custody(events: erc721::CustodyEvents, s: StoreSetRaw) {
+    for event in events.events {
+        if event.kind == erc721::custody_event::Kind::Deposit as i32 {
+            s.set(event.ordinal, custody_key(&event.custodian, event.token_id), &event.account);
+        }
+    }
+}
+
+/// Store the balance of TRACKED_CONTRACT tokens by holder, counting tokens held by custodial contracts
+/// towards their depositor
+#[substreams::handlers::store]
+fn store_effective_balances(
+    transfers: erc721::Transfers,
+    events: erc721::CustodyEvents,
+    custody: StoreGetRaw,
+    s: StoreAddInt64,
+) {
+    for transfer in transfers.transfers {
+        let deposit = events.events.iter().find(|event| {
+            event.ordinal == transfer.ordinal && event.kind == erc721::custody_event::Kind::Deposit as i32
+        });
+        let withdrawal = events.events.iter().find(|event| {
+            event.ordinal == transfer.ordinal && event.kind == erc721::custody_event::Kind::Withdrawal as i32
+        });
+
+        // a deposit leaves the token with its depositor
+        if deposit.is_some() {
+            continue;
+        }
+
+        let from = match withdrawal {
+            // tokens deposited before the start block have no known depositor
+            Some(event) => match custody.get_at(event.ordinal, custody_key(&event.custodian, event.token_id)) {
+                Some(depositor) => depositor,
+                None => NULL_ADDRESS.to_vec(),
+            },
+            None => transfer.from.clone(),
+        };
+
+        if from == transfer.to {
+            continue;
+        }
+
+        if from != NULL_ADDRESS {
+            s.add(transfer.ordinal, generate_key(&from), -1);
+        }
+
+        if transfer.to != NULL_ADDRESS {
+            s.add(transfer.ordinal, generate_key(&transfer.to), 1);
+        }
+    }
+}
+
+fn custody_key(custodian: &[u8], token_id: u64) -> String {
+    keys::key(keys::CUSTODY).hex(custodian).number(token_id).into()
+}
+
+/// Store the Sudoswap pools trading TRACKED_CONTRACT tokens, with the block they were created at
+#[substreams::handlers::store]
+fn store_sudoswap_pools(blk: eth::Block, s: StoreSetInt64) {
+    for (pair, log) in blk.events::<abi::sudoswap_factory::events::NewPair>(&[&sudoswap::FACTORY]) {
+        let nft = abi::sudoswap_pair::functions::Nft {}.call(pair.pool_address.clone());
+        if nft.as_deref() == Some(&TRACKED_CONTRACT[..]) {
+            logging::info!("marketplaces", "Sudoswap pool created {}", Hex(&pair.pool_address));
+            s.set(log.ordinal(), sudoswap::pool_key(&pair.pool_address), &(blk.number as i64));
+        }
+    }
+}
+
+/// Extracts the activity of the Sudoswap pools trading TRACKED_CONTRACT tokens
+#[substreams::handlers::map]
+fn map_sudoswap_events(
+    blk: eth::Block,
+    pools: StoreGetInt64,
+) -> Result<erc721::PoolEvents, substreams::errors::Error> {
+    use abi::sudoswap_factory::events as factory;
+    use abi::sudoswap_pair::events as pair;
+    use erc721::pool_event::Kind;
+
+    let mut events = vec![];
+    for log in blk.logs() {
+        let (kind, pool, amount) = if log.address() == sudoswap::FACTORY {
+            if let Some(event) = factory::NewPair::match_and_decode(log.log) {
+                (Kind::PoolCreated, event.pool_address, String::new())
+            } else if let Some(event) = factory::NftDeposit::match_and_decode(log.log) {
+                (Kind::NftDeposit, event.pool_address, String::new())
+            } else if let Some(event) = factory::TokenDeposit::match_and_decode(log.log) {
+                (Kind::TokenDeposit, event.pool_address, String::new())
+            } else {
+                continue;
+            }
+        } else {
+            let pool = log.address().to_vec();
+            if pair::SwapNftInPair::match_log(log.log) {
+                (Kind::SwapNftIn, pool, String::new())
+            } else if pair::SwapNftOutPair::match_log(log.log) {
+                (Kind::SwapNftOut, pool, String::new())
+            } else if pair::NftWithdrawal::match_log(log.log) {
+                (Kind::NftWithdrawal, pool, String::new())
+            } else if let Some(event) = pair::TokenDeposit::match_and_decode(log.log) {
+                (Kind::TokenDeposit, pool, event.amount.to_string())
+            } else if let Some(event) = pair::TokenWithdrawal::match_and_decode(log.log) {
+                (Kind::TokenWithdrawal, pool, event.amount.to_string())
+            } else if let Some(event) = pair::SpotPriceUpdate::match_and_decode(log.log) {
+                (Kind::SpotPriceUpdate, pool, event.new_spot_price.to_string())
+            } else {
+                continue;
+            }
+        };
+
+        // pools created in this block are only visible through ordinal lookups
+        if pools.get_at(log.ordinal(), sudoswap::pool_key(&pool)).is_none() {
+            continue;
+        }
+
+        events.push(erc721::PoolEvent {
+            id: eth_utils::record_id(&log.receipt.transaction.hash, log.block_index()),
+            kind: kind as i32,
+            pool,
+            amount,
+            trx_hash: log.receipt.transaction.hash.clone(),
+            ordinal: log.ordinal(),
+        });
+    }
+
+    Ok(erc721::PoolEvents { events })
+}
+
+/// Store the counter of the Seaport offerers that cancelled their open orders by incrementing it, the others are at 0
+#[substreams::handlers::store]
+fn store_seaport_counters(blk: eth::Block, s: StoreSetBigInt) {
+    for trx in blk.transactions() {
+        for log in trx.receipt().logs() {
+            if let Some((offerer, counter)) = seaport::decode_counter_incremented(log.log) {
+                s.set(log.ordinal(), seaport::counter_key(&offerer), &counter);
+            }
+        }
+    }
+}
+
+/// Extracts sales of TRACKED_CONTRACT tokens, from Seaport fills and from Sudoswap pool swaps whose prices are implied
+/// from the bonding curve
+#[substreams::handlers::map]
+fn map_sales(
+    blk: eth::Block,
+    pools: StoreGetInt64,
+    counters: StoreGetBigInt,
+) -> Result<erc721::Sales, substreams::errors::Error> {
+    use abi::sudoswap_pair::events as pair;
+
+    let timestamp = eth_utils::block_timestamp(&blk);
+    let mut sales = vec![];
+    // every sale moves a TRACKED_CONTRACT token
+    for trx in eth_utils::transactions_matching(&blk, &[&TRACKED_CONTRACT]) {
+        let mut swapped: Vec<Vec<u8>> = vec![];
+        for log in trx.receipt().logs() {
+            if !pair::SwapNftInPair::match_log(log.log) && !pair::SwapNftOutPair::match_log(log.log) {
+                continue;
+            }
+            if swapped.iter().any(|pool| pool == log.address()) {
+                continue;
+            }
+            if pools.get_at(log.ordinal(), sudoswap::pool_key(log.address())).is_some() {
+                swapped.push(log.address().to_vec());
+            }
+        }
+
+        for pool in swapped {
+            let transfers: Vec<_> = trx
+                .receipt()
+                .logs()
+                .filter(|log| log.address() == TRACKED_CONTRACT)
+                .filter_map(|log| {
+                    transfers::decode(log.log).map(|transfer| (transfer, log.ordinal(), log.index(), log.block_index()))
+                })
+                .filter(|(transfer, _, _, _)| transfer.from == pool || transfer.to == pool)
+                .collect();
+            if transfers.is_empty() {
+                continue;
+            }
+
+            let (total, currency) = match sudoswap::pool_payment(trx, &pool) {
+                Some(payment) => payment,
+                None => {
+                    logging::warning!("marketplaces", "no payment found for Sudoswap swap {}", Hex(&trx.hash));
+                    continue;
+                }
+            };
+            let price = total / substreams::scalar::BigInt::from(transfers.len() as u64);
+
+            for (transfer, ordinal, log_index, block_index) in transfers {
+                let token_id = match token_id(&transfer.token_id, &trx.hash) {
+                    Some(token_id) => token_id,
+                    None => continue,
+                };
+                sales.push(erc721::Sale {
+                    id: format!("{}-{}", eth_utils::record_id(&trx.hash, block_index), token_id),
+                    marketplace: "sudoswap".to_string(),
+                    collection: TRACKED_CONTRACT.to_vec(),
+                    token_id,
+                    seller: transfer.from,
+                    buyer: transfer.to,
+                    price: price.to_string(),
+                    currency: currency.clone(),
+                    trx_hash: trx.hash.clone(),
+                    ordinal,
+                    log_index,
+                    timestamp: timestamp.clone(),
+                    ..Default::default()
+                });
+            }
+        }
+
+        let counter = |offerer: &[u8], ordinal: u64| {
+            counters.get_at(ordinal, seaport::counter_key(offerer)).unwrap_or_else(substreams::scalar::BigInt::zero)
+        };
+        sales.extend(seaport_sales(trx, &timestamp, counter));
+    }
+
+    Ok(erc721::Sales { sales })
+}
+
+/// Sales of TRACKED_CONTRACT tokens filled on Seaport, listings as well as accepted offers. The order hash of the
+/// event is checked against the ones recomputed from the fulfillment calls of the transaction, with the counter of the
+/// offerer at an ordinal given by `counter`.
+fn seaport_sales(
+    trx: &eth::TransactionTrace,
+    timestamp: &Option<prost_types::Timestamp>,
+    counter: impl Fn(&[u8], u64) -> substreams::scalar::BigInt,
+) -> Vec<erc721::Sale> {
+    let mut sales = vec![];
+    let mut recomputed: Option<Vec<Vec<u8>>> = None;
+    for log in trx.receipt().logs() {
+        let order = match seaport::decode_order_fulfilled(log.log) {
+            Some(order) => order,
+            None => continue,
+        };
+
+        let tracked = |items: &[seaport::Item]| -> Vec<u64> {
+            items
+                .iter()
+                .filter(|item| item.item_type == seaport::ERC721 && item.token == TRACKED_CONTRACT)
+                .filter_map(|item| token_id(&item.identifier, &trx.hash))
+                .collect()
+        };
+        let payment = |items: &[seaport::Item]| {
+            let paid: Vec<_> = items
+                .iter()
+                .filter(|item| item.item_type == seaport::NATIVE || item.item_type == seaport::ERC20)
+                .collect();
+            let total = paid
+                .iter()
+                .fold(substreams::scalar::BigInt::zero(), |total, item| total + item.start_amount.clone());
+            let currency = match paid.first() {
+                Some(item) if item.item_type == seaport::ERC20 => item.token.clone(),
+                _ => vec![],
+            };
+            (total, currency)
+        };
+
+        let (token_ids, seller, buyer, (total, currency)) = if !tracked(&order.offer).is_empty() {
+            (tracked(&order.offer), &order.offerer, &order.recipient, payment(&order.consideration))
+        } else if !tracked(&order.consideration).is_empty() {
+            (tracked(&order.consideration), &order.recipient, &order.offerer, payment(&order.offer))
+        } else {
+            continue;
+        };
+
+        let hashes = recomputed.get_or_insert_with(|| {
+            trx.calls
+                .iter()
+                .filter(|call| !call.state_reverted)
+                .filter_map(|call| seaport::order_hash(call, |offerer| counter(offerer, call.begin_ordinal)))
+                .collect()
+        });
+        if !hashes.is_empty() && !hashes.contains(&order.order_hash) {
+            logging::warning!("marketplaces", "recomputed Seaport order hash differs from {}", Hex(&order.order_hash));
+        }
+
+        let count = substreams::scalar::BigInt::from(token_ids.len() as u64);
+        let royalties: Vec<erc721::Royalty> = order
+            .royalties(seller)
+            .into_iter()
+            .map(|(currency, amount)| erc721::Royalty {
+                currency,
+                amount: (amount / count.clone()).to_string(),
+            })
+            .collect();
+        let royalty = royalties
+            .iter()
+            .find(|royalty| royalty.currency == currency)
+            .map_or_else(|| "0".to_string(), |royalty| royalty.amount.clone());
+        let price = total / count;
+        for token_id in token_ids {
+            sales.push(erc721::Sale {
+                id: format!("{}-{}", eth_utils::record_id(&trx.hash, log.block_index()), token_id),
+                marketplace: "seaport".to_string(),
+                collection: TRACKED_CONTRACT.to_vec(),
+                token_id,
+                seller: seller.clone(),
+                buyer: buyer.clone(),
+                price: price.to_string(),
+                currency: currency.clone(),
+                trx_hash: trx.hash.clone(),
+                ordinal: log.ordinal(),
+                log_index: log.index(),
+                timestamp: timestamp.clone(),
+                order_hash: order.order_hash.clone(),
+                royalty: royalty.clone(),
+                royalties: royalties.clone(),
+            });
+        }
+    }
+    sales
+}
+
+const INITIALIZE_METHOD_HASH: [u8; 4] = hex!("1459457a");
+
+#[substreams::handlers::map]
+fn map_tokens(blk: eth::Block) -> Result<pb::tokens::Tokens, substreams::errors::Error> {
+    let (tokens, rejections, rpc) = discover_tokens(&Substreams, &blk);
+    Ok(pb::tokens::Tokens {
+        tokens,
+        rpc_calls: rpc.calls,
+        rpc_failures: rpc.failures,
+        rejections,
+    })
+}
+
+/// Emits the token candidates of map_tokens that got rejected, with the reason and the probe that failed
+#[substreams::handlers::map]
+fn map_token_rejections(tokens: pb::tokens::Tokens) -> Result<pb::tokens::TokenRejections, substreams::errors::Error> {
+    Ok(pb::tokens::TokenRejections {
+        rejections: tokens.rejections,
+        rpc_calls: tokens.rpc_calls,
+        rpc_failures: tokens.rpc_failures,
+    })
+}
+
+/// Contract call of the block worth probing for a token, along with its transaction
+type TokenCandidate<'a> = (&'a eth::TransactionTrace, &'a eth::Call);
+
+/// Contracts created or initialized through a proxy in the block, along with the ones rejected before probing them
+fn token_candidates(blk: &eth::Block) -> (Vec<TokenCandidate>, Vec<pb::tokens::TokenRejection>) {
+    use pb::tokens::token_rejection::Reason;
+
+    let mut candidates = vec![];
+    let mut rejections = vec![];
+    for trx in &blk.transaction_traces {
+        for call in &trx.calls {
+            if call.state_reverted {
+                continue;
+            }
+            if call.call_type == eth::CallType::Create as i32 || call.call_type == eth::CallType::Call as i32 {
+                let call_input_len = call.input.len();
+                if call.call_type == eth::CallType::Call as i32
+                    && (call_input_len < 4 || call.input[0..4] != INITIALIZE_METHOD_HASH)
+                {
+                    // this will check if a proxy contract has been called to create a ERC20 contract.
+                    // if that is the case the Proxy contract will call the initialize function on the ERC20 contract
+                    // this is part of the OpenZeppelin Proxy contract standard
+                    continue;
+                }
+
+                let mut reject = |reason: Reason, detail: String| {
+                    rejections.push(pb::tokens::TokenRejection {
+                        address: call.address.clone(),
+                        reason: reason as i32,
+                        detail,
+                        caller: call.caller.clone(),
+                        trx_hash: trx.hash.clone(),
+                        ..Default::default()
+                    })
+                };
+
+                if call.call_type == eth::CallType::Create as i32 {
+                    let mut code_change_len = 0;
+                    for code_change in &call.code_changes {
+                        code_change_len += code_change.new_code.len()
+                    }
+
+                    if code_change_len <= 150 {
+                        // optimization to skip none viable SC
+                        reject(Reason::CodeTooSmall, format!("{} bytes of code", code_change_len));
+                        continue;
+                    }
+                }
+
+                if call.caller == hex!("0000000000004946c0e9f43f4dee607b0ef1fa1c")
+                    || call.caller == hex!("00000000687f5b66638856396bee28c1db0178d1")
+                {
+                    reject(Reason::KnownCaller, String::new());
+                    continue;
+                }
+
+                candidates.push((trx, call));
+            }
+        }
+    }
+    (candidates, rejections)
+}
+
+/// Tokens created or initialized through a proxy in the block, along with the candidates that were rejected
+fn discover_tokens(
+    client: &impl EthCall,
+    blk: &eth::Block,
+) -> (Vec<pb::tokens::Token>, Vec<pb::tokens::TokenRejection>, RpcCounters) {
+    let (candidates, mut rejections) = token_candidates(blk);
+    let mut tokens = vec![];
+    let mut rpc = RpcCounters::default();
+    for (trx, call) in candidates {
+        let implementation = eth_utils::proxy_implementation(trx, &call.address);
+        let mut token = match probe_token(client, &call.address, implementation.as_deref(), &mut rpc) {
+            Ok(token) => token,
+            Err(mut rejection) => {
+                rejection.caller = call.caller.clone();
+                rejection.trx_hash = trx.hash.clone();
+                rejections.push(rejection);
+                continue;
+            }
+        };
+        let origin = if call.call_type == eth::CallType::Create as i32 {
+            "contract creation".to_string()
+        } else {
+            selectors::describe(&call.input)
+        };
+        logging::debug!(
+            "tokens",
+            "discovered token {} through {}",
+            crypto::checksum_address(&token.address),
+            origin
+        );
+
+        token.deployer = trx.from.clone();
+        if let Some(code_change) = call.code_changes.iter().find(|change| change.address == call.address) {
+            token.code_hash = bytecode::code_hash(&code_change.new_code);
+            token.template = bytecode::template(&code_change.new_code).unwrap_or_default().to_string();
+            (token.can_mint_arbitrarily, token.has_blacklist) = bytecode::capabilities(&code_change.new_code);
+        }
+        tokens.push(token);
+    }
+    (tokens, rejections, rpc)
+}
+
+/// Probes `address` over RPC for the ERC20 decimals, name and symbol, returning why it is not a token otherwise.
+/// The contract URI of a proxy is read through the `implementation` it delegated to when the proxy has none.
+fn probe_token(
+    client: &impl EthCall,
+    address: &Vec<u8>,
+    implementation: Option<&[u8]>,
+    rpc: &mut RpcCounters,
+) -> Result<pb::tokens::Token, pb::tokens::TokenRejection> {
+    use pb::tokens::token_rejection::{Probe, Reason};
+
+    let rejection = |reason: Reason, probe: Probe, detail: String| pb::tokens::TokenRejection {
+        address: address.clone(),
+        reason: reason as i32,
+        probe: probe as i32,
+        detail,
+        ..Default::default()
+    };
+    // the runtime answers every call, a missing response is reported as a failed call rather than panicking
+    let succeeded = |responses: &[substreams_ethereum::pb::eth::rpc::RpcResponse], index: usize, probe: Probe| {
+        match responses.get(index) {
+            Some(response) if !response.failed => Ok(response.raw.clone()),
+            Some(response) => {
+                let error = String::from_utf8_lossy(&response.raw).to_string();
+                Err(rejection(Reason::CallFailed, probe, error))
+            }
+            None => Err(rejection(Reason::CallFailed, probe, "missing response".to_string())),
+        }
+    };
+
+    let rpc_call_decimal = create_rpc_calls(address, vec![rpc_utils::DECIMALS]);
+    let rpc_responses_unmarshalled_decimal: substreams_ethereum::pb::eth::rpc::RpcResponses =
+        client.eth_call(&rpc_call_decimal);
+    let response_decimal = rpc_responses_unmarshalled_decimal.responses;
+    rpc.record(&response_decimal);
+    let decimals_raw = succeeded(&response_decimal, 0, Probe::Decimals)?;
+
+    let decoded_decimals = match eth_utils::read_uint32(&decimals_raw) {
+        Ok(decimals) => decimals,
+        Err(error) => return Err(rejection(Reason::DecodeFailed, Probe::Decimals, error)),
+    };
+
+    let rpc_call_name_symbol = create_rpc_calls(address, vec![rpc_utils::NAME, rpc_utils::SYMBOL]);
+    let rpc_responses_unmarshalled: substreams_ethereum::pb::eth::rpc::RpcResponses =
+        client.eth_call(&rpc_call_name_symbol);
+    let responses = rpc_responses_unmarshalled.responses;
+    rpc.record(&responses);
+    logging::debug!(
+        "tokens",
+        "responses length: {}",
+        responses.len()
+    );
+    let name_raw = succeeded(&responses, 0, Probe::Name)?;
+    let symbol_raw = succeeded(&responses, 1, Probe::Symbol)?;
+
+    let raw_name = match eth_utils::read_string_bytes(&name_raw) {
+        Ok(name) => name,
+        Err(error) => return Err(rejection(Reason::DecodeFailed, Probe::Name, error)),
+    };
+
+    let raw_symbol = match eth_utils::read_string_bytes(&symbol_raw) {
+        Ok(symbol) => symbol,
+        Err(error) => return Err(rejection(Reason::DecodeFailed, Probe::Symbol, error)),
+    };
+
+    let decimals = decoded_decimals as u64;
+    let symbol = eth_utils::sanitize_metadata(&raw_symbol);
+    let name = eth_utils::sanitize_metadata(&raw_name);
+    logging::debug!(
+        "tokens",
+        "{} is an ERC20 token contract with name {}",
+        Hex(address),
+        name,
+    );
+    let mut token = pb::tokens::Token {
+        address: address.clone(),
+        is_lossy: eth_utils::is_lossy(&raw_name) || eth_utils::is_lossy(&raw_symbol),
+        name_raw_bytes: if name.as_bytes() != raw_name.as_slice() { raw_name } else { vec![] },
+        symbol_raw_bytes: if symbol.as_bytes() != raw_symbol.as_slice() { raw_symbol } else { vec![] },
+        name,
+        symbol,
+        decimals,
+        ..Default::default()
+    };
+    spam::score_metadata(&mut token);
+    (token.contract_uri, token.logo_uri) = metadata::uris(client, address, implementation, rpc);
+    Ok(token)
+}
+
+/// Extracts the metadata of the tokens of the collections listed in the `collections` param minted in the block, or
+/// of every ERC721 collection when `every_collection` is set, decoded when the collection is fully on-chain, with the
+/// fields listed in the `fields` param
+#[substreams::handlers::map]
+fn map_token_metadata(params: Params, blk: eth::Block) -> Result<erc721::TokenMetadatas, substreams::errors::Error> {
+    let collections = params.addresses("collections");
+    let every_collection = params.flag("every_collection");
+    if collections.is_empty() && !every_collection {
+        return Ok(erc721::TokenMetadatas::default());
+    }
+
+    let mut mints = vec![];
+    for trx in blk.transactions() {
+        for log in trx.receipt().logs() {
+            if !every_collection && !collections.iter().any(|collection| collection == log.address()) {
+                continue;
+            }
+            let transfer = match abi::erc721::events::Transfer::match_and_decode(log.log) {
+                Some(transfer) if transfer.from == NULL_ADDRESS => transfer,
+                _ => continue,
+            };
+            // token ids past 64 bits don't fit the record
+            if let Some(token_id) = eth_utils::to_u64(&transfer.token_id) {
+                let id = eth_utils::record_id(&trx.hash, log.block_index());
+                mints.push((log.address().to_vec(), token_id, trx.hash.clone(), log.ordinal(), id));
+            }
+        }
+    }
+
+    let mut rpc = RpcCounters::default();
+    let tokens: Vec<(Vec<u8>, u64)> =
+        mints.iter().map(|(contract, token_id, _, _, _)| (contract.clone(), *token_id)).collect();
+    let uris = rpc_utils::token_uris(&Substreams, &tokens, &mut rpc);
+
+    let mut records = vec![];
+    for ((contract, token_id, trx_hash, ordinal, id), token_uri) in mints.into_iter().zip(uris) {
+        let token_uri = token_uri.unwrap_or_default();
+        let mut token = match metadata::token_metadata(&token_uri) {
+            Some(decoded) => erc721::TokenMetadata {
+                on_chain: true,
+                ..decoded
+            },
+            None => erc721::TokenMetadata {
+                token_uri: if token_uri.len() > metadata::MAX_URI_BYTES { String::new() } else { token_uri },
+                ..Default::default()
+            },
+        };
+        token.id = id;
+        token.contract = contract;
+        token.token_id = token_id;
+        token.trx_hash = trx_hash;
+        token.ordinal = ordinal;
+        records.push(token);
+    }
+    projection::project(&params, &mut records);
+
+    Ok(erc721::TokenMetadatas {
+        tokens: records,
+        rpc_calls: rpc.calls,
+        rpc_failures: rpc.failures,
+    })
+}
+
+/// Extracts the ERC721 and ERC1155 collections deployed in the block, with all of their collection level metadata
+#[substreams::handlers::map]
+fn map_created_collections(blk: eth::Block) -> Result<erc721::Collections, substreams::errors::Error> {
+    let mut created = vec![];
+    for trx in blk.transactions() {
+        for call in &trx.calls {
+            if call.call_type == eth::CallType::Create as i32 && !call.state_reverted {
+                created.push((call.address.clone(), trx));
+            }
+        }
+    }
+
+    let mut rpc = RpcCounters::default();
+    let contracts: Vec<Vec<u8>> = created.iter().map(|(contract, _)| contract.clone()).collect();
+    let standards = collections::standards(&Substreams, &contracts, &mut rpc);
+
+    let mut records = vec![];
+    for ((contract, trx), standard) in created.into_iter().zip(standards) {
+        if let Some(standard) = standard {
+            let implementation = eth_utils::proxy_implementation(trx, &contract);
+            let implementation = implementation.as_deref();
+            let mut collection = collections::metadata(&Substreams, &contract, standard, implementation, &mut rpc);
+            collection.deployer = trx.from.clone();
+            collection.trx_hash = trx.hash.clone();
+            records.push(collection);
+        }
+    }
+
+    Ok(erc721::Collections {
+        collections: records,
+        rpc_calls: rpc.calls,
+        rpc_failures: rpc.failures,
+    })
+}
+
+/// Extracts the collections of map_created_collections with the fields of their metadata listed in the `fields` param
+#[substreams::handlers::map]
+fn map_collection_metadata(
+    params: Params,
+    collections: erc721::Collections,
+) -> Result<erc721::Collections, substreams::errors::Error> {
+    let mut collections = collections;
+    projection::project(&params, &mut collections.collections);
+    Ok(collections)
+}
+
+/// Store the name of every collection of map_created_collections, keyed by its address
+#[substreams::handlers::store]
+fn store_collection_names(collections: erc721::Collections, s: StoreSetString) {
+    for collection in collections.collections {
+        if !collection.name.is_empty() {
+            s.set(0, collection_name_key(&collection.address), &collection.name);
+        }
+    }
+}
+
+fn collection_name_key(address: &[u8]) -> String {
+    keys::key(keys::COLLECTION).hex(address).text("name").into()
+}
+
+/// Store the number of ERC20 transfers seen by contract, counting token creations as well
+#[substreams::handlers::store]
+fn store_token_candidates(blk: eth::Block, tokens: pb::tokens::Tokens, s: StoreAddInt64) {
+    for token in tokens.tokens {
+        s.add(0, contract_key(&token.address), 1);
+    }
+
+    // counted by borrowed address first, busy tokens get a single key formatted and added per block
+    let mut counts: BTreeMap<&[u8], (u64, i64)> = BTreeMap::new();
+    for log in blk.logs() {
+        let topics = log.topics();
+        if topics.len() == 3 && topics[0] == eth_utils::TRANSFER_TOPIC {
+            let count = counts.entry(log.address()).or_insert((0, 0));
+            *count = (log.ordinal(), count.1 + 1);
+        }
+    }
+    for (address, (ordinal, count)) in counts {
+        s.add(ordinal, contract_key(address), count);
+    }
+}
+
+/// Extracts the metadata of tokens deployed before the start block, probed the first time they are seen
+/// transferring
+#[substreams::handlers::map]
+fn map_hydrated_tokens(
+    tokens: pb::tokens::Tokens,
+    candidates: Deltas<DeltaInt64>,
+) -> Result<pb::tokens::Tokens, substreams::errors::Error> {
+    let mut hydrated = vec![];
+    let mut rpc = RpcCounters::default();
+    for delta in candidates.deltas {
+        if delta.old_value != 0 {
+            continue;
+        }
+
+        let address = match keys::parse(&delta.key).filter(|key| key.is(keys::CONTRACT)).and_then(|key| key.hex(0)) {
+            Some(address) => address,
+            None => continue,
+        };
+        if tokens.tokens.iter().any(|token| token.address == address) {
+            continue;
+        }
+
+        if let Ok(token) = probe_token(&Substreams, &address, None, &mut rpc) {
+            logging::info!("tokens", "hydrated token {}", crypto::checksum_address(&token.address));
+            hydrated.push(token);
+        }
+    }
+
+    Ok(pb::tokens::Tokens {
+        tokens: hydrated,
+        rpc_calls: rpc.calls,
+        rpc_failures: rpc.failures,
+        ..Default::default()
+    })
+}
+
+#[substreams::handlers::store]
+fn store_tokens(tokens: pb::tokens::Tokens, hydrated: pb::tokens::Tokens, store: store::StoreSetString) {
+    for token in tokens.tokens.into_iter().chain(hydrated.tokens) {
+        logging::debug!(
+            "tokens",
+            "Storing token: {}",
+            token.name
+        );
+        let key = token_address_key(&token.address);
+        store.set(1, key, &token.name);
+    }
+}
+
+/// Store the decimals of the discovered tokens, which outputs scale raw amounts to whole tokens by
+#[substreams::handlers::store]
+fn store_token_decimals(tokens: pb::tokens::Tokens, hydrated: pb::tokens::Tokens, s: StoreSetInt64) {
+    for token in tokens.tokens.into_iter().chain(hydrated.tokens) {
+        s.set(1, token_address_key(&token.address), &(token.decimals as i64));
+    }
+}
+
+/// Decimals of a token in store_token_decimals, None when it was not discovered
+fn token_decimals(decimals: &StoreGetInt64, token: &[u8]) -> Option<u32> {
+    decimals.get_last(token_address_key(token)).and_then(|decimals| u32::try_from(decimals).ok())
+}
+
+/// Sets the decimals and the amount in whole tokens of the ERC20 transfers whose token decimals are known, the raw
+/// `amount` is left as logged
+#[substreams::handlers::map]
+fn map_scaled_erc20_transfers(
+    transfers: pb::tokens::Transfers,
+    decimals: StoreGetInt64,
+) -> Result<pb::tokens::Transfers, substreams::errors::Error> {
+    let mut transfers = transfers;
+    for transfer in transfers.transfers.iter_mut() {
+        let decimals = match token_decimals(&decimals, &transfer.token) {
+            Some(decimals) => decimals,
+            None => continue,
+        };
+        if let Ok(amount) = transfer.amount.parse::<substreams::scalar::BigInt>() {
+            transfer.decimals = decimals;
+            transfer.value = eth_utils::decimal_string(&amount.to_decimal(decimals as u64));
+        }
+    }
+    Ok(transfers)
+}
+
+/// Store the number of token list entries of the discovered tokens, which store_token_list caps its entries with
+#[substreams::handlers::store]
+fn store_token_list_size(params: Params, tokens: pb::tokens::Tokens, hydrated: pb::tokens::Tokens, s: StoreAddInt64) {
+    let entries = tokenlist::entries(tokenlist::chain_id(&params), tokens.tokens.iter().chain(&hydrated.tokens));
+    if !entries.is_empty() {
+        s.add(1, "tokens", entries.len() as i64);
+    }
+}
+
+/// Store the token list entries of the discovered tokens whose metadata fits the token list schema, up to the
+/// 10000 tokens a list holds
+#[substreams::handlers::store]
+fn store_token_list(
+    params: Params,
+    tokens: pb::tokens::Tokens,
+    hydrated: pb::tokens::Tokens,
+    size: StoreGetInt64,
+    s: StoreAppend<String>,
+) {
+    let entries = tokenlist::entries(tokenlist::chain_id(&params), tokens.tokens.iter().chain(&hydrated.tokens));
+    // store_token_list_size already counts the entries of the block
+    let before = (size.get_last("tokens").unwrap_or(0) as usize).saturating_sub(entries.len());
+    for entry in entries.into_iter().take(tokenlist::MAX_TOKENS.saturating_sub(before)) {
+        s.append(1, "tokens", entry);
+    }
+}
+
+/// Extracts every `every` blocks the discovered tokens as a token list, for wallets to generate their lists from
+#[substreams::handlers::map]
+fn map_token_list(
+    params: Params,
+    clock: Clock,
+    entries: StoreGetArray<String>,
+) -> Result<pb::tokens::TokenList, substreams::errors::Error> {
+    let every: u64 = params.get("every").and_then(|value| value.parse().ok()).unwrap_or(7200);
+    let mut list = pb::tokens::TokenList {
+        block: clock.number,
+        ..Default::default()
+    };
+    if every == 0 || clock.number % every != 0 {
+        return Ok(list);
+    }
+
+    let mut entries = entries.get_last("tokens").unwrap_or_default();
+    let mut seen = std::collections::BTreeSet::new();
+    entries.retain(|entry| seen.insert(entry.clone()));
+    entries.truncate(tokenlist::MAX_TOKENS);
+
+    let timestamp = clock.timestamp.map(|timestamp| timestamp.seconds).unwrap_or_default();
+    let name = params.get("name").unwrap_or("Discovered Tokens");
+    list.json = tokenlist::render(name, timestamp, &entries);
+    list.token_count = entries.len() as u64;
+    Ok(list)
+}
+
+/// Store the pipeline counters exposed by map_prometheus
+#[substreams::handlers::store]
+fn store_metrics(
+    transfers: erc721::Transfers,
+    tokens: pb::tokens::Tokens,
+    hydrated: pb::tokens::Tokens,
+    s: StoreAddInt64,
+) {
+    let counts = [
+        (metrics::TRANSFERS_TOTAL, transfers.transfers.len() as u64),
+        (metrics::TOKENS_DISCOVERED_TOTAL, (tokens.tokens.len() + hydrated.tokens.len()) as u64),
+        (metrics::RPC_CALLS_TOTAL, tokens.rpc_calls + hydrated.rpc_calls),
+        (metrics::RPC_FAILURES_TOTAL, tokens.rpc_failures + hydrated.rpc_failures),
+    ];
+    for (name, count) in counts {
+        if count > 0 {
+            s.add(0, name, count as i64);
+        }
+    }
+}
+
+/// Extracts the pipeline counters as metric samples for a Prometheus sink
+#[substreams::handlers::map]
+fn map_prometheus(clock: Clock, counters: StoreGetInt64) -> Result<pb::metrics::Metrics, substreams::errors::Error> {
+    Ok(pb::metrics::Metrics {
+        samples: metrics::COUNTERS
+            .iter()
+            .map(|(name, help)| pb::metrics::Sample {
+                name: name.to_string(),
+                help: help.to_string(),
+                value: counters.get_last(name).unwrap_or(0) as f64,
+                timestamp: clock.timestamp.clone(),
+            })
+            .collect(),
+    })
+}
+
+/// Store the cost proxies of every block summed over the blocks processed, averaged by map_perf
+#[substreams::handlers::store]
+fn store_perf(
+    blk: eth::Block,
+    transfers: erc721::Transfers,
+    erc20_transfers: pb::tokens::Transfers,
+    tokens: pb::tokens::Tokens,
+    hydrated: pb::tokens::Tokens,
+    s: StoreAddInt64,
+) {
+    let candidates = token_candidates(&blk).0.len();
+    for (name, value) in perf::measure(&blk, candidates, &transfers, &erc20_transfers, &tokens, &hydrated) {
+        s.add(0, perf::total_key(name), value);
+    }
+    s.add(0, perf::BLOCKS, 1);
+}
+
+/// Extracts the cost proxies of the block, flagged pathological when one is above `factor` times its average
+#[substreams::handlers::map]
+fn map_perf(
+    params: Params,
+    clock: Clock,
+    blk: eth::Block,
+    transfers: erc721::Transfers,
+    erc20_transfers: pb::tokens::Transfers,
+    tokens: pb::tokens::Tokens,
+    hydrated: pb::tokens::Tokens,
+    totals: StoreGetInt64,
+) -> Result<pb::metrics::BlockPerf, substreams::errors::Error> {
+    let factor = params.get("factor").and_then(|value| value.parse().ok()).unwrap_or(10.0);
+    let candidates = token_candidates(&blk).0.len();
+    let measures = perf::measure(&blk, candidates, &transfers, &erc20_transfers, &tokens, &hydrated);
+    let (measures, pathological) = perf::compare(measures, |key| totals.get_last(key).unwrap_or(0), factor);
+
+    Ok(pb::metrics::BlockPerf {
+        block: clock.number,
+        timestamp: clock.timestamp,
+        measures,
+        pathological,
+    })
+}
+
+/// Extracts the ERC20Votes delegation changes and voting power checkpoints of the tokens of store_tokens
+#[substreams::handlers::map]
+fn map_governance_events(
+    blk: eth::Block,
+    tokens: StoreGetString,
+) -> Result<pb::tokens::GovernanceEvents, substreams::errors::Error> {
+    let discovered = |token: &[u8]| tokens.get_last(token_address_key(token)).is_some();
+    let mut events = pb::tokens::GovernanceEvents::default();
+    for trx in blk.transactions() {
+        governance::decode(trx, blk.number, discovered, &mut events);
+    }
+    Ok(events)
+}
+
+/// Store the voting power of every delegate of the governance tokens, `votes:<token>:<delegate>`, from their last
+/// checkpoint
+#[substreams::handlers::store]
+fn store_voting_power(events: pb::tokens::GovernanceEvents, s: StoreSetBigInt) {
+    for checkpoint in events.checkpoints {
+        match checkpoint.votes.parse::<substreams::scalar::BigInt>() {
+            Ok(votes) => {
+                s.set(checkpoint.ordinal, governance::votes_key(&checkpoint.token, &checkpoint.delegate), &votes)
+            }
+            Err(_) => logging::warning!("tokens", "skipping voting power {}", checkpoint.votes),
+        }
+    }
+}
+
+/// Extracts the proposal lifecycle and vote events of the Governor contracts listed in the `governors` param
+#[substreams::handlers::map]
+fn map_proposal_events(
+    params: Params,
+    blk: eth::Block,
+) -> Result<pb::tokens::ProposalEvents, substreams::errors::Error> {
+    let governors = params.addresses("governors");
+    if governors.is_empty() {
+        return Ok(pb::tokens::ProposalEvents::default());
+    }
+    Ok(pb::tokens::ProposalEvents {
+        events: blk.transactions().flat_map(|trx| governance::proposal_events(trx, &governors)).collect(),
+    })
+}
+
+/// Store the votes cast on every proposal by support, `proposal:<governor>:<proposal_id>:<support>`
+#[substreams::handlers::store]
+fn store_proposal_votes(events: pb::tokens::ProposalEvents, s: StoreAddBigInt) {
+    use pb::tokens::proposal_event::Kind;
+
+    for event in events.events.into_iter().filter(|event| event.kind == Kind::VoteCast as i32) {
+        match event.weight.parse::<substreams::scalar::BigInt>() {
+            Ok(weight) => {
+                s.add(event.ordinal, governance::tally_key(&event.governor, &event.proposal_id, event.support), weight)
+            }
+            Err(_) => logging::warning!("tokens", "skipping vote weight {}", event.weight),
+        }
+    }
+}
+
+/// Store the token of every stream created on the Sablier V1 deployments, `stream:<contract>:<stream_id>`, as their
+/// withdrawals and cancellations don't log it
+#[substreams::handlers::store]
+fn store_sablier_streams(blk: eth::Block, s: StoreSetRaw) {
+    for trx in blk.transactions() {
+        for (ordinal, key, token) in vesting::created_streams(trx) {
+            s.set(ordinal, key, &token);
+        }
+    }
+}
+
+/// Extracts the Sablier V1 streams of the tokens of store_tokens and the funding and releases of the VestingWallet
+/// contracts listed in the `vesting_wallets` param
+#[substreams::handlers::map]
+fn map_vesting_events(
+    params: Params,
+    blk: eth::Block,
+    tokens: StoreGetString,
+    streams: StoreGetRaw,
+) -> Result<pb::tokens::VestingEvents, substreams::errors::Error> {
+    let wallets = params.addresses("vesting_wallets");
+    let discovered = |token: &[u8]| tokens.get_last(token_address_key(token)).is_some();
+    let stream_token = |contract: &[u8], stream_id: &str, ordinal: u64| {
+        streams.get_at(ordinal, vesting::stream_key(contract, stream_id))
+    };
+    Ok(pb::tokens::VestingEvents {
+        events: blk
+            .transactions()
+            .flat_map(|trx| vesting::decode(trx, discovered, &wallets, stream_token))
+            .collect(),
+    })
+}
+
+/// Store the amount of every token locked in vesting contracts, `vesting:<token>`, to exclude from its circulating
+/// supply
+#[substreams::handlers::store]
+fn store_vesting_locked(events: pb::tokens::VestingEvents, s: StoreAddBigInt) {
+    for event in events.events {
+        match vesting::locked_change(&event) {
+            Some(change) => s.add(event.ordinal, vesting::locked_key(&event.token), change),
+            None => logging::warning!("tokens", "skipping vesting amount {}", event.amount),
+        }
+    }
+}
+
+/// Store the Uniswap V2 pairs and V3 pools trading a token of store_tokens, with their two tokens
+#[substreams::handlers::store]
+fn store_dex_pools(blk: eth::Block, tokens: StoreGetString, s: StoreSetRaw) {
+    let discovered = |token: &Vec<u8>| tokens.get_last(token_address_key(token)).is_some();
+
+    for (pair, log) in blk.events::<abi::uniswap_v2_factory::events::PairCreated>(&[&dex::UNISWAP_V2_FACTORY]) {
+        if discovered(&pair.token0) || discovered(&pair.token1) {
+            s.set(log.ordinal(), dex::pool_key(&pair.pair), &dex::encode_pool(&pair.token0, &pair.token1));
+        }
+    }
+
+    for (pool, log) in blk.events::<abi::uniswap_v3_factory::events::PoolCreated>(&[&dex::UNISWAP_V3_FACTORY]) {
+        if discovered(&pool.token0) || discovered(&pool.token1) {
+            s.set(log.ordinal(), dex::pool_key(&pool.pool), &dex::encode_pool(&pool.token0, &pool.token1));
+        }
+    }
+}
+
+/// Extracts the liquidity added to and removed from the pools of store_dex_pools, from Uniswap V2 pair mints and
+/// burns and from Uniswap V3 position manager liquidity changes
+#[substreams::handlers::map]
+fn map_liquidity(blk: eth::Block, pools: StoreGetRaw) -> Result<pb::dex::LiquidityChanges, substreams::errors::Error> {
+    use abi::uniswap_v2_pair::events as v2;
+    use abi::uniswap_v3_pool::events as v3;
+    use abi::uniswap_v3_positions::events as positions;
+    use pb::dex::liquidity_change::Kind;
+
+    let mut changes = vec![];
+    for trx in blk.transactions() {
+        let logs: Vec<_> = trx.receipt().logs().collect();
+        for (index, log) in logs.iter().enumerate() {
+            let mut change = pb::dex::LiquidityChange {
+                id: eth_utils::record_id(&trx.hash, log.block_index()),
+                provider: trx.from.clone(),
+                trx_hash: trx.hash.clone(),
+                ordinal: log.ordinal(),
+                ..Default::default()
+            };
+
+            if let Some(mint) = v2::Mint::match_and_decode(log.log) {
+                change.kind = Kind::Add as i32;
+                change.protocol = dex::UNISWAP_V2.to_string();
+                change.pool = log.address().to_vec();
+                change.amount0 = mint.amount0.to_string();
+                change.amount1 = mint.amount1.to_string();
+            } else if let Some(burn) = v2::Burn::match_and_decode(log.log) {
+                change.kind = Kind::Remove as i32;
+                change.protocol = dex::UNISWAP_V2.to_string();
+                change.pool = log.address().to_vec();
+                change.amount0 = burn.amount0.to_string();
+                change.amount1 = burn.amount1.to_string();
+            } else if log.address() == dex::UNISWAP_V3_POSITIONS {
+                // the position manager logs right after the pool it called into
+                let (kind, position) = if let Some(event) = positions::IncreaseLiquidity::match_and_decode(log.log) {
+                    (Kind::Add, (event.token_id, event.liquidity, event.amount0, event.amount1))
+                } else if let Some(event) = positions::DecreaseLiquidity::match_and_decode(log.log) {
+                    (Kind::Remove, (event.token_id, event.liquidity, event.amount0, event.amount1))
+                } else {
+                    continue;
+                };
+                let pool_log = logs[..index].iter().rev().find(|previous| match kind {
+                    Kind::Add => v3::Mint::match_log(previous.log),
+                    _ => v3::Burn::match_log(previous.log),
+                });
+                let pool_log = match pool_log {
+                    Some(pool_log) => pool_log,
+                    None => continue,
+                };
+
+                change.kind = kind as i32;
+                change.protocol = dex::UNISWAP_V3.to_string();
+                change.pool = pool_log.address().to_vec();
+                change.position_id = match eth_utils::to_u64(&position.0) {
+                    Some(position_id) => position_id,
+                    None => continue,
+                };
+                change.liquidity = position.1.to_string();
+                change.amount0 = position.2.to_string();
+                change.amount1 = position.3.to_string();
+            } else {
+                continue;
+            }
+
+            let (token0, token1) = match pools.get_last(dex::pool_key(&change.pool)).and_then(|pool| dex::decode_pool(&pool)) {
+                Some(tokens) => tokens,
+                None => continue,
+            };
+            change.token0 = token0;
+            change.token1 = token1;
+            changes.push(change);
+        }
+    }
+
+    Ok(pb::dex::LiquidityChanges { changes })
+}
+
+/// Extracts the Uniswap V2 LP tokens of the pools of store_dex_pools locked in a known locker, Unicrypt or
+/// Team.Finance, with the time they unlock at
+#[substreams::handlers::map]
+fn map_liquidity_locks(
+    blk: eth::Block,
+    pools: StoreGetRaw,
+) -> Result<pb::dex::LiquidityLocks, substreams::errors::Error> {
+    use abi::team_finance_locker::events as team_finance;
+    use abi::unicrypt_locker::events as unicrypt;
+
+    let mut locks = vec![];
+    for trx in blk.transactions() {
+        let logs: Vec<_> = trx.receipt().logs().collect();
+        for (index, log) in logs.iter().enumerate() {
+            let deposit = if log.address() == dex::UNICRYPT_LOCKER {
+                unicrypt::OnDeposit::match_and_decode(log.log)
+                    .map(|event| (dex::UNICRYPT, event.lp_token, event.user, event.amount, event.unlock_date))
+            } else if log.address() == dex::TEAM_FINANCE_LOCKER {
+                team_finance::Deposit::match_and_decode(log.log).map(|event| {
+                    let owner = event.withdrawal_address;
+                    (dex::TEAM_FINANCE, event.token_address, owner, event.amount, event.unlock_time)
+                })
+            } else {
+                None
+            };
+            let (locker, pool, owner, amount, unlock_time) = match deposit {
+                Some(deposit) => deposit,
+                None => continue,
+            };
+            let tokens = pools.get_last(dex::pool_key(&pool)).and_then(|value| dex::decode_pool(&value));
+            let (token0, token1) = match tokens {
+                Some(tokens) => tokens,
+                None => continue,
+            };
+
+            // the locker pulls the LP tokens in before it logs the deposit
+            let transferred_in = logs[..index].iter().any(|previous| {
+                previous.address() == pool.as_slice()
+                    && abi::erc20::events::Transfer::match_and_decode(previous.log)
+                        .map_or(false, |transfer| transfer.to == log.address())
+            });
+            if !transferred_in {
+                continue;
+            }
+
+            locks.push(pb::dex::LiquidityLocked {
+                locker: locker.to_string(),
+                locker_address: log.address().to_vec(),
+                pool,
+                token0,
+                token1,
+                owner,
+                amount: amount.to_string(),
+                // an unlock time past u64 is as good as never
+                unlock_timestamp: eth_utils::to_u64(&unlock_time).unwrap_or(u64::MAX),
+                trx_hash: trx.hash.clone(),
+                ordinal: log.ordinal(),
+            });
+        }
+    }
+    Ok(pb::dex::LiquidityLocks { locks })
+}
+
+/// Store the owner and the pool of every Uniswap V3 position, keyed by position id
+#[substreams::handlers::store]
+fn store_positions(blk: eth::Block, liquidity: pb::dex::LiquidityChanges, s: StoreSetRaw) {
+    for (transfer, log) in blk.events::<abi::erc721::events::Transfer>(&[&dex::UNISWAP_V3_POSITIONS]) {
+        let key = match eth_utils::to_u64(&transfer.token_id) {
+            Some(position_id) => dex::position_owner_key(position_id),
+            None => continue,
+        };
+        s.set(log.ordinal(), key, &transfer.to);
+    }
+
+    for change in liquidity.changes {
+        if change.protocol == dex::UNISWAP_V3 {
+            s.set(change.ordinal, dex::position_pool_key(change.position_id), &change.pool);
+        }
+    }
+}
+
+/// Store the liquidity held by every Uniswap V3 position
+#[substreams::handlers::store]
+fn store_position_liquidity(liquidity: pb::dex::LiquidityChanges, s: StoreAddBigInt) {
+    for change in liquidity.changes {
+        if let Some(amount) = dex::signed_liquidity(&change) {
+            s.add(change.ordinal, dex::position_key(change.position_id), amount);
+        }
+    }
+}
+
+/// Store the Uniswap V3 liquidity each owner holds in a pool, and the total of each pool, so owners' shares of a
+/// pool can be read directly. Liquidity follows its position when the position NFT is transferred, owners and
+/// liquidity are read at the ordinal of each change so transfers and changes of the same block are applied in order.
+#[substreams::handlers::store]
+fn store_owner_liquidity(
+    blk: eth::Block,
+    liquidity: pb::dex::LiquidityChanges,
+    positions: StoreGetRaw,
+    position_liquidity: StoreGetBigInt,
+    s: StoreAddBigInt,
+) {
+    for change in liquidity.changes {
+        let amount = match dex::signed_liquidity(&change) {
+            Some(amount) => amount,
+            None => continue,
+        };
+        if let Some(owner) = positions.get_at(change.ordinal, dex::position_owner_key(change.position_id)) {
+            s.add(change.ordinal, dex::owner_key(&owner, &change.pool), amount.clone());
+        }
+        s.add(change.ordinal, dex::pool_key(&change.pool), amount);
+    }
+
+    for (transfer, log) in blk.events::<abi::erc721::events::Transfer>(&[&dex::UNISWAP_V3_POSITIONS]) {
+        // minted positions get their liquidity from the change that follows, burnt ones are already empty
+        if transfer.from == NULL_ADDRESS || transfer.to == NULL_ADDRESS {
+            continue;
+        }
+
+        let position_id = match eth_utils::to_u64(&transfer.token_id) {
+            Some(position_id) => position_id,
+            None => continue,
+        };
+        // owner and liquidity as of the transfer, later changes of the block are moved by their own ordinal
+        let pool = positions.get_at(log.ordinal(), dex::position_pool_key(position_id));
+        let amount = position_liquidity.get_at(log.ordinal(), dex::position_key(position_id));
+        let (pool, amount) = match (pool, amount) {
+            (Some(pool), Some(amount)) => (pool, amount),
+            _ => continue,
+        };
+        s.add(log.ordinal(), dex::owner_key(&transfer.from, &pool), amount.clone().neg());
+        s.add(log.ordinal(), dex::owner_key(&transfer.to, &pool), amount);
+    }
+}
+
+/// Store the block the first Uniswap pool trading each token of store_tokens got created in
+#[substreams::handlers::store]
+fn store_token_launches(clock: Clock, pools: Deltas<DeltaBytes>, tokens: StoreGetString, s: StoreMinInt64) {
+    for delta in pools.deltas {
+        if delta.operation != substreams::pb::substreams::store_delta::Operation::Create {
+            continue;
+        }
+
+        if let Some((token0, token1)) = dex::decode_pool(&delta.new_value) {
+            for token in [token0, token1] {
+                if tokens.get_last(token_address_key(&token)).is_some() {
+                    s.min(delta.ordinal, launches::launch_key(&token), clock.number as i64);
+                }
+            }
+        }
+    }
+}
+
+/// Store the total supply of the tokens of store_tokens, from their mints and burns
+#[substreams::handlers::store]
+fn store_token_supply(transfers: pb::tokens::Transfers, tokens: StoreGetString, s: StoreAddBigInt) {
+    for change in erc20::balance_changes(&transfers) {
+        if change.holder != NULL_ADDRESS {
+            continue;
+        }
+        if tokens.get_last(token_address_key(&change.token)).is_none() {
+            continue;
+        }
+
+        // mints are taken from the zero address and burns are sent to it
+        s.add(change.ordinal, launches::supply_key(&change.token), change.amount.neg());
+    }
+}
+
+/// Extracts the amounts of every token deposited to and withdrawn from the exchange wallets listed in the
+/// `exchanges` param, the known wallets of the largest exchanges by default
+#[substreams::handlers::map]
+fn map_exchange_flows(
+    params: Params,
+    clock: Clock,
+    transfers: pb::tokens::Transfers,
+) -> Result<pb::tokens::ExchangeFlows, substreams::errors::Error> {
+    let wallets = exchanges::Wallets::from_params(&params);
+    Ok(pb::tokens::ExchangeFlows {
+        flows: exchanges::flows(clock.number, &transfers, &wallets),
+    })
+}
+
+/// Store the net flow of every token into every exchange by bucket of its rolling windows,
+/// `netflow.v2:<window>:<bucket>:<token>:<exchange>`, dropping the buckets leaving the windows
+#[substreams::handlers::store]
+fn store_exchange_net_flows(clock: Clock, flows: pb::tokens::ExchangeFlows, s: StoreAddBigInt) {
+    let timestamp = clock.timestamp.map(|timestamp| timestamp.seconds).unwrap_or_default();
+
+    // every block drops the bucket leaving each window, whether it had flows or not. Blocks are much closer than a
+    // bucket, so every bucket gets dropped for all tokens and exchanges.
+    for window in exchanges::WINDOWS.iter() {
+        s.delete_prefix(0, &exchanges::bucket_prefix(window, window.bucket(timestamp) - window.buckets));
+    }
+
+    for flow in flows.flows {
+        for window in exchanges::WINDOWS.iter() {
+            let key = exchanges::net_flow_key(&flow.token, &flow.exchange, window, window.bucket(timestamp));
+            s.add(1, key, exchanges::net(&flow));
+        }
+    }
+}
+
+/// Extracts the rolling 1h and 24h net flows crossing the thresholds of the `thresholds` param
+#[substreams::handlers::map]
+fn map_flow_signals(
+    params: Params,
+    clock: Clock,
+    flows: pb::tokens::ExchangeFlows,
+    net_flows: StoreGetBigInt,
+) -> Result<pb::tokens::FlowSignals, substreams::errors::Error> {
+    use substreams::scalar::BigInt;
+
+    let thresholds = exchanges::thresholds(&params);
+    let timestamp = clock.timestamp.map(|timestamp| timestamp.seconds).unwrap_or_default();
+
+    let mut signals = vec![];
+    for flow in flows.flows {
+        let threshold = match thresholds.get(&flow.token) {
+            Some(threshold) => threshold,
+            None => continue,
+        };
+        for window in exchanges::WINDOWS.iter() {
+            let last = window.bucket(timestamp);
+            let key = |bucket| exchanges::net_flow_key(&flow.token, &flow.exchange, window, bucket);
+            let current = (last - window.buckets + 1..=last)
+                .filter_map(|bucket| net_flows.get_last(key(bucket)))
+                .fold(BigInt::zero(), |total, net| total + net);
+            let previous = current.clone() - exchanges::net(&flow);
+            signals.extend(exchanges::signal(&flow, window, &previous, current, threshold));
+        }
+    }
+    Ok(pb::tokens::FlowSignals { signals })
+}
+
+/// Store the balances of the tokens of store_tokens held by burn addresses and bridge escrows,
+/// `excluded:<token>:<kind>`
+#[substreams::handlers::store]
+fn store_excluded_balances(
+    params: Params,
+    transfers: pb::tokens::Transfers,
+    tokens: StoreGetString,
+    s: StoreAddBigInt,
+) {
+    let exclusions = supply::Exclusions::from_params(&params);
+    for change in erc20::balance_changes(&transfers) {
+        let kind = match exclusions.kind(&change.holder) {
+            Some(kind) => kind,
+            None => continue,
+        };
+        if tokens.get_last(token_address_key(&change.token)).is_none() {
+            continue;
+        }
+        s.add(change.ordinal, supply::excluded_key(&change.token, kind), change.amount);
+    }
+}
+
+/// Extracts the circulating supply of the tokens of store_tokens whose balances moved in the block
+#[substreams::handlers::map]
+fn map_circulating_supply(
+    clock: Clock,
+    transfers: pb::tokens::Transfers,
+    token_supply: StoreGetBigInt,
+    excluded: StoreGetBigInt,
+    locked: StoreGetBigInt,
+) -> Result<pb::tokens::CirculatingSupplies, substreams::errors::Error> {
+    use substreams::scalar::BigInt;
+
+    let moved: std::collections::BTreeSet<Vec<u8>> =
+        erc20::balance_changes(&transfers).into_iter().map(|change| change.token).collect();
+
+    let mut supplies = vec![];
+    for token in moved {
+        // only discovered tokens have a supply
+        let total_supply = match token_supply.get_last(launches::supply_key(&token)) {
+            Some(total_supply) => total_supply,
+            None => continue,
+        };
+        let excluded = |kind| excluded.get_last(supply::excluded_key(&token, kind)).unwrap_or_else(BigInt::zero);
+        let locked = locked.get_last(vesting::locked_key(&token)).unwrap_or_else(BigInt::zero);
+        supplies.push(supply::circulating(
+            &token,
+            clock.number,
+            total_supply,
+            excluded(supply::BURNED),
+            excluded(supply::ESCROWED),
+            locked,
+        ));
+    }
+    Ok(pb::tokens::CirculatingSupplies { supplies })
+}
+
+/// Store the `<buyer>:<amount>` buys of each token made within `window` blocks of its launch, along with the tokens
+/// whose window closes on each block
+#[substreams::handlers::store]
+fn store_launch_buys(
+    params: Params,
+    blk: eth::Block,
+    pools: StoreGetRaw,
+    launches: StoreGetInt64,
+    s: StoreAppend<String>,
+) {
+    let window = launches::window(&params);
+    for buy in launches::buys(&blk, window, &pools, &launches) {
+        s.append(buy.ordinal, launches::buys_key(&buy.token), format!("{}:{}", Hex(&buy.buyer), buy.amount));
+        s.append(buy.ordinal, launches::closing_key(buy.launch_block + window), Hex(&buy.token).to_string());
+    }
+}
+
+/// Extracts the tokens whose launch window closes on this block, with the launch quality score of their buys
+#[substreams::handlers::map]
+fn map_launch_quality(
+    params: Params,
+    clock: Clock,
+    buys: StoreGetArray<String>,
+    supply: StoreGetBigInt,
+    tokens: StoreGetString,
+) -> Result<pb::tokens::Tokens, substreams::errors::Error> {
+    use std::str::FromStr;
+    use substreams::scalar::BigInt;
+
+    let window = launches::window(&params);
+    let snipers: usize = params.get("snipers").and_then(|value| value.parse().ok()).unwrap_or(5);
+    let threshold: f64 = params.get("threshold").and_then(|value| value.parse().ok()).unwrap_or(50.0);
+
+    let mut closing = buys.get_last(launches::closing_key(clock.number)).unwrap_or_default();
+    closing.sort();
+    closing.dedup();
+
+    let mut records = vec![];
+    for token in closing {
+        let token = match hex::decode(&token) {
+            Ok(token) => token,
+            Err(_) => continue,
+        };
+
+        let mut bought: BTreeMap<String, BigInt> = BTreeMap::new();
+        for entry in buys.get_last(launches::buys_key(&token)).unwrap_or_default() {
+            if let Some((buyer, amount)) = entry.split_once(':') {
+                if let Ok(amount) = BigInt::from_str(amount) {
+                    let total = bought.entry(buyer.to_string()).or_insert_with(BigInt::zero);
+                    *total = total.clone() + amount;
+                }
+            }
+        }
+
+        let supply = supply.get_last(launches::supply_key(&token)).unwrap_or_else(BigInt::zero);
+        let launch = launches::quality(
+            clock.number.saturating_sub(window),
+            window,
+            bought.into_values().collect(),
+            &supply,
+            snipers,
+            threshold,
+        );
+        records.push(pb::tokens::Token {
+            name: tokens.get_last(token_address_key(&token)).unwrap_or_default(),
+            address: token,
+            launch: Some(launch),
+            ..Default::default()
+        });
+    }
+
+    Ok(pb::tokens::Tokens {
+        tokens: records,
+        ..Default::default()
+    })
+}
+
+/// Store the deployer of every token discovered by map_tokens
+#[substreams::handlers::store]
+fn store_token_deployers(tokens: pb::tokens::Tokens, s: StoreSetRaw) {
+    for token in tokens.tokens {
+        if !token.deployer.is_empty() {
+            s.set(0, deployers::deployer_key(&token.address), &token.deployer);
+        }
+    }
+}
+
+/// Store the block in which the owner of a token of store_tokens renounced its ownership, transferring it to the null
+/// address
+#[substreams::handlers::store]
+fn store_renounced_tokens(blk: eth::Block, tokens: StoreGetString, s: StoreMinInt64) {
+    for log in blk.logs() {
+        let transfer = match abi::ownable::events::OwnershipTransferred::match_and_decode(log.log) {
+            Some(transfer) => transfer,
+            None => continue,
+        };
+        if transfer.new_owner == NULL_ADDRESS && tokens.get_last(token_address_key(log.address())).is_some() {
+            s.min(log.ordinal(), deployers::renounced_key(log.address()), blk.number as i64);
+        }
+    }
+}
+
+/// Store the presale contracts created by the launchpad factories listed in the `launchpads` param
+#[substreams::handlers::store]
+fn store_launchpad_presales(params: Params, blk: eth::Block, s: StoreSetIfNotExistsRaw) {
+    let launchpads = params.addresses("launchpads");
+    if launchpads.is_empty() {
+        return;
+    }
+
+    for trx in blk.transactions() {
+        for (presale, ordinal) in presales::launched(trx, &launchpads) {
+            s.set_if_not_exists(ordinal, presales::launched_key(&presale), &"1");
+        }
+    }
+}
+
+/// Extracts the contributions, refunds, cancellation and finalization of the presales created by the launchpads of
+/// store_launchpad_presales and of the presale contracts listed in the `presales` param
+#[substreams::handlers::map]
+fn map_presale_events(
+    params: Params,
+    blk: eth::Block,
+    launched: StoreGetRaw,
+) -> Result<pb::tokens::PresaleEvents, substreams::errors::Error> {
+    let listed = params.addresses("presales");
+    let is_presale = |address: &[u8]| {
+        listed.iter().any(|presale| presale == address) || launched.has_last(presales::launched_key(address))
+    };
+
+    let events = blk.transactions().flat_map(|trx| presales::decode(trx, is_presale)).collect();
+    Ok(pb::tokens::PresaleEvents { events })
+}
+
+/// Store the participants of every presale, once each
+#[substreams::handlers::store]
+fn store_presale_participants(events: pb::tokens::PresaleEvents, s: StoreSetIfNotExistsRaw) {
+    use pb::tokens::presale_event::Kind;
+
+    for event in events.events.iter().filter(|event| event.kind == Kind::Contribution as i32) {
+        s.set_if_not_exists(event.ordinal, presales::participant_key(&event.presale, &event.account), &"1");
+    }
+}
+
+/// Store the ETH raised less the refunds, the refunds, the contributions, the participants and the cancellation of
+/// every presale
+#[substreams::handlers::store]
+fn store_presale_stats(events: pb::tokens::PresaleEvents, participants: Deltas<DeltaString>, s: StoreAddBigInt) {
+    use pb::tokens::presale_event::Kind;
+    use substreams::scalar::BigInt;
+
+    for event in events.events.iter() {
+        let amount = match event.amount.parse::<BigInt>() {
+            Ok(amount) => amount,
+            Err(_) => continue,
+        };
+        let stat = |name| presales::stat_key(&event.presale, name);
+        match Kind::from_i32(event.kind) {
+            Some(Kind::Contribution) => {
+                s.add(event.ordinal, stat("raised"), amount);
+                s.add(event.ordinal, stat("contributions"), BigInt::one());
+            }
+            Some(Kind::Refund) => {
+                s.add(event.ordinal, stat("raised"), amount.clone().neg());
+                s.add(event.ordinal, stat("refunded"), amount);
+            }
+            Some(Kind::Cancellation) => s.add(event.ordinal, stat("cancelled"), BigInt::one()),
+            _ => {}
+        }
+    }
+
+    for delta in participants.deltas.iter() {
+        if delta.operation != substreams::pb::substreams::store_delta::Operation::Create {
+            continue;
+        }
+        if let Some(presale) = presales::participant_presale(&delta.key) {
+            s.add(delta.ordinal, presales::stat_key(&presale, "participants"), BigInt::one());
+        }
+    }
+}
+
+/// Extracts the presales of the tokens of store_tokens finalized in the block, with their raise and participants
+#[substreams::handlers::map]
+fn map_presales(
+    events: pb::tokens::PresaleEvents,
+    stats: StoreGetBigInt,
+    tokens: StoreGetString,
+) -> Result<pb::tokens::Presales, substreams::errors::Error> {
+    use pb::tokens::presale_event::Kind;
+
+    let mut presales = vec![];
+    for event in events.events.into_iter().filter(|event| event.kind == Kind::Finalization as i32) {
+        if tokens.get_last(token_address_key(&event.account)).is_none() {
+            continue;
+        }
+
+        let stat = |name| stats.get_last(presales::stat_key(&event.presale, name));
+        let count = |name| stat(name).and_then(|count| eth_utils::to_u64(&count)).unwrap_or(0);
+        let amount = |name| stat(name).map_or_else(|| "0".to_string(), |amount| amount.to_string());
+        presales.push(pb::tokens::Presale {
+            raised: amount("raised"),
+            refunded: amount("refunded"),
+            participants: count("participants"),
+            contributions: count("contributions"),
+            presale: event.presale,
+            token: event.account,
+            liquidity: event.amount,
+            trx_hash: event.trx_hash,
+        });
+    }
+    Ok(pb::tokens::Presales { presales })
+}
+
+/// Store the tokens discovered by map_tokens created from a template trusted by the `templates` param, marked
+/// `known`
+#[substreams::handlers::store]
+fn store_token_templates(params: Params, tokens: pb::tokens::Tokens, s: StoreSetString) {
+    let allowlist = bytecode::Allowlist::from_params(&params);
+    for token in tokens.tokens.iter().filter(|token| allowlist.trusts(&token.template, &token.code_hash)) {
+        s.set(0, bytecode::template_key(&token.address), &bytecode::KNOWN.to_string());
+    }
+}
+
+/// Store the number of tokens discovered by map_tokens created with the same code, and the tokens of each family,
+/// grouping the clones of a token template
+#[substreams::handlers::store]
+fn store_bytecode_families(tokens: pb::tokens::Tokens, s: StoreAddInt64) {
+    for token in tokens.tokens.iter().filter(|token| !token.code_hash.is_empty()) {
+        s.add(0, bytecode::family_key(&token.code_hash), 1);
+        s.add(0, bytecode::member_key(&token.code_hash, &token.address), 1);
+    }
+}
+
+/// Store the amount of each token added to and removed from the pools of store_dex_pools
+#[substreams::handlers::store]
+fn store_token_liquidity(liquidity: pb::dex::LiquidityChanges, s: StoreAddBigInt) {
+    use pb::dex::liquidity_change::Kind;
+    use std::str::FromStr;
+
+    for change in liquidity.changes {
+        for (token, amount) in [(&change.token0, &change.amount0), (&change.token1, &change.amount1)] {
+            let amount = match substreams::scalar::BigInt::from_str(amount) {
+                Ok(amount) => amount,
+                Err(_) => continue,
+            };
+            if change.kind == Kind::Add as i32 {
+                s.add(change.ordinal, deployers::added_key(token), amount);
+            } else if change.kind == Kind::Remove as i32 {
+                s.add(change.ordinal, deployers::removed_key(token), amount);
+            }
+        }
+    }
+}
+
+/// Store by deployer how many tokens they deployed, and how many of them got more than 90% of their liquidity removed
+#[substreams::handlers::store]
+fn store_deployers(
+    tokens: pb::tokens::Tokens,
+    liquidity: pb::dex::LiquidityChanges,
+    token_liquidity: StoreGetBigInt,
+    token_deployers: StoreGetRaw,
+    s: StoreAddInt64,
+) {
+    for token in &tokens.tokens {
+        if !token.deployer.is_empty() {
+            s.add(0, deployers::deployed_key(&token.deployer), 1);
+        }
+    }
+
+    let removed = deployers::token_amounts(&liquidity, pb::dex::liquidity_change::Kind::Remove);
+    for (token, removed_now) in removed {
+        let (added, removed) = match (
+            token_liquidity.get_last(deployers::added_key(&token)),
+            token_liquidity.get_last(deployers::removed_key(&token)),
+        ) {
+            (Some(added), Some(removed)) => (added, removed),
+            _ => continue,
+        };
+        if !deployers::rugged_by(&added, &removed, &removed_now) {
+            continue;
+        }
+
+        if let Some(deployer) = token_deployers.get_last(deployers::deployer_key(&token)) {
+            logging::info!("tokens", "token {} got rugged", crypto::checksum_address(&token));
+            s.add(0, deployers::rugged_key(&deployer), 1);
+        }
+    }
+}
+
+/// Extracts the tokens discovered by map_tokens with the reputation of their deployer
+#[substreams::handlers::map]
+fn map_token_reputation(
+    tokens: pb::tokens::Tokens,
+    deployer_stats: StoreGetInt64,
+    renounced: StoreGetInt64,
+) -> Result<pb::tokens::Tokens, substreams::errors::Error> {
+    let mut records = vec![];
+    for (index, mut token) in tokens.tokens.clone().into_iter().enumerate() {
+        if token.deployer.is_empty() {
+            continue;
+        }
+
+        let deployed = deployer_stats.get_last(deployers::deployed_key(&token.deployer)).unwrap_or(0) as u64;
+        let rugged = deployer_stats.get_last(deployers::rugged_key(&token.deployer)).unwrap_or(0) as u64;
+
+        // the store already counts every token of this block, only keep the ones deployed before this one
+        let same_deployer = |other: &&pb::tokens::Token| other.deployer == token.deployer;
+        let in_block = tokens.tokens.iter().filter(same_deployer).count() as u64;
+        let earlier_in_block = tokens.tokens[..index].iter().filter(same_deployer).count() as u64;
+        let deployed_before = deployed.saturating_sub(in_block) + earlier_in_block;
+
+        token.deployer_reputation = Some(deployers::reputation(deployed_before, rugged));
+        let renounced_at = renounced.get_last(deployers::renounced_key(&token.address));
+        token.ownership_renounced_at_block = renounced_at.unwrap_or(0) as u64;
+        records.push(token);
+    }
+
+    Ok(pb::tokens::Tokens {
+        tokens: records,
+        ..Default::default()
+    })
+}
+
+/// Extracts the tokens of store_tokens that taxed a transfer in the block, with their estimated transfer tax rate
+#[substreams::handlers::map]
+fn map_transfer_taxes(blk: eth::Block, tokens: StoreGetString) -> Result<pb::tokens::Tokens, substreams::errors::Error> {
+    let mut rates: BTreeMap<Vec<u8>, f64> = BTreeMap::new();
+    for trx in blk.transactions() {
+        for (token, rate) in taxes::transfer_taxes(trx) {
+            let highest = rates.entry(token).or_insert(rate);
+            if rate > *highest {
+                *highest = rate;
+            }
+        }
+    }
+
+    let mut records = vec![];
+    for (token, rate) in rates {
+        let name = match tokens.get_last(token_address_key(&token)) {
+            Some(name) => name,
+            None => continue,
+        };
+        records.push(pb::tokens::Token {
+            address: token,
+            name,
+            has_transfer_tax: true,
+            transfer_tax_rate: rate,
+            ..Default::default()
+        });
+    }
+
+    Ok(pb::tokens::Tokens {
+        tokens: records,
+        ..Default::default()
+    })
+}
+
+/// Store the number of addresses each token got airdropped to, by transactions sending it to hundreds of them, along
+/// with the number of transfers it was received in
+#[substreams::handlers::store]
+fn store_token_airdrops(transfers: pb::tokens::Transfers, s: StoreAddInt64) {
+    for (token, recipients) in spam::airdrops(&transfers) {
+        s.add(0, spam::airdropped_key(&token), recipients as i64);
+    }
+    for (token, received) in spam::received(&transfers) {
+        s.add(0, spam::received_key(&token), received as i64);
+    }
+}
+
+/// Store the symbol of the discovered tokens, keyed by their address
+#[substreams::handlers::store]
+fn store_token_symbols(tokens: pb::tokens::Tokens, hydrated: pb::tokens::Tokens, s: StoreSetString) {
+    for token in tokens.tokens.into_iter().chain(hydrated.tokens) {
+        s.set(1, token_symbol_key(&token.address), &token.symbol);
+    }
+}
+
+fn token_symbol_key(address: &[u8]) -> String {
+    keys::key(keys::SYMBOL).hex(address).into()
+}
+
+/// Extracts the tokens of store_tokens airdropped in the block with their full spam score, adding the airdrop and
+/// liquidity signals to the ones of their name and symbol
+#[substreams::handlers::map]
+fn map_spam_scores(
+    transfers: pb::tokens::Transfers,
+    tokens: StoreGetString,
+    symbols: StoreGetString,
+    airdrops: StoreGetInt64,
+    token_liquidity: StoreGetBigInt,
+    templates: StoreGetString,
+    renounced: StoreGetInt64,
+) -> Result<pb::tokens::Tokens, substreams::errors::Error> {
+    let mut records = vec![];
+    for token in spam::airdrops(&transfers).into_keys() {
+        let name = match tokens.get_last(token_address_key(&token)) {
+            Some(name) => name,
+            None => continue,
+        };
+
+        let mut record = pb::tokens::Token {
+            template: templates.get_last(bytecode::template_key(&token)).unwrap_or_default(),
+            ownership_renounced_at_block: renounced.get_last(deployers::renounced_key(&token)).unwrap_or(0) as u64,
+            symbol: symbols.get_last(token_symbol_key(&token)).unwrap_or_default(),
+            address: token,
+            name,
+            ..Default::default()
+        };
+        let mut signals: Vec<String> = spam::metadata_signals(&record).into_iter().map(String::from).collect();
+        let airdropped = airdrops.get_last(spam::airdropped_key(&record.address)).unwrap_or(0);
+        let received = airdrops.get_last(spam::received_key(&record.address)).unwrap_or(0);
+        if spam::mostly_airdropped(airdropped, received) {
+            signals.push(spam::AIRDROP.to_string());
+        }
+        if token_liquidity.get_last(deployers::added_key(&record.address)).is_none() {
+            signals.push(spam::NO_LIQUIDITY.to_string());
+        }
+
+        record.spam_score = spam::score(&signals, &record.template);
+        record.spam_signals = signals;
+        records.push(record);
+    }
+
+    Ok(pb::tokens::Tokens {
+        tokens: records,
+        ..Default::default()
+    })
+}
+
+/// Extracts the rewards claimed from the NFT staking programs listed in the `programs` param
+#[substreams::handlers::map]
+fn map_staking_rewards(
+    params: Params,
+    blk: eth::Block,
+) -> Result<pb::staking::RewardClaims, substreams::errors::Error> {
+    let programs = params.addresses("programs");
+    if programs.is_empty() {
+        return Ok(pb::staking::RewardClaims::default());
+    }
+    Ok(pb::staking::RewardClaims {
+        claims: blk.transactions().flat_map(|trx| staking::reward_claims(trx, &programs)).collect(),
+    })
+}
+
+/// Store the cumulative rewards claimed by every holder from the staking programs, by reward token
+/// (`reward:<holder>:<reward_token>`)
+#[substreams::handlers::store]
+fn store_staking_rewards(claims: pb::staking::RewardClaims, s: StoreAddBigInt) {
+    for claim in claims.claims {
+        match claim.amount.parse::<substreams::scalar::BigInt>() {
+            Ok(amount) => s.add(claim.ordinal, staking::reward_key(&claim.holder, &claim.reward_token), amount),
+            Err(_) => logging::warning!("staking", "skipping reward claim of {}", claim.amount),
+        }
+    }
+}
+
+/// Extracts the validator deposits made to the beacon chain deposit contract and the withdrawals credited in the block
+#[substreams::handlers::map]
+fn map_staking_flows(blk: eth::Block) -> Result<pb::staking::StakingFlows, substreams::errors::Error> {
+    let mut flows = pb::staking::StakingFlows::default();
+    for trx in blk.transactions() {
+        for log in trx.receipt().logs() {
+            if log.address() != staking::DEPOSIT_CONTRACT {
+                continue;
+            }
+
+            if let Some(deposit) = abi::eth2_deposit::events::DepositEvent::match_and_decode(log.log) {
+                flows.deposits.push(pb::staking::Deposit {
+                    id: eth_utils::record_id(&trx.hash, log.block_index()),
+                    pubkey: deposit.pubkey,
+                    withdrawal_credentials: deposit.withdrawal_credentials,
+                    amount_gwei: staking::read_little_endian_u64(&deposit.amount).unwrap_or(0),
+                    index: staking::read_little_endian_u64(&deposit.index).unwrap_or(0),
+                    depositor: trx.from.clone(),
+                    trx_hash: trx.hash.clone(),
+                    ordinal: log.ordinal(),
+                });
+            }
+        }
+    }
+
+    for change in &blk.balance_changes {
+        if change.reason != staking::WITHDRAWAL_REASON {
+            continue;
+        }
+
+        let balance = |value: &Option<eth::BigInt>| match value {
+            Some(value) => substreams::scalar::BigInt::from_unsigned_bytes_be(&value.bytes),
+            None => substreams::scalar::BigInt::zero(),
+        };
+        flows.withdrawals.push(pb::staking::Withdrawal {
+            address: change.address.clone(),
+            amount: (balance(&change.new_value) - balance(&change.old_value)).to_string(),
+            ordinal: change.ordinal,
+        });
+    }
+
+    Ok(flows)
+}
+
+/// Extracts the EIP-4844 blob gas of the block, decoding the source block as the Dencun fields eth::Block lacks
+#[substreams::handlers::map]
+fn map_blob_gas(blk: pb::blocks::BlobBlock) -> Result<pb::blocks::BlobGas, substreams::errors::Error> {
+    Ok(fees::blob_gas(&blk))
+}
+
+/// Extracts the transaction count, gas usage, blob gas and EIP-1559 fee market figures of the block
+#[substreams::handlers::map]
+fn map_block_stats(
+    blk: eth::Block,
+    blob_gas: pb::blocks::BlobGas,
+) -> Result<pb::blocks::BlockStats, substreams::errors::Error> {
+    let header = blk.header.clone().unwrap_or_default();
+    let blob_transactions = blk.transaction_traces.iter().filter(|trx| trx.r#type == BLOB_TRX_TYPE);
+    let mut stats = pb::blocks::BlockStats {
+        number: blk.number,
+        timestamp: header.timestamp.clone(),
+        transaction_count: blk.transaction_traces.len() as u64,
+        gas_used: header.gas_used,
+        gas_limit: header.gas_limit,
+        blob_transaction_count: blob_transactions.count() as u64,
+        blob_gas_used: blob_gas.blob_gas_used,
+        blob_count: blob_gas.blob_count,
+        excess_blob_gas: blob_gas.excess_blob_gas,
+        blob_base_fee: blob_gas.blob_base_fee,
+        ..Default::default()
+    };
+
+    if header.base_fee_per_gas.is_some() {
+        let base_fee = fees::big_int(&header.base_fee_per_gas);
+        let priority_fees = fees::priority_fees(&blk, &base_fee);
+        stats.next_base_fee_per_gas = fees::next_base_fee(&base_fee, header.gas_used, header.gas_limit).to_string();
+        stats.priority_fee_p10 = fees::percentile(&priority_fees, 10).to_string();
+        stats.priority_fee_p50 = fees::percentile(&priority_fees, 50).to_string();
+        stats.priority_fee_p90 = fees::percentile(&priority_fees, 90).to_string();
+        stats.burned = (base_fee.clone() * substreams::scalar::BigInt::from(header.gas_used)).to_string();
+        stats.base_fee_per_gas = base_fee.to_string();
+    }
+
+    Ok(stats)
+}
+
+/// Store the ETH burned since London under `burned`, as reported by map_block_stats
+#[substreams::handlers::store]
+fn store_burned_eth(stats: pb::blocks::BlockStats, s: StoreAddBigInt) {
+    use std::str::FromStr;
+
+    if let Ok(burned) = substreams::scalar::BigInt::from_str(&stats.burned) {
+        s.add(0, "burned", burned);
+    }
+}
+
+/// Extracts the EIP-4844 blob carrying transactions of the block with their senders, mostly rollup batchers
+#[substreams::handlers::map]
+fn map_blob_txs(blk: eth::Block) -> Result<pb::blocks::BlobTransactions, substreams::errors::Error> {
+    Ok(pb::blocks::BlobTransactions {
+        transactions: blk
+            .transaction_traces
+            .iter()
+            .filter(|trx| trx.r#type == BLOB_TRX_TYPE)
+            .map(|trx| pb::blocks::BlobTransaction {
+                hash: trx.hash.clone(),
+                from: trx.from.clone(),
+                to: trx.to.clone(),
+                gas_used: trx.gas_used,
+                ordinal: trx.begin_ordinal,
+            })
+            .collect(),
+    })
+}
+
+/// Extracts the call graph of the transactions calling TRACKED_CONTRACT or a contract listed by the registry
+#[substreams::handlers::map]
+fn map_call_graphs(blk: eth::Block, contracts: StoreGetInt64) -> Result<pb::traces::CallGraphs, substreams::errors::Error> {
+    let tracked = |address: &[u8]| {
+        address == TRACKED_CONTRACT || contracts.get_last(contract_key(address)).is_some()
+    };
+
+    Ok(pb::traces::CallGraphs {
+        graphs: blk
+            .transaction_traces
+            .iter()
+            .filter(|trx| trx.calls.iter().any(|call| tracked(&call.address)))
+            .map(traces::call_graph)
+            .collect(),
+    })
+}
+
+/// Emits the NFT transfers, ERC20 transfers and NFT sales of the block as append only rows for the ClickHouse sink,
+/// see `schema.clickhouse.sql` for the table layouts. Blocks outside of the export range get no rows
+#[substreams::handlers::map]
+fn db_out_clickhouse(
+    range: erc721::ExportRange,
+    clock: Clock,
+    transfers: erc721::Transfers,
+    erc20_transfers: pb::tokens::Transfers,
+    sales: erc721::Sales,
+) -> Result<pb::database::DatabaseChanges, substreams::errors::Error> {
+    if !range.contains {
+        return Ok(pb::database::DatabaseChanges::default());
+    }
+
+    let timestamp = clock.timestamp.map(|timestamp| timestamp.seconds).unwrap_or_default();
+
+    // grouped by table so the sink inserts each table in a single batch
+    let mut table_changes = clickhouse::nft_transfers(clock.number, timestamp, &transfers);
+    table_changes.extend(clickhouse::erc20_transfers(clock.number, timestamp, &erc20_transfers));
+    table_changes.extend(clickhouse::nft_sales(clock.number, timestamp, &sales));
+    Ok(pb::database::DatabaseChanges { table_changes })
+}
+
+/// Bundles the NFT transfers, ERC20 transfers and NFT sales of the block by transaction, as documents of the
+/// `transactions` collection for the MongoDB sink. Blocks outside of the export range get no documents
+#[substreams::handlers::map]
+fn mongo_out(
+    range: erc721::ExportRange,
+    clock: Clock,
+    transfers: erc721::Transfers,
+    erc20_transfers: pb::tokens::Transfers,
+    sales: erc721::Sales,
+) -> Result<pb::database::DatabaseChanges, substreams::errors::Error> {
+    if !range.contains {
+        return Ok(pb::database::DatabaseChanges::default());
+    }
+
+    let mut documents = documents::Documents::new(clock.number, clock.timestamp);
+    documents.nft_transfers(&transfers);
+    documents.erc20_transfers(&erc20_transfers);
+    documents.sales(&sales);
+    Ok(pb::database::DatabaseChanges {
+        table_changes: documents.into_table_changes(),
+    })
+}
+
+/// Renders the whale alerts, mixer interactions and revert audit discrepancies as webhook payloads, ready to be
+/// posted to Slack or Discord as set by the `format` param
+#[substreams::handlers::map]
+fn map_webhook_events(
+    params: Params,
+    clock: Clock,
+    whale_alerts: erc721::WhaleAlerts,
+    activity: erc721::TaggedActivity,
+    revert_audit: erc721::RevertAudit,
+) -> Result<pb::alerts::WebhookEvents, substreams::errors::Error> {
+    let format = webhooks::format(&params);
+    let mut events: Vec<pb::alerts::WebhookEvent> = whale_alerts
+        .alerts
+        .iter()
+        .map(|alert| webhooks::whale_alert(&format, alert))
+        .collect();
+    for transfer in &activity.transfers {
+        events.extend(webhooks::mixer_alerts(&format, clock.number, transfer));
+    }
+    for discrepancy in &revert_audit.discrepancies {
+        events.push(webhooks::revert_audit(&format, clock.number, discrepancy));
+    }
+
+    Ok(pb::alerts::WebhookEvents { events })
+}
+
+/// Emits the Token, Owner, Contract and Transfer entities of the community ERC721 subgraph schema, see
+/// `schema.graphql`, so a subgraph can be swapped for this package without changing its queries
+#[substreams::handlers::map]
+fn graph_out(
+    clock: Clock,
+    transfers: erc721::Transfers,
+    balances: Deltas<DeltaInt64>,
+    supply: Deltas<DeltaInt64>,
+) -> Result<pb::entity::EntityChanges, substreams::errors::Error> {
+    use substreams::pb::substreams::store_delta::Operation;
+
+    let mut entity_changes = vec![];
+    for transfer in &transfers.transfers {
+        entity_changes.push(subgraph::transfer(clock.number, transfer));
+        entity_changes.push(subgraph::token(transfer));
+    }
+
+    for delta in balances.deltas.iter() {
+        // keys of store_transfers are `total:<holder>:<contract>`
+        let holder = match keys::parse(&delta.key).and_then(|key| key.hex(0)) {
+            Some(holder) => holder,
+            None => continue,
+        };
+        let created = delta.operation == Operation::Create;
+        entity_changes.push(subgraph::owner(&holder, delta.ordinal, delta.new_value, created));
+    }
+
+    let mut rpc = RpcCounters::default();
+    for delta in supply.deltas.iter() {
+        let contract = match keys::parse(&delta.key).filter(|key| key.is(keys::SUPPLY)).and_then(|key| key.hex(0)) {
+            Some(contract) => contract,
+            None => continue,
+        };
+        let created = delta.operation == Operation::Create;
+        let contract = subgraph::contract(&Substreams, &contract, delta.ordinal, delta.new_value, created, &mut rpc);
+        entity_changes.push(contract);
+    }
+    logging::debug!("subgraph", "{} rpc calls, {} failed", rpc.calls, rpc.failures);
+
+    Ok(pb::entity::EntityChanges { entity_changes })
+}
+
+/// Store the collections and traders seen on each marketplace, and the buyers and sellers of each collection
+#[substreams::handlers::store]
+fn store_trade_members(sales: erc721::Sales, s: StoreSetIfNotExistsRaw) {
+    for sale in &sales.sales {
+        if let Some((marketplace, _)) = messari::marketplace(sale) {
+            for key in messari::member_keys(sale, &marketplace) {
+                s.set_if_not_exists(sale.ordinal, key, &"1");
+            }
+        }
+    }
+}
+
+/// Store the trade count and ETH volume of each marketplace and collection, with their number of distinct
+/// collections, traders, buyers and sellers
+#[substreams::handlers::store]
+fn store_trade_stats(sales: erc721::Sales, members: Deltas<DeltaString>, s: StoreAddBigInt) {
+    use substreams::scalar::BigInt;
+
+    for sale in &sales.sales {
+        let (marketplace, _) = match messari::marketplace(sale) {
+            Some(marketplace) => marketplace,
+            None => continue,
+        };
+        let volume = messari::price_eth(sale).unwrap_or_else(BigInt::zero);
+        s.add(sale.ordinal, messari::marketplace_key(&marketplace, "trades"), BigInt::one());
+        s.add(sale.ordinal, messari::marketplace_key(&marketplace, "volume"), volume.clone());
+        s.add(sale.ordinal, messari::collection_key(&sale.collection, "trades"), BigInt::one());
+        s.add(sale.ordinal, messari::collection_key(&sale.collection, "volume"), volume);
+    }
+
+    for delta in members.deltas.iter() {
+        if delta.operation != substreams::pb::substreams::store_delta::Operation::Create {
+            continue;
+        }
+        if let Some(counter) = messari::member_counter(&delta.key) {
+            s.add(delta.ordinal, counter, BigInt::one());
+        }
+    }
+}
+
+/// Emits the Marketplace, Collection and Trade entities of the Messari NFT marketplace schema, see
+/// `schema.messari.graphql`
+#[substreams::handlers::map]
+fn graph_out_messari(
+    clock: Clock,
+    sales: erc721::Sales,
+    stats: StoreGetBigInt,
+) -> Result<pb::entity::EntityChanges, substreams::errors::Error> {
+    let mut entity_changes = messari::trades(clock.number, &sales.sales);
+
+    let mut marketplaces: BTreeMap<[u8; 20], (&str, i32, u64)> = BTreeMap::new();
+    let mut collections: BTreeMap<Vec<u8>, (i32, u64)> = BTreeMap::new();
+    for sale in &sales.sales {
+        if let Some((address, name)) = messari::marketplace(sale) {
+            let marketplace = marketplaces.entry(address).or_insert((name, 0, 0));
+            marketplace.1 += 1;
+            marketplace.2 = sale.ordinal;
+            let collection = collections.entry(sale.collection.clone()).or_default();
+            collection.0 += 1;
+            collection.1 = sale.ordinal;
+        }
+    }
+
+    for (address, (name, trades, ordinal)) in marketplaces {
+        entity_changes.push(messari::marketplace_entity(&address, name, trades, ordinal, &stats));
+    }
+    let mut rpc = RpcCounters::default();
+    for (address, (trades, ordinal)) in collections {
+        entity_changes.push(messari::collection_entity(&Substreams, &address, trades, ordinal, &stats, &mut rpc));
+    }
+    logging::debug!("marketplaces", "{} rpc calls, {} failed", rpc.calls, rpc.failures);
+
+    Ok(pb::entity::EntityChanges { entity_changes })
+}
+
+/// Converts tokens to the legacy shape with hex string addresses, for consumers not migrated to bytes yet
+#[substreams::handlers::map]
+fn map_tokens_legacy(tokens: pb::tokens::Tokens) -> Result<pb::tokens::LegacyTokens, substreams::errors::Error> {
+    Ok(pb::tokens::LegacyTokens {
+        tokens: tokens
+            .tokens
+            .into_iter()
+            .map(|token| pb::tokens::LegacyToken {
+                address: Hex(&token.address).to_string(),
+                name: token.name,
+                symbol: token.symbol,
+                decimals: token.decimals,
+            })
+            .collect(),
+    })
+}
+
+/// Renders transfers and tokens as JSON lines, to eyeball payloads in `substreams run` without protoc. Blocks outside
+/// of the export range get no lines
+#[cfg(feature = "jsonl")]
+#[substreams::handlers::map]
+fn map_jsonl(
+    range: erc721::ExportRange,
+    transfers: erc721::Transfers,
+    tokens: pb::tokens::Tokens,
+) -> Result<pb::debug::JsonLines, substreams::errors::Error> {
+    if !range.contains {
+        return Ok(pb::debug::JsonLines::default());
+    }
+
+    Ok(pb::debug::JsonLines {
+        lines: transfers
+            .transfers
+            .iter()
+            .map(jsonl::transfer)
+            .chain(tokens.tokens.iter().map(jsonl::token))
+            .collect(),
+    })
+}
+
+/// Undoes the deltas of the floor price, first acquisition and balance stores every `undo_every` blocks, as a reorg
+/// would, and reports the keys that do not invert cleanly
+#[cfg(feature = "reorg")]
+#[substreams::handlers::map]
+fn map_reorg_check(
+    params: Params,
+    clock: Clock,
+    floor_prices: Deltas<DeltaBigInt>,
+    first_acquisitions: Deltas<DeltaInt64>,
+    balances: Deltas<DeltaInt64>,
+) -> Result<pb::debug::ReorgCheck, substreams::errors::Error> {
+    let every = params.get("undo_every").and_then(|value| value.parse().ok()).unwrap_or(1).max(1);
+    let mut check = pb::debug::ReorgCheck {
+        block: clock.number,
+        undone: clock.number % every == 0,
+        ..Default::default()
+    };
+    if !check.undone {
+        return Ok(check);
+    }
+
+    // the stores are only seen through their deltas, the block starts from the old values they carry
+    let floor_prices: Vec<reorg::Change<_>> = floor_prices.deltas.iter().map(reorg::Change::from).collect();
+    check.keys += reorg::keys(&floor_prices) as u64;
+    check.problems.extend(reorg::check(&reorg::opening(&floor_prices), &floor_prices));
+    for deltas in [first_acquisitions, balances] {
+        let changes: Vec<reorg::Change<i64>> = deltas.deltas.iter().map(reorg::Change::from).collect();
+        check.keys += reorg::keys(&changes) as u64;
+        check.problems.extend(reorg::check(&reorg::opening(&changes), &changes));
+    }
+    Ok(check)
+}
@@ -0,0 +1,83 @@
+/// Verbosity of the module logs, from the `SUBSTREAMS_LOG` build variable. Messages above it are skipped before being
+/// formatted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl Level {
+    fn parse(value: &str) -> Option<Level> {
+        match value {
+            "off" => Some(Level::Off),
+            "warn" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            _ => None,
+        }
+    }
+}
+
+struct Config {
+    level: Level,
+    subsystems: Vec<(String, Level)>,
+}
+
+impl Config {
+    /// Reads the `SUBSTREAMS_LOG` level and the `SUBSTREAMS_LOG_FILTER` overrides, comma separated
+    /// `<subsystem>:<level>` entries, set when building the package. Only warnings are logged without them.
+    ///
+    /// They are not module params: params are part of the module hashes, raising the verbosity would invalidate the
+    /// cache of the modules logging and of everything downstream.
+    fn from_build_env() -> Config {
+        let level = option_env!("SUBSTREAMS_LOG").and_then(Level::parse).unwrap_or(Level::Warn);
+        let subsystems = option_env!("SUBSTREAMS_LOG_FILTER")
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|entry| {
+                let (subsystem, level) = entry.split_once(':')?;
+                Some((subsystem.trim().to_string(), Level::parse(level.trim())?))
+            })
+            .collect();
+        Config { level, subsystems }
+    }
+}
+
+thread_local! {
+    static CONFIG: Config = Config::from_build_env();
+}
+
+pub fn enabled(subsystem: &str, level: Level) -> bool {
+    CONFIG.with(|config| {
+        let max = config
+            .subsystems
+            .iter()
+            .find(|(name, _)| name == subsystem)
+            .map_or(config.level, |(_, level)| *level);
+        level != Level::Off && level <= max
+    })
+}
+
+macro_rules! log_at {
+    ($level:expr, $subsystem:expr, $($arg:tt)*) => {
+        if crate::logging::enabled($subsystem, $level) {
+            substreams::log::println(format!("[{}] {}", $subsystem, format!($($arg)*)));
+        }
+    };
+}
+
+macro_rules! warning {
+    ($subsystem:expr, $($arg:tt)*) => { crate::logging::log_at!(crate::logging::Level::Warn, $subsystem, $($arg)*) };
+}
+
+macro_rules! info {
+    ($subsystem:expr, $($arg:tt)*) => { crate::logging::log_at!(crate::logging::Level::Info, $subsystem, $($arg)*) };
+}
+
+macro_rules! debug {
+    ($subsystem:expr, $($arg:tt)*) => { crate::logging::log_at!(crate::logging::Level::Debug, $subsystem, $($arg)*) };
+}
+
+pub(crate) use {debug, info, log_at, warning};
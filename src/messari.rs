@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams::store::{StoreGet, StoreGetBigInt};
+use substreams::Hex;
+
+use crate::eth_utils;
+use crate::keys;
+use crate::pb::entity::entity_change::Operation;
+use crate::pb::entity::EntityChange;
+use crate::pb::erc721::Sale;
+use crate::rpc_utils::{self, EthCall, RpcCounters};
+use crate::seaport;
+use crate::subgraph::{owner_id, Entity};
+use crate::sudoswap;
+
+const WETH: [u8; 20] = hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+
+/// Marketplace contract of a sale, the Sudoswap pair factory for pool swaps, and its display name.
+pub fn marketplace(sale: &Sale) -> Option<([u8; 20], &'static str)> {
+    match sale.marketplace.as_str() {
+        "seaport" => Some((seaport::SEAPORT, "Seaport")),
+        "sudoswap" => Some((sudoswap::FACTORY, "Sudoswap")),
+        _ => None,
+    }
+}
+
+/// Price of a sale in wei, WETH counted as ETH, None for sales paid in another token.
+pub fn price_eth(sale: &Sale) -> Option<BigInt> {
+    if !sale.currency.is_empty() && sale.currency != WETH {
+        return None;
+    }
+    BigInt::from_str(&sale.price).ok()
+}
+
+/// Wei amount as an ETH decimal string.
+fn eth(wei: &BigInt) -> String {
+    let digits = format!("{:0>19}", wei.to_string());
+    let (whole, fraction) = digits.split_at(digits.len() - 18);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    }
+}
+
+pub fn member_keys(sale: &Sale, marketplace: &[u8]) -> [String; 5] {
+    [
+        keys::key(keys::COLLECTION).hex(marketplace).hex(&sale.collection).into(),
+        keys::key(keys::TRADER).hex(marketplace).hex(&sale.buyer).into(),
+        keys::key(keys::TRADER).hex(marketplace).hex(&sale.seller).into(),
+        keys::key(keys::BUYER).hex(&sale.collection).hex(&sale.buyer).into(),
+        keys::key(keys::SELLER).hex(&sale.collection).hex(&sale.seller).into(),
+    ]
+}
+
+/// Counter of store_trade_stats a new member of store_trade_members adds to, from its key.
+pub fn member_counter(member_key: &str) -> Option<String> {
+    let parsed = keys::parse(member_key)?;
+    let owner = parsed.hex(0)?;
+    let counter = match parsed.name {
+        "collection" => marketplace_key(&owner, "collections"),
+        "trader" => marketplace_key(&owner, "traders"),
+        "buyer" => collection_key(&owner, "buyers"),
+        "seller" => collection_key(&owner, "sellers"),
+        _ => return None,
+    };
+    Some(counter)
+}
+
+pub fn marketplace_key(marketplace: &[u8], counter: &str) -> String {
+    keys::key(keys::MARKETPLACE).hex(marketplace).text(counter).into()
+}
+
+pub fn collection_key(collection: &[u8], counter: &str) -> String {
+    keys::key(keys::COLLECTION).hex(collection).text(counter).into()
+}
+
+fn count(stats: &StoreGetBigInt, key: String) -> i32 {
+    stats
+        .get_last(key)
+        .and_then(|count| eth_utils::to_u64(&count))
+        .map(|count| count as i32)
+        .unwrap_or(0)
+}
+
+fn volume(stats: &StoreGetBigInt, key: String) -> String {
+    eth(&stats.get_last(key).unwrap_or_else(BigInt::zero))
+}
+
+/// Trade entities of the sales, a bundle being several tokens bought in one go on the same marketplace.
+pub fn trades(block: u64, sales: &[Sale]) -> Vec<EntityChange> {
+    let mut bundles: BTreeMap<(&[u8], &str), u32> = BTreeMap::new();
+    for sale in sales {
+        *bundles.entry((&sale.trx_hash, &sale.marketplace)).or_default() += 1;
+    }
+
+    sales
+        .iter()
+        .filter(|sale| marketplace(sale).is_some())
+        .map(|sale| {
+            let timestamp = sale.timestamp.as_ref().map(|timestamp| timestamp.seconds).unwrap_or_default();
+            let price = price_eth(sale).unwrap_or_else(BigInt::zero);
+            Entity::new("Trade", sale.id.clone(), sale.ordinal, Operation::Create)
+                .string("transactionHash", format!("0x{}", Hex(&sale.trx_hash)))
+                .int("logIndex", sale.log_index as i32)
+                .bigint("timestamp", timestamp)
+                .bigint("blockNumber", block)
+                .bool("isBundle", bundles[&(&sale.trx_hash[..], sale.marketplace.as_str())] > 1)
+                .string("collection", owner_id(&sale.collection))
+                .bigint("tokenId", sale.token_id)
+                .bigint("amount", 1)
+                .bigdecimal("priceETH", eth(&price))
+                .string("strategy", "STANDARD_SALE".to_string())
+                .string("buyer", owner_id(&sale.buyer))
+                .string("seller", owner_id(&sale.seller))
+                .into_change()
+        })
+        .collect()
+}
+
+/// Marketplace entity with its cumulative counters, created along with its first trades.
+pub fn marketplace_entity(
+    address: &[u8],
+    name: &str,
+    trades_in_block: i32,
+    ordinal: u64,
+    stats: &StoreGetBigInt,
+) -> EntityChange {
+    let trade_count = count(stats, marketplace_key(address, "trades"));
+    let operation = if trade_count == trades_in_block { Operation::Create } else { Operation::Update };
+    Entity::new("Marketplace", owner_id(address), ordinal, operation)
+        .string("name", name.to_string())
+        .string("slug", name.to_lowercase())
+        .int("collectionCount", count(stats, marketplace_key(address, "collections")))
+        .int("tradeCount", trade_count)
+        .bigdecimal("cumulativeTradeVolumeETH", volume(stats, marketplace_key(address, "volume")))
+        .int("cumulativeUniqueTraders", count(stats, marketplace_key(address, "traders")))
+        .into_change()
+}
+
+/// Collection entity with its cumulative counters, its name and symbol are read over RPC along with its
+/// first trades.
+pub fn collection_entity(
+    client: &impl EthCall,
+    address: &[u8],
+    trades_in_block: i32,
+    ordinal: u64,
+    stats: &StoreGetBigInt,
+    rpc: &mut RpcCounters,
+) -> EntityChange {
+    let trade_count = count(stats, collection_key(address, "trades"));
+    let created = trade_count == trades_in_block;
+    let operation = if created { Operation::Create } else { Operation::Update };
+    let mut entity = Entity::new("Collection", owner_id(address), ordinal, operation)
+        .string("nftStandard", "ERC721".to_string())
+        .int("tradeCount", trade_count)
+        .bigdecimal("cumulativeTradeVolumeETH", volume(stats, collection_key(address, "volume")))
+        .int("buyerCount", count(stats, collection_key(address, "buyers")))
+        .int("sellerCount", count(stats, collection_key(address, "sellers")));
+
+    if created {
+        let (name, symbol) = rpc_utils::name_and_symbol(client, address, rpc);
+        if let Some(name) = name {
+            entity = entity.string("name", name);
+        }
+        if let Some(symbol) = symbol {
+            entity = entity.string("symbol", symbol);
+        }
+    }
+    entity.into_change()
+}
@@ -0,0 +1,196 @@
+use crate::eth_utils;
+use crate::pb::erc721::{TokenMetadata, Trait};
+use crate::rpc_utils::{self, EthCall, RpcCounters};
+
+/// Longest contract or logo URI kept, inline `data:` URIs can embed whole images.
+pub const MAX_URI_BYTES: usize = 4096;
+
+/// ERC-7572 contract URI and logo URI of the contract, empty when unknown. Proxies whose own code does not answer
+/// `contractURI()` are read through their `implementation`, which contracts keeping their metadata as constants of
+/// the implementation code answer for them.
+pub fn uris(
+    client: &impl EthCall,
+    address: &[u8],
+    implementation: Option<&[u8]>,
+    rpc: &mut RpcCounters,
+) -> (String, String) {
+    let contract_uri = match rpc_utils::contract_uri(client, address, rpc) {
+        Some(contract_uri) => contract_uri,
+        None => match implementation.and_then(|implementation| rpc_utils::contract_uri(client, implementation, rpc)) {
+            Some(contract_uri) => contract_uri,
+            None => return (String::new(), String::new()),
+        },
+    };
+    let logo_uri = logo_uri(&contract_uri).unwrap_or_default();
+    if contract_uri.len() > MAX_URI_BYTES {
+        return (String::new(), logo_uri);
+    }
+    (contract_uri, logo_uri)
+}
+
+/// Logo of a contract from its ERC-7572 `contractURI()`, when the URI inlines its JSON metadata. Metadata
+/// hosted off-chain (https, ipfs) can't be read from a module, its logo is left for consumers to resolve.
+pub fn logo_uri(contract_uri: &str) -> Option<String> {
+    let json = inline_json(contract_uri)?;
+    ["image", "logo", "image_url"]
+        .iter()
+        .find_map(|key| json_string_field(&json, key))
+        .filter(|logo| !logo.is_empty() && logo.len() <= MAX_URI_BYTES)
+}
+
+/// JSON document inlined by a `data:application/json` URI, base64 encoded or not.
+pub fn inline_json(uri: &str) -> Option<String> {
+    let (header, payload) = uri.strip_prefix("data:application/json")?.split_once(',')?;
+    if header.ends_with(";base64") {
+        return String::from_utf8(eth_utils::base64_decode(payload)?).ok();
+    }
+    Some(payload.to_string())
+}
+
+/// Metadata of a token of a fully on-chain collection, decoded from its `data:application/json` token URI, with its
+/// SVG image and traits. None for metadata hosted off-chain.
+pub fn token_metadata(token_uri: &str) -> Option<TokenMetadata> {
+    let json = inline_json(token_uri)?;
+    let image = json_string_field(&json, "image").unwrap_or_default();
+    let image_svg = svg(&image).unwrap_or_default();
+    Some(TokenMetadata {
+        name: json_string_field(&json, "name").unwrap_or_default(),
+        description: json_string_field(&json, "description").unwrap_or_default(),
+        // inline SVGs are emitted decoded, the data URI would only repeat them
+        image: if image_svg.is_empty() { image } else { String::new() },
+        image_svg,
+        attributes: attributes(&json),
+        ..Default::default()
+    })
+}
+
+/// SVG document of a `data:image/svg+xml` image URI, base64 encoded or not.
+fn svg(image: &str) -> Option<String> {
+    let (header, payload) = image.strip_prefix("data:image/svg+xml")?.split_once(',')?;
+    if header.ends_with(";base64") {
+        return String::from_utf8(eth_utils::base64_decode(payload)?).ok();
+    }
+    Some(payload.to_string())
+}
+
+/// Traits of the OpenSea `attributes` array, `{"trait_type": ..., "value": ...}` objects whose value is a string, a
+/// number or a boolean.
+fn attributes(json: &str) -> Vec<Trait> {
+    let rest = match top_level_value(json, "attributes").and_then(|value| value.strip_prefix('[')) {
+        Some(rest) => rest,
+        None => return vec![],
+    };
+
+    let mut traits = vec![];
+    let (mut depth, mut in_string, mut escaped, mut object_start) = (0, false, false, 0);
+    for (index, c) in rest.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            _ if in_string => {}
+            '{' => {
+                if depth == 0 {
+                    object_start = index;
+                }
+                depth += 1;
+            }
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    let object = &rest[object_start..=index];
+                    let value = json_string_field(object, "value").or_else(|| json_scalar_field(object, "value"));
+                    if let Some(value) = value {
+                        let trait_type = json_string_field(object, "trait_type").unwrap_or_default();
+                        traits.push(Trait { trait_type, value });
+                    }
+                }
+            }
+            ']' if depth == 0 => break,
+            _ => {}
+        }
+    }
+    traits
+}
+
+/// Raw value of the top-level `"key": value` number or boolean field of a JSON object.
+fn json_scalar_field(json: &str, key: &str) -> Option<String> {
+    let rest = top_level_value(json, key)?;
+    let end = rest.find(|c: char| c == ',' || c == '}' || c.is_whitespace()).unwrap_or(rest.len());
+    Some(rest[..end].to_string()).filter(|value| !value.is_empty() && !value.starts_with('"'))
+}
+
+/// Value of the top-level `"key": "value"` string field of a JSON object, without a full parser.
+fn json_string_field(json: &str, key: &str) -> Option<String> {
+    let rest = top_level_value(json, key)?.strip_prefix('"')?;
+
+    let mut value = String::new();
+    let mut chars = rest.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Some(value),
+            '\\' => match chars.next()? {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                'b' => value.push('\u{8}'),
+                'f' => value.push('\u{c}'),
+                'u' => value.push(unicode_escape(&mut chars)?),
+                escaped => value.push(escaped),
+            },
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+/// Raw JSON value of the top-level `key` of a JSON object, from its first character on. Keys of nested objects, as
+/// the `name` trait of an attribute, and string values reading like the key are skipped.
+fn top_level_value<'a>(json: &'a str, key: &str) -> Option<&'a str> {
+    let (mut depth, mut in_string, mut escaped, mut string_start) = (0, false, false, 0);
+    for (index, c) in json.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' if in_string => {
+                in_string = false;
+                // only keys are followed by a colon
+                let value = json[index + 1..].trim_start().strip_prefix(':');
+                if let Some(value) = value.filter(|_| depth == 1 && json[string_start..index] == *key) {
+                    return Some(value.trim_start());
+                }
+            }
+            '"' => {
+                in_string = true;
+                string_start = index + 1;
+            }
+            _ if in_string => {}
+            '{' | '[' => depth += 1,
+            '}' | ']' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Character of a `\uXXXX` escape whose `\u` was read. Characters past the Basic Multilingual Plane are escaped as
+/// a UTF-16 surrogate pair, `\ud83d\ude00`.
+fn unicode_escape(chars: &mut std::str::Chars) -> Option<char> {
+    let high = code_unit(chars)?;
+    if !(0xd800..0xdc00).contains(&high) {
+        return char::from_u32(high);
+    }
+    if chars.next()? != '\\' || chars.next()? != 'u' {
+        return None;
+    }
+    let low = code_unit(chars).filter(|low| (0xdc00..0xe000).contains(low))?;
+    char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00))
+}
+
+fn code_unit(chars: &mut std::str::Chars) -> Option<u32> {
+    let code: String = chars.by_ref().take(4).collect();
+    if code.len() != 4 {
+        return None;
+    }
+    u32::from_str_radix(&code, 16).ok()
+}
@@ -0,0 +1,12 @@
+pub const TRANSFERS_TOTAL: &str = "transfers_total";
+pub const TOKENS_DISCOVERED_TOTAL: &str = "tokens_discovered_total";
+pub const RPC_CALLS_TOTAL: &str = "rpc_calls_total";
+pub const RPC_FAILURES_TOTAL: &str = "rpc_failures_total";
+
+/// Counters kept in `store_metrics`, with their help text.
+pub const COUNTERS: [(&str, &str); 4] = [
+    (TRANSFERS_TOTAL, "Transfers of the tracked collection"),
+    (TOKENS_DISCOVERED_TOTAL, "ERC20 tokens discovered or hydrated"),
+    (RPC_CALLS_TOTAL, "eth_call requests made to probe token contracts"),
+    (RPC_FAILURES_TOTAL, "eth_call requests that failed"),
+];
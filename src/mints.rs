@@ -0,0 +1,83 @@
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::NULL_ADDRESS;
+
+use crate::eth_utils;
+use crate::pb::erc721::mint_purchase::Phase;
+use crate::pb::erc721::MintPurchase;
+use crate::selectors;
+use crate::transfers;
+
+/// Phase of the mint function called with `input`. Selectors listed in `allowlist` and functions taking a merkle proof
+/// or named after an allowlist are allowlist mints, other known functions are public ones.
+pub fn phase(input: &[u8], allowlist: &[&str]) -> Phase {
+    if input.len() >= 4 && allowlist.contains(&hex::encode(&input[..4]).as_str()) {
+        return Phase::Allowlist;
+    }
+
+    match selectors::signature(input) {
+        Some(signature) => {
+            let name = signature.split('(').next().unwrap_or_default().to_lowercase();
+            let allowlisted = ["allowlist", "whitelist", "presale"].iter().any(|prefix| name.starts_with(prefix));
+            if allowlisted || signature.contains("bytes32[]") {
+                Phase::Allowlist
+            } else {
+                Phase::Public
+            }
+        }
+        None => Phase::Unspecified,
+    }
+}
+
+/// Mints of `contract` in the transaction grouped by the call that paid for them, the outermost call into the
+/// contract whose execution emitted them. Calls from the contract to itself and through its proxy are counted once.
+pub fn purchases(trx: &eth::TransactionTrace, contract: &[u8], allowlist: &[&str]) -> Vec<MintPurchase> {
+    let mints: Vec<(u64, BigInt)> = trx
+        .receipt
+        .as_ref()
+        .map(|receipt| receipt.logs.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter(|log| log.address == contract)
+        .filter_map(|log| transfers::decode(log).map(|transfer| (log.ordinal, transfer)))
+        .filter(|(_, transfer)| transfer.from == NULL_ADDRESS)
+        .map(|(ordinal, transfer)| (ordinal, transfer.token_id))
+        .collect();
+    if mints.is_empty() {
+        return vec![];
+    }
+
+    let mut purchases = vec![];
+    let mut end_ordinal = 0;
+    for call in trx.calls.iter().filter(|call| call.address == contract && !call.state_reverted) {
+        if call.begin_ordinal < end_ordinal {
+            continue;
+        }
+        end_ordinal = call.end_ordinal;
+
+        let token_ids: Vec<u64> = mints
+            .iter()
+            .filter(|(ordinal, _)| (call.begin_ordinal..call.end_ordinal).contains(ordinal))
+            .filter_map(|(_, token_id)| eth_utils::to_u64(token_id))
+            .collect();
+        if token_ids.is_empty() {
+            continue;
+        }
+
+        let paid = call.value.as_ref().map_or_else(BigInt::zero, |value| BigInt::from_unsigned_bytes_be(&value.bytes));
+        purchases.push(MintPurchase {
+            id: eth_utils::call_record_id(&trx.hash, call.index),
+            trx_hash: trx.hash.clone(),
+            minter: call.caller.clone(),
+            method: selectors::describe(&call.input),
+            phase: phase(&call.input, allowlist) as i32,
+            quantity: token_ids.len() as u64,
+            unit_price: (paid.clone() / BigInt::from(token_ids.len() as u64)).to_string(),
+            paid: paid.to_string(),
+            token_ids,
+            ordinal: call.begin_ordinal,
+            ..Default::default()
+        });
+    }
+    purchases
+}
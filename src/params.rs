@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use prost::bytes::{Buf, BufMut};
+use prost::encoding::{skip_field, DecodeContext, WireType};
+use prost::DecodeError;
+
+use crate::eth_utils;
+use crate::logging;
+
+/// Module parameters, declared in the manifest as a `key=value&key=value` string.
+///
+/// The handler macro decodes every non-store input as a protobuf message, while the
+/// runtime hands params over as the raw string bytes, so `Params` implements
+/// `prost::Message` itself and reads the whole buffer as text.
+#[derive(Clone, Debug, Default)]
+pub struct Params {
+    raw: String,
+    values: HashMap<String, String>,
+}
+
+/// Range of blocks a bounded export covers, see `Params::block_range`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockRange {
+    pub start: u64,
+    pub stop: Option<u64>,
+}
+
+impl BlockRange {
+    pub fn contains(&self, block: u64) -> bool {
+        block >= self.start && self.stop.map_or(true, |stop| block < stop)
+    }
+
+    /// Whether `block` is the last one of a bounded range.
+    pub fn ends_at(&self, block: u64) -> bool {
+        self.stop == Some(block + 1) && self.contains(block)
+    }
+}
+
+impl Params {
+    pub fn parse(raw: &str) -> Params {
+        let mut values = HashMap::new();
+        for pair in raw.trim().split('&') {
+            let pair = pair.trim();
+            if pair.is_empty() {
+                continue;
+            }
+
+            match pair.split_once('=') {
+                Some((key, value)) => values.insert(key.trim().to_string(), value.trim().to_string()),
+                None => values.insert(pair.to_string(), String::new()),
+            };
+        }
+
+        Params {
+            raw: raw.to_string(),
+            values,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|value| value.as_str())
+    }
+
+    /// Whether `key` is set to `true` or `1`.
+    pub fn flag(&self, key: &str) -> bool {
+        matches!(self.get(key), Some("true") | Some("1"))
+    }
+
+    /// Comma separated values of `key`, empty entries are skipped.
+    pub fn list(&self, key: &str) -> Vec<&str> {
+        match self.get(key) {
+            Some(value) => value
+                .split(',')
+                .map(|item| item.trim())
+                .filter(|item| !item.is_empty())
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// Blocks from `start_block` up to `stop_block` excluded, as the `substreams run` flags. Either end is open when
+    /// missing or invalid.
+    pub fn block_range(&self) -> BlockRange {
+        let block = |key| self.get(key).and_then(|value| value.parse().ok());
+        BlockRange {
+            start: block("start_block").unwrap_or(0),
+            stop: block("stop_block"),
+        }
+    }
+
+    /// Addresses listed under `key`, invalid entries are logged and skipped.
+    pub fn addresses(&self, key: &str) -> Vec<Vec<u8>> {
+        let mut addresses = vec![];
+        for entry in self.list(key) {
+            match eth_utils::parse_address(entry) {
+                Ok(address) => addresses.push(address),
+                Err(e) => logging::warning!("params", "skipping invalid {} entry: {}", key, e),
+            }
+        }
+        addresses
+    }
+}
+
+impl prost::Message for Params {
+    fn encode_raw<B>(&self, buf: &mut B)
+    where
+        B: BufMut,
+    {
+        buf.put_slice(self.raw.as_bytes());
+    }
+
+    fn merge_field<B>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        skip_field(wire_type, tag, buf, ctx)
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.raw.len()
+    }
+
+    fn clear(&mut self) {
+        *self = Params::default();
+    }
+
+    fn merge<B>(&mut self, mut buf: B) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        let raw = buf.copy_to_bytes(buf.remaining());
+        match std::str::from_utf8(&raw) {
+            Ok(raw) => {
+                *self = Params::parse(raw);
+                Ok(())
+            }
+            Err(_) => Err(DecodeError::new("params are not valid utf-8")),
+        }
+    }
+}
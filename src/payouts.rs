@@ -0,0 +1,62 @@
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::payment_splitter::events as splitter;
+use crate::eth_utils;
+use crate::pb::erc721::creator_payout::Kind;
+use crate::pb::erc721::CreatorPayout;
+
+/// Payouts of the revenue of `contract` in the transaction: releases of PaymentSplitter contracts, the collection
+/// itself or one of the `splitters`, and ETH sent by the collection otherwise. ETH sent to the splitters stays in the
+/// treasury and is left out, their releases are the payouts.
+pub fn decode(trx: &eth::TransactionTrace, contract: &[u8], splitters: &[Vec<u8>]) -> Vec<CreatorPayout> {
+    let from_treasury = |address: &[u8]| address == contract || splitters.iter().any(|splitter| splitter == address);
+    let payout = |kind: Kind, source: &[u8], recipient: Vec<u8>, amount: BigInt, ordinal, id| CreatorPayout {
+        id,
+        kind: kind as i32,
+        source: source.to_vec(),
+        recipient,
+        amount: amount.to_string(),
+        trx_hash: trx.hash.clone(),
+        ordinal,
+        ..Default::default()
+    };
+
+    let mut payouts = vec![];
+    for log in trx.receipt.as_ref().map(|receipt| receipt.logs.as_slice()).unwrap_or_default() {
+        if !from_treasury(&log.address) {
+            continue;
+        }
+        if let Some(released) = splitter::PaymentReleased::match_and_decode(log) {
+            let id = eth_utils::record_id(&trx.hash, log.block_index);
+            payouts.push(payout(Kind::Release, &log.address, released.to, released.amount, log.ordinal, id));
+        } else if let Some(released) = splitter::Erc20PaymentReleased::match_and_decode(log) {
+            let id = eth_utils::record_id(&trx.hash, log.block_index);
+            payouts.push(CreatorPayout {
+                currency: released.token,
+                ..payout(Kind::Release, &log.address, released.to, released.amount, log.ordinal, id)
+            });
+        }
+    }
+
+    for call in trx.calls.iter().filter(|call| call.caller == contract && !call.state_reverted) {
+        let amount =
+            call.value.as_ref().map_or_else(BigInt::zero, |value| BigInt::from_unsigned_bytes_be(&value.bytes));
+        if amount.is_zero() || from_treasury(&call.address) {
+            continue;
+        }
+        // the collection released its own splitter shares
+        let released = |payout: &CreatorPayout| {
+            payout.source == contract && payout.currency.is_empty() && payout.recipient == call.address
+        };
+        if payouts.iter().any(|payout| released(payout) && payout.amount == amount.to_string()) {
+            continue;
+        }
+        let id = eth_utils::call_record_id(&trx.hash, call.index);
+        payouts.push(payout(Kind::Withdrawal, contract, call.address.clone(), amount, call.begin_ordinal, id));
+    }
+
+    payouts.sort_by_key(|payout| payout.ordinal);
+    payouts
+}
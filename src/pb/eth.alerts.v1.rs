@@ -0,0 +1,84 @@
+// @generated
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WebhookEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<WebhookEvent>,
+}
+/// An alert rendered for a chat webhook, a sink only has to POST `payload` as is.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WebhookEvent {
+    /// Same key for the same alert, to drop it when a block is processed again.
+    #[prost(string, tag="1")]
+    pub dedupe_key: ::prost::alloc::string::String,
+    /// `warning` for whale moves, `critical` for mixer interactions and revert audit discrepancies.
+    #[prost(string, tag="2")]
+    pub severity: ::prost::alloc::string::String,
+    /// JSON body of the webhook request, in the format set by the `format` param.
+    #[prost(string, tag="3")]
+    pub payload: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub block: u64,
+}
+/// Encoded file descriptor set for the `eth.alerts.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0xa2, 0x07, 0x0a, 0x0c, 0x61, 0x6c, 0x65, 0x72, 0x74, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x12, 0x0d, 0x65, 0x74, 0x68, 0x2e, 0x61, 0x6c, 0x65, 0x72, 0x74, 0x73, 0x2e, 0x76, 0x31,
+    0x22, 0x44, 0x0a, 0x0d, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x45, 0x76, 0x65, 0x6e, 0x74,
+    0x73, 0x12, 0x33, 0x0a, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28,
+    0x0b, 0x32, 0x1b, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x61, 0x6c, 0x65, 0x72, 0x74, 0x73, 0x2e, 0x76,
+    0x31, 0x2e, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x06,
+    0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x22, 0x79, 0x0a, 0x0c, 0x57, 0x65, 0x62, 0x68, 0x6f, 0x6f,
+    0x6b, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x64, 0x65, 0x64, 0x75, 0x70, 0x65,
+    0x5f, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x64, 0x65, 0x64, 0x75,
+    0x70, 0x65, 0x4b, 0x65, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x73, 0x65, 0x76, 0x65, 0x72, 0x69, 0x74,
+    0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x73, 0x65, 0x76, 0x65, 0x72, 0x69, 0x74,
+    0x79, 0x12, 0x18, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x07, 0x70, 0x61, 0x79, 0x6c, 0x6f, 0x61, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x4a, 0xb9, 0x05, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x11, 0x01, 0x0a, 0x08, 0x0a, 0x01,
+    0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x16,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x04, 0x00, 0x06, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00,
+    0x12, 0x03, 0x05, 0x02, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03,
+    0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x05, 0x0b,
+    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x05, 0x18, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x05, 0x21, 0x22, 0x0a, 0x5c, 0x0a,
+    0x02, 0x04, 0x01, 0x12, 0x04, 0x09, 0x00, 0x11, 0x01, 0x1a, 0x50, 0x20, 0x41, 0x6e, 0x20, 0x61,
+    0x6c, 0x65, 0x72, 0x74, 0x20, 0x72, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x65, 0x64, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x61, 0x20, 0x63, 0x68, 0x61, 0x74, 0x20, 0x77, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b,
+    0x2c, 0x20, 0x61, 0x20, 0x73, 0x69, 0x6e, 0x6b, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x68, 0x61,
+    0x73, 0x20, 0x74, 0x6f, 0x20, 0x50, 0x4f, 0x53, 0x54, 0x20, 0x60, 0x70, 0x61, 0x79, 0x6c, 0x6f,
+    0x61, 0x64, 0x60, 0x20, 0x61, 0x73, 0x20, 0x69, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x01, 0x01, 0x12, 0x03, 0x09, 0x08, 0x14, 0x0a, 0x57, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12,
+    0x03, 0x0b, 0x02, 0x18, 0x1a, 0x4a, 0x20, 0x53, 0x61, 0x6d, 0x65, 0x20, 0x6b, 0x65, 0x79, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x6c, 0x65,
+    0x72, 0x74, 0x2c, 0x20, 0x74, 0x6f, 0x20, 0x64, 0x72, 0x6f, 0x70, 0x20, 0x69, 0x74, 0x20, 0x77,
+    0x68, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x69, 0x73, 0x20, 0x70,
+    0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x65, 0x64, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0b, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0b, 0x16, 0x17, 0x0a, 0x6b, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x01, 0x12, 0x03, 0x0d, 0x02, 0x16, 0x1a, 0x5e, 0x20, 0x60, 0x77, 0x61, 0x72, 0x6e, 0x69,
+    0x6e, 0x67, 0x60, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x61, 0x6c, 0x65, 0x20, 0x6d, 0x6f,
+    0x76, 0x65, 0x73, 0x2c, 0x20, 0x60, 0x63, 0x72, 0x69, 0x74, 0x69, 0x63, 0x61, 0x6c, 0x60, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x6d, 0x69, 0x78, 0x65, 0x72, 0x20, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x61,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x65, 0x76, 0x65, 0x72,
+    0x74, 0x20, 0x61, 0x75, 0x64, 0x69, 0x74, 0x20, 0x64, 0x69, 0x73, 0x63, 0x72, 0x65, 0x70, 0x61,
+    0x6e, 0x63, 0x69, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05,
+    0x12, 0x03, 0x0d, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x0d, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0d, 0x14,
+    0x15, 0x0a, 0x59, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x0f, 0x02, 0x15, 0x1a, 0x4c,
+    0x20, 0x4a, 0x53, 0x4f, 0x4e, 0x20, 0x62, 0x6f, 0x64, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x77, 0x65, 0x62, 0x68, 0x6f, 0x6f, 0x6b, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74,
+    0x20, 0x73, 0x65, 0x74, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x66, 0x6f, 0x72,
+    0x6d, 0x61, 0x74, 0x60, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x0f, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03,
+    0x10, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x05, 0x12, 0x03, 0x10, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03, 0x10, 0x09, 0x0e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x10, 0x11, 0x12, 0x62, 0x06, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x33,
+];
+// @@protoc_insertion_point(module)
\ No newline at end of file
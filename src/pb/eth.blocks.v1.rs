@@ -0,0 +1,264 @@
+// @generated
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockStats {
+    #[prost(uint64, tag="1")]
+    pub number: u64,
+    #[prost(message, optional, tag="2")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag="3")]
+    pub transaction_count: u64,
+    #[prost(uint64, tag="4")]
+    pub gas_used: u64,
+    #[prost(uint64, tag="5")]
+    pub gas_limit: u64,
+    /// EIP-4844 transactions carrying blobs.
+    #[prost(uint64, tag="6")]
+    pub blob_transaction_count: u64,
+    /// EIP-1559 fee market, in wei as decimal strings. Empty before London.
+    #[prost(string, tag="7")]
+    pub base_fee_per_gas: ::prost::alloc::string::String,
+    /// Base fee the next block will have, from how far this block gas usage is from its target.
+    #[prost(string, tag="8")]
+    pub next_base_fee_per_gas: ::prost::alloc::string::String,
+    /// Priority fee per gas paid by the transactions, the effective gas price above the base fee.
+    #[prost(string, tag="9")]
+    pub priority_fee_p10: ::prost::alloc::string::String,
+    #[prost(string, tag="10")]
+    pub priority_fee_p50: ::prost::alloc::string::String,
+    #[prost(string, tag="11")]
+    pub priority_fee_p90: ::prost::alloc::string::String,
+    /// ETH burned by the block, its base fee times its gas used.
+    #[prost(string, tag="12")]
+    pub burned: ::prost::alloc::string::String,
+    /// EIP-4844 blob gas, from map_blob_gas. Zero before Dencun.
+    #[prost(uint64, tag="13")]
+    pub blob_gas_used: u64,
+    #[prost(uint64, tag="14")]
+    pub blob_count: u64,
+    #[prost(uint64, tag="15")]
+    pub excess_blob_gas: u64,
+    /// Blob base fee in wei as a decimal string, paid by the blob transactions. Empty when the block has none.
+    #[prost(string, tag="16")]
+    pub blob_base_fee: ::prost::alloc::string::String,
+}
+/// EIP-4844 blob gas of a block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlobGas {
+    #[prost(uint64, tag="1")]
+    pub blob_gas_used: u64,
+    /// Blobs posted by the block, its blob gas used over the gas of a blob.
+    #[prost(uint64, tag="2")]
+    pub blob_count: u64,
+    #[prost(uint64, tag="3")]
+    pub excess_blob_gas: u64,
+    /// Blob base fee in wei as a decimal string, paid by the blob transactions. Empty when the block has none.
+    #[prost(string, tag="4")]
+    pub blob_base_fee: ::prost::alloc::string::String,
+}
+/// The EIP-4844 fields of sf.ethereum.type.v2.Block under their tags there, which the substreams-ethereum 0.8 block
+/// model predates. map_blob_gas decodes its source block as this message, skipping every other field.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlobBlock {
+    #[prost(message, optional, tag="5")]
+    pub header: ::core::option::Option<BlobHeader>,
+    #[prost(message, repeated, tag="10")]
+    pub transaction_traces: ::prost::alloc::vec::Vec<BlobTrace>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlobHeader {
+    #[prost(uint64, optional, tag="22")]
+    pub blob_gas_used: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="23")]
+    pub excess_blob_gas: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlobTrace {
+    #[prost(message, optional, tag="31")]
+    pub receipt: ::core::option::Option<BlobReceipt>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlobReceipt {
+    #[prost(uint64, optional, tag="5")]
+    pub blob_gas_used: ::core::option::Option<u64>,
+    #[prost(message, optional, tag="6")]
+    pub blob_gas_price: ::core::option::Option<BigInt>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BigInt {
+    #[prost(bytes="vec", tag="1")]
+    pub bytes: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlobTransactions {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<BlobTransaction>,
+}
+/// An EIP-4844 transaction, sent by rollup batchers to post their data as blobs.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlobTransaction {
+    #[prost(bytes="vec", tag="1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    /// Batch inbox or rollup contract the blobs are posted to.
+    #[prost(bytes="vec", tag="3")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="4")]
+    pub gas_used: u64,
+    #[prost(uint64, tag="5")]
+    pub ordinal: u64,
+}
+/// Encoded file descriptor set for the `eth.blocks.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0xdd, 0x12, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x12, 0x0d, 0x65, 0x74, 0x68, 0x2e, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x2e, 0x76, 0x31,
+    0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75,
+    0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x22, 0xea, 0x03, 0x0a, 0x0a, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x53, 0x74, 0x61, 0x74, 0x73,
+    0x12, 0x16, 0x0a, 0x06, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x06, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65,
+    0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f,
+    0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69,
+    0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x12, 0x2b, 0x0a, 0x11, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x5f, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x10, 0x74,
+    0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x12,
+    0x19, 0x0a, 0x08, 0x67, 0x61, 0x73, 0x5f, 0x75, 0x73, 0x65, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x07, 0x67, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x12, 0x1b, 0x0a, 0x09, 0x67, 0x61,
+    0x73, 0x5f, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x67,
+    0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x34, 0x0a, 0x16, 0x62, 0x6c, 0x6f, 0x62, 0x5f,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x14, 0x62, 0x6c, 0x6f, 0x62, 0x54, 0x72, 0x61,
+    0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x27, 0x0a,
+    0x10, 0x62, 0x61, 0x73, 0x65, 0x5f, 0x66, 0x65, 0x65, 0x5f, 0x70, 0x65, 0x72, 0x5f, 0x67, 0x61,
+    0x73, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x62, 0x61, 0x73, 0x65, 0x46, 0x65, 0x65,
+    0x50, 0x65, 0x72, 0x47, 0x61, 0x73, 0x12, 0x30, 0x0a, 0x15, 0x6e, 0x65, 0x78, 0x74, 0x5f, 0x62,
+    0x61, 0x73, 0x65, 0x5f, 0x66, 0x65, 0x65, 0x5f, 0x70, 0x65, 0x72, 0x5f, 0x67, 0x61, 0x73, 0x18,
+    0x08, 0x20, 0x01, 0x28, 0x09, 0x52, 0x11, 0x6e, 0x65, 0x78, 0x74, 0x42, 0x61, 0x73, 0x65, 0x46,
+    0x65, 0x65, 0x50, 0x65, 0x72, 0x47, 0x61, 0x73, 0x12, 0x28, 0x0a, 0x10, 0x70, 0x72, 0x69, 0x6f,
+    0x72, 0x69, 0x74, 0x79, 0x5f, 0x66, 0x65, 0x65, 0x5f, 0x70, 0x31, 0x30, 0x18, 0x09, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x0e, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x46, 0x65, 0x65, 0x50,
+    0x31, 0x30, 0x12, 0x28, 0x0a, 0x10, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x5f, 0x66,
+    0x65, 0x65, 0x5f, 0x70, 0x35, 0x30, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0e, 0x70, 0x72,
+    0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x46, 0x65, 0x65, 0x50, 0x35, 0x30, 0x12, 0x28, 0x0a, 0x10,
+    0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x5f, 0x66, 0x65, 0x65, 0x5f, 0x70, 0x39, 0x30,
+    0x18, 0x0b, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0e, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79,
+    0x46, 0x65, 0x65, 0x50, 0x39, 0x30, 0x12, 0x16, 0x0a, 0x06, 0x62, 0x75, 0x72, 0x6e, 0x65, 0x64,
+    0x18, 0x0c, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x62, 0x75, 0x72, 0x6e, 0x65, 0x64, 0x22, 0x56,
+    0x0a, 0x10, 0x42, 0x6c, 0x6f, 0x62, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x73, 0x12, 0x42, 0x0a, 0x0c, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1e, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x42, 0x6c, 0x6f, 0x62, 0x54, 0x72, 0x61,
+    0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0c, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x22, 0x7e, 0x0a, 0x0f, 0x42, 0x6c, 0x6f, 0x62, 0x54, 0x72,
+    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x68, 0x61, 0x73,
+    0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x12, 0x12, 0x0a,
+    0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f,
+    0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x02, 0x74,
+    0x6f, 0x12, 0x19, 0x0a, 0x08, 0x67, 0x61, 0x73, 0x5f, 0x75, 0x73, 0x65, 0x64, 0x18, 0x04, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x07, 0x67, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x12, 0x18, 0x0a, 0x07,
+    0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f,
+    0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x4a, 0xcf, 0x0c, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x26,
+    0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02,
+    0x12, 0x03, 0x02, 0x00, 0x16, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x29,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x06, 0x00, 0x18, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x00, 0x01, 0x12, 0x03, 0x06, 0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00,
+    0x12, 0x03, 0x07, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x07, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x09,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x07, 0x12, 0x13, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x08, 0x02, 0x2a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x01, 0x06, 0x12, 0x03, 0x08, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x08, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x08, 0x28, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03,
+    0x09, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x09, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x09, 0x09, 0x1a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x09, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x0a, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x03, 0x05, 0x12, 0x03, 0x0a, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x0a, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x0a, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x0b, 0x02,
+    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x05, 0x12, 0x03, 0x0b, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x0b, 0x09, 0x12, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x0b, 0x15, 0x16, 0x0a, 0x34, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x05, 0x12, 0x03, 0x0d, 0x02, 0x24, 0x1a, 0x27, 0x20, 0x45, 0x49, 0x50, 0x2d, 0x34,
+    0x38, 0x34, 0x34, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73,
+    0x20, 0x63, 0x61, 0x72, 0x72, 0x79, 0x69, 0x6e, 0x67, 0x20, 0x62, 0x6c, 0x6f, 0x62, 0x73, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x05, 0x12, 0x03, 0x0d, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x0d, 0x09, 0x1f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x05, 0x03, 0x12, 0x03, 0x0d, 0x22, 0x23, 0x0a, 0x53, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x06, 0x12, 0x03, 0x0f, 0x02, 0x1e, 0x1a, 0x46, 0x20, 0x45, 0x49, 0x50, 0x2d, 0x31,
+    0x35, 0x35, 0x39, 0x20, 0x66, 0x65, 0x65, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x2c, 0x20,
+    0x69, 0x6e, 0x20, 0x77, 0x65, 0x69, 0x20, 0x61, 0x73, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61,
+    0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x2e, 0x20, 0x45, 0x6d, 0x70, 0x74, 0x79,
+    0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x4c, 0x6f, 0x6e, 0x64, 0x6f, 0x6e, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x05, 0x12, 0x03, 0x0f, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x0f, 0x09, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x06, 0x03, 0x12, 0x03, 0x0f, 0x1c, 0x1d, 0x0a, 0x67, 0x0a, 0x04, 0x04, 0x00,
+    0x02, 0x07, 0x12, 0x03, 0x11, 0x02, 0x23, 0x1a, 0x5a, 0x20, 0x42, 0x61, 0x73, 0x65, 0x20, 0x66,
+    0x65, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x20, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x68, 0x61, 0x76, 0x65, 0x2c, 0x20, 0x66, 0x72, 0x6f,
+    0x6d, 0x20, 0x68, 0x6f, 0x77, 0x20, 0x66, 0x61, 0x72, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x67, 0x61, 0x73, 0x20, 0x75, 0x73, 0x61, 0x67, 0x65, 0x20, 0x69,
+    0x73, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x69, 0x74, 0x73, 0x20, 0x74, 0x61, 0x72, 0x67, 0x65,
+    0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x05, 0x12, 0x03, 0x11, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x11, 0x09, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x03, 0x12, 0x03, 0x11, 0x21, 0x22, 0x0a, 0x69, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x08, 0x12, 0x03, 0x13, 0x02, 0x1e, 0x1a, 0x5c, 0x20, 0x50, 0x72, 0x69,
+    0x6f, 0x72, 0x69, 0x74, 0x79, 0x20, 0x66, 0x65, 0x65, 0x20, 0x70, 0x65, 0x72, 0x20, 0x67, 0x61,
+    0x73, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x69, 0x76, 0x65, 0x20, 0x67, 0x61, 0x73, 0x20, 0x70, 0x72,
+    0x69, 0x63, 0x65, 0x20, 0x61, 0x62, 0x6f, 0x76, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x61,
+    0x73, 0x65, 0x20, 0x66, 0x65, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08,
+    0x05, 0x12, 0x03, 0x13, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12,
+    0x03, 0x13, 0x09, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x03, 0x12, 0x03, 0x13,
+    0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x09, 0x12, 0x03, 0x14, 0x02, 0x1f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x09, 0x05, 0x12, 0x03, 0x14, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x09, 0x01, 0x12, 0x03, 0x14, 0x09, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x09, 0x03, 0x12, 0x03, 0x14, 0x1c, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x0a, 0x12, 0x03, 0x15, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0a, 0x05, 0x12,
+    0x03, 0x15, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x15,
+    0x09, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x15, 0x1c, 0x1e,
+    0x0a, 0x48, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x0b, 0x12, 0x03, 0x17, 0x02, 0x15, 0x1a, 0x3b, 0x20,
+    0x45, 0x54, 0x48, 0x20, 0x62, 0x75, 0x72, 0x6e, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x69, 0x74, 0x73, 0x20, 0x62, 0x61, 0x73,
+    0x65, 0x20, 0x66, 0x65, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x20, 0x69, 0x74, 0x73, 0x20,
+    0x67, 0x61, 0x73, 0x20, 0x75, 0x73, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x0b, 0x05, 0x12, 0x03, 0x17, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0b,
+    0x01, 0x12, 0x03, 0x17, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x0b, 0x03, 0x12,
+    0x03, 0x17, 0x12, 0x14, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x1a, 0x00, 0x1c, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x1a, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x1b, 0x02, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x1b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06,
+    0x12, 0x03, 0x1b, 0x0b, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x1b, 0x1b, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1b, 0x2a,
+    0x2b, 0x0a, 0x5b, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x1f, 0x00, 0x26, 0x01, 0x1a, 0x4f, 0x20,
+    0x41, 0x6e, 0x20, 0x45, 0x49, 0x50, 0x2d, 0x34, 0x38, 0x34, 0x34, 0x20, 0x74, 0x72, 0x61, 0x6e,
+    0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x62, 0x79,
+    0x20, 0x72, 0x6f, 0x6c, 0x6c, 0x75, 0x70, 0x20, 0x62, 0x61, 0x74, 0x63, 0x68, 0x65, 0x72, 0x73,
+    0x20, 0x74, 0x6f, 0x20, 0x70, 0x6f, 0x73, 0x74, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x64,
+    0x61, 0x74, 0x61, 0x20, 0x61, 0x73, 0x20, 0x62, 0x6c, 0x6f, 0x62, 0x73, 0x2e, 0x0a, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x1f, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02,
+    0x02, 0x00, 0x12, 0x03, 0x20, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x20, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x20, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x20, 0x0f,
+    0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x21, 0x02, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x21, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x21, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x21, 0x0f, 0x10, 0x0a, 0x46, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02,
+    0x12, 0x03, 0x23, 0x02, 0x0f, 0x1a, 0x39, 0x20, 0x42, 0x61, 0x74, 0x63, 0x68, 0x20, 0x69, 0x6e,
+    0x62, 0x6f, 0x78, 0x20, 0x6f, 0x72, 0x20, 0x72, 0x6f, 0x6c, 0x6c, 0x75, 0x70, 0x20, 0x63, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x62, 0x73,
+    0x20, 0x61, 0x72, 0x65, 0x20, 0x70, 0x6f, 0x73, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05, 0x12, 0x03, 0x23, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x23, 0x08, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x23, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x24, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x05,
+    0x12, 0x03, 0x24, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x24, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x24, 0x14,
+    0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x25, 0x02, 0x15, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x05, 0x12, 0x03, 0x25, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x25, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x04, 0x03, 0x12, 0x03, 0x25, 0x13, 0x14, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+];
+// @@protoc_insertion_point(module)
\ No newline at end of file
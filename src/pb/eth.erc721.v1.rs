@@ -16,50 +16,3096 @@ pub struct Transfer {
     pub trx_hash: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint64, tag="5")]
     pub ordinal: u64,
+    /// Collection the token belongs to.
+    #[prost(bytes="vec", tag="6")]
+    pub contract: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag="7")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// `data` of the safeTransferFrom call that made the transfer, some protocols encode instructions for the receiver
+    /// there. Empty for other transfer functions.
+    #[prost(bytes="vec", tag="8")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    /// `<trx_hash>-<block_index>` with the log's index in the block, stable across replays for sinks to upsert on.
+    #[prost(string, tag="9")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FailedTransferAttempts {
+    #[prost(message, repeated, tag="1")]
+    pub attempts: ::prost::alloc::vec::Vec<FailedTransferAttempt>,
+}
+/// Safe transfer undone because the onERC721Received hook of the receiver reverted or returned another value than its
+/// selector.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FailedTransferAttempt {
+    #[prost(bytes="vec", tag="1")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Whether the whole transaction failed, the transfer call may also revert alone inside a successful one.
+    #[prost(bool, tag="2")]
+    pub trx_failed: bool,
+    /// Caller of safeTransferFrom, the holder or an approved operator like a marketplace.
+    #[prost(bytes="vec", tag="3")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    /// Contract whose hook rejected the token.
+    #[prost(bytes="vec", tag="5")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub token_id: u64,
+    /// Revert reason of the hook, or the unexpected value it returned.
+    #[prost(string, tag="7")]
+    pub reason: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub ordinal: u64,
+    /// `<trx_hash>-call-<call_index>`, stable across replays for sinks to upsert on.
+    #[prost(string, tag="9")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MintPurchases {
+    #[prost(message, repeated, tag="1")]
+    pub purchases: ::prost::alloc::vec::Vec<MintPurchase>,
+}
+/// Tokens minted by one call into the collection, with the ETH paid for them.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MintPurchase {
+    #[prost(bytes="vec", tag="1")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Caller of the mint function, the buyer or a contract minting on its behalf.
+    #[prost(bytes="vec", tag="2")]
+    pub minter: ::prost::alloc::vec::Vec<u8>,
+    /// Signature of the mint function, or its selector when unknown.
+    #[prost(string, tag="3")]
+    pub method: ::prost::alloc::string::String,
+    #[prost(enumeration="mint_purchase::Phase", tag="4")]
+    pub phase: i32,
+    #[prost(uint64, repeated, tag="5")]
+    pub token_ids: ::prost::alloc::vec::Vec<u64>,
+    #[prost(uint64, tag="6")]
+    pub quantity: u64,
+    /// ETH paid by the call and paid per token, in wei as decimal strings.
+    #[prost(string, tag="7")]
+    pub paid: ::prost::alloc::string::String,
+    #[prost(string, tag="8")]
+    pub unit_price: ::prost::alloc::string::String,
+    #[prost(uint64, tag="9")]
+    pub ordinal: u64,
+    #[prost(message, optional, tag="10")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// `<trx_hash>-call-<call_index>`, stable across replays for sinks to upsert on.
+    #[prost(string, tag="11")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `MintPurchase`.
+pub mod mint_purchase {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Phase {
+        /// The mint function is not in the selector table.
+        Unspecified = 0,
+        Public = 1,
+        /// The mint function takes a merkle proof, is named after an allowlist or is listed in the `allowlist` param.
+        Allowlist = 2,
+    }
+    impl Phase {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Phase::Unspecified => "PHASE_UNSPECIFIED",
+                Phase::Public => "PHASE_PUBLIC",
+                Phase::Allowlist => "PHASE_ALLOWLIST",
+            }
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreatorPayouts {
+    #[prost(message, repeated, tag="1")]
+    pub payouts: ::prost::alloc::vec::Vec<CreatorPayout>,
+}
+/// Revenue leaving the treasury of a collection: the collection contract and its PaymentSplitter contracts.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreatorPayout {
+    #[prost(enumeration="creator_payout::Kind", tag="1")]
+    pub kind: i32,
+    #[prost(bytes="vec", tag="2")]
+    pub collection: ::prost::alloc::vec::Vec<u8>,
+    /// Contract paying out, the collection or one of its splitters.
+    #[prost(bytes="vec", tag="3")]
+    pub source: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub recipient: ::prost::alloc::vec::Vec<u8>,
+    /// In the smallest unit of `currency`, as a decimal string.
+    #[prost(string, tag="5")]
+    pub amount: ::prost::alloc::string::String,
+    /// ERC20 contract of the payout, empty for native ETH.
+    #[prost(bytes="vec", tag="6")]
+    pub currency: ::prost::alloc::vec::Vec<u8>,
+    /// ETH revenue of the collection from mints and royalties so far, see store_collection_revenue.
+    #[prost(string, tag="7")]
+    pub revenue_to_date: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="8")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="9")]
+    pub ordinal: u64,
+    #[prost(message, optional, tag="10")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// `<trx_hash>-<block_index>` for releases and `<trx_hash>-call-<call_index>` for withdrawals, stable across
+    /// replays for sinks to upsert on.
+    #[prost(string, tag="11")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `CreatorPayout`.
+pub mod creator_payout {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        Unspecified = 0,
+        /// PaymentReleased or ERC20PaymentReleased of a splitter.
+        Release = 1,
+        /// ETH sent by the collection contract.
+        Withdrawal = 2,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Kind::Unspecified => "KIND_UNSPECIFIED",
+                Kind::Release => "KIND_RELEASE",
+                Kind::Withdrawal => "KIND_WITHDRAWAL",
+            }
+        }
+    }
+}
+/// Transfers of a block as parallel arrays, entry `i` of every column describes the same transfer.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferColumns {
+    /// Distinct collections and transactions of the block, referred to by position.
+    #[prost(bytes="vec", repeated, tag="1")]
+    pub contracts: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", repeated, tag="2")]
+    pub trx_hashes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, repeated, tag="3")]
+    pub contract_index: ::prost::alloc::vec::Vec<u32>,
+    #[prost(uint32, repeated, tag="4")]
+    pub trx_index: ::prost::alloc::vec::Vec<u32>,
+    #[prost(bytes="vec", repeated, tag="5")]
+    pub from: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", repeated, tag="6")]
+    pub to: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint64, repeated, tag="7")]
+    pub token_id: ::prost::alloc::vec::Vec<u64>,
+    #[prost(uint64, repeated, tag="8")]
+    pub ordinal: ::prost::alloc::vec::Vec<u64>,
+    #[prost(message, optional, tag="9")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Transfers as rows of scalar columns, the layout substreams-sink-files writes to Parquet and CSV as is.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FlatTransfers {
+    #[prost(message, repeated, tag="1")]
+    pub rows: ::prost::alloc::vec::Vec<FlatTransfer>,
+}
+/// Addresses and hashes are `0x` prefixed lowercase hex, the block timestamp is in seconds since the epoch.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FlatTransfer {
+    #[prost(uint64, tag="1")]
+    pub block_number: u64,
+    #[prost(int64, tag="2")]
+    pub block_timestamp: i64,
+    #[prost(string, tag="3")]
+    pub trx_hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub ordinal: u64,
+    #[prost(string, tag="5")]
+    pub contract: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub from_address: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub to_address: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub token_id: u64,
+    /// `<trx_hash>-<block_index>` with the log's index in the block, stable across replays for sinks to upsert on.
+    #[prost(string, tag="9")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Range of blocks of a bounded export, from the `start_block` and `stop_block` params of map_export_range, as seen
+/// from the current block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportRange {
+    #[prost(uint64, tag="1")]
+    pub start_block: u64,
+    /// First block after the range, 0 when the range is open ended.
+    #[prost(uint64, tag="2")]
+    pub stop_block: u64,
+    /// Whether the current block is in the range, the exporters emit nothing for it otherwise.
+    #[prost(bool, tag="3")]
+    pub contains: bool,
+    /// Whether the current block is the last one of a bounded range.
+    #[prost(bool, tag="4")]
+    pub ends: bool,
+}
+/// Emitted once, at the last block of a bounded export.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportSummary {
+    #[prost(uint64, tag="1")]
+    pub start_block: u64,
+    /// First block after the range.
+    #[prost(uint64, tag="2")]
+    pub stop_block: u64,
+    #[prost(uint64, tag="3")]
+    pub rows: u64,
+    #[prost(uint64, tag="4")]
+    pub blocks_with_rows: u64,
+    /// Timestamp of the last block of the range.
+    #[prost(message, optional, tag="5")]
+    pub completed_at: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Collections {
+    #[prost(message, repeated, tag="1")]
+    pub collections: ::prost::alloc::vec::Vec<CollectionMetadata>,
+    /// RPC calls made while probing contracts in the block, and how many of them failed.
+    #[prost(uint64, tag="2")]
+    pub rpc_calls: u64,
+    #[prost(uint64, tag="3")]
+    pub rpc_failures: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenMetadatas {
+    #[prost(message, repeated, tag="1")]
+    pub tokens: ::prost::alloc::vec::Vec<TokenMetadata>,
+    /// `tokenURI` calls made for the tokens minted in the block, and how many of them failed.
+    #[prost(uint64, tag="2")]
+    pub rpc_calls: u64,
+    #[prost(uint64, tag="3")]
+    pub rpc_failures: u64,
+}
+/// Metadata of a token minted in the block. Fully on-chain collections inline it as a `data:application/json` token
+/// URI, it is emitted decoded. Other token URIs are left for consumers to resolve.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenMetadata {
+    #[prost(bytes="vec", tag="1")]
+    pub contract: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="2")]
+    pub token_id: u64,
+    /// Token URI when it points off-chain, empty once decoded.
+    #[prost(string, tag="3")]
+    pub token_uri: ::prost::alloc::string::String,
+    #[prost(bool, tag="4")]
+    pub on_chain: bool,
+    #[prost(string, tag="5")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub description: ::prost::alloc::string::String,
+    /// Image URI, empty when it is an inline SVG.
+    #[prost(string, tag="7")]
+    pub image: ::prost::alloc::string::String,
+    /// SVG document of a `data:image/svg+xml` image, base64 decoded.
+    #[prost(string, tag="8")]
+    pub image_svg: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="9")]
+    pub attributes: ::prost::alloc::vec::Vec<Trait>,
+    #[prost(bytes="vec", tag="10")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="11")]
+    pub ordinal: u64,
+    /// Id of the mint transfer, unique and stable across replays for sinks to upsert on.
+    #[prost(string, tag="12")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Trait {
+    #[prost(string, tag="1")]
+    pub trait_type: ::prost::alloc::string::String,
+    /// Numbers and booleans as their JSON text.
+    #[prost(string, tag="2")]
+    pub value: ::prost::alloc::string::String,
+}
+/// An NFT collection deployed in the block, with the metadata a marketplace needs for its page.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CollectionMetadata {
+    #[prost(bytes="vec", tag="1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="collection_metadata::Standard", tag="2")]
+    pub standard: i32,
+    #[prost(string, tag="3")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub symbol: ::prost::alloc::string::String,
+    /// `owner()` of the contract, empty when it is not Ownable.
+    #[prost(bytes="vec", tag="5")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    /// ERC-7572 `contractURI()`, and the logo its metadata points to when inlined as a `data:` URI.
+    #[prost(string, tag="6")]
+    pub contract_uri: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub logo_uri: ::prost::alloc::string::String,
+    /// EIP-2981 default royalty, as returned by `royaltyInfo` for token 0. Empty when not implemented.
+    #[prost(bytes="vec", tag="8")]
+    pub royalty_receiver: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag="9")]
+    pub royalty_bps: u32,
+    /// Transaction sender that created the contract.
+    #[prost(bytes="vec", tag="10")]
+    pub deployer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="11")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// Nested message and enum types in `CollectionMetadata`.
+pub mod collection_metadata {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Standard {
+        Unspecified = 0,
+        Erc721 = 1,
+        Erc1155 = 2,
+    }
+    impl Standard {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Standard::Unspecified => "STANDARD_UNSPECIFIED",
+                Standard::Erc721 => "STANDARD_ERC721",
+                Standard::Erc1155 => "STANDARD_ERC1155",
+            }
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FractionalEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<FractionalEvent>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FractionalEvent {
+    #[prost(enumeration="fractional_event::Kind", tag="1")]
+    pub kind: i32,
+    #[prost(bytes="vec", tag="2")]
+    pub vault: ::prost::alloc::vec::Vec<u8>,
+    /// ERC20 (or ERC1155) contract representing the fractions, the vault itself when it is the token.
+    #[prost(bytes="vec", tag="3")]
+    pub vault_token: ::prost::alloc::vec::Vec<u8>,
+    /// Depositor for `KIND_FRACTIONALIZED`, recipient for `KIND_REDEEMED`.
+    #[prost(bytes="vec", tag="4")]
+    pub holder: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub token_id: u64,
+    #[prost(bytes="vec", tag="6")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub ordinal: u64,
+    /// `<transfer id>-fractionalized` or `<transfer id>-redeemed`, unique and stable across replays for sinks to
+    /// upsert on.
+    #[prost(string, tag="8")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `FractionalEvent`.
+pub mod fractional_event {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        Unspecified = 0,
+        /// The token was transferred into a fractionalizer vault.
+        Fractionalized = 1,
+        /// The token was transferred out of a fractionalizer vault.
+        Redeemed = 2,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Kind::Unspecified => "KIND_UNSPECIFIED",
+                Kind::Fractionalized => "KIND_FRACTIONALIZED",
+                Kind::Redeemed => "KIND_REDEEMED",
+            }
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Sales {
+    #[prost(message, repeated, tag="1")]
+    pub sales: ::prost::alloc::vec::Vec<Sale>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Sale {
+    /// Marketplace the sale happened on, e.g. `sudoswap`.
+    #[prost(string, tag="1")]
+    pub marketplace: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="2")]
+    pub collection: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="3")]
+    pub token_id: u64,
+    #[prost(bytes="vec", tag="4")]
+    pub seller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub buyer: ::prost::alloc::vec::Vec<u8>,
+    /// Price paid for the token in the smallest unit of `currency`, as a decimal string.
+    #[prost(string, tag="6")]
+    pub price: ::prost::alloc::string::String,
+    /// ERC20 contract the price is paid in, empty for native ETH.
+    #[prost(bytes="vec", tag="7")]
+    pub currency: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="8")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="9")]
+    pub ordinal: u64,
+    #[prost(message, optional, tag="10")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// Seaport order hash, to join the sale with off-chain order books.
+    #[prost(bytes="vec", tag="11")]
+    pub order_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Part of `price` paid to the creator, in the same currency. Empty on marketplaces that don't split it out.
+    #[prost(string, tag="12")]
+    pub royalty: ::prost::alloc::string::String,
+    /// `<trx_hash>-<block_index>-<token_id>`, stable across replays for sinks to upsert on.
+    #[prost(string, tag="13")]
+    pub id: ::prost::alloc::string::String,
+    /// Index of the log of the sale among the logs of its transaction receipt.
+    #[prost(uint32, tag="14")]
+    pub log_index: u32,
+    /// Payments to the creator by currency, including the ones in another currency than `price`.
+    #[prost(message, repeated, tag="15")]
+    pub royalties: ::prost::alloc::vec::Vec<Royalty>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Royalty {
+    /// ERC20 contract the royalty is paid in, empty for native ETH.
+    #[prost(bytes="vec", tag="1")]
+    pub currency: ::prost::alloc::vec::Vec<u8>,
+    /// Amount in the smallest unit of `currency`, as a decimal string.
+    #[prost(string, tag="2")]
+    pub amount: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<PoolEvent>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PoolEvent {
+    #[prost(enumeration="pool_event::Kind", tag="1")]
+    pub kind: i32,
+    #[prost(bytes="vec", tag="2")]
+    pub pool: ::prost::alloc::vec::Vec<u8>,
+    /// Amount carried by the event (tokens deposited or withdrawn, new spot price), as a decimal string.
+    #[prost(string, tag="3")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="4")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub ordinal: u64,
+    /// `<trx_hash>-<block_index>` with the log's index in the block, stable across replays for sinks to upsert on.
+    #[prost(string, tag="6")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `PoolEvent`.
+pub mod pool_event {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        Unspecified = 0,
+        PoolCreated = 1,
+        NftDeposit = 2,
+        TokenDeposit = 3,
+        NftWithdrawal = 4,
+        TokenWithdrawal = 5,
+        SwapNftIn = 6,
+        SwapNftOut = 7,
+        SpotPriceUpdate = 8,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Kind::Unspecified => "KIND_UNSPECIFIED",
+                Kind::PoolCreated => "KIND_POOL_CREATED",
+                Kind::NftDeposit => "KIND_NFT_DEPOSIT",
+                Kind::TokenDeposit => "KIND_TOKEN_DEPOSIT",
+                Kind::NftWithdrawal => "KIND_NFT_WITHDRAWAL",
+                Kind::TokenWithdrawal => "KIND_TOKEN_WITHDRAWAL",
+                Kind::SwapNftIn => "KIND_SWAP_NFT_IN",
+                Kind::SwapNftOut => "KIND_SWAP_NFT_OUT",
+                Kind::SpotPriceUpdate => "KIND_SPOT_PRICE_UPDATE",
+            }
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CustodyEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<CustodyEvent>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CustodyEvent {
+    #[prost(enumeration="custody_event::Kind", tag="1")]
+    pub kind: i32,
+    /// Custodial contract (staking, escrow, ...) holding the token.
+    #[prost(bytes="vec", tag="2")]
+    pub custodian: ::prost::alloc::vec::Vec<u8>,
+    /// Depositor for deposits, recipient for withdrawals.
+    #[prost(bytes="vec", tag="3")]
+    pub account: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="4")]
+    pub token_id: u64,
+    #[prost(bytes="vec", tag="5")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    /// `<transfer id>-deposit` or `<transfer id>-withdrawal`, unique and stable across replays for sinks to upsert on.
+    #[prost(string, tag="7")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `CustodyEvent`.
+pub mod custody_event {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        Unspecified = 0,
+        Deposit = 1,
+        Withdrawal = 2,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Kind::Unspecified => "KIND_UNSPECIFIED",
+                Kind::Deposit => "KIND_DEPOSIT",
+                Kind::Withdrawal => "KIND_WITHDRAWAL",
+            }
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenActivities {
+    #[prost(message, repeated, tag="1")]
+    pub activities: ::prost::alloc::vec::Vec<TokenActivity>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenActivity {
+    #[prost(uint64, tag="1")]
+    pub token_id: u64,
+    /// Number of transfers of the token since the start block, including this block.
+    #[prost(int64, tag="2")]
+    pub transfer_count: i64,
+    #[prost(uint64, tag="3")]
+    pub last_block: u64,
+    #[prost(message, optional, tag="4")]
+    pub last_timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Owner of a token, as kept by store_token_owners.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenOwner {
+    #[prost(bytes="vec", tag="1")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    /// Block and time the owner received the token at.
+    #[prost(uint64, tag="2")]
+    pub since_block: u64,
+    #[prost(message, optional, tag="3")]
+    pub since: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EnrichedTransfers {
+    #[prost(message, repeated, tag="1")]
+    pub transfers: ::prost::alloc::vec::Vec<EnrichedTransfer>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EnrichedTransfer {
+    #[prost(message, optional, tag="1")]
+    pub transfer: ::core::option::Option<Transfer>,
+    /// Owner of the token before the transfer according to store_token_owners, empty for mints and for tokens not
+    /// transferred since the start block.
+    #[prost(bytes="vec", tag="2")]
+    pub previous_owner: ::prost::alloc::vec::Vec<u8>,
+    /// How long the previous owner held the token, 0 when the previous owner is not known.
+    #[prost(uint64, tag="3")]
+    pub holding_blocks: u64,
+    #[prost(int64, tag="4")]
+    pub holding_seconds: i64,
+    /// `name()` of the collection in store_collection_names, empty when map_created_collections did not see it deployed.
+    #[prost(string, tag="5")]
+    pub collection_name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FilteredTransfers {
+    #[prost(message, repeated, tag="1")]
+    pub transfers: ::prost::alloc::vec::Vec<Transfer>,
+    /// Transfers dropped because `from` and `to` are the same account.
+    #[prost(uint64, tag="2")]
+    pub dropped_self_transfers: u64,
+    /// Transfers dropped because the token came back to its original owner within the same transaction.
+    #[prost(uint64, tag="3")]
+    pub dropped_round_trips: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RevertAudit {
+    #[prost(message, repeated, tag="1")]
+    pub discrepancies: ::prost::alloc::vec::Vec<LogDiscrepancy>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LogDiscrepancy {
+    #[prost(enumeration="log_discrepancy::Kind", tag="1")]
+    pub kind: i32,
+    #[prost(bytes="vec", tag="2")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint32, tag="3")]
+    pub block_index: u32,
+    #[prost(int32, tag="4")]
+    pub trx_status: i32,
+}
+/// Nested message and enum types in `LogDiscrepancy`.
+pub mod log_discrepancy {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        Unspecified = 0,
+        /// The receipt log was emitted by a call whose state got reverted.
+        RevertedCall = 1,
+        /// The receipt log could not be found in any call of the transaction.
+        MissingCall = 2,
+        /// The transaction failed but its receipt still carries logs.
+        FailedTransaction = 3,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Kind::Unspecified => "KIND_UNSPECIFIED",
+                Kind::RevertedCall => "KIND_REVERTED_CALL",
+                Kind::MissingCall => "KIND_MISSING_CALL",
+                Kind::FailedTransaction => "KIND_FAILED_TRANSACTION",
+            }
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContractCalls {
+    #[prost(message, repeated, tag="1")]
+    pub calls: ::prost::alloc::vec::Vec<ContractCall>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContractCall {
+    /// Solidity name of the called function, e.g. `safeTransferFrom`. Functions without a decoder are
+    /// reported by signature from the embedded selector table, e.g. `withdraw()`, or by `0x` selector
+    /// when unknown, with their raw calldata as the `input` param.
+    #[prost(string, tag="1")]
+    pub method: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="2")]
+    pub inputs: ::prost::alloc::vec::Vec<CallParam>,
+    #[prost(message, repeated, tag="3")]
+    pub outputs: ::prost::alloc::vec::Vec<CallParam>,
+    #[prost(bytes="vec", tag="4")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    /// Value sent along the call, in wei, as a decimal string.
+    #[prost(string, tag="5")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="6")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub ordinal: u64,
+    /// `<trx_hash>-call-<call_index>`, stable across replays for sinks to upsert on.
+    #[prost(string, tag="8")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CallParam {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    /// Addresses and bytes are 0x prefixed hex, integers are decimal strings.
+    #[prost(string, tag="2")]
+    pub value: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FinalityUpdate {
+    #[prost(uint64, tag="1")]
+    pub block: u64,
+    /// Transfers of `block`, provisional until `confirmations` more blocks are built on top of it.
+    #[prost(message, repeated, tag="2")]
+    pub provisional: ::prost::alloc::vec::Vec<Transfer>,
+    /// Block whose transfers are now confirmed, zero while fewer than `confirmations` blocks were processed.
+    #[prost(uint64, tag="3")]
+    pub confirmed_block: u64,
+    #[prost(message, repeated, tag="4")]
+    pub confirmed: ::prost::alloc::vec::Vec<Transfer>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CohortStats {
+    #[prost(uint64, tag="1")]
+    pub block: u64,
+    /// Addresses acquiring a token of the collection for the first time.
+    #[prost(uint64, tag="2")]
+    pub new_holders: u64,
+    /// Addresses acquiring a token after a first acquisition in an earlier block.
+    #[prost(uint64, tag="3")]
+    pub returning_buyers: u64,
+    /// Addresses whose balance dropped to zero.
+    #[prost(uint64, tag="4")]
+    pub exiting_holders: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WhaleAlerts {
+    #[prost(message, repeated, tag="1")]
+    pub alerts: ::prost::alloc::vec::Vec<WhaleAlert>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WhaleAlert {
+    #[prost(bytes="vec", tag="1")]
+    pub holder: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub contract: ::prost::alloc::vec::Vec<u8>,
+    /// Tokens of the collection held at the start of the block, unset for ERC20 alerts.
+    #[prost(string, tag="3")]
+    pub balance: ::prost::alloc::string::String,
+    /// Tokens sent during the block, in whole tokens scaled by the `erc20_prices` decimals for ERC20 alerts.
+    #[prost(string, tag="4")]
+    pub amount: ::prost::alloc::string::String,
+    /// Value of `amount` in USD, only set for ERC20 alerts.
+    #[prost(double, tag="5")]
+    pub usd_value: f64,
+    #[prost(uint64, tag="6")]
+    pub block: u64,
+}
+/// Addresses of the tracked collection waking up after a dormancy, and every `every` blocks the ones that went dormant.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DormancyReport {
+    #[prost(uint64, tag="1")]
+    pub block: u64,
+    /// Whether the block was picked by the `every` param, `dormant` is only set then.
+    #[prost(bool, tag="2")]
+    pub reported: bool,
+    /// Addresses last active in the period that crossed the `dormancy` threshold since the previous report.
+    #[prost(message, repeated, tag="3")]
+    pub dormant: ::prost::alloc::vec::Vec<DormantAddress>,
+    /// Dormant addresses that sent tokens again in the block.
+    #[prost(message, repeated, tag="4")]
+    pub awakened: ::prost::alloc::vec::Vec<DormantAddress>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DormantAddress {
+    #[prost(bytes="vec", tag="1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    /// Last block the address sent tokens at, before the block for awakened addresses.
+    #[prost(uint64, tag="2")]
+    pub last_active_block: u64,
+    #[prost(uint64, tag="3")]
+    pub inactive_blocks: u64,
+    /// Tokens of the tracked collection held, at the start of the block for awakened addresses.
+    #[prost(int64, tag="4")]
+    pub balance: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaggedActivity {
+    #[prost(message, repeated, tag="1")]
+    pub transfers: ::prost::alloc::vec::Vec<TaggedTransfer>,
+    #[prost(message, repeated, tag="2")]
+    pub sales: ::prost::alloc::vec::Vec<TaggedSale>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaggedTransfer {
+    #[prost(message, optional, tag="1")]
+    pub transfer: ::core::option::Option<Transfer>,
+    #[prost(string, repeated, tag="2")]
+    pub from_tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="3")]
+    pub to_tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TaggedSale {
+    #[prost(message, optional, tag="1")]
+    pub sale: ::core::option::Option<Sale>,
+    #[prost(string, repeated, tag="2")]
+    pub seller_tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(string, repeated, tag="3")]
+    pub buyer_tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClusteredTransfers {
+    #[prost(message, repeated, tag="1")]
+    pub transfers: ::prost::alloc::vec::Vec<ClusteredTransfer>,
+}
+/// Cluster ids are empty for wallets outside of a cluster.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClusteredTransfer {
+    #[prost(message, optional, tag="1")]
+    pub transfer: ::core::option::Option<Transfer>,
+    #[prost(string, tag="2")]
+    pub from_cluster: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub to_cluster: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Pnl {
+    #[prost(message, repeated, tag="1")]
+    pub realized: ::prost::alloc::vec::Vec<RealizedPnl>,
+}
+/// Profit of a holder selling a token it bought, amounts in wei as decimal strings.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RealizedPnl {
+    #[prost(bytes="vec", tag="1")]
+    pub holder: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="2")]
+    pub token_id: u64,
+    #[prost(string, tag="3")]
+    pub cost: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub proceeds: ::prost::alloc::string::String,
+    /// Proceeds minus cost, negative on a loss.
+    #[prost(string, tag="5")]
+    pub profit: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="6")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub ordinal: u64,
+    /// Id of the sale, unique and stable across replays for sinks to upsert on.
+    #[prost(string, tag="8")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Valuations {
+    #[prost(uint64, tag="1")]
+    pub block: u64,
+    /// Lowest ETH sale price of the last complete window, in wei as a decimal string.
+    #[prost(string, tag="2")]
+    pub floor_price: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag="3")]
+    pub holders: ::prost::alloc::vec::Vec<HolderValuation>,
+}
+/// Holder count of the tracked collection recomputed from scratch, against the incremental count.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HolderAudit {
+    #[prost(uint64, tag="1")]
+    pub block: u64,
+    /// Whether the block was picked by the `every` param, the other fields are only set then.
+    #[prost(bool, tag="2")]
+    pub audited: bool,
+    /// Holders counted by store_holder_count as balances cross zero.
+    #[prost(int64, tag="3")]
+    pub incremental: i64,
+    /// Distinct owners of the tokens in store_token_owners, recounted independently of the balances.
+    #[prost(int64, tag="4")]
+    pub recomputed: i64,
+    /// `incremental` minus `recomputed`, 0 when the stores agree.
+    #[prost(int64, tag="5")]
+    pub drift: i64,
+    /// Addresses of store_holders whose balance went below zero, see map_balance_anomalies.
+    #[prost(uint64, tag="6")]
+    pub negative_balances: u64,
+    /// Addresses whose store_transfers balance differs from the number of tokens store_token_owners gives them.
+    #[prost(uint64, tag="7")]
+    pub mismatched_balances: u64,
+}
+/// Sale prices of the last complete window, estimated from a histogram whose buckets span about 19% each.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MarketStats {
+    #[prost(uint64, tag="1")]
+    pub block: u64,
+    /// Window the stats are about, the block number divided by the window size.
+    #[prost(uint64, tag="2")]
+    pub window: u64,
+    #[prost(int64, tag="3")]
+    pub sales: i64,
+    /// Prices in wei as decimal strings, empty when no ETH sale happened in the window.
+    #[prost(string, tag="4")]
+    pub median_price: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub p90_price: ::prost::alloc::string::String,
+}
+/// Holder inventory marked to the floor, amounts in wei as decimal strings.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HolderValuation {
+    #[prost(bytes="vec", tag="1")]
+    pub holder: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int64, tag="2")]
+    pub balance: i64,
+    #[prost(string, tag="3")]
+    pub value: ::prost::alloc::string::String,
+    /// Price paid for the tokens still held, tokens minted or received for free cost nothing.
+    #[prost(string, tag="4")]
+    pub cost_basis: ::prost::alloc::string::String,
+    /// Value minus cost basis, negative on a loss.
+    #[prost(string, tag="5")]
+    pub unrealized_pnl: ::prost::alloc::string::String,
 }
 /// Encoded file descriptor set for the `eth.erc721.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0x90, 0x05, 0x0a, 0x0c, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x70, 0x72, 0x6f, 0x74,
-    0x6f, 0x12, 0x0d, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31,
-    0x22, 0x42, 0x0a, 0x09, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x12, 0x35, 0x0a,
-    0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b,
+    0x0a, 0xc9, 0x8d, 0x02, 0x0a, 0x0c, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x12, 0x0d, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76,
+    0x31, 0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62,
+    0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x2e, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x22, 0x42, 0x0a, 0x09, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x12,
+    0x35, 0x0a, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x17, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e,
+    0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52, 0x09, 0x74, 0x72, 0x61,
+    0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x22, 0xf8, 0x01, 0x0a, 0x08, 0x54, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x12, 0x12, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x02, 0x74, 0x6f, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x49, 0x64, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a,
+    0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07,
+    0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70,
+    0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e,
+    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x12, 0x12, 0x0a,
+    0x04, 0x64, 0x61, 0x74, 0x61, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x64, 0x61, 0x74,
+    0x61, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69,
+    0x64, 0x22, 0x5a, 0x0a, 0x16, 0x46, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x54, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x41, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x73, 0x12, 0x40, 0x0a, 0x08, 0x61,
+    0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x24, 0x2e,
+    0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x61,
+    0x69, 0x6c, 0x65, 0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x41, 0x74, 0x74, 0x65,
+    0x6d, 0x70, 0x74, 0x52, 0x08, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x73, 0x22, 0xee, 0x01,
+    0x0a, 0x15, 0x46, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x41, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68,
+    0x61, 0x73, 0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61,
+    0x73, 0x68, 0x12, 0x1d, 0x0a, 0x0a, 0x74, 0x72, 0x78, 0x5f, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x09, 0x74, 0x72, 0x78, 0x46, 0x61, 0x69, 0x6c, 0x65,
+    0x64, 0x12, 0x1a, 0x0a, 0x08, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x08, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x12, 0x12, 0x0a,
+    0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f,
+    0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x02, 0x74,
+    0x6f, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x16, 0x0a, 0x06,
+    0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x72, 0x65,
+    0x61, 0x73, 0x6f, 0x6e, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18,
+    0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x0e,
+    0x0a, 0x02, 0x69, 0x64, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x4a,
+    0x0a, 0x0d, 0x4d, 0x69, 0x6e, 0x74, 0x50, 0x75, 0x72, 0x63, 0x68, 0x61, 0x73, 0x65, 0x73, 0x12,
+    0x39, 0x0a, 0x09, 0x70, 0x75, 0x72, 0x63, 0x68, 0x61, 0x73, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e,
+    0x76, 0x31, 0x2e, 0x4d, 0x69, 0x6e, 0x74, 0x50, 0x75, 0x72, 0x63, 0x68, 0x61, 0x73, 0x65, 0x52,
+    0x09, 0x70, 0x75, 0x72, 0x63, 0x68, 0x61, 0x73, 0x65, 0x73, 0x22, 0xa9, 0x03, 0x0a, 0x0c, 0x4d,
+    0x69, 0x6e, 0x74, 0x50, 0x75, 0x72, 0x63, 0x68, 0x61, 0x73, 0x65, 0x12, 0x19, 0x0a, 0x08, 0x74,
+    0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74,
+    0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x16, 0x0a, 0x06, 0x6d, 0x69, 0x6e, 0x74, 0x65, 0x72,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x6d, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x12, 0x16,
+    0x0a, 0x06, 0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06,
+    0x6d, 0x65, 0x74, 0x68, 0x6f, 0x64, 0x12, 0x37, 0x0a, 0x05, 0x70, 0x68, 0x61, 0x73, 0x65, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x21, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37,
+    0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x4d, 0x69, 0x6e, 0x74, 0x50, 0x75, 0x72, 0x63, 0x68, 0x61,
+    0x73, 0x65, 0x2e, 0x50, 0x68, 0x61, 0x73, 0x65, 0x52, 0x05, 0x70, 0x68, 0x61, 0x73, 0x65, 0x12,
+    0x1b, 0x0a, 0x09, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x05, 0x20, 0x03,
+    0x28, 0x04, 0x52, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x73, 0x12, 0x1a, 0x0a, 0x08,
+    0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08,
+    0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x70, 0x61, 0x69, 0x64,
+    0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x70, 0x61, 0x69, 0x64, 0x12, 0x1d, 0x0a, 0x0a,
+    0x75, 0x6e, 0x69, 0x74, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x09, 0x75, 0x6e, 0x69, 0x74, 0x50, 0x72, 0x69, 0x63, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x6f,
+    0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72,
+    0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c,
+    0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73,
+    0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x12,
+    0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22,
+    0x45, 0x0a, 0x05, 0x50, 0x68, 0x61, 0x73, 0x65, 0x12, 0x15, 0x0a, 0x11, 0x50, 0x48, 0x41, 0x53,
+    0x45, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12,
+    0x10, 0x0a, 0x0c, 0x50, 0x48, 0x41, 0x53, 0x45, 0x5f, 0x50, 0x55, 0x42, 0x4c, 0x49, 0x43, 0x10,
+    0x01, 0x12, 0x13, 0x0a, 0x0f, 0x50, 0x48, 0x41, 0x53, 0x45, 0x5f, 0x41, 0x4c, 0x4c, 0x4f, 0x57,
+    0x4c, 0x49, 0x53, 0x54, 0x10, 0x02, 0x22, 0x48, 0x0a, 0x0e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x6f,
+    0x72, 0x50, 0x61, 0x79, 0x6f, 0x75, 0x74, 0x73, 0x12, 0x36, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6f,
+    0x75, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x65, 0x74, 0x68, 0x2e,
+    0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x6f,
+    0x72, 0x50, 0x61, 0x79, 0x6f, 0x75, 0x74, 0x52, 0x07, 0x70, 0x61, 0x79, 0x6f, 0x75, 0x74, 0x73,
+    0x22, 0xbc, 0x03, 0x0a, 0x0d, 0x43, 0x72, 0x65, 0x61, 0x74, 0x6f, 0x72, 0x50, 0x61, 0x79, 0x6f,
+    0x75, 0x74, 0x12, 0x35, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e,
+    0x32, 0x21, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31,
+    0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x6f, 0x72, 0x50, 0x61, 0x79, 0x6f, 0x75, 0x74, 0x2e, 0x4b,
+    0x69, 0x6e, 0x64, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x1e, 0x0a, 0x0a, 0x63, 0x6f, 0x6c,
+    0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0a, 0x63,
+    0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x6f, 0x75,
+    0x72, 0x63, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x73, 0x6f, 0x75, 0x72, 0x63,
+    0x65, 0x12, 0x1c, 0x0a, 0x09, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x12,
+    0x16, 0x0a, 0x06, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x06, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x75, 0x72, 0x72, 0x65,
+    0x6e, 0x63, 0x79, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x63, 0x75, 0x72, 0x72, 0x65,
+    0x6e, 0x63, 0x79, 0x12, 0x26, 0x0a, 0x0f, 0x72, 0x65, 0x76, 0x65, 0x6e, 0x75, 0x65, 0x5f, 0x74,
+    0x6f, 0x5f, 0x64, 0x61, 0x74, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x72, 0x65,
+    0x76, 0x65, 0x6e, 0x75, 0x65, 0x54, 0x6f, 0x44, 0x61, 0x74, 0x65, 0x12, 0x19, 0x0a, 0x08, 0x74,
+    0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74,
+    0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61,
+    0x6c, 0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c,
+    0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x0a, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52,
+    0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64,
+    0x18, 0x0b, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x43, 0x0a, 0x04, 0x4b, 0x69,
+    0x6e, 0x64, 0x12, 0x14, 0x0a, 0x10, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45,
+    0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x10, 0x0a, 0x0c, 0x4b, 0x49, 0x4e, 0x44,
+    0x5f, 0x52, 0x45, 0x4c, 0x45, 0x41, 0x53, 0x45, 0x10, 0x01, 0x12, 0x13, 0x0a, 0x0f, 0x4b, 0x49,
+    0x4e, 0x44, 0x5f, 0x57, 0x49, 0x54, 0x48, 0x44, 0x52, 0x41, 0x57, 0x41, 0x4c, 0x10, 0x02, 0x22,
+    0xa5, 0x02, 0x0a, 0x0f, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x43, 0x6f, 0x6c, 0x75,
+    0x6d, 0x6e, 0x73, 0x12, 0x1c, 0x0a, 0x09, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73,
+    0x18, 0x01, 0x20, 0x03, 0x28, 0x0c, 0x52, 0x09, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x65, 0x73, 0x18,
+    0x02, 0x20, 0x03, 0x28, 0x0c, 0x52, 0x09, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x65, 0x73,
+    0x12, 0x25, 0x0a, 0x0e, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x69, 0x6e, 0x64,
+    0x65, 0x78, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0d, 0x52, 0x0d, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x1b, 0x0a, 0x09, 0x74, 0x72, 0x78, 0x5f, 0x69,
+    0x6e, 0x64, 0x65, 0x78, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0d, 0x52, 0x08, 0x74, 0x72, 0x78, 0x49,
+    0x6e, 0x64, 0x65, 0x78, 0x12, 0x12, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x05, 0x20, 0x03,
+    0x28, 0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f, 0x18, 0x06,
+    0x20, 0x03, 0x28, 0x0c, 0x52, 0x02, 0x74, 0x6f, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x5f, 0x69, 0x64, 0x18, 0x07, 0x20, 0x03, 0x28, 0x04, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x49, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x08,
+    0x20, 0x03, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x38, 0x0a,
+    0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62,
+    0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69,
+    0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0x40, 0x0a, 0x0d, 0x46, 0x6c, 0x61, 0x74, 0x54,
+    0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x12, 0x2f, 0x0a, 0x04, 0x72, 0x6f, 0x77, 0x73,
+    0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63,
+    0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x6c, 0x61, 0x74, 0x54, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x52, 0x04, 0x72, 0x6f, 0x77, 0x73, 0x22, 0x98, 0x02, 0x0a, 0x0c, 0x46, 0x6c,
+    0x61, 0x74, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x27, 0x0a,
+    0x0f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0e, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x54, 0x69, 0x6d,
+    0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61,
+    0x73, 0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73,
+    0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x1a, 0x0a, 0x08, 0x63,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x63,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x12, 0x21, 0x0a, 0x0c, 0x66, 0x72, 0x6f, 0x6d, 0x5f,
+    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x66,
+    0x72, 0x6f, 0x6d, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x74, 0x6f,
+    0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09,
+    0x74, 0x6f, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x49, 0x64, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x02, 0x69, 0x64, 0x22, 0xcc, 0x01, 0x0a, 0x0d, 0x45, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x53,
+    0x75, 0x6d, 0x6d, 0x61, 0x72, 0x79, 0x12, 0x1f, 0x0a, 0x0b, 0x73, 0x74, 0x61, 0x72, 0x74, 0x5f,
+    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x73, 0x74, 0x61,
+    0x72, 0x74, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x74, 0x6f, 0x70, 0x5f,
+    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x73, 0x74, 0x6f,
+    0x70, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x12, 0x0a, 0x04, 0x72, 0x6f, 0x77, 0x73, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x04, 0x72, 0x6f, 0x77, 0x73, 0x12, 0x28, 0x0a, 0x10, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x73, 0x5f, 0x77, 0x69, 0x74, 0x68, 0x5f, 0x72, 0x6f, 0x77, 0x73, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x0e, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x57, 0x69, 0x74, 0x68,
+    0x52, 0x6f, 0x77, 0x73, 0x12, 0x3d, 0x0a, 0x0c, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65,
+    0x64, 0x5f, 0x61, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f,
+    0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d,
+    0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x0b, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65,
+    0x64, 0x41, 0x74, 0x22, 0x92, 0x01, 0x0a, 0x0b, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x73, 0x12, 0x43, 0x0a, 0x0b, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65,
+    0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x52, 0x0b, 0x63, 0x6f, 0x6c,
+    0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x1b, 0x0a, 0x09, 0x72, 0x70, 0x63, 0x5f,
+    0x63, 0x61, 0x6c, 0x6c, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x72, 0x70, 0x63,
+    0x43, 0x61, 0x6c, 0x6c, 0x73, 0x12, 0x21, 0x0a, 0x0c, 0x72, 0x70, 0x63, 0x5f, 0x66, 0x61, 0x69,
+    0x6c, 0x75, 0x72, 0x65, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x72, 0x70, 0x63,
+    0x46, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x73, 0x22, 0x86, 0x01, 0x0a, 0x0e, 0x54, 0x6f, 0x6b,
+    0x65, 0x6e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x73, 0x12, 0x34, 0x0a, 0x06, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x65, 0x74,
+    0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x6f, 0x6b, 0x65,
+    0x6e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x52, 0x06, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x73, 0x12, 0x1b, 0x0a, 0x09, 0x72, 0x70, 0x63, 0x5f, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x72, 0x70, 0x63, 0x43, 0x61, 0x6c, 0x6c, 0x73, 0x12, 0x21,
+    0x0a, 0x0c, 0x72, 0x70, 0x63, 0x5f, 0x66, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x73, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x72, 0x70, 0x63, 0x46, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65,
+    0x73, 0x22, 0xd2, 0x02, 0x0a, 0x0d, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x4d, 0x65, 0x74, 0x61, 0x64,
+    0x61, 0x74, 0x61, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x12,
+    0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x1b, 0x0a, 0x09, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x5f, 0x75, 0x72, 0x69, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x55, 0x72, 0x69, 0x12, 0x19, 0x0a, 0x08, 0x6f, 0x6e, 0x5f, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x6f, 0x6e, 0x43, 0x68, 0x61,
+    0x69, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x20, 0x0a, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69,
+    0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x64, 0x65, 0x73,
+    0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6d, 0x61, 0x67,
+    0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x69, 0x6d, 0x61, 0x67, 0x65, 0x12, 0x1b,
+    0x0a, 0x09, 0x69, 0x6d, 0x61, 0x67, 0x65, 0x5f, 0x73, 0x76, 0x67, 0x18, 0x08, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x08, 0x69, 0x6d, 0x61, 0x67, 0x65, 0x53, 0x76, 0x67, 0x12, 0x34, 0x0a, 0x0a, 0x61,
+    0x74, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x65, 0x73, 0x18, 0x09, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x14, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e,
+    0x54, 0x72, 0x61, 0x69, 0x74, 0x52, 0x0a, 0x61, 0x74, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x65,
+    0x73, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x0a, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07,
+    0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f,
+    0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x22, 0x3c, 0x0a, 0x05, 0x54, 0x72, 0x61, 0x69, 0x74, 0x12,
+    0x1d, 0x0a, 0x0a, 0x74, 0x72, 0x61, 0x69, 0x74, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x09, 0x74, 0x72, 0x61, 0x69, 0x74, 0x54, 0x79, 0x70, 0x65, 0x12, 0x14,
+    0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76,
+    0x61, 0x6c, 0x75, 0x65, 0x22, 0xca, 0x03, 0x0a, 0x12, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x12, 0x18, 0x0a, 0x07, 0x61,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64,
+    0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x46, 0x0a, 0x08, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72,
+    0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x2a, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72,
+    0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x53, 0x74, 0x61, 0x6e, 0x64,
+    0x61, 0x72, 0x64, 0x52, 0x08, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x12, 0x12, 0x0a,
+    0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d,
+    0x65, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x06, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x12, 0x14, 0x0a, 0x05, 0x6f, 0x77, 0x6e,
+    0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x12,
+    0x21, 0x0a, 0x0c, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x75, 0x72, 0x69, 0x18,
+    0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x55,
+    0x72, 0x69, 0x12, 0x19, 0x0a, 0x08, 0x6c, 0x6f, 0x67, 0x6f, 0x5f, 0x75, 0x72, 0x69, 0x18, 0x07,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x6c, 0x6f, 0x67, 0x6f, 0x55, 0x72, 0x69, 0x12, 0x29, 0x0a,
+    0x10, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x5f, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65,
+    0x72, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79,
+    0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x72, 0x12, 0x1f, 0x0a, 0x0b, 0x72, 0x6f, 0x79, 0x61,
+    0x6c, 0x74, 0x79, 0x5f, 0x62, 0x70, 0x73, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x72,
+    0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x42, 0x70, 0x73, 0x12, 0x1a, 0x0a, 0x08, 0x64, 0x65, 0x70,
+    0x6c, 0x6f, 0x79, 0x65, 0x72, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x64, 0x65, 0x70,
+    0x6c, 0x6f, 0x79, 0x65, 0x72, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73,
+    0x68, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68,
+    0x22, 0x4f, 0x0a, 0x08, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x12, 0x18, 0x0a, 0x14,
+    0x53, 0x54, 0x41, 0x4e, 0x44, 0x41, 0x52, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49,
+    0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x13, 0x0a, 0x0f, 0x53, 0x54, 0x41, 0x4e, 0x44, 0x41,
+    0x52, 0x44, 0x5f, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x10, 0x01, 0x12, 0x14, 0x0a, 0x10, 0x53,
+    0x54, 0x41, 0x4e, 0x44, 0x41, 0x52, 0x44, 0x5f, 0x45, 0x52, 0x43, 0x31, 0x31, 0x35, 0x35, 0x10,
+    0x02, 0x22, 0x4a, 0x0a, 0x10, 0x46, 0x72, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x45,
+    0x76, 0x65, 0x6e, 0x74, 0x73, 0x12, 0x36, 0x0a, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x18,
+    0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1e, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37,
+    0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x72, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c,
+    0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x22, 0xb3, 0x02,
+    0x0a, 0x0f, 0x46, 0x72, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x45, 0x76, 0x65, 0x6e,
+    0x74, 0x12, 0x37, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32,
+    0x23, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e,
+    0x46, 0x72, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x2e,
+    0x4b, 0x69, 0x6e, 0x64, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61,
+    0x75, 0x6c, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x75, 0x6c, 0x74,
+    0x12, 0x1f, 0x0a, 0x0b, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0a, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x54, 0x6f, 0x6b, 0x65,
+    0x6e, 0x12, 0x16, 0x0a, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x49, 0x64, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68,
+    0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12,
+    0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x22, 0x48, 0x0a, 0x04, 0x4b, 0x69, 0x6e,
+    0x64, 0x12, 0x14, 0x0a, 0x10, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43,
+    0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x17, 0x0a, 0x13, 0x4b, 0x49, 0x4e, 0x44, 0x5f,
+    0x46, 0x52, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x41, 0x4c, 0x49, 0x5a, 0x45, 0x44, 0x10, 0x01,
+    0x12, 0x11, 0x0a, 0x0d, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x52, 0x45, 0x44, 0x45, 0x45, 0x4d, 0x45,
+    0x44, 0x10, 0x02, 0x22, 0x32, 0x0a, 0x05, 0x53, 0x61, 0x6c, 0x65, 0x73, 0x12, 0x29, 0x0a, 0x05,
+    0x73, 0x61, 0x6c, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x65, 0x74,
+    0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x61, 0x6c, 0x65,
+    0x52, 0x05, 0x73, 0x61, 0x6c, 0x65, 0x73, 0x22, 0xfb, 0x02, 0x0a, 0x04, 0x53, 0x61, 0x6c, 0x65,
+    0x12, 0x20, 0x0a, 0x0b, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61,
+    0x63, 0x65, 0x12, 0x1e, 0x0a, 0x0a, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0a, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x16, 0x0a,
+    0x06, 0x73, 0x65, 0x6c, 0x6c, 0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x73,
+    0x65, 0x6c, 0x6c, 0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x75, 0x79, 0x65, 0x72, 0x18, 0x05,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x62, 0x75, 0x79, 0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x70,
+    0x72, 0x69, 0x63, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x70, 0x72, 0x69, 0x63,
+    0x65, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x63, 0x79, 0x18, 0x07, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x08, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x63, 0x79, 0x12, 0x19, 0x0a,
+    0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69,
+    0x6e, 0x61, 0x6c, 0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18,
+    0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x12, 0x1d, 0x0a, 0x0a,
+    0x6f, 0x72, 0x64, 0x65, 0x72, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0c,
+    0x52, 0x09, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x72,
+    0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x72, 0x6f,
+    0x79, 0x61, 0x6c, 0x74, 0x79, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x0d, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x3e, 0x0a, 0x0a, 0x50, 0x6f, 0x6f, 0x6c, 0x45, 0x76, 0x65,
+    0x6e, 0x74, 0x73, 0x12, 0x30, 0x0a, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x18, 0x01, 0x20,
+    0x03, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31,
+    0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6f, 0x6f, 0x6c, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x06, 0x65,
+    0x76, 0x65, 0x6e, 0x74, 0x73, 0x22, 0x80, 0x03, 0x0a, 0x09, 0x50, 0x6f, 0x6f, 0x6c, 0x45, 0x76,
+    0x65, 0x6e, 0x74, 0x12, 0x31, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0e, 0x32, 0x1d, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76,
+    0x31, 0x2e, 0x50, 0x6f, 0x6f, 0x6c, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x2e, 0x4b, 0x69, 0x6e, 0x64,
+    0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x70, 0x6f, 0x6f, 0x6c, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x70, 0x6f, 0x6f, 0x6c, 0x12, 0x16, 0x0a, 0x06, 0x61, 0x6d,
+    0x6f, 0x75, 0x6e, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x61, 0x6d, 0x6f, 0x75,
+    0x6e, 0x74, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a,
+    0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07,
+    0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x22, 0xde, 0x01, 0x0a, 0x04, 0x4b, 0x69, 0x6e, 0x64,
+    0x12, 0x14, 0x0a, 0x10, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49,
+    0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x15, 0x0a, 0x11, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x50,
+    0x4f, 0x4f, 0x4c, 0x5f, 0x43, 0x52, 0x45, 0x41, 0x54, 0x45, 0x44, 0x10, 0x01, 0x12, 0x14, 0x0a,
+    0x10, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4e, 0x46, 0x54, 0x5f, 0x44, 0x45, 0x50, 0x4f, 0x53, 0x49,
+    0x54, 0x10, 0x02, 0x12, 0x16, 0x0a, 0x12, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x54, 0x4f, 0x4b, 0x45,
+    0x4e, 0x5f, 0x44, 0x45, 0x50, 0x4f, 0x53, 0x49, 0x54, 0x10, 0x03, 0x12, 0x17, 0x0a, 0x13, 0x4b,
+    0x49, 0x4e, 0x44, 0x5f, 0x4e, 0x46, 0x54, 0x5f, 0x57, 0x49, 0x54, 0x48, 0x44, 0x52, 0x41, 0x57,
+    0x41, 0x4c, 0x10, 0x04, 0x12, 0x19, 0x0a, 0x15, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x54, 0x4f, 0x4b,
+    0x45, 0x4e, 0x5f, 0x57, 0x49, 0x54, 0x48, 0x44, 0x52, 0x41, 0x57, 0x41, 0x4c, 0x10, 0x05, 0x12,
+    0x14, 0x0a, 0x10, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x53, 0x57, 0x41, 0x50, 0x5f, 0x4e, 0x46, 0x54,
+    0x5f, 0x49, 0x4e, 0x10, 0x06, 0x12, 0x15, 0x0a, 0x11, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x53, 0x57,
+    0x41, 0x50, 0x5f, 0x4e, 0x46, 0x54, 0x5f, 0x4f, 0x55, 0x54, 0x10, 0x07, 0x12, 0x1a, 0x0a, 0x16,
+    0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x53, 0x50, 0x4f, 0x54, 0x5f, 0x50, 0x52, 0x49, 0x43, 0x45, 0x5f,
+    0x55, 0x50, 0x44, 0x41, 0x54, 0x45, 0x10, 0x08, 0x22, 0x44, 0x0a, 0x0d, 0x43, 0x75, 0x73, 0x74,
+    0x6f, 0x64, 0x79, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x12, 0x33, 0x0a, 0x06, 0x65, 0x76, 0x65,
+    0x6e, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x65, 0x74, 0x68, 0x2e,
+    0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x64,
+    0x79, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x22, 0x91,
+    0x02, 0x0a, 0x0c, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x64, 0x79, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12,
+    0x34, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x20, 0x2e,
+    0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x75,
+    0x73, 0x74, 0x6f, 0x64, 0x79, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x2e, 0x4b, 0x69, 0x6e, 0x64, 0x52,
+    0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x1c, 0x0a, 0x09, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x64, 0x69,
+    0x61, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x64,
+    0x69, 0x61, 0x6e, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x19, 0x0a,
+    0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f,
+    0x68, 0x61, 0x73, 0x68, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48,
+    0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x06,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x22, 0x43, 0x0a,
+    0x04, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x14, 0x0a, 0x10, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e,
+    0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x10, 0x0a, 0x0c, 0x4b,
+    0x49, 0x4e, 0x44, 0x5f, 0x44, 0x45, 0x50, 0x4f, 0x53, 0x49, 0x54, 0x10, 0x01, 0x12, 0x13, 0x0a,
+    0x0f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x57, 0x49, 0x54, 0x48, 0x44, 0x52, 0x41, 0x57, 0x41, 0x4c,
+    0x10, 0x02, 0x22, 0x4f, 0x0a, 0x0f, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x41, 0x63, 0x74, 0x69, 0x76,
+    0x69, 0x74, 0x69, 0x65, 0x73, 0x12, 0x3c, 0x0a, 0x0a, 0x61, 0x63, 0x74, 0x69, 0x76, 0x69, 0x74,
+    0x69, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x65, 0x74, 0x68, 0x2e,
+    0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x41,
+    0x63, 0x74, 0x69, 0x76, 0x69, 0x74, 0x79, 0x52, 0x0a, 0x61, 0x63, 0x74, 0x69, 0x76, 0x69, 0x74,
+    0x69, 0x65, 0x73, 0x22, 0xb3, 0x01, 0x0a, 0x0d, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x41, 0x63, 0x74,
+    0x69, 0x76, 0x69, 0x74, 0x79, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69,
+    0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64,
+    0x12, 0x25, 0x0a, 0x0e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x5f, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0d, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x6c, 0x61, 0x73, 0x74, 0x5f,
+    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x6c, 0x61, 0x73,
+    0x74, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x41, 0x0a, 0x0e, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x74,
+    0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a,
+    0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66,
+    0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x0d, 0x6c, 0x61, 0x73, 0x74,
+    0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0x75, 0x0a, 0x0a, 0x54, 0x6f, 0x6b,
+    0x65, 0x6e, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x6f, 0x77, 0x6e, 0x65, 0x72,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x12, 0x1f, 0x0a,
+    0x0b, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x0a, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x30,
+    0x0a, 0x05, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e,
+    0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e,
+    0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x05, 0x73, 0x69, 0x6e, 0x63, 0x65,
+    0x22, 0x52, 0x0a, 0x11, 0x45, 0x6e, 0x72, 0x69, 0x63, 0x68, 0x65, 0x64, 0x54, 0x72, 0x61, 0x6e,
+    0x73, 0x66, 0x65, 0x72, 0x73, 0x12, 0x3d, 0x0a, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65,
+    0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65,
+    0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x45, 0x6e, 0x72, 0x69, 0x63, 0x68, 0x65,
+    0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x73, 0x22, 0xe7, 0x01, 0x0a, 0x10, 0x45, 0x6e, 0x72, 0x69, 0x63, 0x68, 0x65,
+    0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x33, 0x0a, 0x08, 0x74, 0x72, 0x61,
+    0x6e, 0x73, 0x66, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x65, 0x74,
+    0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e,
+    0x73, 0x66, 0x65, 0x72, 0x52, 0x08, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x25,
+    0x0a, 0x0e, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x5f, 0x6f, 0x77, 0x6e, 0x65, 0x72,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0d, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73,
+    0x4f, 0x77, 0x6e, 0x65, 0x72, 0x12, 0x25, 0x0a, 0x0e, 0x68, 0x6f, 0x6c, 0x64, 0x69, 0x6e, 0x67,
+    0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0d, 0x68,
+    0x6f, 0x6c, 0x64, 0x69, 0x6e, 0x67, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x12, 0x27, 0x0a, 0x0f,
+    0x68, 0x6f, 0x6c, 0x64, 0x69, 0x6e, 0x67, 0x5f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0e, 0x68, 0x6f, 0x6c, 0x64, 0x69, 0x6e, 0x67, 0x53, 0x65,
+    0x63, 0x6f, 0x6e, 0x64, 0x73, 0x12, 0x27, 0x0a, 0x0f, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0e,
+    0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x4e, 0x61, 0x6d, 0x65, 0x22, 0xb0,
+    0x01, 0x0a, 0x11, 0x46, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x65, 0x64, 0x54, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x73, 0x12, 0x35, 0x0a, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72,
+    0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x52, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x12, 0x34, 0x0a, 0x16, 0x64,
+    0x72, 0x6f, 0x70, 0x70, 0x65, 0x64, 0x5f, 0x73, 0x65, 0x6c, 0x66, 0x5f, 0x74, 0x72, 0x61, 0x6e,
+    0x73, 0x66, 0x65, 0x72, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x14, 0x64, 0x72, 0x6f,
+    0x70, 0x70, 0x65, 0x64, 0x53, 0x65, 0x6c, 0x66, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x73, 0x12, 0x2e, 0x0a, 0x13, 0x64, 0x72, 0x6f, 0x70, 0x70, 0x65, 0x64, 0x5f, 0x72, 0x6f, 0x75,
+    0x6e, 0x64, 0x5f, 0x74, 0x72, 0x69, 0x70, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x11,
+    0x64, 0x72, 0x6f, 0x70, 0x70, 0x65, 0x64, 0x52, 0x6f, 0x75, 0x6e, 0x64, 0x54, 0x72, 0x69, 0x70,
+    0x73, 0x22, 0x52, 0x0a, 0x0b, 0x52, 0x65, 0x76, 0x65, 0x72, 0x74, 0x41, 0x75, 0x64, 0x69, 0x74,
+    0x12, 0x43, 0x0a, 0x0d, 0x64, 0x69, 0x73, 0x63, 0x72, 0x65, 0x70, 0x61, 0x6e, 0x63, 0x69, 0x65,
+    0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72,
+    0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x6f, 0x67, 0x44, 0x69, 0x73, 0x63, 0x72,
+    0x65, 0x70, 0x61, 0x6e, 0x63, 0x79, 0x52, 0x0d, 0x64, 0x69, 0x73, 0x63, 0x72, 0x65, 0x70, 0x61,
+    0x6e, 0x63, 0x69, 0x65, 0x73, 0x22, 0x8d, 0x02, 0x0a, 0x0e, 0x4c, 0x6f, 0x67, 0x44, 0x69, 0x73,
+    0x63, 0x72, 0x65, 0x70, 0x61, 0x6e, 0x63, 0x79, 0x12, 0x36, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x22, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63,
+    0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x6f, 0x67, 0x44, 0x69, 0x73, 0x63, 0x72, 0x65,
+    0x70, 0x61, 0x6e, 0x63, 0x79, 0x2e, 0x4b, 0x69, 0x6e, 0x64, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64,
+    0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x1f, 0x0a, 0x0b, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d,
+    0x52, 0x0a, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x1d, 0x0a, 0x0a,
+    0x74, 0x72, 0x78, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x05,
+    0x52, 0x09, 0x74, 0x72, 0x78, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x22, 0x68, 0x0a, 0x04, 0x4b,
+    0x69, 0x6e, 0x64, 0x12, 0x14, 0x0a, 0x10, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50,
+    0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x16, 0x0a, 0x12, 0x4b, 0x49, 0x4e,
+    0x44, 0x5f, 0x52, 0x45, 0x56, 0x45, 0x52, 0x54, 0x45, 0x44, 0x5f, 0x43, 0x41, 0x4c, 0x4c, 0x10,
+    0x01, 0x12, 0x15, 0x0a, 0x11, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x4d, 0x49, 0x53, 0x53, 0x49, 0x4e,
+    0x47, 0x5f, 0x43, 0x41, 0x4c, 0x4c, 0x10, 0x02, 0x12, 0x1b, 0x0a, 0x17, 0x4b, 0x49, 0x4e, 0x44,
+    0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45, 0x44, 0x5f, 0x54, 0x52, 0x41, 0x4e, 0x53, 0x41, 0x43, 0x54,
+    0x49, 0x4f, 0x4e, 0x10, 0x03, 0x22, 0x42, 0x0a, 0x0d, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63,
+    0x74, 0x43, 0x61, 0x6c, 0x6c, 0x73, 0x12, 0x31, 0x0a, 0x05, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x18,
+    0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37,
+    0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x43, 0x61,
+    0x6c, 0x6c, 0x52, 0x05, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x22, 0xff, 0x01, 0x0a, 0x0c, 0x43, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x43, 0x61, 0x6c, 0x6c, 0x12, 0x16, 0x0a, 0x06, 0x6d, 0x65,
+    0x74, 0x68, 0x6f, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x6d, 0x65, 0x74, 0x68,
+    0x6f, 0x64, 0x12, 0x30, 0x0a, 0x06, 0x69, 0x6e, 0x70, 0x75, 0x74, 0x73, 0x18, 0x02, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x18, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e,
+    0x76, 0x31, 0x2e, 0x43, 0x61, 0x6c, 0x6c, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x52, 0x06, 0x69, 0x6e,
+    0x70, 0x75, 0x74, 0x73, 0x12, 0x32, 0x0a, 0x07, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x73, 0x18,
+    0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37,
+    0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x61, 0x6c, 0x6c, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x52,
+    0x07, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x73, 0x12, 0x16, 0x0a, 0x06, 0x63, 0x61, 0x6c, 0x6c,
+    0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72,
+    0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61,
+    0x73, 0x68, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73,
+    0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x07, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x0e, 0x0a, 0x02, 0x69,
+    0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x35, 0x0a, 0x09, 0x43,
+    0x61, 0x6c, 0x6c, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x14, 0x0a, 0x05,
+    0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c,
+    0x75, 0x65, 0x22, 0xc1, 0x01, 0x0a, 0x0e, 0x46, 0x69, 0x6e, 0x61, 0x6c, 0x69, 0x74, 0x79, 0x55,
+    0x70, 0x64, 0x61, 0x74, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x39, 0x0a, 0x0b, 0x70,
+    0x72, 0x6f, 0x76, 0x69, 0x73, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b,
     0x32, 0x17, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31,
-    0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73,
-    0x66, 0x65, 0x72, 0x73, 0x22, 0x7e, 0x0a, 0x08, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
-    0x12, 0x12, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04,
-    0x66, 0x72, 0x6f, 0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c,
-    0x52, 0x02, 0x74, 0x6f, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64,
-    0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12,
-    0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28,
-    0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72,
-    0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64,
-    0x69, 0x6e, 0x61, 0x6c, 0x4a, 0xa4, 0x03, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x0e, 0x01, 0x0a,
-    0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03,
-    0x02, 0x00, 0x16, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x04, 0x00, 0x06, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x00, 0x12, 0x03, 0x05, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
-    0x04, 0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12,
-    0x03, 0x05, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x05,
-    0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x05, 0x20, 0x21,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x08, 0x00, 0x0e, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x01, 0x01, 0x12, 0x03, 0x08, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00,
-    0x12, 0x03, 0x09, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03,
-    0x09, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x09, 0x08,
-    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x09, 0x0f, 0x10, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0a, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0a, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x0a, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03,
-    0x0b, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0b, 0x02,
-    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0b, 0x09, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0b, 0x14, 0x15, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x0c, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x03, 0x05, 0x12, 0x03, 0x0c, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03,
-    0x01, 0x12, 0x03, 0x0c, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12,
-    0x03, 0x0c, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x0d, 0x02,
-    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x05, 0x12, 0x03, 0x0d, 0x02, 0x08, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x0d, 0x09, 0x10, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x0d, 0x13, 0x14, 0x62, 0x06, 0x70, 0x72, 0x6f,
-    0x74, 0x6f, 0x33,
+    0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52, 0x0b, 0x70, 0x72, 0x6f, 0x76, 0x69,
+    0x73, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x12, 0x27, 0x0a, 0x0f, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x72,
+    0x6d, 0x65, 0x64, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x0e, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x72, 0x6d, 0x65, 0x64, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12,
+    0x35, 0x0a, 0x09, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x72, 0x6d, 0x65, 0x64, 0x18, 0x04, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x17, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e,
+    0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52, 0x09, 0x63, 0x6f, 0x6e,
+    0x66, 0x69, 0x72, 0x6d, 0x65, 0x64, 0x22, 0x98, 0x01, 0x0a, 0x0b, 0x43, 0x6f, 0x68, 0x6f, 0x72,
+    0x74, 0x53, 0x74, 0x61, 0x74, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x1f, 0x0a, 0x0b,
+    0x6e, 0x65, 0x77, 0x5f, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x0a, 0x6e, 0x65, 0x77, 0x48, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x73, 0x12, 0x29, 0x0a,
+    0x10, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x69, 0x6e, 0x67, 0x5f, 0x62, 0x75, 0x79, 0x65, 0x72,
+    0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0f, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x69,
+    0x6e, 0x67, 0x42, 0x75, 0x79, 0x65, 0x72, 0x73, 0x12, 0x27, 0x0a, 0x0f, 0x65, 0x78, 0x69, 0x74,
+    0x69, 0x6e, 0x67, 0x5f, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x0e, 0x65, 0x78, 0x69, 0x74, 0x69, 0x6e, 0x67, 0x48, 0x6f, 0x6c, 0x64, 0x65, 0x72,
+    0x73, 0x22, 0x40, 0x0a, 0x0b, 0x57, 0x68, 0x61, 0x6c, 0x65, 0x41, 0x6c, 0x65, 0x72, 0x74, 0x73,
+    0x12, 0x31, 0x0a, 0x06, 0x61, 0x6c, 0x65, 0x72, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b,
+    0x32, 0x19, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31,
+    0x2e, 0x57, 0x68, 0x61, 0x6c, 0x65, 0x41, 0x6c, 0x65, 0x72, 0x74, 0x52, 0x06, 0x61, 0x6c, 0x65,
+    0x72, 0x74, 0x73, 0x22, 0xa5, 0x01, 0x0a, 0x0a, 0x57, 0x68, 0x61, 0x6c, 0x65, 0x41, 0x6c, 0x65,
+    0x72, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x63, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63,
+    0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65,
+    0x12, 0x16, 0x0a, 0x06, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x06, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x75, 0x73, 0x64, 0x5f,
+    0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x01, 0x52, 0x08, 0x75, 0x73, 0x64,
+    0x56, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x06,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x22, 0xb6, 0x01, 0x0a, 0x0e,
+    0x44, 0x6f, 0x72, 0x6d, 0x61, 0x6e, 0x63, 0x79, 0x52, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x12, 0x14,
+    0x0a, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x08, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64,
+    0x12, 0x37, 0x0a, 0x07, 0x64, 0x6f, 0x72, 0x6d, 0x61, 0x6e, 0x74, 0x18, 0x03, 0x20, 0x03, 0x28,
+    0x0b, 0x32, 0x1d, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76,
+    0x31, 0x2e, 0x44, 0x6f, 0x72, 0x6d, 0x61, 0x6e, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x52, 0x07, 0x64, 0x6f, 0x72, 0x6d, 0x61, 0x6e, 0x74, 0x12, 0x39, 0x0a, 0x08, 0x61, 0x77, 0x61,
+    0x6b, 0x65, 0x6e, 0x65, 0x64, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x65, 0x74,
+    0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x44, 0x6f, 0x72, 0x6d,
+    0x61, 0x6e, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x52, 0x08, 0x61, 0x77, 0x61, 0x6b,
+    0x65, 0x6e, 0x65, 0x64, 0x22, 0x99, 0x01, 0x0a, 0x0e, 0x44, 0x6f, 0x72, 0x6d, 0x61, 0x6e, 0x74,
+    0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65,
+    0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x12, 0x2a, 0x0a, 0x11, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x61, 0x63, 0x74, 0x69, 0x76, 0x65,
+    0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0f, 0x6c, 0x61,
+    0x73, 0x74, 0x41, 0x63, 0x74, 0x69, 0x76, 0x65, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x27, 0x0a,
+    0x0f, 0x69, 0x6e, 0x61, 0x63, 0x74, 0x69, 0x76, 0x65, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0e, 0x69, 0x6e, 0x61, 0x63, 0x74, 0x69, 0x76, 0x65,
+    0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x12, 0x18, 0x0a, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63,
+    0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x03, 0x52, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65,
+    0x22, 0x7e, 0x0a, 0x0e, 0x54, 0x61, 0x67, 0x67, 0x65, 0x64, 0x41, 0x63, 0x74, 0x69, 0x76, 0x69,
+    0x74, 0x79, 0x12, 0x3b, 0x0a, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x18,
+    0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37,
+    0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x61, 0x67, 0x67, 0x65, 0x64, 0x54, 0x72, 0x61, 0x6e,
+    0x73, 0x66, 0x65, 0x72, 0x52, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x12,
+    0x2f, 0x0a, 0x05, 0x73, 0x61, 0x6c, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x19,
+    0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x54,
+    0x61, 0x67, 0x67, 0x65, 0x64, 0x53, 0x61, 0x6c, 0x65, 0x52, 0x05, 0x73, 0x61, 0x6c, 0x65, 0x73,
+    0x22, 0x7b, 0x0a, 0x0e, 0x54, 0x61, 0x67, 0x67, 0x65, 0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x12, 0x33, 0x0a, 0x08, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32,
+    0x31, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52, 0x08, 0x74,
+    0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x1b, 0x0a, 0x09, 0x66, 0x72, 0x6f, 0x6d, 0x5f,
+    0x74, 0x61, 0x67, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x52, 0x08, 0x66, 0x72, 0x6f, 0x6d,
+    0x54, 0x61, 0x67, 0x73, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x6f, 0x5f, 0x74, 0x61, 0x67, 0x73, 0x18,
+    0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x06, 0x74, 0x6f, 0x54, 0x61, 0x67, 0x73, 0x22, 0x75, 0x0a,
+    0x0a, 0x54, 0x61, 0x67, 0x67, 0x65, 0x64, 0x53, 0x61, 0x6c, 0x65, 0x12, 0x27, 0x0a, 0x04, 0x73,
+    0x61, 0x6c, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x65, 0x74, 0x68, 0x2e,
+    0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x61, 0x6c, 0x65, 0x52, 0x04,
+    0x73, 0x61, 0x6c, 0x65, 0x12, 0x1f, 0x0a, 0x0b, 0x73, 0x65, 0x6c, 0x6c, 0x65, 0x72, 0x5f, 0x74,
+    0x61, 0x67, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0a, 0x73, 0x65, 0x6c, 0x6c, 0x65,
+    0x72, 0x54, 0x61, 0x67, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x62, 0x75, 0x79, 0x65, 0x72, 0x5f, 0x74,
+    0x61, 0x67, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x09, 0x62, 0x75, 0x79, 0x65, 0x72,
+    0x54, 0x61, 0x67, 0x73, 0x22, 0x54, 0x0a, 0x12, 0x43, 0x6c, 0x75, 0x73, 0x74, 0x65, 0x72, 0x65,
+    0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x12, 0x3e, 0x0a, 0x09, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e,
+    0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6c,
+    0x75, 0x73, 0x74, 0x65, 0x72, 0x65, 0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52,
+    0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x22, 0x8a, 0x01, 0x0a, 0x11, 0x43,
+    0x6c, 0x75, 0x73, 0x74, 0x65, 0x72, 0x65, 0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x12, 0x33, 0x0a, 0x08, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x17, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e,
+    0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52, 0x08, 0x74, 0x72, 0x61,
+    0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x21, 0x0a, 0x0c, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x63, 0x6c,
+    0x75, 0x73, 0x74, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x66, 0x72, 0x6f,
+    0x6d, 0x43, 0x6c, 0x75, 0x73, 0x74, 0x65, 0x72, 0x12, 0x1d, 0x0a, 0x0a, 0x74, 0x6f, 0x5f, 0x63,
+    0x6c, 0x75, 0x73, 0x74, 0x65, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x74, 0x6f,
+    0x43, 0x6c, 0x75, 0x73, 0x74, 0x65, 0x72, 0x22, 0x3d, 0x0a, 0x03, 0x50, 0x6e, 0x6c, 0x12, 0x36,
+    0x0a, 0x08, 0x72, 0x65, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b,
+    0x32, 0x1a, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31,
+    0x2e, 0x52, 0x65, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x50, 0x6e, 0x6c, 0x52, 0x08, 0x72, 0x65,
+    0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x22, 0xbd, 0x01, 0x0a, 0x0b, 0x52, 0x65, 0x61, 0x6c, 0x69,
+    0x7a, 0x65, 0x64, 0x50, 0x6e, 0x6c, 0x12, 0x16, 0x0a, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x12, 0x19,
+    0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x63, 0x6f, 0x73,
+    0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x63, 0x6f, 0x73, 0x74, 0x12, 0x1a, 0x0a,
+    0x08, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x65, 0x64, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x08, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x65, 0x64, 0x73, 0x12, 0x16, 0x0a, 0x06, 0x70, 0x72, 0x6f,
+    0x66, 0x69, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x70, 0x72, 0x6f, 0x66, 0x69,
+    0x74, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07,
+    0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f,
+    0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x22, 0x7d, 0x0a, 0x0a, 0x56, 0x61, 0x6c, 0x75, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x1f, 0x0a, 0x0b, 0x66, 0x6c,
+    0x6f, 0x6f, 0x72, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x0a, 0x66, 0x6c, 0x6f, 0x6f, 0x72, 0x50, 0x72, 0x69, 0x63, 0x65, 0x12, 0x38, 0x0a, 0x07, 0x68,
+    0x6f, 0x6c, 0x64, 0x65, 0x72, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1e, 0x2e, 0x65,
+    0x74, 0x68, 0x2e, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x2e, 0x76, 0x31, 0x2e, 0x48, 0x6f, 0x6c,
+    0x64, 0x65, 0x72, 0x56, 0x61, 0x6c, 0x75, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x07, 0x68, 0x6f,
+    0x6c, 0x64, 0x65, 0x72, 0x73, 0x22, 0xc2, 0x01, 0x0a, 0x0b, 0x48, 0x6f, 0x6c, 0x64, 0x65, 0x72,
+    0x41, 0x75, 0x64, 0x69, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x18, 0x0a, 0x07, 0x61,
+    0x75, 0x64, 0x69, 0x74, 0x65, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x61, 0x75,
+    0x64, 0x69, 0x74, 0x65, 0x64, 0x12, 0x20, 0x0a, 0x0b, 0x69, 0x6e, 0x63, 0x72, 0x65, 0x6d, 0x65,
+    0x6e, 0x74, 0x61, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0b, 0x69, 0x6e, 0x63, 0x72,
+    0x65, 0x6d, 0x65, 0x6e, 0x74, 0x61, 0x6c, 0x12, 0x1e, 0x0a, 0x0a, 0x72, 0x65, 0x63, 0x6f, 0x6d,
+    0x70, 0x75, 0x74, 0x65, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x03, 0x52, 0x0a, 0x72, 0x65, 0x63,
+    0x6f, 0x6d, 0x70, 0x75, 0x74, 0x65, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x64, 0x72, 0x69, 0x66, 0x74,
+    0x18, 0x05, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x64, 0x72, 0x69, 0x66, 0x74, 0x12, 0x2b, 0x0a,
+    0x11, 0x6e, 0x65, 0x67, 0x61, 0x74, 0x69, 0x76, 0x65, 0x5f, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63,
+    0x65, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x10, 0x6e, 0x65, 0x67, 0x61, 0x74, 0x69,
+    0x76, 0x65, 0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x73, 0x22, 0x91, 0x01, 0x0a, 0x0b, 0x4d,
+    0x61, 0x72, 0x6b, 0x65, 0x74, 0x53, 0x74, 0x61, 0x74, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x12, 0x16, 0x0a, 0x06, 0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x06, 0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x61, 0x6c, 0x65,
+    0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x73, 0x61, 0x6c, 0x65, 0x73, 0x12, 0x21,
+    0x0a, 0x0c, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x6e, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x6e, 0x50, 0x72, 0x69, 0x63,
+    0x65, 0x12, 0x1b, 0x0a, 0x09, 0x70, 0x39, 0x30, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x05,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x70, 0x39, 0x30, 0x50, 0x72, 0x69, 0x63, 0x65, 0x22, 0x9f,
+    0x01, 0x0a, 0x0f, 0x48, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x56, 0x61, 0x6c, 0x75, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x12, 0x16, 0x0a, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x62, 0x61,
+    0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x03, 0x52, 0x07, 0x62, 0x61, 0x6c,
+    0x61, 0x6e, 0x63, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x63, 0x6f,
+    0x73, 0x74, 0x5f, 0x62, 0x61, 0x73, 0x69, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09,
+    0x63, 0x6f, 0x73, 0x74, 0x42, 0x61, 0x73, 0x69, 0x73, 0x12, 0x25, 0x0a, 0x0e, 0x75, 0x6e, 0x72,
+    0x65, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x5f, 0x70, 0x6e, 0x6c, 0x18, 0x05, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x0d, 0x75, 0x6e, 0x72, 0x65, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x50, 0x6e, 0x6c,
+    0x4a, 0x91, 0xc4, 0x01, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0xa3, 0x04, 0x01, 0x0a, 0x08, 0x0a,
+    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00,
+    0x16, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x29, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x00, 0x12, 0x04, 0x06, 0x00, 0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12,
+    0x03, 0x06, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02,
+    0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x07, 0x0b, 0x13, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x07, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12,
+    0x04, 0x0a, 0x00, 0x18, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0a, 0x08,
+    0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x0b, 0x0f, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01,
+    0x12, 0x03, 0x0c, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x0c, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0c, 0x08,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0c, 0x0d, 0x0e, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x0d, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0d, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x0d, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03,
+    0x0e, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x05, 0x12, 0x03, 0x0e, 0x02,
+    0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03, 0x0e, 0x08, 0x10, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x0e, 0x13, 0x14, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x0f, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x04, 0x05, 0x12, 0x03, 0x0f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04,
+    0x01, 0x12, 0x03, 0x0f, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12,
+    0x03, 0x0f, 0x13, 0x14, 0x0a, 0x2f, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05, 0x12, 0x03, 0x11, 0x02,
+    0x15, 0x1a, 0x22, 0x20, 0x43, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x6e, 0x67, 0x73,
+    0x20, 0x74, 0x6f, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x05, 0x12, 0x03,
+    0x11, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x11, 0x08,
+    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x11, 0x13, 0x14, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12, 0x03, 0x12, 0x02, 0x2a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x06, 0x06, 0x12, 0x03, 0x12, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x06, 0x01, 0x12, 0x03, 0x12, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06,
+    0x03, 0x12, 0x03, 0x12, 0x28, 0x29, 0x0a, 0xab, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12,
+    0x03, 0x15, 0x02, 0x11, 0x1a, 0x9d, 0x01, 0x20, 0x60, 0x64, 0x61, 0x74, 0x61, 0x60, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x66, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x46, 0x72, 0x6f, 0x6d, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x6d, 0x61, 0x64, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x2c, 0x20, 0x73, 0x6f, 0x6d, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f,
+    0x6c, 0x73, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x20, 0x69, 0x6e, 0x73, 0x74, 0x72, 0x75,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72,
+    0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x72, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x2e, 0x20,
+    0x45, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x05, 0x12, 0x03, 0x15,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x01, 0x12, 0x03, 0x15, 0x08, 0x0c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x03, 0x12, 0x03, 0x15, 0x0f, 0x10, 0x0a, 0x5f,
+    0x0a, 0x04, 0x04, 0x01, 0x02, 0x08, 0x12, 0x03, 0x17, 0x02, 0x10, 0x1a, 0x52, 0x20, 0x60, 0x3c,
+    0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x3e, 0x2d, 0x3c, 0x6f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x3e, 0x60, 0x2c, 0x20, 0x75, 0x6e, 0x69, 0x71, 0x75, 0x65, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x73, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x72,
+    0x65, 0x70, 0x6c, 0x61, 0x79, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x69, 0x6e, 0x6b, 0x73,
+    0x20, 0x74, 0x6f, 0x20, 0x75, 0x70, 0x73, 0x65, 0x72, 0x74, 0x20, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x05, 0x12, 0x03, 0x17, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03, 0x17, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x08, 0x03, 0x12, 0x03, 0x17, 0x0e, 0x0f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12,
+    0x04, 0x1a, 0x00, 0x1c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x1a, 0x08,
+    0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x1b, 0x02, 0x2e, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x1b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1b, 0x0b, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x21, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x1b, 0x2c, 0x2d, 0x0a, 0x8b, 0x01, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x20,
+    0x00, 0x2f, 0x01, 0x1a, 0x7f, 0x20, 0x53, 0x61, 0x66, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x20, 0x75, 0x6e, 0x64, 0x6f, 0x6e, 0x65, 0x20, 0x62, 0x65, 0x63, 0x61, 0x75,
+    0x73, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x6e, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x52,
+    0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x68, 0x6f, 0x6f, 0x6b, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x72, 0x20, 0x72, 0x65, 0x76,
+    0x65, 0x72, 0x74, 0x65, 0x64, 0x20, 0x6f, 0x72, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x65,
+    0x64, 0x20, 0x61, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20,
+    0x74, 0x68, 0x61, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x0a, 0x20, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74,
+    0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x20, 0x08, 0x1d,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x21, 0x02, 0x15, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x21, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x21, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x21, 0x13, 0x14, 0x0a, 0x75, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12,
+    0x03, 0x23, 0x02, 0x16, 0x1a, 0x68, 0x20, 0x57, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x77, 0x68, 0x6f, 0x6c, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x2c, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x6d,
+    0x61, 0x79, 0x20, 0x61, 0x6c, 0x73, 0x6f, 0x20, 0x72, 0x65, 0x76, 0x65, 0x72, 0x74, 0x20, 0x61,
+    0x6c, 0x6f, 0x6e, 0x65, 0x20, 0x69, 0x6e, 0x73, 0x69, 0x64, 0x65, 0x20, 0x61, 0x20, 0x73, 0x75,
+    0x63, 0x63, 0x65, 0x73, 0x73, 0x66, 0x75, 0x6c, 0x20, 0x6f, 0x6e, 0x65, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x23, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x23, 0x07, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x01, 0x03, 0x12, 0x03, 0x23, 0x14, 0x15, 0x0a, 0x61, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02,
+    0x12, 0x03, 0x25, 0x02, 0x15, 0x1a, 0x54, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x20, 0x6f,
+    0x66, 0x20, 0x73, 0x61, 0x66, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x46, 0x72,
+    0x6f, 0x6d, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x20, 0x6f,
+    0x72, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x6f, 0x70,
+    0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x6c, 0x69, 0x6b, 0x65, 0x20, 0x61, 0x20, 0x6d, 0x61,
+    0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x25, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x25, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03,
+    0x12, 0x03, 0x25, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x03, 0x12, 0x03, 0x26,
+    0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x05, 0x12, 0x03, 0x26, 0x02, 0x07,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x01, 0x12, 0x03, 0x26, 0x08, 0x0c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x03, 0x12, 0x03, 0x26, 0x0f, 0x10, 0x0a, 0x36, 0x0a, 0x04,
+    0x04, 0x03, 0x02, 0x04, 0x12, 0x03, 0x28, 0x02, 0x0f, 0x1a, 0x29, 0x20, 0x43, 0x6f, 0x6e, 0x74,
+    0x72, 0x61, 0x63, 0x74, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x68, 0x6f, 0x6f, 0x6b, 0x20,
+    0x72, 0x65, 0x6a, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x05, 0x12, 0x03, 0x28,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x01, 0x12, 0x03, 0x28, 0x08, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x03, 0x12, 0x03, 0x28, 0x0d, 0x0e, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x03, 0x02, 0x05, 0x12, 0x03, 0x29, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x05, 0x05, 0x12, 0x03, 0x29, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x05, 0x01, 0x12, 0x03, 0x29, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x03,
+    0x12, 0x03, 0x29, 0x14, 0x15, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x06, 0x12, 0x03, 0x2b,
+    0x02, 0x14, 0x1a, 0x41, 0x20, 0x52, 0x65, 0x76, 0x65, 0x72, 0x74, 0x20, 0x72, 0x65, 0x61, 0x73,
+    0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x6f, 0x6f, 0x6b, 0x2c, 0x20,
+    0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x6e, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x65,
+    0x64, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x69, 0x74, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72,
+    0x6e, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x06, 0x05, 0x12, 0x03,
+    0x2b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x06, 0x01, 0x12, 0x03, 0x2b, 0x09,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x06, 0x03, 0x12, 0x03, 0x2b, 0x12, 0x13, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x07, 0x12, 0x03, 0x2c, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x07, 0x05, 0x12, 0x03, 0x2c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x07, 0x01, 0x12, 0x03, 0x2c, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07,
+    0x03, 0x12, 0x03, 0x2c, 0x13, 0x14, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x08, 0x12, 0x03,
+    0x2e, 0x02, 0x10, 0x1a, 0x52, 0x20, 0x60, 0x3c, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68,
+    0x3e, 0x2d, 0x3c, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x3e, 0x60, 0x2c, 0x20, 0x75, 0x6e,
+    0x69, 0x71, 0x75, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20,
+    0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x79, 0x73, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x73, 0x69, 0x6e, 0x6b, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x75, 0x70, 0x73, 0x65,
+    0x72, 0x74, 0x20, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x05,
+    0x12, 0x03, 0x2e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x01, 0x12, 0x03,
+    0x2e, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x03, 0x12, 0x03, 0x2e, 0x0e,
+    0x0f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x31, 0x00, 0x33, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x31, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02,
+    0x00, 0x12, 0x03, 0x32, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12,
+    0x03, 0x32, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x32,
+    0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x32, 0x18, 0x21,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x32, 0x24, 0x25, 0x0a, 0x58,
+    0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x36, 0x00, 0x4e, 0x01, 0x1a, 0x4c, 0x20, 0x54, 0x6f, 0x6b,
+    0x65, 0x6e, 0x73, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x6f, 0x6e,
+    0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x77, 0x69, 0x74, 0x68,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x45, 0x54, 0x48, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12,
+    0x03, 0x36, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x05, 0x04, 0x00, 0x12, 0x04, 0x37, 0x02,
+    0x3d, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x04, 0x00, 0x01, 0x12, 0x03, 0x37, 0x07, 0x0c,
+    0x0a, 0x40, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x39, 0x04, 0x1a, 0x1a,
+    0x31, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x20, 0x66, 0x75, 0x6e, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x20, 0x74, 0x61, 0x62, 0x6c, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x39,
+    0x04, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x39,
+    0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x3a, 0x04,
+    0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3a, 0x04,
+    0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x3a, 0x13,
+    0x14, 0x0a, 0x7b, 0x0a, 0x06, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x3c, 0x04, 0x18,
+    0x1a, 0x6c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x20, 0x66, 0x75, 0x6e, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x61, 0x6b, 0x65, 0x73, 0x20, 0x61, 0x20, 0x6d, 0x65, 0x72,
+    0x6b, 0x6c, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2c, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x61,
+    0x6d, 0x65, 0x64, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x61, 0x6e, 0x20, 0x61, 0x6c, 0x6c,
+    0x6f, 0x77, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x6f, 0x72, 0x20, 0x69, 0x73, 0x20, 0x6c, 0x69, 0x73,
+    0x74, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x61, 0x6c, 0x6c, 0x6f,
+    0x77, 0x6c, 0x69, 0x73, 0x74, 0x60, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x2e, 0x0a, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3c, 0x04, 0x13, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x05, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x3c, 0x16, 0x17, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x3f, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3f, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x3f, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x3f, 0x13, 0x14, 0x0a, 0x5a, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x41,
+    0x02, 0x13, 0x1a, 0x4d, 0x20, 0x43, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x20, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x75, 0x79, 0x65, 0x72, 0x20, 0x6f, 0x72, 0x20, 0x61,
+    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x69, 0x6e,
+    0x67, 0x20, 0x6f, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x20, 0x62, 0x65, 0x68, 0x61, 0x6c, 0x66, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x41, 0x02, 0x07, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x41, 0x08, 0x0e, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x41, 0x11, 0x12, 0x0a, 0x4c, 0x0a, 0x04, 0x04,
+    0x05, 0x02, 0x02, 0x12, 0x03, 0x43, 0x02, 0x14, 0x1a, 0x3f, 0x20, 0x53, 0x69, 0x67, 0x6e, 0x61,
+    0x74, 0x75, 0x72, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x69, 0x6e, 0x74,
+    0x20, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x69, 0x74,
+    0x73, 0x20, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20,
+    0x75, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x02, 0x05, 0x12, 0x03, 0x43, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x43, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03,
+    0x43, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x44, 0x02, 0x12,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x06, 0x12, 0x03, 0x44, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x44, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x03, 0x03, 0x12, 0x03, 0x44, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05,
+    0x02, 0x04, 0x12, 0x03, 0x45, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x04,
+    0x12, 0x03, 0x45, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x05, 0x12, 0x03,
+    0x45, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x01, 0x12, 0x03, 0x45, 0x12,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x03, 0x12, 0x03, 0x45, 0x1e, 0x1f, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x05, 0x12, 0x03, 0x46, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x05, 0x05, 0x12, 0x03, 0x46, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x05, 0x01, 0x12, 0x03, 0x46, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x05,
+    0x03, 0x12, 0x03, 0x46, 0x14, 0x15, 0x0a, 0x52, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x06, 0x12, 0x03,
+    0x48, 0x02, 0x12, 0x1a, 0x45, 0x20, 0x45, 0x54, 0x48, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x62,
+    0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x70,
+    0x61, 0x69, 0x64, 0x20, 0x70, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x69,
+    0x6e, 0x20, 0x77, 0x65, 0x69, 0x20, 0x61, 0x73, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c,
+    0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x06, 0x05, 0x12, 0x03, 0x48, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06,
+    0x01, 0x12, 0x03, 0x48, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06, 0x03, 0x12,
+    0x03, 0x48, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x07, 0x12, 0x03, 0x49, 0x02,
+    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x07, 0x05, 0x12, 0x03, 0x49, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x07, 0x01, 0x12, 0x03, 0x49, 0x09, 0x13, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x07, 0x03, 0x12, 0x03, 0x49, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x05, 0x02, 0x08, 0x12, 0x03, 0x4a, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x08,
+    0x05, 0x12, 0x03, 0x4a, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x08, 0x01, 0x12,
+    0x03, 0x4a, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x08, 0x03, 0x12, 0x03, 0x4a,
+    0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x09, 0x12, 0x03, 0x4b, 0x02, 0x2b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x09, 0x06, 0x12, 0x03, 0x4b, 0x02, 0x1b, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x09, 0x01, 0x12, 0x03, 0x4b, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x09, 0x03, 0x12, 0x03, 0x4b, 0x28, 0x2a, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x05, 0x02,
+    0x0a, 0x12, 0x03, 0x4d, 0x02, 0x11, 0x1a, 0x52, 0x20, 0x60, 0x3c, 0x74, 0x72, 0x78, 0x5f, 0x68,
+    0x61, 0x73, 0x68, 0x3e, 0x2d, 0x3c, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x3e, 0x60, 0x2c,
+    0x20, 0x75, 0x6e, 0x69, 0x71, 0x75, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x74, 0x61, 0x62,
+    0x6c, 0x65, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x79,
+    0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x69, 0x6e, 0x6b, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x75,
+    0x70, 0x73, 0x65, 0x72, 0x74, 0x20, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x0a, 0x05, 0x12, 0x03, 0x4d, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0a,
+    0x01, 0x12, 0x03, 0x4d, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x0a, 0x03, 0x12,
+    0x03, 0x4d, 0x0e, 0x10, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x50, 0x00, 0x52, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x50, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x51, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x51, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06,
+    0x12, 0x03, 0x51, 0x0b, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x51, 0x19, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x51, 0x23,
+    0x24, 0x0a, 0x76, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x55, 0x00, 0x6e, 0x01, 0x1a, 0x6a, 0x20,
+    0x52, 0x65, 0x76, 0x65, 0x6e, 0x75, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x76, 0x69, 0x6e, 0x67, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x65, 0x61, 0x73, 0x75, 0x72, 0x79, 0x20, 0x6f, 0x66, 0x20,
+    0x61, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x3a, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x74, 0x73, 0x20, 0x50, 0x61,
+    0x79, 0x6d, 0x65, 0x6e, 0x74, 0x53, 0x70, 0x6c, 0x69, 0x74, 0x74, 0x65, 0x72, 0x20, 0x63, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01,
+    0x12, 0x03, 0x55, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x07, 0x04, 0x00, 0x12, 0x04, 0x56,
+    0x02, 0x5c, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x04, 0x00, 0x01, 0x12, 0x03, 0x56, 0x07,
+    0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x57, 0x04, 0x19,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x57, 0x04, 0x14,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x57, 0x17, 0x18,
+    0x0a, 0x47, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x59, 0x04, 0x15, 0x1a,
+    0x38, 0x20, 0x50, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x52, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65,
+    0x64, 0x20, 0x6f, 0x72, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30, 0x50, 0x61, 0x79, 0x6d, 0x65, 0x6e,
+    0x74, 0x52, 0x65, 0x6c, 0x65, 0x61, 0x73, 0x65, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x73,
+    0x70, 0x6c, 0x69, 0x74, 0x74, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04,
+    0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x59, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04,
+    0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x59, 0x13, 0x14, 0x0a, 0x35, 0x0a, 0x06, 0x04, 0x07, 0x04,
+    0x00, 0x02, 0x02, 0x12, 0x03, 0x5b, 0x04, 0x18, 0x1a, 0x26, 0x20, 0x45, 0x54, 0x48, 0x20, 0x73,
+    0x65, 0x6e, 0x74, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x5b, 0x04, 0x13,
+    0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x5b, 0x16, 0x17,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x5e, 0x02, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x00, 0x06, 0x12, 0x03, 0x5e, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5e, 0x07, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x5e, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12,
+    0x03, 0x5f, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5f,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5f, 0x08, 0x12,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5f, 0x15, 0x16, 0x0a, 0x4b,
+    0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x61, 0x02, 0x13, 0x1a, 0x3e, 0x20, 0x43, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x70, 0x61, 0x79, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x75,
+    0x74, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x20, 0x6f, 0x72, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x74, 0x73, 0x20,
+    0x73, 0x70, 0x6c, 0x69, 0x74, 0x74, 0x65, 0x72, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x02, 0x05, 0x12, 0x03, 0x61, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x61, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03,
+    0x12, 0x03, 0x61, 0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12, 0x03, 0x62,
+    0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03, 0x62, 0x02, 0x07,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x62, 0x08, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x62, 0x14, 0x15, 0x0a, 0x47, 0x0a, 0x04,
+    0x04, 0x07, 0x02, 0x04, 0x12, 0x03, 0x64, 0x02, 0x14, 0x1a, 0x3a, 0x20, 0x49, 0x6e, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x65, 0x73, 0x74, 0x20, 0x75, 0x6e, 0x69, 0x74,
+    0x20, 0x6f, 0x66, 0x20, 0x60, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x63, 0x79, 0x60, 0x2c, 0x20,
+    0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72,
+    0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x05, 0x12, 0x03,
+    0x64, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x01, 0x12, 0x03, 0x64, 0x09,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x03, 0x12, 0x03, 0x64, 0x12, 0x13, 0x0a,
+    0x42, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x05, 0x12, 0x03, 0x66, 0x02, 0x15, 0x1a, 0x35, 0x20, 0x45,
+    0x52, 0x43, 0x32, 0x30, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x79, 0x6f, 0x75, 0x74, 0x2c, 0x20, 0x65, 0x6d, 0x70,
+    0x74, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6e, 0x61, 0x74, 0x69, 0x76, 0x65, 0x20, 0x45, 0x54,
+    0x48, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x05, 0x12, 0x03, 0x66, 0x02,
+    0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x01, 0x12, 0x03, 0x66, 0x08, 0x10, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x03, 0x12, 0x03, 0x66, 0x13, 0x14, 0x0a, 0x6b, 0x0a,
+    0x04, 0x04, 0x07, 0x02, 0x06, 0x12, 0x03, 0x68, 0x02, 0x1d, 0x1a, 0x5e, 0x20, 0x45, 0x54, 0x48,
+    0x20, 0x72, 0x65, 0x76, 0x65, 0x6e, 0x75, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20,
+    0x6d, 0x69, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74,
+    0x69, 0x65, 0x73, 0x20, 0x73, 0x6f, 0x20, 0x66, 0x61, 0x72, 0x2c, 0x20, 0x73, 0x65, 0x65, 0x20,
+    0x73, 0x74, 0x6f, 0x72, 0x65, 0x5f, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x5f, 0x72, 0x65, 0x76, 0x65, 0x6e, 0x75, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x06, 0x05, 0x12, 0x03, 0x68, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x06,
+    0x01, 0x12, 0x03, 0x68, 0x09, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x06, 0x03, 0x12,
+    0x03, 0x68, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x07, 0x12, 0x03, 0x69, 0x02,
+    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x07, 0x05, 0x12, 0x03, 0x69, 0x02, 0x07, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x07, 0x01, 0x12, 0x03, 0x69, 0x08, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x07, 0x03, 0x12, 0x03, 0x69, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x07, 0x02, 0x08, 0x12, 0x03, 0x6a, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x08,
+    0x05, 0x12, 0x03, 0x6a, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x08, 0x01, 0x12,
+    0x03, 0x6a, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x08, 0x03, 0x12, 0x03, 0x6a,
+    0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x09, 0x12, 0x03, 0x6b, 0x02, 0x2b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x09, 0x06, 0x12, 0x03, 0x6b, 0x02, 0x1b, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x09, 0x01, 0x12, 0x03, 0x6b, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x09, 0x03, 0x12, 0x03, 0x6b, 0x28, 0x2a, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x07, 0x02,
+    0x0a, 0x12, 0x03, 0x6d, 0x02, 0x11, 0x1a, 0x52, 0x20, 0x60, 0x3c, 0x74, 0x72, 0x78, 0x5f, 0x68,
+    0x61, 0x73, 0x68, 0x3e, 0x2d, 0x3c, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x3e, 0x60, 0x2c,
+    0x20, 0x75, 0x6e, 0x69, 0x71, 0x75, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x74, 0x61, 0x62,
+    0x6c, 0x65, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x79,
+    0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x69, 0x6e, 0x6b, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x75,
+    0x70, 0x73, 0x65, 0x72, 0x74, 0x20, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x0a, 0x05, 0x12, 0x03, 0x6d, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0a,
+    0x01, 0x12, 0x03, 0x6d, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x0a, 0x03, 0x12,
+    0x03, 0x6d, 0x0e, 0x10, 0x0a, 0x6d, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x71, 0x00, 0x7c, 0x01,
+    0x1a, 0x61, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x20, 0x6f, 0x66, 0x20,
+    0x61, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x61, 0x73, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6c,
+    0x6c, 0x65, 0x6c, 0x20, 0x61, 0x72, 0x72, 0x61, 0x79, 0x73, 0x2c, 0x20, 0x65, 0x6e, 0x74, 0x72,
+    0x79, 0x20, 0x60, 0x69, 0x60, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x63,
+    0x6f, 0x6c, 0x75, 0x6d, 0x6e, 0x20, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65,
+    0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x71, 0x08, 0x17, 0x0a,
+    0x5b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x73, 0x02, 0x1f, 0x1a, 0x4e, 0x20, 0x44,
+    0x69, 0x73, 0x74, 0x69, 0x6e, 0x63, 0x74, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x2c, 0x20, 0x72, 0x65, 0x66, 0x65, 0x72, 0x72, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x62,
+    0x79, 0x20, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x73, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x73, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x73, 0x11, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x73, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x74, 0x02,
+    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x74, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x74, 0x0b, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x74, 0x11, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x74, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02,
+    0x02, 0x12, 0x03, 0x75, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x04, 0x12,
+    0x03, 0x75, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x75,
+    0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x03, 0x75, 0x12, 0x20,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x03, 0x75, 0x23, 0x24, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03, 0x76, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x03, 0x04, 0x12, 0x03, 0x76, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x03, 0x05, 0x12, 0x03, 0x76, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x76, 0x12, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x03,
+    0x76, 0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x04, 0x12, 0x03, 0x77, 0x02, 0x1a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x04, 0x12, 0x03, 0x77, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x77, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x77, 0x11, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x04, 0x03, 0x12, 0x03, 0x77, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x05,
+    0x12, 0x03, 0x78, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x04, 0x12, 0x03,
+    0x78, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x05, 0x12, 0x03, 0x78, 0x0b,
+    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x01, 0x12, 0x03, 0x78, 0x11, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x03, 0x12, 0x03, 0x78, 0x16, 0x17, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x08, 0x02, 0x06, 0x12, 0x03, 0x79, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x06, 0x04, 0x12, 0x03, 0x79, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06,
+    0x05, 0x12, 0x03, 0x79, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x01, 0x12,
+    0x03, 0x79, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x03, 0x12, 0x03, 0x79,
+    0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x07, 0x12, 0x03, 0x7a, 0x02, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x07, 0x04, 0x12, 0x03, 0x7a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x07, 0x05, 0x12, 0x03, 0x7a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x07, 0x01, 0x12, 0x03, 0x7a, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x07, 0x03, 0x12, 0x03, 0x7a, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x08, 0x12,
+    0x03, 0x7b, 0x02, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x06, 0x12, 0x03, 0x7b,
+    0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x01, 0x12, 0x03, 0x7b, 0x1c, 0x25,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x03, 0x12, 0x03, 0x7b, 0x28, 0x29, 0x0a, 0x75,
+    0x0a, 0x02, 0x04, 0x09, 0x12, 0x05, 0x7f, 0x00, 0x81, 0x01, 0x01, 0x1a, 0x68, 0x20, 0x54, 0x72,
+    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x20, 0x61, 0x73, 0x20, 0x72, 0x6f, 0x77, 0x73, 0x20,
+    0x6f, 0x66, 0x20, 0x73, 0x63, 0x61, 0x6c, 0x61, 0x72, 0x20, 0x63, 0x6f, 0x6c, 0x75, 0x6d, 0x6e,
+    0x73, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x79, 0x6f, 0x75, 0x74, 0x20, 0x73, 0x75,
+    0x62, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x73, 0x2d, 0x73, 0x69, 0x6e, 0x6b, 0x2d, 0x66, 0x69,
+    0x6c, 0x65, 0x73, 0x20, 0x77, 0x72, 0x69, 0x74, 0x65, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x50, 0x61,
+    0x72, 0x71, 0x75, 0x65, 0x74, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x43, 0x53, 0x56, 0x20, 0x61, 0x73,
+    0x20, 0x69, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x7f, 0x08,
+    0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x04, 0x80, 0x01, 0x02, 0x21, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x04, 0x80, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x06, 0x12, 0x04, 0x80, 0x01, 0x0b, 0x17, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x04, 0x80, 0x01, 0x18, 0x1c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x04, 0x80, 0x01, 0x1f, 0x20, 0x0a, 0x78, 0x0a, 0x02, 0x04,
+    0x0a, 0x12, 0x06, 0x84, 0x01, 0x00, 0x8f, 0x01, 0x01, 0x1a, 0x6a, 0x20, 0x41, 0x64, 0x64, 0x72,
+    0x65, 0x73, 0x73, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x68, 0x61, 0x73, 0x68, 0x65, 0x73,
+    0x20, 0x61, 0x72, 0x65, 0x20, 0x60, 0x30, 0x78, 0x60, 0x20, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78,
+    0x65, 0x64, 0x20, 0x6c, 0x6f, 0x77, 0x65, 0x72, 0x63, 0x61, 0x73, 0x65, 0x20, 0x68, 0x65, 0x78,
+    0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x74, 0x69, 0x6d, 0x65,
+    0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x73, 0x65, 0x63, 0x6f,
+    0x6e, 0x64, 0x73, 0x20, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x70,
+    0x6f, 0x63, 0x68, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x04, 0x84, 0x01,
+    0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x04, 0x85, 0x01, 0x02, 0x1a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x04, 0x85, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x04, 0x85, 0x01, 0x09, 0x15, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x04, 0x85, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x04, 0x86, 0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x01, 0x05, 0x12, 0x04, 0x86, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0x86, 0x01, 0x08, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0x86, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02,
+    0x12, 0x04, 0x87, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12,
+    0x04, 0x87, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x04,
+    0x87, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x03, 0x12, 0x04, 0x87,
+    0x01, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x03, 0x12, 0x04, 0x88, 0x01, 0x02,
+    0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x05, 0x12, 0x04, 0x88, 0x01, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x04, 0x88, 0x01, 0x09, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x04, 0x88, 0x01, 0x13, 0x14, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x0a, 0x02, 0x04, 0x12, 0x04, 0x89, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x89, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x89, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x04, 0x03, 0x12, 0x04, 0x89, 0x01, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x02,
+    0x05, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x05,
+    0x12, 0x04, 0x8a, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x01, 0x12,
+    0x04, 0x8a, 0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x03, 0x12, 0x04,
+    0x8a, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x06, 0x12, 0x04, 0x8b, 0x01,
+    0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x05, 0x12, 0x04, 0x8b, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x01, 0x12, 0x04, 0x8b, 0x01, 0x09, 0x13,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x03, 0x12, 0x04, 0x8b, 0x01, 0x16, 0x17, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x07, 0x12, 0x04, 0x8c, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x07, 0x05, 0x12, 0x04, 0x8c, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x07, 0x01, 0x12, 0x04, 0x8c, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x07, 0x03, 0x12, 0x04, 0x8c, 0x01, 0x14, 0x15, 0x0a, 0x60, 0x0a, 0x04, 0x04, 0x0a,
+    0x02, 0x08, 0x12, 0x04, 0x8e, 0x01, 0x02, 0x10, 0x1a, 0x52, 0x20, 0x60, 0x3c, 0x74, 0x72, 0x78,
+    0x5f, 0x68, 0x61, 0x73, 0x68, 0x3e, 0x2d, 0x3c, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x3e,
+    0x60, 0x2c, 0x20, 0x75, 0x6e, 0x69, 0x71, 0x75, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x74,
+    0x61, 0x62, 0x6c, 0x65, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x72, 0x65, 0x70, 0x6c,
+    0x61, 0x79, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x69, 0x6e, 0x6b, 0x73, 0x20, 0x74, 0x6f,
+    0x20, 0x75, 0x70, 0x73, 0x65, 0x72, 0x74, 0x20, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x08, 0x05, 0x12, 0x04, 0x8e, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x08, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x09, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x08, 0x03, 0x12, 0x04, 0x8e, 0x01, 0x0e, 0x0f, 0x0a, 0x44, 0x0a, 0x02, 0x04, 0x0b, 0x12,
+    0x06, 0x92, 0x01, 0x00, 0x9a, 0x01, 0x01, 0x1a, 0x36, 0x20, 0x45, 0x6d, 0x69, 0x74, 0x74, 0x65,
+    0x64, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x2c, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c,
+    0x61, 0x73, 0x74, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x62,
+    0x6f, 0x75, 0x6e, 0x64, 0x65, 0x64, 0x20, 0x65, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x2e, 0x0a, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x04, 0x92, 0x01, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x0b, 0x02, 0x00, 0x12, 0x04, 0x93, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0x93, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x93, 0x01, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0x93, 0x01, 0x17, 0x18, 0x0a, 0x2c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12,
+    0x04, 0x95, 0x01, 0x02, 0x18, 0x1a, 0x1e, 0x20, 0x46, 0x69, 0x72, 0x73, 0x74, 0x20, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x61,
+    0x6e, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x04,
+    0x95, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x04, 0x95,
+    0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x04, 0x95, 0x01,
+    0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02, 0x12, 0x04, 0x96, 0x01, 0x02, 0x12,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x05, 0x12, 0x04, 0x96, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x04, 0x96, 0x01, 0x09, 0x0d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x04, 0x96, 0x01, 0x10, 0x11, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x0b, 0x02, 0x03, 0x12, 0x04, 0x97, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x03, 0x05, 0x12, 0x04, 0x97, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x03, 0x01, 0x12, 0x04, 0x97, 0x01, 0x09, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x03, 0x03, 0x12, 0x04, 0x97, 0x01, 0x1c, 0x1d, 0x0a, 0x39, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x04,
+    0x12, 0x04, 0x99, 0x01, 0x02, 0x2d, 0x1a, 0x2b, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x61, 0x6e, 0x67,
+    0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x04, 0x06, 0x12, 0x04, 0x99, 0x01,
+    0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x04, 0x01, 0x12, 0x04, 0x99, 0x01, 0x1c,
+    0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x04, 0x03, 0x12, 0x04, 0x99, 0x01, 0x2b, 0x2c,
+    0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x06, 0x9c, 0x01, 0x00, 0xa1, 0x01, 0x01, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x04, 0x9c, 0x01, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x0c, 0x02, 0x00, 0x12, 0x04, 0x9d, 0x01, 0x02, 0x2e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0x9d, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00,
+    0x06, 0x12, 0x04, 0x9d, 0x01, 0x0b, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0x9d, 0x01, 0x1e, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0x9d, 0x01, 0x2c, 0x2d, 0x0a, 0x61, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x04, 0x9f,
+    0x01, 0x02, 0x17, 0x1a, 0x53, 0x20, 0x52, 0x50, 0x43, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x20,
+    0x6d, 0x61, 0x64, 0x65, 0x20, 0x77, 0x68, 0x69, 0x6c, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x62, 0x69,
+    0x6e, 0x67, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x20, 0x69, 0x6e, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x68,
+    0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x20,
+    0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0x9f, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0x9f, 0x01, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0x9f, 0x01, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x02, 0x12, 0x04, 0xa0,
+    0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x05, 0x12, 0x04, 0xa0, 0x01,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa0, 0x01, 0x09,
+    0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa0, 0x01, 0x18, 0x19,
+    0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x06, 0xa3, 0x01, 0x00, 0xa8, 0x01, 0x01, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x04, 0xa3, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x0d, 0x02, 0x00, 0x12, 0x04, 0xa4, 0x01, 0x02, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0xa4, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00,
+    0x06, 0x12, 0x04, 0xa4, 0x01, 0x0b, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xa4, 0x01, 0x19, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xa4, 0x01, 0x22, 0x23, 0x0a, 0x66, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12, 0x04, 0xa6,
+    0x01, 0x02, 0x17, 0x1a, 0x58, 0x20, 0x60, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x55, 0x52, 0x49, 0x60,
+    0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x6d, 0x61, 0x64, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x65,
+    0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x68, 0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x6d, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x01, 0x05, 0x12, 0x04, 0xa6, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa6, 0x01, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa6, 0x01, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d,
+    0x02, 0x02, 0x12, 0x04, 0xa7, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02,
+    0x05, 0x12, 0x04, 0xa7, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x01,
+    0x12, 0x04, 0xa7, 0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x03, 0x12,
+    0x04, 0xa7, 0x01, 0x18, 0x19, 0x0a, 0xd2, 0x01, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06, 0xac, 0x01,
+    0x00, 0xbb, 0x01, 0x01, 0x1a, 0xc3, 0x01, 0x20, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
+    0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x6d, 0x69, 0x6e, 0x74,
+    0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e,
+    0x20, 0x46, 0x75, 0x6c, 0x6c, 0x79, 0x20, 0x6f, 0x6e, 0x2d, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20,
+    0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x69, 0x6e, 0x6c, 0x69,
+    0x6e, 0x65, 0x20, 0x69, 0x74, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x60, 0x64, 0x61, 0x74, 0x61,
+    0x3a, 0x61, 0x70, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2f, 0x6a, 0x73, 0x6f,
+    0x6e, 0x60, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x0a, 0x20, 0x55, 0x52, 0x49, 0x2c, 0x20, 0x69,
+    0x74, 0x20, 0x69, 0x73, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x64, 0x65, 0x63,
+    0x6f, 0x64, 0x65, 0x64, 0x2e, 0x20, 0x4f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x20, 0x55, 0x52, 0x49, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6c, 0x65, 0x66, 0x74, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x73, 0x75, 0x6d, 0x65, 0x72, 0x73, 0x20, 0x74, 0x6f,
+    0x20, 0x72, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0e,
+    0x01, 0x12, 0x04, 0xac, 0x01, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x00, 0x12,
+    0x04, 0xad, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x05, 0x12, 0x04,
+    0xad, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12, 0x04, 0xad,
+    0x01, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x04, 0xad, 0x01,
+    0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x04, 0xae, 0x01, 0x02, 0x16,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x05, 0x12, 0x04, 0xae, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x04, 0xae, 0x01, 0x09, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x04, 0xae, 0x01, 0x14, 0x15, 0x0a, 0x47, 0x0a,
+    0x04, 0x04, 0x0e, 0x02, 0x02, 0x12, 0x04, 0xb0, 0x01, 0x02, 0x17, 0x1a, 0x39, 0x20, 0x54, 0x6f,
+    0x6b, 0x65, 0x6e, 0x20, 0x55, 0x52, 0x49, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20,
+    0x70, 0x6f, 0x69, 0x6e, 0x74, 0x73, 0x20, 0x6f, 0x66, 0x66, 0x2d, 0x63, 0x68, 0x61, 0x69, 0x6e,
+    0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x64, 0x65, 0x63,
+    0x6f, 0x64, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x05, 0x12,
+    0x04, 0xb0, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x01, 0x12, 0x04,
+    0xb0, 0x01, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x03, 0x12, 0x04, 0xb0,
+    0x01, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x03, 0x12, 0x04, 0xb1, 0x01, 0x02,
+    0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x05, 0x12, 0x04, 0xb1, 0x01, 0x02, 0x06,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x01, 0x12, 0x04, 0xb1, 0x01, 0x07, 0x0f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x03, 0x12, 0x04, 0xb1, 0x01, 0x12, 0x13, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x0e, 0x02, 0x04, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0e, 0x02, 0x04, 0x05, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x04, 0x01, 0x12, 0x04, 0xb2, 0x01, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x04, 0x03, 0x12, 0x04, 0xb2, 0x01, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02,
+    0x05, 0x12, 0x04, 0xb3, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x05,
+    0x12, 0x04, 0xb3, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x01, 0x12,
+    0x04, 0xb3, 0x01, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x03, 0x12, 0x04,
+    0xb3, 0x01, 0x17, 0x18, 0x0a, 0x3a, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x06, 0x12, 0x04, 0xb5, 0x01,
+    0x02, 0x13, 0x1a, 0x2c, 0x20, 0x49, 0x6d, 0x61, 0x67, 0x65, 0x20, 0x55, 0x52, 0x49, 0x2c, 0x20,
+    0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73,
+    0x20, 0x61, 0x6e, 0x20, 0x69, 0x6e, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x53, 0x56, 0x47, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x05, 0x12, 0x04, 0xb5, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x01, 0x12, 0x04, 0xb5, 0x01, 0x09, 0x0e, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x03, 0x12, 0x04, 0xb5, 0x01, 0x11, 0x12, 0x0a, 0x4d, 0x0a,
+    0x04, 0x04, 0x0e, 0x02, 0x07, 0x12, 0x04, 0xb7, 0x01, 0x02, 0x17, 0x1a, 0x3f, 0x20, 0x53, 0x56,
+    0x47, 0x20, 0x64, 0x6f, 0x63, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20,
+    0x60, 0x64, 0x61, 0x74, 0x61, 0x3a, 0x69, 0x6d, 0x61, 0x67, 0x65, 0x2f, 0x73, 0x76, 0x67, 0x2b,
+    0x78, 0x6d, 0x6c, 0x60, 0x20, 0x69, 0x6d, 0x61, 0x67, 0x65, 0x2c, 0x20, 0x62, 0x61, 0x73, 0x65,
+    0x36, 0x34, 0x20, 0x64, 0x65, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0e, 0x02, 0x07, 0x05, 0x12, 0x04, 0xb7, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x07, 0x01, 0x12, 0x04, 0xb7, 0x01, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x07, 0x03, 0x12, 0x04, 0xb7, 0x01, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02,
+    0x08, 0x12, 0x04, 0xb8, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x08, 0x04,
+    0x12, 0x04, 0xb8, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x08, 0x06, 0x12,
+    0x04, 0xb8, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x08, 0x01, 0x12, 0x04,
+    0xb8, 0x01, 0x11, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x08, 0x03, 0x12, 0x04, 0xb8,
+    0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x09, 0x12, 0x04, 0xb9, 0x01, 0x02,
+    0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x09, 0x05, 0x12, 0x04, 0xb9, 0x01, 0x02, 0x07,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x09, 0x01, 0x12, 0x04, 0xb9, 0x01, 0x08, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x09, 0x03, 0x12, 0x04, 0xb9, 0x01, 0x13, 0x15, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x0e, 0x02, 0x0a, 0x12, 0x04, 0xba, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0e, 0x02, 0x0a, 0x05, 0x12, 0x04, 0xba, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x0a, 0x01, 0x12, 0x04, 0xba, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x0a, 0x03, 0x12, 0x04, 0xba, 0x01, 0x13, 0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0f, 0x12,
+    0x06, 0xbd, 0x01, 0x00, 0xc1, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x04,
+    0xbd, 0x01, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x04, 0xbe, 0x01,
+    0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x05, 0x12, 0x04, 0xbe, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0xbe, 0x01, 0x09, 0x13,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x04, 0xbe, 0x01, 0x16, 0x17, 0x0a,
+    0x38, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x04, 0xc0, 0x01, 0x02, 0x13, 0x1a, 0x2a, 0x20,
+    0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x62, 0x6f, 0x6f, 0x6c,
+    0x65, 0x61, 0x6e, 0x73, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x4a, 0x53,
+    0x4f, 0x4e, 0x20, 0x74, 0x65, 0x78, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02,
+    0x01, 0x05, 0x12, 0x04, 0xc0, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0xc0, 0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03,
+    0x12, 0x04, 0xc0, 0x01, 0x11, 0x12, 0x0a, 0x6c, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0xc4, 0x01,
+    0x00, 0xd9, 0x01, 0x01, 0x1a, 0x5e, 0x20, 0x41, 0x6e, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x63, 0x6f,
+    0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x64, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65,
+    0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74,
+    0x61, 0x20, 0x61, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20,
+    0x6e, 0x65, 0x65, 0x64, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x69, 0x74, 0x73, 0x20, 0x70, 0x61,
+    0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0xc4, 0x01, 0x08,
+    0x1a, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x10, 0x04, 0x00, 0x12, 0x06, 0xc5, 0x01, 0x02, 0xc9, 0x01,
+    0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x04, 0x00, 0x01, 0x12, 0x04, 0xc5, 0x01, 0x07, 0x0f,
+    0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x10, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xc6, 0x01, 0x04, 0x1d,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x10, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc6, 0x01, 0x04,
+    0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x10, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xc6, 0x01,
+    0x1b, 0x1c, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x10, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xc7, 0x01,
+    0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x10, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc7,
+    0x01, 0x04, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x10, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04,
+    0xc7, 0x01, 0x16, 0x17, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x10, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04,
+    0xc8, 0x01, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x10, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0xc8, 0x01, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x10, 0x04, 0x00, 0x02, 0x02, 0x02,
+    0x12, 0x04, 0xc8, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04,
+    0xca, 0x01, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x05, 0x12, 0x04, 0xca,
+    0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x01, 0x12, 0x04, 0xca, 0x01,
+    0x08, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12, 0x04, 0xca, 0x01, 0x12,
+    0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x01, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x18, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x06, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12, 0x04, 0xcb, 0x01, 0x0b, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12, 0x04, 0xcb, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x10, 0x02, 0x02, 0x12, 0x04, 0xcc, 0x01, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0xcc, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0xcc, 0x01, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0xcc, 0x01, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x03, 0x12,
+    0x04, 0xcd, 0x01, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x05, 0x12, 0x04,
+    0xcd, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x01, 0x12, 0x04, 0xcd,
+    0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x03, 0x12, 0x04, 0xcd, 0x01,
+    0x12, 0x13, 0x0a, 0x48, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x04, 0x12, 0x04, 0xcf, 0x01, 0x02, 0x12,
+    0x1a, 0x3a, 0x20, 0x60, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x28, 0x29, 0x60, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2c, 0x20, 0x65, 0x6d,
+    0x70, 0x74, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x6e,
+    0x6f, 0x74, 0x20, 0x4f, 0x77, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x04, 0x05, 0x12, 0x04, 0xcf, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x10, 0x02, 0x04, 0x01, 0x12, 0x04, 0xcf, 0x01, 0x08, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x04, 0x03, 0x12, 0x04, 0xcf, 0x01, 0x10, 0x11, 0x0a, 0x6c, 0x0a, 0x04, 0x04, 0x10, 0x02,
+    0x05, 0x12, 0x04, 0xd1, 0x01, 0x02, 0x1a, 0x1a, 0x5e, 0x20, 0x45, 0x52, 0x43, 0x2d, 0x37, 0x35,
+    0x37, 0x32, 0x20, 0x60, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x55, 0x52, 0x49, 0x28,
+    0x29, 0x60, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x6f, 0x67, 0x6f,
+    0x20, 0x69, 0x74, 0x73, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x20, 0x70, 0x6f,
+    0x69, 0x6e, 0x74, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x6e, 0x6c,
+    0x69, 0x6e, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x60, 0x64, 0x61, 0x74, 0x61, 0x3a,
+    0x60, 0x20, 0x55, 0x52, 0x49, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x05, 0x05,
+    0x12, 0x04, 0xd1, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x05, 0x01, 0x12,
+    0x04, 0xd1, 0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x05, 0x03, 0x12, 0x04,
+    0xd1, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x06, 0x12, 0x04, 0xd2, 0x01,
+    0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x06, 0x05, 0x12, 0x04, 0xd2, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x06, 0x01, 0x12, 0x04, 0xd2, 0x01, 0x09, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x06, 0x03, 0x12, 0x04, 0xd2, 0x01, 0x14, 0x15, 0x0a,
+    0x6f, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x07, 0x12, 0x04, 0xd4, 0x01, 0x02, 0x1d, 0x1a, 0x61, 0x20,
+    0x45, 0x49, 0x50, 0x2d, 0x32, 0x39, 0x38, 0x31, 0x20, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74,
+    0x20, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x72, 0x65, 0x74,
+    0x75, 0x72, 0x6e, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x60, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74,
+    0x79, 0x49, 0x6e, 0x66, 0x6f, 0x60, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x20, 0x30, 0x2e, 0x20, 0x45, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x6e,
+    0x6f, 0x74, 0x20, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x07, 0x05, 0x12, 0x04, 0xd4, 0x01, 0x02, 0x07, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x07, 0x01, 0x12, 0x04, 0xd4, 0x01, 0x08, 0x18, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x07, 0x03, 0x12, 0x04, 0xd4, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x10, 0x02, 0x08, 0x12, 0x04, 0xd5, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x10, 0x02, 0x08, 0x05, 0x12, 0x04, 0xd5, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x08, 0x01, 0x12, 0x04, 0xd5, 0x01, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x08, 0x03, 0x12, 0x04, 0xd5, 0x01, 0x17, 0x18, 0x0a, 0x3d, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x09,
+    0x12, 0x04, 0xd7, 0x01, 0x02, 0x16, 0x1a, 0x2f, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x09, 0x05,
+    0x12, 0x04, 0xd7, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x09, 0x01, 0x12,
+    0x04, 0xd7, 0x01, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x09, 0x03, 0x12, 0x04,
+    0xd7, 0x01, 0x13, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x0a, 0x12, 0x04, 0xd8, 0x01,
+    0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x0a, 0x05, 0x12, 0x04, 0xd8, 0x01, 0x02,
+    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x0a, 0x01, 0x12, 0x04, 0xd8, 0x01, 0x08, 0x10,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x0a, 0x03, 0x12, 0x04, 0xd8, 0x01, 0x13, 0x15, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x06, 0xdb, 0x01, 0x00, 0xdd, 0x01, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x11, 0x01, 0x12, 0x04, 0xdb, 0x01, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11,
+    0x02, 0x00, 0x12, 0x04, 0xdc, 0x01, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00,
+    0x04, 0x12, 0x04, 0xdc, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x06,
+    0x12, 0x04, 0xdc, 0x01, 0x0b, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xdc, 0x01, 0x1b, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xdc, 0x01, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x06, 0xdf, 0x01, 0x00, 0xf1,
+    0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x04, 0xdf, 0x01, 0x08, 0x17, 0x0a,
+    0x0e, 0x0a, 0x04, 0x04, 0x12, 0x04, 0x00, 0x12, 0x06, 0xe0, 0x01, 0x02, 0xe6, 0x01, 0x03, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x12, 0x04, 0x00, 0x01, 0x12, 0x04, 0xe0, 0x01, 0x07, 0x0b, 0x0a, 0x0e,
+    0x0a, 0x06, 0x04, 0x12, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xe1, 0x01, 0x04, 0x19, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x12, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe1, 0x01, 0x04, 0x14, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x12, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xe1, 0x01, 0x17, 0x18,
+    0x0a, 0x48, 0x0a, 0x06, 0x04, 0x12, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xe3, 0x01, 0x04, 0x1c,
+    0x1a, 0x38, 0x20, 0x54, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x77, 0x61, 0x73,
+    0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x72, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x74,
+    0x6f, 0x20, 0x61, 0x20, 0x66, 0x72, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x69, 0x7a,
+    0x65, 0x72, 0x20, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x12,
+    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe3, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x12, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xe3, 0x01, 0x1a, 0x1b, 0x0a, 0x4a, 0x0a, 0x06,
+    0x04, 0x12, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0xe5, 0x01, 0x04, 0x16, 0x1a, 0x3a, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x77, 0x61, 0x73, 0x20, 0x74, 0x72, 0x61,
+    0x6e, 0x73, 0x66, 0x65, 0x72, 0x72, 0x65, 0x64, 0x20, 0x6f, 0x75, 0x74, 0x20, 0x6f, 0x66, 0x20,
+    0x61, 0x20, 0x66, 0x72, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x72,
+    0x20, 0x76, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x12, 0x04, 0x00,
+    0x02, 0x02, 0x01, 0x12, 0x04, 0xe5, 0x01, 0x04, 0x11, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x12, 0x04,
+    0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xe5, 0x01, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12,
+    0x02, 0x00, 0x12, 0x04, 0xe8, 0x01, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00,
+    0x06, 0x12, 0x04, 0xe8, 0x01, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xe8, 0x01, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xe8, 0x01, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x01, 0x12, 0x04, 0xe9,
+    0x01, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x05, 0x12, 0x04, 0xe9, 0x01,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe9, 0x01, 0x08,
+    0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x03, 0x12, 0x04, 0xe9, 0x01, 0x10, 0x11,
+    0x0a, 0x6e, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x02, 0x12, 0x04, 0xeb, 0x01, 0x02, 0x18, 0x1a, 0x60,
+    0x20, 0x45, 0x52, 0x43, 0x32, 0x30, 0x20, 0x28, 0x6f, 0x72, 0x20, 0x45, 0x52, 0x43, 0x31, 0x31,
+    0x35, 0x35, 0x29, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x72, 0x65, 0x70,
+    0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x72,
+    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x61, 0x75,
+    0x6c, 0x74, 0x20, 0x69, 0x74, 0x73, 0x65, 0x6c, 0x66, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69,
+    0x74, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x02, 0x05, 0x12, 0x04, 0xeb, 0x01, 0x02, 0x07, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x02, 0x01, 0x12, 0x04, 0xeb, 0x01, 0x08, 0x13, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x12, 0x02, 0x02, 0x03, 0x12, 0x04, 0xeb, 0x01, 0x16, 0x17, 0x0a, 0x53, 0x0a,
+    0x04, 0x04, 0x12, 0x02, 0x03, 0x12, 0x04, 0xed, 0x01, 0x02, 0x13, 0x1a, 0x45, 0x20, 0x44, 0x65,
+    0x70, 0x6f, 0x73, 0x69, 0x74, 0x6f, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x60, 0x4b, 0x49, 0x4e,
+    0x44, 0x5f, 0x46, 0x52, 0x41, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x41, 0x4c, 0x49, 0x5a, 0x45, 0x44,
+    0x60, 0x2c, 0x20, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x60, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x52, 0x45, 0x44, 0x45, 0x45, 0x4d, 0x45, 0x44, 0x60,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x05, 0x12, 0x04, 0xed, 0x01, 0x02,
+    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x01, 0x12, 0x04, 0xed, 0x01, 0x08, 0x0e,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x03, 0x03, 0x12, 0x04, 0xed, 0x01, 0x11, 0x12, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x04, 0x12, 0x04, 0xee, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x04, 0x05, 0x12, 0x04, 0xee, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x12, 0x02, 0x04, 0x01, 0x12, 0x04, 0xee, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x12, 0x02, 0x04, 0x03, 0x12, 0x04, 0xee, 0x01, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12,
+    0x02, 0x05, 0x12, 0x04, 0xef, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x05,
+    0x05, 0x12, 0x04, 0xef, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x05, 0x01,
+    0x12, 0x04, 0xef, 0x01, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x05, 0x03, 0x12,
+    0x04, 0xef, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x06, 0x12, 0x04, 0xf0,
+    0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x06, 0x05, 0x12, 0x04, 0xf0, 0x01,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x06, 0x01, 0x12, 0x04, 0xf0, 0x01, 0x09,
+    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x06, 0x03, 0x12, 0x04, 0xf0, 0x01, 0x13, 0x14,
+    0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x13, 0x12, 0x06, 0xf3, 0x01, 0x00, 0xf5, 0x01, 0x01, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x13, 0x01, 0x12, 0x04, 0xf3, 0x01, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x13, 0x02, 0x00, 0x12, 0x04, 0xf4, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0xf4, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00,
+    0x06, 0x12, 0x04, 0xf4, 0x01, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xf4, 0x01, 0x10, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xf4, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x14, 0x12, 0x06, 0xf7, 0x01, 0x00,
+    0x8b, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14, 0x01, 0x12, 0x04, 0xf7, 0x01, 0x08, 0x0c,
+    0x0a, 0x42, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x00, 0x12, 0x04, 0xf9, 0x01, 0x02, 0x19, 0x1a, 0x34,
+    0x20, 0x4d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x73, 0x61, 0x6c, 0x65, 0x20, 0x68, 0x61, 0x70, 0x70, 0x65, 0x6e, 0x65, 0x64, 0x20, 0x6f,
+    0x6e, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20, 0x60, 0x73, 0x75, 0x64, 0x6f, 0x73, 0x77, 0x61,
+    0x70, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x05, 0x12, 0x04, 0xf9,
+    0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf9, 0x01,
+    0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf9, 0x01, 0x17,
+    0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12, 0x04, 0xfa, 0x01, 0x02, 0x17, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x05, 0x12, 0x04, 0xfa, 0x01, 0x02, 0x07, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x01, 0x12, 0x04, 0xfa, 0x01, 0x08, 0x12, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x14, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfa, 0x01, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x14, 0x02, 0x02, 0x12, 0x04, 0xfb, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0xfb, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0xfb, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0xfb, 0x01, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x03, 0x12,
+    0x04, 0xfc, 0x01, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x05, 0x12, 0x04,
+    0xfc, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x01, 0x12, 0x04, 0xfc,
+    0x01, 0x08, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x03, 0x12, 0x04, 0xfc, 0x01,
+    0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x04, 0x12, 0x04, 0xfd, 0x01, 0x02, 0x12,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x05, 0x12, 0x04, 0xfd, 0x01, 0x02, 0x07, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x01, 0x12, 0x04, 0xfd, 0x01, 0x08, 0x0d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x03, 0x12, 0x04, 0xfd, 0x01, 0x10, 0x11, 0x0a, 0x61, 0x0a,
+    0x04, 0x04, 0x14, 0x02, 0x05, 0x12, 0x04, 0xff, 0x01, 0x02, 0x13, 0x1a, 0x53, 0x20, 0x50, 0x72,
+    0x69, 0x63, 0x65, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d,
+    0x61, 0x6c, 0x6c, 0x65, 0x73, 0x74, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x60,
+    0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x63, 0x79, 0x60, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20,
+    0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x05, 0x05, 0x12, 0x04, 0xff, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x05, 0x01, 0x12, 0x04, 0xff, 0x01, 0x09, 0x0e, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x05, 0x03, 0x12, 0x04, 0xff, 0x01, 0x11, 0x12, 0x0a, 0x4a, 0x0a,
+    0x04, 0x04, 0x14, 0x02, 0x06, 0x12, 0x04, 0x81, 0x02, 0x02, 0x15, 0x1a, 0x3c, 0x20, 0x45, 0x52,
+    0x43, 0x32, 0x30, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x70, 0x72, 0x69, 0x63, 0x65, 0x20, 0x69, 0x73, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x69,
+    0x6e, 0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6e, 0x61, 0x74,
+    0x69, 0x76, 0x65, 0x20, 0x45, 0x54, 0x48, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
+    0x06, 0x05, 0x12, 0x04, 0x81, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x06,
+    0x01, 0x12, 0x04, 0x81, 0x02, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x06, 0x03,
+    0x12, 0x04, 0x81, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x07, 0x12, 0x04,
+    0x82, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x07, 0x05, 0x12, 0x04, 0x82,
+    0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x07, 0x01, 0x12, 0x04, 0x82, 0x02,
+    0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x07, 0x03, 0x12, 0x04, 0x82, 0x02, 0x13,
+    0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x08, 0x12, 0x04, 0x83, 0x02, 0x02, 0x15, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x08, 0x05, 0x12, 0x04, 0x83, 0x02, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x08, 0x01, 0x12, 0x04, 0x83, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x14, 0x02, 0x08, 0x03, 0x12, 0x04, 0x83, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x14, 0x02, 0x09, 0x12, 0x04, 0x84, 0x02, 0x02, 0x2b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14,
+    0x02, 0x09, 0x06, 0x12, 0x04, 0x84, 0x02, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
+    0x09, 0x01, 0x12, 0x04, 0x84, 0x02, 0x1c, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x09,
+    0x03, 0x12, 0x04, 0x84, 0x02, 0x28, 0x2a, 0x0a, 0x50, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x0a, 0x12,
+    0x04, 0x86, 0x02, 0x02, 0x18, 0x1a, 0x42, 0x20, 0x53, 0x65, 0x61, 0x70, 0x6f, 0x72, 0x74, 0x20,
+    0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x68, 0x61, 0x73, 0x68, 0x2c, 0x20, 0x74, 0x6f, 0x20, 0x6a,
+    0x6f, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x20, 0x77, 0x69, 0x74,
+    0x68, 0x20, 0x6f, 0x66, 0x66, 0x2d, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x6f, 0x72, 0x64, 0x65,
+    0x72, 0x20, 0x62, 0x6f, 0x6f, 0x6b, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
+    0x0a, 0x05, 0x12, 0x04, 0x86, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x0a,
+    0x01, 0x12, 0x04, 0x86, 0x02, 0x08, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x0a, 0x03,
+    0x12, 0x04, 0x86, 0x02, 0x15, 0x17, 0x0a, 0x79, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x0b, 0x12, 0x04,
+    0x88, 0x02, 0x02, 0x16, 0x1a, 0x6b, 0x20, 0x50, 0x61, 0x72, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x60,
+    0x70, 0x72, 0x69, 0x63, 0x65, 0x60, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x6f, 0x72, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x63, 0x79,
+    0x2e, 0x20, 0x45, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x6f, 0x6e, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65,
+    0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x64, 0x6f, 0x6e,
+    0x27, 0x74, 0x20, 0x73, 0x70, 0x6c, 0x69, 0x74, 0x20, 0x69, 0x74, 0x20, 0x6f, 0x75, 0x74, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x0b, 0x05, 0x12, 0x04, 0x88, 0x02, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x0b, 0x01, 0x12, 0x04, 0x88, 0x02, 0x09, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x0b, 0x03, 0x12, 0x04, 0x88, 0x02, 0x13, 0x15, 0x0a, 0x6b,
+    0x0a, 0x04, 0x04, 0x14, 0x02, 0x0c, 0x12, 0x04, 0x8a, 0x02, 0x02, 0x11, 0x1a, 0x5d, 0x20, 0x60,
+    0x3c, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x3e, 0x2d, 0x3c, 0x6f, 0x72, 0x64, 0x69,
+    0x6e, 0x61, 0x6c, 0x3e, 0x2d, 0x3c, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x3e, 0x60,
+    0x2c, 0x20, 0x75, 0x6e, 0x69, 0x71, 0x75, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x74, 0x61,
+    0x62, 0x6c, 0x65, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61,
+    0x79, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x69, 0x6e, 0x6b, 0x73, 0x20, 0x74, 0x6f, 0x20,
+    0x75, 0x70, 0x73, 0x65, 0x72, 0x74, 0x20, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x14, 0x02, 0x0c, 0x05, 0x12, 0x04, 0x8a, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14,
+    0x02, 0x0c, 0x01, 0x12, 0x04, 0x8a, 0x02, 0x09, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
+    0x0c, 0x03, 0x12, 0x04, 0x8a, 0x02, 0x0e, 0x10, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x15, 0x12, 0x06,
+    0x8d, 0x02, 0x00, 0x8f, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x15, 0x01, 0x12, 0x04, 0x8d,
+    0x02, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x00, 0x12, 0x04, 0x8e, 0x02, 0x02,
+    0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8e, 0x02, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x06, 0x12, 0x04, 0x8e, 0x02, 0x0b, 0x14, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8e, 0x02, 0x15, 0x1b, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8e, 0x02, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a,
+    0x02, 0x04, 0x16, 0x12, 0x06, 0x91, 0x02, 0x00, 0xa4, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x16, 0x01, 0x12, 0x04, 0x91, 0x02, 0x08, 0x11, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x16, 0x04, 0x00,
+    0x12, 0x06, 0x92, 0x02, 0x02, 0x9c, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x04, 0x00,
+    0x01, 0x12, 0x04, 0x92, 0x02, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x16, 0x04, 0x00, 0x02,
+    0x00, 0x12, 0x04, 0x93, 0x02, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x93, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00,
+    0x02, 0x00, 0x02, 0x12, 0x04, 0x93, 0x02, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x16, 0x04,
+    0x00, 0x02, 0x01, 0x12, 0x04, 0x94, 0x02, 0x04, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04,
+    0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x94, 0x02, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16,
+    0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x94, 0x02, 0x18, 0x19, 0x0a, 0x0e, 0x0a, 0x06, 0x04,
+    0x16, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x95, 0x02, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x16, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x95, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x16, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x95, 0x02, 0x17, 0x18, 0x0a, 0x0e, 0x0a,
+    0x06, 0x04, 0x16, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0x96, 0x02, 0x04, 0x1b, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x16, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0x96, 0x02, 0x04, 0x16, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0x96, 0x02, 0x19, 0x1a, 0x0a,
+    0x0e, 0x0a, 0x06, 0x04, 0x16, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0x97, 0x02, 0x04, 0x1c, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0x97, 0x02, 0x04, 0x17,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0x97, 0x02, 0x1a,
+    0x1b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x16, 0x04, 0x00, 0x02, 0x05, 0x12, 0x04, 0x98, 0x02, 0x04,
+    0x1e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0x98, 0x02,
+    0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0x98,
+    0x02, 0x1c, 0x1d, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x16, 0x04, 0x00, 0x02, 0x06, 0x12, 0x04, 0x99,
+    0x02, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x04,
+    0x99, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02, 0x06, 0x02, 0x12,
+    0x04, 0x99, 0x02, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x16, 0x04, 0x00, 0x02, 0x07, 0x12,
+    0x04, 0x9a, 0x02, 0x04, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02, 0x07, 0x01,
+    0x12, 0x04, 0x9a, 0x02, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02, 0x07,
+    0x02, 0x12, 0x04, 0x9a, 0x02, 0x18, 0x19, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x16, 0x04, 0x00, 0x02,
+    0x08, 0x12, 0x04, 0x9b, 0x02, 0x04, 0x1f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02,
+    0x08, 0x01, 0x12, 0x04, 0x9b, 0x02, 0x04, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00,
+    0x02, 0x08, 0x02, 0x12, 0x04, 0x9b, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02,
+    0x00, 0x12, 0x04, 0x9e, 0x02, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x06,
+    0x12, 0x04, 0x9e, 0x02, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0x9e, 0x02, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0x9e, 0x02, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x01, 0x12, 0x04, 0x9f, 0x02,
+    0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x05, 0x12, 0x04, 0x9f, 0x02, 0x02,
+    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9f, 0x02, 0x08, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9f, 0x02, 0x0f, 0x10, 0x0a,
+    0x71, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x02, 0x12, 0x04, 0xa1, 0x02, 0x02, 0x14, 0x1a, 0x63, 0x20,
+    0x41, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x63, 0x61, 0x72, 0x72, 0x69, 0x65, 0x64, 0x20, 0x62,
+    0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x28, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x73, 0x20, 0x64, 0x65, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x65, 0x64, 0x20, 0x6f, 0x72,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x64, 0x72, 0x61, 0x77, 0x6e, 0x2c, 0x20, 0x6e, 0x65, 0x77, 0x20,
+    0x73, 0x70, 0x6f, 0x74, 0x20, 0x70, 0x72, 0x69, 0x63, 0x65, 0x29, 0x2c, 0x20, 0x61, 0x73, 0x20,
+    0x61, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x05, 0x12, 0x04, 0xa1, 0x02, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa1, 0x02, 0x09, 0x0f,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa1, 0x02, 0x12, 0x13, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x03, 0x12, 0x04, 0xa2, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x16, 0x02, 0x03, 0x05, 0x12, 0x04, 0xa2, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x16, 0x02, 0x03, 0x01, 0x12, 0x04, 0xa2, 0x02, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x16, 0x02, 0x03, 0x03, 0x12, 0x04, 0xa2, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16,
+    0x02, 0x04, 0x12, 0x04, 0xa3, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x04,
+    0x05, 0x12, 0x04, 0xa3, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0xa3, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x04, 0x03, 0x12,
+    0x04, 0xa3, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x17, 0x12, 0x06, 0xa6, 0x02, 0x00,
+    0xa8, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x17, 0x01, 0x12, 0x04, 0xa6, 0x02, 0x08, 0x15,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x00, 0x12, 0x04, 0xa7, 0x02, 0x02, 0x23, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa7, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x17, 0x02, 0x00, 0x06, 0x12, 0x04, 0xa7, 0x02, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x17, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa7, 0x02, 0x18, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x17, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa7, 0x02, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x18,
+    0x12, 0x06, 0xaa, 0x02, 0x00, 0xb9, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x18, 0x01, 0x12,
+    0x04, 0xaa, 0x02, 0x08, 0x14, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x18, 0x04, 0x00, 0x12, 0x06, 0xab,
+    0x02, 0x02, 0xaf, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x04, 0x00, 0x01, 0x12, 0x04,
+    0xab, 0x02, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x18, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04,
+    0xac, 0x02, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x18, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xac, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x18, 0x04, 0x00, 0x02, 0x00, 0x02,
+    0x12, 0x04, 0xac, 0x02, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x18, 0x04, 0x00, 0x02, 0x01,
+    0x12, 0x04, 0xad, 0x02, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x18, 0x04, 0x00, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0xad, 0x02, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x18, 0x04, 0x00, 0x02,
+    0x01, 0x02, 0x12, 0x04, 0xad, 0x02, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x18, 0x04, 0x00,
+    0x02, 0x02, 0x12, 0x04, 0xae, 0x02, 0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x18, 0x04, 0x00,
+    0x02, 0x02, 0x01, 0x12, 0x04, 0xae, 0x02, 0x04, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x18, 0x04,
+    0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xae, 0x02, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18,
+    0x02, 0x00, 0x12, 0x04, 0xb1, 0x02, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00,
+    0x06, 0x12, 0x04, 0xb1, 0x02, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xb1, 0x02, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xb1, 0x02, 0x0e, 0x0f, 0x0a, 0x4c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x01, 0x12, 0x04, 0xb3,
+    0x02, 0x02, 0x16, 0x1a, 0x3e, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x64, 0x69, 0x61, 0x6c, 0x20,
+    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x28, 0x73, 0x74, 0x61, 0x6b, 0x69, 0x6e,
+    0x67, 0x2c, 0x20, 0x65, 0x73, 0x63, 0x72, 0x6f, 0x77, 0x2c, 0x20, 0x2e, 0x2e, 0x2e, 0x29, 0x20,
+    0x68, 0x6f, 0x6c, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb3, 0x02,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb3, 0x02, 0x08,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb3, 0x02, 0x14, 0x15,
+    0x0a, 0x42, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x02, 0x12, 0x04, 0xb5, 0x02, 0x02, 0x14, 0x1a, 0x34,
+    0x20, 0x44, 0x65, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x6f, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x64,
+    0x65, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x73, 0x2c, 0x20, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65,
+    0x6e, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x64, 0x72, 0x61, 0x77, 0x61,
+    0x6c, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x02, 0x05, 0x12, 0x04, 0xb5,
+    0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x02, 0x01, 0x12, 0x04, 0xb5, 0x02,
+    0x08, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x02, 0x03, 0x12, 0x04, 0xb5, 0x02, 0x12,
+    0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x03, 0x12, 0x04, 0xb6, 0x02, 0x02, 0x16, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03, 0x05, 0x12, 0x04, 0xb6, 0x02, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x18, 0x02, 0x03, 0x01, 0x12, 0x04, 0xb6, 0x02, 0x09, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x18, 0x02, 0x03, 0x03, 0x12, 0x04, 0xb6, 0x02, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x18, 0x02, 0x04, 0x12, 0x04, 0xb7, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18,
+    0x02, 0x04, 0x05, 0x12, 0x04, 0xb7, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02,
+    0x04, 0x01, 0x12, 0x04, 0xb7, 0x02, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x04,
+    0x03, 0x12, 0x04, 0xb7, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x05, 0x12,
+    0x04, 0xb8, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x05, 0x05, 0x12, 0x04,
+    0xb8, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x05, 0x01, 0x12, 0x04, 0xb8,
+    0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x05, 0x03, 0x12, 0x04, 0xb8, 0x02,
+    0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x19, 0x12, 0x06, 0xbb, 0x02, 0x00, 0xbd, 0x02, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x19, 0x01, 0x12, 0x04, 0xbb, 0x02, 0x08, 0x17, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x19, 0x02, 0x00, 0x12, 0x04, 0xbc, 0x02, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x19, 0x02, 0x00, 0x04, 0x12, 0x04, 0xbc, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19,
+    0x02, 0x00, 0x06, 0x12, 0x04, 0xbc, 0x02, 0x0b, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xbc, 0x02, 0x19, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xbc, 0x02, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1a, 0x12, 0x06, 0xbf,
+    0x02, 0x00, 0xc5, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1a, 0x01, 0x12, 0x04, 0xbf, 0x02,
+    0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x00, 0x12, 0x04, 0xc0, 0x02, 0x02, 0x16,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x05, 0x12, 0x04, 0xc0, 0x02, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc0, 0x02, 0x09, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc0, 0x02, 0x14, 0x15, 0x0a, 0x5d, 0x0a,
+    0x04, 0x04, 0x1a, 0x02, 0x01, 0x12, 0x04, 0xc2, 0x02, 0x02, 0x1b, 0x1a, 0x4f, 0x20, 0x4e, 0x75,
+    0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x73,
+    0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x20, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x69, 0x6e, 0x67, 0x20,
+    0x74, 0x68, 0x69, 0x73, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1a, 0x02, 0x01, 0x05, 0x12, 0x04, 0xc2, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc2, 0x02, 0x08, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xc2, 0x02, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02,
+    0x02, 0x12, 0x04, 0xc3, 0x02, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x05,
+    0x12, 0x04, 0xc3, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0xc3, 0x02, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0xc3, 0x02, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x03, 0x12, 0x04, 0xc4, 0x02,
+    0x02, 0x2f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03, 0x06, 0x12, 0x04, 0xc4, 0x02, 0x02,
+    0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc4, 0x02, 0x1c, 0x2a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03, 0x03, 0x12, 0x04, 0xc4, 0x02, 0x2d, 0x2e, 0x0a,
+    0x40, 0x0a, 0x02, 0x04, 0x1b, 0x12, 0x06, 0xc8, 0x02, 0x00, 0xcd, 0x02, 0x01, 0x1a, 0x32, 0x20,
+    0x4f, 0x77, 0x6e, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x2c, 0x20, 0x61, 0x73, 0x20, 0x6b, 0x65, 0x70, 0x74, 0x20, 0x62, 0x79, 0x20, 0x73, 0x74, 0x6f,
+    0x72, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x2e,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1b, 0x01, 0x12, 0x04, 0xc8, 0x02, 0x08, 0x12, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1b, 0x02, 0x00, 0x12, 0x04, 0xc9, 0x02, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1b, 0x02, 0x00, 0x05, 0x12, 0x04, 0xc9, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1b, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc9, 0x02, 0x08, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xc9, 0x02, 0x10, 0x11, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x1b, 0x02,
+    0x01, 0x12, 0x04, 0xcb, 0x02, 0x02, 0x19, 0x1a, 0x31, 0x20, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x77, 0x6e,
+    0x65, 0x72, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x61, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0xcb, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xcb, 0x02, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xcb, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x02, 0x12,
+    0x04, 0xcc, 0x02, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x06, 0x12, 0x04,
+    0xcc, 0x02, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x01, 0x12, 0x04, 0xcc,
+    0x02, 0x1c, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x03, 0x12, 0x04, 0xcc, 0x02,
+    0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1c, 0x12, 0x06, 0xcf, 0x02, 0x00, 0xd1, 0x02, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1c, 0x01, 0x12, 0x04, 0xcf, 0x02, 0x08, 0x19, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x1c, 0x02, 0x00, 0x12, 0x04, 0xd0, 0x02, 0x02, 0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1c, 0x02, 0x00, 0x04, 0x12, 0x04, 0xd0, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c,
+    0x02, 0x00, 0x06, 0x12, 0x04, 0xd0, 0x02, 0x0b, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xd0, 0x02, 0x1c, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xd0, 0x02, 0x28, 0x29, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1d, 0x12, 0x06, 0xd3,
+    0x02, 0x00, 0xdd, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1d, 0x01, 0x12, 0x04, 0xd3, 0x02,
+    0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x00, 0x12, 0x04, 0xd4, 0x02, 0x02, 0x18,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x06, 0x12, 0x04, 0xd4, 0x02, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd4, 0x02, 0x0b, 0x13, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd4, 0x02, 0x16, 0x17, 0x0a, 0x9f, 0x01,
+    0x0a, 0x04, 0x04, 0x1d, 0x02, 0x01, 0x12, 0x04, 0xd7, 0x02, 0x02, 0x1b, 0x1a, 0x90, 0x01, 0x20,
+    0x4f, 0x77, 0x6e, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x67,
+    0x20, 0x74, 0x6f, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f,
+    0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x0a, 0x20, 0x74, 0x72, 0x61, 0x6e,
+    0x73, 0x66, 0x65, 0x72, 0x72, 0x65, 0x64, 0x20, 0x73, 0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01, 0x05, 0x12, 0x04, 0xd7, 0x02, 0x02, 0x07, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd7, 0x02, 0x08, 0x16, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1d, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd7, 0x02, 0x19, 0x1a, 0x0a, 0x63, 0x0a, 0x04,
+    0x04, 0x1d, 0x02, 0x02, 0x12, 0x04, 0xd9, 0x02, 0x02, 0x1c, 0x1a, 0x55, 0x20, 0x48, 0x6f, 0x77,
+    0x20, 0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f,
+    0x75, 0x73, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x20, 0x68, 0x65, 0x6c, 0x64, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x30, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x20, 0x6f, 0x77, 0x6e,
+    0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x02, 0x05, 0x12, 0x04, 0xd9, 0x02, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x02, 0x01, 0x12, 0x04, 0xd9, 0x02, 0x09, 0x17, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x02, 0x03, 0x12, 0x04, 0xd9, 0x02, 0x1a, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1d, 0x02, 0x03, 0x12, 0x04, 0xda, 0x02, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1d, 0x02, 0x03, 0x05, 0x12, 0x04, 0xda, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1d, 0x02, 0x03, 0x01, 0x12, 0x04, 0xda, 0x02, 0x08, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d,
+    0x02, 0x03, 0x03, 0x12, 0x04, 0xda, 0x02, 0x1a, 0x1b, 0x0a, 0x59, 0x0a, 0x04, 0x04, 0x1d, 0x02,
+    0x04, 0x12, 0x04, 0xdc, 0x02, 0x02, 0x1d, 0x1a, 0x4b, 0x20, 0x4e, 0x61, 0x6d, 0x65, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x69, 0x6e, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73,
+    0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20,
+    0x77, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x64, 0x69, 0x73, 0x63, 0x6f, 0x76, 0x65, 0x72,
+    0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x04, 0x05, 0x12, 0x04, 0xdc,
+    0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x04, 0x01, 0x12, 0x04, 0xdc, 0x02,
+    0x09, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x04, 0x03, 0x12, 0x04, 0xdc, 0x02, 0x1b,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1e, 0x12, 0x06, 0xdf, 0x02, 0x00, 0xe5, 0x02, 0x01, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x1e, 0x01, 0x12, 0x04, 0xdf, 0x02, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x1e, 0x02, 0x00, 0x12, 0x04, 0xe0, 0x02, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e,
+    0x02, 0x00, 0x04, 0x12, 0x04, 0xe0, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02,
+    0x00, 0x06, 0x12, 0x04, 0xe0, 0x02, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xe0, 0x02, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xe0, 0x02, 0x20, 0x21, 0x0a, 0x4f, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x01, 0x12, 0x04,
+    0xe2, 0x02, 0x02, 0x24, 0x1a, 0x41, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73,
+    0x20, 0x64, 0x72, 0x6f, 0x70, 0x70, 0x65, 0x64, 0x20, 0x62, 0x65, 0x63, 0x61, 0x75, 0x73, 0x65,
+    0x20, 0x60, 0x66, 0x72, 0x6f, 0x6d, 0x60, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x60, 0x74, 0x6f, 0x60,
+    0x20, 0x61, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xe2, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xe2, 0x02, 0x09, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xe2, 0x02, 0x22, 0x23, 0x0a, 0x70, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x02, 0x12, 0x04, 0xe4, 0x02,
+    0x02, 0x21, 0x1a, 0x62, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x20, 0x64,
+    0x72, 0x6f, 0x70, 0x70, 0x65, 0x64, 0x20, 0x62, 0x65, 0x63, 0x61, 0x75, 0x73, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x63, 0x61, 0x6d, 0x65, 0x20, 0x62, 0x61,
+    0x63, 0x6b, 0x20, 0x74, 0x6f, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e,
+    0x61, 0x6c, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x05, 0x12,
+    0x04, 0xe4, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x01, 0x12, 0x04,
+    0xe4, 0x02, 0x09, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x03, 0x12, 0x04, 0xe4,
+    0x02, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1f, 0x12, 0x06, 0xe7, 0x02, 0x00, 0xe9, 0x02,
+    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1f, 0x01, 0x12, 0x04, 0xe7, 0x02, 0x08, 0x13, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1f, 0x02, 0x00, 0x12, 0x04, 0xe8, 0x02, 0x02, 0x2c, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1f, 0x02, 0x00, 0x04, 0x12, 0x04, 0xe8, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1f, 0x02, 0x00, 0x06, 0x12, 0x04, 0xe8, 0x02, 0x0b, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xe8, 0x02, 0x1a, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xe8, 0x02, 0x2a, 0x2b, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x20, 0x12, 0x06,
+    0xeb, 0x02, 0x00, 0xfa, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x20, 0x01, 0x12, 0x04, 0xeb,
+    0x02, 0x08, 0x16, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x20, 0x04, 0x00, 0x12, 0x06, 0xec, 0x02, 0x02,
+    0xf4, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x04, 0x00, 0x01, 0x12, 0x04, 0xec, 0x02,
+    0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x20, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xed, 0x02,
+    0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x20, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xed,
+    0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x20, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04,
+    0xed, 0x02, 0x17, 0x18, 0x0a, 0x51, 0x0a, 0x06, 0x04, 0x20, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04,
+    0xef, 0x02, 0x04, 0x1b, 0x1a, 0x41, 0x20, 0x54, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69,
+    0x70, 0x74, 0x20, 0x6c, 0x6f, 0x67, 0x20, 0x77, 0x61, 0x73, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74,
+    0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x77, 0x68, 0x6f,
+    0x73, 0x65, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x20, 0x67, 0x6f, 0x74, 0x20, 0x72, 0x65, 0x76,
+    0x65, 0x72, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x20, 0x04, 0x00, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xef, 0x02, 0x04, 0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x20, 0x04, 0x00,
+    0x02, 0x01, 0x02, 0x12, 0x04, 0xef, 0x02, 0x19, 0x1a, 0x0a, 0x54, 0x0a, 0x06, 0x04, 0x20, 0x04,
+    0x00, 0x02, 0x02, 0x12, 0x04, 0xf1, 0x02, 0x04, 0x1a, 0x1a, 0x44, 0x20, 0x54, 0x68, 0x65, 0x20,
+    0x72, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74, 0x20, 0x6c, 0x6f, 0x67, 0x20, 0x63, 0x6f, 0x75, 0x6c,
+    0x64, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x20, 0x66, 0x6f, 0x75, 0x6e, 0x64, 0x20, 0x69,
+    0x6e, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x20, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf1, 0x02, 0x04, 0x15,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x20, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xf1, 0x02, 0x18,
+    0x19, 0x0a, 0x4c, 0x0a, 0x06, 0x04, 0x20, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0xf3, 0x02, 0x04,
+    0x20, 0x1a, 0x3c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x62, 0x75, 0x74, 0x20, 0x69,
+    0x74, 0x73, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74, 0x20, 0x73, 0x74, 0x69, 0x6c, 0x6c,
+    0x20, 0x63, 0x61, 0x72, 0x72, 0x69, 0x65, 0x73, 0x20, 0x6c, 0x6f, 0x67, 0x73, 0x2e, 0x0a, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x20, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0xf3, 0x02, 0x04, 0x1b,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x20, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xf3, 0x02, 0x1e,
+    0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x00, 0x12, 0x04, 0xf6, 0x02, 0x02, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x06, 0x12, 0x04, 0xf6, 0x02, 0x02, 0x06, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf6, 0x02, 0x07, 0x0b, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x20, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf6, 0x02, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x20, 0x02, 0x01, 0x12, 0x04, 0xf7, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0xf7, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xf7, 0x02, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xf7, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x02, 0x12,
+    0x04, 0xf8, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x02, 0x05, 0x12, 0x04,
+    0xf8, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf8,
+    0x02, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x02, 0x03, 0x12, 0x04, 0xf8, 0x02,
+    0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x03, 0x12, 0x04, 0xf9, 0x02, 0x02, 0x17,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x03, 0x05, 0x12, 0x04, 0xf9, 0x02, 0x02, 0x07, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x03, 0x01, 0x12, 0x04, 0xf9, 0x02, 0x08, 0x12, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x20, 0x02, 0x03, 0x03, 0x12, 0x04, 0xf9, 0x02, 0x15, 0x16, 0x0a, 0x0c, 0x0a,
+    0x02, 0x04, 0x21, 0x12, 0x06, 0xfc, 0x02, 0x00, 0xfe, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x21, 0x01, 0x12, 0x04, 0xfc, 0x02, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x00,
+    0x12, 0x04, 0xfd, 0x02, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x04, 0x12,
+    0x04, 0xfd, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x06, 0x12, 0x04,
+    0xfd, 0x02, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x01, 0x12, 0x04, 0xfd,
+    0x02, 0x18, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x03, 0x12, 0x04, 0xfd, 0x02,
+    0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x22, 0x12, 0x06, 0x80, 0x03, 0x00, 0x8e, 0x03, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x22, 0x01, 0x12, 0x04, 0x80, 0x03, 0x08, 0x14, 0x0a, 0x8c, 0x02,
+    0x0a, 0x04, 0x04, 0x22, 0x02, 0x00, 0x12, 0x04, 0x84, 0x03, 0x02, 0x14, 0x1a, 0xfd, 0x01, 0x20,
+    0x53, 0x6f, 0x6c, 0x69, 0x64, 0x69, 0x74, 0x79, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x64, 0x20, 0x66, 0x75, 0x6e, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20, 0x60, 0x73, 0x61, 0x66, 0x65,
+    0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x46, 0x72, 0x6f, 0x6d, 0x60, 0x2e, 0x20, 0x46,
+    0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74,
+    0x20, 0x61, 0x20, 0x64, 0x65, 0x63, 0x6f, 0x64, 0x65, 0x72, 0x20, 0x61, 0x72, 0x65, 0x0a, 0x20,
+    0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x73, 0x69, 0x67, 0x6e,
+    0x61, 0x74, 0x75, 0x72, 0x65, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65,
+    0x6d, 0x62, 0x65, 0x64, 0x64, 0x65, 0x64, 0x20, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x6f, 0x72,
+    0x20, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20, 0x60, 0x77, 0x69,
+    0x74, 0x68, 0x64, 0x72, 0x61, 0x77, 0x28, 0x29, 0x60, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x62, 0x79,
+    0x20, 0x60, 0x30, 0x78, 0x60, 0x20, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x0a, 0x20,
+    0x77, 0x68, 0x65, 0x6e, 0x20, 0x75, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x2c, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x72, 0x61, 0x77, 0x20, 0x63, 0x61, 0x6c,
+    0x6c, 0x64, 0x61, 0x74, 0x61, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x69, 0x6e,
+    0x70, 0x75, 0x74, 0x60, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x22, 0x02, 0x00, 0x05, 0x12, 0x04, 0x84, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x22, 0x02, 0x00, 0x01, 0x12, 0x04, 0x84, 0x03, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0x84, 0x03, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02,
+    0x01, 0x12, 0x04, 0x85, 0x03, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x04,
+    0x12, 0x04, 0x85, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x06, 0x12,
+    0x04, 0x85, 0x03, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0x85, 0x03, 0x15, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x03, 0x12, 0x04, 0x85,
+    0x03, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x02, 0x12, 0x04, 0x86, 0x03, 0x02,
+    0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x02, 0x04, 0x12, 0x04, 0x86, 0x03, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x02, 0x06, 0x12, 0x04, 0x86, 0x03, 0x0b, 0x14, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x02, 0x01, 0x12, 0x04, 0x86, 0x03, 0x15, 0x1c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x22, 0x02, 0x02, 0x03, 0x12, 0x04, 0x86, 0x03, 0x1f, 0x20, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x22, 0x02, 0x03, 0x12, 0x04, 0x87, 0x03, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x22, 0x02, 0x03, 0x05, 0x12, 0x04, 0x87, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22,
+    0x02, 0x03, 0x01, 0x12, 0x04, 0x87, 0x03, 0x08, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02,
+    0x03, 0x03, 0x12, 0x04, 0x87, 0x03, 0x11, 0x12, 0x0a, 0x47, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x04,
+    0x12, 0x04, 0x89, 0x03, 0x02, 0x13, 0x1a, 0x39, 0x20, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x73,
+    0x65, 0x6e, 0x74, 0x20, 0x61, 0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61,
+    0x6c, 0x6c, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x65, 0x69, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x61,
+    0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x04, 0x05, 0x12, 0x04, 0x89, 0x03, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x04, 0x01, 0x12, 0x04, 0x89, 0x03, 0x09, 0x0e, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x04, 0x03, 0x12, 0x04, 0x89, 0x03, 0x11, 0x12, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x22, 0x02, 0x05, 0x12, 0x04, 0x8a, 0x03, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x22, 0x02, 0x05, 0x05, 0x12, 0x04, 0x8a, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x22, 0x02, 0x05, 0x01, 0x12, 0x04, 0x8a, 0x03, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22,
+    0x02, 0x05, 0x03, 0x12, 0x04, 0x8a, 0x03, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02,
+    0x06, 0x12, 0x04, 0x8b, 0x03, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x06, 0x05,
+    0x12, 0x04, 0x8b, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x06, 0x01, 0x12,
+    0x04, 0x8b, 0x03, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x06, 0x03, 0x12, 0x04,
+    0x8b, 0x03, 0x13, 0x14, 0x0a, 0x60, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x07, 0x12, 0x04, 0x8d, 0x03,
+    0x02, 0x10, 0x1a, 0x52, 0x20, 0x60, 0x3c, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x3e,
+    0x2d, 0x3c, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x3e, 0x60, 0x2c, 0x20, 0x75, 0x6e, 0x69,
+    0x71, 0x75, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x61,
+    0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x79, 0x73, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x73, 0x69, 0x6e, 0x6b, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x75, 0x70, 0x73, 0x65, 0x72,
+    0x74, 0x20, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x07, 0x05, 0x12,
+    0x04, 0x8d, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x07, 0x01, 0x12, 0x04,
+    0x8d, 0x03, 0x09, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x07, 0x03, 0x12, 0x04, 0x8d,
+    0x03, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x23, 0x12, 0x06, 0x90, 0x03, 0x00, 0x94, 0x03,
+    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x23, 0x01, 0x12, 0x04, 0x90, 0x03, 0x08, 0x11, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x23, 0x02, 0x00, 0x12, 0x04, 0x91, 0x03, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x23, 0x02, 0x00, 0x05, 0x12, 0x04, 0x91, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x23, 0x02, 0x00, 0x01, 0x12, 0x04, 0x91, 0x03, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0x91, 0x03, 0x10, 0x11, 0x0a, 0x56, 0x0a, 0x04, 0x04, 0x23, 0x02,
+    0x01, 0x12, 0x04, 0x93, 0x03, 0x02, 0x13, 0x1a, 0x48, 0x20, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x62, 0x79, 0x74, 0x65, 0x73, 0x20, 0x61, 0x72,
+    0x65, 0x20, 0x30, 0x78, 0x20, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78, 0x65, 0x64, 0x20, 0x68, 0x65,
+    0x78, 0x2c, 0x20, 0x69, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20,
+    0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x01, 0x05, 0x12, 0x04, 0x93, 0x03, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x01, 0x01, 0x12, 0x04, 0x93, 0x03, 0x09, 0x0e, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x01, 0x03, 0x12, 0x04, 0x93, 0x03, 0x11, 0x12, 0x0a, 0x0c,
+    0x0a, 0x02, 0x04, 0x24, 0x12, 0x06, 0x96, 0x03, 0x00, 0x9d, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x24, 0x01, 0x12, 0x04, 0x96, 0x03, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02,
+    0x00, 0x12, 0x04, 0x97, 0x03, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0x97, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0x97, 0x03, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0x97, 0x03, 0x11, 0x12, 0x0a, 0x6b, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x01, 0x12, 0x04, 0x99, 0x03,
+    0x02, 0x24, 0x1a, 0x5d, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x20, 0x6f,
+    0x66, 0x20, 0x60, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x60, 0x2c, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x69,
+    0x73, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x75, 0x6e, 0x74, 0x69, 0x6c, 0x20, 0x60, 0x63, 0x6f,
+    0x6e, 0x66, 0x69, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x60, 0x20, 0x6d, 0x6f, 0x72,
+    0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x62, 0x75, 0x69,
+    0x6c, 0x74, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x6f, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x74, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x04, 0x12, 0x04, 0x99, 0x03, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x06, 0x12, 0x04, 0x99, 0x03, 0x0b, 0x13, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x01, 0x12, 0x04, 0x99, 0x03, 0x14, 0x1f, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x03, 0x12, 0x04, 0x99, 0x03, 0x22, 0x23, 0x0a, 0x75, 0x0a,
+    0x04, 0x04, 0x24, 0x02, 0x02, 0x12, 0x04, 0x9b, 0x03, 0x02, 0x1d, 0x1a, 0x67, 0x20, 0x42, 0x6c,
+    0x6f, 0x63, 0x6b, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6e, 0x6f, 0x77, 0x20, 0x63, 0x6f, 0x6e, 0x66,
+    0x69, 0x72, 0x6d, 0x65, 0x64, 0x2c, 0x20, 0x7a, 0x65, 0x72, 0x6f, 0x20, 0x77, 0x68, 0x69, 0x6c,
+    0x65, 0x20, 0x66, 0x65, 0x77, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x60, 0x63, 0x6f,
+    0x6e, 0x66, 0x69, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x60, 0x20, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x73, 0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73,
+    0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x02, 0x05, 0x12, 0x04, 0x9b,
+    0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9b, 0x03,
+    0x09, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x02, 0x03, 0x12, 0x04, 0x9b, 0x03, 0x1b,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x03, 0x12, 0x04, 0x9c, 0x03, 0x02, 0x22, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x03, 0x04, 0x12, 0x04, 0x9c, 0x03, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x24, 0x02, 0x03, 0x06, 0x12, 0x04, 0x9c, 0x03, 0x0b, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x24, 0x02, 0x03, 0x01, 0x12, 0x04, 0x9c, 0x03, 0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x24, 0x02, 0x03, 0x03, 0x12, 0x04, 0x9c, 0x03, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x25, 0x12, 0x06, 0x9f, 0x03, 0x00, 0xa7, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x25, 0x01,
+    0x12, 0x04, 0x9f, 0x03, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x25, 0x02, 0x00, 0x12, 0x04,
+    0xa0, 0x03, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa0,
+    0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa0, 0x03,
+    0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa0, 0x03, 0x11,
+    0x12, 0x0a, 0x51, 0x0a, 0x04, 0x04, 0x25, 0x02, 0x01, 0x12, 0x04, 0xa2, 0x03, 0x02, 0x19, 0x1a,
+    0x43, 0x20, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x65, 0x73, 0x20, 0x61, 0x63, 0x71, 0x75,
+    0x69, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x74, 0x69,
+    0x6d, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x05, 0x12, 0x04, 0xa2,
+    0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa2, 0x03,
+    0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa2, 0x03, 0x17,
+    0x18, 0x0a, 0x5a, 0x0a, 0x04, 0x04, 0x25, 0x02, 0x02, 0x12, 0x04, 0xa4, 0x03, 0x02, 0x1e, 0x1a,
+    0x4c, 0x20, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x65, 0x73, 0x20, 0x61, 0x63, 0x71, 0x75,
+    0x69, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x61, 0x66,
+    0x74, 0x65, 0x72, 0x20, 0x61, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x61, 0x63, 0x71, 0x75,
+    0x69, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x6e, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x61,
+    0x72, 0x6c, 0x69, 0x65, 0x72, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x25, 0x02, 0x02, 0x05, 0x12, 0x04, 0xa4, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x25, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa4, 0x03, 0x09, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x25, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa4, 0x03, 0x1c, 0x1d, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x25,
+    0x02, 0x03, 0x12, 0x04, 0xa6, 0x03, 0x02, 0x1d, 0x1a, 0x2a, 0x20, 0x41, 0x64, 0x64, 0x72, 0x65,
+    0x73, 0x73, 0x65, 0x73, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e,
+    0x63, 0x65, 0x20, 0x64, 0x72, 0x6f, 0x70, 0x70, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x7a, 0x65,
+    0x72, 0x6f, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x03, 0x05, 0x12, 0x04, 0xa6,
+    0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x03, 0x01, 0x12, 0x04, 0xa6, 0x03,
+    0x09, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x03, 0x03, 0x12, 0x04, 0xa6, 0x03, 0x1b,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x26, 0x12, 0x06, 0xa9, 0x03, 0x00, 0xab, 0x03, 0x01, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x26, 0x01, 0x12, 0x04, 0xa9, 0x03, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x26, 0x02, 0x00, 0x12, 0x04, 0xaa, 0x03, 0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26,
+    0x02, 0x00, 0x04, 0x12, 0x04, 0xaa, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02,
+    0x00, 0x06, 0x12, 0x04, 0xaa, 0x03, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xaa, 0x03, 0x16, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xaa, 0x03, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x27, 0x12, 0x06, 0xad, 0x03,
+    0x00, 0xb7, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x27, 0x01, 0x12, 0x04, 0xad, 0x03, 0x08,
+    0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x27, 0x02, 0x00, 0x12, 0x04, 0xae, 0x03, 0x02, 0x13, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x05, 0x12, 0x04, 0xae, 0x03, 0x02, 0x07, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x01, 0x12, 0x04, 0xae, 0x03, 0x08, 0x0e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x27, 0x02, 0x00, 0x03, 0x12, 0x04, 0xae, 0x03, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x27, 0x02, 0x01, 0x12, 0x04, 0xaf, 0x03, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0xaf, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xaf, 0x03, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xaf, 0x03, 0x13, 0x14, 0x0a, 0x60, 0x0a, 0x04, 0x04, 0x27, 0x02, 0x02, 0x12,
+    0x04, 0xb1, 0x03, 0x02, 0x15, 0x1a, 0x52, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x68, 0x65, 0x6c, 0x64, 0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61,
+    0x72, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c,
+    0x20, 0x75, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30,
+    0x20, 0x61, 0x6c, 0x65, 0x72, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0xb1, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xb1, 0x03, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0xb1, 0x03, 0x13, 0x14, 0x0a, 0x75, 0x0a, 0x04, 0x04, 0x27, 0x02, 0x03, 0x12, 0x04,
+    0xb3, 0x03, 0x02, 0x14, 0x1a, 0x67, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x73, 0x65,
+    0x6e, 0x74, 0x20, 0x64, 0x75, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68, 0x6f, 0x6c, 0x65, 0x20, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x73, 0x20, 0x73, 0x63, 0x61, 0x6c, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x60, 0x65, 0x72, 0x63, 0x32, 0x30, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x73,
+    0x60, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x45,
+    0x52, 0x43, 0x32, 0x30, 0x20, 0x61, 0x6c, 0x65, 0x72, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x27, 0x02, 0x03, 0x05, 0x12, 0x04, 0xb3, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x27, 0x02, 0x03, 0x01, 0x12, 0x04, 0xb3, 0x03, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x27, 0x02, 0x03, 0x03, 0x12, 0x04, 0xb3, 0x03, 0x12, 0x13, 0x0a, 0x44, 0x0a, 0x04, 0x04, 0x27,
+    0x02, 0x04, 0x12, 0x04, 0xb5, 0x03, 0x02, 0x17, 0x1a, 0x36, 0x20, 0x56, 0x61, 0x6c, 0x75, 0x65,
+    0x20, 0x6f, 0x66, 0x20, 0x60, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x60, 0x20, 0x69, 0x6e, 0x20,
+    0x55, 0x53, 0x44, 0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30, 0x20, 0x61, 0x6c, 0x65, 0x72, 0x74, 0x73, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x04, 0x05, 0x12, 0x04, 0xb5, 0x03, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x04, 0x01, 0x12, 0x04, 0xb5, 0x03, 0x09, 0x12, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x27, 0x02, 0x04, 0x03, 0x12, 0x04, 0xb5, 0x03, 0x15, 0x16, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x27, 0x02, 0x05, 0x12, 0x04, 0xb6, 0x03, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x27, 0x02, 0x05, 0x05, 0x12, 0x04, 0xb6, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27,
+    0x02, 0x05, 0x01, 0x12, 0x04, 0xb6, 0x03, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02,
+    0x05, 0x03, 0x12, 0x04, 0xb6, 0x03, 0x11, 0x12, 0x0a, 0x84, 0x01, 0x0a, 0x02, 0x04, 0x28, 0x12,
+    0x06, 0xba, 0x03, 0x00, 0xc2, 0x03, 0x01, 0x1a, 0x76, 0x20, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x63, 0x6b,
+    0x65, 0x64, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x77, 0x61,
+    0x6b, 0x69, 0x6e, 0x67, 0x20, 0x75, 0x70, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x61, 0x20,
+    0x64, 0x6f, 0x72, 0x6d, 0x61, 0x6e, 0x63, 0x79, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x65, 0x76,
+    0x65, 0x72, 0x79, 0x20, 0x60, 0x65, 0x76, 0x65, 0x72, 0x79, 0x60, 0x20, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x77, 0x65, 0x6e, 0x74, 0x20, 0x64, 0x6f, 0x72, 0x6d, 0x61, 0x6e, 0x74, 0x2e, 0x0a, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x28, 0x01, 0x12, 0x04, 0xba, 0x03, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x28, 0x02, 0x00, 0x12, 0x04, 0xbb, 0x03, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0xbb, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xbb, 0x03, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xbb, 0x03, 0x11, 0x12, 0x0a, 0x5e, 0x0a, 0x04, 0x04, 0x28, 0x02, 0x01, 0x12,
+    0x04, 0xbd, 0x03, 0x02, 0x14, 0x1a, 0x50, 0x20, 0x57, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x77, 0x61, 0x73, 0x20, 0x70, 0x69,
+    0x63, 0x6b, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x65, 0x76, 0x65,
+    0x72, 0x79, 0x60, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x2c, 0x20, 0x60, 0x64, 0x6f, 0x72, 0x6d,
+    0x61, 0x6e, 0x74, 0x60, 0x20, 0x69, 0x73, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74,
+    0x20, 0x74, 0x68, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xbd, 0x03, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xbd, 0x03, 0x07, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xbd, 0x03, 0x12, 0x13, 0x0a, 0x74, 0x0a, 0x04, 0x04, 0x28, 0x02, 0x02, 0x12, 0x04, 0xbf, 0x03,
+    0x02, 0x26, 0x1a, 0x66, 0x20, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x65, 0x73, 0x20, 0x6c,
+    0x61, 0x73, 0x74, 0x20, 0x61, 0x63, 0x74, 0x69, 0x76, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x72,
+    0x6f, 0x73, 0x73, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x64, 0x6f, 0x72, 0x6d, 0x61,
+    0x6e, 0x63, 0x79, 0x60, 0x20, 0x74, 0x68, 0x72, 0x65, 0x73, 0x68, 0x6f, 0x6c, 0x64, 0x20, 0x73,
+    0x69, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75,
+    0x73, 0x20, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28,
+    0x02, 0x02, 0x04, 0x12, 0x04, 0xbf, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02,
+    0x02, 0x06, 0x12, 0x04, 0xbf, 0x03, 0x0b, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xbf, 0x03, 0x1a, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0xbf, 0x03, 0x24, 0x25, 0x0a, 0x46, 0x0a, 0x04, 0x04, 0x28, 0x02, 0x03, 0x12, 0x04,
+    0xc1, 0x03, 0x02, 0x27, 0x1a, 0x38, 0x20, 0x44, 0x6f, 0x72, 0x6d, 0x61, 0x6e, 0x74, 0x20, 0x61,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x65, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x73, 0x65,
+    0x6e, 0x74, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x20,
+    0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x28, 0x02, 0x03, 0x04, 0x12, 0x04, 0xc1, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x28, 0x02, 0x03, 0x06, 0x12, 0x04, 0xc1, 0x03, 0x0b, 0x19, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x28, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc1, 0x03, 0x1a, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x28, 0x02, 0x03, 0x03, 0x12, 0x04, 0xc1, 0x03, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x29,
+    0x12, 0x06, 0xc4, 0x03, 0x00, 0xcb, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x29, 0x01, 0x12,
+    0x04, 0xc4, 0x03, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x00, 0x12, 0x04, 0xc5,
+    0x03, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x00, 0x05, 0x12, 0x04, 0xc5, 0x03,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc5, 0x03, 0x08,
+    0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc5, 0x03, 0x12, 0x13,
+    0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x01, 0x12, 0x04, 0xc7, 0x03, 0x02, 0x1f, 0x1a, 0x51,
+    0x20, 0x4c, 0x61, 0x73, 0x74, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x73, 0x20, 0x61, 0x74, 0x2c, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x77, 0x61,
+    0x6b, 0x65, 0x6e, 0x65, 0x64, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x65, 0x73, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x05, 0x12, 0x04, 0xc7, 0x03, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc7, 0x03, 0x09, 0x1a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x03, 0x12, 0x04, 0xc7, 0x03, 0x1d, 0x1e, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x29, 0x02, 0x02, 0x12, 0x04, 0xc8, 0x03, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x29, 0x02, 0x02, 0x05, 0x12, 0x04, 0xc8, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x29, 0x02, 0x02, 0x01, 0x12, 0x04, 0xc8, 0x03, 0x09, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0xc8, 0x03, 0x1b, 0x1c, 0x0a, 0x68, 0x0a, 0x04, 0x04, 0x29, 0x02,
+    0x03, 0x12, 0x04, 0xca, 0x03, 0x02, 0x14, 0x1a, 0x5a, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x63, 0x6b, 0x65, 0x64, 0x20,
+    0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x68, 0x65, 0x6c, 0x64, 0x2c,
+    0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61,
+    0x77, 0x61, 0x6b, 0x65, 0x6e, 0x65, 0x64, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x65,
+    0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x03, 0x05, 0x12, 0x04, 0xca, 0x03,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x03, 0x01, 0x12, 0x04, 0xca, 0x03, 0x08,
+    0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x03, 0x03, 0x12, 0x04, 0xca, 0x03, 0x12, 0x13,
+    0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x2a, 0x12, 0x06, 0xcd, 0x03, 0x00, 0xd0, 0x03, 0x01, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x2a, 0x01, 0x12, 0x04, 0xcd, 0x03, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x2a, 0x02, 0x00, 0x12, 0x04, 0xce, 0x03, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0xce, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00,
+    0x06, 0x12, 0x04, 0xce, 0x03, 0x0b, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xce, 0x03, 0x1a, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xce, 0x03, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a, 0x02, 0x01, 0x12, 0x04, 0xcf,
+    0x03, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x04, 0x12, 0x04, 0xcf, 0x03,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x06, 0x12, 0x04, 0xcf, 0x03, 0x0b,
+    0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xcf, 0x03, 0x16, 0x1b,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xcf, 0x03, 0x1e, 0x1f, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x2b, 0x12, 0x06, 0xd2, 0x03, 0x00, 0xd6, 0x03, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x2b, 0x01, 0x12, 0x04, 0xd2, 0x03, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2b,
+    0x02, 0x00, 0x12, 0x04, 0xd3, 0x03, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x00,
+    0x06, 0x12, 0x04, 0xd3, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xd3, 0x03, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xd3, 0x03, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2b, 0x02, 0x01, 0x12, 0x04, 0xd4,
+    0x03, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x01, 0x04, 0x12, 0x04, 0xd4, 0x03,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x01, 0x05, 0x12, 0x04, 0xd4, 0x03, 0x0b,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd4, 0x03, 0x12, 0x1b,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd4, 0x03, 0x1e, 0x1f, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x2b, 0x02, 0x02, 0x12, 0x04, 0xd5, 0x03, 0x02, 0x1e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x2b, 0x02, 0x02, 0x04, 0x12, 0x04, 0xd5, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x2b, 0x02, 0x02, 0x05, 0x12, 0x04, 0xd5, 0x03, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x2b, 0x02, 0x02, 0x01, 0x12, 0x04, 0xd5, 0x03, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0xd5, 0x03, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x2c, 0x12,
+    0x06, 0xd8, 0x03, 0x00, 0xdc, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x2c, 0x01, 0x12, 0x04,
+    0xd8, 0x03, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2c, 0x02, 0x00, 0x12, 0x04, 0xd9, 0x03,
+    0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x00, 0x06, 0x12, 0x04, 0xd9, 0x03, 0x02,
+    0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd9, 0x03, 0x07, 0x0b,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd9, 0x03, 0x0e, 0x0f, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x2c, 0x02, 0x01, 0x12, 0x04, 0xda, 0x03, 0x02, 0x22, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x2c, 0x02, 0x01, 0x04, 0x12, 0x04, 0xda, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x2c, 0x02, 0x01, 0x05, 0x12, 0x04, 0xda, 0x03, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x2c, 0x02, 0x01, 0x01, 0x12, 0x04, 0xda, 0x03, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xda, 0x03, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2c, 0x02,
+    0x02, 0x12, 0x04, 0xdb, 0x03, 0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x02, 0x04,
+    0x12, 0x04, 0xdb, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x02, 0x05, 0x12,
+    0x04, 0xdb, 0x03, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x02, 0x01, 0x12, 0x04,
+    0xdb, 0x03, 0x12, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x02, 0x03, 0x12, 0x04, 0xdb,
+    0x03, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x2d, 0x12, 0x06, 0xde, 0x03, 0x00, 0xe0, 0x03,
+    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x2d, 0x01, 0x12, 0x04, 0xde, 0x03, 0x08, 0x1a, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x2d, 0x02, 0x00, 0x12, 0x04, 0xdf, 0x03, 0x02, 0x2b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x2d, 0x02, 0x00, 0x04, 0x12, 0x04, 0xdf, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x2d, 0x02, 0x00, 0x06, 0x12, 0x04, 0xdf, 0x03, 0x0b, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xdf, 0x03, 0x1d, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xdf, 0x03, 0x29, 0x2a, 0x0a, 0x47, 0x0a, 0x02, 0x04, 0x2e, 0x12, 0x06,
+    0xe3, 0x03, 0x00, 0xe7, 0x03, 0x01, 0x1a, 0x39, 0x20, 0x43, 0x6c, 0x75, 0x73, 0x74, 0x65, 0x72,
+    0x20, 0x69, 0x64, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x73, 0x20, 0x6f, 0x75, 0x74, 0x73, 0x69,
+    0x64, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x63, 0x6c, 0x75, 0x73, 0x74, 0x65, 0x72, 0x2e,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x2e, 0x01, 0x12, 0x04, 0xe3, 0x03, 0x08, 0x19, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x2e, 0x02, 0x00, 0x12, 0x04, 0xe4, 0x03, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x2e, 0x02, 0x00, 0x06, 0x12, 0x04, 0xe4, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x2e, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe4, 0x03, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xe4, 0x03, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2e, 0x02,
+    0x01, 0x12, 0x04, 0xe5, 0x03, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xe5, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xe5, 0x03, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xe5, 0x03, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2e, 0x02, 0x02, 0x12, 0x04, 0xe6, 0x03,
+    0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x02, 0x02, 0x05, 0x12, 0x04, 0xe6, 0x03, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x02, 0x02, 0x01, 0x12, 0x04, 0xe6, 0x03, 0x09, 0x13,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x02, 0x02, 0x03, 0x12, 0x04, 0xe6, 0x03, 0x16, 0x17, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x2f, 0x12, 0x06, 0xe9, 0x03, 0x00, 0xeb, 0x03, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x2f, 0x01, 0x12, 0x04, 0xe9, 0x03, 0x08, 0x0b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2f,
+    0x02, 0x00, 0x12, 0x04, 0xea, 0x03, 0x02, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x00,
+    0x04, 0x12, 0x04, 0xea, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x00, 0x06,
+    0x12, 0x04, 0xea, 0x03, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xea, 0x03, 0x17, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xea, 0x03, 0x22, 0x23, 0x0a, 0x60, 0x0a, 0x02, 0x04, 0x30, 0x12, 0x06, 0xee, 0x03, 0x00, 0xf7,
+    0x03, 0x01, 0x1a, 0x52, 0x20, 0x50, 0x72, 0x6f, 0x66, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x61,
+    0x20, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x20, 0x73, 0x65, 0x6c, 0x6c, 0x69, 0x6e, 0x67, 0x20,
+    0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x62, 0x6f, 0x75, 0x67, 0x68,
+    0x74, 0x2c, 0x20, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x65,
+    0x69, 0x20, 0x61, 0x73, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72,
+    0x69, 0x6e, 0x67, 0x73, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x30, 0x01, 0x12, 0x04, 0xee,
+    0x03, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x30, 0x02, 0x00, 0x12, 0x04, 0xef, 0x03, 0x02,
+    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x00, 0x05, 0x12, 0x04, 0xef, 0x03, 0x02, 0x07,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x00, 0x01, 0x12, 0x04, 0xef, 0x03, 0x08, 0x0e, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x00, 0x03, 0x12, 0x04, 0xef, 0x03, 0x11, 0x12, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x30, 0x02, 0x01, 0x12, 0x04, 0xf0, 0x03, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x30, 0x02, 0x01, 0x05, 0x12, 0x04, 0xf0, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x30, 0x02, 0x01, 0x01, 0x12, 0x04, 0xf0, 0x03, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xf0, 0x03, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x30, 0x02,
+    0x02, 0x12, 0x04, 0xf1, 0x03, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x02, 0x05,
+    0x12, 0x04, 0xf1, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0xf1, 0x03, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0xf1, 0x03, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x30, 0x02, 0x03, 0x12, 0x04, 0xf2, 0x03,
+    0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x03, 0x05, 0x12, 0x04, 0xf2, 0x03, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x03, 0x01, 0x12, 0x04, 0xf2, 0x03, 0x09, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x03, 0x03, 0x12, 0x04, 0xf2, 0x03, 0x14, 0x15, 0x0a,
+    0x38, 0x0a, 0x04, 0x04, 0x30, 0x02, 0x04, 0x12, 0x04, 0xf4, 0x03, 0x02, 0x14, 0x1a, 0x2a, 0x20,
+    0x50, 0x72, 0x6f, 0x63, 0x65, 0x65, 0x64, 0x73, 0x20, 0x6d, 0x69, 0x6e, 0x75, 0x73, 0x20, 0x63,
+    0x6f, 0x73, 0x74, 0x2c, 0x20, 0x6e, 0x65, 0x67, 0x61, 0x74, 0x69, 0x76, 0x65, 0x20, 0x6f, 0x6e,
+    0x20, 0x61, 0x20, 0x6c, 0x6f, 0x73, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02,
+    0x04, 0x05, 0x12, 0x04, 0xf4, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x04,
+    0x01, 0x12, 0x04, 0xf4, 0x03, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x04, 0x03,
+    0x12, 0x04, 0xf4, 0x03, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x30, 0x02, 0x05, 0x12, 0x04,
+    0xf5, 0x03, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x05, 0x05, 0x12, 0x04, 0xf5,
+    0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x05, 0x01, 0x12, 0x04, 0xf5, 0x03,
+    0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x05, 0x03, 0x12, 0x04, 0xf5, 0x03, 0x13,
+    0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x30, 0x02, 0x06, 0x12, 0x04, 0xf6, 0x03, 0x02, 0x15, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x06, 0x05, 0x12, 0x04, 0xf6, 0x03, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x30, 0x02, 0x06, 0x01, 0x12, 0x04, 0xf6, 0x03, 0x09, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x30, 0x02, 0x06, 0x03, 0x12, 0x04, 0xf6, 0x03, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x02,
+    0x04, 0x31, 0x12, 0x06, 0xf9, 0x03, 0x00, 0xfe, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x31,
+    0x01, 0x12, 0x04, 0xf9, 0x03, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x31, 0x02, 0x00, 0x12,
+    0x04, 0xfa, 0x03, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x31, 0x02, 0x00, 0x05, 0x12, 0x04,
+    0xfa, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x31, 0x02, 0x00, 0x01, 0x12, 0x04, 0xfa,
+    0x03, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x31, 0x02, 0x00, 0x03, 0x12, 0x04, 0xfa, 0x03,
+    0x11, 0x12, 0x0a, 0x5e, 0x0a, 0x04, 0x04, 0x31, 0x02, 0x01, 0x12, 0x04, 0xfc, 0x03, 0x02, 0x19,
+    0x1a, 0x50, 0x20, 0x4c, 0x6f, 0x77, 0x65, 0x73, 0x74, 0x20, 0x45, 0x54, 0x48, 0x20, 0x73, 0x61,
+    0x6c, 0x65, 0x20, 0x70, 0x72, 0x69, 0x63, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6c, 0x61, 0x73, 0x74, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x20, 0x77, 0x69,
+    0x6e, 0x64, 0x6f, 0x77, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x65, 0x69, 0x20, 0x61, 0x73, 0x20,
+    0x61, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x31, 0x02, 0x01, 0x05, 0x12, 0x04, 0xfc, 0x03, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x31, 0x02, 0x01, 0x01, 0x12, 0x04, 0xfc, 0x03, 0x09, 0x14,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x31, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfc, 0x03, 0x17, 0x18, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x31, 0x02, 0x02, 0x12, 0x04, 0xfd, 0x03, 0x02, 0x27, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x31, 0x02, 0x02, 0x04, 0x12, 0x04, 0xfd, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x31, 0x02, 0x02, 0x06, 0x12, 0x04, 0xfd, 0x03, 0x0b, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x31, 0x02, 0x02, 0x01, 0x12, 0x04, 0xfd, 0x03, 0x1b, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x31,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0xfd, 0x03, 0x25, 0x26, 0x0a, 0x6e, 0x0a, 0x02, 0x04, 0x32, 0x12,
+    0x06, 0x81, 0x04, 0x00, 0x8d, 0x04, 0x01, 0x1a, 0x60, 0x20, 0x48, 0x6f, 0x6c, 0x64, 0x65, 0x72,
+    0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72,
+    0x61, 0x63, 0x6b, 0x65, 0x64, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x72, 0x65, 0x63, 0x6f, 0x6d, 0x70, 0x75, 0x74, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d,
+    0x20, 0x73, 0x63, 0x72, 0x61, 0x74, 0x63, 0x68, 0x2c, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x73,
+    0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x63, 0x72, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x61,
+    0x6c, 0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x32, 0x01,
+    0x12, 0x04, 0x81, 0x04, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x32, 0x02, 0x00, 0x12, 0x04,
+    0x82, 0x04, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x00, 0x05, 0x12, 0x04, 0x82,
+    0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x00, 0x01, 0x12, 0x04, 0x82, 0x04,
+    0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x00, 0x03, 0x12, 0x04, 0x82, 0x04, 0x11,
+    0x12, 0x0a, 0x66, 0x0a, 0x04, 0x04, 0x32, 0x02, 0x01, 0x12, 0x04, 0x84, 0x04, 0x02, 0x13, 0x1a,
+    0x58, 0x20, 0x57, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x20, 0x77, 0x61, 0x73, 0x20, 0x70, 0x69, 0x63, 0x6b, 0x65, 0x64, 0x20, 0x62,
+    0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x65, 0x76, 0x65, 0x72, 0x79, 0x60, 0x20, 0x70, 0x61,
+    0x72, 0x61, 0x6d, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x66,
+    0x69, 0x65, 0x6c, 0x64, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x73,
+    0x65, 0x74, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02,
+    0x01, 0x05, 0x12, 0x04, 0x84, 0x04, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0x84, 0x04, 0x07, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x01, 0x03,
+    0x12, 0x04, 0x84, 0x04, 0x11, 0x12, 0x0a, 0x4d, 0x0a, 0x04, 0x04, 0x32, 0x02, 0x02, 0x12, 0x04,
+    0x86, 0x04, 0x02, 0x18, 0x1a, 0x3f, 0x20, 0x48, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x73, 0x20, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x5f,
+    0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x5f, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x61, 0x73, 0x20,
+    0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x73, 0x20, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x7a,
+    0x65, 0x72, 0x6f, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x02, 0x05, 0x12, 0x04,
+    0x86, 0x04, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x02, 0x01, 0x12, 0x04, 0x86,
+    0x04, 0x08, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x02, 0x03, 0x12, 0x04, 0x86, 0x04,
+    0x16, 0x17, 0x0a, 0x55, 0x0a, 0x04, 0x04, 0x32, 0x02, 0x03, 0x12, 0x04, 0x88, 0x04, 0x02, 0x17,
+    0x1a, 0x47, 0x20, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20,
+    0x73, 0x74, 0x6f, 0x72, 0x65, 0x5f, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x73, 0x20, 0x77, 0x68,
+    0x6f, 0x73, 0x65, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x5f, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x73, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x69, 0x73, 0x20, 0x70,
+    0x6f, 0x73, 0x69, 0x74, 0x69, 0x76, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02,
+    0x03, 0x05, 0x12, 0x04, 0x88, 0x04, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x03,
+    0x01, 0x12, 0x04, 0x88, 0x04, 0x08, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x03, 0x03,
+    0x12, 0x04, 0x88, 0x04, 0x15, 0x16, 0x0a, 0x4a, 0x0a, 0x04, 0x04, 0x32, 0x02, 0x04, 0x12, 0x04,
+    0x8a, 0x04, 0x02, 0x12, 0x1a, 0x3c, 0x20, 0x60, 0x69, 0x6e, 0x63, 0x72, 0x65, 0x6d, 0x65, 0x6e,
+    0x74, 0x61, 0x6c, 0x60, 0x20, 0x6d, 0x69, 0x6e, 0x75, 0x73, 0x20, 0x60, 0x72, 0x65, 0x63, 0x6f,
+    0x6d, 0x70, 0x75, 0x74, 0x65, 0x64, 0x60, 0x2c, 0x20, 0x30, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x73, 0x20, 0x61, 0x67, 0x72, 0x65, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x04, 0x05, 0x12, 0x04, 0x8a, 0x04, 0x02,
+    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x04, 0x01, 0x12, 0x04, 0x8a, 0x04, 0x08, 0x0d,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x04, 0x03, 0x12, 0x04, 0x8a, 0x04, 0x10, 0x11, 0x0a,
+    0x64, 0x0a, 0x04, 0x04, 0x32, 0x02, 0x05, 0x12, 0x04, 0x8c, 0x04, 0x02, 0x1f, 0x1a, 0x56, 0x20,
+    0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x73, 0x74, 0x6f,
+    0x72, 0x65, 0x5f, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x73, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65,
+    0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x77, 0x65, 0x6e, 0x74, 0x20, 0x62, 0x65,
+    0x6c, 0x6f, 0x77, 0x20, 0x7a, 0x65, 0x72, 0x6f, 0x2c, 0x20, 0x73, 0x65, 0x65, 0x20, 0x6d, 0x61,
+    0x70, 0x5f, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x5f, 0x61, 0x6e, 0x6f, 0x6d, 0x61, 0x6c,
+    0x69, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x05, 0x05, 0x12, 0x04,
+    0x8c, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x05, 0x01, 0x12, 0x04, 0x8c,
+    0x04, 0x09, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x05, 0x03, 0x12, 0x04, 0x8c, 0x04,
+    0x1d, 0x1e, 0x0a, 0x76, 0x0a, 0x02, 0x04, 0x33, 0x12, 0x06, 0x90, 0x04, 0x00, 0x98, 0x04, 0x01,
+    0x1a, 0x68, 0x20, 0x53, 0x61, 0x6c, 0x65, 0x20, 0x70, 0x72, 0x69, 0x63, 0x65, 0x73, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x6c,
+    0x65, 0x74, 0x65, 0x20, 0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x2c, 0x20, 0x65, 0x73, 0x74, 0x69,
+    0x6d, 0x61, 0x74, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x61, 0x20, 0x68, 0x69, 0x73,
+    0x74, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x62, 0x75, 0x63,
+    0x6b, 0x65, 0x74, 0x73, 0x20, 0x73, 0x70, 0x61, 0x6e, 0x20, 0x61, 0x62, 0x6f, 0x75, 0x74, 0x20,
+    0x31, 0x39, 0x25, 0x20, 0x65, 0x61, 0x63, 0x68, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x33,
+    0x01, 0x12, 0x04, 0x90, 0x04, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x33, 0x02, 0x00, 0x12,
+    0x04, 0x91, 0x04, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x00, 0x05, 0x12, 0x04,
+    0x91, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x00, 0x01, 0x12, 0x04, 0x91,
+    0x04, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x00, 0x03, 0x12, 0x04, 0x91, 0x04,
+    0x11, 0x12, 0x0a, 0x58, 0x0a, 0x04, 0x04, 0x33, 0x02, 0x01, 0x12, 0x04, 0x93, 0x04, 0x02, 0x14,
+    0x1a, 0x4a, 0x20, 0x57, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74,
+    0x61, 0x74, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x61, 0x62, 0x6f, 0x75, 0x74, 0x2c, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20,
+    0x64, 0x69, 0x76, 0x69, 0x64, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x77,
+    0x69, 0x6e, 0x64, 0x6f, 0x77, 0x20, 0x73, 0x69, 0x7a, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x33, 0x02, 0x01, 0x05, 0x12, 0x04, 0x93, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x33, 0x02, 0x01, 0x01, 0x12, 0x04, 0x93, 0x04, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0x93, 0x04, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x33, 0x02,
+    0x02, 0x12, 0x04, 0x94, 0x04, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x02, 0x05,
+    0x12, 0x04, 0x94, 0x04, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0x94, 0x04, 0x08, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0x94, 0x04, 0x10, 0x11, 0x0a, 0x60, 0x0a, 0x04, 0x04, 0x33, 0x02, 0x03, 0x12, 0x04, 0x96, 0x04,
+    0x02, 0x1a, 0x1a, 0x52, 0x20, 0x50, 0x72, 0x69, 0x63, 0x65, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x77,
+    0x65, 0x69, 0x20, 0x61, 0x73, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0x73, 0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x77, 0x68, 0x65,
+    0x6e, 0x20, 0x6e, 0x6f, 0x20, 0x45, 0x54, 0x48, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x20, 0x68, 0x61,
+    0x70, 0x70, 0x65, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x77, 0x69,
+    0x6e, 0x64, 0x6f, 0x77, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x03, 0x05, 0x12,
+    0x04, 0x96, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x03, 0x01, 0x12, 0x04,
+    0x96, 0x04, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x03, 0x03, 0x12, 0x04, 0x96,
+    0x04, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x33, 0x02, 0x04, 0x12, 0x04, 0x97, 0x04, 0x02,
+    0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x04, 0x05, 0x12, 0x04, 0x97, 0x04, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x04, 0x01, 0x12, 0x04, 0x97, 0x04, 0x09, 0x12, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x04, 0x03, 0x12, 0x04, 0x97, 0x04, 0x15, 0x16, 0x0a, 0x58,
+    0x0a, 0x02, 0x04, 0x34, 0x12, 0x06, 0x9b, 0x04, 0x00, 0xa3, 0x04, 0x01, 0x1a, 0x4a, 0x20, 0x48,
+    0x6f, 0x6c, 0x64, 0x65, 0x72, 0x20, 0x69, 0x6e, 0x76, 0x65, 0x6e, 0x74, 0x6f, 0x72, 0x79, 0x20,
+    0x6d, 0x61, 0x72, 0x6b, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x6c,
+    0x6f, 0x6f, 0x72, 0x2c, 0x20, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x20, 0x69, 0x6e, 0x20,
+    0x77, 0x65, 0x69, 0x20, 0x61, 0x73, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73,
+    0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x34, 0x01, 0x12,
+    0x04, 0x9b, 0x04, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x34, 0x02, 0x00, 0x12, 0x04, 0x9c,
+    0x04, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x00, 0x05, 0x12, 0x04, 0x9c, 0x04,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9c, 0x04, 0x08,
+    0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9c, 0x04, 0x11, 0x12,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x34, 0x02, 0x01, 0x12, 0x04, 0x9d, 0x04, 0x02, 0x14, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x34, 0x02, 0x01, 0x05, 0x12, 0x04, 0x9d, 0x04, 0x02, 0x07, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x34, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9d, 0x04, 0x08, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x34, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9d, 0x04, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x34, 0x02, 0x02, 0x12, 0x04, 0x9e, 0x04, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0x9e, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0x9e, 0x04, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0x9e, 0x04, 0x11, 0x12, 0x0a, 0x66, 0x0a, 0x04, 0x04, 0x34, 0x02, 0x03, 0x12, 0x04,
+    0xa0, 0x04, 0x02, 0x18, 0x1a, 0x58, 0x20, 0x50, 0x72, 0x69, 0x63, 0x65, 0x20, 0x70, 0x61, 0x69,
+    0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73,
+    0x20, 0x73, 0x74, 0x69, 0x6c, 0x6c, 0x20, 0x68, 0x65, 0x6c, 0x64, 0x2c, 0x20, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x73, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x6f, 0x72, 0x20, 0x72, 0x65,
+    0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x66, 0x72, 0x65, 0x65, 0x20,
+    0x63, 0x6f, 0x73, 0x74, 0x20, 0x6e, 0x6f, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x34, 0x02, 0x03, 0x05, 0x12, 0x04, 0xa0, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x34, 0x02, 0x03, 0x01, 0x12, 0x04, 0xa0, 0x04, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x34, 0x02, 0x03, 0x03, 0x12, 0x04, 0xa0, 0x04, 0x16, 0x17, 0x0a, 0x3b, 0x0a, 0x04, 0x04,
+    0x34, 0x02, 0x04, 0x12, 0x04, 0xa2, 0x04, 0x02, 0x1c, 0x1a, 0x2d, 0x20, 0x56, 0x61, 0x6c, 0x75,
+    0x65, 0x20, 0x6d, 0x69, 0x6e, 0x75, 0x73, 0x20, 0x63, 0x6f, 0x73, 0x74, 0x20, 0x62, 0x61, 0x73,
+    0x69, 0x73, 0x2c, 0x20, 0x6e, 0x65, 0x67, 0x61, 0x74, 0x69, 0x76, 0x65, 0x20, 0x6f, 0x6e, 0x20,
+    0x61, 0x20, 0x6c, 0x6f, 0x73, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x04,
+    0x05, 0x12, 0x04, 0xa2, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0xa2, 0x04, 0x09, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x04, 0x03, 0x12,
+    0x04, 0xa2, 0x04, 0x1a, 0x1b, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 ];
 // @@protoc_insertion_point(module)
\ No newline at end of file
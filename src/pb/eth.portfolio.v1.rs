@@ -0,0 +1,551 @@
+// @generated
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Portfolios {
+    #[prost(message, repeated, tag="1")]
+    pub portfolios: ::prost::alloc::vec::Vec<Portfolio>,
+}
+/// Holdings of an address that changed in the block, across token standards.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Portfolio {
+    #[prost(bytes="vec", tag="1")]
+    pub holder: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, repeated, tag="2")]
+    pub holdings: ::prost::alloc::vec::Vec<Holding>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Holding {
+    #[prost(enumeration="holding::Standard", tag="1")]
+    pub standard: i32,
+    #[prost(bytes="vec", tag="2")]
+    pub contract: ::prost::alloc::vec::Vec<u8>,
+    /// Only set for ERC1155 holdings, ERC721 balances count every token of the collection.
+    #[prost(string, tag="3")]
+    pub token_id: ::prost::alloc::string::String,
+    /// Balance after the block in the smallest unit of the token, as a decimal string.
+    #[prost(string, tag="4")]
+    pub balance: ::prost::alloc::string::String,
+    /// Only set for share based rebasing tokens, whose `balance` is converted from the shares at the
+    /// rate of the block. `balance` is left empty when the rate could not be read.
+    #[prost(string, tag="5")]
+    pub shares: ::prost::alloc::string::String,
+    /// Decimals of ERC20 tokens from store_token_decimals, 0 when they are not known.
+    #[prost(uint32, tag="6")]
+    pub decimals: u32,
+    /// The computed balance went below zero, as when history starts mid-chain or a token moves balances without
+    /// standard events. `balance` is then reported as 0, see map_balance_anomalies.
+    #[prost(bool, tag="7")]
+    pub clamped: bool,
+    /// ERC20 `balance` in whole tokens scaled by `decimals`, empty when the decimals are not known.
+    #[prost(string, tag="8")]
+    pub value: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `Holding`.
+pub mod holding {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Standard {
+        Unspecified = 0,
+        Erc721 = 1,
+        Erc1155 = 2,
+        Erc20 = 3,
+    }
+    impl Standard {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Standard::Unspecified => "STANDARD_UNSPECIFIED",
+                Standard::Erc721 => "STANDARD_ERC721",
+                Standard::Erc1155 => "STANDARD_ERC1155",
+                Standard::Erc20 => "STANDARD_ERC20",
+            }
+        }
+    }
+}
+/// Balances a balance store starts from, packed and base64 encoded in its `snapshot` param.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceSnapshot {
+    #[prost(message, repeated, tag="1")]
+    pub balances: ::prost::alloc::vec::Vec<SnapshotBalance>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotBalance {
+    #[prost(bytes="vec", tag="1")]
+    pub holder: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub contract: ::prost::alloc::vec::Vec<u8>,
+    /// Only set for ERC1155 balances.
+    #[prost(string, tag="3")]
+    pub token_id: ::prost::alloc::string::String,
+    /// Balance in the smallest unit of the token, as a decimal string.
+    #[prost(string, tag="4")]
+    pub balance: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetTransfers {
+    #[prost(message, repeated, tag="1")]
+    pub transfers: ::prost::alloc::vec::Vec<AssetTransfer>,
+}
+/// A transfer of any asset, mints come from the zero address and burns go to it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetTransfer {
+    #[prost(enumeration="asset_transfer::Standard", tag="1")]
+    pub standard: i32,
+    /// Token contract, empty for ETH.
+    #[prost(bytes="vec", tag="2")]
+    pub contract: ::prost::alloc::vec::Vec<u8>,
+    /// Decimal token id of ERC721 and ERC1155 transfers, empty for fungible ones.
+    #[prost(string, tag="3")]
+    pub token_id: ::prost::alloc::string::String,
+    /// Amount in the smallest unit of the asset as a decimal string, 1 for ERC721 transfers.
+    #[prost(string, tag="4")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="5")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub ordinal: u64,
+    /// `<trx_hash>-<block_index>`, followed by `-<token_id>` for ERC721 and ERC1155 transfers, or
+    /// `<trx_hash>-call-<call_index>` for ETH transfers, stable across replays for sinks to upsert on.
+    #[prost(string, tag="9")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `AssetTransfer`.
+pub mod asset_transfer {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Standard {
+        Unspecified = 0,
+        /// ETH sent along a call, the transaction itself or an internal call.
+        Native = 1,
+        Erc20 = 2,
+        Erc777 = 3,
+        Erc1363 = 4,
+        Erc721 = 5,
+        Erc1155 = 6,
+    }
+    impl Standard {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Standard::Unspecified => "STANDARD_UNSPECIFIED",
+                Standard::Native => "STANDARD_NATIVE",
+                Standard::Erc20 => "STANDARD_ERC20",
+                Standard::Erc777 => "STANDARD_ERC777",
+                Standard::Erc1363 => "STANDARD_ERC1363",
+                Standard::Erc721 => "STANDARD_ERC721",
+                Standard::Erc1155 => "STANDARD_ERC1155",
+            }
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceAnomalies {
+    #[prost(message, repeated, tag="1")]
+    pub anomalies: ::prost::alloc::vec::Vec<BalanceAnomaly>,
+}
+/// Balance of a holder that went below zero in the block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceAnomaly {
+    #[prost(bytes="vec", tag="1")]
+    pub holder: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="holding::Standard", tag="2")]
+    pub standard: i32,
+    #[prost(bytes="vec", tag="3")]
+    pub contract: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="4")]
+    pub token_id: ::prost::alloc::string::String,
+    /// Computed balance before and after the change, as decimal strings. ERC20 balances are in whole tokens.
+    #[prost(string, tag="5")]
+    pub previous_balance: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub balance: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub ordinal: u64,
+}
+/// Encoded file descriptor set for the `eth.portfolio.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0xd0, 0x2e, 0x0a, 0x0f, 0x70, 0x6f, 0x72, 0x74, 0x66, 0x6f, 0x6c, 0x69, 0x6f, 0x2e, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x12, 0x10, 0x65, 0x74, 0x68, 0x2e, 0x70, 0x6f, 0x72, 0x74, 0x66, 0x6f,
+    0x6c, 0x69, 0x6f, 0x2e, 0x76, 0x31, 0x22, 0x49, 0x0a, 0x0a, 0x50, 0x6f, 0x72, 0x74, 0x66, 0x6f,
+    0x6c, 0x69, 0x6f, 0x73, 0x12, 0x3b, 0x0a, 0x0a, 0x70, 0x6f, 0x72, 0x74, 0x66, 0x6f, 0x6c, 0x69,
+    0x6f, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x70,
+    0x6f, 0x72, 0x74, 0x66, 0x6f, 0x6c, 0x69, 0x6f, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6f, 0x72, 0x74,
+    0x66, 0x6f, 0x6c, 0x69, 0x6f, 0x52, 0x0a, 0x70, 0x6f, 0x72, 0x74, 0x66, 0x6f, 0x6c, 0x69, 0x6f,
+    0x73, 0x22, 0x5a, 0x0a, 0x09, 0x50, 0x6f, 0x72, 0x74, 0x66, 0x6f, 0x6c, 0x69, 0x6f, 0x12, 0x16,
+    0x0a, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06,
+    0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x12, 0x35, 0x0a, 0x08, 0x68, 0x6f, 0x6c, 0x64, 0x69, 0x6e,
+    0x67, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x70,
+    0x6f, 0x72, 0x74, 0x66, 0x6f, 0x6c, 0x69, 0x6f, 0x2e, 0x76, 0x31, 0x2e, 0x48, 0x6f, 0x6c, 0x64,
+    0x69, 0x6e, 0x67, 0x52, 0x08, 0x68, 0x6f, 0x6c, 0x64, 0x69, 0x6e, 0x67, 0x73, 0x22, 0xcd, 0x02,
+    0x0a, 0x07, 0x48, 0x6f, 0x6c, 0x64, 0x69, 0x6e, 0x67, 0x12, 0x3e, 0x0a, 0x08, 0x73, 0x74, 0x61,
+    0x6e, 0x64, 0x61, 0x72, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x22, 0x2e, 0x65, 0x74,
+    0x68, 0x2e, 0x70, 0x6f, 0x72, 0x74, 0x66, 0x6f, 0x6c, 0x69, 0x6f, 0x2e, 0x76, 0x31, 0x2e, 0x48,
+    0x6f, 0x6c, 0x64, 0x69, 0x6e, 0x67, 0x2e, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x52,
+    0x08, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69,
+    0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64,
+    0x12, 0x18, 0x0a, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x68,
+    0x61, 0x72, 0x65, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x68, 0x61, 0x72,
+    0x65, 0x73, 0x12, 0x1a, 0x0a, 0x08, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x73, 0x18, 0x06,
+    0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x73, 0x12, 0x18,
+    0x0a, 0x07, 0x63, 0x6c, 0x61, 0x6d, 0x70, 0x65, 0x64, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x52,
+    0x07, 0x63, 0x6c, 0x61, 0x6d, 0x70, 0x65, 0x64, 0x22, 0x63, 0x0a, 0x08, 0x53, 0x74, 0x61, 0x6e,
+    0x64, 0x61, 0x72, 0x64, 0x12, 0x18, 0x0a, 0x14, 0x53, 0x54, 0x41, 0x4e, 0x44, 0x41, 0x52, 0x44,
+    0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x13,
+    0x0a, 0x0f, 0x53, 0x54, 0x41, 0x4e, 0x44, 0x41, 0x52, 0x44, 0x5f, 0x45, 0x52, 0x43, 0x37, 0x32,
+    0x31, 0x10, 0x01, 0x12, 0x14, 0x0a, 0x10, 0x53, 0x54, 0x41, 0x4e, 0x44, 0x41, 0x52, 0x44, 0x5f,
+    0x45, 0x52, 0x43, 0x31, 0x31, 0x35, 0x35, 0x10, 0x02, 0x12, 0x12, 0x0a, 0x0e, 0x53, 0x54, 0x41,
+    0x4e, 0x44, 0x41, 0x52, 0x44, 0x5f, 0x45, 0x52, 0x43, 0x32, 0x30, 0x10, 0x03, 0x22, 0x50, 0x0a,
+    0x0f, 0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74,
+    0x12, 0x3d, 0x0a, 0x08, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x21, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x70, 0x6f, 0x72, 0x74, 0x66, 0x6f, 0x6c,
+    0x69, 0x6f, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x42, 0x61,
+    0x6c, 0x61, 0x6e, 0x63, 0x65, 0x52, 0x08, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x73, 0x22,
+    0x7a, 0x0a, 0x0f, 0x53, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x42, 0x61, 0x6c, 0x61, 0x6e,
+    0x63, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x63, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f,
+    0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49,
+    0x64, 0x12, 0x18, 0x0a, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x22, 0x4f, 0x0a, 0x0e, 0x41,
+    0x73, 0x73, 0x65, 0x74, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x12, 0x3d, 0x0a,
+    0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b,
+    0x32, 0x1f, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x70, 0x6f, 0x72, 0x74, 0x66, 0x6f, 0x6c, 0x69, 0x6f,
+    0x2e, 0x76, 0x31, 0x2e, 0x41, 0x73, 0x73, 0x65, 0x74, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65,
+    0x72, 0x52, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x22, 0xb3, 0x03, 0x0a,
+    0x0d, 0x41, 0x73, 0x73, 0x65, 0x74, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x44,
+    0x0a, 0x08, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e,
+    0x32, 0x28, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x70, 0x6f, 0x72, 0x74, 0x66, 0x6f, 0x6c, 0x69, 0x6f,
+    0x2e, 0x76, 0x31, 0x2e, 0x41, 0x73, 0x73, 0x65, 0x74, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65,
+    0x72, 0x2e, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x52, 0x08, 0x73, 0x74, 0x61, 0x6e,
+    0x64, 0x61, 0x72, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x61,
+    0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x61, 0x6d, 0x6f,
+    0x75, 0x6e, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x05, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x02, 0x74, 0x6f, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68,
+    0x61, 0x73, 0x68, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61,
+    0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x08, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x0e, 0x0a, 0x02,
+    0x69, 0x64, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0xa3, 0x01, 0x0a,
+    0x08, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x12, 0x18, 0x0a, 0x14, 0x53, 0x54, 0x41,
+    0x4e, 0x44, 0x41, 0x52, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45,
+    0x44, 0x10, 0x00, 0x12, 0x13, 0x0a, 0x0f, 0x53, 0x54, 0x41, 0x4e, 0x44, 0x41, 0x52, 0x44, 0x5f,
+    0x4e, 0x41, 0x54, 0x49, 0x56, 0x45, 0x10, 0x01, 0x12, 0x12, 0x0a, 0x0e, 0x53, 0x54, 0x41, 0x4e,
+    0x44, 0x41, 0x52, 0x44, 0x5f, 0x45, 0x52, 0x43, 0x32, 0x30, 0x10, 0x02, 0x12, 0x13, 0x0a, 0x0f,
+    0x53, 0x54, 0x41, 0x4e, 0x44, 0x41, 0x52, 0x44, 0x5f, 0x45, 0x52, 0x43, 0x37, 0x37, 0x37, 0x10,
+    0x03, 0x12, 0x14, 0x0a, 0x10, 0x53, 0x54, 0x41, 0x4e, 0x44, 0x41, 0x52, 0x44, 0x5f, 0x45, 0x52,
+    0x43, 0x31, 0x33, 0x36, 0x33, 0x10, 0x04, 0x12, 0x13, 0x0a, 0x0f, 0x53, 0x54, 0x41, 0x4e, 0x44,
+    0x41, 0x52, 0x44, 0x5f, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x10, 0x05, 0x12, 0x14, 0x0a, 0x10,
+    0x53, 0x54, 0x41, 0x4e, 0x44, 0x41, 0x52, 0x44, 0x5f, 0x45, 0x52, 0x43, 0x31, 0x31, 0x35, 0x35,
+    0x10, 0x06, 0x22, 0x52, 0x0a, 0x10, 0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x41, 0x6e, 0x6f,
+    0x6d, 0x61, 0x6c, 0x69, 0x65, 0x73, 0x12, 0x3e, 0x0a, 0x09, 0x61, 0x6e, 0x6f, 0x6d, 0x61, 0x6c,
+    0x69, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x65, 0x74, 0x68, 0x2e,
+    0x70, 0x6f, 0x72, 0x74, 0x66, 0x6f, 0x6c, 0x69, 0x6f, 0x2e, 0x76, 0x31, 0x2e, 0x42, 0x61, 0x6c,
+    0x61, 0x6e, 0x63, 0x65, 0x41, 0x6e, 0x6f, 0x6d, 0x61, 0x6c, 0x79, 0x52, 0x09, 0x61, 0x6e, 0x6f,
+    0x6d, 0x61, 0x6c, 0x69, 0x65, 0x73, 0x22, 0xfe, 0x01, 0x0a, 0x0e, 0x42, 0x61, 0x6c, 0x61, 0x6e,
+    0x63, 0x65, 0x41, 0x6e, 0x6f, 0x6d, 0x61, 0x6c, 0x79, 0x12, 0x16, 0x0a, 0x06, 0x68, 0x6f, 0x6c,
+    0x64, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65,
+    0x72, 0x12, 0x3e, 0x0a, 0x08, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x0e, 0x32, 0x22, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x70, 0x6f, 0x72, 0x74, 0x66, 0x6f,
+    0x6c, 0x69, 0x6f, 0x2e, 0x76, 0x31, 0x2e, 0x48, 0x6f, 0x6c, 0x64, 0x69, 0x6e, 0x67, 0x2e, 0x53,
+    0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x52, 0x08, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72,
+    0x64, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x12, 0x19, 0x0a,
+    0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x29, 0x0a, 0x10, 0x70, 0x72, 0x65, 0x76,
+    0x69, 0x6f, 0x75, 0x73, 0x5f, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x05, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x0f, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x42, 0x61, 0x6c, 0x61,
+    0x6e, 0x63, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x06,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x12, 0x18, 0x0a,
+    0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07,
+    0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x4a, 0x81, 0x22, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00,
+    0x63, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01,
+    0x02, 0x12, 0x03, 0x02, 0x00, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x04, 0x00,
+    0x06, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08, 0x12, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x05, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x06, 0x12, 0x03, 0x05, 0x0b, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x05, 0x15, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x05, 0x22, 0x23, 0x0a, 0x57, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x09, 0x00, 0x0c, 0x01, 0x1a,
+    0x4b, 0x20, 0x48, 0x6f, 0x6c, 0x64, 0x69, 0x6e, 0x67, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e,
+    0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x68,
+    0x61, 0x6e, 0x67, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x2c, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x01, 0x01, 0x12, 0x03, 0x09, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00,
+    0x12, 0x03, 0x0a, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x0a, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x08,
+    0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0a, 0x11, 0x12, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0b, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x01, 0x06, 0x12, 0x03, 0x0b, 0x0b, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x0b, 0x13, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x0b, 0x1e, 0x1f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x0e, 0x00, 0x25, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x0e, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x02, 0x04, 0x00, 0x12, 0x04, 0x0f, 0x02, 0x14, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x04, 0x00, 0x01, 0x12, 0x03, 0x0f, 0x07, 0x0f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x02, 0x04, 0x00,
+    0x02, 0x00, 0x12, 0x03, 0x10, 0x04, 0x1d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x02, 0x04, 0x00, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x10, 0x04, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x02, 0x04, 0x00, 0x02,
+    0x00, 0x02, 0x12, 0x03, 0x10, 0x1b, 0x1c, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x02, 0x04, 0x00, 0x02,
+    0x01, 0x12, 0x03, 0x11, 0x04, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x02, 0x04, 0x00, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x11, 0x04, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x02, 0x04, 0x00, 0x02, 0x01,
+    0x02, 0x12, 0x03, 0x11, 0x16, 0x17, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x02, 0x04, 0x00, 0x02, 0x02,
+    0x12, 0x03, 0x12, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x02, 0x04, 0x00, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x12, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x02, 0x04, 0x00, 0x02, 0x02, 0x02,
+    0x12, 0x03, 0x12, 0x17, 0x18, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x02, 0x04, 0x00, 0x02, 0x03, 0x12,
+    0x03, 0x13, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x02, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x13, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x02, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12,
+    0x03, 0x13, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x16, 0x02,
+    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x16, 0x0b, 0x13, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x16, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x17, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x17, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x17, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x17,
+    0x13, 0x14, 0x0a, 0x62, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x19, 0x02, 0x16, 0x1a,
+    0x55, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x45,
+    0x52, 0x43, 0x31, 0x31, 0x35, 0x35, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x69, 0x6e, 0x67, 0x73, 0x2c,
+    0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x73,
+    0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x19, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x19,
+    0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x19, 0x14, 0x15,
+    0x0a, 0x3c, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x02, 0x15, 0x1a, 0x2f, 0x20,
+    0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x65,
+    0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x05, 0x12, 0x03, 0x1b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1b, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x03, 0x03, 0x12, 0x03, 0x1b, 0x13, 0x14, 0x0a, 0xba, 0x01, 0x0a, 0x04, 0x04, 0x02, 0x02,
+    0x04, 0x12, 0x03, 0x1e, 0x02, 0x14, 0x1a, 0xac, 0x01, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x73,
+    0x65, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x68, 0x61, 0x72, 0x65, 0x20, 0x62, 0x61, 0x73,
+    0x65, 0x64, 0x20, 0x72, 0x65, 0x62, 0x61, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x73, 0x2c, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x60, 0x62, 0x61, 0x6c, 0x61, 0x6e,
+    0x63, 0x65, 0x60, 0x20, 0x69, 0x73, 0x20, 0x63, 0x6f, 0x6e, 0x76, 0x65, 0x72, 0x74, 0x65, 0x64,
+    0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x68, 0x61, 0x72, 0x65, 0x73,
+    0x20, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x72, 0x61, 0x74, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x20, 0x60, 0x62, 0x61, 0x6c,
+    0x61, 0x6e, 0x63, 0x65, 0x60, 0x20, 0x69, 0x73, 0x20, 0x6c, 0x65, 0x66, 0x74, 0x20, 0x65, 0x6d,
+    0x70, 0x74, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x61, 0x74,
+    0x65, 0x20, 0x63, 0x6f, 0x75, 0x6c, 0x64, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x20, 0x72,
+    0x65, 0x61, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x05, 0x12, 0x03,
+    0x1e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1e, 0x09,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x03, 0x12, 0x03, 0x1e, 0x12, 0x13, 0x0a,
+    0xc5, 0x01, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x05, 0x12, 0x03, 0x21, 0x02, 0x16, 0x1a, 0xb7, 0x01,
+    0x20, 0x44, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x73, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30, 0x20,
+    0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x73, 0x63, 0x61,
+    0x6c, 0x65, 0x64, 0x20, 0x62, 0x79, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x60, 0x62,
+    0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x60, 0x20, 0x69, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68,
+    0x6f, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x20, 0x30, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x77, 0x68, 0x6f,
+    0x73, 0x65, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x73, 0x20, 0x61, 0x72, 0x65, 0x0a,
+    0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x69,
+    0x72, 0x20, 0x60, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x60, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x6e, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c,
+    0x65, 0x73, 0x74, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x05,
+    0x12, 0x03, 0x21, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x01, 0x12, 0x03,
+    0x21, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x03, 0x12, 0x03, 0x21, 0x14,
+    0x15, 0x0a, 0xc6, 0x01, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x06, 0x12, 0x03, 0x24, 0x02, 0x13, 0x1a,
+    0xb8, 0x01, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x75, 0x74, 0x65, 0x64, 0x20,
+    0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x77, 0x65, 0x6e, 0x74, 0x20, 0x62, 0x65, 0x6c,
+    0x6f, 0x77, 0x20, 0x7a, 0x65, 0x72, 0x6f, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x77, 0x68, 0x65, 0x6e,
+    0x20, 0x68, 0x69, 0x73, 0x74, 0x6f, 0x72, 0x79, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x73, 0x20,
+    0x6d, 0x69, 0x64, 0x2d, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x6d, 0x6f, 0x76, 0x65, 0x73, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e,
+    0x63, 0x65, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x0a, 0x20, 0x73, 0x74, 0x61,
+    0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x2e, 0x20, 0x60, 0x62,
+    0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x60, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x6e, 0x20,
+    0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x30, 0x2c, 0x20, 0x73,
+    0x65, 0x65, 0x20, 0x6d, 0x61, 0x70, 0x5f, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x5f, 0x61,
+    0x6e, 0x6f, 0x6d, 0x61, 0x6c, 0x69, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x06, 0x05, 0x12, 0x03, 0x24, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x06,
+    0x01, 0x12, 0x03, 0x24, 0x07, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x06, 0x03, 0x12,
+    0x03, 0x24, 0x11, 0x12, 0x0a, 0x66, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x28, 0x00, 0x2a, 0x01,
+    0x1a, 0x5a, 0x20, 0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x73, 0x20, 0x61, 0x20, 0x62, 0x61,
+    0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x20, 0x73, 0x74, 0x61, 0x72,
+    0x74, 0x73, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x2c, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x65, 0x64, 0x20,
+    0x61, 0x6e, 0x64, 0x20, 0x62, 0x61, 0x73, 0x65, 0x36, 0x34, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64,
+    0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x20, 0x60, 0x73, 0x6e, 0x61, 0x70, 0x73,
+    0x68, 0x6f, 0x74, 0x60, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x03, 0x01, 0x12, 0x03, 0x28, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00,
+    0x12, 0x03, 0x29, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03,
+    0x29, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x29, 0x0b,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x29, 0x1b, 0x23, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x29, 0x26, 0x27, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x04, 0x12, 0x04, 0x2c, 0x00, 0x33, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01,
+    0x12, 0x03, 0x2c, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x2d,
+    0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2d, 0x02, 0x07,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2d, 0x08, 0x0e, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2d, 0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x2e, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x01, 0x05, 0x12, 0x03, 0x2e, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x2e, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03,
+    0x2e, 0x13, 0x14, 0x0a, 0x2d, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x02, 0x12, 0x03, 0x30, 0x02, 0x16,
+    0x1a, 0x20, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x45, 0x52, 0x43, 0x31, 0x31, 0x35, 0x35, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x73,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x05, 0x12, 0x03, 0x30, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x30, 0x09, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x30, 0x14, 0x15, 0x0a, 0x6e, 0x0a, 0x04,
+    0x04, 0x04, 0x02, 0x03, 0x12, 0x03, 0x32, 0x02, 0x15, 0x1a, 0x61, 0x20, 0x42, 0x61, 0x6c, 0x61,
+    0x6e, 0x63, 0x65, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c,
+    0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30, 0x20, 0x62,
+    0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68, 0x6f, 0x6c, 0x65,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x61, 0x73, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65,
+    0x5f, 0x65, 0x72, 0x63, 0x32, 0x30, 0x5f, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x73, 0x20,
+    0x6b, 0x65, 0x65, 0x70, 0x73, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x03, 0x05, 0x12, 0x03, 0x32, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x32, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x32, 0x13, 0x14, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x35, 0x00,
+    0x37, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x35, 0x08, 0x16, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x36, 0x02, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x36, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x00, 0x06, 0x12, 0x03, 0x36, 0x0b, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x36, 0x19, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x36, 0x25, 0x26, 0x0a, 0x5b, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x3a, 0x00, 0x53, 0x01, 0x1a,
+    0x4f, 0x20, 0x41, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20,
+    0x61, 0x6e, 0x79, 0x20, 0x61, 0x73, 0x73, 0x65, 0x74, 0x2c, 0x20, 0x6d, 0x69, 0x6e, 0x74, 0x73,
+    0x20, 0x63, 0x6f, 0x6d, 0x65, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x7a,
+    0x65, 0x72, 0x6f, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20,
+    0x62, 0x75, 0x72, 0x6e, 0x73, 0x20, 0x67, 0x6f, 0x20, 0x74, 0x6f, 0x20, 0x69, 0x74, 0x2e, 0x0a,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x3a, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x06, 0x04, 0x00, 0x12, 0x04, 0x3b, 0x02, 0x44, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x04, 0x00, 0x01, 0x12, 0x03, 0x3b, 0x07, 0x0f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x06, 0x04, 0x00,
+    0x02, 0x00, 0x12, 0x03, 0x3c, 0x04, 0x1d, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06, 0x04, 0x00, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x3c, 0x04, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06, 0x04, 0x00, 0x02,
+    0x00, 0x02, 0x12, 0x03, 0x3c, 0x1b, 0x1c, 0x0a, 0x53, 0x0a, 0x06, 0x04, 0x06, 0x04, 0x00, 0x02,
+    0x01, 0x12, 0x03, 0x3e, 0x04, 0x18, 0x1a, 0x44, 0x20, 0x45, 0x54, 0x48, 0x20, 0x73, 0x65, 0x6e,
+    0x74, 0x20, 0x61, 0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2c, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x69, 0x74, 0x73, 0x65, 0x6c, 0x66, 0x20, 0x6f, 0x72, 0x20, 0x61, 0x6e, 0x20, 0x69, 0x6e, 0x74,
+    0x65, 0x72, 0x6e, 0x61, 0x6c, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x06, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3e, 0x04, 0x13, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x06, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x3e, 0x16, 0x17, 0x0a, 0x0d, 0x0a, 0x06,
+    0x04, 0x06, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x3f, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x06, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3f, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x06, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x3f, 0x15, 0x16, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x06, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x40, 0x04, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06,
+    0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x40, 0x04, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06,
+    0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x40, 0x16, 0x17, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x06,
+    0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x41, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06, 0x04,
+    0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x41, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06, 0x04,
+    0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x41, 0x17, 0x18, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x06, 0x04,
+    0x00, 0x02, 0x05, 0x12, 0x03, 0x42, 0x04, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06, 0x04, 0x00,
+    0x02, 0x05, 0x01, 0x12, 0x03, 0x42, 0x04, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06, 0x04, 0x00,
+    0x02, 0x05, 0x02, 0x12, 0x03, 0x42, 0x16, 0x17, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x06, 0x04, 0x00,
+    0x02, 0x06, 0x12, 0x03, 0x43, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06, 0x04, 0x00, 0x02,
+    0x06, 0x01, 0x12, 0x03, 0x43, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x06, 0x04, 0x00, 0x02,
+    0x06, 0x02, 0x12, 0x03, 0x43, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12,
+    0x03, 0x45, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06, 0x12, 0x03, 0x45,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x45, 0x0b, 0x13,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x45, 0x16, 0x17, 0x0a, 0x2d,
+    0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x47, 0x02, 0x15, 0x1a, 0x20, 0x20, 0x54, 0x6f,
+    0x6b, 0x65, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2c, 0x20, 0x65, 0x6d,
+    0x70, 0x74, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x45, 0x54, 0x48, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x47, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x47, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x47, 0x13, 0x14, 0x0a, 0x59, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12,
+    0x03, 0x49, 0x02, 0x16, 0x1a, 0x4c, 0x20, 0x44, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x45, 0x52, 0x43, 0x37, 0x32,
+    0x31, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x45, 0x52, 0x43, 0x31, 0x31, 0x35, 0x35, 0x20, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x66, 0x75, 0x6e, 0x67, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x73,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x49, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x49, 0x09, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x49, 0x14, 0x15, 0x0a, 0x64, 0x0a, 0x04,
+    0x04, 0x06, 0x02, 0x03, 0x12, 0x03, 0x4b, 0x02, 0x14, 0x1a, 0x57, 0x20, 0x41, 0x6d, 0x6f, 0x75,
+    0x6e, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x65,
+    0x73, 0x74, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61,
+    0x73, 0x73, 0x65, 0x74, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61,
+    0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2c, 0x20, 0x31, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x05, 0x12, 0x03, 0x4b, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03, 0x4b, 0x09, 0x0f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x4b, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x06, 0x02, 0x04, 0x12, 0x03, 0x4c, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x04, 0x05, 0x12, 0x03, 0x4c, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x01,
+    0x12, 0x03, 0x4c, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x03, 0x12, 0x03,
+    0x4c, 0x0f, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x05, 0x12, 0x03, 0x4d, 0x02, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x05, 0x12, 0x03, 0x4d, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x01, 0x12, 0x03, 0x4d, 0x08, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x05, 0x03, 0x12, 0x03, 0x4d, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06,
+    0x02, 0x06, 0x12, 0x03, 0x4e, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x05,
+    0x12, 0x03, 0x4e, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x01, 0x12, 0x03,
+    0x4e, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x03, 0x12, 0x03, 0x4e, 0x13,
+    0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x07, 0x12, 0x03, 0x4f, 0x02, 0x15, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x05, 0x12, 0x03, 0x4f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x07, 0x01, 0x12, 0x03, 0x4f, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x07, 0x03, 0x12, 0x03, 0x4f, 0x13, 0x14, 0x0a, 0x9d, 0x01, 0x0a, 0x04, 0x04, 0x06, 0x02,
+    0x08, 0x12, 0x03, 0x52, 0x02, 0x10, 0x1a, 0x8f, 0x01, 0x20, 0x60, 0x3c, 0x74, 0x72, 0x78, 0x5f,
+    0x68, 0x61, 0x73, 0x68, 0x3e, 0x2d, 0x3c, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x3e, 0x60,
+    0x2c, 0x20, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x60, 0x2d,
+    0x3c, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x3e, 0x60, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x45, 0x52, 0x43, 0x31, 0x31,
+    0x35, 0x35, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2c, 0x20, 0x75, 0x6e,
+    0x69, 0x71, 0x75, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20,
+    0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x79, 0x73, 0x0a, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x73, 0x69, 0x6e, 0x6b, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x75, 0x70, 0x73,
+    0x65, 0x72, 0x74, 0x20, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08,
+    0x05, 0x12, 0x03, 0x52, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x01, 0x12,
+    0x03, 0x52, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x03, 0x12, 0x03, 0x52,
+    0x0e, 0x0f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x55, 0x00, 0x57, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x55, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07,
+    0x02, 0x00, 0x12, 0x03, 0x56, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04,
+    0x12, 0x03, 0x56, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x06, 0x12, 0x03,
+    0x56, 0x0b, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x56, 0x1a,
+    0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x56, 0x26, 0x27, 0x0a,
+    0x44, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x5a, 0x00, 0x63, 0x01, 0x1a, 0x38, 0x20, 0x42, 0x61,
+    0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x65,
+    0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x65, 0x6e, 0x74, 0x20, 0x62, 0x65, 0x6c, 0x6f,
+    0x77, 0x20, 0x7a, 0x65, 0x72, 0x6f, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x5a, 0x08,
+    0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x5b, 0x02, 0x13, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x5b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5b, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x5b, 0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01,
+    0x12, 0x03, 0x5c, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x06, 0x12, 0x03,
+    0x5c, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5c, 0x13,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5c, 0x1e, 0x1f, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x03, 0x5d, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x5d, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x5d, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x5d, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03,
+    0x5e, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x05, 0x12, 0x03, 0x5e, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x03, 0x5e, 0x09, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x03, 0x5e, 0x14, 0x15, 0x0a, 0x74, 0x0a,
+    0x04, 0x04, 0x08, 0x02, 0x04, 0x12, 0x03, 0x60, 0x02, 0x1e, 0x1a, 0x67, 0x20, 0x43, 0x6f, 0x6d,
+    0x70, 0x75, 0x74, 0x65, 0x64, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x62, 0x65,
+    0x66, 0x6f, 0x72, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x64, 0x65,
+    0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x2e, 0x20, 0x45,
+    0x52, 0x43, 0x32, 0x30, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x73, 0x20, 0x61, 0x72,
+    0x65, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68, 0x6f, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x60, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x60, 0x09, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x03, 0x12, 0x03, 0x60, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x08, 0x02, 0x05, 0x12, 0x03, 0x61, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x05, 0x05, 0x12, 0x03, 0x61, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05,
+    0x01, 0x12, 0x03, 0x61, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x03, 0x12,
+    0x03, 0x61, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x06, 0x12, 0x03, 0x62, 0x02,
+    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x05, 0x12, 0x03, 0x62, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x01, 0x12, 0x03, 0x62, 0x09, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x06, 0x03, 0x12, 0x03, 0x62, 0x13, 0x14, 0x62, 0x06, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x33,
+];
+// @@protoc_insertion_point(module)
\ No newline at end of file
@@ -1,8 +1,55 @@
+#[path = "eth.alerts.v1.rs"]
+#[allow(dead_code)]
+pub mod alerts;
+
+#[path = "eth.blocks.v1.rs"]
+#[allow(dead_code)]
+pub mod blocks;
+
+#[path = "eth.debug.v1.rs"]
+#[allow(dead_code)]
+pub mod debug;
+
+#[path = "eth.dex.v1.rs"]
+#[allow(dead_code)]
+pub mod dex;
+
+#[path = "eth.documents.v1.rs"]
+#[allow(dead_code)]
+pub mod documents;
+
 #[path = "eth.erc721.v1.rs"]
 #[allow(dead_code)]
 pub mod erc721;
 
+#[path = "eth.metrics.v1.rs"]
+#[allow(dead_code)]
+pub mod metrics;
+
+#[path = "eth.portfolio.v1.rs"]
+#[allow(dead_code)]
+pub mod portfolio;
+
+#[path = "eth.staking.v1.rs"]
+#[allow(dead_code)]
+pub mod staking;
+
+#[path = "eth.traces.v1.rs"]
+#[allow(dead_code)]
+pub mod traces;
+
 #[path = "sf.ethereum.tokens.v1.rs"]
 #[allow(dead_code)]
 pub mod tokens;
 
+#[path = "sf.substreams.index.v1.rs"]
+#[allow(dead_code)]
+pub mod index;
+
+#[path = "sf.substreams.sink.database.v1.rs"]
+#[allow(dead_code)]
+pub mod database;
+
+#[path = "substreams.entity.v1.rs"]
+#[allow(dead_code)]
+pub mod entity;
@@ -3,9 +3,720 @@
 pub struct Tokens {
     #[prost(message, repeated, tag="1")]
     pub tokens: ::prost::alloc::vec::Vec<Token>,
+    /// RPC calls made while probing contracts in the block, and how many of them failed.
+    #[prost(uint64, tag="2")]
+    pub rpc_calls: u64,
+    #[prost(uint64, tag="3")]
+    pub rpc_failures: u64,
+    /// Candidates of the block that turned out not to be tokens, only set by map_tokens.
+    #[prost(message, repeated, tag="4")]
+    pub rejections: ::prost::alloc::vec::Vec<TokenRejection>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Token {
+    #[prost(bytes="vec", tag="1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub decimals: u64,
+    /// Only set on the records emitted by map_launch_quality, once the launch window of the token closes.
+    #[prost(message, optional, tag="5")]
+    pub launch: ::core::option::Option<LaunchQuality>,
+    /// Transaction sender that created the token, empty for hydrated tokens.
+    #[prost(bytes="vec", tag="6")]
+    pub deployer: ::prost::alloc::vec::Vec<u8>,
+    /// Only set on the records emitted by map_token_reputation.
+    #[prost(message, optional, tag="7")]
+    pub deployer_reputation: ::core::option::Option<DeployerReputation>,
+    /// Only set on the records emitted by map_transfer_taxes, for fee-on-transfer and reflection tokens.
+    #[prost(bool, tag="8")]
+    pub has_transfer_tax: bool,
+    /// Highest share of a transfer taken as a fee in the block, in percent.
+    #[prost(double, tag="9")]
+    pub transfer_tax_rate: f64,
+    /// From 0 to 100, how likely the token is spam. map_tokens and map_hydrated_tokens score the name and
+    /// symbol, map_spam_scores adds the liquidity and airdrop signals.
+    #[prost(double, tag="10")]
+    pub spam_score: f64,
+    /// impersonation, unicode, url, no_liquidity or airdrop.
+    #[prost(string, repeated, tag="11")]
+    pub spam_signals: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Bytes returned by `name()` and `symbol()`, only set when `name` or `symbol` had to be sanitized. The
+    /// sanitized values are NFC normalized, without control characters and at most 64 characters long.
+    #[prost(bytes="vec", tag="12")]
+    pub name_raw_bytes: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="13")]
+    pub symbol_raw_bytes: ::prost::alloc::vec::Vec<u8>,
+    /// Set when `name_raw_bytes` or `symbol_raw_bytes` is not valid UTF-8, `name` or `symbol` then holds the hex
+    /// of the raw bytes.
+    #[prost(bool, tag="14")]
+    pub is_lossy: bool,
+    /// ERC-7572 `contractURI()` of the token, and the logo its metadata points to when it is inlined as a
+    /// `data:` URI. Metadata hosted off-chain is left for consumers to resolve.
+    #[prost(string, tag="15")]
+    pub contract_uri: ::prost::alloc::string::String,
+    #[prost(string, tag="16")]
+    pub logo_uri: ::prost::alloc::string::String,
+    /// keccak256 of the code the token was created with, without its compiler metadata. Clones of a token template
+    /// share it, see store_bytecode_families. Empty for tokens initialized through a proxy and hydrated tokens.
+    #[prost(bytes="vec", tag="17")]
+    pub code_hash: ::prost::alloc::vec::Vec<u8>,
+    /// Name of the token template whose selectors the code matches, such as `openzeppelin_erc20`, see
+    /// `bytecode::TEMPLATES`. store_token_templates marks the tokens of trusted templates `known`, which lowers their spam
+    /// score in map_spam_scores.
+    #[prost(string, tag="18")]
+    pub template: ::prost::alloc::string::String,
+    /// The code dispatches a function minting any amount to any address (`mint(address,uint256)`, `issue(uint256)`,
+    /// ...) or blacklisting addresses (`blacklist(address)`, `addBlackList(address)`, ...). Found from the selectors
+    /// of the code, whether they are restricted to an owner is not checked. Only set with `code_hash`.
+    #[prost(bool, tag="19")]
+    pub can_mint_arbitrarily: bool,
+    #[prost(bool, tag="20")]
+    pub has_blacklist: bool,
+    /// Block in which the owner of the token renounced its ownership, transferring it to the null address. 0 while
+    /// the token has an owner. Only set on the records emitted by map_token_reputation and map_spam_scores.
+    #[prost(uint64, tag="21")]
+    pub ownership_renounced_at_block: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PresaleEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<PresaleEvent>,
+}
+/// Contribution to a launchpad presale, its refund, cancellation or finalization.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PresaleEvent {
+    #[prost(enumeration="presale_event::Kind", tag="1")]
+    pub kind: i32,
+    #[prost(bytes="vec", tag="2")]
+    pub presale: ::prost::alloc::vec::Vec<u8>,
+    /// Contributor for `KIND_CONTRIBUTION` and `KIND_REFUND`, the caller for `KIND_CANCELLATION`, the token of the
+    /// presale for `KIND_FINALIZATION`.
+    #[prost(bytes="vec", tag="3")]
+    pub account: ::prost::alloc::vec::Vec<u8>,
+    /// ETH contributed, refunded, or added to the pool at finalization, in wei as a decimal string.
+    #[prost(string, tag="4")]
+    pub amount: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="5")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    /// `<trx_hash>-call-<call_index>`, stable across replays for sinks to upsert on.
+    #[prost(string, tag="7")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `PresaleEvent`.
+pub mod presale_event {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        Unspecified = 0,
+        /// ETH paid to the presale contract.
+        Contribution = 1,
+        /// The presale added its raise to a pool of its token.
+        Finalization = 2,
+        /// ETH the presale paid back to a contributor.
+        Refund = 3,
+        /// The owner of the presale cancelled it.
+        Cancellation = 4,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Kind::Unspecified => "KIND_UNSPECIFIED",
+                Kind::Contribution => "KIND_CONTRIBUTION",
+                Kind::Finalization => "KIND_FINALIZATION",
+                Kind::Refund => "KIND_REFUND",
+                Kind::Cancellation => "KIND_CANCELLATION",
+            }
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Presales {
+    #[prost(message, repeated, tag="1")]
+    pub presales: ::prost::alloc::vec::Vec<Presale>,
+}
+/// Presale of a discovered token, emitted when it finalizes.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Presale {
+    #[prost(bytes="vec", tag="1")]
+    pub presale: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    /// ETH raised over the presale less the refunds, in wei as a decimal string.
+    #[prost(string, tag="3")]
+    pub raised: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub participants: u64,
+    #[prost(uint64, tag="5")]
+    pub contributions: u64,
+    /// ETH added to the pool of the token at finalization, in wei as a decimal string.
+    #[prost(string, tag="6")]
+    pub liquidity: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="7")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    /// ETH refunded to contributors before the finalization, in wei as a decimal string.
+    #[prost(string, tag="8")]
+    pub refunded: ::prost::alloc::string::String,
+}
+/// Token candidates of map_tokens that were rejected, to audit the discovery heuristics.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenRejections {
+    #[prost(message, repeated, tag="1")]
+    pub rejections: ::prost::alloc::vec::Vec<TokenRejection>,
+    #[prost(uint64, tag="2")]
+    pub rpc_calls: u64,
+    #[prost(uint64, tag="3")]
+    pub rpc_failures: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenRejection {
+    #[prost(bytes="vec", tag="1")]
+    pub address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(enumeration="token_rejection::Reason", tag="2")]
+    pub reason: i32,
+    /// Probe that failed, unspecified when the candidate was rejected before probing.
+    #[prost(enumeration="token_rejection::Probe", tag="3")]
+    pub probe: i32,
+    /// Revert data, decoding error or code size behind the rejection.
+    #[prost(string, tag="4")]
+    pub detail: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="5")]
+    pub caller: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+}
+/// Nested message and enum types in `TokenRejection`.
+pub mod token_rejection {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Reason {
+        Unspecified = 0,
+        /// Created contract with too little code to be a token.
+        CodeTooSmall = 1,
+        /// Created by a caller known to deploy non token contracts.
+        KnownCaller = 2,
+        /// A probe reverted.
+        CallFailed = 3,
+        /// A probe returned data that does not decode.
+        DecodeFailed = 4,
+    }
+    impl Reason {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Reason::Unspecified => "REASON_UNSPECIFIED",
+                Reason::CodeTooSmall => "REASON_CODE_TOO_SMALL",
+                Reason::KnownCaller => "REASON_KNOWN_CALLER",
+                Reason::CallFailed => "REASON_CALL_FAILED",
+                Reason::DecodeFailed => "REASON_DECODE_FAILED",
+            }
+        }
+    }
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Probe {
+        Unspecified = 0,
+        Decimals = 1,
+        Name = 2,
+        Symbol = 3,
+    }
+    impl Probe {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Probe::Unspecified => "PROBE_UNSPECIFIED",
+                Probe::Decimals => "PROBE_DECIMALS",
+                Probe::Name => "PROBE_NAME",
+                Probe::Symbol => "PROBE_SYMBOL",
+            }
+        }
+    }
+}
+/// Track record of a deployer before the token was discovered.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeployerReputation {
+    /// Tokens deployed before this one.
+    #[prost(uint64, tag="1")]
+    pub deployed: u64,
+    /// How many of them got more than 90% of their liquidity removed.
+    #[prost(uint64, tag="2")]
+    pub rugged: u64,
+    /// From 0 (every token rugged) to 100 (none rugged, or no previous token).
+    #[prost(double, tag="3")]
+    pub score: f64,
+}
+/// How concentrated the buying of a token was right after its first DEX pool got created.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LaunchQuality {
+    #[prost(uint64, tag="1")]
+    pub launch_block: u64,
+    /// Blocks after `launch_block` whose buys are counted.
+    #[prost(uint64, tag="2")]
+    pub window: u64,
+    #[prost(uint64, tag="3")]
+    pub buyers: u64,
+    /// Percentage of the supply bought within the window by the largest buyers.
+    #[prost(double, tag="4")]
+    pub top_buyers_share: f64,
+    /// Whether `top_buyers_share` is above the sniping threshold.
+    #[prost(bool, tag="5")]
+    pub sniped: bool,
+    /// From 0 (supply cornered) to 100 (nothing bought by the largest buyers).
+    #[prost(double, tag="6")]
+    pub score: f64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transfers {
+    #[prost(message, repeated, tag="1")]
+    pub transfers: ::prost::alloc::vec::Vec<Transfer>,
+    /// Per token totals of the block, set by map_sampled_erc20_transfers when its `mode` is `sample` or `aggregate`.
+    #[prost(message, repeated, tag="2")]
+    pub aggregates: ::prost::alloc::vec::Vec<TransferAggregate>,
+    /// Duplicate Transfer events of a single movement left out, see `erc20::decode`.
+    #[prost(uint64, tag="3")]
+    pub duplicate_suppressed: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferAggregate {
+    #[prost(bytes="vec", tag="1")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="2")]
+    pub transfer_count: u64,
+    /// Amount moved by the transfers, in the smallest unit of the token, as a decimal string.
+    #[prost(string, tag="3")]
+    pub volume: ::prost::alloc::string::String,
+    /// Net balance change of every address involved, the zero address included so mints and burns add up.
+    #[prost(message, repeated, tag="4")]
+    pub changes: ::prost::alloc::vec::Vec<NetChange>,
+    /// Ordinal of the last transfer of the token in the block.
+    #[prost(uint64, tag="5")]
+    pub ordinal: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NetChange {
+    #[prost(bytes="vec", tag="1")]
+    pub holder: ::prost::alloc::vec::Vec<u8>,
+    /// Signed amount in the smallest unit of the token, as a decimal string.
+    #[prost(string, tag="2")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// A fungible token transfer, mints come from the zero address and burns go to it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transfer {
+    #[prost(enumeration="transfer::Standard", tag="1")]
+    pub standard: i32,
+    #[prost(bytes="vec", tag="2")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub from: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub to: ::prost::alloc::vec::Vec<u8>,
+    /// Amount in the smallest unit of the token, as a decimal string.
+    #[prost(string, tag="5")]
+    pub amount: ::prost::alloc::string::String,
+    /// ERC777 operator that moved the tokens, empty for the other standards.
+    #[prost(bytes="vec", tag="6")]
+    pub operator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="7")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub ordinal: u64,
+    /// `<trx_hash>-<block_index>` with the log's index in the block, stable across replays for sinks to upsert on.
+    #[prost(string, tag="9")]
+    pub id: ::prost::alloc::string::String,
+    /// Decimals of the token from store_token_decimals, only set by map_scaled_erc20_transfers.
+    #[prost(uint32, tag="10")]
+    pub decimals: u32,
+    /// `amount` in whole tokens scaled by `decimals`, only set by map_scaled_erc20_transfers for the tokens whose
+    /// decimals are known.
+    #[prost(string, tag="11")]
+    pub value: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `Transfer`.
+pub mod transfer {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Standard {
+        Unspecified = 0,
+        Erc20 = 1,
+        /// `Sent`, `Minted` and `Burned` events, the Transfer events emitted alongside them are skipped.
+        Erc777 = 2,
+        /// Transfer events emitted by a `transferAndCall` or `transferFromAndCall` call.
+        Erc1363 = 3,
+    }
+    impl Standard {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Standard::Unspecified => "STANDARD_UNSPECIFIED",
+                Standard::Erc20 => "STANDARD_ERC20",
+                Standard::Erc777 => "STANDARD_ERC777",
+                Standard::Erc1363 => "STANDARD_ERC1363",
+            }
+        }
+    }
+}
+/// Transfers of a block as parallel arrays, entry `i` of every column describes the same transfer.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferColumns {
+    /// Distinct tokens and transactions of the block, referred to by position.
+    #[prost(bytes="vec", repeated, tag="1")]
+    pub tokens: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", repeated, tag="2")]
+    pub trx_hashes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint32, repeated, tag="3")]
+    pub token_index: ::prost::alloc::vec::Vec<u32>,
+    #[prost(uint32, repeated, tag="4")]
+    pub trx_index: ::prost::alloc::vec::Vec<u32>,
+    #[prost(enumeration="transfer::Standard", repeated, tag="5")]
+    pub standard: ::prost::alloc::vec::Vec<i32>,
+    #[prost(bytes="vec", repeated, tag="6")]
+    pub from: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(bytes="vec", repeated, tag="7")]
+    pub to: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(string, repeated, tag="8")]
+    pub amount: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(bytes="vec", repeated, tag="9")]
+    pub operator: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(uint64, repeated, tag="10")]
+    pub ordinal: ::prost::alloc::vec::Vec<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GovernanceEvents {
+    #[prost(message, repeated, tag="1")]
+    pub delegations: ::prost::alloc::vec::Vec<Delegation>,
+    #[prost(message, repeated, tag="2")]
+    pub checkpoints: ::prost::alloc::vec::Vec<VotingCheckpoint>,
+}
+/// ERC20Votes `DelegateChanged`: the delegator moved its voting power to another delegate.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Delegation {
+    #[prost(bytes="vec", tag="1")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub delegator: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub from_delegate: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="4")]
+    pub to_delegate: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="5")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    /// `<trx_hash>-<block_index>` with the log's index in the block, stable across replays for sinks to upsert on.
+    #[prost(string, tag="7")]
+    pub id: ::prost::alloc::string::String,
+}
+/// ERC20Votes `DelegateVotesChanged`: voting power of a delegate from the block on, as read by snapshot-style tooling.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VotingCheckpoint {
+    #[prost(bytes="vec", tag="1")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub delegate: ::prost::alloc::vec::Vec<u8>,
+    /// Voting power before and after the change, in the smallest unit of the token, as decimal strings.
+    #[prost(string, tag="3")]
+    pub previous_votes: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub votes: ::prost::alloc::string::String,
+    #[prost(uint64, tag="5")]
+    pub block: u64,
+    #[prost(bytes="vec", tag="6")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub ordinal: u64,
+    /// `<trx_hash>-<block_index>` with the log's index in the block, stable across replays for sinks to upsert on.
+    #[prost(string, tag="8")]
+    pub id: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<ProposalEvent>,
+}
+/// Governor Bravo or OpenZeppelin Governor proposal event, fields not logged by the kind of event are left empty.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalEvent {
+    #[prost(enumeration="proposal_event::Kind", tag="1")]
+    pub kind: i32,
+    #[prost(bytes="vec", tag="2")]
+    pub governor: ::prost::alloc::vec::Vec<u8>,
+    /// Decimal proposal id, OpenZeppelin Governor ids are hashes that don't fit in 64 bits.
+    #[prost(string, tag="3")]
+    pub proposal_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="4")]
+    pub proposer: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", repeated, tag="5")]
+    pub targets: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+    #[prost(string, tag="6")]
+    pub description: ::prost::alloc::string::String,
+    /// Voting period, in blocks for most governors and in seconds for timestamp based ones.
+    #[prost(uint64, tag="7")]
+    pub start_block: u64,
+    #[prost(uint64, tag="8")]
+    pub end_block: u64,
+    /// Timestamp the queued proposal can be executed at.
+    #[prost(uint64, tag="9")]
+    pub eta: u64,
+    #[prost(bytes="vec", tag="10")]
+    pub voter: ::prost::alloc::vec::Vec<u8>,
+    /// 0 against, 1 for, 2 abstain.
+    #[prost(uint32, tag="11")]
+    pub support: u32,
+    /// Votes cast, in the smallest unit of the governance token, as a decimal string.
+    #[prost(string, tag="12")]
+    pub weight: ::prost::alloc::string::String,
+    #[prost(string, tag="13")]
+    pub reason: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="14")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="15")]
+    pub ordinal: u64,
+    /// `<trx_hash>-<block_index>` with the log's index in the block, stable across replays for sinks to upsert on.
+    #[prost(string, tag="16")]
+    pub id: ::prost::alloc::string::String,
+    /// Params of an OpenZeppelin `VoteCastWithParams`, passed to the counting module of the governor.
+    #[prost(bytes="vec", tag="17")]
+    pub params: ::prost::alloc::vec::Vec<u8>,
+}
+/// Nested message and enum types in `ProposalEvent`.
+pub mod proposal_event {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        Unspecified = 0,
+        Created = 1,
+        Queued = 2,
+        Executed = 3,
+        Canceled = 4,
+        VoteCast = 5,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Kind::Unspecified => "KIND_UNSPECIFIED",
+                Kind::Created => "KIND_CREATED",
+                Kind::Queued => "KIND_QUEUED",
+                Kind::Executed => "KIND_EXECUTED",
+                Kind::Canceled => "KIND_CANCELED",
+                Kind::VoteCast => "KIND_VOTE_CAST",
+            }
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VestingEvents {
+    #[prost(message, repeated, tag="1")]
+    pub events: ::prost::alloc::vec::Vec<VestingEvent>,
+}
+/// Sablier V1 stream or OpenZeppelin VestingWallet event moving discovered tokens in or out of a vesting contract,
+/// fields not logged by the kind of event are left empty.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VestingEvent {
+    #[prost(enumeration="vesting_event::Kind", tag="1")]
+    pub kind: i32,
+    /// Sablier contract or vesting wallet.
+    #[prost(bytes="vec", tag="2")]
+    pub contract: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    /// Decimal stream id, empty for vesting wallets.
+    #[prost(string, tag="4")]
+    pub stream_id: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="5")]
+    pub sender: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub recipient: ::prost::alloc::vec::Vec<u8>,
+    /// Deposit, withdrawal, balance left at cancellation, funding or release, in the smallest unit of the token, as a
+    /// decimal string.
+    #[prost(string, tag="7")]
+    pub amount: ::prost::alloc::string::String,
+    /// Streaming period of the deposit, in seconds.
+    #[prost(uint64, tag="8")]
+    pub start_time: u64,
+    #[prost(uint64, tag="9")]
+    pub stop_time: u64,
+    #[prost(bytes="vec", tag="10")]
+    pub trx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="11")]
+    pub ordinal: u64,
+    /// `<trx_hash>-<block_index>` with the log's index in the block, stable across replays for sinks to upsert on.
+    #[prost(string, tag="12")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `VestingEvent`.
+pub mod vesting_event {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        Unspecified = 0,
+        StreamCreated = 1,
+        StreamWithdrawal = 2,
+        StreamCanceled = 3,
+        WalletFunded = 4,
+        WalletRelease = 5,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Kind::Unspecified => "KIND_UNSPECIFIED",
+                Kind::StreamCreated => "KIND_STREAM_CREATED",
+                Kind::StreamWithdrawal => "KIND_STREAM_WITHDRAWAL",
+                Kind::StreamCanceled => "KIND_STREAM_CANCELED",
+                Kind::WalletFunded => "KIND_WALLET_FUNDED",
+                Kind::WalletRelease => "KIND_WALLET_RELEASE",
+            }
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CirculatingSupplies {
+    #[prost(message, repeated, tag="1")]
+    pub supplies: ::prost::alloc::vec::Vec<CirculatingSupply>,
+}
+/// Supply of a discovered token whose balances moved in the block, amounts in the smallest unit of the token as decimal
+/// strings. `circulating` is `total_supply` less `burned`, `escrowed` and `locked`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CirculatingSupply {
+    #[prost(bytes="vec", tag="1")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="2")]
+    pub block: u64,
+    /// Minted less the amounts sent to the zero address.
+    #[prost(string, tag="3")]
+    pub total_supply: ::prost::alloc::string::String,
+    /// Held by the dead address and the `burn_addresses`.
+    #[prost(string, tag="4")]
+    pub burned: ::prost::alloc::string::String,
+    /// Held by the bridge `escrows`.
+    #[prost(string, tag="5")]
+    pub escrowed: ::prost::alloc::string::String,
+    /// Locked in Sablier streams and vesting wallets.
+    #[prost(string, tag="6")]
+    pub locked: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub circulating: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExchangeFlows {
+    #[prost(message, repeated, tag="1")]
+    pub flows: ::prost::alloc::vec::Vec<ExchangeFlow>,
+}
+/// Amount of a token moved in and out of the wallets of a centralized exchange in the block, in the smallest unit of
+/// the token as decimal strings.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExchangeFlow {
+    #[prost(uint64, tag="1")]
+    pub block: u64,
+    #[prost(bytes="vec", tag="2")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="3")]
+    pub exchange: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub inflow: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub outflow: ::prost::alloc::string::String,
+    /// Transfers to and from the exchange, left at zero when the transfers carry aggregates.
+    #[prost(uint64, tag="6")]
+    pub deposits: u64,
+    #[prost(uint64, tag="7")]
+    pub withdrawals: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FlowSignals {
+    #[prost(message, repeated, tag="1")]
+    pub signals: ::prost::alloc::vec::Vec<FlowSignal>,
+}
+/// Rolling net flow of a token into an exchange crossing its threshold, as of the block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FlowSignal {
+    #[prost(uint64, tag="1")]
+    pub block: u64,
+    #[prost(bytes="vec", tag="2")]
+    pub token: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag="3")]
+    pub exchange: ::prost::alloc::string::String,
+    /// `1h` or `24h`.
+    #[prost(string, tag="4")]
+    pub window: ::prost::alloc::string::String,
+    #[prost(enumeration="flow_signal::Direction", tag="5")]
+    pub direction: i32,
+    /// Signed net flow over the window, in the smallest unit of the token, as a decimal string.
+    #[prost(string, tag="6")]
+    pub net_flow: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub threshold: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `FlowSignal`.
+pub mod flow_signal {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Direction {
+        Unspecified = 0,
+        /// Net inflow rose to the threshold, tokens moving to the exchange, often to be sold.
+        Inflow = 1,
+        /// Net outflow rose to the threshold, tokens leaving the exchange.
+        Outflow = 2,
+    }
+    impl Direction {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Direction::Unspecified => "DIRECTION_UNSPECIFIED",
+                Direction::Inflow => "DIRECTION_INFLOW",
+                Direction::Outflow => "DIRECTION_OUTFLOW",
+            }
+        }
+    }
+}
+/// Discovered tokens as a token list (<https://tokenlists.org>) JSON document, without logos.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenList {
+    #[prost(uint64, tag="1")]
+    pub block: u64,
+    /// Empty outside of the blocks the list is emitted at.
+    #[prost(string, tag="2")]
+    pub json: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub token_count: u64,
+}
+/// Shape of `Token` before addresses moved to bytes, with the address as a lowercase hex string.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LegacyTokens {
+    #[prost(message, repeated, tag="1")]
+    pub tokens: ::prost::alloc::vec::Vec<LegacyToken>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LegacyToken {
     #[prost(string, tag="1")]
     pub address: ::prost::alloc::string::String,
     #[prost(string, tag="2")]
@@ -17,42 +728,1695 @@ pub struct Token {
 }
 /// Encoded file descriptor set for the `sf.ethereum.tokens.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xc8, 0x04, 0x0a, 0x0c, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74,
-    0x6f, 0x12, 0x15, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74,
-    0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x22, 0x3e, 0x0a, 0x06, 0x54, 0x6f, 0x6b, 0x65,
-    0x6e, 0x73, 0x12, 0x34, 0x0a, 0x06, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03,
-    0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d,
-    0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e,
-    0x52, 0x06, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x22, 0x69, 0x0a, 0x05, 0x54, 0x6f, 0x6b, 0x65,
-    0x6e, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01,
-    0x28, 0x09, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x6e,
-    0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12,
-    0x16, 0x0a, 0x06, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52,
-    0x06, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x12, 0x1a, 0x0a, 0x08, 0x64, 0x65, 0x63, 0x69, 0x6d,
-    0x61, 0x6c, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x64, 0x65, 0x63, 0x69, 0x6d,
-    0x61, 0x6c, 0x73, 0x4a, 0xed, 0x02, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x0d, 0x01, 0x0a, 0x08,
-    0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02,
-    0x00, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x04, 0x00, 0x06, 0x01, 0x0a, 0x0a,
-    0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
-    0x02, 0x00, 0x12, 0x03, 0x05, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04,
-    0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03,
-    0x05, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x05, 0x11,
-    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x05, 0x1a, 0x1b, 0x0a,
-    0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x08, 0x00, 0x0d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
-    0x01, 0x01, 0x12, 0x03, 0x08, 0x08, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12,
-    0x03, 0x09, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x09,
-    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x09, 0x09, 0x10,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x09, 0x13, 0x14, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0a, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0a, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x0a, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x0a, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x0b,
-    0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0b, 0x02, 0x08,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0b, 0x09, 0x0f, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0b, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x0c, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x03, 0x05, 0x12, 0x03, 0x0c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01,
-    0x12, 0x03, 0x0c, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03,
-    0x0c, 0x14, 0x15, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x0a, 0x98, 0xd3, 0x01, 0x0a, 0x0c, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x12, 0x15, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x22, 0x7e, 0x0a, 0x06, 0x54, 0x6f, 0x6b,
+    0x65, 0x6e, 0x73, 0x12, 0x34, 0x0a, 0x06, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x18, 0x01, 0x20,
+    0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75,
+    0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x6f, 0x6b, 0x65,
+    0x6e, 0x52, 0x06, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x12, 0x1b, 0x0a, 0x09, 0x72, 0x70, 0x63,
+    0x5f, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x72, 0x70,
+    0x63, 0x43, 0x61, 0x6c, 0x6c, 0x73, 0x12, 0x21, 0x0a, 0x0c, 0x72, 0x70, 0x63, 0x5f, 0x66, 0x61,
+    0x69, 0x6c, 0x75, 0x72, 0x65, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x72, 0x70,
+    0x63, 0x46, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x73, 0x22, 0xb1, 0x06, 0x0a, 0x05, 0x54, 0x6f,
+    0x6b, 0x65, 0x6e, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x12, 0x0a,
+    0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d,
+    0x65, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x06, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x12, 0x1a, 0x0a, 0x08, 0x64, 0x65, 0x63,
+    0x69, 0x6d, 0x61, 0x6c, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x64, 0x65, 0x63,
+    0x69, 0x6d, 0x61, 0x6c, 0x73, 0x12, 0x3c, 0x0a, 0x06, 0x6c, 0x61, 0x75, 0x6e, 0x63, 0x68, 0x18,
+    0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x24, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72,
+    0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x61,
+    0x75, 0x6e, 0x63, 0x68, 0x51, 0x75, 0x61, 0x6c, 0x69, 0x74, 0x79, 0x52, 0x06, 0x6c, 0x61, 0x75,
+    0x6e, 0x63, 0x68, 0x12, 0x1a, 0x0a, 0x08, 0x64, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65, 0x72, 0x18,
+    0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x64, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65, 0x72, 0x12,
+    0x5a, 0x0a, 0x13, 0x64, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65, 0x72, 0x5f, 0x72, 0x65, 0x70, 0x75,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x29, 0x2e, 0x73,
+    0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x73, 0x2e, 0x76, 0x31, 0x2e, 0x44, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65, 0x72, 0x52, 0x65, 0x70,
+    0x75, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x12, 0x64, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65,
+    0x72, 0x52, 0x65, 0x70, 0x75, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x28, 0x0a, 0x10, 0x68,
+    0x61, 0x73, 0x5f, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x5f, 0x74, 0x61, 0x78, 0x18,
+    0x08, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0e, 0x68, 0x61, 0x73, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x54, 0x61, 0x78, 0x12, 0x2a, 0x0a, 0x11, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65,
+    0x72, 0x5f, 0x74, 0x61, 0x78, 0x5f, 0x72, 0x61, 0x74, 0x65, 0x18, 0x09, 0x20, 0x01, 0x28, 0x01,
+    0x52, 0x0f, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x54, 0x61, 0x78, 0x52, 0x61, 0x74,
+    0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x70, 0x61, 0x6d, 0x5f, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x18,
+    0x0a, 0x20, 0x01, 0x28, 0x01, 0x52, 0x09, 0x73, 0x70, 0x61, 0x6d, 0x53, 0x63, 0x6f, 0x72, 0x65,
+    0x12, 0x21, 0x0a, 0x0c, 0x73, 0x70, 0x61, 0x6d, 0x5f, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x6c, 0x73,
+    0x18, 0x0b, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0b, 0x73, 0x70, 0x61, 0x6d, 0x53, 0x69, 0x67, 0x6e,
+    0x61, 0x6c, 0x73, 0x12, 0x24, 0x0a, 0x0e, 0x6e, 0x61, 0x6d, 0x65, 0x5f, 0x72, 0x61, 0x77, 0x5f,
+    0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0c, 0x6e, 0x61, 0x6d,
+    0x65, 0x52, 0x61, 0x77, 0x42, 0x79, 0x74, 0x65, 0x73, 0x12, 0x28, 0x0a, 0x10, 0x73, 0x79, 0x6d,
+    0x62, 0x6f, 0x6c, 0x5f, 0x72, 0x61, 0x77, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x0d, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x0e, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x52, 0x61, 0x77, 0x42, 0x79,
+    0x74, 0x65, 0x73, 0x12, 0x19, 0x0a, 0x08, 0x69, 0x73, 0x5f, 0x6c, 0x6f, 0x73, 0x73, 0x79, 0x18,
+    0x0e, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x69, 0x73, 0x4c, 0x6f, 0x73, 0x73, 0x79, 0x12, 0x21,
+    0x0a, 0x0c, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x75, 0x72, 0x69, 0x18, 0x0f,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x55, 0x72,
+    0x69, 0x12, 0x19, 0x0a, 0x08, 0x6c, 0x6f, 0x67, 0x6f, 0x5f, 0x75, 0x72, 0x69, 0x18, 0x10, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x07, 0x6c, 0x6f, 0x67, 0x6f, 0x55, 0x72, 0x69, 0x12, 0x1b, 0x0a, 0x09,
+    0x63, 0x6f, 0x64, 0x65, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x11, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x08, 0x63, 0x6f, 0x64, 0x65, 0x48, 0x61, 0x73, 0x68, 0x12, 0x1a, 0x0a, 0x08, 0x74, 0x65, 0x6d,
+    0x70, 0x6c, 0x61, 0x74, 0x65, 0x18, 0x12, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x74, 0x65, 0x6d,
+    0x70, 0x6c, 0x61, 0x74, 0x65, 0x12, 0x30, 0x0a, 0x14, 0x63, 0x61, 0x6e, 0x5f, 0x6d, 0x69, 0x6e,
+    0x74, 0x5f, 0x61, 0x72, 0x62, 0x69, 0x74, 0x72, 0x61, 0x72, 0x69, 0x6c, 0x79, 0x18, 0x13, 0x20,
+    0x01, 0x28, 0x08, 0x52, 0x12, 0x63, 0x61, 0x6e, 0x4d, 0x69, 0x6e, 0x74, 0x41, 0x72, 0x62, 0x69,
+    0x74, 0x72, 0x61, 0x72, 0x69, 0x6c, 0x79, 0x12, 0x23, 0x0a, 0x0d, 0x68, 0x61, 0x73, 0x5f, 0x62,
+    0x6c, 0x61, 0x63, 0x6b, 0x6c, 0x69, 0x73, 0x74, 0x18, 0x14, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0c,
+    0x68, 0x61, 0x73, 0x42, 0x6c, 0x61, 0x63, 0x6b, 0x6c, 0x69, 0x73, 0x74, 0x12, 0x3f, 0x0a, 0x1c,
+    0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x5f, 0x72, 0x65, 0x6e, 0x6f, 0x75, 0x6e,
+    0x63, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x15, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x19, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x52, 0x65, 0x6e,
+    0x6f, 0x75, 0x6e, 0x63, 0x65, 0x64, 0x41, 0x74, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x22, 0x4c, 0x0a,
+    0x0d, 0x50, 0x72, 0x65, 0x73, 0x61, 0x6c, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x12, 0x3b,
+    0x0a, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x23,
+    0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x72, 0x65, 0x73, 0x61, 0x6c, 0x65, 0x45, 0x76,
+    0x65, 0x6e, 0x74, 0x52, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x22, 0x99, 0x02, 0x0a, 0x0c,
+    0x50, 0x72, 0x65, 0x73, 0x61, 0x6c, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x3c, 0x0a, 0x04,
+    0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x28, 0x2e, 0x73, 0x66, 0x2e,
+    0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e,
+    0x76, 0x31, 0x2e, 0x50, 0x72, 0x65, 0x73, 0x61, 0x6c, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x2e,
+    0x4b, 0x69, 0x6e, 0x64, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x70, 0x72,
+    0x65, 0x73, 0x61, 0x6c, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x70, 0x72, 0x65,
+    0x73, 0x61, 0x6c, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x16,
+    0x0a, 0x06, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06,
+    0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61,
+    0x73, 0x68, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73,
+    0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x06, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x22, 0x4a, 0x0a, 0x04, 0x4b,
+    0x69, 0x6e, 0x64, 0x12, 0x14, 0x0a, 0x10, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50,
+    0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x15, 0x0a, 0x11, 0x4b, 0x49, 0x4e,
+    0x44, 0x5f, 0x43, 0x4f, 0x4e, 0x54, 0x52, 0x49, 0x42, 0x55, 0x54, 0x49, 0x4f, 0x4e, 0x10, 0x01,
+    0x12, 0x15, 0x0a, 0x11, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x46, 0x49, 0x4e, 0x41, 0x4c, 0x49, 0x5a,
+    0x41, 0x54, 0x49, 0x4f, 0x4e, 0x10, 0x02, 0x22, 0x46, 0x0a, 0x08, 0x50, 0x72, 0x65, 0x73, 0x61,
+    0x6c, 0x65, 0x73, 0x12, 0x3a, 0x0a, 0x08, 0x70, 0x72, 0x65, 0x73, 0x61, 0x6c, 0x65, 0x73, 0x18,
+    0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1e, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72,
+    0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x72,
+    0x65, 0x73, 0x61, 0x6c, 0x65, 0x52, 0x08, 0x70, 0x72, 0x65, 0x73, 0x61, 0x6c, 0x65, 0x73, 0x22,
+    0xd4, 0x01, 0x0a, 0x07, 0x50, 0x72, 0x65, 0x73, 0x61, 0x6c, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x70,
+    0x72, 0x65, 0x73, 0x61, 0x6c, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x70, 0x72,
+    0x65, 0x73, 0x61, 0x6c, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x16, 0x0a, 0x06, 0x72,
+    0x61, 0x69, 0x73, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x72, 0x61, 0x69,
+    0x73, 0x65, 0x64, 0x12, 0x22, 0x0a, 0x0c, 0x70, 0x61, 0x72, 0x74, 0x69, 0x63, 0x69, 0x70, 0x61,
+    0x6e, 0x74, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0c, 0x70, 0x61, 0x72, 0x74, 0x69,
+    0x63, 0x69, 0x70, 0x61, 0x6e, 0x74, 0x73, 0x12, 0x24, 0x0a, 0x0d, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x69, 0x62, 0x75, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0d,
+    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x1c, 0x0a,
+    0x09, 0x6c, 0x69, 0x71, 0x75, 0x69, 0x64, 0x69, 0x74, 0x79, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x09, 0x6c, 0x69, 0x71, 0x75, 0x69, 0x64, 0x69, 0x74, 0x79, 0x12, 0x19, 0x0a, 0x08, 0x74,
+    0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74,
+    0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x22, 0x98, 0x01, 0x0a, 0x0f, 0x54, 0x6f, 0x6b, 0x65, 0x6e,
+    0x52, 0x65, 0x6a, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x45, 0x0a, 0x0a, 0x72, 0x65,
+    0x6a, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x25,
+    0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x6a, 0x65,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0a, 0x72, 0x65, 0x6a, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x73, 0x12, 0x1b, 0x0a, 0x09, 0x72, 0x70, 0x63, 0x5f, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x72, 0x70, 0x63, 0x43, 0x61, 0x6c, 0x6c, 0x73, 0x12, 0x21,
+    0x0a, 0x0c, 0x72, 0x70, 0x63, 0x5f, 0x66, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65, 0x73, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x72, 0x70, 0x63, 0x46, 0x61, 0x69, 0x6c, 0x75, 0x72, 0x65,
+    0x73, 0x22, 0xdd, 0x03, 0x0a, 0x0e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x6a, 0x65, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x44,
+    0x0a, 0x06, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x2c,
+    0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x65, 0x6a, 0x65,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x52, 0x06, 0x72, 0x65,
+    0x61, 0x73, 0x6f, 0x6e, 0x12, 0x41, 0x0a, 0x05, 0x70, 0x72, 0x6f, 0x62, 0x65, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x0e, 0x32, 0x2b, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75,
+    0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x6f, 0x6b, 0x65,
+    0x6e, 0x52, 0x65, 0x6a, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x50, 0x72, 0x6f, 0x62, 0x65,
+    0x52, 0x05, 0x70, 0x72, 0x6f, 0x62, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x64, 0x65, 0x74, 0x61, 0x69,
+    0x6c, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x64, 0x65, 0x74, 0x61, 0x69, 0x6c, 0x12,
+    0x16, 0x0a, 0x06, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x06, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68,
+    0x61, 0x73, 0x68, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61,
+    0x73, 0x68, 0x22, 0x86, 0x01, 0x0a, 0x06, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x12, 0x16, 0x0a,
+    0x12, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46,
+    0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x19, 0x0a, 0x15, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f,
+    0x43, 0x4f, 0x44, 0x45, 0x5f, 0x54, 0x4f, 0x4f, 0x5f, 0x53, 0x4d, 0x41, 0x4c, 0x4c, 0x10, 0x01,
+    0x12, 0x17, 0x0a, 0x13, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x4b, 0x4e, 0x4f, 0x57, 0x4e,
+    0x5f, 0x43, 0x41, 0x4c, 0x4c, 0x45, 0x52, 0x10, 0x02, 0x12, 0x16, 0x0a, 0x12, 0x52, 0x45, 0x41,
+    0x53, 0x4f, 0x4e, 0x5f, 0x43, 0x41, 0x4c, 0x4c, 0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45, 0x44, 0x10,
+    0x03, 0x12, 0x18, 0x0a, 0x14, 0x52, 0x45, 0x41, 0x53, 0x4f, 0x4e, 0x5f, 0x44, 0x45, 0x43, 0x4f,
+    0x44, 0x45, 0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45, 0x44, 0x10, 0x04, 0x22, 0x54, 0x0a, 0x05, 0x50,
+    0x72, 0x6f, 0x62, 0x65, 0x12, 0x15, 0x0a, 0x11, 0x50, 0x52, 0x4f, 0x42, 0x45, 0x5f, 0x55, 0x4e,
+    0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x12, 0x0a, 0x0e, 0x50,
+    0x52, 0x4f, 0x42, 0x45, 0x5f, 0x44, 0x45, 0x43, 0x49, 0x4d, 0x41, 0x4c, 0x53, 0x10, 0x01, 0x12,
+    0x0e, 0x0a, 0x0a, 0x50, 0x52, 0x4f, 0x42, 0x45, 0x5f, 0x4e, 0x41, 0x4d, 0x45, 0x10, 0x02, 0x12,
+    0x10, 0x0a, 0x0c, 0x50, 0x52, 0x4f, 0x42, 0x45, 0x5f, 0x53, 0x59, 0x4d, 0x42, 0x4f, 0x4c, 0x10,
+    0x03, 0x22, 0x5e, 0x0a, 0x12, 0x44, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65, 0x72, 0x52, 0x65, 0x70,
+    0x75, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1a, 0x0a, 0x08, 0x64, 0x65, 0x70, 0x6c, 0x6f,
+    0x79, 0x65, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x64, 0x65, 0x70, 0x6c, 0x6f,
+    0x79, 0x65, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x72, 0x75, 0x67, 0x67, 0x65, 0x64, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x06, 0x72, 0x75, 0x67, 0x67, 0x65, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x73,
+    0x63, 0x6f, 0x72, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x01, 0x52, 0x05, 0x73, 0x63, 0x6f, 0x72,
+    0x65, 0x22, 0xba, 0x01, 0x0a, 0x0d, 0x4c, 0x61, 0x75, 0x6e, 0x63, 0x68, 0x51, 0x75, 0x61, 0x6c,
+    0x69, 0x74, 0x79, 0x12, 0x21, 0x0a, 0x0c, 0x6c, 0x61, 0x75, 0x6e, 0x63, 0x68, 0x5f, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x6c, 0x61, 0x75, 0x6e, 0x63,
+    0x68, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x16, 0x0a, 0x06, 0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x06, 0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x12, 0x16,
+    0x0a, 0x06, 0x62, 0x75, 0x79, 0x65, 0x72, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x06,
+    0x62, 0x75, 0x79, 0x65, 0x72, 0x73, 0x12, 0x28, 0x0a, 0x10, 0x74, 0x6f, 0x70, 0x5f, 0x62, 0x75,
+    0x79, 0x65, 0x72, 0x73, 0x5f, 0x73, 0x68, 0x61, 0x72, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x01,
+    0x52, 0x0e, 0x74, 0x6f, 0x70, 0x42, 0x75, 0x79, 0x65, 0x72, 0x73, 0x53, 0x68, 0x61, 0x72, 0x65,
+    0x12, 0x16, 0x0a, 0x06, 0x73, 0x6e, 0x69, 0x70, 0x65, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x08,
+    0x52, 0x06, 0x73, 0x6e, 0x69, 0x70, 0x65, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x63, 0x6f, 0x72,
+    0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x01, 0x52, 0x05, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x22, 0xc7,
+    0x01, 0x0a, 0x09, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x12, 0x3d, 0x0a, 0x09,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x1f, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x52, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x12, 0x48, 0x0a, 0x0a, 0x61,
+    0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x28, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x52, 0x0a, 0x61, 0x67, 0x67, 0x72, 0x65,
+    0x67, 0x61, 0x74, 0x65, 0x73, 0x12, 0x31, 0x0a, 0x14, 0x64, 0x75, 0x70, 0x6c, 0x69, 0x63, 0x61,
+    0x74, 0x65, 0x5f, 0x73, 0x75, 0x70, 0x70, 0x72, 0x65, 0x73, 0x73, 0x65, 0x64, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x13, 0x64, 0x75, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x65, 0x53, 0x75,
+    0x70, 0x70, 0x72, 0x65, 0x73, 0x73, 0x65, 0x64, 0x22, 0xbe, 0x01, 0x0a, 0x11, 0x54, 0x72, 0x61,
+    0x6e, 0x73, 0x66, 0x65, 0x72, 0x41, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x12, 0x14,
+    0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x25, 0x0a, 0x0e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x5f, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0d, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x76,
+    0x6f, 0x6c, 0x75, 0x6d, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x76, 0x6f, 0x6c,
+    0x75, 0x6d, 0x65, 0x12, 0x3a, 0x0a, 0x07, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x18, 0x04,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65,
+    0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4e, 0x65, 0x74,
+    0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x52, 0x07, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x12,
+    0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x22, 0x3b, 0x0a, 0x09, 0x4e, 0x65, 0x74,
+    0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x12, 0x16,
+    0x0a, 0x06, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06,
+    0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x22, 0xe8, 0x02, 0x0a, 0x08, 0x54, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x12, 0x44, 0x0a, 0x08, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x28, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72,
+    0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72,
+    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2e, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x52,
+    0x08, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x12,
+    0x12, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x66,
+    0x72, 0x6f, 0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x02, 0x74, 0x6f, 0x12, 0x16, 0x0a, 0x06, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x05, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x06, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x1a, 0x0a, 0x08, 0x6f,
+    0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x6f,
+    0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68,
+    0x61, 0x73, 0x68, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61,
+    0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x08, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x0e, 0x0a, 0x02,
+    0x69, 0x64, 0x18, 0x09, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x63, 0x0a, 0x08,
+    0x53, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x12, 0x18, 0x0a, 0x14, 0x53, 0x54, 0x41, 0x4e,
+    0x44, 0x41, 0x52, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44,
+    0x10, 0x00, 0x12, 0x12, 0x0a, 0x0e, 0x53, 0x54, 0x41, 0x4e, 0x44, 0x41, 0x52, 0x44, 0x5f, 0x45,
+    0x52, 0x43, 0x32, 0x30, 0x10, 0x01, 0x12, 0x13, 0x0a, 0x0f, 0x53, 0x54, 0x41, 0x4e, 0x44, 0x41,
+    0x52, 0x44, 0x5f, 0x45, 0x52, 0x43, 0x37, 0x37, 0x37, 0x10, 0x02, 0x12, 0x14, 0x0a, 0x10, 0x53,
+    0x54, 0x41, 0x4e, 0x44, 0x41, 0x52, 0x44, 0x5f, 0x45, 0x52, 0x43, 0x31, 0x33, 0x36, 0x33, 0x10,
+    0x03, 0x22, 0xbe, 0x02, 0x0a, 0x0f, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x43, 0x6f,
+    0x6c, 0x75, 0x6d, 0x6e, 0x73, 0x12, 0x16, 0x0a, 0x06, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x18,
+    0x01, 0x20, 0x03, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x12, 0x1d, 0x0a,
+    0x0a, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28,
+    0x0c, 0x52, 0x09, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x65, 0x73, 0x12, 0x1f, 0x0a, 0x0b,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x03, 0x20, 0x03, 0x28,
+    0x0d, 0x52, 0x0a, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x1b, 0x0a,
+    0x09, 0x74, 0x72, 0x78, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0d,
+    0x52, 0x08, 0x74, 0x72, 0x78, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x44, 0x0a, 0x08, 0x73, 0x74,
+    0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0e, 0x32, 0x28, 0x2e, 0x73,
+    0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2e, 0x53, 0x74,
+    0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x52, 0x08, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64,
+    0x12, 0x12, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0c, 0x52, 0x04,
+    0x66, 0x72, 0x6f, 0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0c,
+    0x52, 0x02, 0x74, 0x6f, 0x12, 0x16, 0x0a, 0x06, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x08,
+    0x20, 0x03, 0x28, 0x09, 0x52, 0x06, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x1a, 0x0a, 0x08,
+    0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x18, 0x09, 0x20, 0x03, 0x28, 0x0c, 0x52, 0x08,
+    0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69,
+    0x6e, 0x61, 0x6c, 0x18, 0x0a, 0x20, 0x03, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x22, 0xa2, 0x01, 0x0a, 0x10, 0x47, 0x6f, 0x76, 0x65, 0x72, 0x6e, 0x61, 0x6e, 0x63,
+    0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x12, 0x43, 0x0a, 0x0b, 0x64, 0x65, 0x6c, 0x65, 0x67,
+    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x73,
+    0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x73, 0x2e, 0x76, 0x31, 0x2e, 0x44, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52,
+    0x0b, 0x64, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x49, 0x0a, 0x0b,
+    0x63, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28,
+    0x0b, 0x32, 0x27, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x56, 0x6f, 0x74, 0x69, 0x6e, 0x67,
+    0x43, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x52, 0x0b, 0x63, 0x68, 0x65, 0x63,
+    0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x73, 0x22, 0xcb, 0x01, 0x0a, 0x0a, 0x44, 0x65, 0x6c, 0x65,
+    0x67, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x1c, 0x0a, 0x09,
+    0x64, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x09, 0x64, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x6f, 0x72, 0x12, 0x23, 0x0a, 0x0d, 0x66, 0x72,
+    0x6f, 0x6d, 0x5f, 0x64, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x0c, 0x66, 0x72, 0x6f, 0x6d, 0x44, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x65, 0x12,
+    0x1f, 0x0a, 0x0b, 0x74, 0x6f, 0x5f, 0x64, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x65, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x0a, 0x74, 0x6f, 0x44, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x65,
+    0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x05, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f,
+    0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72,
+    0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x07, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0xdc, 0x01, 0x0a, 0x10, 0x56, 0x6f, 0x74, 0x69, 0x6e, 0x67,
+    0x43, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x12, 0x1a, 0x0a, 0x08, 0x64, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x65, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x08, 0x64, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x65, 0x12, 0x25, 0x0a, 0x0e,
+    0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x5f, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x18, 0x03,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x56, 0x6f,
+    0x74, 0x65, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x05, 0x76, 0x6f, 0x74, 0x65, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x12,
+    0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x06, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72,
+    0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64,
+    0x69, 0x6e, 0x61, 0x6c, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x02, 0x69, 0x64, 0x22, 0x4e, 0x0a, 0x0e, 0x50, 0x72, 0x6f, 0x70, 0x6f, 0x73, 0x61, 0x6c,
+    0x45, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x12, 0x3c, 0x0a, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73,
+    0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x24, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65,
+    0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50,
+    0x72, 0x6f, 0x70, 0x6f, 0x73, 0x61, 0x6c, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x52, 0x06, 0x65, 0x76,
+    0x65, 0x6e, 0x74, 0x73, 0x22, 0xd3, 0x04, 0x0a, 0x0d, 0x50, 0x72, 0x6f, 0x70, 0x6f, 0x73, 0x61,
+    0x6c, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x3d, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x0e, 0x32, 0x29, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65,
+    0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x72, 0x6f,
+    0x70, 0x6f, 0x73, 0x61, 0x6c, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x2e, 0x4b, 0x69, 0x6e, 0x64, 0x52,
+    0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x67, 0x6f, 0x76, 0x65, 0x72, 0x6e, 0x6f,
+    0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x67, 0x6f, 0x76, 0x65, 0x72, 0x6e, 0x6f,
+    0x72, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x72, 0x6f, 0x70, 0x6f, 0x73, 0x61, 0x6c, 0x5f, 0x69, 0x64,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x72, 0x6f, 0x70, 0x6f, 0x73, 0x61, 0x6c,
+    0x49, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x70, 0x72, 0x6f, 0x70, 0x6f, 0x73, 0x65, 0x72, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x70, 0x72, 0x6f, 0x70, 0x6f, 0x73, 0x65, 0x72, 0x12, 0x18,
+    0x0a, 0x07, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0c, 0x52,
+    0x07, 0x74, 0x61, 0x72, 0x67, 0x65, 0x74, 0x73, 0x12, 0x20, 0x0a, 0x0b, 0x64, 0x65, 0x73, 0x63,
+    0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x64,
+    0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1f, 0x0a, 0x0b, 0x73, 0x74,
+    0x61, 0x72, 0x74, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x0a, 0x73, 0x74, 0x61, 0x72, 0x74, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x1b, 0x0a, 0x09, 0x65,
+    0x6e, 0x64, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08,
+    0x65, 0x6e, 0x64, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x10, 0x0a, 0x03, 0x65, 0x74, 0x61, 0x18,
+    0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x03, 0x65, 0x74, 0x61, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x6f,
+    0x74, 0x65, 0x72, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x76, 0x6f, 0x74, 0x65, 0x72,
+    0x12, 0x18, 0x0a, 0x07, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x18, 0x0b, 0x20, 0x01, 0x28,
+    0x0d, 0x52, 0x07, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x77, 0x65,
+    0x69, 0x67, 0x68, 0x74, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x77, 0x65, 0x69, 0x67,
+    0x68, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x18, 0x0d, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x06, 0x72, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x72,
+    0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x72,
+    0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c,
+    0x18, 0x0f, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12,
+    0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x10, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x69, 0x64, 0x22,
+    0x79, 0x0a, 0x04, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x14, 0x0a, 0x10, 0x4b, 0x49, 0x4e, 0x44, 0x5f,
+    0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x10, 0x0a,
+    0x0c, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x43, 0x52, 0x45, 0x41, 0x54, 0x45, 0x44, 0x10, 0x01, 0x12,
+    0x0f, 0x0a, 0x0b, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x51, 0x55, 0x45, 0x55, 0x45, 0x44, 0x10, 0x02,
+    0x12, 0x11, 0x0a, 0x0d, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x45, 0x58, 0x45, 0x43, 0x55, 0x54, 0x45,
+    0x44, 0x10, 0x03, 0x12, 0x11, 0x0a, 0x0d, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x43, 0x41, 0x4e, 0x43,
+    0x45, 0x4c, 0x45, 0x44, 0x10, 0x04, 0x12, 0x12, 0x0a, 0x0e, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x56,
+    0x4f, 0x54, 0x45, 0x5f, 0x43, 0x41, 0x53, 0x54, 0x10, 0x05, 0x22, 0x4c, 0x0a, 0x0d, 0x56, 0x65,
+    0x73, 0x74, 0x69, 0x6e, 0x67, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x12, 0x3b, 0x0a, 0x06, 0x65,
+    0x76, 0x65, 0x6e, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x73, 0x66,
+    0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73,
+    0x2e, 0x76, 0x31, 0x2e, 0x56, 0x65, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x45, 0x76, 0x65, 0x6e, 0x74,
+    0x52, 0x06, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x22, 0x89, 0x04, 0x0a, 0x0c, 0x56, 0x65, 0x73,
+    0x74, 0x69, 0x6e, 0x67, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x3c, 0x0a, 0x04, 0x6b, 0x69, 0x6e,
+    0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x28, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68,
+    0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e,
+    0x56, 0x65, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x2e, 0x4b, 0x69, 0x6e,
+    0x64, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x1b, 0x0a, 0x09, 0x73, 0x74, 0x72,
+    0x65, 0x61, 0x6d, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x73, 0x74,
+    0x72, 0x65, 0x61, 0x6d, 0x49, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72,
+    0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x12, 0x1c,
+    0x0a, 0x09, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x09, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x12, 0x16, 0x0a, 0x06,
+    0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x61, 0x6d,
+    0x6f, 0x75, 0x6e, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x74, 0x61, 0x72, 0x74, 0x5f, 0x74, 0x69,
+    0x6d, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x73, 0x74, 0x61, 0x72, 0x74, 0x54,
+    0x69, 0x6d, 0x65, 0x12, 0x1b, 0x0a, 0x09, 0x73, 0x74, 0x6f, 0x70, 0x5f, 0x74, 0x69, 0x6d, 0x65,
+    0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x73, 0x74, 0x6f, 0x70, 0x54, 0x69, 0x6d, 0x65,
+    0x12, 0x19, 0x0a, 0x08, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x0a, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x07, 0x74, 0x72, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f,
+    0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72,
+    0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x0c, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x02, 0x69, 0x64, 0x22, 0x9c, 0x01, 0x0a, 0x04, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x14,
+    0x0a, 0x10, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49,
+    0x45, 0x44, 0x10, 0x00, 0x12, 0x17, 0x0a, 0x13, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x53, 0x54, 0x52,
+    0x45, 0x41, 0x4d, 0x5f, 0x43, 0x52, 0x45, 0x41, 0x54, 0x45, 0x44, 0x10, 0x01, 0x12, 0x1a, 0x0a,
+    0x16, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x53, 0x54, 0x52, 0x45, 0x41, 0x4d, 0x5f, 0x57, 0x49, 0x54,
+    0x48, 0x44, 0x52, 0x41, 0x57, 0x41, 0x4c, 0x10, 0x02, 0x12, 0x18, 0x0a, 0x14, 0x4b, 0x49, 0x4e,
+    0x44, 0x5f, 0x53, 0x54, 0x52, 0x45, 0x41, 0x4d, 0x5f, 0x43, 0x41, 0x4e, 0x43, 0x45, 0x4c, 0x45,
+    0x44, 0x10, 0x03, 0x12, 0x16, 0x0a, 0x12, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x57, 0x41, 0x4c, 0x4c,
+    0x45, 0x54, 0x5f, 0x46, 0x55, 0x4e, 0x44, 0x45, 0x44, 0x10, 0x04, 0x12, 0x17, 0x0a, 0x13, 0x4b,
+    0x49, 0x4e, 0x44, 0x5f, 0x57, 0x41, 0x4c, 0x4c, 0x45, 0x54, 0x5f, 0x52, 0x45, 0x4c, 0x45, 0x41,
+    0x53, 0x45, 0x10, 0x05, 0x22, 0x5b, 0x0a, 0x13, 0x43, 0x69, 0x72, 0x63, 0x75, 0x6c, 0x61, 0x74,
+    0x69, 0x6e, 0x67, 0x53, 0x75, 0x70, 0x70, 0x6c, 0x69, 0x65, 0x73, 0x12, 0x44, 0x0a, 0x08, 0x73,
+    0x75, 0x70, 0x70, 0x6c, 0x69, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x28, 0x2e,
+    0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x69, 0x72, 0x63, 0x75, 0x6c, 0x61, 0x74, 0x69, 0x6e,
+    0x67, 0x53, 0x75, 0x70, 0x70, 0x6c, 0x79, 0x52, 0x08, 0x73, 0x75, 0x70, 0x70, 0x6c, 0x69, 0x65,
+    0x73, 0x22, 0xd0, 0x01, 0x0a, 0x11, 0x43, 0x69, 0x72, 0x63, 0x75, 0x6c, 0x61, 0x74, 0x69, 0x6e,
+    0x67, 0x53, 0x75, 0x70, 0x70, 0x6c, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x14, 0x0a,
+    0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x12, 0x21, 0x0a, 0x0c, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x5f, 0x73, 0x75, 0x70,
+    0x70, 0x6c, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x74, 0x6f, 0x74, 0x61, 0x6c,
+    0x53, 0x75, 0x70, 0x70, 0x6c, 0x79, 0x12, 0x16, 0x0a, 0x06, 0x62, 0x75, 0x72, 0x6e, 0x65, 0x64,
+    0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x62, 0x75, 0x72, 0x6e, 0x65, 0x64, 0x12, 0x1a,
+    0x0a, 0x08, 0x65, 0x73, 0x63, 0x72, 0x6f, 0x77, 0x65, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x08, 0x65, 0x73, 0x63, 0x72, 0x6f, 0x77, 0x65, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x6c, 0x6f,
+    0x63, 0x6b, 0x65, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x6c, 0x6f, 0x63, 0x6b,
+    0x65, 0x64, 0x12, 0x20, 0x0a, 0x0b, 0x63, 0x69, 0x72, 0x63, 0x75, 0x6c, 0x61, 0x74, 0x69, 0x6e,
+    0x67, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x63, 0x69, 0x72, 0x63, 0x75, 0x6c, 0x61,
+    0x74, 0x69, 0x6e, 0x67, 0x22, 0x4a, 0x0a, 0x0d, 0x45, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65,
+    0x46, 0x6c, 0x6f, 0x77, 0x73, 0x12, 0x39, 0x0a, 0x05, 0x66, 0x6c, 0x6f, 0x77, 0x73, 0x18, 0x01,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72, 0x65,
+    0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x45, 0x78, 0x63,
+    0x68, 0x61, 0x6e, 0x67, 0x65, 0x46, 0x6c, 0x6f, 0x77, 0x52, 0x05, 0x66, 0x6c, 0x6f, 0x77, 0x73,
+    0x22, 0xc6, 0x01, 0x0a, 0x0c, 0x45, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x46, 0x6c, 0x6f,
+    0x77, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x1a, 0x0a,
+    0x08, 0x65, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x08, 0x65, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x69, 0x6e, 0x66,
+    0x6c, 0x6f, 0x77, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x69, 0x6e, 0x66, 0x6c, 0x6f,
+    0x77, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x75, 0x74, 0x66, 0x6c, 0x6f, 0x77, 0x18, 0x05, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x07, 0x6f, 0x75, 0x74, 0x66, 0x6c, 0x6f, 0x77, 0x12, 0x1a, 0x0a, 0x08, 0x64,
+    0x65, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x64,
+    0x65, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x73, 0x12, 0x20, 0x0a, 0x0b, 0x77, 0x69, 0x74, 0x68, 0x64,
+    0x72, 0x61, 0x77, 0x61, 0x6c, 0x73, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x77, 0x69,
+    0x74, 0x68, 0x64, 0x72, 0x61, 0x77, 0x61, 0x6c, 0x73, 0x22, 0x4a, 0x0a, 0x0b, 0x46, 0x6c, 0x6f,
+    0x77, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x6c, 0x73, 0x12, 0x3b, 0x0a, 0x07, 0x73, 0x69, 0x67, 0x6e,
+    0x61, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x73, 0x66, 0x2e, 0x65,
+    0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76,
+    0x31, 0x2e, 0x46, 0x6c, 0x6f, 0x77, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x6c, 0x52, 0x07, 0x73, 0x69,
+    0x67, 0x6e, 0x61, 0x6c, 0x73, 0x22, 0xc5, 0x02, 0x0a, 0x0a, 0x46, 0x6c, 0x6f, 0x77, 0x53, 0x69,
+    0x67, 0x6e, 0x61, 0x6c, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x12, 0x1a, 0x0a, 0x08, 0x65, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x08, 0x65, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x12, 0x16, 0x0a, 0x06,
+    0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x77, 0x69,
+    0x6e, 0x64, 0x6f, 0x77, 0x12, 0x49, 0x0a, 0x09, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x2b, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68,
+    0x65, 0x72, 0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e,
+    0x46, 0x6c, 0x6f, 0x77, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x6c, 0x2e, 0x44, 0x69, 0x72, 0x65, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x52, 0x09, 0x64, 0x69, 0x72, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12,
+    0x19, 0x0a, 0x08, 0x6e, 0x65, 0x74, 0x5f, 0x66, 0x6c, 0x6f, 0x77, 0x18, 0x06, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x07, 0x6e, 0x65, 0x74, 0x46, 0x6c, 0x6f, 0x77, 0x12, 0x1c, 0x0a, 0x09, 0x74, 0x68,
+    0x72, 0x65, 0x73, 0x68, 0x6f, 0x6c, 0x64, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x74,
+    0x68, 0x72, 0x65, 0x73, 0x68, 0x6f, 0x6c, 0x64, 0x22, 0x53, 0x0a, 0x09, 0x44, 0x69, 0x72, 0x65,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x19, 0x0a, 0x15, 0x44, 0x49, 0x52, 0x45, 0x43, 0x54, 0x49,
+    0x4f, 0x4e, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00,
+    0x12, 0x14, 0x0a, 0x10, 0x44, 0x49, 0x52, 0x45, 0x43, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x49, 0x4e,
+    0x46, 0x4c, 0x4f, 0x57, 0x10, 0x01, 0x12, 0x15, 0x0a, 0x11, 0x44, 0x49, 0x52, 0x45, 0x43, 0x54,
+    0x49, 0x4f, 0x4e, 0x5f, 0x4f, 0x55, 0x54, 0x46, 0x4c, 0x4f, 0x57, 0x10, 0x02, 0x22, 0x56, 0x0a,
+    0x09, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x4c, 0x69, 0x73, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x12, 0x12, 0x0a, 0x04, 0x6a, 0x73, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04,
+    0x6a, 0x73, 0x6f, 0x6e, 0x12, 0x1f, 0x0a, 0x0b, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x43, 0x6f, 0x75, 0x6e, 0x74, 0x22, 0x4a, 0x0a, 0x0c, 0x4c, 0x65, 0x67, 0x61, 0x63, 0x79, 0x54,
+    0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x12, 0x3a, 0x0a, 0x06, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x18,
+    0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x73, 0x66, 0x2e, 0x65, 0x74, 0x68, 0x65, 0x72,
+    0x65, 0x75, 0x6d, 0x2e, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x65,
+    0x67, 0x61, 0x63, 0x79, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x52, 0x06, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x73, 0x22, 0x6f, 0x0a, 0x0b, 0x4c, 0x65, 0x67, 0x61, 0x63, 0x79, 0x54, 0x6f, 0x6b, 0x65, 0x6e,
+    0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61,
+    0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x16,
+    0x0a, 0x06, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06,
+    0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x12, 0x1a, 0x0a, 0x08, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61,
+    0x6c, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61,
+    0x6c, 0x73, 0x4a, 0xde, 0x9d, 0x01, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x98, 0x03, 0x01, 0x0a,
+    0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03,
+    0x02, 0x00, 0x1e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x04, 0x00, 0x09, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x04, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x00, 0x12, 0x03, 0x05, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x04, 0x12, 0x03, 0x05, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12,
+    0x03, 0x05, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x05,
+    0x11, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x05, 0x1a, 0x1b,
+    0x0a, 0x60, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x07, 0x02, 0x17, 0x1a, 0x53, 0x20,
+    0x52, 0x50, 0x43, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x6d, 0x61, 0x64, 0x65, 0x20, 0x77,
+    0x68, 0x69, 0x6c, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x62, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x68, 0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e,
+    0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x07, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x07, 0x09, 0x12, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x07, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x08, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x02, 0x05, 0x12, 0x03, 0x08, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x08, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03,
+    0x08, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0b, 0x00, 0x38, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x01, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x0c, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x0c, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0c,
+    0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x02, 0x12, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0d, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0d, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x02, 0x12, 0x03, 0x0e, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x0e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0e,
+    0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0e, 0x12, 0x13,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x0f, 0x02, 0x16, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x03, 0x05, 0x12, 0x03, 0x0f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x03, 0x01, 0x12, 0x03, 0x0f, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x03, 0x03, 0x12, 0x03, 0x0f, 0x14, 0x15, 0x0a, 0x71, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12,
+    0x03, 0x11, 0x02, 0x1b, 0x1a, 0x64, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74, 0x20,
+    0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x20, 0x65,
+    0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x6d, 0x61, 0x70, 0x5f, 0x6c, 0x61,
+    0x75, 0x6e, 0x63, 0x68, 0x5f, 0x71, 0x75, 0x61, 0x6c, 0x69, 0x74, 0x79, 0x2c, 0x20, 0x6f, 0x6e,
+    0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x75, 0x6e, 0x63, 0x68, 0x20, 0x77, 0x69,
+    0x6e, 0x64, 0x6f, 0x77, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x20, 0x63, 0x6c, 0x6f, 0x73, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x04, 0x06, 0x12, 0x03, 0x11, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04,
+    0x01, 0x12, 0x03, 0x11, 0x10, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12,
+    0x03, 0x11, 0x19, 0x1a, 0x0a, 0x54, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05, 0x12, 0x03, 0x13, 0x02,
+    0x15, 0x1a, 0x47, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x72, 0x65, 0x61,
+    0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x65,
+    0x6d, 0x70, 0x74, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x68, 0x79, 0x64, 0x72, 0x61, 0x74, 0x65,
+    0x64, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x05, 0x05, 0x12, 0x03, 0x13, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05,
+    0x01, 0x12, 0x03, 0x13, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12,
+    0x03, 0x13, 0x13, 0x14, 0x0a, 0x47, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12, 0x03, 0x15, 0x02,
+    0x2d, 0x1a, 0x3a, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74, 0x20, 0x6f, 0x6e, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x20, 0x65, 0x6d, 0x69, 0x74,
+    0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x6d, 0x61, 0x70, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x5f, 0x72, 0x65, 0x70, 0x75, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x06, 0x06, 0x12, 0x03, 0x15, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x06, 0x01, 0x12, 0x03, 0x15, 0x15, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x06, 0x03, 0x12, 0x03, 0x15, 0x2b, 0x2c, 0x0a, 0x70, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12,
+    0x03, 0x17, 0x02, 0x1c, 0x1a, 0x63, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74, 0x20,
+    0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x20, 0x65,
+    0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x6d, 0x61, 0x70, 0x5f, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x5f, 0x74, 0x61, 0x78, 0x65, 0x73, 0x2c, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x66, 0x65, 0x65, 0x2d, 0x6f, 0x6e, 0x2d, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65,
+    0x72, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x65, 0x66, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x07, 0x05, 0x12, 0x03, 0x17, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x01,
+    0x12, 0x03, 0x17, 0x07, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x03, 0x12, 0x03,
+    0x17, 0x1a, 0x1b, 0x0a, 0x53, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x08, 0x12, 0x03, 0x19, 0x02, 0x1f,
+    0x1a, 0x46, 0x20, 0x48, 0x69, 0x67, 0x68, 0x65, 0x73, 0x74, 0x20, 0x73, 0x68, 0x61, 0x72, 0x65,
+    0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x74,
+    0x61, 0x6b, 0x65, 0x6e, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x66, 0x65, 0x65, 0x20, 0x69, 0x6e,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x70,
+    0x65, 0x72, 0x63, 0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08,
+    0x05, 0x12, 0x03, 0x19, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12,
+    0x03, 0x19, 0x09, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12, 0x03, 0x19,
+    0x1d, 0x1e, 0x0a, 0xb3, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x09, 0x12, 0x03, 0x1c, 0x02, 0x19,
+    0x1a, 0xa5, 0x01, 0x20, 0x46, 0x72, 0x6f, 0x6d, 0x20, 0x30, 0x20, 0x74, 0x6f, 0x20, 0x31, 0x30,
+    0x30, 0x2c, 0x20, 0x68, 0x6f, 0x77, 0x20, 0x6c, 0x69, 0x6b, 0x65, 0x6c, 0x79, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x73, 0x70, 0x61, 0x6d, 0x2e,
+    0x20, 0x6d, 0x61, 0x70, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20,
+    0x6d, 0x61, 0x70, 0x5f, 0x68, 0x79, 0x64, 0x72, 0x61, 0x74, 0x65, 0x64, 0x5f, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x73, 0x20, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x61,
+    0x6d, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x0a, 0x20, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x2c, 0x20,
+    0x6d, 0x61, 0x70, 0x5f, 0x73, 0x70, 0x61, 0x6d, 0x5f, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x73, 0x20,
+    0x61, 0x64, 0x64, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x69, 0x71, 0x75, 0x69, 0x64, 0x69,
+    0x74, 0x79, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x61, 0x69, 0x72, 0x64, 0x72, 0x6f, 0x70, 0x20, 0x73,
+    0x69, 0x67, 0x6e, 0x61, 0x6c, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09,
+    0x05, 0x12, 0x03, 0x1c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x01, 0x12,
+    0x03, 0x1c, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x03, 0x12, 0x03, 0x1c,
+    0x16, 0x18, 0x0a, 0x44, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0a, 0x12, 0x03, 0x1e, 0x02, 0x24, 0x1a,
+    0x37, 0x20, 0x69, 0x6d, 0x70, 0x65, 0x72, 0x73, 0x6f, 0x6e, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2c,
+    0x20, 0x75, 0x6e, 0x69, 0x63, 0x6f, 0x64, 0x65, 0x2c, 0x20, 0x75, 0x72, 0x6c, 0x2c, 0x20, 0x6e,
+    0x6f, 0x5f, 0x6c, 0x69, 0x71, 0x75, 0x69, 0x64, 0x69, 0x74, 0x79, 0x20, 0x6f, 0x72, 0x20, 0x61,
+    0x69, 0x72, 0x64, 0x72, 0x6f, 0x70, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a,
+    0x04, 0x12, 0x03, 0x1e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x05, 0x12,
+    0x03, 0x1e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x1e,
+    0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x1e, 0x21, 0x23,
+    0x0a, 0xd5, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0b, 0x12, 0x03, 0x21, 0x02, 0x1c, 0x1a, 0xc7,
+    0x01, 0x20, 0x42, 0x79, 0x74, 0x65, 0x73, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x65, 0x64,
+    0x20, 0x62, 0x79, 0x20, 0x60, 0x6e, 0x61, 0x6d, 0x65, 0x28, 0x29, 0x60, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x60, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x28, 0x29, 0x60, 0x2c, 0x20, 0x6f, 0x6e, 0x6c,
+    0x79, 0x20, 0x73, 0x65, 0x74, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x60, 0x6e, 0x61, 0x6d, 0x65,
+    0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x60, 0x20, 0x68, 0x61,
+    0x64, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x20, 0x73, 0x61, 0x6e, 0x69, 0x74, 0x69, 0x7a, 0x65,
+    0x64, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x61, 0x6e, 0x69, 0x74, 0x69, 0x7a, 0x65,
+    0x64, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x4e, 0x46, 0x43,
+    0x20, 0x6e, 0x6f, 0x72, 0x6d, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x2c, 0x20, 0x77, 0x69, 0x74,
+    0x68, 0x6f, 0x75, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x6f, 0x6c, 0x20, 0x63, 0x68, 0x61,
+    0x72, 0x61, 0x63, 0x74, 0x65, 0x72, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x61, 0x74, 0x20, 0x6d,
+    0x6f, 0x73, 0x74, 0x20, 0x36, 0x34, 0x20, 0x63, 0x68, 0x61, 0x72, 0x61, 0x63, 0x74, 0x65, 0x72,
+    0x73, 0x20, 0x6c, 0x6f, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0b,
+    0x05, 0x12, 0x03, 0x21, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0b, 0x01, 0x12,
+    0x03, 0x21, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0b, 0x03, 0x12, 0x03, 0x21,
+    0x19, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0c, 0x12, 0x03, 0x22, 0x02, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0c, 0x05, 0x12, 0x03, 0x22, 0x02, 0x07, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x0c, 0x01, 0x12, 0x03, 0x22, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x0c, 0x03, 0x12, 0x03, 0x22, 0x1b, 0x1d, 0x0a, 0x8b, 0x01, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x0d, 0x12, 0x03, 0x25, 0x02, 0x15, 0x1a, 0x7e, 0x20, 0x53, 0x65, 0x74, 0x20, 0x77, 0x68,
+    0x65, 0x6e, 0x20, 0x60, 0x6e, 0x61, 0x6d, 0x65, 0x5f, 0x72, 0x61, 0x77, 0x5f, 0x62, 0x79, 0x74,
+    0x65, 0x73, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x5f, 0x72,
+    0x61, 0x77, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x60, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x74,
+    0x20, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x20, 0x55, 0x54, 0x46, 0x2d, 0x38, 0x2c, 0x20, 0x60, 0x6e,
+    0x61, 0x6d, 0x65, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x73, 0x79, 0x6d, 0x62, 0x6f, 0x6c, 0x60,
+    0x20, 0x74, 0x68, 0x65, 0x6e, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x68, 0x65, 0x78, 0x0a, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x61, 0x77, 0x20,
+    0x62, 0x79, 0x74, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0d, 0x05,
+    0x12, 0x03, 0x25, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0d, 0x01, 0x12, 0x03,
+    0x25, 0x07, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0d, 0x03, 0x12, 0x03, 0x25, 0x12,
+    0x14, 0x0a, 0xbc, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0e, 0x12, 0x03, 0x28, 0x02, 0x1b, 0x1a,
+    0xae, 0x01, 0x20, 0x45, 0x52, 0x43, 0x2d, 0x37, 0x35, 0x37, 0x32, 0x20, 0x60, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x55, 0x52, 0x49, 0x28, 0x29, 0x60, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6c, 0x6f, 0x67, 0x6f, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64,
+    0x61, 0x74, 0x61, 0x20, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x77, 0x68,
+    0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x69, 0x6e, 0x6c, 0x69, 0x6e, 0x65, 0x64,
+    0x20, 0x61, 0x73, 0x20, 0x61, 0x0a, 0x20, 0x60, 0x64, 0x61, 0x74, 0x61, 0x3a, 0x60, 0x20, 0x55,
+    0x52, 0x49, 0x2e, 0x20, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x20, 0x68, 0x6f, 0x73,
+    0x74, 0x65, 0x64, 0x20, 0x6f, 0x66, 0x66, 0x2d, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x69, 0x73,
+    0x20, 0x6c, 0x65, 0x66, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x73, 0x75, 0x6d,
+    0x65, 0x72, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x73, 0x6f, 0x6c, 0x76, 0x65, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0e, 0x05, 0x12, 0x03, 0x28, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x0e, 0x01, 0x12, 0x03, 0x28, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x0e, 0x03, 0x12, 0x03, 0x28, 0x18, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x0f, 0x12, 0x03, 0x29, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0f, 0x05,
+    0x12, 0x03, 0x29, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0f, 0x01, 0x12, 0x03,
+    0x29, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0f, 0x03, 0x12, 0x03, 0x29, 0x14,
+    0x16, 0x0a, 0xe5, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x10, 0x12, 0x03, 0x2c, 0x02, 0x17, 0x1a,
+    0xd7, 0x01, 0x20, 0x6b, 0x65, 0x63, 0x63, 0x61, 0x6b, 0x32, 0x35, 0x36, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x64, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x20, 0x77, 0x61, 0x73, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x2c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x20, 0x69, 0x74, 0x73,
+    0x20, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x72, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61,
+    0x74, 0x61, 0x2e, 0x20, 0x43, 0x6c, 0x6f, 0x6e, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x74, 0x65, 0x6d, 0x70, 0x6c, 0x61, 0x74, 0x65, 0x0a, 0x20,
+    0x73, 0x68, 0x61, 0x72, 0x65, 0x20, 0x69, 0x74, 0x2c, 0x20, 0x73, 0x65, 0x65, 0x20, 0x73, 0x74,
+    0x6f, 0x72, 0x65, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x63, 0x6f, 0x64, 0x65, 0x5f, 0x66, 0x61, 0x6d,
+    0x69, 0x6c, 0x69, 0x65, 0x73, 0x2e, 0x20, 0x45, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x6c, 0x69,
+    0x7a, 0x65, 0x64, 0x20, 0x74, 0x68, 0x72, 0x6f, 0x75, 0x67, 0x68, 0x20, 0x61, 0x20, 0x70, 0x72,
+    0x6f, 0x78, 0x79, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x68, 0x79, 0x64, 0x72, 0x61, 0x74, 0x65, 0x64,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x10, 0x05, 0x12, 0x03, 0x2c, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x10, 0x01,
+    0x12, 0x03, 0x2c, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x10, 0x03, 0x12, 0x03,
+    0x2c, 0x14, 0x16, 0x0a, 0xce, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x11, 0x12, 0x03, 0x2f, 0x02,
+    0x17, 0x1a, 0xc0, 0x01, 0x20, 0x60, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x60, 0x20, 0x77, 0x68, 0x65,
+    0x6e, 0x20, 0x60, 0x63, 0x6f, 0x64, 0x65, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x60, 0x20, 0x69, 0x73,
+    0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x65, 0x72, 0x69,
+    0x66, 0x69, 0x65, 0x64, 0x20, 0x74, 0x65, 0x6d, 0x70, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x20, 0x6c,
+    0x69, 0x73, 0x74, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x74, 0x65,
+    0x6d, 0x70, 0x6c, 0x61, 0x74, 0x65, 0x73, 0x60, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x20, 0x6f,
+    0x66, 0x20, 0x6d, 0x61, 0x70, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2c, 0x20, 0x73, 0x75,
+    0x63, 0x68, 0x0a, 0x20, 0x61, 0x73, 0x20, 0x4f, 0x70, 0x65, 0x6e, 0x5a, 0x65, 0x70, 0x70, 0x65,
+    0x6c, 0x69, 0x6e, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30, 0x20, 0x70, 0x72, 0x65, 0x73, 0x65, 0x74,
+    0x73, 0x20, 0x6f, 0x72, 0x20, 0x6c, 0x61, 0x75, 0x6e, 0x63, 0x68, 0x70, 0x61, 0x64, 0x20, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x69, 0x72, 0x20, 0x73, 0x70, 0x61,
+    0x6d, 0x20, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x20, 0x69, 0x73, 0x20, 0x6c, 0x6f, 0x77, 0x65, 0x72,
+    0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x11, 0x05, 0x12, 0x03, 0x2f,
+    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x11, 0x01, 0x12, 0x03, 0x2f, 0x09, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x11, 0x03, 0x12, 0x03, 0x2f, 0x14, 0x16, 0x0a, 0xcc,
+    0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x12, 0x12, 0x03, 0x33, 0x02, 0x21, 0x1a, 0xbe, 0x02, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x64, 0x65, 0x20, 0x64, 0x69, 0x73, 0x70, 0x61, 0x74, 0x63,
+    0x68, 0x65, 0x73, 0x20, 0x61, 0x20, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6d,
+    0x69, 0x6e, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x61, 0x6d, 0x6f, 0x75, 0x6e,
+    0x74, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x79, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x20, 0x28, 0x60, 0x6d, 0x69, 0x6e, 0x74, 0x28, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x2c,
+    0x75, 0x69, 0x6e, 0x74, 0x32, 0x35, 0x36, 0x29, 0x60, 0x2c, 0x20, 0x60, 0x69, 0x73, 0x73, 0x75,
+    0x65, 0x28, 0x75, 0x69, 0x6e, 0x74, 0x32, 0x35, 0x36, 0x29, 0x60, 0x2c, 0x0a, 0x20, 0x2e, 0x2e,
+    0x2e, 0x29, 0x20, 0x6f, 0x72, 0x20, 0x62, 0x6c, 0x61, 0x63, 0x6b, 0x6c, 0x69, 0x73, 0x74, 0x69,
+    0x6e, 0x67, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x65, 0x73, 0x20, 0x28, 0x60, 0x62,
+    0x6c, 0x61, 0x63, 0x6b, 0x6c, 0x69, 0x73, 0x74, 0x28, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x29, 0x60, 0x2c, 0x20, 0x60, 0x61, 0x64, 0x64, 0x42, 0x6c, 0x61, 0x63, 0x6b, 0x4c, 0x69, 0x73,
+    0x74, 0x28, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x29, 0x60, 0x2c, 0x20, 0x2e, 0x2e, 0x2e,
+    0x29, 0x2e, 0x20, 0x46, 0x6f, 0x75, 0x6e, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x65, 0x6c, 0x65, 0x63, 0x74, 0x6f, 0x72, 0x73, 0x0a, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x64, 0x65, 0x2c, 0x20, 0x77, 0x68, 0x65, 0x74, 0x68, 0x65,
+    0x72, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x61, 0x72, 0x65, 0x20, 0x72, 0x65, 0x73, 0x74, 0x72,
+    0x69, 0x63, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x77, 0x6e, 0x65,
+    0x72, 0x20, 0x69, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x65, 0x64,
+    0x2e, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
+    0x60, 0x63, 0x6f, 0x64, 0x65, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x12, 0x05, 0x12, 0x03, 0x33, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x12, 0x01, 0x12, 0x03, 0x33, 0x07, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x12, 0x03, 0x12, 0x03, 0x33, 0x1e, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x13, 0x12,
+    0x03, 0x34, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x13, 0x05, 0x12, 0x03, 0x34,
+    0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x13, 0x01, 0x12, 0x03, 0x34, 0x07, 0x14,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x13, 0x03, 0x12, 0x03, 0x34, 0x17, 0x19, 0x0a, 0xe1,
+    0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x14, 0x12, 0x03, 0x37, 0x02, 0x2b, 0x1a, 0xd3, 0x01, 0x20,
+    0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x72, 0x65, 0x6e, 0x6f, 0x75, 0x6e, 0x63, 0x65, 0x64, 0x20,
+    0x69, 0x74, 0x73, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x2c, 0x20, 0x74,
+    0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x74, 0x20, 0x74,
+    0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x75, 0x6c, 0x6c, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65,
+    0x73, 0x73, 0x2e, 0x20, 0x30, 0x20, 0x77, 0x68, 0x69, 0x6c, 0x65, 0x0a, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x68, 0x61, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x77,
+    0x6e, 0x65, 0x72, 0x2e, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x74, 0x20, 0x6f, 0x6e,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x73, 0x20, 0x65, 0x6d, 0x69,
+    0x74, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x6d, 0x61, 0x70, 0x5f, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x5f, 0x72, 0x65, 0x70, 0x75, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x6d, 0x61, 0x70, 0x5f, 0x73, 0x70, 0x61, 0x6d, 0x5f, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x73,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x14, 0x05, 0x12, 0x03, 0x37, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x14, 0x01, 0x12, 0x03, 0x37, 0x09, 0x25, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x14, 0x03, 0x12, 0x03, 0x37, 0x28, 0x2a, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x02, 0x12, 0x04, 0x3a, 0x00, 0x3c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12,
+    0x03, 0x3a, 0x08, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x3b, 0x02,
+    0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3b, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x3b, 0x0b, 0x17, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3b, 0x18, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3b, 0x21, 0x22, 0x0a, 0x47, 0x0a, 0x02, 0x04, 0x03, 0x12,
+    0x04, 0x3f, 0x00, 0x50, 0x01, 0x1a, 0x3b, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x69, 0x62, 0x75,
+    0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x6c, 0x61, 0x75, 0x6e, 0x63, 0x68,
+    0x70, 0x61, 0x64, 0x20, 0x70, 0x72, 0x65, 0x73, 0x61, 0x6c, 0x65, 0x2c, 0x20, 0x6f, 0x72, 0x20,
+    0x69, 0x74, 0x73, 0x20, 0x66, 0x69, 0x6e, 0x61, 0x6c, 0x69, 0x7a, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x3f, 0x08, 0x14, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x03, 0x04, 0x00, 0x12, 0x04, 0x40, 0x02, 0x46, 0x03, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x40, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x03,
+    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x41, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x04,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x41, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x04,
+    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x41, 0x17, 0x18, 0x0a, 0x32, 0x0a, 0x06, 0x04, 0x03, 0x04,
+    0x00, 0x02, 0x01, 0x12, 0x03, 0x43, 0x04, 0x1a, 0x1a, 0x23, 0x20, 0x45, 0x54, 0x48, 0x20, 0x70,
+    0x61, 0x69, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x65, 0x73, 0x61,
+    0x6c, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x03, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x43, 0x04, 0x15, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x03, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x43, 0x18, 0x19, 0x0a, 0x44, 0x0a,
+    0x06, 0x04, 0x03, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x45, 0x04, 0x1a, 0x1a, 0x35, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x70, 0x72, 0x65, 0x73, 0x61, 0x6c, 0x65, 0x20, 0x61, 0x64, 0x64, 0x65, 0x64,
+    0x20, 0x69, 0x74, 0x73, 0x20, 0x72, 0x61, 0x69, 0x73, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20,
+    0x70, 0x6f, 0x6f, 0x6c, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x74, 0x73, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x45, 0x04, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x03, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03,
+    0x45, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x48, 0x02, 0x10,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x48, 0x02, 0x06, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x48, 0x07, 0x0b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x48, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03,
+    0x02, 0x01, 0x12, 0x03, 0x49, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05,
+    0x12, 0x03, 0x49, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x49, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x49, 0x12,
+    0x13, 0x0a, 0x65, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x4b, 0x02, 0x14, 0x1a, 0x58,
+    0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x60, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x43, 0x4f, 0x4e, 0x54, 0x52, 0x49, 0x42, 0x55, 0x54,
+    0x49, 0x4f, 0x4e, 0x60, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x65, 0x73, 0x61, 0x6c, 0x65, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x60, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x46, 0x49, 0x4e, 0x41, 0x4c, 0x49, 0x5a,
+    0x41, 0x54, 0x49, 0x4f, 0x4e, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02,
+    0x05, 0x12, 0x03, 0x4b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x4b, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x4b,
+    0x12, 0x13, 0x0a, 0x61, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x03, 0x12, 0x03, 0x4d, 0x02, 0x14, 0x1a,
+    0x54, 0x20, 0x45, 0x54, 0x48, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x65,
+    0x64, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x61, 0x64, 0x64, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x70, 0x6f, 0x6f, 0x6c, 0x20, 0x61, 0x74, 0x20, 0x66, 0x69, 0x6e, 0x61, 0x6c,
+    0x69, 0x7a, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x65, 0x69, 0x20,
+    0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72,
+    0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x05, 0x12, 0x03,
+    0x4d, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x01, 0x12, 0x03, 0x4d, 0x09,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x03, 0x12, 0x03, 0x4d, 0x12, 0x13, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x04, 0x12, 0x03, 0x4e, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x04, 0x05, 0x12, 0x03, 0x4e, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x04, 0x01, 0x12, 0x03, 0x4e, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04,
+    0x03, 0x12, 0x03, 0x4e, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x05, 0x12, 0x03,
+    0x4f, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x05, 0x12, 0x03, 0x4f, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x01, 0x12, 0x03, 0x4f, 0x09, 0x10, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x03, 0x12, 0x03, 0x4f, 0x13, 0x14, 0x0a, 0x0a, 0x0a,
+    0x02, 0x04, 0x04, 0x12, 0x04, 0x52, 0x00, 0x54, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01,
+    0x12, 0x03, 0x52, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x53,
+    0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x53, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x53, 0x0b, 0x12, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x53, 0x13, 0x1b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x53, 0x1e, 0x1f, 0x0a, 0x47, 0x0a, 0x02, 0x04, 0x05,
+    0x12, 0x04, 0x57, 0x00, 0x61, 0x01, 0x1a, 0x3b, 0x20, 0x50, 0x72, 0x65, 0x73, 0x61, 0x6c, 0x65,
+    0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x64, 0x69, 0x73, 0x63, 0x6f, 0x76, 0x65, 0x72, 0x65, 0x64,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20,
+    0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x66, 0x69, 0x6e, 0x61, 0x6c, 0x69, 0x7a, 0x65,
+    0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x57, 0x08, 0x0f, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x58, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x58, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x58, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x58, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03,
+    0x59, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x05, 0x12, 0x03, 0x59, 0x02,
+    0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x59, 0x08, 0x0d, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x59, 0x10, 0x11, 0x0a, 0x47, 0x0a,
+    0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x5b, 0x02, 0x14, 0x1a, 0x3a, 0x20, 0x45, 0x54, 0x48,
+    0x20, 0x72, 0x61, 0x69, 0x73, 0x65, 0x64, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x70, 0x72, 0x65, 0x73, 0x61, 0x6c, 0x65, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x65, 0x69,
+    0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x5b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x5b,
+    0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x5b, 0x12, 0x13,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x5c, 0x02, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x03, 0x05, 0x12, 0x03, 0x5c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x5c, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x03, 0x03, 0x12, 0x03, 0x5c, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x04, 0x12,
+    0x03, 0x5d, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x05, 0x12, 0x03, 0x5d,
+    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x01, 0x12, 0x03, 0x5d, 0x09, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x04, 0x03, 0x12, 0x03, 0x5d, 0x19, 0x1a, 0x0a, 0x5e,
+    0x0a, 0x04, 0x04, 0x05, 0x02, 0x05, 0x12, 0x03, 0x5f, 0x02, 0x17, 0x1a, 0x51, 0x20, 0x45, 0x54,
+    0x48, 0x20, 0x61, 0x64, 0x64, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70,
+    0x6f, 0x6f, 0x6c, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x20, 0x61, 0x74, 0x20, 0x66, 0x69, 0x6e, 0x61, 0x6c, 0x69, 0x7a, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x2c, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x65, 0x69, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x65,
+    0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x05, 0x05, 0x12, 0x03, 0x5f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x05, 0x01, 0x12, 0x03, 0x5f, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x05, 0x03, 0x12, 0x03, 0x5f, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x06,
+    0x12, 0x03, 0x60, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06, 0x05, 0x12, 0x03,
+    0x60, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06, 0x01, 0x12, 0x03, 0x60, 0x08,
+    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x06, 0x03, 0x12, 0x03, 0x60, 0x13, 0x14, 0x0a,
+    0x63, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x64, 0x00, 0x68, 0x01, 0x1a, 0x57, 0x20, 0x54, 0x6f,
+    0x6b, 0x65, 0x6e, 0x20, 0x63, 0x61, 0x6e, 0x64, 0x69, 0x64, 0x61, 0x74, 0x65, 0x73, 0x20, 0x6f,
+    0x66, 0x20, 0x6d, 0x61, 0x70, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x72, 0x65, 0x6a, 0x65, 0x63, 0x74, 0x65, 0x64, 0x2c,
+    0x20, 0x74, 0x6f, 0x20, 0x61, 0x75, 0x64, 0x69, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x64, 0x69,
+    0x73, 0x63, 0x6f, 0x76, 0x65, 0x72, 0x79, 0x20, 0x68, 0x65, 0x75, 0x72, 0x69, 0x73, 0x74, 0x69,
+    0x63, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x64, 0x08, 0x17,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x65, 0x02, 0x29, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x65, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x00, 0x06, 0x12, 0x03, 0x65, 0x0b, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x65, 0x1a, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x65, 0x27, 0x28, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x66,
+    0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x66, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x66, 0x09, 0x12, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x66, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x67, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x02, 0x05, 0x12, 0x03, 0x67, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x67, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03,
+    0x67, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x05, 0x6a, 0x00, 0x84, 0x01, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x6a, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x07, 0x04, 0x00, 0x12, 0x04, 0x6b, 0x02, 0x75, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x04, 0x00, 0x01, 0x12, 0x03, 0x6b, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00,
+    0x02, 0x00, 0x12, 0x03, 0x6c, 0x04, 0x1b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x6c, 0x04, 0x16, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02,
+    0x00, 0x02, 0x12, 0x03, 0x6c, 0x19, 0x1a, 0x0a, 0x45, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02,
+    0x01, 0x12, 0x03, 0x6e, 0x04, 0x1e, 0x1a, 0x36, 0x20, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64,
+    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74,
+    0x6f, 0x6f, 0x20, 0x6c, 0x69, 0x74, 0x74, 0x6c, 0x65, 0x20, 0x63, 0x6f, 0x64, 0x65, 0x20, 0x74,
+    0x6f, 0x20, 0x62, 0x65, 0x20, 0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6e, 0x04, 0x19, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x6e, 0x1c, 0x1d, 0x0a, 0x49,
+    0x0a, 0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x70, 0x04, 0x1c, 0x1a, 0x3a, 0x20,
+    0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x63, 0x61, 0x6c,
+    0x6c, 0x65, 0x72, 0x20, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x64, 0x65, 0x70,
+    0x6c, 0x6f, 0x79, 0x20, 0x6e, 0x6f, 0x6e, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x63, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04,
+    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x70, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04,
+    0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x70, 0x1a, 0x1b, 0x0a, 0x22, 0x0a, 0x06, 0x04, 0x07, 0x04,
+    0x00, 0x02, 0x03, 0x12, 0x03, 0x72, 0x04, 0x1b, 0x1a, 0x13, 0x20, 0x41, 0x20, 0x70, 0x72, 0x6f,
+    0x62, 0x65, 0x20, 0x72, 0x65, 0x76, 0x65, 0x72, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x72, 0x04, 0x16, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x07, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x72, 0x19, 0x1a, 0x0a, 0x3c, 0x0a,
+    0x06, 0x04, 0x07, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x74, 0x04, 0x1d, 0x1a, 0x2d, 0x20, 0x41,
+    0x20, 0x70, 0x72, 0x6f, 0x62, 0x65, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x65, 0x64, 0x20,
+    0x64, 0x61, 0x74, 0x61, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x64, 0x6f, 0x65, 0x73, 0x20, 0x6e,
+    0x6f, 0x74, 0x20, 0x64, 0x65, 0x63, 0x6f, 0x64, 0x65, 0x2e, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x07, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x74, 0x04, 0x18, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
+    0x07, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x74, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x07, 0x04, 0x01, 0x12, 0x04, 0x76, 0x02, 0x7b, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x04,
+    0x01, 0x01, 0x12, 0x03, 0x76, 0x07, 0x0c, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x01, 0x02,
+    0x00, 0x12, 0x03, 0x77, 0x04, 0x1a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x01, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x77, 0x04, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x01, 0x02, 0x00,
+    0x02, 0x12, 0x03, 0x77, 0x18, 0x19, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x01, 0x02, 0x01,
+    0x12, 0x03, 0x78, 0x04, 0x17, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x01, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x78, 0x04, 0x12, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x01, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x78, 0x15, 0x16, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x01, 0x02, 0x02, 0x12,
+    0x03, 0x79, 0x04, 0x13, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x79, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x01, 0x02, 0x02, 0x02, 0x12,
+    0x03, 0x79, 0x11, 0x12, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03,
+    0x7a, 0x04, 0x15, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x7a, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x07, 0x04, 0x01, 0x02, 0x03, 0x02, 0x12, 0x03,
+    0x7a, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x7c, 0x02, 0x14,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7c, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7c, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7c, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07,
+    0x02, 0x01, 0x12, 0x03, 0x7d, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x06,
+    0x12, 0x03, 0x7d, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x7d, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x7d, 0x12,
+    0x13, 0x0a, 0x5d, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x7f, 0x02, 0x12, 0x1a, 0x50,
+    0x20, 0x50, 0x72, 0x6f, 0x62, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x66, 0x61, 0x69, 0x6c,
+    0x65, 0x64, 0x2c, 0x20, 0x75, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20,
+    0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6e, 0x64, 0x69, 0x64, 0x61,
+    0x74, 0x65, 0x20, 0x77, 0x61, 0x73, 0x20, 0x72, 0x65, 0x6a, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20,
+    0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x62, 0x69, 0x6e, 0x67, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x06, 0x12, 0x03, 0x7f, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01, 0x12, 0x03, 0x7f, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x02, 0x03, 0x12, 0x03, 0x7f, 0x10, 0x11, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x07,
+    0x02, 0x03, 0x12, 0x04, 0x81, 0x01, 0x02, 0x14, 0x1a, 0x40, 0x20, 0x52, 0x65, 0x76, 0x65, 0x72,
+    0x74, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2c, 0x20, 0x64, 0x65, 0x63, 0x6f, 0x64, 0x69, 0x6e, 0x67,
+    0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x64, 0x65, 0x20, 0x73,
+    0x69, 0x7a, 0x65, 0x20, 0x62, 0x65, 0x68, 0x69, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72,
+    0x65, 0x6a, 0x65, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x03, 0x05, 0x12, 0x04, 0x81, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x03, 0x01, 0x12, 0x04, 0x81, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03,
+    0x03, 0x12, 0x04, 0x81, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x04, 0x12,
+    0x04, 0x82, 0x01, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x05, 0x12, 0x04,
+    0x82, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x01, 0x12, 0x04, 0x82,
+    0x01, 0x08, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x03, 0x12, 0x04, 0x82, 0x01,
+    0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x05, 0x12, 0x04, 0x83, 0x01, 0x02, 0x15,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x05, 0x12, 0x04, 0x83, 0x01, 0x02, 0x07, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x01, 0x12, 0x04, 0x83, 0x01, 0x08, 0x10, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x05, 0x03, 0x12, 0x04, 0x83, 0x01, 0x13, 0x14, 0x0a, 0x4b, 0x0a,
+    0x02, 0x04, 0x08, 0x12, 0x06, 0x87, 0x01, 0x00, 0x8e, 0x01, 0x01, 0x1a, 0x3d, 0x20, 0x54, 0x72,
+    0x61, 0x63, 0x6b, 0x20, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20,
+    0x64, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65, 0x72, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x77, 0x61, 0x73, 0x20, 0x64, 0x69,
+    0x73, 0x63, 0x6f, 0x76, 0x65, 0x72, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x08,
+    0x01, 0x12, 0x04, 0x87, 0x01, 0x08, 0x1a, 0x0a, 0x30, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12,
+    0x04, 0x89, 0x01, 0x02, 0x16, 0x1a, 0x22, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x64,
+    0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65, 0x64, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x74,
+    0x68, 0x69, 0x73, 0x20, 0x6f, 0x6e, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x00, 0x05, 0x12, 0x04, 0x89, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0x89, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0x89, 0x01, 0x14, 0x15, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x04,
+    0x8b, 0x01, 0x02, 0x14, 0x1a, 0x40, 0x20, 0x48, 0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x20, 0x67, 0x6f, 0x74, 0x20, 0x6d, 0x6f, 0x72, 0x65,
+    0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x39, 0x30, 0x25, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x69, 0x72, 0x20, 0x6c, 0x69, 0x71, 0x75, 0x69, 0x64, 0x69, 0x74, 0x79, 0x20, 0x72, 0x65, 0x6d,
+    0x6f, 0x76, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12,
+    0x04, 0x8b, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0x8b, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8b,
+    0x01, 0x12, 0x13, 0x0a, 0x57, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x04, 0x8d, 0x01, 0x02,
+    0x13, 0x1a, 0x49, 0x20, 0x46, 0x72, 0x6f, 0x6d, 0x20, 0x30, 0x20, 0x28, 0x65, 0x76, 0x65, 0x72,
+    0x79, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x72, 0x75, 0x67, 0x67, 0x65, 0x64, 0x29, 0x20,
+    0x74, 0x6f, 0x20, 0x31, 0x30, 0x30, 0x20, 0x28, 0x6e, 0x6f, 0x6e, 0x65, 0x20, 0x72, 0x75, 0x67,
+    0x67, 0x65, 0x64, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x6e, 0x6f, 0x20, 0x70, 0x72, 0x65, 0x76, 0x69,
+    0x6f, 0x75, 0x73, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x29, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x04, 0x8d, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x02, 0x01, 0x12, 0x04, 0x8d, 0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0x8d, 0x01, 0x11, 0x12, 0x0a, 0x66, 0x0a, 0x02, 0x04, 0x09, 0x12,
+    0x06, 0x91, 0x01, 0x00, 0x9c, 0x01, 0x01, 0x1a, 0x58, 0x20, 0x48, 0x6f, 0x77, 0x20, 0x63, 0x6f,
+    0x6e, 0x63, 0x65, 0x6e, 0x74, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62,
+    0x75, 0x79, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x20, 0x77, 0x61, 0x73, 0x20, 0x72, 0x69, 0x67, 0x68, 0x74, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72,
+    0x20, 0x69, 0x74, 0x73, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x44, 0x45, 0x58, 0x20, 0x70,
+    0x6f, 0x6f, 0x6c, 0x20, 0x67, 0x6f, 0x74, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x2e,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x04, 0x91, 0x01, 0x08, 0x15, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x04, 0x92, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x04, 0x92, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x00, 0x01, 0x12, 0x04, 0x92, 0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0x92, 0x01, 0x18, 0x19, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x09, 0x02,
+    0x01, 0x12, 0x04, 0x94, 0x01, 0x02, 0x14, 0x1a, 0x35, 0x20, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x73,
+    0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x60, 0x6c, 0x61, 0x75, 0x6e, 0x63, 0x68, 0x5f, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x60, 0x20, 0x77, 0x68, 0x6f, 0x73, 0x65, 0x20, 0x62, 0x75, 0x79, 0x73,
+    0x20, 0x61, 0x72, 0x65, 0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x04, 0x94, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x04, 0x94, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x04, 0x94, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x09, 0x02, 0x02, 0x12, 0x04, 0x95, 0x01, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0x95, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0x95, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0x95, 0x01, 0x12, 0x13, 0x0a, 0x58, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x04,
+    0x97, 0x01, 0x02, 0x1e, 0x1a, 0x4a, 0x20, 0x50, 0x65, 0x72, 0x63, 0x65, 0x6e, 0x74, 0x61, 0x67,
+    0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x75, 0x70, 0x70, 0x6c, 0x79, 0x20,
+    0x62, 0x6f, 0x75, 0x67, 0x68, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6c, 0x61, 0x72, 0x67, 0x65, 0x73, 0x74, 0x20, 0x62, 0x75, 0x79, 0x65, 0x72, 0x73, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x05, 0x12, 0x04, 0x97, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x04, 0x97, 0x01, 0x09, 0x19, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x03, 0x12, 0x04, 0x97, 0x01, 0x1c, 0x1d, 0x0a, 0x4a, 0x0a,
+    0x04, 0x04, 0x09, 0x02, 0x04, 0x12, 0x04, 0x99, 0x01, 0x02, 0x12, 0x1a, 0x3c, 0x20, 0x57, 0x68,
+    0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x60, 0x74, 0x6f, 0x70, 0x5f, 0x62, 0x75, 0x79, 0x65, 0x72,
+    0x73, 0x5f, 0x73, 0x68, 0x61, 0x72, 0x65, 0x60, 0x20, 0x69, 0x73, 0x20, 0x61, 0x62, 0x6f, 0x76,
+    0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6e, 0x69, 0x70, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68,
+    0x72, 0x65, 0x73, 0x68, 0x6f, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x04, 0x05, 0x12, 0x04, 0x99, 0x01, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04,
+    0x01, 0x12, 0x04, 0x99, 0x01, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x03,
+    0x12, 0x04, 0x99, 0x01, 0x10, 0x11, 0x0a, 0x57, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x05, 0x12, 0x04,
+    0x9b, 0x01, 0x02, 0x13, 0x1a, 0x49, 0x20, 0x46, 0x72, 0x6f, 0x6d, 0x20, 0x30, 0x20, 0x28, 0x73,
+    0x75, 0x70, 0x70, 0x6c, 0x79, 0x20, 0x63, 0x6f, 0x72, 0x6e, 0x65, 0x72, 0x65, 0x64, 0x29, 0x20,
+    0x74, 0x6f, 0x20, 0x31, 0x30, 0x30, 0x20, 0x28, 0x6e, 0x6f, 0x74, 0x68, 0x69, 0x6e, 0x67, 0x20,
+    0x62, 0x6f, 0x75, 0x67, 0x68, 0x74, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61,
+    0x72, 0x67, 0x65, 0x73, 0x74, 0x20, 0x62, 0x75, 0x79, 0x65, 0x72, 0x73, 0x29, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x05, 0x12, 0x04, 0x9b, 0x01, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x01, 0x12, 0x04, 0x9b, 0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x05, 0x03, 0x12, 0x04, 0x9b, 0x01, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x02,
+    0x04, 0x0a, 0x12, 0x06, 0x9e, 0x01, 0x00, 0xa4, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0a,
+    0x01, 0x12, 0x04, 0x9e, 0x01, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12,
+    0x04, 0x9f, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x04, 0x12, 0x04,
+    0x9f, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x06, 0x12, 0x04, 0x9f,
+    0x01, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9f, 0x01,
+    0x14, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9f, 0x01, 0x20,
+    0x21, 0x0a, 0x64, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x02, 0x2c, 0x1a,
+    0x56, 0x20, 0x50, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x74, 0x6f, 0x74, 0x61,
+    0x6c, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c,
+    0x20, 0x73, 0x65, 0x74, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x6d,
+    0x6f, 0x64, 0x65, 0x60, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x20, 0x69, 0x73, 0x20, 0x60, 0x73,
+    0x61, 0x6d, 0x70, 0x6c, 0x65, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x61, 0x67, 0x67, 0x72, 0x65,
+    0x67, 0x61, 0x74, 0x65, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x04,
+    0x12, 0x04, 0xa1, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x06, 0x12,
+    0x04, 0xa1, 0x01, 0x0b, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0xa1, 0x01, 0x1d, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa1,
+    0x01, 0x2a, 0x2b, 0x0a, 0x5d, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x04, 0xa3, 0x01, 0x02,
+    0x22, 0x1a, 0x4f, 0x20, 0x44, 0x75, 0x70, 0x6c, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x54, 0x72,
+    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x6f, 0x66,
+    0x20, 0x61, 0x20, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x20, 0x6d, 0x6f, 0x76, 0x65, 0x6d, 0x65,
+    0x6e, 0x74, 0x20, 0x6c, 0x65, 0x66, 0x74, 0x20, 0x6f, 0x75, 0x74, 0x2c, 0x20, 0x73, 0x65, 0x65,
+    0x20, 0x60, 0x65, 0x72, 0x63, 0x32, 0x30, 0x3a, 0x3a, 0x64, 0x65, 0x63, 0x6f, 0x64, 0x65, 0x60,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x04, 0xa3, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa3, 0x01, 0x09, 0x1d,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa3, 0x01, 0x20, 0x21, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x06, 0xa6, 0x01, 0x00, 0xaf, 0x01, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x0b, 0x01, 0x12, 0x04, 0xa6, 0x01, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0b,
+    0x02, 0x00, 0x12, 0x04, 0xa7, 0x01, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0xa7, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xa7, 0x01, 0x08, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xa7, 0x01, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x04, 0xa8,
+    0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x04, 0xa8, 0x01,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa8, 0x01, 0x09,
+    0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa8, 0x01, 0x1a, 0x1b,
+    0x0a, 0x66, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02, 0x12, 0x04, 0xaa, 0x01, 0x02, 0x14, 0x1a, 0x58,
+    0x20, 0x41, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x62, 0x79,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2c, 0x20,
+    0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x65, 0x73, 0x74, 0x20,
+    0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20,
+    0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02,
+    0x05, 0x12, 0x04, 0xaa, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01,
+    0x12, 0x04, 0xaa, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12,
+    0x04, 0xaa, 0x01, 0x12, 0x13, 0x0a, 0x72, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x03, 0x12, 0x04, 0xac,
+    0x01, 0x02, 0x21, 0x1a, 0x64, 0x20, 0x4e, 0x65, 0x74, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63,
+    0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x76, 0x65, 0x72,
+    0x79, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x6e, 0x76, 0x6f, 0x6c, 0x76,
+    0x65, 0x64, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x7a, 0x65, 0x72, 0x6f, 0x20, 0x61, 0x64, 0x64,
+    0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x64, 0x20, 0x73, 0x6f,
+    0x20, 0x6d, 0x69, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x62, 0x75, 0x72, 0x6e, 0x73,
+    0x20, 0x61, 0x64, 0x64, 0x20, 0x75, 0x70, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x03, 0x04, 0x12, 0x04, 0xac, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03,
+    0x06, 0x12, 0x04, 0xac, 0x01, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03, 0x01,
+    0x12, 0x04, 0xac, 0x01, 0x15, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03, 0x03, 0x12,
+    0x04, 0xac, 0x01, 0x1f, 0x20, 0x0a, 0x47, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x04, 0x12, 0x04, 0xae,
+    0x01, 0x02, 0x15, 0x1a, 0x39, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20,
+    0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x04, 0x05, 0x12, 0x04, 0xae, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x04, 0x01, 0x12, 0x04, 0xae, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x04, 0x03, 0x12, 0x04, 0xae, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x0c, 0x12, 0x06, 0xb1, 0x01, 0x00, 0xb5, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0c, 0x01,
+    0x12, 0x04, 0xb1, 0x01, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x04,
+    0xb2, 0x01, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb2,
+    0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb2, 0x01,
+    0x08, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb2, 0x01, 0x11,
+    0x12, 0x0a, 0x55, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x04, 0xb4, 0x01, 0x02, 0x14, 0x1a,
+    0x47, 0x20, 0x53, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x20,
+    0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x65, 0x73, 0x74, 0x20,
+    0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20,
+    0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0xb4, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0xb4, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0xb4, 0x01, 0x12, 0x13, 0x0a, 0x5f, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x06, 0xb8, 0x01, 0x00,
+    0xcd, 0x01, 0x01, 0x1a, 0x51, 0x20, 0x41, 0x20, 0x66, 0x75, 0x6e, 0x67, 0x69, 0x62, 0x6c, 0x65,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2c,
+    0x20, 0x6d, 0x69, 0x6e, 0x74, 0x73, 0x20, 0x63, 0x6f, 0x6d, 0x65, 0x20, 0x66, 0x72, 0x6f, 0x6d,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x7a, 0x65, 0x72, 0x6f, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x62, 0x75, 0x72, 0x6e, 0x73, 0x20, 0x67, 0x6f, 0x20, 0x74,
+    0x6f, 0x20, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x04, 0xb8,
+    0x01, 0x08, 0x10, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x0d, 0x04, 0x00, 0x12, 0x06, 0xb9, 0x01, 0x02,
+    0xc0, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x04, 0x00, 0x01, 0x12, 0x04, 0xb9, 0x01,
+    0x07, 0x0f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xba, 0x01,
+    0x04, 0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xba,
+    0x01, 0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04,
+    0xba, 0x01, 0x1b, 0x1c, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04,
+    0xbb, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xbb, 0x01, 0x04, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x01, 0x02,
+    0x12, 0x04, 0xbb, 0x01, 0x15, 0x16, 0x0a, 0x6f, 0x0a, 0x06, 0x04, 0x0d, 0x04, 0x00, 0x02, 0x02,
+    0x12, 0x04, 0xbd, 0x01, 0x04, 0x18, 0x1a, 0x5f, 0x20, 0x60, 0x53, 0x65, 0x6e, 0x74, 0x60, 0x2c,
+    0x20, 0x60, 0x4d, 0x69, 0x6e, 0x74, 0x65, 0x64, 0x60, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x60, 0x42,
+    0x75, 0x72, 0x6e, 0x65, 0x64, 0x60, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x2c, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x65, 0x76, 0x65, 0x6e,
+    0x74, 0x73, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x61, 0x6c, 0x6f, 0x6e, 0x67,
+    0x73, 0x69, 0x64, 0x65, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x20, 0x61, 0x72, 0x65, 0x20, 0x73, 0x6b,
+    0x69, 0x70, 0x70, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0xbd, 0x01, 0x04, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0d, 0x04, 0x00,
+    0x02, 0x02, 0x02, 0x12, 0x04, 0xbd, 0x01, 0x16, 0x17, 0x0a, 0x5f, 0x0a, 0x06, 0x04, 0x0d, 0x04,
+    0x00, 0x02, 0x03, 0x12, 0x04, 0xbf, 0x01, 0x04, 0x19, 0x1a, 0x4f, 0x20, 0x54, 0x72, 0x61, 0x6e,
+    0x73, 0x66, 0x65, 0x72, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x65, 0x6d, 0x69, 0x74,
+    0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x60, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x41, 0x6e, 0x64, 0x43, 0x61, 0x6c, 0x6c, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x74,
+    0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x46, 0x72, 0x6f, 0x6d, 0x41, 0x6e, 0x64, 0x43, 0x61,
+    0x6c, 0x6c, 0x60, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0d,
+    0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0xbf, 0x01, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x0d, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xbf, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x0d, 0x02, 0x00, 0x12, 0x04, 0xc1, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x00, 0x06, 0x12, 0x04, 0xc1, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xc1, 0x01, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xc1, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12,
+    0x04, 0xc2, 0x01, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x05, 0x12, 0x04,
+    0xc2, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc2,
+    0x01, 0x08, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x03, 0x12, 0x04, 0xc2, 0x01,
+    0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x02, 0x12, 0x04, 0xc3, 0x01, 0x02, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x05, 0x12, 0x04, 0xc3, 0x01, 0x02, 0x07, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x01, 0x12, 0x04, 0xc3, 0x01, 0x08, 0x0c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x03, 0x12, 0x04, 0xc3, 0x01, 0x0f, 0x10, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x0d, 0x02, 0x03, 0x12, 0x04, 0xc4, 0x01, 0x02, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x03, 0x05, 0x12, 0x04, 0xc4, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x03, 0x01, 0x12, 0x04, 0xc4, 0x01, 0x08, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x03, 0x03, 0x12, 0x04, 0xc4, 0x01, 0x0d, 0x0e, 0x0a, 0x4e, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x04,
+    0x12, 0x04, 0xc6, 0x01, 0x02, 0x14, 0x1a, 0x40, 0x20, 0x41, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x20,
+    0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x65, 0x73, 0x74, 0x20,
+    0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20,
+    0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04,
+    0x05, 0x12, 0x04, 0xc6, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0xc6, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x04, 0x03, 0x12,
+    0x04, 0xc6, 0x01, 0x12, 0x13, 0x0a, 0x55, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x05, 0x12, 0x04, 0xc8,
+    0x01, 0x02, 0x15, 0x1a, 0x47, 0x20, 0x45, 0x52, 0x43, 0x37, 0x37, 0x37, 0x20, 0x6f, 0x70, 0x65,
+    0x72, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x6d, 0x6f, 0x76, 0x65, 0x64,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2c, 0x20, 0x65, 0x6d, 0x70,
+    0x74, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72,
+    0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x05, 0x05, 0x12, 0x04, 0xc8, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x05, 0x01, 0x12, 0x04, 0xc8, 0x01, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x05, 0x03, 0x12, 0x04, 0xc8, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02,
+    0x06, 0x12, 0x04, 0xc9, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x06, 0x05,
+    0x12, 0x04, 0xc9, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x06, 0x01, 0x12,
+    0x04, 0xc9, 0x01, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x06, 0x03, 0x12, 0x04,
+    0xc9, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x07, 0x12, 0x04, 0xca, 0x01,
+    0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x07, 0x05, 0x12, 0x04, 0xca, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x07, 0x01, 0x12, 0x04, 0xca, 0x01, 0x09, 0x10,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x07, 0x03, 0x12, 0x04, 0xca, 0x01, 0x13, 0x14, 0x0a,
+    0x60, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x08, 0x12, 0x04, 0xcc, 0x01, 0x02, 0x10, 0x1a, 0x52, 0x20,
+    0x60, 0x3c, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x3e, 0x2d, 0x3c, 0x6f, 0x72, 0x64,
+    0x69, 0x6e, 0x61, 0x6c, 0x3e, 0x60, 0x2c, 0x20, 0x75, 0x6e, 0x69, 0x71, 0x75, 0x65, 0x20, 0x61,
+    0x6e, 0x64, 0x20, 0x73, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73,
+    0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x79, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x69, 0x6e,
+    0x6b, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x75, 0x70, 0x73, 0x65, 0x72, 0x74, 0x20, 0x6f, 0x6e, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x08, 0x05, 0x12, 0x04, 0xcc, 0x01, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x08, 0x01, 0x12, 0x04, 0xcc, 0x01, 0x09, 0x0b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x08, 0x03, 0x12, 0x04, 0xcc, 0x01, 0x0e, 0x0f, 0x0a, 0x6f,
+    0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06, 0xd0, 0x01, 0x00, 0xdc, 0x01, 0x01, 0x1a, 0x61, 0x20, 0x54,
+    0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x20, 0x61, 0x73, 0x20, 0x70, 0x61, 0x72, 0x61, 0x6c, 0x6c, 0x65, 0x6c, 0x20,
+    0x61, 0x72, 0x72, 0x61, 0x79, 0x73, 0x2c, 0x20, 0x65, 0x6e, 0x74, 0x72, 0x79, 0x20, 0x60, 0x69,
+    0x60, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x63, 0x6f, 0x6c, 0x75, 0x6d,
+    0x6e, 0x20, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x62, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x61, 0x6d, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2e, 0x0a, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x04, 0xd0, 0x01, 0x08, 0x17, 0x0a, 0x57, 0x0a, 0x04,
+    0x04, 0x0e, 0x02, 0x00, 0x12, 0x04, 0xd2, 0x01, 0x02, 0x1c, 0x1a, 0x49, 0x20, 0x44, 0x69, 0x73,
+    0x74, 0x69, 0x6e, 0x63, 0x74, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x72, 0x65, 0x66, 0x65,
+    0x72, 0x72, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x79, 0x20, 0x70, 0x6f, 0x73, 0x69, 0x74,
+    0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x04, 0x12, 0x04,
+    0xd2, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x05, 0x12, 0x04, 0xd2,
+    0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd2, 0x01,
+    0x11, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd2, 0x01, 0x1a,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x04, 0xd3, 0x01, 0x02, 0x20, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x04, 0x12, 0x04, 0xd3, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x05, 0x12, 0x04, 0xd3, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd3, 0x01, 0x11, 0x1b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd3, 0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x0e, 0x02, 0x02, 0x12, 0x04, 0xd4, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x02, 0x04, 0x12, 0x04, 0xd4, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02,
+    0x05, 0x12, 0x04, 0xd4, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x01,
+    0x12, 0x04, 0xd4, 0x01, 0x12, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x03, 0x12,
+    0x04, 0xd4, 0x01, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x03, 0x12, 0x04, 0xd5,
+    0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x04, 0x12, 0x04, 0xd5, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x05, 0x12, 0x04, 0xd5, 0x01, 0x0b,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x01, 0x12, 0x04, 0xd5, 0x01, 0x12, 0x1b,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x03, 0x12, 0x04, 0xd5, 0x01, 0x1e, 0x1f, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x04, 0x12, 0x04, 0xd6, 0x01, 0x02, 0x2a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x04, 0x04, 0x12, 0x04, 0xd6, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0e, 0x02, 0x04, 0x06, 0x12, 0x04, 0xd6, 0x01, 0x0b, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x04, 0x01, 0x12, 0x04, 0xd6, 0x01, 0x1d, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x04, 0x03, 0x12, 0x04, 0xd6, 0x01, 0x28, 0x29, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02,
+    0x05, 0x12, 0x04, 0xd7, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x04,
+    0x12, 0x04, 0xd7, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x05, 0x12,
+    0x04, 0xd7, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x01, 0x12, 0x04,
+    0xd7, 0x01, 0x11, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x03, 0x12, 0x04, 0xd7,
+    0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x06, 0x12, 0x04, 0xd8, 0x01, 0x02,
+    0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x04, 0x12, 0x04, 0xd8, 0x01, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x05, 0x12, 0x04, 0xd8, 0x01, 0x0b, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x01, 0x12, 0x04, 0xd8, 0x01, 0x11, 0x13, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x03, 0x12, 0x04, 0xd8, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x0e, 0x02, 0x07, 0x12, 0x04, 0xd9, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x07, 0x04, 0x12, 0x04, 0xd9, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x07, 0x05, 0x12, 0x04, 0xd9, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x07, 0x01, 0x12, 0x04, 0xd9, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x07,
+    0x03, 0x12, 0x04, 0xd9, 0x01, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x08, 0x12,
+    0x04, 0xda, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x08, 0x04, 0x12, 0x04,
+    0xda, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x08, 0x05, 0x12, 0x04, 0xda,
+    0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x08, 0x01, 0x12, 0x04, 0xda, 0x01,
+    0x11, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x08, 0x03, 0x12, 0x04, 0xda, 0x01, 0x1c,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x09, 0x12, 0x04, 0xdb, 0x01, 0x02, 0x1f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x09, 0x04, 0x12, 0x04, 0xdb, 0x01, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x09, 0x05, 0x12, 0x04, 0xdb, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x09, 0x01, 0x12, 0x04, 0xdb, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0e, 0x02, 0x09, 0x03, 0x12, 0x04, 0xdb, 0x01, 0x1c, 0x1e, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x0f, 0x12, 0x06, 0xde, 0x01, 0x00, 0xe1, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01,
+    0x12, 0x04, 0xde, 0x01, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x04,
+    0xdf, 0x01, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x04, 0x12, 0x04, 0xdf,
+    0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x06, 0x12, 0x04, 0xdf, 0x01,
+    0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0xdf, 0x01, 0x16,
+    0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x04, 0xdf, 0x01, 0x24, 0x25,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x04, 0xe0, 0x01, 0x02, 0x2c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x04, 0x12, 0x04, 0xe0, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0f, 0x02, 0x01, 0x06, 0x12, 0x04, 0xe0, 0x01, 0x0b, 0x1b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe0, 0x01, 0x1c, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0f, 0x02, 0x01, 0x03, 0x12, 0x04, 0xe0, 0x01, 0x2a, 0x2b, 0x0a, 0x67, 0x0a, 0x02, 0x04, 0x10,
+    0x12, 0x06, 0xe4, 0x01, 0x00, 0xed, 0x01, 0x01, 0x1a, 0x59, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30,
+    0x56, 0x6f, 0x74, 0x65, 0x73, 0x20, 0x60, 0x44, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x65, 0x43,
+    0x68, 0x61, 0x6e, 0x67, 0x65, 0x64, 0x60, 0x3a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x64, 0x65, 0x6c,
+    0x65, 0x67, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x69, 0x74, 0x73,
+    0x20, 0x76, 0x6f, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x70, 0x6f, 0x77, 0x65, 0x72, 0x20, 0x74, 0x6f,
+    0x20, 0x61, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x64, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74,
+    0x65, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0xe4, 0x01, 0x08, 0x12,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04, 0xe5, 0x01, 0x02, 0x12, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe5, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe5, 0x01, 0x08, 0x0d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe5, 0x01, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x10, 0x02, 0x01, 0x12, 0x04, 0xe6, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x01, 0x05, 0x12, 0x04, 0xe6, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0xe6, 0x01, 0x08, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03,
+    0x12, 0x04, 0xe6, 0x01, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x02, 0x12, 0x04,
+    0xe7, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x05, 0x12, 0x04, 0xe7,
+    0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x01, 0x12, 0x04, 0xe7, 0x01,
+    0x08, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x03, 0x12, 0x04, 0xe7, 0x01, 0x18,
+    0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x03, 0x12, 0x04, 0xe8, 0x01, 0x02, 0x18, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x05, 0x12, 0x04, 0xe8, 0x01, 0x02, 0x07, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x01, 0x12, 0x04, 0xe8, 0x01, 0x08, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x03, 0x03, 0x12, 0x04, 0xe8, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x10, 0x02, 0x04, 0x12, 0x04, 0xe9, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x04, 0x05, 0x12, 0x04, 0xe9, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x04, 0x01, 0x12, 0x04, 0xe9, 0x01, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04,
+    0x03, 0x12, 0x04, 0xe9, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x05, 0x12,
+    0x04, 0xea, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x05, 0x05, 0x12, 0x04,
+    0xea, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x05, 0x01, 0x12, 0x04, 0xea,
+    0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x05, 0x03, 0x12, 0x04, 0xea, 0x01,
+    0x13, 0x14, 0x0a, 0x60, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x06, 0x12, 0x04, 0xec, 0x01, 0x02, 0x10,
+    0x1a, 0x52, 0x20, 0x60, 0x3c, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x3e, 0x2d, 0x3c,
+    0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x3e, 0x60, 0x2c, 0x20, 0x75, 0x6e, 0x69, 0x71, 0x75,
+    0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x61, 0x63, 0x72,
+    0x6f, 0x73, 0x73, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x79, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x73, 0x69, 0x6e, 0x6b, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x75, 0x70, 0x73, 0x65, 0x72, 0x74, 0x20,
+    0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x06, 0x05, 0x12, 0x04, 0xec,
+    0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x06, 0x01, 0x12, 0x04, 0xec, 0x01,
+    0x09, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x06, 0x03, 0x12, 0x04, 0xec, 0x01, 0x0e,
+    0x0f, 0x0a, 0x83, 0x01, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x06, 0xf0, 0x01, 0x00, 0xfb, 0x01, 0x01,
+    0x1a, 0x75, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30, 0x56, 0x6f, 0x74, 0x65, 0x73, 0x20, 0x60, 0x44,
+    0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x65, 0x56, 0x6f, 0x74, 0x65, 0x73, 0x43, 0x68, 0x61, 0x6e,
+    0x67, 0x65, 0x64, 0x60, 0x3a, 0x20, 0x76, 0x6f, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x70, 0x6f, 0x77,
+    0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x64, 0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x65,
+    0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20,
+    0x6f, 0x6e, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x72, 0x65, 0x61, 0x64, 0x20, 0x62, 0x79, 0x20, 0x73,
+    0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x2d, 0x73, 0x74, 0x79, 0x6c, 0x65, 0x20, 0x74, 0x6f,
+    0x6f, 0x6c, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x04,
+    0xf0, 0x01, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x04, 0xf1, 0x01,
+    0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x05, 0x12, 0x04, 0xf1, 0x01, 0x02,
+    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf1, 0x01, 0x08, 0x0d,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf1, 0x01, 0x10, 0x11, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x01, 0x12, 0x04, 0xf2, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x11, 0x02, 0x01, 0x05, 0x12, 0x04, 0xf2, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x11, 0x02, 0x01, 0x01, 0x12, 0x04, 0xf2, 0x01, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x11, 0x02, 0x01, 0x03, 0x12, 0x04, 0xf2, 0x01, 0x13, 0x14, 0x0a, 0x70, 0x0a, 0x04, 0x04, 0x11,
+    0x02, 0x02, 0x12, 0x04, 0xf4, 0x01, 0x02, 0x1c, 0x1a, 0x62, 0x20, 0x56, 0x6f, 0x74, 0x69, 0x6e,
+    0x67, 0x20, 0x70, 0x6f, 0x77, 0x65, 0x72, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x61,
+    0x6e, 0x64, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61,
+    0x6e, 0x67, 0x65, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x6c,
+    0x6c, 0x65, 0x73, 0x74, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d,
+    0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x11, 0x02, 0x02, 0x05, 0x12, 0x04, 0xf4, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x11, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf4, 0x01, 0x09, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0xf4, 0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02,
+    0x03, 0x12, 0x04, 0xf5, 0x01, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x05,
+    0x12, 0x04, 0xf5, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x01, 0x12,
+    0x04, 0xf5, 0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x03, 0x12, 0x04,
+    0xf5, 0x01, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x04, 0x12, 0x04, 0xf6, 0x01,
+    0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x04, 0x05, 0x12, 0x04, 0xf6, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x04, 0x01, 0x12, 0x04, 0xf6, 0x01, 0x09, 0x0e,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x04, 0x03, 0x12, 0x04, 0xf6, 0x01, 0x11, 0x12, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x05, 0x12, 0x04, 0xf7, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x11, 0x02, 0x05, 0x05, 0x12, 0x04, 0xf7, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x11, 0x02, 0x05, 0x01, 0x12, 0x04, 0xf7, 0x01, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x11, 0x02, 0x05, 0x03, 0x12, 0x04, 0xf7, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11,
+    0x02, 0x06, 0x12, 0x04, 0xf8, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x06,
+    0x05, 0x12, 0x04, 0xf8, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x06, 0x01,
+    0x12, 0x04, 0xf8, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x06, 0x03, 0x12,
+    0x04, 0xf8, 0x01, 0x13, 0x14, 0x0a, 0x60, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x07, 0x12, 0x04, 0xfa,
+    0x01, 0x02, 0x10, 0x1a, 0x52, 0x20, 0x60, 0x3c, 0x74, 0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68,
+    0x3e, 0x2d, 0x3c, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x3e, 0x60, 0x2c, 0x20, 0x75, 0x6e,
+    0x69, 0x71, 0x75, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20,
+    0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x79, 0x73, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x73, 0x69, 0x6e, 0x6b, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x75, 0x70, 0x73, 0x65,
+    0x72, 0x74, 0x20, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x07, 0x05,
+    0x12, 0x04, 0xfa, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x07, 0x01, 0x12,
+    0x04, 0xfa, 0x01, 0x09, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x07, 0x03, 0x12, 0x04,
+    0xfa, 0x01, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x06, 0xfd, 0x01, 0x00, 0xff,
+    0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x04, 0xfd, 0x01, 0x08, 0x16, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x00, 0x12, 0x04, 0xfe, 0x01, 0x02, 0x24, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x00, 0x04, 0x12, 0x04, 0xfe, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x12, 0x02, 0x00, 0x06, 0x12, 0x04, 0xfe, 0x01, 0x0b, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x12, 0x02, 0x00, 0x01, 0x12, 0x04, 0xfe, 0x01, 0x19, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xfe, 0x01, 0x22, 0x23, 0x0a, 0x7e, 0x0a, 0x02, 0x04, 0x13, 0x12,
+    0x06, 0x82, 0x02, 0x00, 0xa2, 0x02, 0x01, 0x1a, 0x70, 0x20, 0x47, 0x6f, 0x76, 0x65, 0x72, 0x6e,
+    0x6f, 0x72, 0x20, 0x42, 0x72, 0x61, 0x76, 0x6f, 0x20, 0x6f, 0x72, 0x20, 0x4f, 0x70, 0x65, 0x6e,
+    0x5a, 0x65, 0x70, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x20, 0x47, 0x6f, 0x76, 0x65, 0x72, 0x6e, 0x6f,
+    0x72, 0x20, 0x70, 0x72, 0x6f, 0x70, 0x6f, 0x73, 0x61, 0x6c, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74,
+    0x2c, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6c, 0x6f, 0x67,
+    0x67, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20,
+    0x6f, 0x66, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6c, 0x65, 0x66,
+    0x74, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x13, 0x01,
+    0x12, 0x04, 0x82, 0x02, 0x08, 0x15, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x13, 0x04, 0x00, 0x12, 0x06,
+    0x83, 0x02, 0x02, 0x8a, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x04, 0x00, 0x01, 0x12,
+    0x04, 0x83, 0x02, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x13, 0x04, 0x00, 0x02, 0x00, 0x12,
+    0x04, 0x84, 0x02, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x13, 0x04, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0x84, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x13, 0x04, 0x00, 0x02, 0x00,
+    0x02, 0x12, 0x04, 0x84, 0x02, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x13, 0x04, 0x00, 0x02,
+    0x01, 0x12, 0x04, 0x85, 0x02, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x13, 0x04, 0x00, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0x85, 0x02, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x13, 0x04, 0x00,
+    0x02, 0x01, 0x02, 0x12, 0x04, 0x85, 0x02, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x13, 0x04,
+    0x00, 0x02, 0x02, 0x12, 0x04, 0x86, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x13, 0x04,
+    0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x86, 0x02, 0x04, 0x0f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x13,
+    0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x86, 0x02, 0x12, 0x13, 0x0a, 0x0e, 0x0a, 0x06, 0x04,
+    0x13, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0x87, 0x02, 0x04, 0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x13, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0x87, 0x02, 0x04, 0x11, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x13, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0x87, 0x02, 0x14, 0x15, 0x0a, 0x0e, 0x0a,
+    0x06, 0x04, 0x13, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0x88, 0x02, 0x04, 0x16, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x13, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0x88, 0x02, 0x04, 0x11, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x13, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0x88, 0x02, 0x14, 0x15, 0x0a,
+    0x0e, 0x0a, 0x06, 0x04, 0x13, 0x04, 0x00, 0x02, 0x05, 0x12, 0x04, 0x89, 0x02, 0x04, 0x17, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x13, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0x89, 0x02, 0x04, 0x12,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x13, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0x89, 0x02, 0x15,
+    0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x00, 0x12, 0x04, 0x8c, 0x02, 0x02, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x06, 0x12, 0x04, 0x8c, 0x02, 0x02, 0x06, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8c, 0x02, 0x07, 0x0b, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8c, 0x02, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x13, 0x02, 0x01, 0x12, 0x04, 0x8d, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0x8d, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0x8d, 0x02, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0x8d, 0x02, 0x13, 0x14, 0x0a, 0x64, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x02, 0x12,
+    0x04, 0x8f, 0x02, 0x02, 0x19, 0x1a, 0x56, 0x20, 0x44, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20,
+    0x70, 0x72, 0x6f, 0x70, 0x6f, 0x73, 0x61, 0x6c, 0x20, 0x69, 0x64, 0x2c, 0x20, 0x4f, 0x70, 0x65,
+    0x6e, 0x5a, 0x65, 0x70, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x20, 0x47, 0x6f, 0x76, 0x65, 0x72, 0x6e,
+    0x6f, 0x72, 0x20, 0x69, 0x64, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x65,
+    0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x64, 0x6f, 0x6e, 0x27, 0x74, 0x20, 0x66, 0x69, 0x74,
+    0x20, 0x69, 0x6e, 0x20, 0x36, 0x34, 0x20, 0x62, 0x69, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x02, 0x05, 0x12, 0x04, 0x8f, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x13, 0x02, 0x02, 0x01, 0x12, 0x04, 0x8f, 0x02, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x13, 0x02, 0x02, 0x03, 0x12, 0x04, 0x8f, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13,
+    0x02, 0x03, 0x12, 0x04, 0x90, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03,
+    0x05, 0x12, 0x04, 0x90, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x01,
+    0x12, 0x04, 0x90, 0x02, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x03, 0x03, 0x12,
+    0x04, 0x90, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x04, 0x12, 0x04, 0x91,
+    0x02, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x04, 0x12, 0x04, 0x91, 0x02,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x05, 0x12, 0x04, 0x91, 0x02, 0x0b,
+    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x01, 0x12, 0x04, 0x91, 0x02, 0x11, 0x18,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x04, 0x03, 0x12, 0x04, 0x91, 0x02, 0x1b, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x05, 0x12, 0x04, 0x92, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x05, 0x05, 0x12, 0x04, 0x92, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x13, 0x02, 0x05, 0x01, 0x12, 0x04, 0x92, 0x02, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x13, 0x02, 0x05, 0x03, 0x12, 0x04, 0x92, 0x02, 0x17, 0x18, 0x0a, 0x64, 0x0a, 0x04, 0x04, 0x13,
+    0x02, 0x06, 0x12, 0x04, 0x94, 0x02, 0x02, 0x19, 0x1a, 0x56, 0x20, 0x56, 0x6f, 0x74, 0x69, 0x6e,
+    0x67, 0x20, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6d, 0x6f, 0x73, 0x74, 0x20, 0x67, 0x6f, 0x76,
+    0x65, 0x72, 0x6e, 0x6f, 0x72, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x73, 0x65,
+    0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74,
+    0x61, 0x6d, 0x70, 0x20, 0x62, 0x61, 0x73, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x65, 0x73, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x06, 0x05, 0x12, 0x04, 0x94, 0x02, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x06, 0x01, 0x12, 0x04, 0x94, 0x02, 0x09, 0x14, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x06, 0x03, 0x12, 0x04, 0x94, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x13, 0x02, 0x07, 0x12, 0x04, 0x95, 0x02, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x13, 0x02, 0x07, 0x05, 0x12, 0x04, 0x95, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13,
+    0x02, 0x07, 0x01, 0x12, 0x04, 0x95, 0x02, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x07, 0x03, 0x12, 0x04, 0x95, 0x02, 0x15, 0x16, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x08,
+    0x12, 0x04, 0x97, 0x02, 0x02, 0x11, 0x1a, 0x33, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x20, 0x74, 0x68, 0x65, 0x20, 0x71, 0x75, 0x65, 0x75, 0x65, 0x64, 0x20, 0x70, 0x72,
+    0x6f, 0x70, 0x6f, 0x73, 0x61, 0x6c, 0x20, 0x63, 0x61, 0x6e, 0x20, 0x62, 0x65, 0x20, 0x65, 0x78,
+    0x65, 0x63, 0x75, 0x74, 0x65, 0x64, 0x20, 0x61, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x13, 0x02, 0x08, 0x05, 0x12, 0x04, 0x97, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13,
+    0x02, 0x08, 0x01, 0x12, 0x04, 0x97, 0x02, 0x09, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x08, 0x03, 0x12, 0x04, 0x97, 0x02, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x09,
+    0x12, 0x04, 0x98, 0x02, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x09, 0x05, 0x12,
+    0x04, 0x98, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x09, 0x01, 0x12, 0x04,
+    0x98, 0x02, 0x08, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x09, 0x03, 0x12, 0x04, 0x98,
+    0x02, 0x10, 0x12, 0x0a, 0x2c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x0a, 0x12, 0x04, 0x9a, 0x02, 0x02,
+    0x16, 0x1a, 0x1e, 0x20, 0x30, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x73, 0x74, 0x2c, 0x20, 0x31,
+    0x20, 0x66, 0x6f, 0x72, 0x2c, 0x20, 0x32, 0x20, 0x61, 0x62, 0x73, 0x74, 0x61, 0x69, 0x6e, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x0a, 0x05, 0x12, 0x04, 0x9a, 0x02, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x0a, 0x01, 0x12, 0x04, 0x9a, 0x02, 0x09, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x0a, 0x03, 0x12, 0x04, 0x9a, 0x02, 0x13, 0x15, 0x0a, 0x5e,
+    0x0a, 0x04, 0x04, 0x13, 0x02, 0x0b, 0x12, 0x04, 0x9c, 0x02, 0x02, 0x15, 0x1a, 0x50, 0x20, 0x56,
+    0x6f, 0x74, 0x65, 0x73, 0x20, 0x63, 0x61, 0x73, 0x74, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x65, 0x73, 0x74, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x6f, 0x76, 0x65, 0x72, 0x6e, 0x61, 0x6e, 0x63,
+    0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x64, 0x65,
+    0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x0b, 0x05, 0x12, 0x04, 0x9c, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x0b, 0x01, 0x12, 0x04, 0x9c, 0x02, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x13, 0x02, 0x0b, 0x03, 0x12, 0x04, 0x9c, 0x02, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x13, 0x02, 0x0c, 0x12, 0x04, 0x9d, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x0c, 0x05, 0x12, 0x04, 0x9d, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x0c,
+    0x01, 0x12, 0x04, 0x9d, 0x02, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x0c, 0x03,
+    0x12, 0x04, 0x9d, 0x02, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x0d, 0x12, 0x04,
+    0x9e, 0x02, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x0d, 0x05, 0x12, 0x04, 0x9e,
+    0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x0d, 0x01, 0x12, 0x04, 0x9e, 0x02,
+    0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x0d, 0x03, 0x12, 0x04, 0x9e, 0x02, 0x13,
+    0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x0e, 0x12, 0x04, 0x9f, 0x02, 0x02, 0x16, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x0e, 0x05, 0x12, 0x04, 0x9f, 0x02, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x0e, 0x01, 0x12, 0x04, 0x9f, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x0e, 0x03, 0x12, 0x04, 0x9f, 0x02, 0x13, 0x15, 0x0a, 0x60, 0x0a, 0x04,
+    0x04, 0x13, 0x02, 0x0f, 0x12, 0x04, 0xa1, 0x02, 0x02, 0x11, 0x1a, 0x52, 0x20, 0x60, 0x3c, 0x74,
+    0x72, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x3e, 0x2d, 0x3c, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61,
+    0x6c, 0x3e, 0x60, 0x2c, 0x20, 0x75, 0x6e, 0x69, 0x71, 0x75, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20,
+    0x73, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x72, 0x65,
+    0x70, 0x6c, 0x61, 0x79, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x69, 0x6e, 0x6b, 0x73, 0x20,
+    0x74, 0x6f, 0x20, 0x75, 0x70, 0x73, 0x65, 0x72, 0x74, 0x20, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x13, 0x02, 0x0f, 0x05, 0x12, 0x04, 0xa1, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x0f, 0x01, 0x12, 0x04, 0xa1, 0x02, 0x09, 0x0b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x13, 0x02, 0x0f, 0x03, 0x12, 0x04, 0xa1, 0x02, 0x0e, 0x10, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x14, 0x12, 0x06, 0xa4, 0x02, 0x00, 0xa6, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14, 0x01,
+    0x12, 0x04, 0xa4, 0x02, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x00, 0x12, 0x04,
+    0xa5, 0x02, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa5,
+    0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x06, 0x12, 0x04, 0xa5, 0x02,
+    0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa5, 0x02, 0x18,
+    0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa5, 0x02, 0x21, 0x22,
+    0x0a, 0xb8, 0x01, 0x0a, 0x02, 0x04, 0x15, 0x12, 0x06, 0xaa, 0x02, 0x00, 0xc6, 0x02, 0x01, 0x1a,
+    0xa9, 0x01, 0x20, 0x53, 0x61, 0x62, 0x6c, 0x69, 0x65, 0x72, 0x20, 0x56, 0x31, 0x20, 0x73, 0x74,
+    0x72, 0x65, 0x61, 0x6d, 0x20, 0x6f, 0x72, 0x20, 0x4f, 0x70, 0x65, 0x6e, 0x5a, 0x65, 0x70, 0x70,
+    0x65, 0x6c, 0x69, 0x6e, 0x20, 0x56, 0x65, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x57, 0x61, 0x6c, 0x6c,
+    0x65, 0x74, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x6d, 0x6f, 0x76, 0x69, 0x6e, 0x67, 0x20,
+    0x64, 0x69, 0x73, 0x63, 0x6f, 0x76, 0x65, 0x72, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x73, 0x20, 0x69, 0x6e, 0x20, 0x6f, 0x72, 0x20, 0x6f, 0x75, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x61,
+    0x20, 0x76, 0x65, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63,
+    0x74, 0x2c, 0x0a, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6c,
+    0x6f, 0x67, 0x67, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6b, 0x69, 0x6e,
+    0x64, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6c,
+    0x65, 0x66, 0x74, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x15, 0x01, 0x12, 0x04, 0xaa, 0x02, 0x08, 0x14, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x15, 0x04, 0x00,
+    0x12, 0x06, 0xab, 0x02, 0x02, 0xb2, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x04, 0x00,
+    0x01, 0x12, 0x04, 0xab, 0x02, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x15, 0x04, 0x00, 0x02,
+    0x00, 0x12, 0x04, 0xac, 0x02, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x15, 0x04, 0x00, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xac, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x15, 0x04, 0x00,
+    0x02, 0x00, 0x02, 0x12, 0x04, 0xac, 0x02, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x15, 0x04,
+    0x00, 0x02, 0x01, 0x12, 0x04, 0xad, 0x02, 0x04, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x15, 0x04,
+    0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xad, 0x02, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x15,
+    0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xad, 0x02, 0x1a, 0x1b, 0x0a, 0x0e, 0x0a, 0x06, 0x04,
+    0x15, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0xae, 0x02, 0x04, 0x1f, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x15, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xae, 0x02, 0x04, 0x1a, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x15, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xae, 0x02, 0x1d, 0x1e, 0x0a, 0x0e, 0x0a,
+    0x06, 0x04, 0x15, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0xaf, 0x02, 0x04, 0x1d, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x15, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0xaf, 0x02, 0x04, 0x18, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x15, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xaf, 0x02, 0x1b, 0x1c, 0x0a,
+    0x0e, 0x0a, 0x06, 0x04, 0x15, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0xb0, 0x02, 0x04, 0x1b, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x15, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xb0, 0x02, 0x04, 0x16,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x15, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xb0, 0x02, 0x19,
+    0x1a, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x15, 0x04, 0x00, 0x02, 0x05, 0x12, 0x04, 0xb1, 0x02, 0x04,
+    0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x15, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0xb1, 0x02,
+    0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x15, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0xb1,
+    0x02, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x00, 0x12, 0x04, 0xb4, 0x02, 0x02,
+    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x06, 0x12, 0x04, 0xb4, 0x02, 0x02, 0x06,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb4, 0x02, 0x07, 0x0b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb4, 0x02, 0x0e, 0x0f, 0x0a, 0x33,
+    0x0a, 0x04, 0x04, 0x15, 0x02, 0x01, 0x12, 0x04, 0xb6, 0x02, 0x02, 0x15, 0x1a, 0x25, 0x20, 0x53,
+    0x61, 0x62, 0x6c, 0x69, 0x65, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20,
+    0x6f, 0x72, 0x20, 0x76, 0x65, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x77, 0x61, 0x6c, 0x6c, 0x65,
+    0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb6, 0x02,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb6, 0x02, 0x08,
+    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb6, 0x02, 0x13, 0x14,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x02, 0x12, 0x04, 0xb7, 0x02, 0x02, 0x12, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x15, 0x02, 0x02, 0x05, 0x12, 0x04, 0xb7, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x15, 0x02, 0x02, 0x01, 0x12, 0x04, 0xb7, 0x02, 0x08, 0x0d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x15, 0x02, 0x02, 0x03, 0x12, 0x04, 0xb7, 0x02, 0x10, 0x11, 0x0a, 0x3d, 0x0a, 0x04, 0x04,
+    0x15, 0x02, 0x03, 0x12, 0x04, 0xb9, 0x02, 0x02, 0x17, 0x1a, 0x2f, 0x20, 0x44, 0x65, 0x63, 0x69,
+    0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x20, 0x69, 0x64, 0x2c, 0x20, 0x65,
+    0x6d, 0x70, 0x74, 0x79, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x76, 0x65, 0x73, 0x74, 0x69, 0x6e, 0x67,
+    0x20, 0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15,
+    0x02, 0x03, 0x05, 0x12, 0x04, 0xb9, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02,
+    0x03, 0x01, 0x12, 0x04, 0xb9, 0x02, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03,
+    0x03, 0x12, 0x04, 0xb9, 0x02, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x04, 0x12,
+    0x04, 0xba, 0x02, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x04, 0x05, 0x12, 0x04,
+    0xba, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x04, 0x01, 0x12, 0x04, 0xba,
+    0x02, 0x08, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x04, 0x03, 0x12, 0x04, 0xba, 0x02,
+    0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x05, 0x12, 0x04, 0xbb, 0x02, 0x02, 0x16,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x05, 0x05, 0x12, 0x04, 0xbb, 0x02, 0x02, 0x07, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x05, 0x01, 0x12, 0x04, 0xbb, 0x02, 0x08, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x15, 0x02, 0x05, 0x03, 0x12, 0x04, 0xbb, 0x02, 0x14, 0x15, 0x0a, 0x90, 0x01,
+    0x0a, 0x04, 0x04, 0x15, 0x02, 0x06, 0x12, 0x04, 0xbe, 0x02, 0x02, 0x14, 0x1a, 0x81, 0x01, 0x20,
+    0x44, 0x65, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x2c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x64, 0x72, 0x61,
+    0x77, 0x61, 0x6c, 0x2c, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x6c, 0x65, 0x66,
+    0x74, 0x20, 0x61, 0x74, 0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x2c, 0x20, 0x66, 0x75, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x72, 0x20, 0x72, 0x65,
+    0x6c, 0x65, 0x61, 0x73, 0x65, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d,
+    0x61, 0x6c, 0x6c, 0x65, 0x73, 0x74, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x61, 0x0a, 0x20,
+    0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x06, 0x05, 0x12, 0x04, 0xbe, 0x02, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x06, 0x01, 0x12, 0x04, 0xbe, 0x02, 0x09, 0x0f, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x15, 0x02, 0x06, 0x03, 0x12, 0x04, 0xbe, 0x02, 0x12, 0x13, 0x0a, 0x3c, 0x0a,
+    0x04, 0x04, 0x15, 0x02, 0x07, 0x12, 0x04, 0xc0, 0x02, 0x02, 0x18, 0x1a, 0x2e, 0x20, 0x53, 0x74,
+    0x72, 0x65, 0x61, 0x6d, 0x69, 0x6e, 0x67, 0x20, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x64, 0x65, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x2c, 0x20, 0x69,
+    0x6e, 0x20, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x15, 0x02, 0x07, 0x05, 0x12, 0x04, 0xc0, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15,
+    0x02, 0x07, 0x01, 0x12, 0x04, 0xc0, 0x02, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02,
+    0x07, 0x03, 0x12, 0x04, 0xc0, 0x02, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x08,
+    0x12, 0x04, 0xc1, 0x02, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x08, 0x05, 0x12,
+    0x04, 0xc1, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x08, 0x01, 0x12, 0x04,
+    0xc1, 0x02, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x08, 0x03, 0x12, 0x04, 0xc1,
+    0x02, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x09, 0x12, 0x04, 0xc2, 0x02, 0x02,
+    0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x09, 0x05, 0x12, 0x04, 0xc2, 0x02, 0x02, 0x07,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x09, 0x01, 0x12, 0x04, 0xc2, 0x02, 0x08, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x09, 0x03, 0x12, 0x04, 0xc2, 0x02, 0x13, 0x15, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x15, 0x02, 0x0a, 0x12, 0x04, 0xc3, 0x02, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x15, 0x02, 0x0a, 0x05, 0x12, 0x04, 0xc3, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x15, 0x02, 0x0a, 0x01, 0x12, 0x04, 0xc3, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15,
+    0x02, 0x0a, 0x03, 0x12, 0x04, 0xc3, 0x02, 0x13, 0x15, 0x0a, 0x60, 0x0a, 0x04, 0x04, 0x15, 0x02,
+    0x0b, 0x12, 0x04, 0xc5, 0x02, 0x02, 0x11, 0x1a, 0x52, 0x20, 0x60, 0x3c, 0x74, 0x72, 0x78, 0x5f,
+    0x68, 0x61, 0x73, 0x68, 0x3e, 0x2d, 0x3c, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x3e, 0x60,
+    0x2c, 0x20, 0x75, 0x6e, 0x69, 0x71, 0x75, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x74, 0x61,
+    0x62, 0x6c, 0x65, 0x20, 0x61, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61,
+    0x79, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x69, 0x6e, 0x6b, 0x73, 0x20, 0x74, 0x6f, 0x20,
+    0x75, 0x70, 0x73, 0x65, 0x72, 0x74, 0x20, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x15, 0x02, 0x0b, 0x05, 0x12, 0x04, 0xc5, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15,
+    0x02, 0x0b, 0x01, 0x12, 0x04, 0xc5, 0x02, 0x09, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02,
+    0x0b, 0x03, 0x12, 0x04, 0xc5, 0x02, 0x0e, 0x10, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x16, 0x12, 0x06,
+    0xc8, 0x02, 0x00, 0xca, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x16, 0x01, 0x12, 0x04, 0xc8,
+    0x02, 0x08, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x00, 0x12, 0x04, 0xc9, 0x02, 0x02,
+    0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x04, 0x12, 0x04, 0xc9, 0x02, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x06, 0x12, 0x04, 0xc9, 0x02, 0x0b, 0x1c, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc9, 0x02, 0x1d, 0x25, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc9, 0x02, 0x28, 0x29, 0x0a, 0xd7, 0x01,
+    0x0a, 0x02, 0x04, 0x17, 0x12, 0x06, 0xce, 0x02, 0x00, 0xda, 0x02, 0x01, 0x1a, 0xc8, 0x01, 0x20,
+    0x53, 0x75, 0x70, 0x70, 0x6c, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x64, 0x69, 0x73, 0x63,
+    0x6f, 0x76, 0x65, 0x72, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x77, 0x68, 0x6f,
+    0x73, 0x65, 0x20, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x73, 0x20, 0x6d, 0x6f, 0x76, 0x65,
+    0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20,
+    0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x6d, 0x61, 0x6c, 0x6c, 0x65, 0x73, 0x74, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x61, 0x73, 0x20, 0x64, 0x65, 0x63,
+    0x69, 0x6d, 0x61, 0x6c, 0x0a, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x2e, 0x20, 0x60,
+    0x63, 0x69, 0x72, 0x63, 0x75, 0x6c, 0x61, 0x74, 0x69, 0x6e, 0x67, 0x60, 0x20, 0x69, 0x73, 0x20,
+    0x60, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x5f, 0x73, 0x75, 0x70, 0x70, 0x6c, 0x79, 0x60, 0x20, 0x6c,
+    0x65, 0x73, 0x73, 0x20, 0x60, 0x62, 0x75, 0x72, 0x6e, 0x65, 0x64, 0x60, 0x2c, 0x20, 0x60, 0x65,
+    0x73, 0x63, 0x72, 0x6f, 0x77, 0x65, 0x64, 0x60, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x60, 0x6c, 0x6f,
+    0x63, 0x6b, 0x65, 0x64, 0x60, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x17, 0x01, 0x12, 0x04,
+    0xce, 0x02, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x00, 0x12, 0x04, 0xcf, 0x02,
+    0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x05, 0x12, 0x04, 0xcf, 0x02, 0x02,
+    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x01, 0x12, 0x04, 0xcf, 0x02, 0x08, 0x0d,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x03, 0x12, 0x04, 0xcf, 0x02, 0x10, 0x11, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x01, 0x12, 0x04, 0xd0, 0x02, 0x02, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x17, 0x02, 0x01, 0x05, 0x12, 0x04, 0xd0, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x17, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd0, 0x02, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x17, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd0, 0x02, 0x11, 0x12, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x17,
+    0x02, 0x02, 0x12, 0x04, 0xd2, 0x02, 0x02, 0x1a, 0x1a, 0x33, 0x20, 0x4d, 0x69, 0x6e, 0x74, 0x65,
+    0x64, 0x20, 0x6c, 0x65, 0x73, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x6d, 0x6f, 0x75, 0x6e,
+    0x74, 0x73, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x7a,
+    0x65, 0x72, 0x6f, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x17, 0x02, 0x02, 0x05, 0x12, 0x04, 0xd2, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x17, 0x02, 0x02, 0x01, 0x12, 0x04, 0xd2, 0x02, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x17, 0x02, 0x02, 0x03, 0x12, 0x04, 0xd2, 0x02, 0x18, 0x19, 0x0a, 0x42, 0x0a, 0x04, 0x04, 0x17,
+    0x02, 0x03, 0x12, 0x04, 0xd4, 0x02, 0x02, 0x14, 0x1a, 0x34, 0x20, 0x48, 0x65, 0x6c, 0x64, 0x20,
+    0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x64, 0x65, 0x61, 0x64, 0x20, 0x61, 0x64, 0x64, 0x72,
+    0x65, 0x73, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x62, 0x75, 0x72,
+    0x6e, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x65, 0x73, 0x60, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x17, 0x02, 0x03, 0x05, 0x12, 0x04, 0xd4, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x17, 0x02, 0x03, 0x01, 0x12, 0x04, 0xd4, 0x02, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x17, 0x02, 0x03, 0x03, 0x12, 0x04, 0xd4, 0x02, 0x12, 0x13, 0x0a, 0x2d, 0x0a, 0x04, 0x04,
+    0x17, 0x02, 0x04, 0x12, 0x04, 0xd6, 0x02, 0x02, 0x16, 0x1a, 0x1f, 0x20, 0x48, 0x65, 0x6c, 0x64,
+    0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x60,
+    0x65, 0x73, 0x63, 0x72, 0x6f, 0x77, 0x73, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17,
+    0x02, 0x04, 0x05, 0x12, 0x04, 0xd6, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02,
+    0x04, 0x01, 0x12, 0x04, 0xd6, 0x02, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x04,
+    0x03, 0x12, 0x04, 0xd6, 0x02, 0x14, 0x15, 0x0a, 0x3e, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x05, 0x12,
+    0x04, 0xd8, 0x02, 0x02, 0x14, 0x1a, 0x30, 0x20, 0x4c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x20, 0x69,
+    0x6e, 0x20, 0x53, 0x61, 0x62, 0x6c, 0x69, 0x65, 0x72, 0x20, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d,
+    0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x76, 0x65, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x77, 0x61,
+    0x6c, 0x6c, 0x65, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x05, 0x05,
+    0x12, 0x04, 0xd8, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x05, 0x01, 0x12,
+    0x04, 0xd8, 0x02, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x05, 0x03, 0x12, 0x04,
+    0xd8, 0x02, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x06, 0x12, 0x04, 0xd9, 0x02,
+    0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x06, 0x05, 0x12, 0x04, 0xd9, 0x02, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x06, 0x01, 0x12, 0x04, 0xd9, 0x02, 0x09, 0x14,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x06, 0x03, 0x12, 0x04, 0xd9, 0x02, 0x17, 0x18, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x18, 0x12, 0x06, 0xdc, 0x02, 0x00, 0xde, 0x02, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x18, 0x01, 0x12, 0x04, 0xdc, 0x02, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18,
+    0x02, 0x00, 0x12, 0x04, 0xdd, 0x02, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00,
+    0x04, 0x12, 0x04, 0xdd, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x06,
+    0x12, 0x04, 0xdd, 0x02, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xdd, 0x02, 0x18, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xdd, 0x02, 0x20, 0x21, 0x0a, 0xa1, 0x01, 0x0a, 0x02, 0x04, 0x19, 0x12, 0x06, 0xe2, 0x02, 0x00,
+    0xeb, 0x02, 0x01, 0x1a, 0x92, 0x01, 0x20, 0x41, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6f, 0x66,
+    0x20, 0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x69,
+    0x6e, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x6f, 0x75, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x77, 0x61, 0x6c, 0x6c, 0x65, 0x74, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x63, 0x65,
+    0x6e, 0x74, 0x72, 0x61, 0x6c, 0x69, 0x7a, 0x65, 0x64, 0x20, 0x65, 0x78, 0x63, 0x68, 0x61, 0x6e,
+    0x67, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c,
+    0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x65, 0x73, 0x74,
+    0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x20, 0x61, 0x73, 0x20, 0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73,
+    0x74, 0x72, 0x69, 0x6e, 0x67, 0x73, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x19, 0x01, 0x12,
+    0x04, 0xe2, 0x02, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x00, 0x12, 0x04, 0xe3,
+    0x02, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe3, 0x02,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe3, 0x02, 0x09,
+    0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe3, 0x02, 0x11, 0x12,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x01, 0x12, 0x04, 0xe4, 0x02, 0x02, 0x12, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x19, 0x02, 0x01, 0x05, 0x12, 0x04, 0xe4, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x19, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe4, 0x02, 0x08, 0x0d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x19, 0x02, 0x01, 0x03, 0x12, 0x04, 0xe4, 0x02, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x19, 0x02, 0x02, 0x12, 0x04, 0xe5, 0x02, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0xe5, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xe5, 0x02, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0xe5, 0x02, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x03, 0x12, 0x04,
+    0xe6, 0x02, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x03, 0x05, 0x12, 0x04, 0xe6,
+    0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x03, 0x01, 0x12, 0x04, 0xe6, 0x02,
+    0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x03, 0x03, 0x12, 0x04, 0xe6, 0x02, 0x12,
+    0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x04, 0x12, 0x04, 0xe7, 0x02, 0x02, 0x15, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x04, 0x05, 0x12, 0x04, 0xe7, 0x02, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x19, 0x02, 0x04, 0x01, 0x12, 0x04, 0xe7, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x19, 0x02, 0x04, 0x03, 0x12, 0x04, 0xe7, 0x02, 0x13, 0x14, 0x0a, 0x70, 0x0a, 0x04,
+    0x04, 0x19, 0x02, 0x05, 0x12, 0x04, 0xe9, 0x02, 0x02, 0x16, 0x1a, 0x62, 0x20, 0x54, 0x72, 0x61,
+    0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x66, 0x72,
+    0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x2c,
+    0x20, 0x6c, 0x65, 0x66, 0x74, 0x20, 0x61, 0x74, 0x20, 0x7a, 0x65, 0x72, 0x6f, 0x20, 0x77, 0x68,
+    0x65, 0x6e, 0x20, 0x6d, 0x61, 0x70, 0x5f, 0x65, 0x72, 0x63, 0x32, 0x30, 0x5f, 0x74, 0x72, 0x61,
+    0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x65, 0x6d, 0x69, 0x74,
+    0x73, 0x20, 0x61, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x19, 0x02, 0x05, 0x05, 0x12, 0x04, 0xe9, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x19, 0x02, 0x05, 0x01, 0x12, 0x04, 0xe9, 0x02, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x19, 0x02, 0x05, 0x03, 0x12, 0x04, 0xe9, 0x02, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x19, 0x02, 0x06, 0x12, 0x04, 0xea, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02,
+    0x06, 0x05, 0x12, 0x04, 0xea, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x06,
+    0x01, 0x12, 0x04, 0xea, 0x02, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x06, 0x03,
+    0x12, 0x04, 0xea, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1a, 0x12, 0x06, 0xed, 0x02,
+    0x00, 0xef, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1a, 0x01, 0x12, 0x04, 0xed, 0x02, 0x08,
+    0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x00, 0x12, 0x04, 0xee, 0x02, 0x02, 0x22, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x04, 0x12, 0x04, 0xee, 0x02, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x06, 0x12, 0x04, 0xee, 0x02, 0x0b, 0x15, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1a, 0x02, 0x00, 0x01, 0x12, 0x04, 0xee, 0x02, 0x16, 0x1d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1a, 0x02, 0x00, 0x03, 0x12, 0x04, 0xee, 0x02, 0x20, 0x21, 0x0a, 0x65, 0x0a, 0x02, 0x04,
+    0x1b, 0x12, 0x06, 0xf2, 0x02, 0x00, 0x84, 0x03, 0x01, 0x1a, 0x57, 0x20, 0x52, 0x6f, 0x6c, 0x6c,
+    0x69, 0x6e, 0x67, 0x20, 0x6e, 0x65, 0x74, 0x20, 0x66, 0x6c, 0x6f, 0x77, 0x20, 0x6f, 0x66, 0x20,
+    0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x20,
+    0x65, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x63, 0x72, 0x6f, 0x73, 0x73, 0x69, 0x6e,
+    0x67, 0x20, 0x69, 0x74, 0x73, 0x20, 0x74, 0x68, 0x72, 0x65, 0x73, 0x68, 0x6f, 0x6c, 0x64, 0x2c,
+    0x20, 0x61, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1b, 0x01, 0x12, 0x04, 0xf2, 0x02, 0x08, 0x12, 0x0a,
+    0x0e, 0x0a, 0x04, 0x04, 0x1b, 0x04, 0x00, 0x12, 0x06, 0xf3, 0x02, 0x02, 0xf9, 0x02, 0x03, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x04, 0x00, 0x01, 0x12, 0x04, 0xf3, 0x02, 0x07, 0x10, 0x0a, 0x0e,
+    0x0a, 0x06, 0x04, 0x1b, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xf4, 0x02, 0x04, 0x1e, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x1b, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf4, 0x02, 0x04, 0x19, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x1b, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xf4, 0x02, 0x1c, 0x1d,
+    0x0a, 0x64, 0x0a, 0x06, 0x04, 0x1b, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xf6, 0x02, 0x04, 0x19,
+    0x1a, 0x54, 0x20, 0x4e, 0x65, 0x74, 0x20, 0x69, 0x6e, 0x66, 0x6c, 0x6f, 0x77, 0x20, 0x72, 0x6f,
+    0x73, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x68, 0x72, 0x65, 0x73, 0x68,
+    0x6f, 0x6c, 0x64, 0x2c, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x6d, 0x6f, 0x76, 0x69,
+    0x6e, 0x67, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x63, 0x68, 0x61, 0x6e,
+    0x67, 0x65, 0x2c, 0x20, 0x6f, 0x66, 0x74, 0x65, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x20,
+    0x73, 0x6f, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1b, 0x04, 0x00, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0xf6, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1b, 0x04, 0x00, 0x02,
+    0x01, 0x02, 0x12, 0x04, 0xf6, 0x02, 0x17, 0x18, 0x0a, 0x51, 0x0a, 0x06, 0x04, 0x1b, 0x04, 0x00,
+    0x02, 0x02, 0x12, 0x04, 0xf8, 0x02, 0x04, 0x1a, 0x1a, 0x41, 0x20, 0x4e, 0x65, 0x74, 0x20, 0x6f,
+    0x75, 0x74, 0x66, 0x6c, 0x6f, 0x77, 0x20, 0x72, 0x6f, 0x73, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x68, 0x72, 0x65, 0x73, 0x68, 0x6f, 0x6c, 0x64, 0x2c, 0x20, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x73, 0x20, 0x6c, 0x65, 0x61, 0x76, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x65, 0x78, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x1b, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf8, 0x02, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x1b, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xf8, 0x02, 0x18, 0x19, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x1b, 0x02, 0x00, 0x12, 0x04, 0xfb, 0x02, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1b, 0x02, 0x00, 0x05, 0x12, 0x04, 0xfb, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xfb, 0x02, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xfb, 0x02, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x01,
+    0x12, 0x04, 0xfc, 0x02, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x05, 0x12,
+    0x04, 0xfc, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0xfc, 0x02, 0x08, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfc,
+    0x02, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x02, 0x12, 0x04, 0xfd, 0x02, 0x02,
+    0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x05, 0x12, 0x04, 0xfd, 0x02, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x01, 0x12, 0x04, 0xfd, 0x02, 0x09, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x02, 0x03, 0x12, 0x04, 0xfd, 0x02, 0x14, 0x15, 0x0a, 0x1e,
+    0x0a, 0x04, 0x04, 0x1b, 0x02, 0x03, 0x12, 0x04, 0xff, 0x02, 0x02, 0x14, 0x1a, 0x10, 0x20, 0x60,
+    0x31, 0x68, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x32, 0x34, 0x68, 0x60, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1b, 0x02, 0x03, 0x05, 0x12, 0x04, 0xff, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1b, 0x02, 0x03, 0x01, 0x12, 0x04, 0xff, 0x02, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1b, 0x02, 0x03, 0x03, 0x12, 0x04, 0xff, 0x02, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x1b, 0x02, 0x04, 0x12, 0x04, 0x80, 0x03, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02,
+    0x04, 0x06, 0x12, 0x04, 0x80, 0x03, 0x02, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x04,
+    0x01, 0x12, 0x04, 0x80, 0x03, 0x0c, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x04, 0x03,
+    0x12, 0x04, 0x80, 0x03, 0x18, 0x19, 0x0a, 0x68, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x05, 0x12, 0x04,
+    0x82, 0x03, 0x02, 0x16, 0x1a, 0x5a, 0x20, 0x53, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x6e, 0x65,
+    0x74, 0x20, 0x66, 0x6c, 0x6f, 0x77, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73,
+    0x6d, 0x61, 0x6c, 0x6c, 0x65, 0x73, 0x74, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20,
+    0x64, 0x65, 0x63, 0x69, 0x6d, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x05, 0x05, 0x12, 0x04, 0x82, 0x03, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x05, 0x01, 0x12, 0x04, 0x82, 0x03, 0x09, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1b, 0x02, 0x05, 0x03, 0x12, 0x04, 0x82, 0x03, 0x14, 0x15, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x1b, 0x02, 0x06, 0x12, 0x04, 0x83, 0x03, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1b, 0x02, 0x06, 0x05, 0x12, 0x04, 0x83, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b,
+    0x02, 0x06, 0x01, 0x12, 0x04, 0x83, 0x03, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02,
+    0x06, 0x03, 0x12, 0x04, 0x83, 0x03, 0x15, 0x16, 0x0a, 0x68, 0x0a, 0x02, 0x04, 0x1c, 0x12, 0x06,
+    0x87, 0x03, 0x00, 0x8c, 0x03, 0x01, 0x1a, 0x5a, 0x20, 0x44, 0x69, 0x73, 0x63, 0x6f, 0x76, 0x65,
+    0x72, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20, 0x28, 0x68, 0x74, 0x74, 0x70,
+    0x73, 0x3a, 0x2f, 0x2f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x6c, 0x69, 0x73, 0x74, 0x73, 0x2e, 0x6f,
+    0x72, 0x67, 0x29, 0x20, 0x4a, 0x53, 0x4f, 0x4e, 0x20, 0x64, 0x6f, 0x63, 0x75, 0x6d, 0x65, 0x6e,
+    0x74, 0x2c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x20, 0x6c, 0x6f, 0x67, 0x6f, 0x73,
+    0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1c, 0x01, 0x12, 0x04, 0x87, 0x03, 0x08, 0x11, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x00, 0x12, 0x04, 0x88, 0x03, 0x02, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1c, 0x02, 0x00, 0x05, 0x12, 0x04, 0x88, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1c, 0x02, 0x00, 0x01, 0x12, 0x04, 0x88, 0x03, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1c, 0x02, 0x00, 0x03, 0x12, 0x04, 0x88, 0x03, 0x11, 0x12, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x1c,
+    0x02, 0x01, 0x12, 0x04, 0x8a, 0x03, 0x02, 0x12, 0x1a, 0x35, 0x20, 0x45, 0x6d, 0x70, 0x74, 0x79,
+    0x20, 0x6f, 0x75, 0x74, 0x73, 0x69, 0x64, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x69, 0x73, 0x74, 0x20,
+    0x69, 0x73, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x61, 0x74, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8a, 0x03, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8a, 0x03, 0x09, 0x0d, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1c, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8a, 0x03, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x1c, 0x02, 0x02, 0x12, 0x04, 0x8b, 0x03, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0x8b, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x8b, 0x03, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0x8b, 0x03, 0x17, 0x18, 0x0a, 0x6d, 0x0a, 0x02, 0x04, 0x1d, 0x12, 0x06, 0x8f,
+    0x03, 0x00, 0x91, 0x03, 0x01, 0x1a, 0x5f, 0x20, 0x53, 0x68, 0x61, 0x70, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x60, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x60, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20,
+    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x65, 0x73, 0x20, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x20,
+    0x74, 0x6f, 0x20, 0x62, 0x79, 0x74, 0x65, 0x73, 0x2c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20,
+    0x6c, 0x6f, 0x77, 0x65, 0x72, 0x63, 0x61, 0x73, 0x65, 0x20, 0x68, 0x65, 0x78, 0x20, 0x73, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1d, 0x01, 0x12, 0x04, 0x8f,
+    0x03, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x00, 0x12, 0x04, 0x90, 0x03, 0x02,
+    0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x04, 0x12, 0x04, 0x90, 0x03, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x06, 0x12, 0x04, 0x90, 0x03, 0x0b, 0x16, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x01, 0x12, 0x04, 0x90, 0x03, 0x17, 0x1d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x03, 0x12, 0x04, 0x90, 0x03, 0x20, 0x21, 0x0a, 0x0c, 0x0a,
+    0x02, 0x04, 0x1e, 0x12, 0x06, 0x93, 0x03, 0x00, 0x98, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x1e, 0x01, 0x12, 0x04, 0x93, 0x03, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x00,
+    0x12, 0x04, 0x94, 0x03, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x05, 0x12,
+    0x04, 0x94, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0x94, 0x03, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x03, 0x12, 0x04, 0x94,
+    0x03, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x01, 0x12, 0x04, 0x95, 0x03, 0x02,
+    0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01, 0x05, 0x12, 0x04, 0x95, 0x03, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01, 0x01, 0x12, 0x04, 0x95, 0x03, 0x09, 0x0d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01, 0x03, 0x12, 0x04, 0x95, 0x03, 0x10, 0x11, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1e, 0x02, 0x02, 0x12, 0x04, 0x96, 0x03, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1e, 0x02, 0x02, 0x05, 0x12, 0x04, 0x96, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1e, 0x02, 0x02, 0x01, 0x12, 0x04, 0x96, 0x03, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0x96, 0x03, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02,
+    0x03, 0x12, 0x04, 0x97, 0x03, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x03, 0x05,
+    0x12, 0x04, 0x97, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x03, 0x01, 0x12,
+    0x04, 0x97, 0x03, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x03, 0x03, 0x12, 0x04,
+    0x97, 0x03, 0x14, 0x15, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 ];
 // @@protoc_insertion_point(module)
\ No newline at end of file
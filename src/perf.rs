@@ -0,0 +1,65 @@
+use prost::Message;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::erc20;
+use crate::eth_utils;
+use crate::keys;
+use crate::pb::erc721;
+use crate::pb::metrics::Measure;
+use crate::pb::tokens;
+
+pub const BLOCKS: &str = "blocks";
+
+/// Cost proxies of a block: records the modules go through, bytes they emit and contracts they probe over RPC.
+pub fn measure(
+    blk: &eth::Block,
+    candidates: usize,
+    transfers: &erc721::Transfers,
+    erc20_transfers: &tokens::Transfers,
+    discovered: &tokens::Tokens,
+    hydrated: &tokens::Tokens,
+) -> Vec<(&'static str, i64)> {
+    let logs: usize = blk.receipts().map(|receipt| receipt.receipt.logs.len()).sum();
+    let calls: usize = blk.transaction_traces.iter().map(|trx| trx.calls.len()).sum();
+    // transactions map_erc20_transfers decodes, the others are ruled out by their bloom
+    let topics: Vec<&[u8]> = erc20::TOPICS.iter().map(|topic| topic.as_slice()).collect();
+    let fungible_transactions = eth_utils::transactions_matching_any(blk, &topics).count();
+    let bytes_emitted =
+        transfers.encoded_len() + erc20_transfers.encoded_len() + discovered.encoded_len() + hydrated.encoded_len();
+
+    vec![
+        ("transactions", blk.transaction_traces.len() as i64),
+        ("logs", logs as i64),
+        ("calls", calls as i64),
+        ("fungible_transactions", fungible_transactions as i64),
+        ("bytes_emitted", bytes_emitted as i64),
+        ("candidates_probed", candidates as i64),
+        ("rpc_calls", (discovered.rpc_calls + hydrated.rpc_calls) as i64),
+    ]
+}
+
+pub fn total_key(name: &str) -> String {
+    keys::key(keys::TOTAL).text(name).into()
+}
+
+/// Compares the measures of the block to their average over the blocks processed so far. The block is
+/// pathological when one of its measures is above `factor` times its average.
+pub fn compare(measures: Vec<(&'static str, i64)>, totals: impl Fn(&str) -> i64, factor: f64) -> (Vec<Measure>, bool) {
+    let blocks = totals(BLOCKS).max(1) as f64;
+    let mut pathological = false;
+    let measures = measures
+        .into_iter()
+        .map(|(name, value)| {
+            let average = totals(&total_key(name)) as f64 / blocks;
+            let ratio = if average > 0.0 { value as f64 / average } else { 0.0 };
+            pathological |= ratio > factor;
+            Measure {
+                name: name.to_string(),
+                value: value as u64,
+                average,
+                ratio,
+            }
+        })
+        .collect();
+    (measures, pathological)
+}
@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+
+use substreams::scalar::BigInt;
+use substreams::store::{DeltaBigInt, DeltaInt64, Deltas};
+
+use crate::eth_utils;
+use crate::keys;
+use crate::pb::portfolio::{holding::Standard, BalanceAnomalies, BalanceAnomaly, Holding, Portfolio, Portfolios};
+
+/// Keys of an ERC20 balance, holder first then contract first.
+pub fn erc20_keys(holder: &[u8], contract: &[u8]) -> [String; 2] {
+    keys::ERC20_BALANCES.keys(holder, contract).map(String::from)
+}
+
+/// Keys of an ERC1155 balance, holder first then contract first, both followed by the token id.
+pub fn erc1155_keys(holder: &[u8], contract: &[u8], token_id: &BigInt) -> [String; 2] {
+    keys::ERC1155_BALANCES.keys(holder, contract).map(|key| key.number(token_id).into())
+}
+
+/// Groups the balances changed in the block by holder, from the `total:<holder>:<contract>`,
+/// `erc1155:<holder>:<contract>:<id>` and `erc20:<holder>:<contract>` store deltas, and the
+/// `shares:<holder>:<contract>` rebasing balances. The contract first keys are left out. Balances stay in the smallest
+/// unit of their token, ERC20 holdings get their `value` in whole tokens when the `decimals` of their contract are
+/// known. Balances below zero are reported as 0 and flagged `clamped`.
+pub fn build(
+    erc721: Deltas<DeltaInt64>,
+    erc1155: Deltas<DeltaBigInt>,
+    erc20: Deltas<DeltaBigInt>,
+    rebasing: BTreeMap<String, (BigInt, Option<BigInt>)>,
+    decimals: impl Fn(&[u8]) -> Option<u32>,
+) -> Portfolios {
+    // the last delta of a key holds its balance at the end of the block
+    let mut balances: BTreeMap<String, (Standard, String, String)> = BTreeMap::new();
+    for delta in erc721.deltas {
+        balances.insert(delta.key, (Standard::Erc721, delta.new_value.to_string(), String::new()));
+    }
+    let holder_first = |key: &str, prefix| keys::parse(key).map_or(false, |key| key.is(prefix));
+    for delta in erc1155.deltas.into_iter().filter(|delta| holder_first(&delta.key, keys::ERC1155)) {
+        balances.insert(delta.key, (Standard::Erc1155, delta.new_value.to_string(), String::new()));
+    }
+    for delta in erc20.deltas.into_iter().filter(|delta| holder_first(&delta.key, keys::ERC20)) {
+        balances.insert(delta.key, (Standard::Erc20, delta.new_value.to_string(), String::new()));
+    }
+    for (key, (shares, balance)) in rebasing {
+        let balance = balance.map(|balance| balance.to_string()).unwrap_or_default();
+        balances.insert(key, (Standard::Erc20, balance, shares.to_string()));
+    }
+
+    let mut portfolios: BTreeMap<Vec<u8>, Vec<Holding>> = BTreeMap::new();
+    for (key, (standard, balance, shares)) in balances {
+        let (holder, contract, token_id) = match holding_key(&key) {
+            Some(holding) => holding,
+            None => continue,
+        };
+        let clamped = balance.starts_with('-');
+        let balance = if clamped { "0".to_string() } else { balance };
+
+        let decimals = match standard {
+            Standard::Erc20 => decimals(&contract),
+            _ => None,
+        };
+        let value = match (decimals, balance.parse::<BigInt>()) {
+            (Some(decimals), Ok(amount)) => eth_utils::decimal_string(&amount.to_decimal(decimals as u64)),
+            _ => String::new(),
+        };
+        portfolios.entry(holder).or_default().push(Holding {
+            standard: standard as i32,
+            contract,
+            token_id,
+            balance,
+            shares,
+            decimals: decimals.unwrap_or_default(),
+            clamped,
+            value,
+        });
+    }
+
+    Portfolios {
+        portfolios: portfolios
+            .into_iter()
+            .map(|(holder, holdings)| Portfolio { holder, holdings })
+            .collect(),
+    }
+}
+
+/// Holder, contract and ERC1155 token id of a holder first balance key.
+fn holding_key(key: &str) -> Option<(Vec<u8>, Vec<u8>, String)> {
+    let parsed = keys::parse(key).filter(|parsed| parsed.len() == 2 || parsed.len() == 3)?;
+    let token_id = parsed.raw(2).unwrap_or_default().to_string();
+    Some((parsed.hex(0)?, parsed.hex(1)?, token_id))
+}
+
+/// Balances of the ERC721, ERC1155 and ERC20 balance stores that went from zero or above to below zero in the block,
+/// one anomaly per crossing. Balances already below zero are not reported again until they get back above it.
+pub fn anomalies(
+    erc721: Deltas<DeltaInt64>,
+    erc1155: Deltas<DeltaBigInt>,
+    erc20: Deltas<DeltaBigInt>,
+) -> BalanceAnomalies {
+    // (standard, key, previous balance, balance, ordinal) of every crossing
+    let mut crossings = vec![];
+    for delta in erc721.deltas.into_iter().filter(|delta| delta.old_value >= 0 && delta.new_value < 0) {
+        let (previous, balance) = (delta.old_value.to_string(), delta.new_value.to_string());
+        crossings.push((Standard::Erc721, delta.key, previous, balance, delta.ordinal));
+    }
+    let holder_first = |key: &str, prefix| keys::parse(key).map_or(false, |key| key.is(prefix));
+    for delta in erc1155.deltas.into_iter().filter(|delta| holder_first(&delta.key, keys::ERC1155)) {
+        if delta.old_value >= BigInt::zero() && delta.new_value < BigInt::zero() {
+            let (previous, balance) = (delta.old_value.to_string(), delta.new_value.to_string());
+            crossings.push((Standard::Erc1155, delta.key, previous, balance, delta.ordinal));
+        }
+    }
+    for delta in erc20.deltas.into_iter().filter(|delta| holder_first(&delta.key, keys::ERC20)) {
+        if delta.old_value >= BigInt::zero() && delta.new_value < BigInt::zero() {
+            let (previous, balance) = (delta.old_value.to_string(), delta.new_value.to_string());
+            crossings.push((Standard::Erc20, delta.key, previous, balance, delta.ordinal));
+        }
+    }
+
+    let mut anomalies = vec![];
+    for (standard, key, previous_balance, balance, ordinal) in crossings {
+        if let Some((holder, contract, token_id)) = holding_key(&key) {
+            anomalies.push(BalanceAnomaly {
+                holder,
+                standard: standard as i32,
+                contract,
+                token_id,
+                previous_balance,
+                balance,
+                ordinal,
+            });
+        }
+    }
+    anomalies.sort_by_key(|anomaly| anomaly.ordinal);
+    BalanceAnomalies { anomalies }
+}
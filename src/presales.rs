@@ -0,0 +1,116 @@
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::crypto;
+use crate::eth_utils;
+use crate::keys;
+use crate::pb::tokens::presale_event::Kind;
+use crate::pb::tokens::PresaleEvent;
+
+/// Router function presale contracts call to add the raise and the token to a Uniswap V2 pool when they finalize.
+const ADD_LIQUIDITY_ETH: &str = "addLiquidityETH(address,uint256,uint256,uint256,address,uint256)";
+
+/// Function the owner of a presale calls to cancel it, its contributors then withdraw their contribution.
+const CANCEL: &str = "cancel()";
+
+/// Presale contracts the `launchpads` factories created in the transaction, with the ordinal of their creation.
+/// Pinksale-style launchpads deploy a contract for every presale from their factory.
+pub fn launched(trx: &eth::TransactionTrace, launchpads: &[Vec<u8>]) -> Vec<(Vec<u8>, u64)> {
+    trx.calls
+        .iter()
+        .filter(|call| !call.state_reverted && call.call_type == eth::CallType::Create as i32)
+        .filter(|call| launchpads.contains(&call.caller))
+        .map(|call| (call.address.clone(), call.begin_ordinal))
+        .collect()
+}
+
+/// Marks a presale contract created by a launchpad factory.
+pub fn launched_key(presale: &[u8]) -> String {
+    keys::key(keys::PRESALE).hex(presale).into()
+}
+
+/// Contributions to the presales in the transaction, their refunds, cancellation and finalization. Presale contracts
+/// of Pinksale-style launchpads take contributions as plain payments, and finalize by adding the raise to a pool of
+/// their token through `addLiquidityETH`, whose first argument is the token. ETH a presale pays back to the sender of
+/// a transaction that does not finalize it is a refund, as when contributors withdraw from a cancelled presale.
+pub fn decode(trx: &eth::TransactionTrace, is_presale: impl Fn(&[u8]) -> bool) -> Vec<PresaleEvent> {
+    let add_liquidity = &crypto::keccak256(ADD_LIQUIDITY_ETH.as_bytes())[..4];
+    let cancel = &crypto::keccak256(CANCEL.as_bytes())[..4];
+    let value = |call: &eth::Call| {
+        call.value.as_ref().map_or_else(BigInt::zero, |value| BigInt::from_unsigned_bytes_be(&value.bytes))
+    };
+    let calls: Vec<_> = trx.calls.iter().filter(|call| !call.state_reverted).collect();
+    let finalizes = calls.iter().any(|call| is_presale(&call.caller) && call.input.starts_with(add_liquidity));
+
+    let mut events = vec![];
+    for call in calls {
+        let event = PresaleEvent {
+            id: eth_utils::call_record_id(&trx.hash, call.index),
+            trx_hash: trx.hash.clone(),
+            ordinal: call.begin_ordinal,
+            ..Default::default()
+        };
+
+        // the creation of a presale is no contribution, even when the factory funds it
+        let created = call.call_type == eth::CallType::Create as i32;
+        if is_presale(&call.address) && !is_presale(&call.caller) && !created {
+            let amount = value(call);
+            if !amount.is_zero() {
+                events.push(PresaleEvent {
+                    kind: Kind::Contribution as i32,
+                    presale: call.address.clone(),
+                    account: call.caller.clone(),
+                    amount: amount.to_string(),
+                    ..event
+                });
+            } else if call.input.starts_with(cancel) {
+                events.push(PresaleEvent {
+                    kind: Kind::Cancellation as i32,
+                    presale: call.address.clone(),
+                    account: call.caller.clone(),
+                    amount: "0".to_string(),
+                    ..event
+                });
+            }
+        } else if is_presale(&call.caller) && call.input.len() >= 36 && call.input.starts_with(add_liquidity) {
+            events.push(PresaleEvent {
+                kind: Kind::Finalization as i32,
+                presale: call.caller.clone(),
+                account: call.input[16..36].to_vec(),
+                amount: value(call).to_string(),
+                ..event
+            });
+        } else if is_presale(&call.caller) && call.address == trx.from && !finalizes {
+            let amount = value(call);
+            if !amount.is_zero() {
+                events.push(PresaleEvent {
+                    kind: Kind::Refund as i32,
+                    presale: call.caller.clone(),
+                    account: call.address.clone(),
+                    amount: amount.to_string(),
+                    ..event
+                });
+            }
+        }
+    }
+    events
+}
+
+/// Marks a participant of a presale, counted once whatever the number of its contributions.
+pub fn participant_key(presale: &[u8], participant: &[u8]) -> String {
+    keys::key(keys::PRESALE).hex(presale).text("participant").hex(participant).into()
+}
+
+/// Counter of a presale: `raised` and `refunded` in wei, `participants`, `contributions` or `cancelled`.
+pub fn stat_key(presale: &[u8], stat: &str) -> String {
+    keys::key(keys::PRESALE).hex(presale).text(stat).into()
+}
+
+/// Presale of a `participant_key`, to count the participants.
+pub fn participant_presale(key: &str) -> Option<Vec<u8>> {
+    let parsed = keys::parse(key).filter(|parsed| parsed.is(keys::PRESALE) && parsed.len() == 3)?;
+    if parsed.text(1).as_deref() != Some("participant") {
+        return None;
+    }
+    parsed.hex(0)
+}
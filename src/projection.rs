@@ -0,0 +1,73 @@
+use crate::logging;
+use crate::params::Params;
+use crate::pb::{erc721, portfolio};
+
+/// Message whose top level fields can be reset to their default value by name.
+pub trait Project {
+    const FIELDS: &'static [&'static str];
+
+    fn clear(&mut self, field: &str);
+}
+
+macro_rules! projectable {
+    ($message:ty { $($field:ident),* $(,)? }) => {
+        impl Project for $message {
+            const FIELDS: &'static [&'static str] = &[$(stringify!($field)),*];
+
+            fn clear(&mut self, field: &str) {
+                match field {
+                    $(stringify!($field) => self.$field = Default::default(),)*
+                    _ => {}
+                }
+            }
+        }
+    };
+}
+
+projectable!(erc721::ContractCall { method, inputs, outputs, caller, value, trx_hash, ordinal, id });
+projectable!(erc721::CollectionMetadata {
+    address,
+    standard,
+    name,
+    symbol,
+    owner,
+    contract_uri,
+    logo_uri,
+    royalty_receiver,
+    royalty_bps,
+    deployer,
+    trx_hash,
+});
+projectable!(erc721::TokenMetadata {
+    contract,
+    token_id,
+    token_uri,
+    on_chain,
+    name,
+    description,
+    image,
+    image_svg,
+    attributes,
+    trx_hash,
+    ordinal,
+    id,
+});
+projectable!(portfolio::AssetTransfer { standard, contract, token_id, amount, from, to, trx_hash, ordinal, id });
+
+/// Keeps only the fields listed in the `fields` param of every message, all of them when the param is empty. Unknown
+/// field names are logged and ignored.
+pub fn project<T: Project>(params: &Params, messages: &mut [T]) {
+    let fields = params.list("fields");
+    if fields.is_empty() {
+        return;
+    }
+    for field in fields.iter().filter(|field| !T::FIELDS.contains(field)) {
+        logging::warning!("params", "ignoring unknown field {}, expected one of {}", field, T::FIELDS.join(","));
+    }
+
+    for message in messages {
+        for field in T::FIELDS.iter().filter(|field| !fields.contains(field)) {
+            message.clear(field);
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+
+use substreams::scalar::BigInt;
+use substreams::store::{DeltaBigInt, Deltas};
+use substreams_ethereum::pb::eth::rpc::{RpcCall, RpcCalls};
+
+use crate::abi::lido_steth::functions::GetPooledEthByShares;
+use crate::keys;
+use crate::logging;
+use crate::rpc_utils::{EthCall, RpcCounters};
+
+pub fn shares_key(holder: &[u8], token: &[u8]) -> String {
+    keys::key(keys::SHARES).hex(holder).hex(token).into()
+}
+
+/// Share balances changed in the block, by `shares:<holder>:<token>` key, along with the token
+/// balance they convert to. The conversion rate changes with every rebase, so it is probed over
+/// RPC through `getPooledEthByShares` when the balances are emitted rather than stored.
+pub fn balances(
+    client: &impl EthCall,
+    shares: Deltas<DeltaBigInt>,
+    rpc: &mut RpcCounters,
+) -> BTreeMap<String, (BigInt, Option<BigInt>)> {
+    // the last delta of a key holds its shares at the end of the block
+    let mut latest: BTreeMap<String, BigInt> = BTreeMap::new();
+    for delta in shares.deltas {
+        latest.insert(delta.key, delta.new_value);
+    }
+
+    let mut calls = vec![];
+    for (key, shares) in &latest {
+        let token = keys::parse(key).and_then(|parsed| parsed.hex(1)).unwrap_or_default();
+        calls.push(RpcCall {
+            to_addr: token,
+            data: GetPooledEthByShares {
+                shares_amount: shares.clone(),
+            }
+            .encode(),
+        });
+    }
+    if calls.is_empty() {
+        return BTreeMap::new();
+    }
+
+    let responses = client.eth_call(&RpcCalls { calls }).responses;
+    rpc.record(&responses);
+    latest
+        .into_iter()
+        .zip(responses)
+        .map(|((key, shares), response)| {
+            let balance = if response.failed {
+                None
+            } else {
+                GetPooledEthByShares::output(&response.raw).ok()
+            };
+            if balance.is_none() {
+                logging::warning!("balances", "failed to convert the shares of {}", key);
+            }
+            (key, (shares, balance))
+        })
+        .collect()
+}
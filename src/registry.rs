@@ -0,0 +1,90 @@
+use substreams::Hex;
+
+use crate::eth_utils;
+use crate::logging;
+use crate::params::Params;
+use crate::rpc_utils::{create_rpc_calls, EthCall, RpcCounters};
+
+/// An on-chain allowlist, `getter` being the selector of a view function returning `address[]`.
+pub struct Registry {
+    pub address: Vec<u8>,
+    getter: String,
+    start_block: Option<u64>,
+    refresh: u64,
+}
+
+/// Reads the `registry`, `getter`, `start_block` and `refresh` params, None when no registry is configured.
+pub fn from_params(params: &Params) -> Option<Registry> {
+    let address = match params.get("registry") {
+        Some(registry) if !registry.is_empty() => match eth_utils::parse_address(registry) {
+            Ok(address) => address,
+            Err(e) => {
+                logging::warning!("registry", "invalid registry: {}", e);
+                return None;
+            }
+        },
+        _ => return None,
+    };
+
+    let getter = params.get("getter").unwrap_or_default();
+    let getter = getter.strip_prefix("0x").unwrap_or(getter);
+    if getter.len() != 8 || hex::decode(getter).is_err() {
+        logging::warning!("registry", "invalid registry getter selector: {}", getter);
+        return None;
+    }
+
+    Some(Registry {
+        address,
+        getter: getter.to_string(),
+        start_block: params.get("start_block").and_then(|start| start.parse().ok()),
+        refresh: params.get("refresh").and_then(|refresh| refresh.parse().ok()).unwrap_or(0),
+    })
+}
+
+impl Registry {
+    /// The registry is read on the configured start block and then every `refresh` blocks.
+    pub fn should_refresh(&self, block: u64) -> bool {
+        self.start_block == Some(block) || (self.refresh > 0 && block % self.refresh == 0)
+    }
+
+    pub fn contracts(&self, client: &impl EthCall, rpc: &mut RpcCounters) -> Option<Vec<Vec<u8>>> {
+        let rpc_calls = create_rpc_calls(&self.address, vec![self.getter.as_str()]);
+        let responses = client.eth_call(&rpc_calls).responses;
+        rpc.record(&responses);
+        let response = match responses.first() {
+            Some(response) if !response.failed => response,
+            _ => {
+                logging::warning!("registry", "registry {} call failed", Hex(&self.address));
+                return None;
+            }
+        };
+
+        let values = match ethabi::decode(
+            &[ethabi::ParamType::Array(Box::new(ethabi::ParamType::Address))],
+            response.raw.as_ref(),
+        ) {
+            Ok(values) => values,
+            Err(e) => {
+                logging::warning!(
+                    "registry",
+                    "registry {} returned an invalid address list: {:?}",
+                    Hex(&self.address),
+                    e
+                );
+                return None;
+            }
+        };
+
+        Some(
+            values
+                .into_iter()
+                .next()
+                .and_then(|value| value.into_array())
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|value| value.into_address())
+                .map(|address| address.as_bytes().to_vec())
+                .collect(),
+        )
+    }
+}
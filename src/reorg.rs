@@ -0,0 +1,108 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::scalar::BigInt;
+use substreams::store::{DeltaBigInt, DeltaInt64};
+
+/// Store delta as the runtime records it, reduced to what undoing it takes.
+#[derive(Clone, Debug)]
+pub struct Change<T> {
+    pub operation: Operation,
+    pub key: String,
+    pub old_value: T,
+    pub new_value: T,
+}
+
+impl From<&DeltaInt64> for Change<i64> {
+    fn from(delta: &DeltaInt64) -> Self {
+        Change {
+            operation: delta.operation,
+            key: delta.key.clone(),
+            old_value: delta.old_value,
+            new_value: delta.new_value,
+        }
+    }
+}
+
+impl From<&DeltaBigInt> for Change<BigInt> {
+    fn from(delta: &DeltaBigInt) -> Self {
+        Change {
+            operation: delta.operation,
+            key: delta.key.clone(),
+            old_value: delta.old_value.clone(),
+            new_value: delta.new_value.clone(),
+        }
+    }
+}
+
+/// Number of keys the changes of a block touch.
+pub fn keys<T>(changes: &[Change<T>]) -> usize {
+    changes.iter().map(|change| &change.key).collect::<BTreeSet<_>>().len()
+}
+
+/// Values the changes of a block start from: the old value of the first change of every key, keys created in the
+/// block are left out.
+pub fn opening<T: Clone>(changes: &[Change<T>]) -> BTreeMap<String, T> {
+    let mut opening = BTreeMap::new();
+    let mut seen = BTreeSet::new();
+    for change in changes {
+        if !seen.insert(&change.key) {
+            continue;
+        }
+        if change.operation != Operation::Create {
+            opening.insert(change.key.clone(), change.old_value.clone());
+        }
+    }
+    opening
+}
+
+/// Applies the changes of a block in order, as the runtime does when it processes the block.
+pub fn apply<T: Clone>(state: &mut BTreeMap<String, T>, changes: &[Change<T>]) {
+    for change in changes {
+        match change.operation {
+            Operation::Delete => state.remove(&change.key),
+            _ => state.insert(change.key.clone(), change.new_value.clone()),
+        };
+    }
+}
+
+/// Undoes the changes of a block in reverse order, as the runtime does when the block gets forked out.
+pub fn undo<T: Clone>(state: &mut BTreeMap<String, T>, changes: &[Change<T>]) {
+    for change in changes.iter().rev() {
+        match change.operation {
+            Operation::Create => state.remove(&change.key),
+            _ => state.insert(change.key.clone(), change.old_value.clone()),
+        };
+    }
+}
+
+/// Problems undoing the changes of a block from `before`: changes whose operation or old value does not follow the
+/// value the previous changes left, and keys that applying then undoing the block does not bring back.
+pub fn check<T: Clone + PartialEq + Debug>(before: &BTreeMap<String, T>, changes: &[Change<T>]) -> Vec<String> {
+    let mut problems = vec![];
+    let mut state = before.clone();
+    for change in changes {
+        match (change.operation, state.get(&change.key)) {
+            (Operation::Create, Some(value)) => {
+                problems.push(format!("{} created while holding {:?}", change.key, value));
+            }
+            (Operation::Create, None) => {}
+            (_, None) => problems.push(format!("{} changed before being created", change.key)),
+            (_, Some(value)) if *value != change.old_value => {
+                problems.push(format!("{} changed from {:?} while holding {:?}", change.key, change.old_value, value));
+            }
+            _ => {}
+        }
+        apply(&mut state, std::slice::from_ref(change));
+    }
+
+    undo(&mut state, changes);
+    let keys: BTreeSet<&String> = changes.iter().map(|change| &change.key).collect();
+    for key in keys {
+        if state.get(key) != before.get(key) {
+            problems.push(format!("{} not restored by the undo", key));
+        }
+    }
+    problems
+}
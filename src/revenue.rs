@@ -0,0 +1,47 @@
+use substreams::scalar::BigInt;
+
+use crate::keys;
+use crate::pb::erc721::{MintPurchases, Sales};
+use crate::TRACKED_CONTRACT;
+
+pub const PRIMARY: &str = "primary";
+pub const SECONDARY: &str = "secondary";
+
+const DAY: i64 = 86_400;
+
+/// ETH revenue of `contract` from `source`, of all time or of the UTC day numbered from the unix epoch.
+pub fn revenue_key(contract: &[u8], source: &str, day: Option<i64>) -> String {
+    let key = keys::key(keys::REVENUE).hex(contract).text(source);
+    match day {
+        Some(day) => key.number(day).into(),
+        None => key.into(),
+    }
+}
+
+/// Revenue of the block as `(ordinal, key, amount)`, added to the total and to the day of every mint paid in ETH and
+/// every royalty paid in ETH on a secondary sale.
+pub fn entries(purchases: &MintPurchases, sales: &Sales) -> Vec<(u64, String, BigInt)> {
+    let mut entries = vec![];
+    let mut add = |ordinal, contract: &[u8], source, amount: BigInt, seconds: Option<i64>| {
+        if amount <= BigInt::zero() {
+            return;
+        }
+        entries.push((ordinal, revenue_key(contract, source, None), amount.clone()));
+        if let Some(seconds) = seconds {
+            entries.push((ordinal, revenue_key(contract, source, Some(seconds / DAY)), amount));
+        }
+    };
+
+    for purchase in &purchases.purchases {
+        let paid = purchase.paid.parse().unwrap_or_else(|_| BigInt::zero());
+        add(purchase.ordinal, &TRACKED_CONTRACT, PRIMARY, paid, purchase.timestamp.as_ref().map(|time| time.seconds));
+    }
+    for sale in &sales.sales {
+        // royalties in ETH count whatever the currency of the price
+        for royalty in sale.royalties.iter().filter(|royalty| royalty.currency.is_empty()) {
+            let amount = royalty.amount.parse().unwrap_or_else(|_| BigInt::zero());
+            add(sale.ordinal, &sale.collection, SECONDARY, amount, sale.timestamp.as_ref().map(|time| time.seconds));
+        }
+    }
+    entries
+}
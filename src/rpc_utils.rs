@@ -4,16 +4,161 @@ use substreams_ethereum::pb::eth;
 pub const DECIMALS: &str = "313ce567";
 pub const NAME: &str = "06fdde03";
 pub const SYMBOL: &str = "95d89b41";
+pub const OWNER: &str = "8da5cb5b";
+// ERC165 `supportsInterface(bytes4)`
+pub const SUPPORTS_INTERFACE: &str = "01ffc9a7";
+// EIP-2981 `royaltyInfo(uint256,uint256)`
+pub const ROYALTY_INFO: &str = "2a55205a";
+// ERC-7572 `contractURI()`
+pub const CONTRACT_URI: &str = "e8a3d485";
+// ERC721 `tokenURI(uint256)`
+pub const TOKEN_URI: &str = "c87b56dd";
 
 pub fn create_rpc_calls(addr: &Vec<u8>, method_signatures: Vec<&str>) -> eth::rpc::RpcCalls {
     let mut rpc_calls = eth::rpc::RpcCalls { calls: vec![] };
 
     for method_signature in method_signatures {
         rpc_calls.calls.push(eth::rpc::RpcCall {
-            to_addr: Vec::from(addr.clone()),
+            to_addr: addr.clone(),
             data: hex::decode(method_signature).unwrap(),
         })
     }
 
     return  rpc_calls
-}
\ No newline at end of file
+}
+
+/// Backend of the `eth_call`s made while probing contracts, the Substreams runtime in modules. Tests use
+/// `Recorded` responses instead, so the probing logic runs without a Substreams backend.
+pub trait EthCall {
+    fn eth_call(&self, calls: &eth::rpc::RpcCalls) -> eth::rpc::RpcResponses;
+}
+
+pub struct Substreams;
+
+impl EthCall for Substreams {
+    fn eth_call(&self, calls: &eth::rpc::RpcCalls) -> eth::rpc::RpcResponses {
+        substreams_ethereum::rpc::eth_call(calls)
+    }
+}
+
+/// Responses recorded by contract and call data, calls without a recorded response fail as a revert would.
+#[cfg(test)]
+#[derive(Default)]
+pub struct Recorded {
+    responses: std::collections::HashMap<(Vec<u8>, Vec<u8>), eth::rpc::RpcResponse>,
+}
+
+#[cfg(test)]
+impl Recorded {
+    pub fn respond(mut self, address: &[u8], method_signature: &str, raw: Vec<u8>) -> Self {
+        let response = eth::rpc::RpcResponse { raw, failed: false };
+        self.responses
+            .insert((address.to_vec(), hex::decode(method_signature).unwrap()), response);
+        self
+    }
+
+    pub fn revert(mut self, address: &[u8], method_signature: &str) -> Self {
+        let response = eth::rpc::RpcResponse {
+            raw: b"execution reverted".to_vec(),
+            failed: true,
+        };
+        self.responses
+            .insert((address.to_vec(), hex::decode(method_signature).unwrap()), response);
+        self
+    }
+}
+
+#[cfg(test)]
+impl EthCall for Recorded {
+    fn eth_call(&self, calls: &eth::rpc::RpcCalls) -> eth::rpc::RpcResponses {
+        let responses = calls
+            .calls
+            .iter()
+            .map(|call| {
+                self.responses
+                    .get(&(call.to_addr.clone(), call.data.clone()))
+                    .cloned()
+                    .unwrap_or(eth::rpc::RpcResponse {
+                        raw: vec![],
+                        failed: true,
+                    })
+            })
+            .collect();
+        eth::rpc::RpcResponses { responses }
+    }
+}
+
+/// Number of `eth_call`s made and failed, reported next to the module output.
+#[derive(Default)]
+pub struct RpcCounters {
+    pub calls: u64,
+    pub failures: u64,
+}
+
+impl RpcCounters {
+    pub fn record(&mut self, responses: &[eth::rpc::RpcResponse]) {
+        self.calls += responses.len() as u64;
+        self.failures += responses.iter().filter(|response| response.failed).count() as u64;
+    }
+}
+
+/// `name()` and `symbol()` of the contract, None for the calls that failed or returned no string.
+pub fn name_and_symbol(
+    client: &impl EthCall,
+    address: &[u8],
+    rpc: &mut RpcCounters,
+) -> (Option<String>, Option<String>) {
+    let calls = create_rpc_calls(&address.to_vec(), vec![NAME, SYMBOL]);
+    let responses = client.eth_call(&calls).responses;
+    rpc.record(&responses);
+    let read = |index: usize| {
+        responses
+            .get(index)
+            .filter(|response| !response.failed)
+            .and_then(|response| crate::eth_utils::read_string_bytes(&response.raw).ok())
+            .map(|raw| crate::eth_utils::sanitize_metadata(&raw))
+    };
+    (read(0), read(1))
+}
+
+/// ERC-7572 `contractURI()` of the contract, None when it is not implemented.
+pub fn contract_uri(client: &impl EthCall, address: &[u8], rpc: &mut RpcCounters) -> Option<String> {
+    let calls = create_rpc_calls(&address.to_vec(), vec![CONTRACT_URI]);
+    let responses = client.eth_call(&calls).responses;
+    rpc.record(&responses);
+    let response = responses.first().filter(|response| !response.failed)?;
+    let raw = crate::eth_utils::read_string_bytes(&response.raw).ok()?;
+    String::from_utf8(raw)
+        .ok()
+        .map(|uri| uri.trim().to_string())
+        .filter(|uri| !uri.is_empty())
+}
+
+/// ERC721 `tokenURI(token_id)` of every `(contract, token_id)`, all fetched in a single `eth_call` request. None for
+/// the calls that fail or return no string.
+pub fn token_uris(client: &impl EthCall, tokens: &[(Vec<u8>, u64)], rpc: &mut RpcCounters) -> Vec<Option<String>> {
+    if tokens.is_empty() {
+        return vec![];
+    }
+
+    let mut calls = eth::rpc::RpcCalls { calls: vec![] };
+    for (address, token_id) in tokens {
+        let mut data = hex::decode(TOKEN_URI).unwrap();
+        data.extend_from_slice(&[0u8; 24]);
+        data.extend_from_slice(&token_id.to_be_bytes());
+        calls.calls.push(eth::rpc::RpcCall { to_addr: address.clone(), data });
+    }
+
+    let responses = client.eth_call(&calls).responses;
+    rpc.record(&responses);
+    (0..tokens.len())
+        .map(|index| {
+            let response = responses.get(index).filter(|response| !response.failed)?;
+            let raw = crate::eth_utils::read_string_bytes(&response.raw).ok()?;
+            String::from_utf8(raw)
+                .ok()
+                .map(|uri| uri.trim().to_string())
+                .filter(|uri| !uri.is_empty())
+        })
+        .collect()
+}
@@ -0,0 +1,468 @@
+use std::collections::BTreeMap;
+
+use ethabi::{ParamType, Token};
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::crypto;
+use crate::keys;
+
+// Seaport 1.1
+pub const SEAPORT: [u8; 20] = hex!("00000000006c3852cbef3e08e8df289169ede581");
+
+/// OpenSea fee recipients, payments to other recipients than the seller are creator royalties.
+pub const FEE_RECIPIENTS: [[u8; 20]; 2] = [
+    hex!("0000a26b00c1f0df003000390027140000faa719"),
+    hex!("5b3256965e7c3cf26e11fcaf296dfc8807c01073"),
+];
+
+// OrderFulfilled(bytes32,address,address,address,(uint8,address,uint256,uint256)[],(uint8,address,uint256,uint256,address)[])
+const ORDER_FULFILLED_TOPIC: [u8; 32] = hex!("9d9af8e38d66c62e2c12f0225249fd9d721c54b83f48d9352c97c6cacdcb6f31");
+const FULFILL_BASIC_ORDER: [u8; 4] = hex!("fb0f3ee1");
+const FULFILL_ORDER: [u8; 4] = hex!("b3a34c4c");
+const FULFILL_ADVANCED_ORDER: [u8; 4] = hex!("e7acab24");
+const COUNTER_INCREMENTED: &str = "CounterIncremented(uint256,address)";
+
+const OFFER_ITEM_TYPE: &str =
+    "OfferItem(uint8 itemType,address token,uint256 identifierOrCriteria,uint256 startAmount,uint256 endAmount)";
+const CONSIDERATION_ITEM_TYPE: &str = "ConsiderationItem(uint8 itemType,address token,uint256 identifierOrCriteria,uint256 startAmount,uint256 endAmount,address recipient)";
+const ORDER_COMPONENTS_TYPE: &str = "OrderComponents(address offerer,address zone,OfferItem[] offer,ConsiderationItem[] consideration,uint8 orderType,uint256 startTime,uint256 endTime,bytes32 zoneHash,uint256 salt,bytes32 conduitKey,uint256 counter)";
+
+pub const NATIVE: u8 = 0;
+pub const ERC20: u8 = 1;
+pub const ERC721: u8 = 2;
+pub const ERC1155: u8 = 3;
+
+pub struct Item {
+    pub item_type: u8,
+    pub token: Vec<u8>,
+    pub identifier: BigInt,
+    pub start_amount: BigInt,
+    pub end_amount: BigInt,
+    pub recipient: Vec<u8>,
+}
+
+impl Item {
+    fn hash(&self, consideration: bool) -> [u8; 32] {
+        let mut members = vec![
+            crypto::uint_word(&BigInt::from(self.item_type as u64)),
+            crypto::address_word(&self.token),
+            crypto::uint_word(&self.identifier),
+            crypto::uint_word(&self.start_amount),
+            crypto::uint_word(&self.end_amount),
+        ];
+        if consideration {
+            members.push(crypto::address_word(&self.recipient));
+            return crypto::hash_struct(&crypto::keccak256(CONSIDERATION_ITEM_TYPE.as_bytes()), &members);
+        }
+        crypto::hash_struct(&crypto::keccak256(OFFER_ITEM_TYPE.as_bytes()), &members)
+    }
+}
+
+/// The signed part of a Seaport order.
+pub struct OrderComponents {
+    pub offerer: Vec<u8>,
+    pub zone: Vec<u8>,
+    pub offer: Vec<Item>,
+    pub consideration: Vec<Item>,
+    pub order_type: u8,
+    pub start_time: BigInt,
+    pub end_time: BigInt,
+    pub zone_hash: Vec<u8>,
+    pub salt: BigInt,
+    pub conduit_key: Vec<u8>,
+    pub counter: BigInt,
+}
+
+impl OrderComponents {
+    /// The order hash, the EIP-712 `hashStruct` of the components that off-chain order books index orders by.
+    pub fn hash(&self) -> [u8; 32] {
+        let order_type = format!("{}{}{}", ORDER_COMPONENTS_TYPE, CONSIDERATION_ITEM_TYPE, OFFER_ITEM_TYPE);
+        let offer: Vec<_> = self.offer.iter().map(|item| item.hash(false)).collect();
+        let consideration: Vec<_> = self.consideration.iter().map(|item| item.hash(true)).collect();
+
+        crypto::hash_struct(
+            &crypto::keccak256(order_type.as_bytes()),
+            &[
+                crypto::address_word(&self.offerer),
+                crypto::address_word(&self.zone),
+                crypto::hash_array(&offer),
+                crypto::hash_array(&consideration),
+                crypto::uint_word(&BigInt::from(self.order_type as u64)),
+                crypto::uint_word(&self.start_time),
+                crypto::uint_word(&self.end_time),
+                crypto::bytes32_word(&self.zone_hash),
+                crypto::uint_word(&self.salt),
+                crypto::bytes32_word(&self.conduit_key),
+                crypto::uint_word(&self.counter),
+            ],
+        )
+    }
+}
+
+pub struct OrderFulfilled {
+    pub order_hash: Vec<u8>,
+    pub offerer: Vec<u8>,
+    pub recipient: Vec<u8>,
+    pub offer: Vec<Item>,
+    pub consideration: Vec<Item>,
+}
+
+impl OrderFulfilled {
+    /// Payments of the order to other recipients than the seller and the marketplace fee recipients, added up by
+    /// currency: the ERC20 contract, empty for native ETH.
+    pub fn royalties(&self, seller: &[u8]) -> BTreeMap<Vec<u8>, BigInt> {
+        let mut royalties = BTreeMap::new();
+        let paid = self
+            .consideration
+            .iter()
+            .filter(|item| item.item_type == NATIVE || item.item_type == ERC20)
+            .filter(|item| item.recipient != seller && !FEE_RECIPIENTS.iter().any(|fees| item.recipient == fees));
+        for item in paid {
+            let currency = if item.item_type == ERC20 { item.token.clone() } else { vec![] };
+            let royalty = royalties.entry(currency).or_insert_with(BigInt::zero);
+            *royalty = royalty.clone() + item.start_amount.clone();
+        }
+        royalties
+    }
+}
+
+pub fn decode_order_fulfilled(log: &eth::Log) -> Option<OrderFulfilled> {
+    if log.address != SEAPORT || log.topics.len() != 3 || log.topics[0] != ORDER_FULFILLED_TOPIC {
+        return None;
+    }
+
+    let spent_item = ParamType::Tuple(vec![
+        ParamType::Uint(8),
+        ParamType::Address,
+        ParamType::Uint(256),
+        ParamType::Uint(256),
+    ]);
+    let received_item = ParamType::Tuple(vec![
+        ParamType::Uint(8),
+        ParamType::Address,
+        ParamType::Uint(256),
+        ParamType::Uint(256),
+        ParamType::Address,
+    ]);
+    let types = [
+        ParamType::FixedBytes(32),
+        ParamType::Address,
+        ParamType::Array(Box::new(spent_item)),
+        ParamType::Array(Box::new(received_item)),
+    ];
+    let mut fields = Fields(ethabi::decode(&types, &log.data).ok()?.into_iter());
+
+    let order_hash = fields.bytes32()?;
+    let recipient = fields.address()?;
+    let offer = fields.items(|item| {
+        let (item_type, token, identifier, amount) = (item.uint8()?, item.address()?, item.uint()?, item.uint()?);
+        Some(Item {
+            item_type,
+            token,
+            identifier,
+            start_amount: amount.clone(),
+            end_amount: amount,
+            recipient: vec![],
+        })
+    })?;
+    let consideration = fields.items(|item| {
+        let (item_type, token, identifier, amount) = (item.uint8()?, item.address()?, item.uint()?, item.uint()?);
+        Some(Item {
+            item_type,
+            token,
+            identifier,
+            start_amount: amount.clone(),
+            end_amount: amount,
+            recipient: item.address()?,
+        })
+    })?;
+
+    Some(OrderFulfilled {
+        order_hash,
+        offerer: log.topics[1][12..].to_vec(),
+        recipient,
+        offer,
+        consideration,
+    })
+}
+
+/// Counter of an offerer, bumped to cancel all its open orders at once.
+pub fn counter_key(offerer: &[u8]) -> String {
+    keys::key(keys::SEAPORT_COUNTER).hex(offerer).into()
+}
+
+/// Offerer and new counter of a `CounterIncremented` event.
+pub fn decode_counter_incremented(log: &eth::Log) -> Option<(Vec<u8>, BigInt)> {
+    if log.address != SEAPORT || log.topics.len() != 2 || log.data.len() != 32 {
+        return None;
+    }
+    if log.topics[0] != crypto::keccak256(COUNTER_INCREMENTED.as_bytes()) {
+        return None;
+    }
+    Some((log.topics[1][12..].to_vec(), BigInt::from_unsigned_bytes_be(&log.data)))
+}
+
+/// Recomputes the hash of the order fulfilled by a `fulfillBasicOrder`, `fulfillOrder` or
+/// `fulfillAdvancedOrder` call. The offerer counter is not part of the calldata, `counter` gives it as of the call.
+pub fn order_hash(call: &eth::Call, counter: impl Fn(&[u8]) -> BigInt) -> Option<Vec<u8>> {
+    if call.address != SEAPORT || call.input.len() < 4 {
+        return None;
+    }
+
+    let (selector, input) = call.input.split_at(4);
+    let mut order = if selector == FULFILL_BASIC_ORDER {
+        decode_basic_order(input)?
+    } else if selector == FULFILL_ORDER {
+        decode_order(input, false)?
+    } else if selector == FULFILL_ADVANCED_ORDER {
+        decode_order(input, true)?
+    } else {
+        return None;
+    };
+
+    order.counter = counter(&order.offerer);
+    Some(order.hash().to_vec())
+}
+
+fn offer_item_type() -> ParamType {
+    ParamType::Tuple(vec![
+        ParamType::Uint(8),
+        ParamType::Address,
+        ParamType::Uint(256),
+        ParamType::Uint(256),
+        ParamType::Uint(256),
+    ])
+}
+
+fn consideration_item_type() -> ParamType {
+    ParamType::Tuple(vec![
+        ParamType::Uint(8),
+        ParamType::Address,
+        ParamType::Uint(256),
+        ParamType::Uint(256),
+        ParamType::Uint(256),
+        ParamType::Address,
+    ])
+}
+
+/// Decodes the `OrderParameters` leading both the `Order` of `fulfillOrder`, `(OrderParameters, bytes signature)`,
+/// and the `AdvancedOrder` of `fulfillAdvancedOrder`, `(OrderParameters, uint120 numerator, uint120 denominator,
+/// bytes signature, bytes extraData)`. Only the first parameter of the call is read.
+fn decode_order(input: &[u8], advanced: bool) -> Option<OrderComponents> {
+    let parameters = ParamType::Tuple(vec![
+        ParamType::Address,
+        ParamType::Address,
+        ParamType::Array(Box::new(offer_item_type())),
+        ParamType::Array(Box::new(consideration_item_type())),
+        ParamType::Uint(8),
+        ParamType::Uint(256),
+        ParamType::Uint(256),
+        ParamType::FixedBytes(32),
+        ParamType::Uint(256),
+        ParamType::FixedBytes(32),
+        ParamType::Uint(256),
+    ]);
+    let order = if advanced {
+        vec![parameters, ParamType::Uint(120), ParamType::Uint(120), ParamType::Bytes, ParamType::Bytes]
+    } else {
+        vec![parameters, ParamType::Bytes]
+    };
+    let order = ethabi::decode(&[ParamType::Tuple(order)], input).ok()?;
+    let mut order = Fields(order.into_iter()).tuple()?;
+    let mut fields = order.tuple()?;
+
+    let offerer = fields.address()?;
+    let zone = fields.address()?;
+    let offer = fields.items(|item| {
+        Some(Item {
+            item_type: item.uint8()?,
+            token: item.address()?,
+            identifier: item.uint()?,
+            start_amount: item.uint()?,
+            end_amount: item.uint()?,
+            recipient: vec![],
+        })
+    })?;
+    let mut consideration = fields.items(|item| {
+        Some(Item {
+            item_type: item.uint8()?,
+            token: item.address()?,
+            identifier: item.uint()?,
+            start_amount: item.uint()?,
+            end_amount: item.uint()?,
+            recipient: item.address()?,
+        })
+    })?;
+    let order_type = fields.uint8()?;
+    let (start_time, end_time, zone_hash, salt, conduit_key) =
+        (fields.uint()?, fields.uint()?, fields.bytes32()?, fields.uint()?, fields.bytes32()?);
+
+    // the fulfiller may append tips, only the original items are signed
+    let original = fields.count()?;
+    consideration.truncate(original);
+
+    Some(OrderComponents {
+        offerer,
+        zone,
+        offer,
+        consideration,
+        order_type,
+        start_time,
+        end_time,
+        zone_hash,
+        salt,
+        conduit_key,
+        counter: BigInt::zero(),
+    })
+}
+
+/// Rebuilds the order components of a `BasicOrderParameters`. Its `basicOrderType` packs the order
+/// type with the route, which tells the item types on both sides: routes 0 to 3 pay ETH or an ERC20
+/// for an ERC721 or ERC1155, routes 4 and 5 accept an ERC20 offer for one.
+fn decode_basic_order(input: &[u8]) -> Option<OrderComponents> {
+    let additional_recipient = ParamType::Tuple(vec![ParamType::Uint(256), ParamType::Address]);
+    let parameters = ParamType::Tuple(vec![
+        ParamType::Address,
+        ParamType::Uint(256),
+        ParamType::Uint(256),
+        ParamType::Address,
+        ParamType::Address,
+        ParamType::Address,
+        ParamType::Uint(256),
+        ParamType::Uint(256),
+        ParamType::Uint(8),
+        ParamType::Uint(256),
+        ParamType::Uint(256),
+        ParamType::FixedBytes(32),
+        ParamType::Uint(256),
+        ParamType::FixedBytes(32),
+        ParamType::FixedBytes(32),
+        ParamType::Uint(256),
+        ParamType::Array(Box::new(additional_recipient)),
+        ParamType::Bytes,
+    ]);
+    let decoded = ethabi::decode(&[parameters], input).ok()?;
+    let mut fields = Fields(decoded.into_iter()).tuple()?;
+
+    let (consideration_token, consideration_identifier, consideration_amount) =
+        (fields.address()?, fields.uint()?, fields.uint()?);
+    let (offerer, zone) = (fields.address()?, fields.address()?);
+    let (offer_token, offer_identifier, offer_amount) = (fields.address()?, fields.uint()?, fields.uint()?);
+    let basic_order_type = fields.uint8()?;
+    let (start_time, end_time, zone_hash, salt, conduit_key) =
+        (fields.uint()?, fields.uint()?, fields.bytes32()?, fields.uint()?, fields.bytes32()?);
+    fields.skip();
+    let original = fields.count()?;
+    let additional_recipients = fields.items(|recipient| Some((recipient.uint()?, recipient.address()?)))?;
+
+    let route = basic_order_type / 4;
+    let (offer_type, consideration_type) = match route {
+        0 => (ERC721, NATIVE),
+        1 => (ERC1155, NATIVE),
+        2 => (ERC721, ERC20),
+        3 => (ERC1155, ERC20),
+        4 => (ERC20, ERC721),
+        5 => (ERC20, ERC1155),
+        _ => return None,
+    };
+    // fees and royalties are paid in the currency of the order
+    let (fee_type, fee_token) = if route < 4 {
+        (consideration_type, consideration_token.clone())
+    } else {
+        (offer_type, offer_token.clone())
+    };
+
+    let mut consideration = vec![Item {
+        item_type: consideration_type,
+        token: consideration_token,
+        identifier: consideration_identifier,
+        start_amount: consideration_amount.clone(),
+        end_amount: consideration_amount,
+        recipient: offerer.clone(),
+    }];
+    for (amount, recipient) in additional_recipients.into_iter().take(original) {
+        consideration.push(Item {
+            item_type: fee_type,
+            token: fee_token.clone(),
+            identifier: BigInt::zero(),
+            start_amount: amount.clone(),
+            end_amount: amount,
+            recipient,
+        });
+    }
+
+    Some(OrderComponents {
+        offerer,
+        zone,
+        offer: vec![Item {
+            item_type: offer_type,
+            token: offer_token,
+            identifier: offer_identifier,
+            start_amount: offer_amount.clone(),
+            end_amount: offer_amount,
+            recipient: vec![],
+        }],
+        consideration,
+        order_type: basic_order_type % 4,
+        start_time,
+        end_time,
+        zone_hash,
+        salt,
+        conduit_key,
+        counter: BigInt::zero(),
+    })
+}
+
+/// Reads decoded ABI values in order.
+struct Fields(std::vec::IntoIter<Token>);
+
+impl Fields {
+    fn skip(&mut self) {
+        self.0.next();
+    }
+
+    fn address(&mut self) -> Option<Vec<u8>> {
+        self.0.next()?.into_address().map(|address| address.as_bytes().to_vec())
+    }
+
+    fn uint(&mut self) -> Option<BigInt> {
+        let value = self.0.next()?.into_uint()?;
+        let mut bytes = [0u8; 32];
+        value.to_big_endian(&mut bytes);
+        Some(BigInt::from_unsigned_bytes_be(&bytes))
+    }
+
+    /// A `uint8`, None when the calldata holds a larger value in its word.
+    fn uint8(&mut self) -> Option<u8> {
+        let value = self.0.next()?.into_uint()?;
+        (value.bits() <= 8).then(|| value.low_u32() as u8)
+    }
+
+    /// A number of items, None when larger than any array could be.
+    fn count(&mut self) -> Option<usize> {
+        let value = self.0.next()?.into_uint()?;
+        (value.bits() <= 32).then(|| value.low_u32() as usize)
+    }
+
+    fn bytes32(&mut self) -> Option<Vec<u8>> {
+        self.0.next()?.into_fixed_bytes()
+    }
+
+    fn tuple(&mut self) -> Option<Fields> {
+        self.0.next()?.into_tuple().map(|fields| Fields(fields.into_iter()))
+    }
+
+    fn items<T, F>(&mut self, decode: F) -> Option<Vec<T>>
+    where
+        F: Fn(&mut Fields) -> Option<T>,
+    {
+        self.0
+            .next()?
+            .into_array()?
+            .into_iter()
+            .map(|item| decode(&mut Fields(item.into_tuple()?.into_iter())))
+            .collect()
+    }
+}
@@ -0,0 +1,18 @@
+/// `(selector, signature)` pairs of `abi/selectors.txt`, sorted by selector by the build script.
+static TABLE: &[([u8; 4], &str)] = include!(concat!(env!("OUT_DIR"), "/selectors.rs"));
+
+/// Signature of the function called with `input`, from the embedded selector table.
+pub fn signature(input: &[u8]) -> Option<&'static str> {
+    let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+    let index = TABLE.binary_search_by_key(&selector, |(selector, _)| *selector).ok()?;
+    Some(TABLE[index].1)
+}
+
+/// Signature of the function called with `input` when known, its `0x` prefixed selector otherwise.
+pub fn describe(input: &[u8]) -> String {
+    match signature(input) {
+        Some(signature) => signature.to_string(),
+        None if input.len() >= 4 => format!("0x{}", hex::encode(&input[..4])),
+        None => "fallback".to_string(),
+    }
+}
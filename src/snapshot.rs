@@ -0,0 +1,51 @@
+use prost::Message;
+
+use crate::eth_utils;
+use crate::logging;
+use crate::params::Params;
+use crate::pb::portfolio::{BalanceSnapshot, SnapshotBalance};
+
+/// Balances a balance store starts from, read from the `snapshot` param at the `snapshot_block` block and empty at
+/// every other block. `snapshot` holds comma separated `<holder>:<contract>:<balance>` entries, with the token id
+/// before the balance for ERC1155 (`<holder>:<contract>:<token_id>:<balance>`), or a packed BalanceSnapshot after a
+/// `base64:` prefix. Invalid entries are logged and skipped.
+pub fn balances(params: &Params, block: u64) -> Vec<SnapshotBalance> {
+    let snapshot_block = params.get("snapshot_block").and_then(|value| value.parse::<u64>().ok());
+    if snapshot_block != Some(block) {
+        return vec![];
+    }
+
+    let raw = params.get("snapshot").unwrap_or_default();
+    if let Some(packed) = raw.strip_prefix("base64:") {
+        let snapshot =
+            eth_utils::base64_decode(packed).and_then(|bytes| BalanceSnapshot::decode(bytes.as_slice()).ok());
+        if snapshot.is_none() {
+            logging::warning!("snapshot", "skipping snapshot that is not a base64 packed BalanceSnapshot");
+        }
+        return snapshot.map(|snapshot| snapshot.balances).unwrap_or_default();
+    }
+
+    let mut balances = vec![];
+    for entry in params.list("snapshot") {
+        match parse_entry(entry) {
+            Ok(balance) => balances.push(balance),
+            Err(e) => logging::warning!("snapshot", "skipping invalid snapshot entry: {}", e),
+        }
+    }
+    balances
+}
+
+fn parse_entry(entry: &str) -> Result<SnapshotBalance, String> {
+    let segments: Vec<&str> = entry.split(':').collect();
+    let (holder, contract, token_id, balance) = match segments.as_slice() {
+        [holder, contract, balance] => (holder, contract, "", balance),
+        [holder, contract, token_id, balance] => (holder, contract, *token_id, balance),
+        _ => return Err(format!("{} is not <holder>:<contract>[:<token_id>]:<balance>", entry)),
+    };
+    Ok(SnapshotBalance {
+        holder: eth_utils::parse_address(holder)?,
+        contract: eth_utils::parse_address(contract)?,
+        token_id: token_id.to_string(),
+        balance: balance.to_string(),
+    })
+}
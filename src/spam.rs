@@ -0,0 +1,149 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use hex_literal::hex;
+use substreams_ethereum::NULL_ADDRESS;
+
+use crate::bytecode;
+use crate::keys;
+use crate::pb::tokens::{Token, Transfers};
+
+pub const IMPERSONATION: &str = "impersonation";
+pub const UNICODE: &str = "unicode";
+pub const URL: &str = "url";
+pub const NO_LIQUIDITY: &str = "no_liquidity";
+pub const AIRDROP: &str = "airdrop";
+
+/// Weight of each signal in the spam score, which is capped at 100.
+const WEIGHTS: [(&str, f64); 5] = [
+    (IMPERSONATION, 60.0),
+    (UNICODE, 30.0),
+    (URL, 40.0),
+    (NO_LIQUIDITY, 20.0),
+    (AIRDROP, 40.0),
+];
+
+/// Taken off the score of tokens created from a verified template, whose code is known to behave.
+const TEMPLATE_CREDIT: f64 = 30.0;
+
+/// Recipients a single transaction has to send a token to for it to count as an airdrop.
+const AIRDROP_MIN_RECIPIENTS: usize = 100;
+
+/// Share of the transfers of a token that went to airdrop recipients above which the airdrop signal is raised.
+/// Established tokens get batch sent too, but most of their transfers are people moving them around.
+const AIRDROP_SHARE: f64 = 0.5;
+
+/// Symbols and names of major tokens, only their own contract may use them.
+const MAJORS: [(&str, &str, [u8; 20]); 10] = [
+    ("USDT", "Tether USD", hex!("dac17f958d2ee523a2206206994597c13d831ec7")),
+    ("USDC", "USD Coin", hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")),
+    ("WETH", "Wrapped Ether", hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")),
+    ("DAI", "Dai Stablecoin", hex!("6b175474e89094c44da98b954eedeac495271d0f")),
+    ("WBTC", "Wrapped BTC", hex!("2260fac5e5542a773aa44fbcfedf7c193bc2c599")),
+    ("UNI", "Uniswap", hex!("1f9840a85d5af5bf1d1762f925bdaddc4201f984")),
+    ("LINK", "ChainLink Token", hex!("514910771af9ca656af840dff83e8264ecf986ca")),
+    ("SHIB", "SHIBA INU", hex!("95ad61b0a150d79219dcf64e1e6cc01f0b64c4ce")),
+    ("STETH", "Liquid staked Ether 2.0", hex!("ae7ab96520de3a18e5e111b5eaab095312d7fe84")),
+    ("APE", "ApeCoin", hex!("4d224452801aced8b2f0aebe155379bb5d594381")),
+];
+
+const URL_MARKERS: [&str; 8] = ["http", "www.", ".com", ".io", ".xyz", ".org", "visit", "claim"];
+
+/// Lowercase alphanumeric skeleton of a name, so `U.S.D.T` and `usdt` compare equal.
+fn skeleton(value: &str) -> String {
+    value.chars().filter(|c| c.is_ascii_alphanumeric()).map(|c| c.to_ascii_lowercase()).collect()
+}
+
+/// Signals raised by the name and symbol of the token.
+pub fn metadata_signals(token: &Token) -> Vec<&'static str> {
+    let mut signals = vec![];
+
+    let symbol = skeleton(&token.symbol);
+    let name = skeleton(&token.name);
+    let impersonates = MAJORS.iter().any(|(major_symbol, major_name, address)| {
+        let same_symbol = !symbol.is_empty() && symbol == skeleton(major_symbol);
+        let same_name = !name.is_empty() && name == skeleton(major_name);
+        token.address != address && (same_symbol || same_name)
+    });
+    if impersonates {
+        signals.push(IMPERSONATION);
+    }
+
+    // homoglyphs, zero width and control characters all fall outside of printable ASCII, control characters
+    // are already stripped but leave the raw bytes behind
+    let tricky = |value: &str| value.chars().any(|c| !(' '..='~').contains(&c));
+    let sanitized = !token.name_raw_bytes.is_empty() || !token.symbol_raw_bytes.is_empty();
+    if sanitized || tricky(&token.name) || tricky(&token.symbol) {
+        signals.push(UNICODE);
+    }
+
+    let lowercase = format!("{} {}", token.name, token.symbol).to_lowercase();
+    if URL_MARKERS.iter().any(|marker| lowercase.contains(marker)) {
+        signals.push(URL);
+    }
+    signals
+}
+
+/// Score of the signals, lowered by TEMPLATE_CREDIT for the tokens of a `known` template. Impersonating a major
+/// token keeps half of its weight.
+pub fn score(signals: &[String], template: &str) -> f64 {
+    let total: f64 = WEIGHTS
+        .iter()
+        .filter(|(signal, _)| signals.iter().any(|raised| raised == signal))
+        .map(|(_, weight)| weight)
+        .sum();
+    let credit = if template == bytecode::KNOWN { TEMPLATE_CREDIT } else { 0.0 };
+    (total - credit).clamp(0.0, 100.0)
+}
+
+/// Sets the spam signals and score of the token from its name and symbol.
+pub fn score_metadata(token: &mut Token) {
+    token.spam_signals = metadata_signals(token).into_iter().map(String::from).collect();
+    token.spam_score = score(&token.spam_signals, &token.template);
+}
+
+/// Transaction hash, token and sender of transfers.
+type Batch<'a> = (&'a [u8], &'a [u8], &'a [u8]);
+
+pub fn airdropped_key(token: &[u8]) -> String {
+    keys::key(keys::AIRDROPPED).hex(token).into()
+}
+
+/// Number of transfers received by holders, keyed by token, the null address left out.
+pub fn received_key(token: &[u8]) -> String {
+    keys::key(keys::RECEIVED).hex(token).into()
+}
+
+/// Transfers of the block to another address than the null one, by token.
+pub fn received(transfers: &Transfers) -> BTreeMap<Vec<u8>, u64> {
+    let mut received = BTreeMap::new();
+    for transfer in transfers.transfers.iter().filter(|transfer| transfer.to != NULL_ADDRESS) {
+        *received.entry(transfer.token.clone()).or_default() += 1;
+    }
+    received
+}
+
+/// Whether the airdrops of a token account for most of its transfers so far, as for tokens whose only
+/// distribution is sending themselves to addresses that never asked for them.
+pub fn mostly_airdropped(airdropped: i64, received: i64) -> bool {
+    received > 0 && airdropped as f64 > received as f64 * AIRDROP_SHARE
+}
+
+/// Recipients of the tokens sent by a single sender to at least AIRDROP_MIN_RECIPIENTS addresses in one
+/// transaction, keyed by token.
+pub fn airdrops(transfers: &Transfers) -> BTreeMap<Vec<u8>, u64> {
+    let mut recipients: BTreeMap<Batch, BTreeSet<&[u8]>> = BTreeMap::new();
+    for transfer in &transfers.transfers {
+        recipients
+            .entry((&transfer.trx_hash, &transfer.token, &transfer.from))
+            .or_default()
+            .insert(&transfer.to);
+    }
+
+    let mut airdrops = BTreeMap::new();
+    for ((_, token, _), recipients) in recipients {
+        if recipients.len() >= AIRDROP_MIN_RECIPIENTS {
+            *airdrops.entry(token.to_vec()).or_default() += recipients.len() as u64;
+        }
+    }
+    airdrops
+}
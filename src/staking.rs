@@ -0,0 +1,103 @@
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::crypto;
+use crate::eth_utils;
+use crate::keys;
+use crate::pb::staking::RewardClaim;
+
+// Beacon chain deposit contract
+pub const DEPOSIT_CONTRACT: [u8; 20] = hex!("00000000219ab540356cbb839cbe05303d7705fa");
+
+/// `REASON_WITHDRAWAL` of the Firehose balance changes, added for Shanghai after the block model
+/// this package is built against, so it is matched by value.
+pub const WITHDRAWAL_REASON: i32 = 16;
+
+/// The deposit contract logs its amounts and indexes as little endian `uint64` bytes.
+pub fn read_little_endian_u64(bytes: &[u8]) -> Option<u64> {
+    let bytes: [u8; 8] = bytes.try_into().ok()?;
+    Some(u64::from_le_bytes(bytes))
+}
+
+/// Reward claim events of NFT staking programs, all logging the claimer and the amount claimed.
+pub const CLAIM_EVENTS: [&str; 4] = [
+    "RewardPaid(address,uint256)",
+    "RewardClaimed(address,uint256)",
+    "RewardsClaimed(address,uint256)",
+    "Claimed(address,uint256)",
+];
+
+/// ApeCoinStaking claim of the ApeCoin pool: `ClaimRewards(address indexed user, uint256 amount, address recipient)`,
+/// paid to `recipient`.
+const CLAIM_REWARDS: &str = "ClaimRewards(address,uint256,address)";
+
+/// ApeCoinStaking claim of an NFT pool:
+/// `ClaimRewardsNft(address indexed user, uint256 indexed poolId, uint256 amount, uint256 tokenId)`, paid to the
+/// recipient the claim function was given, which the event leaves out. The claims of a call share one transfer.
+const CLAIM_REWARDS_NFT: &str = "ClaimRewardsNft(address,uint256,uint256,uint256)";
+
+/// Rewards claimed from the `programs` staking contracts in the transaction, credited to the address they were paid
+/// to. The claimer of the generic events is read from the first topic when indexed and from the data otherwise. The
+/// reward token is the ERC20 the program sent the holder in the same transaction, empty when paid in ETH or in a token
+/// minted without a Transfer event.
+pub fn reward_claims(trx: &eth::TransactionTrace, programs: &[Vec<u8>]) -> Vec<RewardClaim> {
+    let topics: Vec<[u8; 32]> = CLAIM_EVENTS.iter().map(|event| crypto::keccak256(event.as_bytes())).collect();
+    let claim_rewards = crypto::keccak256(CLAIM_REWARDS.as_bytes());
+    let claim_rewards_nft = crypto::keccak256(CLAIM_REWARDS_NFT.as_bytes());
+    let logs = trx.receipt.as_ref().map(|receipt| receipt.logs.as_slice()).unwrap_or_default();
+
+    let mut claims = vec![];
+    for log in logs.iter().filter(|log| programs.contains(&log.address) && !log.topics.is_empty()) {
+        // (holder, amount, token id), the holder is None when only the transfer of the reward tells it
+        let (holder, amount, token_id) = match (log.topics.len(), log.data.len()) {
+            (2, 64) if log.topics[0] == claim_rewards => {
+                (Some(log.data[44..64].to_vec()), &log.data[..32], String::new())
+            }
+            (3, 64) if log.topics[0] == claim_rewards_nft => {
+                (None, &log.data[..32], BigInt::from_unsigned_bytes_be(&log.data[32..64]).to_string())
+            }
+            _ if !topics.iter().any(|topic| log.topics[0] == topic) => continue,
+            (2, 32) => (Some(log.topics[1][12..].to_vec()), &log.data[..], String::new()),
+            (1, 64) => (Some(log.data[12..32].to_vec()), &log.data[32..], String::new()),
+            _ => continue,
+        };
+        let amount = BigInt::from_unsigned_bytes_be(amount);
+
+        let paid_by_program = |transfer: &&eth::Log| {
+            transfer.topics.len() == 3
+                && transfer.topics[0] == eth_utils::TRANSFER_TOPIC
+                && transfer.topics[1][12..] == log.address[..]
+        };
+        let payment = match &holder {
+            Some(holder) => logs.iter().filter(paid_by_program).find(|transfer| {
+                transfer.topics[2][12..] == holder[..] && BigInt::from_unsigned_bytes_be(&transfer.data) == amount
+            }),
+            // NFT claims are paid at once after their events, in a single transfer of their total
+            None => logs.iter().filter(paid_by_program).find(|transfer| transfer.ordinal > log.ordinal),
+        };
+        let holder = match (holder, payment) {
+            (Some(holder), _) => holder,
+            (None, Some(payment)) => payment.topics[2][12..].to_vec(),
+            // the NFT claim paid nothing the transaction shows, the claimer is the best guess
+            (None, None) => log.topics[1][12..].to_vec(),
+        };
+
+        claims.push(RewardClaim {
+            id: eth_utils::record_id(&trx.hash, log.block_index),
+            program: log.address.clone(),
+            holder,
+            reward_token: payment.map(|transfer| transfer.address.clone()).unwrap_or_default(),
+            amount: amount.to_string(),
+            trx_hash: trx.hash.clone(),
+            ordinal: log.ordinal,
+            token_id,
+        });
+    }
+    claims
+}
+
+/// Rewards claimed by `holder` in `reward_token` across programs.
+pub fn reward_key(holder: &[u8], reward_token: &[u8]) -> String {
+    keys::key(keys::REWARD).hex(holder).hex(reward_token).into()
+}
@@ -0,0 +1,115 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use substreams::store::{StoreGet, StoreGetInt64};
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::crypto;
+use crate::eth_utils;
+use crate::keys;
+use crate::logging;
+use crate::params::Params;
+
+/// Highest storage slot tried when inferring the layout of a token.
+const MAX_SLOT: u64 = 16;
+
+pub fn balances_layout_key(token: &[u8]) -> String {
+    keys::key(keys::BALANCES).hex(token).into()
+}
+
+pub fn allowances_layout_key(token: &[u8]) -> String {
+    keys::key(keys::ALLOWANCES).hex(token).into()
+}
+
+/// Reads the `erc20` param, a comma separated list of `<token>[:<slot>]` entries where `slot` is the
+/// storage slot of the token `balances` mapping. Without a slot, the one inferred by
+/// store_storage_layouts is used, or 0 (OpenZeppelin layout) when none was inferred yet.
+pub fn balance_slots(params: &Params, layouts: &StoreGetInt64) -> BTreeMap<Vec<u8>, u64> {
+    let mut slots = BTreeMap::new();
+    for entry in params.list("erc20") {
+        let (address, slot) = match entry.split_once(':') {
+            Some((address, slot)) => (address, slot.parse().map(Some)),
+            None => (entry, Ok(None)),
+        };
+
+        match (eth_utils::parse_address(address), slot) {
+            (Ok(address), Ok(slot)) => {
+                let slot = slot
+                    .or_else(|| layouts.get_last(balances_layout_key(&address)).map(|slot| slot as u64))
+                    .unwrap_or(0);
+                slots.insert(address, slot);
+            }
+            _ => logging::warning!("params", "skipping invalid erc20 entry {}", entry),
+        }
+    }
+    slots
+}
+
+/// Storage key of the balance of `holder` in a `mapping(address => uint256)` stored at `slot`.
+pub fn balance_key(holder: &[u8], slot: u64) -> Vec<u8> {
+    let mut slot_word = [0u8; 32];
+    slot_word[24..].copy_from_slice(&slot.to_be_bytes());
+    mapping_key(holder, &slot_word)
+}
+
+/// Storage key of the allowance of `spender` over the tokens of `owner` in a
+/// `mapping(address => mapping(address => uint256))` stored at `slot`.
+pub fn allowance_key(owner: &[u8], spender: &[u8], slot: u64) -> Vec<u8> {
+    mapping_key(spender, &balance_key(owner, slot))
+}
+
+/// Slot of the mapping whose `key` entry is among the storage changes, trying every slot up to MAX_SLOT.
+pub fn infer_slot<F>(changes: &[&eth::StorageChange], key: F) -> Option<u64>
+where
+    F: Fn(u64) -> Vec<u8>,
+{
+    (0..=MAX_SLOT).find(|slot| {
+        let key = key(*slot);
+        changes.iter().any(|change| change.key == key)
+    })
+}
+
+fn mapping_key(address: &[u8], slot_word: &[u8]) -> Vec<u8> {
+    let mut preimage = [0u8; 64];
+    preimage[12..32].copy_from_slice(address);
+    preimage[32..].copy_from_slice(slot_word);
+    crypto::keccak256(&preimage).to_vec()
+}
+
+/// Addresses whose balance the transaction may have changed: its sender and the callers and targets
+/// of its calls, along with every address-like word of the call inputs and the log topics.
+pub fn candidate_holders(trx: &eth::TransactionTrace) -> BTreeSet<Vec<u8>> {
+    let mut holders = BTreeSet::new();
+    holders.insert(trx.from.clone());
+    holders.insert(trx.to.clone());
+
+    for call in &trx.calls {
+        holders.insert(call.caller.clone());
+        holders.insert(call.address.clone());
+
+        if call.input.len() > 4 {
+            for word in call.input[4..].chunks(32) {
+                if let Some(address) = address_word(word) {
+                    holders.insert(address);
+                }
+            }
+        }
+
+        for log in &call.logs {
+            for topic in log.topics.iter().skip(1) {
+                if let Some(address) = address_word(topic) {
+                    holders.insert(address);
+                }
+            }
+        }
+    }
+
+    holders.retain(|holder| holder.len() == 20);
+    holders
+}
+
+fn address_word(word: &[u8]) -> Option<Vec<u8>> {
+    if word.len() != 32 || word[..12].iter().any(|byte| *byte != 0) || word[12..].iter().all(|byte| *byte == 0) {
+        return None;
+    }
+    Some(word[12..].to_vec())
+}
@@ -0,0 +1,137 @@
+use substreams::Hex;
+use substreams_ethereum::NULL_ADDRESS;
+
+use crate::eth_utils;
+use crate::metadata;
+use crate::rpc_utils::{self, EthCall, RpcCounters};
+use crate::pb::entity::entity_change::Operation;
+use crate::pb::entity::value::Typed;
+use crate::pb::entity::{EntityChange, Field, Value};
+use crate::pb::erc721;
+
+/// Entity of the ERC721 subgraph schema, see `schema.graphql`.
+pub struct Entity {
+    change: EntityChange,
+}
+
+impl Entity {
+    pub fn new(entity: &str, id: String, ordinal: u64, operation: Operation) -> Entity {
+        Entity {
+            change: EntityChange {
+                entity: entity.to_string(),
+                id,
+                ordinal,
+                operation: operation as i32,
+                fields: vec![],
+            },
+        }
+    }
+
+    fn field(mut self, name: &str, value: Typed) -> Entity {
+        self.change.fields.push(Field {
+            name: name.to_string(),
+            new_value: Some(Value { typed: Some(value) }),
+            old_value: None,
+        });
+        self
+    }
+
+    pub fn string(self, name: &str, value: String) -> Entity {
+        self.field(name, Typed::String(value))
+    }
+
+    pub fn bigint<V: ToString>(self, name: &str, value: V) -> Entity {
+        self.field(name, Typed::Bigint(value.to_string()))
+    }
+
+    pub fn bigdecimal(self, name: &str, value: String) -> Entity {
+        self.field(name, Typed::Bigdecimal(value))
+    }
+
+    pub fn int(self, name: &str, value: i32) -> Entity {
+        self.field(name, Typed::Int32(value))
+    }
+
+    pub fn bool(self, name: &str, value: bool) -> Entity {
+        self.field(name, Typed::Bool(value))
+    }
+
+    pub fn into_change(self) -> EntityChange {
+        self.change
+    }
+}
+
+/// Ids are lowercase `0x` prefixed addresses, as `Address.toHex()` renders them in subgraph mappings.
+pub fn owner_id(address: &[u8]) -> String {
+    eth_utils::format_address(address)
+}
+
+pub fn token_id(token_id: u64) -> String {
+    token_id.to_string()
+}
+
+pub fn transfer(block: u64, transfer: &erc721::Transfer) -> EntityChange {
+    let timestamp = transfer.timestamp.as_ref().map(|timestamp| timestamp.seconds).unwrap_or_default();
+    let mut entity = Entity::new("Transfer", transfer.id.clone(), transfer.ordinal, Operation::Create)
+        .string("token", token_id(transfer.token_id))
+        .bigint("timestamp", timestamp)
+        .bigint("block", block)
+        .string("transactionHash", format!("0x{}", Hex(&transfer.trx_hash)));
+    if transfer.from != NULL_ADDRESS {
+        entity = entity.string("from", owner_id(&transfer.from));
+    }
+    if transfer.to != NULL_ADDRESS {
+        entity = entity.string("to", owner_id(&transfer.to));
+    }
+    entity.into_change()
+}
+
+/// Owner and contract of the token after the transfer, a minted token is created.
+pub fn token(transfer: &erc721::Transfer) -> EntityChange {
+    let operation = if transfer.from == NULL_ADDRESS { Operation::Create } else { Operation::Update };
+    Entity::new("Token", token_id(transfer.token_id), transfer.ordinal, operation)
+        .string("owner", owner_id(&transfer.to))
+        .string("contract", owner_id(&transfer.contract))
+        .into_change()
+}
+
+/// Contract entity with its name, symbol and ERC-7572 URIs read over RPC, when the collection is first minted.
+pub fn contract(
+    client: &impl EthCall,
+    address: &[u8],
+    ordinal: u64,
+    total_supply: i64,
+    created: bool,
+    rpc: &mut RpcCounters,
+) -> EntityChange {
+    if !created {
+        return Entity::new("Contract", owner_id(address), ordinal, Operation::Update)
+            .bigint("totalSupply", total_supply)
+            .into_change();
+    }
+
+    let mut entity =
+        Entity::new("Contract", owner_id(address), ordinal, Operation::Create).bigint("totalSupply", total_supply);
+    let (name, symbol) = rpc_utils::name_and_symbol(client, address, rpc);
+    if let Some(name) = name {
+        entity = entity.string("name", name);
+    }
+    if let Some(symbol) = symbol {
+        entity = entity.string("symbol", symbol);
+    }
+    let (contract_uri, logo_uri) = metadata::uris(client, address, None, rpc);
+    if !contract_uri.is_empty() {
+        entity = entity.string("contractURI", contract_uri);
+    }
+    if !logo_uri.is_empty() {
+        entity = entity.string("logoURI", logo_uri);
+    }
+    entity.into_change()
+}
+
+pub fn owner(address: &[u8], ordinal: u64, balance: i64, created: bool) -> EntityChange {
+    let operation = if created { Operation::Create } else { Operation::Update };
+    Entity::new("Owner", owner_id(address), ordinal, operation)
+        .bigint("balance", balance)
+        .into_change()
+}
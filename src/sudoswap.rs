@@ -0,0 +1,65 @@
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::eth_utils;
+use crate::keys;
+
+// Sudoswap LSSVMPairFactory
+pub const FACTORY: [u8; 20] = hex!("b16c1342e617a5b6e4b631eb114483fdb289c0a4");
+
+pub fn pool_key(pool: &[u8]) -> String {
+    keys::key(keys::POOL).hex(pool).into()
+}
+
+/// Total amount paid into or out of `pool` during the transaction, along with the ERC20 contract
+/// it was paid in. Native ETH is read from the pool balance changes and reported with an empty
+/// currency, ERC20 pools fall back to the token transfers touching the pool.
+pub fn pool_payment(trx: &eth::TransactionTrace, pool: &[u8]) -> Option<(BigInt, Vec<u8>)> {
+    let mut delta = BigInt::zero();
+    for call in &trx.calls {
+        if call.state_reverted {
+            continue;
+        }
+
+        for change in &call.balance_changes {
+            if change.address != pool {
+                continue;
+            }
+            delta = delta + balance(&change.new_value) - balance(&change.old_value);
+        }
+    }
+
+    if !delta.is_zero() {
+        if delta < BigInt::zero() {
+            delta = delta.neg();
+        }
+        return Some((delta, vec![]));
+    }
+
+    let mut amount = BigInt::zero();
+    let mut currency = vec![];
+    for log in trx.receipt().logs() {
+        let topics = log.topics();
+        if topics.len() != 3 || topics[0] != eth_utils::TRANSFER_TOPIC || log.data().len() != 32 {
+            continue;
+        }
+
+        if topics[1][12..] == *pool || topics[2][12..] == *pool {
+            amount = amount + BigInt::from_unsigned_bytes_be(log.data());
+            currency = log.address().to_vec();
+        }
+    }
+
+    if amount.is_zero() {
+        return None;
+    }
+    Some((amount, currency))
+}
+
+fn balance(value: &Option<eth::BigInt>) -> BigInt {
+    match value {
+        Some(value) => BigInt::from_unsigned_bytes_be(&value.bytes),
+        None => BigInt::zero(),
+    }
+}
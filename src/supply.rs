@@ -0,0 +1,69 @@
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+
+use crate::keys;
+use crate::params::Params;
+use crate::pb::tokens::CirculatingSupply;
+
+/// Burn address used by most tokens besides the zero address, whose transfers already lower the total supply.
+pub const DEAD_ADDRESS: [u8; 20] = hex!("000000000000000000000000000000000000dead");
+
+pub const BURNED: &str = "burned";
+pub const ESCROWED: &str = "escrowed";
+
+/// Balance of `token` held by the burn addresses, `kind` [BURNED], or by the bridge escrows, `kind` [ESCROWED].
+pub fn excluded_key(token: &[u8], kind: &str) -> String {
+    keys::key(keys::EXCLUDED).hex(token).text(kind).into()
+}
+
+/// Addresses whose balances are left out of the circulating supply, read from the `burn_addresses` and `escrows`
+/// params.
+pub struct Exclusions {
+    burn_addresses: Vec<Vec<u8>>,
+    escrows: Vec<Vec<u8>>,
+}
+
+impl Exclusions {
+    pub fn from_params(params: &Params) -> Exclusions {
+        let mut burn_addresses = params.addresses("burn_addresses");
+        burn_addresses.push(DEAD_ADDRESS.to_vec());
+        Exclusions {
+            burn_addresses,
+            escrows: params.addresses("escrows"),
+        }
+    }
+
+    /// Kind of exclusion of `holder`, if any.
+    pub fn kind(&self, holder: &[u8]) -> Option<&'static str> {
+        if self.burn_addresses.iter().any(|address| address[..] == *holder) {
+            Some(BURNED)
+        } else if self.escrows.iter().any(|address| address[..] == *holder) {
+            Some(ESCROWED)
+        } else {
+            None
+        }
+    }
+}
+
+/// Circulating supply of `token`: its total supply less what is burned, escrowed by bridges and locked in vesting
+/// contracts, floored at zero.
+pub fn circulating(
+    token: &[u8],
+    block: u64,
+    total_supply: BigInt,
+    burned: BigInt,
+    escrowed: BigInt,
+    locked: BigInt,
+) -> CirculatingSupply {
+    let excluded = burned.clone() + escrowed.clone() + locked.clone();
+    let circulating = if excluded > total_supply { BigInt::zero() } else { total_supply.clone() - excluded };
+    CirculatingSupply {
+        token: token.to_vec(),
+        block,
+        total_supply: total_supply.to_string(),
+        burned: burned.to_string(),
+        escrowed: escrowed.to_string(),
+        locked: locked.to_string(),
+        circulating: circulating.to_string(),
+    }
+}
@@ -0,0 +1,134 @@
+use std::str::FromStr;
+
+use substreams::scalar::BigInt;
+use substreams::store::{StoreGet, StoreGetInt64};
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::NULL_ADDRESS;
+
+use crate::keys;
+use crate::params::Params;
+use crate::pb::erc721;
+
+pub const EARLY_MINTER: &str = "early_minter";
+pub const PROFITABLE_FLIPPER: &str = "profitable_flipper";
+pub const MIXER_INTERACTION: &str = "mixer_interaction";
+
+/// Tornado Cash ETH pools (0.1, 1, 10 and 100 ETH), used when the `mixers` param is not set.
+const TORNADO_CASH_POOLS: [&str; 4] = [
+    "0x12d66f87a04a9e220743712ce6d9bb1b5616b8fc",
+    "0x47ce0c6ed5b0ce3d3a51fdb1c52dc66a7c3c2936",
+    "0x910cbd523d972eb0a6f4cae4618ad62622b39dbf",
+    "0xa160cdab225685da1d56aa342ad8841c3b53f291",
+];
+
+/// Tags kept in `store_tags`, a new tag needs an entry here and a rule applied by `store_tags`. `early_minter` is kept
+/// in `store_early_minters`.
+pub const TAGS: [&str; 2] = [EARLY_MINTER, PROFITABLE_FLIPPER];
+
+/// Stores the persistent tags are read from.
+pub struct WalletTags<'a> {
+    pub tags: &'a StoreGetInt64,
+    pub early_minters: &'a StoreGetInt64,
+}
+
+pub fn key(address: &[u8], tag: &str) -> String {
+    keys::key(keys::TAG).hex(address).text(tag).into()
+}
+
+pub fn bought_key(holder: &[u8], token_id: u64) -> String {
+    keys::key(keys::BOUGHT).hex(holder).number(token_id).into()
+}
+
+/// Minters of a token before the block set by the `early_mint_until` param.
+pub fn early_minter<'a>(params: &Params, block: u64, transfer: &'a erc721::Transfer) -> Option<&'a [u8]> {
+    let until: u64 = params.get("early_mint_until")?.parse().ok()?;
+    if transfer.from == NULL_ADDRESS && transfer.to != NULL_ADDRESS && block < until {
+        return Some(&transfer.to);
+    }
+    None
+}
+
+/// Sellers getting more ETH for a token than what they bought it for.
+pub fn profitable_flipper(sale: &erc721::Sale, bought: Option<BigInt>) -> Option<&[u8]> {
+    if !sale.currency.is_empty() {
+        return None;
+    }
+    let price = BigInt::from_str(&sale.price).ok()?;
+    if price > bought? {
+        return Some(&sale.seller);
+    }
+    None
+}
+
+/// Tags of `address` as of the end of the block.
+pub fn lookup(stores: &WalletTags, address: &[u8]) -> Vec<String> {
+    TAGS.iter()
+        .filter(|tag| {
+            let store = if **tag == EARLY_MINTER { stores.early_minters } else { stores.tags };
+            store.get_last(key(address, tag)).is_some()
+        })
+        .map(|tag| tag.to_string())
+        .collect()
+}
+
+pub fn mixer_key(address: &[u8]) -> String {
+    keys::key(keys::MIXER).hex(address).into()
+}
+
+/// Mixer contracts listed by the `mixers` param, the Tornado Cash ETH pools by default.
+pub fn mixers(params: &Params) -> Vec<Vec<u8>> {
+    if !params.list("mixers").is_empty() {
+        return params.addresses("mixers");
+    }
+    TORNADO_CASH_POOLS
+        .iter()
+        .filter_map(|pool| crate::eth_utils::parse_address(pool).ok())
+        .collect()
+}
+
+/// Blocks after an interaction with a mixer a wallet stays tagged for, read from the `lookback` param.
+pub fn lookback(params: &Params) -> u64 {
+    params.get("lookback").and_then(|value| value.parse().ok()).unwrap_or(50400)
+}
+
+/// Wallets that deposited to or withdrew from one of the mixers in the transaction: the sender and the
+/// callers of a mixer, and the recipients of the ETH it sent out.
+pub fn mixer_counterparties(trx: &eth::TransactionTrace, mixers: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    let mut counterparties: Vec<Vec<u8>> = vec![];
+    for call in trx.calls.iter().filter(|call| !call.state_reverted) {
+        let mut involved = vec![];
+        if mixers.contains(&call.address) {
+            involved.push(&trx.from);
+            involved.push(&call.caller);
+        }
+        let sends_value = call.value.as_ref().map_or(false, |value| value.bytes.iter().any(|byte| *byte != 0));
+        if mixers.contains(&call.caller) && sends_value {
+            involved.push(&call.address);
+        }
+
+        for address in involved {
+            if !mixers.contains(address) && !counterparties.contains(address) {
+                counterparties.push(address.clone());
+            }
+        }
+    }
+    counterparties
+}
+
+/// Tags of `address` as of the end of `block`, `mixer_interaction` included when it interacted with a
+/// mixer within the lookback.
+pub fn lookup_with_mixers(
+    tags: &WalletTags,
+    mixer_interactions: &StoreGetInt64,
+    lookback: u64,
+    block: u64,
+    address: &[u8],
+) -> Vec<String> {
+    let mut found = lookup(tags, address);
+    if let Some(last) = mixer_interactions.get_last(mixer_key(address)) {
+        if block.saturating_sub(last as u64) <= lookback {
+            found.push(MIXER_INTERACTION.to_string());
+        }
+    }
+    found
+}
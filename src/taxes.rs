@@ -0,0 +1,61 @@
+use std::collections::BTreeMap;
+
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::{Event, NULL_ADDRESS};
+
+use crate::abi;
+
+struct Transfer {
+    token: Vec<u8>,
+    from: Vec<u8>,
+    to: Vec<u8>,
+    value: BigInt,
+}
+
+/// Estimated transfer tax of the tokens taxing a transfer of the transaction, in percent. A taxed
+/// transfer shows up as two consecutive Transfer events from the same sender, one paying the fee
+/// to the token contract itself and the other sending what is left to the recipient.
+pub fn transfer_taxes(trx: &eth::TransactionTrace) -> BTreeMap<Vec<u8>, f64> {
+    let transfers: Vec<Transfer> = trx
+        .receipt()
+        .logs()
+        .filter_map(|log| {
+            abi::erc20::events::Transfer::match_and_decode(log.log).map(|transfer| Transfer {
+                token: log.address().to_vec(),
+                from: transfer.from,
+                to: transfer.to,
+                value: transfer.value,
+            })
+        })
+        .collect();
+
+    let mut taxes = BTreeMap::new();
+    for pair in transfers.windows(2) {
+        let (first, second) = (&pair[0], &pair[1]);
+        if first.token != second.token || first.from != second.from || first.from == NULL_ADDRESS {
+            continue;
+        }
+
+        let (fee, amount) = if first.to == first.token && second.to != second.token {
+            (&first.value, &second.value)
+        } else if second.to == second.token && first.to != first.token {
+            (&second.value, &first.value)
+        } else {
+            continue;
+        };
+
+        let fee: f64 = fee.to_string().parse().unwrap_or(0.0);
+        let amount: f64 = amount.to_string().parse().unwrap_or(0.0);
+        if fee <= 0.0 || amount <= 0.0 {
+            continue;
+        }
+
+        let rate = fee / (fee + amount) * 100.0;
+        let highest = taxes.entry(first.token.clone()).or_insert(rate);
+        if rate > *highest {
+            *highest = rate;
+        }
+    }
+    taxes
+}
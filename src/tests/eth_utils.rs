@@ -0,0 +1,108 @@
+use ethabi::Token;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use super::fixtures::word;
+use crate::eth_utils::{self, MAX_METADATA_CHARS};
+
+proptest! {
+    #[test]
+    fn strings_round_trip(value in ".*") {
+        let encoded = ethabi::encode(&[Token::String(value.clone())]);
+        prop_assert_eq!(eth_utils::read_string_bytes(&encoded).unwrap(), value.into_bytes());
+    }
+
+    #[test]
+    fn bytes32_strings_are_unpadded(value in "[a-zA-Z0-9][a-zA-Z0-9 ]{0,31}") {
+        let mut encoded = value.trim_end().as_bytes().to_vec();
+        encoded.resize(32, 0);
+        prop_assert_eq!(eth_utils::read_string_bytes(&encoded).unwrap(), value.trim_end().as_bytes());
+    }
+
+    #[test]
+    fn bytes32_numbers_are_rejected(value in any::<u64>()) {
+        prop_assert!(eth_utils::read_string_bytes(&word(&value.to_be_bytes())).is_err());
+    }
+
+    #[test]
+    fn strings_never_panic(input in vec(any::<u8>(), 0..256)) {
+        let _ = eth_utils::read_string_bytes(&input);
+    }
+
+    #[test]
+    fn strings_at_other_offsets_are_rejected(offset in any::<u32>(), tail in vec(any::<u8>(), 32..128)) {
+        prop_assume!(offset != 32);
+        let mut input = word(&offset.to_be_bytes());
+        input.extend(tail);
+        prop_assert!(eth_utils::read_string_bytes(&input).is_err());
+    }
+
+    #[test]
+    fn lengths_past_the_data_are_rejected(size in any::<u32>(), data in vec(any::<u8>(), 0..128)) {
+        prop_assume!(size as usize > data.len());
+        let mut input = word(&[32]);
+        input.extend(word(&size.to_be_bytes()));
+        input.extend(data);
+        prop_assert!(eth_utils::read_string_bytes(&input).is_err());
+    }
+
+    #[test]
+    fn uints_round_trip(value in any::<u32>()) {
+        prop_assert_eq!(eth_utils::read_uint32(&word(&value.to_be_bytes())).unwrap(), value);
+    }
+
+    #[test]
+    fn uints_never_panic(input in vec(any::<u8>(), 0..64)) {
+        let _ = eth_utils::read_uint32(&input);
+    }
+
+    #[test]
+    fn metadata_is_bounded_and_printable(raw in vec(any::<u8>(), 0..512)) {
+        let sanitized = eth_utils::sanitize_metadata(&raw);
+        prop_assert!(sanitized.chars().count() <= MAX_METADATA_CHARS);
+        prop_assert!(!sanitized.chars().any(char::is_control));
+        prop_assert_eq!(eth_utils::is_lossy(&raw), std::str::from_utf8(&raw).is_err());
+    }
+
+    #[test]
+    fn addresses_round_trip(address in vec(any::<u8>(), 20)) {
+        let formatted = eth_utils::format_address(&address);
+        prop_assert_eq!(eth_utils::parse_address(&formatted).unwrap(), address);
+    }
+}
+
+#[test]
+fn empty_strings() {
+    let encoded = ethabi::encode(&[Token::String(String::new())]);
+    assert_eq!(encoded.len(), 64);
+    assert_eq!(eth_utils::read_string_bytes(&encoded).unwrap(), Vec::<u8>::new());
+}
+
+#[test]
+fn maximal_length_field() {
+    let mut input = word(&[32]);
+    input.extend(word(&u32::MAX.to_be_bytes()));
+    input.extend([0u8; 32]);
+    assert!(eth_utils::read_string_bytes(&input).is_err());
+}
+
+#[test]
+fn bytes32_strings_need_printable_nul_padded_content() {
+    let bytes32 = |content: &[u8]| {
+        let mut input = content.to_vec();
+        input.resize(32, 0);
+        input
+    };
+    assert_eq!(eth_utils::read_string_bytes(&bytes32(b"Maker")).unwrap(), b"Maker");
+    assert!(eth_utils::read_string_bytes(&bytes32(b"")).is_err());
+    assert!(eth_utils::read_string_bytes(&bytes32(b"MKR\x01")).is_err());
+    assert!(eth_utils::read_string_bytes(&bytes32(b"MKR\0junk")).is_err());
+    assert!(eth_utils::read_string_bytes(&bytes32(&[0xc3, 0xa9])).is_err());
+}
+
+#[test]
+fn record_ids_tell_logs_and_calls_apart() {
+    let hash = [0xab; 32];
+    assert_eq!(eth_utils::record_id(&hash, 7), format!("0x{}-7", "ab".repeat(32)));
+    assert_eq!(eth_utils::call_record_id(&hash, 7), format!("0x{}-call-7", "ab".repeat(32)));
+}
@@ -0,0 +1,128 @@
+//! Recorded blocks shaped after mainnet ones, holding only the fields the handlers read, and builders for the
+//! transactions and logs of the tests.
+
+use ethabi::Token;
+use hex_literal::hex;
+use prost::Message;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::eth_utils;
+use crate::TRACKED_CONTRACT;
+
+pub const MINTER: [u8; 20] = hex!("aba7161a7fb69c88e16ed9f455ce62b791ee4d03");
+pub const SELLER: [u8; 20] = hex!("1b523dc90a79cf5ee5d095825e586e33780f7188");
+pub const BUYER: [u8; 20] = hex!("020ca66c30bec2c4fe3861a94e4db4a498a35872");
+pub const PROXY_TOKEN: [u8; 20] = hex!("5a98fcbea516cf06857215779fd812ca3bef1b32");
+pub const TOKEN: [u8; 20] = hex!("7d1afa7b718fb893db30a3abc0cfc608aacfebb0");
+pub const ORDER_HASH: [u8; 32] = hex!("2cc0a9e2d42c5f5b1a6d0a3f25bd4cd6a0e0f3bfa4b6c3f0f8e1b7a2c1d9e0f1");
+
+pub const TIMESTAMP: i64 = 1_651_000_000;
+pub const PROCEEDS: u128 = 70_000_000_000_000_000_000;
+pub const FEES: u128 = 1_750_000_000_000_000_000;
+
+pub fn block(number: u64, transaction_traces: Vec<eth::TransactionTrace>) -> eth::Block {
+    eth::Block {
+        number,
+        header: Some(eth::BlockHeader {
+            number,
+            timestamp: Some(prost_types::Timestamp {
+                seconds: TIMESTAMP,
+                nanos: 0,
+            }),
+            ..Default::default()
+        }),
+        transaction_traces,
+        ..Default::default()
+    }
+}
+
+pub fn transaction(hash: u8, from: &[u8], logs: Vec<eth::Log>, calls: Vec<eth::Call>) -> eth::TransactionTrace {
+    eth::TransactionTrace {
+        hash: vec![hash; 32],
+        from: from.to_vec(),
+        status: eth::TransactionTraceStatus::Succeeded as i32,
+        receipt: Some(eth::TransactionReceipt {
+            logs,
+            ..Default::default()
+        }),
+        calls,
+        ..Default::default()
+    }
+}
+
+/// ABI word of `bytes` left padded to 32 bytes: an address topic, or a small uint from its big endian bytes.
+pub fn word(bytes: &[u8]) -> Vec<u8> {
+    [&[0u8; 32][bytes.len()..], bytes].concat()
+}
+
+/// Log of `address` at `ordinal`, which also stands for its index in the block.
+pub fn log(address: &[u8], topics: Vec<Vec<u8>>, data: Vec<u8>, ordinal: u64) -> eth::Log {
+    eth::Log {
+        address: address.to_vec(),
+        topics,
+        data,
+        block_index: ordinal as u32,
+        ordinal,
+        ..Default::default()
+    }
+}
+
+/// Log of the `signature` event of `address`, its indexed `topics` following the event topic.
+pub fn event(address: &[u8], signature: &str, topics: Vec<Vec<u8>>, data: Vec<u8>, ordinal: u64) -> eth::Log {
+    let topics = [vec![crate::crypto::keccak256(signature.as_bytes()).to_vec()], topics].concat();
+    log(address, topics, data, ordinal)
+}
+
+/// ERC721 `Transfer` of a TRACKED_CONTRACT token.
+pub fn transfer_log(from: &[u8], to: &[u8], token_id: u64, block_index: u32) -> eth::Log {
+    eth::Log {
+        address: TRACKED_CONTRACT.to_vec(),
+        topics: vec![eth_utils::TRANSFER_TOPIC.to_vec(), word(from), word(to), word(&token_id.to_be_bytes())],
+        block_index,
+        ordinal: block_index as u64 + 1,
+        ..Default::default()
+    }
+}
+
+/// Logs bloom holding the log addresses and topics.
+pub fn bloom(inputs: &[&[u8]]) -> Vec<u8> {
+    let mut bloom = vec![0u8; 256];
+    for input in inputs {
+        let hash = crate::crypto::keccak256(input);
+        for i in 0..3 {
+            let bit = (((hash[2 * i] as usize) << 8) | hash[2 * i + 1] as usize) & 2047;
+            bloom[255 - bit / 8] |= 1 << (bit % 8);
+        }
+    }
+    bloom
+}
+
+/// ABI encoded `string` return value.
+pub fn string_return(value: &str) -> Vec<u8> {
+    ethabi::encode(&[Token::String(value.to_string())])
+}
+
+/// ABI encoded `uint` return value.
+pub fn uint_return(value: u64) -> Vec<u8> {
+    ethabi::encode(&[Token::Uint(value.into())])
+}
+
+/// Serialized `sf.ethereum.type.v2.Block` checked in under `src/tests/blocks`.
+fn recorded(encoded: &[u8]) -> eth::Block {
+    eth::Block::decode(encoded).expect("invalid recorded block")
+}
+
+/// Thirty tokens minted in one transaction, along with a failed mint whose logs must be ignored.
+pub fn mint_heavy() -> eth::Block {
+    recorded(include_bytes!("blocks/mint_heavy.pb"))
+}
+
+/// A token initialized through its proxy, next to creations that are no token candidates.
+pub fn proxy_token() -> eth::Block {
+    recorded(include_bytes!("blocks/proxy_token.pb"))
+}
+
+/// A token sold on Seaport for 70 ETH to the seller and 1.75 ETH of fees.
+pub fn marketplace_sale() -> eth::Block {
+    recorded(include_bytes!("blocks/marketplace_sale.pb"))
+}
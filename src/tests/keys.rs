@@ -0,0 +1,73 @@
+use crate::keys::{self, migrate};
+
+const HOLDER: [u8; 2] = [0xab, 0xcd];
+
+#[test]
+fn builds_the_legacy_layouts_of_unversioned_families() {
+    let key: String = keys::key(keys::TOTAL).hex(&HOLDER).hex(&[0x01]).into();
+    assert_eq!(key, "total:abcd:01");
+    let key: String = keys::key(keys::PENDING).block(42).into();
+    assert_eq!(key, "pending:00000000000000000042");
+    let key: String = keys::key(keys::OWNER).key(keys::key(keys::POSITION).number(7)).into();
+    assert_eq!(key, "owner:position:7");
+}
+
+#[test]
+fn escapes_separators_in_text_segments() {
+    let key: String = keys::key(keys::TAG).hex(&HOLDER).text("early:minter%").into();
+    assert_eq!(key, "tag.v2:abcd:early%3Aminter%25");
+
+    let parsed = keys::parse(&key).unwrap();
+    assert!(parsed.is(keys::TAG));
+    assert_eq!(parsed.len(), 2);
+    assert_eq!(parsed.hex(0), Some(HOLDER.to_vec()));
+    assert_eq!(parsed.text(1).as_deref(), Some("early:minter%"));
+}
+
+#[test]
+fn migrates_legacy_keys() {
+    let collection = [0x01];
+    assert_eq!(
+        migrate("tag:abcd:early_minter", &collection).as_deref(),
+        Some("tag.v2:abcd:early_minter")
+    );
+    assert_eq!(migrate("size:abcd:300", &collection).as_deref(), Some("size.v2:abcd%3A300"));
+    assert_eq!(
+        migrate("recent:token:7:3", &collection).as_deref(),
+        Some("recent.v2:nft:7:01:00000000000000000003")
+    );
+    assert_eq!(
+        migrate("recent:holder:abcd:12", &collection).as_deref(),
+        Some("recent.v2:holder:abcd:00000000000000000012")
+    );
+    assert_eq!(
+        migrate("netflow:abcd:binance:1h:480000", &collection).as_deref(),
+        Some("netflow.v2:1h:00000000000000480000:abcd:binance")
+    );
+    // families that did not change keep their keys
+    assert_eq!(migrate("total:abcd:01", &collection).as_deref(), Some("total:abcd:01"));
+    assert_eq!(migrate("tag:not_hex:early_minter", &collection), None);
+    assert_eq!(migrate("recent:token:7", &collection), None);
+    assert_eq!(migrate("netflow:abcd:binance:1h:480000:1", &collection), None);
+
+    let migrated = migrate("size:abcd:300", &collection).unwrap();
+    let parsed = keys::parse(&migrated).unwrap();
+    assert!(parsed.is(keys::SIZE));
+    assert_eq!(parsed.text(0).as_deref(), Some("abcd:300"));
+}
+
+#[test]
+fn keeps_token_contracts_and_collection_tokens_apart() {
+    let contract: String = keys::key(keys::TOKEN).hex(&HOLDER).into();
+    assert_eq!(contract, "token:abcd");
+    let token: String = keys::key(keys::NFT).number(7).hex(&HOLDER).into();
+    assert_eq!(token, "nft:7:abcd");
+}
+
+#[test]
+fn keeps_balances_under_both_orderings() {
+    let contract = [0x01];
+    let [by_holder, by_contract] = keys::ERC20_BALANCES.keys(&HOLDER, &contract);
+    assert_eq!(by_holder.to_string(), "erc20:abcd:01");
+    assert_eq!(by_contract.to_string(), "erc20_holders:01:abcd");
+}
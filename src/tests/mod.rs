@@ -0,0 +1,8 @@
+mod eth_utils;
+mod fixtures;
+mod keys;
+mod perf;
+mod reorg;
+mod sales;
+mod tokens;
+mod transfers;
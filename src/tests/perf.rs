@@ -0,0 +1,48 @@
+use super::fixtures;
+use crate::pb::{erc721, tokens};
+use crate::perf::{self, BLOCKS};
+
+#[test]
+fn measures_the_block() {
+    let block = fixtures::mint_heavy();
+    let transfers = erc721::Transfers {
+        transfers: crate::block_transfers(&block),
+    };
+    let none = tokens::Tokens::default();
+    let measures = perf::measure(&block, 0, &transfers, &tokens::Transfers::default(), &none, &none);
+
+    let value = |name: &str| measures.iter().find(|(measure, _)| *measure == name).unwrap().1;
+    assert_eq!(value("transactions"), 2);
+    // logs of the failed transaction are not gone through
+    assert_eq!(value("logs"), 30);
+    assert_eq!(value("fungible_transactions"), 1);
+    assert!(value("bytes_emitted") > 0);
+}
+
+#[test]
+fn counts_the_transactions_the_bloom_rules_out_of_fungible_decoding() {
+    let mut block = fixtures::mint_heavy();
+    block.transaction_traces[0].receipt.as_mut().unwrap().logs_bloom = fixtures::bloom(&[&fixtures::MINTER]);
+    let none = tokens::Tokens::default();
+    let measures = perf::measure(&block, 0, &erc721::Transfers::default(), &tokens::Transfers::default(), &none, &none);
+
+    let value = |name: &str| measures.iter().find(|(measure, _)| *measure == name).unwrap().1;
+    assert_eq!(value("transactions"), 2);
+    assert_eq!(value("fungible_transactions"), 0);
+}
+
+#[test]
+fn flags_measures_far_above_their_average() {
+    let totals = |key: &str| match key {
+        BLOCKS => 100,
+        "total:logs" => 1_000,
+        _ => 0,
+    };
+
+    let (measures, pathological) = perf::compare(vec![("logs", 50)], totals, 10.0);
+    assert_eq!(measures[0].ratio, 5.0);
+    assert!(!pathological);
+
+    let (_, pathological) = perf::compare(vec![("logs", 500)], totals, 10.0);
+    assert!(pathological);
+}
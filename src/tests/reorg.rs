@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::scalar::BigInt;
+use substreams_ethereum::NULL_ADDRESS;
+
+use super::fixtures::{self, BUYER, MINTER, SELLER};
+use crate::pb::erc721;
+use crate::reorg::{self, Change};
+use crate::{block_transfers, first_acquisition_key, first_acquisitions, floor_prices, generate_key, holder_deltas};
+
+/// Writes of a StoreAdd handler against `state`, recorded as the deltas the runtime keeps for the block.
+fn add(state: &mut BTreeMap<String, i64>, writes: Vec<(u64, String, i64)>) -> Vec<Change<i64>> {
+    let mut changes = vec![];
+    for (_, key, delta) in writes {
+        let recorded = match state.get(&key) {
+            Some(old_value) => change(Operation::Update, &key, *old_value, old_value + delta),
+            None => change(Operation::Create, &key, 0, delta),
+        };
+        reorg::apply(state, std::slice::from_ref(&recorded));
+        changes.push(recorded);
+    }
+    changes
+}
+
+/// Writes of a StoreMin handler against `state`, only the writes lowering the value leave a delta.
+fn min<T>(state: &mut BTreeMap<String, T>, writes: Vec<(u64, String, T)>) -> Vec<Change<T>>
+where
+    T: Clone + From<i32> + PartialOrd,
+{
+    let mut changes = vec![];
+    for (_, key, value) in writes {
+        let recorded = match state.get(&key) {
+            Some(old_value) if *old_value <= value => continue,
+            Some(old_value) => change(Operation::Update, &key, old_value.clone(), value),
+            None => change(Operation::Create, &key, T::from(0), value),
+        };
+        reorg::apply(state, std::slice::from_ref(&recorded));
+        changes.push(recorded);
+    }
+    changes
+}
+
+fn transfers(number: u64, from: &[u8], to: &[u8], token_id: u64) -> erc721::Transfers {
+    let trx = fixtures::transaction(1, from, vec![fixtures::transfer_log(from, to, token_id, 0)], vec![]);
+    erc721::Transfers {
+        transfers: block_transfers(&fixtures::block(number, vec![trx])),
+        ..Default::default()
+    }
+}
+
+fn sale(price: u64) -> erc721::Sales {
+    erc721::Sales {
+        sales: vec![erc721::Sale {
+            price: price.to_string(),
+            ordinal: 1,
+            ..Default::default()
+        }],
+    }
+}
+
+fn change<T>(operation: Operation, key: &str, old_value: T, new_value: T) -> Change<T> {
+    Change {
+        operation,
+        key: key.to_string(),
+        old_value,
+        new_value,
+    }
+}
+
+#[test]
+fn floor_price_lowered_twice_inverts_to_the_previous_floor() {
+    // store_floor_price: a floor set by an earlier block, lowered by two sales, and a window opened by a third one
+    let changes = vec![
+        change(Operation::Update, "floor:0", BigInt::from(90), BigInt::from(80)),
+        change(Operation::Update, "floor:0", BigInt::from(80), BigInt::from(70)),
+        change(Operation::Create, "floor:1", BigInt::zero(), BigInt::from(75)),
+    ];
+    let before = reorg::opening(&changes);
+    assert_eq!(before, BTreeMap::from([("floor:0".to_string(), BigInt::from(90))]));
+    assert!(reorg::check(&before, &changes).is_empty());
+
+    let mut state = before.clone();
+    reorg::apply(&mut state, &changes);
+    assert_eq!(state.get("floor:1"), Some(&BigInt::from(75)));
+    reorg::undo(&mut state, &changes);
+    assert_eq!(state, before);
+    assert_eq!(reorg::keys(&changes), 2);
+}
+
+#[test]
+fn first_acquisitions_and_balances_created_in_the_block_are_dropped_by_the_undo() {
+    // store_first_acquisition and store_transfers for a holder that got its first token in the block
+    let changes = vec![
+        change(Operation::Create, "first:holder", 0, 100),
+        change(Operation::Create, "total:holder:contract", 0, 1),
+        change(Operation::Update, "total:holder:contract", 1, 0),
+    ];
+    let mut state = BTreeMap::new();
+    reorg::apply(&mut state, &changes);
+    reorg::undo(&mut state, &changes);
+    assert!(state.is_empty());
+    assert!(reorg::check(&BTreeMap::new(), &changes).is_empty());
+}
+
+#[test]
+fn deltas_that_do_not_chain_up_are_reported() {
+    let changes = vec![
+        change(Operation::Update, "total:holder:contract", 2, 1),
+        change(Operation::Update, "total:holder:contract", 3, 2),
+        change(Operation::Create, "first:holder", 0, 100),
+    ];
+    let before = BTreeMap::from([("total:holder:contract".to_string(), 2), ("first:holder".to_string(), 90)]);
+    let problems = reorg::check(&before, &changes);
+    assert_eq!(problems.len(), 3, "{:?}", problems);
+    assert!(problems[0].contains("changed from 3 while holding 1"));
+    assert!(problems[1].contains("first:holder created while holding 90"));
+    assert!(problems[2].contains("first:holder not restored"));
+}
+
+#[test]
+fn forked_out_transfer_leaves_the_balances_of_the_canonical_block() {
+    // store_transfers: SELLER minted a token, the forked out block sends it to BUYER, the canonical one to MINTER
+    let mut state = BTreeMap::new();
+    add(&mut state, holder_deltas(&transfers(100, &NULL_ADDRESS, &SELLER, 1)));
+    let before = state.clone();
+
+    let forked = add(&mut state, holder_deltas(&transfers(101, &SELLER, &BUYER, 1)));
+    assert_eq!(state.get(&generate_key(&BUYER.to_vec())), Some(&1));
+    assert!(reorg::check(&before, &forked).is_empty());
+    reorg::undo(&mut state, &forked);
+    assert_eq!(state, before);
+
+    add(&mut state, holder_deltas(&transfers(101, &SELLER, &MINTER, 1)));
+    assert_eq!(state.get(&generate_key(&SELLER.to_vec())), Some(&0));
+    assert_eq!(state.get(&generate_key(&MINTER.to_vec())), Some(&1));
+    assert!(!state.contains_key(&generate_key(&BUYER.to_vec())));
+}
+
+#[test]
+fn forked_out_first_acquisition_does_not_hold_back_the_later_one() {
+    // store_first_acquisition: a min store would keep block 101 for MINTER if the undo left the created key behind
+    let mut state = BTreeMap::from([(first_acquisition_key(&BUYER), 90)]);
+    let before = state.clone();
+
+    let forked = min(&mut state, first_acquisitions(&transfers(101, &BUYER, &MINTER, 1), 101));
+    assert_eq!(forked.len(), 1);
+    reorg::undo(&mut state, &forked);
+    assert_eq!(state, before);
+
+    min(&mut state, first_acquisitions(&transfers(101, &NULL_ADDRESS, &BUYER, 2), 101));
+    min(&mut state, first_acquisitions(&transfers(102, &BUYER, &MINTER, 1), 102));
+    assert_eq!(state.get(&first_acquisition_key(&BUYER)), Some(&90));
+    assert_eq!(state.get(&first_acquisition_key(&MINTER)), Some(&102));
+}
+
+#[test]
+fn forked_out_sale_gives_back_the_previous_floor() {
+    // store_floor_price: the forked out sale lowered the floor of the window below the canonical one
+    let mut state = BTreeMap::new();
+    min(&mut state, floor_prices(&sale(90), 7200, 7200));
+    let before = state.clone();
+
+    let forked = min(&mut state, floor_prices(&sale(70), 7200, 7201));
+    reorg::undo(&mut state, &forked);
+    assert_eq!(state, before);
+
+    min(&mut state, floor_prices(&sale(80), 7200, 7201));
+    assert_eq!(state.values().collect::<Vec<_>>(), vec![&BigInt::from(80)]);
+}
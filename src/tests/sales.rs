@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+
+use ethabi::Token;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use super::fixtures::{self, BUYER, FEES, ORDER_HASH, PROCEEDS, SELLER, TIMESTAMP};
+use crate::crypto;
+use crate::documents;
+use crate::histogram;
+use crate::payouts;
+use crate::pb::erc721;
+use crate::pb::erc721::creator_payout::Kind;
+use crate::revenue;
+use crate::seaport;
+use crate::{seaport_sales, TRACKED_CONTRACT};
+
+#[test]
+fn seaport_listing_fill() {
+    let block = fixtures::marketplace_sale();
+    let trx = &block.transaction_traces[0];
+    let sales = seaport_sales(trx, &block.header.as_ref().unwrap().timestamp, |_: &[u8], _| BigInt::zero());
+
+    assert_eq!(sales.len(), 1);
+    let sale = &sales[0];
+    assert_eq!(sale.marketplace, "seaport");
+    assert_eq!(sale.token_id, 1234);
+    assert_eq!(sale.seller, SELLER);
+    assert_eq!(sale.buyer, BUYER);
+    assert_eq!(sale.price, (PROCEEDS + FEES).to_string());
+    assert!(sale.currency.is_empty());
+    assert_eq!(sale.order_hash, ORDER_HASH);
+    // the fees all go to OpenSea
+    assert_eq!(sale.royalty, "0");
+    assert!(sale.royalties.is_empty());
+    assert_eq!(sale.ordinal, 2);
+    // the OrderFulfilled event is the second log of the block
+    assert_eq!(sale.id, format!("0x{}-1-1234", "01".repeat(32)));
+}
+
+#[test]
+fn sales_are_embedded_in_the_document_of_their_transaction() {
+    let block = fixtures::marketplace_sale();
+    let trx = &block.transaction_traces[0];
+    let timestamp = block.header.as_ref().unwrap().timestamp.clone();
+    let sales = erc721::Sales {
+        sales: seaport_sales(trx, &timestamp, |_: &[u8], _| BigInt::zero()),
+    };
+
+    let mut documents = documents::Documents::new(block.number, timestamp);
+    documents.sales(&sales);
+    let changes = documents.into_table_changes();
+    assert_eq!(changes.len(), 1);
+    assert_eq!((changes[0].table.as_str(), changes[0].ordinal), (documents::COLLECTION, 2));
+    assert_eq!(changes[0].pk, format!("0x{}", "01".repeat(32)));
+
+    let field = |name: &str| changes[0].fields.iter().find(|field| field.name == name).unwrap().new_value.clone();
+    assert_eq!(field("block_timestamp"), TIMESTAMP.to_string());
+    assert_eq!(field("nft_transfers"), "[]");
+    let embedded = field("sales");
+    assert!(embedded.starts_with(r#"[{"marketplace":"seaport","collection":"0x"#));
+    assert!(embedded.contains(&format!(r#""token_id":1234,"seller":"0x{}""#, substreams::Hex(SELLER))));
+    assert!(embedded.ends_with(r#""currency":"ETH","ordinal":2}]"#));
+}
+
+#[test]
+fn seaport_royalties_are_added_up_by_currency() {
+    let weth = [1u8; 20];
+    let creator = [2u8; 20];
+    let item = |item_type, token: &[u8], amount: u64, recipient: &[u8]| seaport::Item {
+        item_type,
+        token: token.to_vec(),
+        identifier: BigInt::zero(),
+        start_amount: BigInt::from(amount),
+        end_amount: BigInt::from(amount),
+        recipient: recipient.to_vec(),
+    };
+    let order = seaport::OrderFulfilled {
+        order_hash: ORDER_HASH.to_vec(),
+        offerer: SELLER.to_vec(),
+        recipient: BUYER.to_vec(),
+        offer: vec![],
+        consideration: vec![
+            item(seaport::NATIVE, &[0u8; 20], 900, &SELLER),
+            item(seaport::NATIVE, &[0u8; 20], 25, &seaport::FEE_RECIPIENTS[0]),
+            item(seaport::NATIVE, &[0u8; 20], 50, &creator),
+            item(seaport::ERC20, &weth, 70, &creator),
+            item(seaport::ERC20, &weth, 30, &[3u8; 20]),
+        ],
+    };
+
+    let royalties = order.royalties(&SELLER);
+    assert_eq!(royalties, BTreeMap::from([(vec![], BigInt::from(50)), (weth.to_vec(), BigInt::from(100))]));
+}
+
+#[test]
+fn advanced_orders_hash_like_plain_orders() {
+    let address = |address: &[u8]| Token::Address(ethabi::Address::from_slice(address));
+    let uint = |value: u64| Token::Uint(value.into());
+    let offer = Token::Tuple(vec![uint(2), address(&TRACKED_CONTRACT), uint(1234), uint(1), uint(1)]);
+    let consideration = Token::Tuple(vec![uint(0), address(&[0u8; 20]), uint(0), uint(70), uint(70), address(&SELLER)]);
+    let parameters = Token::Tuple(vec![
+        address(&SELLER),
+        address(&[0u8; 20]),
+        Token::Array(vec![offer]),
+        Token::Array(vec![consideration]),
+        uint(0),
+        uint(1),
+        uint(2),
+        Token::FixedBytes(vec![0u8; 32]),
+        uint(3),
+        Token::FixedBytes(vec![0u8; 32]),
+        uint(1),
+    ]);
+    let call = |selector: [u8; 4], order: Vec<Token>| eth::Call {
+        address: seaport::SEAPORT.to_vec(),
+        input: [&selector[..], &ethabi::encode(&[Token::Tuple(order), Token::FixedBytes(vec![0u8; 32])])].concat(),
+        ..Default::default()
+    };
+    let order = call(hex_literal::hex!("b3a34c4c"), vec![parameters.clone(), Token::Bytes(vec![1; 65])]);
+    let advanced = call(
+        hex_literal::hex!("e7acab24"),
+        vec![parameters, uint(1), uint(1), Token::Bytes(vec![1; 65]), Token::Bytes(vec![])],
+    );
+
+    let hash = seaport::order_hash(&order, |_| BigInt::zero()).unwrap();
+    assert_eq!(seaport::order_hash(&advanced, |_| BigInt::zero()), Some(hash.clone()));
+    // cancelling every open order by incrementing the counter changes the hash
+    assert_ne!(seaport::order_hash(&advanced, |_| BigInt::from(1)), Some(hash));
+}
+
+#[test]
+fn estimates_price_quantiles_within_a_bucket() {
+    let eth = |tenths: u64| BigInt::from(tenths) * BigInt::from(100_000_000_000_000_000u64);
+    // nine sales around 1 ETH and one outlier at 50 ETH
+    let mut counts: BTreeMap<u32, i64> = BTreeMap::new();
+    for price in [9, 10, 10, 10, 10, 11, 11, 10, 10, 500].map(eth) {
+        *counts.entry(histogram::bucket(&price).unwrap()).or_default() += 1;
+    }
+    let counts: Vec<(u32, i64)> = counts.into_iter().collect();
+
+    let median = histogram::quantile(&counts, 0.5).unwrap() / 1e18;
+    assert!((0.9..1.2).contains(&median), "median {}", median);
+    let p90 = histogram::quantile(&counts, 0.9).unwrap() / 1e18;
+    assert!((0.9..1.2).contains(&p90), "p90 {}", p90);
+    assert!(histogram::quantile(&counts, 1.0).unwrap() / 1e18 > 45.0);
+    assert_eq!(histogram::bucket(&BigInt::zero()), None);
+    assert_eq!(histogram::quantile(&[], 0.5), None);
+}
+
+#[test]
+fn adds_mint_and_royalty_revenue_to_totals_and_days() {
+    let timestamp = Some(prost_types::Timestamp { seconds: TIMESTAMP, nanos: 0 });
+    let purchases = erc721::MintPurchases {
+        purchases: vec![erc721::MintPurchase {
+            paid: "800".to_string(),
+            timestamp: timestamp.clone(),
+            ..Default::default()
+        }],
+    };
+    let sale = |royalties: &[(&[u8], &str)]| erc721::Sale {
+        collection: TRACKED_CONTRACT.to_vec(),
+        royalties: royalties
+            .iter()
+            .map(|(currency, amount)| erc721::Royalty {
+                currency: currency.to_vec(),
+                amount: amount.to_string(),
+            })
+            .collect(),
+        timestamp: timestamp.clone(),
+        ..Default::default()
+    };
+    // royalties in WETH and sales without royalty are left out
+    let sales = erc721::Sales {
+        sales: vec![sale(&[(&[], "25"), (&[1u8; 20], "30")]), sale(&[])],
+    };
+
+    let day = TIMESTAMP / 86_400;
+    let entries: Vec<(String, BigInt)> =
+        revenue::entries(&purchases, &sales).into_iter().map(|(_, key, amount)| (key, amount)).collect();
+    assert_eq!(
+        entries,
+        [
+            (revenue::revenue_key(&TRACKED_CONTRACT, revenue::PRIMARY, None), BigInt::from(800)),
+            (revenue::revenue_key(&TRACKED_CONTRACT, revenue::PRIMARY, Some(day)), BigInt::from(800)),
+            (revenue::revenue_key(&TRACKED_CONTRACT, revenue::SECONDARY, None), BigInt::from(25)),
+            (revenue::revenue_key(&TRACKED_CONTRACT, revenue::SECONDARY, Some(day)), BigInt::from(25)),
+        ]
+    );
+}
+
+#[test]
+fn payouts_of_a_collection_splitting_its_revenue() {
+    let splitter = [5u8; 20];
+    let address = |address: &[u8]| Token::Address(ethabi::Address::from_slice(address));
+    let released = eth::Log {
+        address: TRACKED_CONTRACT.to_vec(),
+        topics: vec![crypto::keccak256(b"PaymentReleased(address,uint256)").to_vec()],
+        data: ethabi::encode(&[address(&SELLER), Token::Uint(500.into())]),
+        ordinal: 3,
+        ..Default::default()
+    };
+    let send = |to: &[u8], value: u64, begin_ordinal| eth::Call {
+        caller: TRACKED_CONTRACT.to_vec(),
+        address: to.to_vec(),
+        value: Some(eth::BigInt { bytes: value.to_be_bytes().to_vec() }),
+        begin_ordinal,
+        ..Default::default()
+    };
+    // the release of the share of the seller, a withdrawal to the buyer and a move to the splitter
+    let calls = vec![send(&SELLER, 500, 2), send(&BUYER, 300, 4), send(&splitter, 700, 5)];
+    let trx = fixtures::transaction(1, &SELLER, vec![released], calls);
+
+    let payouts = payouts::decode(&trx, &TRACKED_CONTRACT, &[splitter.to_vec()]);
+    assert_eq!(payouts.len(), 2);
+    assert_eq!(payouts[0].kind, Kind::Release as i32);
+    assert_eq!(payouts[0].recipient, SELLER);
+    assert_eq!(payouts[0].amount, "500");
+    assert_eq!(payouts[1].kind, Kind::Withdrawal as i32);
+    assert_eq!(payouts[1].recipient, BUYER);
+    assert_eq!(payouts[1].amount, "300");
+    // releases are keyed by their log, withdrawals by their call
+    assert_eq!(payouts[0].id, format!("0x{}-0", "01".repeat(32)));
+    assert_eq!(payouts[1].id, format!("0x{}-call-0", "01".repeat(32)));
+}
@@ -0,0 +1,749 @@
+use std::collections::BTreeMap;
+
+use ethabi::Token;
+use hex_literal::hex;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::scalar::BigInt;
+use substreams::store::{DeltaBigInt, Deltas};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use super::fixtures::{self, event, log, word, BUYER, MINTER, PROXY_TOKEN, SELLER, TOKEN};
+use crate::bytecode;
+use crate::collections;
+use crate::crypto;
+use crate::eth_utils;
+use crate::exchanges;
+use crate::governance;
+use crate::params::Params;
+use crate::pb::erc721::collection_metadata::Standard;
+use crate::pb::tokens::proposal_event::Kind as ProposalKind;
+use crate::pb::tokens::GovernanceEvents;
+use crate::pb::tokens::presale_event::Kind;
+use crate::pb::tokens::token_rejection::{Probe, Reason};
+use crate::pb::tokens::vesting_event::Kind as VestingKind;
+use crate::portfolio;
+use crate::presales;
+use crate::rpc_utils::{self, Recorded, RpcCounters};
+use crate::snapshot;
+use crate::spam;
+use crate::staking;
+use crate::supply;
+use crate::tokenlist;
+use crate::vesting;
+use crate::whale;
+use crate::{discover_tokens, probe_token, token_candidates};
+
+#[test]
+fn proxy_initializations_and_creations_are_candidates() {
+    let block = fixtures::proxy_token();
+    let (candidates, _) = token_candidates(&block);
+
+    let addresses: Vec<_> = candidates.iter().map(|(_, call)| call.address.clone()).collect();
+    assert_eq!(addresses, vec![PROXY_TOKEN.to_vec(), TOKEN.to_vec()]);
+    assert!(candidates.iter().all(|(trx, _)| trx.from == MINTER));
+}
+
+#[test]
+fn small_contracts_and_known_callers_are_rejected() {
+    let block = fixtures::proxy_token();
+    let (_, rejections) = token_candidates(&block);
+
+    assert_eq!(rejections.len(), 2);
+    assert_eq!(rejections[0].address, vec![1u8; 20]);
+    assert_eq!(rejections[0].reason, Reason::CodeTooSmall as i32);
+    assert_eq!(rejections[0].detail, "45 bytes of code");
+    assert_eq!(rejections[1].address, vec![2u8; 20]);
+    assert_eq!(rejections[1].reason, Reason::KnownCaller as i32);
+    assert_eq!(rejections[1].caller, hex!("0000000000004946c0e9f43f4dee607b0ef1fa1c"));
+}
+
+fn token_responses(address: &[u8]) -> Recorded {
+    Recorded::default()
+        .respond(address, rpc_utils::DECIMALS, fixtures::uint_return(18))
+        .respond(
+            address,
+            rpc_utils::NAME,
+            fixtures::string_return("Polygon Ecosystem Token"),
+        )
+        .respond(address, rpc_utils::SYMBOL, fixtures::string_return("POL"))
+}
+
+#[test]
+fn probes_decimals_name_and_symbol() {
+    let mut rpc = RpcCounters::default();
+    let token = probe_token(&token_responses(&TOKEN), &TOKEN.to_vec(), None, &mut rpc).unwrap();
+
+    assert_eq!(token.name, "Polygon Ecosystem Token");
+    assert_eq!(token.symbol, "POL");
+    assert_eq!(token.decimals, 18);
+    // the token does not implement contractURI, its failed call is counted
+    assert!(token.contract_uri.is_empty());
+    assert_eq!((rpc.calls, rpc.failures), (4, 1));
+}
+
+#[test]
+fn reads_name_and_symbol_of_contracts_answering_either() {
+    let mut rpc = RpcCounters::default();
+    let client = Recorded::default().respond(&TOKEN, rpc_utils::SYMBOL, fixtures::string_return("POL"));
+    assert_eq!(rpc_utils::name_and_symbol(&client, &TOKEN, &mut rpc), (None, Some("POL".to_string())));
+    assert_eq!((rpc.calls, rpc.failures), (2, 1));
+}
+
+#[test]
+fn bytes32_metadata_is_decoded() {
+    let mut name = b"Maker".to_vec();
+    name.resize(32, 0);
+    let mut symbol = b"MKR".to_vec();
+    symbol.resize(32, 0);
+    let client = Recorded::default()
+        .respond(&TOKEN, rpc_utils::DECIMALS, fixtures::uint_return(18))
+        .respond(&TOKEN, rpc_utils::NAME, name)
+        .respond(&TOKEN, rpc_utils::SYMBOL, symbol);
+
+    let token = probe_token(&client, &TOKEN.to_vec(), None, &mut RpcCounters::default()).unwrap();
+    assert_eq!((token.name.as_str(), token.symbol.as_str()), ("Maker", "MKR"));
+}
+
+#[test]
+fn inlined_contract_uri_logo() {
+    let uri = r#"data:application/json,{"name":"POL","image":"ipfs://logo"}"#;
+    let client = token_responses(&TOKEN).respond(&TOKEN, rpc_utils::CONTRACT_URI, fixtures::string_return(uri));
+
+    let token = probe_token(&client, &TOKEN.to_vec(), None, &mut RpcCounters::default()).unwrap();
+    assert_eq!(token.contract_uri, uri);
+    assert_eq!(token.logo_uri, "ipfs://logo");
+}
+
+#[test]
+fn failing_probes_are_reported() {
+    let mut rpc = RpcCounters::default();
+    let client = Recorded::default().revert(&TOKEN, rpc_utils::DECIMALS);
+    let rejection = probe_token(&client, &TOKEN.to_vec(), None, &mut rpc).unwrap_err();
+    assert_eq!(rejection.reason, Reason::CallFailed as i32);
+    assert_eq!(rejection.probe, Probe::Decimals as i32);
+    assert_eq!(rejection.detail, "execution reverted");
+    assert_eq!((rpc.calls, rpc.failures), (1, 1));
+
+    let client = Recorded::default()
+        .respond(&TOKEN, rpc_utils::DECIMALS, fixtures::uint_return(18))
+        .respond(
+            &TOKEN,
+            rpc_utils::NAME,
+            fixtures::string_return("Polygon Ecosystem Token"),
+        )
+        .respond(&TOKEN, rpc_utils::SYMBOL, vec![0xff; 40]);
+    let rejection = probe_token(&client, &TOKEN.to_vec(), None, &mut RpcCounters::default()).unwrap_err();
+    assert_eq!(rejection.reason, Reason::DecodeFailed as i32);
+    assert_eq!(rejection.probe, Probe::Symbol as i32);
+}
+
+#[test]
+fn discovers_tokens_of_the_block() {
+    let (tokens, rejections, rpc) = discover_tokens(&token_responses(&PROXY_TOKEN), &fixtures::proxy_token());
+
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].address, PROXY_TOKEN);
+    assert_eq!(tokens[0].deployer, MINTER);
+
+    // TOKEN has no recorded response, its decimals probe fails after the candidates rejected before probing
+    assert_eq!(rejections.len(), 3);
+    assert_eq!(rejections[2].address, TOKEN);
+    assert_eq!(rejections[2].probe, Probe::Decimals as i32);
+    assert_eq!(rejections[2].caller, MINTER);
+    assert_eq!(rejections[2].trx_hash, vec![1u8; 32]);
+    assert_eq!((rpc.calls, rpc.failures), (5, 2));
+}
+
+#[test]
+fn reads_the_contract_uri_of_proxies_through_their_implementation() {
+    let implementation = [9u8; 20];
+    let mut block = fixtures::proxy_token();
+    block.transaction_traces[0].calls.push(eth::Call {
+        call_type: eth::CallType::Delegate as i32,
+        address: implementation.to_vec(),
+        caller: PROXY_TOKEN.to_vec(),
+        ..Default::default()
+    });
+    let uri = r#"data:application/json,{"name":"POL","image":"ipfs://logo"}"#;
+    let client =
+        token_responses(&PROXY_TOKEN).respond(&implementation, rpc_utils::CONTRACT_URI, fixtures::string_return(uri));
+
+    let (tokens, _, rpc) = discover_tokens(&client, &block);
+    assert_eq!(tokens[0].contract_uri, uri);
+    assert_eq!(tokens[0].logo_uri, "ipfs://logo");
+    // the proxy's own contractURI and TOKEN's decimals fail, the implementation answers
+    assert_eq!((rpc.calls, rpc.failures), (6, 2));
+}
+
+#[test]
+fn probes_collection_standards_and_metadata() {
+    let [erc721, erc1155, other] = [[7u8; 20], [8u8; 20], [9u8; 20]];
+    let supports = |interface: &str| format!("{}{}{}", rpc_utils::SUPPORTS_INTERFACE, interface, "0".repeat(56));
+    let royalty_info = format!("{}{:064x}{:064x}", rpc_utils::ROYALTY_INFO, 0, 10_000);
+    let client = Recorded::default()
+        .respond(&erc721, &supports("80ac58cd"), fixtures::uint_return(1))
+        .respond(&erc1155, &supports("80ac58cd"), fixtures::uint_return(0))
+        .respond(&erc1155, &supports("d9b67a26"), fixtures::uint_return(1))
+        .respond(&erc721, rpc_utils::NAME, fixtures::string_return("Apes"))
+        .respond(&erc721, rpc_utils::SYMBOL, fixtures::string_return("APE"))
+        .respond(&erc721, rpc_utils::OWNER, word(&MINTER))
+        .respond(&erc721, &royalty_info, [word(&SELLER), word(&[0x01, 0xf4])].concat())
+        .respond(&erc721, rpc_utils::CONTRACT_URI, fixtures::string_return("ipfs://collection"));
+
+    let mut rpc = RpcCounters::default();
+    let contracts = [erc721.to_vec(), erc1155.to_vec(), other.to_vec()];
+    let standards = collections::standards(&client, &contracts, &mut rpc);
+    assert_eq!(standards, vec![Some(Standard::Erc721), Some(Standard::Erc1155), None]);
+    // the ERC1155 probe of the ERC721 contract and both probes of the other contract revert
+    assert_eq!((rpc.calls, rpc.failures), (6, 3));
+
+    let collection = collections::metadata(&client, &erc721, Standard::Erc721, None, &mut rpc);
+    assert_eq!((collection.name.as_str(), collection.symbol.as_str()), ("Apes", "APE"));
+    assert_eq!(collection.owner, MINTER.to_vec());
+    assert_eq!((collection.royalty_receiver.as_slice(), collection.royalty_bps), (&SELLER[..], 500));
+    assert_eq!(collection.contract_uri, "ipfs://collection");
+    assert_eq!((rpc.calls, rpc.failures), (11, 3));
+}
+
+#[test]
+fn scales_amounts_to_whole_tokens() {
+    let params = Params::parse(&format!("erc20_prices=0x{}:6:1", Hex(TOKEN)));
+    let priced = whale::priced_tokens(&params);
+    assert_eq!(priced[0].units(&BigInt::from(1_500_000)), "1.5");
+    assert_eq!(priced[0].units(&BigInt::from(2_000_000)), "2");
+    assert_eq!(priced[0].usd_value(&BigInt::from(1_500_000)), 1.5);
+}
+
+#[test]
+fn keeps_raw_balances_and_scales_holding_values() {
+    let deltas = || {
+        let [key, _] = portfolio::erc20_keys(&SELLER, &TOKEN);
+        let delta = DeltaBigInt {
+            operation: Operation::Update,
+            ordinal: 1,
+            key,
+            old_value: BigInt::from(0),
+            new_value: BigInt::from(1_500_000),
+        };
+        Deltas { deltas: vec![delta] }
+    };
+    let empty = || Deltas { deltas: vec![] };
+
+    let portfolios =
+        portfolio::build(Deltas { deltas: vec![] }, empty(), deltas(), BTreeMap::new(), |_| Some(6));
+    let holding = &portfolios.portfolios[0].holdings[0];
+    assert_eq!((holding.balance.as_str(), holding.value.as_str(), holding.decimals), ("1500000", "1.5", 6));
+
+    // tokens that were never discovered keep their raw balance only
+    let portfolios = portfolio::build(Deltas { deltas: vec![] }, empty(), deltas(), BTreeMap::new(), |_| None);
+    let holding = &portfolios.portfolios[0].holdings[0];
+    assert_eq!((holding.balance.as_str(), holding.value.as_str(), holding.decimals), ("1500000", "", 0));
+}
+
+#[test]
+fn reads_inline_and_packed_snapshots_at_their_block() {
+    let inline = format!("snapshot=0x{}:0x{}:1.5,invalid&snapshot_block=100", Hex(SELLER), Hex(TOKEN));
+    let params = Params::parse(&inline);
+    assert!(snapshot::balances(&params, 99).is_empty());
+    let balances = snapshot::balances(&params, 100);
+    assert_eq!(balances.len(), 1);
+    assert_eq!((balances[0].holder.as_slice(), balances[0].contract.as_slice()), (&SELLER[..], &TOKEN[..]));
+    assert_eq!(balances[0].balance, "1.5");
+
+    // the same balance, packed as a BalanceSnapshot
+    let packed = "CjEKFBtSPckKec9e5dCVgl5YbjN4D3GIEhR9Gvp7cY+4k9swo6vAz8YIqs/rsCIDMS41";
+    let packed = Params::parse(&format!("snapshot=base64:{}&snapshot_block=100", packed));
+    assert_eq!(snapshot::balances(&packed, 100), balances);
+}
+
+#[test]
+fn clones_differing_by_metadata_share_a_code_hash() {
+    let code = |metadata_hash: u8| {
+        let mut code = hex!("6080604052348015600f57600080fd5b50").to_vec();
+        // {"ipfs": <34 bytes>, "solc": 0.8.19}, followed by its length
+        code.extend_from_slice(&hex!("a264697066735822"));
+        code.extend_from_slice(&[metadata_hash; 34]);
+        code.extend_from_slice(&hex!("64736f6c63430008130033"));
+        code
+    };
+
+    assert_eq!(bytecode::strip_metadata(&code(1)), hex!("6080604052348015600f57600080fd5b50"));
+    assert_eq!(bytecode::code_hash(&code(1)), bytecode::code_hash(&code(2)));
+    assert_eq!(bytecode::strip_metadata(&[0x60, 0x80, 0x00, 0x01]), [0x60, 0x80, 0x00, 0x01]);
+}
+
+#[test]
+fn airdrop_signal_needs_airdrops_to_make_most_of_the_transfers() {
+    let transfer = |trx: u8, token: &[u8], to: u8| crate::pb::tokens::Transfer {
+        trx_hash: vec![trx; 32],
+        token: token.to_vec(),
+        from: MINTER.to_vec(),
+        to: vec![to; 20],
+        ..Default::default()
+    };
+    // a batch of 120 recipients of TOKEN, and 5 transfers of PROXY_TOKEN
+    let mut transfers: Vec<_> = (1..=120).map(|to| transfer(1, &TOKEN, to)).collect();
+    transfers.extend((1..=5).map(|to| transfer(2, &PROXY_TOKEN, to)));
+    let transfers = crate::pb::tokens::Transfers {
+        transfers,
+        ..Default::default()
+    };
+    assert_eq!(spam::airdrops(&transfers), BTreeMap::from([(TOKEN.to_vec(), 120)]));
+    assert_eq!(spam::received(&transfers)[&TOKEN.to_vec()], 120);
+
+    assert!(spam::mostly_airdropped(120, 120));
+    // a token moved around in 10000 transfers before getting batch sent
+    assert!(!spam::mostly_airdropped(120, 10_120));
+    assert!(!spam::mostly_airdropped(0, 0));
+}
+
+#[test]
+fn impersonation_is_caught_on_the_symbol_alone() {
+    let token = crate::pb::tokens::Token {
+        address: TOKEN.to_vec(),
+        name: "Totally Legit".to_string(),
+        symbol: "U.S.D.T".to_string(),
+        ..Default::default()
+    };
+    assert!(spam::metadata_signals(&token).contains(&spam::IMPERSONATION));
+}
+
+#[test]
+fn known_templates_lower_the_spam_score() {
+    let signals = [spam::NO_LIQUIDITY.to_string(), spam::AIRDROP.to_string()];
+    assert_eq!(spam::score(&signals, ""), 60.0);
+    assert_eq!(spam::score(&signals, bytecode::KNOWN), 30.0);
+    assert_eq!(spam::score(&[spam::IMPERSONATION.to_string()], bytecode::KNOWN), 30.0);
+
+    let hash = bytecode::code_hash(&[0x60; 200]);
+    let params = Params::parse(&format!("templates=openzeppelin_erc20,0x{},0x1234", Hex(&hash)));
+    let allowlist = bytecode::Allowlist::from_params(&params);
+    assert!(allowlist.trusts("openzeppelin_erc20", &[]));
+    assert!(allowlist.trusts("", &hash));
+    assert!(!allowlist.trusts("openzeppelin_erc20_v5", &[1u8; 32]));
+}
+
+#[test]
+fn token_templates_are_matched_by_their_selectors() {
+    // PUSH4 of every selector, as the dispatcher does, and the Panic(uint256) selector solc pushes anyway
+    let code = |functions: &[&str]| {
+        let mut code = vec![0x63, 0x4e, 0x48, 0x7b, 0x71];
+        for function in functions {
+            code.push(0x63);
+            code.extend_from_slice(&crypto::keccak256(function.as_bytes())[..4]);
+        }
+        code
+    };
+    let erc20 = [
+        "name()",
+        "symbol()",
+        "decimals()",
+        "totalSupply()",
+        "balanceOf(address)",
+        "transfer(address,uint256)",
+        "allowance(address,address)",
+        "approve(address,uint256)",
+        "transferFrom(address,address,uint256)",
+        "increaseAllowance(address,uint256)",
+        "decreaseAllowance(address,uint256)",
+    ];
+    assert_eq!(bytecode::template(&code(&erc20)), Some("openzeppelin_erc20"));
+
+    let burnable = [&erc20[..], &["burn(uint256)", "burnFrom(address,uint256)"]].concat();
+    assert_eq!(bytecode::template(&code(&burnable)), Some("openzeppelin_erc20_burnable"));
+
+    // an owner mint makes it another contract
+    let mintable = [&erc20[..], &["mint(address,uint256)"]].concat();
+    assert_eq!(bytecode::template(&code(&mintable)), None);
+    assert_eq!(bytecode::template(&[]), None);
+}
+
+#[test]
+fn detects_mint_and_blacklist_selectors() {
+    let selector = |signature: &str| crypto::keccak256(signature.as_bytes())[..4].to_vec();
+    // PUSH4 mint(address,uint256), then the blacklist(address) selector inside a PUSH32 constant
+    let mut code = vec![0x63];
+    code.extend(selector("mint(address,uint256)"));
+    code.push(0x7f);
+    code.extend(selector("blacklist(address)"));
+    code.extend([0u8; 28]);
+    assert_eq!(bytecode::capabilities(&code), (true, false));
+
+    let mut code = vec![0x63];
+    code.extend(selector("addBlackList(address)"));
+    assert_eq!(bytecode::capabilities(&code), (false, true));
+}
+
+#[test]
+fn decodes_presale_contributions_and_finalization() {
+    let presale = [9u8; 20];
+    let call = |caller: &[u8], address: &[u8], input: Vec<u8>, value: u64| eth::Call {
+        caller: caller.to_vec(),
+        address: address.to_vec(),
+        input,
+        value: Some(eth::BigInt { bytes: BigInt::from(value).to_bytes_be().1 }),
+        ..Default::default()
+    };
+    let signature = b"addLiquidityETH(address,uint256,uint256,uint256,address,uint256)";
+    let mut add_liquidity = crypto::keccak256(signature)[..4].to_vec();
+    add_liquidity.extend_from_slice(&[0u8; 12]);
+    add_liquidity.extend_from_slice(&TOKEN);
+    add_liquidity.extend_from_slice(&[0u8; 160]);
+    let trx = fixtures::transaction(
+        1,
+        &MINTER,
+        vec![],
+        vec![
+            call(&MINTER, &presale, vec![], 5),
+            call(&MINTER, &presale, vec![], 0),
+            call(&presale, &[7u8; 20], add_liquidity, 4),
+        ],
+    );
+
+    let is_presale = |address: &[u8]| address == presale;
+    let events = presales::decode(&trx, is_presale);
+    assert_eq!(events.len(), 2);
+    assert_eq!((events[0].kind, events[0].account.as_slice()), (Kind::Contribution as i32, &MINTER[..]));
+    assert_eq!(events[0].amount, "5");
+    assert_eq!((events[1].kind, events[1].account.as_slice()), (Kind::Finalization as i32, &TOKEN[..]));
+    assert_eq!(events[1].amount, "4");
+
+    // the owner cancels, then the contributor withdraws
+    let cancel = crypto::keccak256(b"cancel()")[..4].to_vec();
+    let trx = fixtures::transaction(2, &SELLER, vec![], vec![call(&SELLER, &presale, cancel, 0)]);
+    let events = presales::decode(&trx, is_presale);
+    assert_eq!((events[0].kind, events[0].account.as_slice()), (Kind::Cancellation as i32, &SELLER[..]));
+    let withdraw = vec![call(&MINTER, &presale, vec![], 0), call(&presale, &MINTER, vec![], 5)];
+    let trx = fixtures::transaction(3, &MINTER, vec![], withdraw);
+    let events = presales::decode(&trx, is_presale);
+    assert_eq!(events.len(), 1);
+    assert_eq!((events[0].kind, events[0].account.as_slice()), (Kind::Refund as i32, &MINTER[..]));
+    assert_eq!(events[0].amount, "5");
+}
+
+#[test]
+fn follows_the_presales_launchpads_create() {
+    let (launchpad, presale) = ([8u8; 20], [9u8; 20]);
+    let create = |caller: &[u8]| eth::Call {
+        call_type: eth::CallType::Create as i32,
+        caller: caller.to_vec(),
+        address: presale.to_vec(),
+        begin_ordinal: 3,
+        ..Default::default()
+    };
+    let trx = fixtures::transaction(1, &MINTER, vec![], vec![create(&launchpad)]);
+    assert_eq!(presales::launched(&trx, &[launchpad.to_vec()]), vec![(presale.to_vec(), 3)]);
+
+    let trx = fixtures::transaction(1, &MINTER, vec![], vec![create(&MINTER)]);
+    assert!(presales::launched(&trx, &[launchpad.to_vec()]).is_empty());
+}
+
+#[test]
+fn staking_reward_claims_are_tied_to_the_paid_token() {
+    let program = [8u8; 20];
+    let reward_paid = crypto::keccak256(b"RewardPaid(address,uint256)").to_vec();
+    let claimed = crypto::keccak256(b"Claimed(address,uint256)").to_vec();
+    let logs = vec![
+        log(&TOKEN, vec![eth_utils::TRANSFER_TOPIC.to_vec(), word(&program), word(&SELLER)], word(&[0x64]), 1),
+        log(&program, vec![reward_paid, word(&SELLER)], word(&[0x64]), 2),
+        // unindexed claimer
+        log(&program, vec![claimed.clone()], [word(&MINTER), word(&[7])].concat(), 3),
+        // same event from a contract that is no staking program
+        log(&TOKEN, vec![claimed], [word(&MINTER), word(&[7])].concat(), 4),
+    ];
+    let trx = fixtures::transaction(1, &SELLER, logs, vec![]);
+
+    let claims = staking::reward_claims(&trx, &[program.to_vec()]);
+    assert_eq!(claims.len(), 2);
+    assert_eq!((claims[0].holder.as_slice(), claims[0].amount.as_str()), (&SELLER[..], "100"));
+    assert_eq!(claims[0].reward_token, TOKEN);
+    assert_eq!((claims[1].holder.as_slice(), claims[1].amount.as_str()), (&MINTER[..], "7"));
+    assert!(claims[1].reward_token.is_empty());
+    // claims are keyed by their event, the index of its log in the block
+    let ids: Vec<&str> = claims.iter().map(|claim| claim.id.as_str()).collect();
+    assert_eq!(ids, [format!("0x{}-2", "01".repeat(32)), format!("0x{}-3", "01".repeat(32))]);
+}
+
+#[test]
+fn ape_coin_staking_claims_are_credited_to_their_recipient() {
+    let program = [8u8; 20];
+    let paid = |to: &[u8], ordinal| {
+        log(&TOKEN, vec![eth_utils::TRANSFER_TOPIC.to_vec(), word(&program), word(to)], word(&[0x64]), ordinal)
+    };
+    let claim_rewards = crypto::keccak256(b"ClaimRewards(address,uint256,address)").to_vec();
+    let claim_rewards_nft = crypto::keccak256(b"ClaimRewardsNft(address,uint256,uint256,uint256)").to_vec();
+    let logs = vec![
+        paid(&BUYER, 1),
+        log(&program, vec![claim_rewards, word(&SELLER)], [word(&[0x64]), word(&BUYER)].concat(), 2),
+        // BAYC pool, token 42, claimed by the seller to the minter
+        log(&program, vec![claim_rewards_nft, word(&SELLER), word(&[1])], [word(&[0x64]), word(&[42])].concat(), 3),
+        paid(&MINTER, 4),
+    ];
+    let trx = fixtures::transaction(1, &SELLER, logs, vec![]);
+
+    let claims = staking::reward_claims(&trx, &[program.to_vec()]);
+    assert_eq!(claims.len(), 2);
+    assert_eq!((claims[0].holder.as_slice(), claims[0].reward_token.as_slice()), (&BUYER[..], &TOKEN[..]));
+    assert!(claims[0].token_id.is_empty());
+    assert_eq!((claims[1].holder.as_slice(), claims[1].token_id.as_str()), (&MINTER[..], "42"));
+    assert_eq!(claims[1].amount, "100");
+}
+
+#[test]
+fn governance_events_of_discovered_tokens_only() {
+    let delegate_changed = crypto::keccak256(b"DelegateChanged(address,address,address)").to_vec();
+    let votes_changed = crypto::keccak256(b"DelegateVotesChanged(address,uint256,uint256)").to_vec();
+    let logs = vec![
+        log(&TOKEN, vec![delegate_changed, word(&SELLER), word(&[0u8; 20]), word(&MINTER)], vec![], 1),
+        log(&TOKEN, vec![votes_changed.clone(), word(&MINTER)], [word(&[0]), word(&[0x64])].concat(), 2),
+        log(&PROXY_TOKEN, vec![votes_changed, word(&MINTER)], [word(&[0]), word(&[1])].concat(), 3),
+    ];
+    let trx = fixtures::transaction(1, &SELLER, logs, vec![]);
+
+    let mut events = GovernanceEvents::default();
+    governance::decode(&trx, 15_000_000, |token| token == TOKEN, &mut events);
+    assert_eq!(events.delegations.len(), 1);
+    assert_eq!(events.delegations[0].delegator, SELLER);
+    assert_eq!(events.delegations[0].to_delegate, MINTER);
+    assert_eq!(events.checkpoints.len(), 1);
+    assert_eq!(events.checkpoints[0].delegate, MINTER);
+    assert_eq!((events.checkpoints[0].previous_votes.as_str(), events.checkpoints[0].votes.as_str()), ("0", "100"));
+    assert_eq!(events.checkpoints[0].block, 15_000_000);
+}
+
+#[test]
+fn proposal_lifecycle_and_votes_of_listed_governors() {
+    let governor = [9u8; 20];
+    let address = |address: &[u8]| Token::Address(ethabi::Address::from_slice(address));
+    let created = ethabi::encode(&[
+        Token::Uint(7.into()),
+        address(&SELLER),
+        Token::Array(vec![address(&TOKEN)]),
+        Token::Array(vec![Token::Uint(0.into())]),
+        Token::Array(vec![Token::String(String::new())]),
+        Token::Array(vec![Token::Bytes(vec![])]),
+        Token::Uint(100.into()),
+        Token::Uint(200.into()),
+        Token::String("Fund the grants".to_string()),
+    ]);
+    let vote = ethabi::encode(&[
+        Token::Uint(7.into()),
+        Token::Uint(1.into()),
+        Token::Uint(500.into()),
+        Token::String("gm".to_string()),
+    ]);
+    let vote_with_params = ethabi::encode(&[
+        Token::Uint(7.into()),
+        Token::Uint(0.into()),
+        Token::Uint(300.into()),
+        Token::String(String::new()),
+        Token::Bytes(vec![0xab]),
+    ]);
+    let proposal_created =
+        "ProposalCreated(uint256,address,address[],uint256[],string[],bytes[],uint256,uint256,string)";
+    let vote_cast_with_params = "VoteCastWithParams(address,uint256,uint8,uint256,string,bytes)";
+    let logs = vec![
+        event(&governor, proposal_created, vec![], created, 1),
+        event(&governor, "VoteCast(address,uint256,uint8,uint256,string)", vec![word(&MINTER)], vote, 2),
+        event(&governor, vote_cast_with_params, vec![word(&MINTER)], vote_with_params, 3),
+        event(&governor, "ProposalExecuted(uint256)", vec![], ethabi::encode(&[Token::Uint(7.into())]), 4),
+    ];
+    let trx = fixtures::transaction(1, &SELLER, logs, vec![]);
+
+    let events = governance::proposal_events(&trx, &[governor.to_vec()]);
+    let kinds: Vec<i32> = events.iter().map(|event| event.kind).collect();
+    let vote_cast = ProposalKind::VoteCast as i32;
+    assert_eq!(kinds, [ProposalKind::Created as i32, vote_cast, vote_cast, ProposalKind::Executed as i32]);
+    assert!(events.iter().all(|event| event.proposal_id == "7"));
+    assert_eq!(events[0].proposer, SELLER);
+    assert_eq!(events[0].targets, [TOKEN.to_vec()]);
+    assert_eq!((events[0].start_block, events[0].end_block), (100, 200));
+    assert_eq!(events[0].description, "Fund the grants");
+    assert_eq!((events[1].voter.as_slice(), events[1].support, events[1].weight.as_str()), (&MINTER[..], 1, "500"));
+    assert_eq!((events[2].support, events[2].weight.as_str(), events[2].params.as_slice()), (0, "300", &[0xab][..]));
+    assert!(governance::proposal_events(&trx, &[]).is_empty());
+}
+
+#[test]
+fn vesting_streams_and_wallets_lock_discovered_tokens() {
+    let sablier = vesting::SABLIER_V1[1];
+    let impostor = [8u8; 20];
+    let wallet = [7u8; 20];
+    let address = |address: &[u8]| Token::Address(ethabi::Address::from_slice(address));
+    let transfer = "Transfer(address,address,uint256)";
+    let created = ethabi::encode(&[
+        Token::Uint(1000.into()),
+        address(&TOKEN),
+        Token::Uint(100.into()),
+        Token::Uint(200.into()),
+    ]);
+    let create_stream = "CreateStream(uint256,address,address,uint256,address,uint256,uint256)";
+    let stream = |id: u8, party: &[u8]| vec![word(&[id]), word(party)];
+    let withdraw = "WithdrawFromStream(uint256,address,uint256)";
+    let logs = vec![
+        event(&sablier, create_stream, [stream(1, &SELLER), vec![word(&MINTER)]].concat(), created.clone(), 1),
+        event(&impostor, create_stream, [stream(1, &SELLER), vec![word(&MINTER)]].concat(), created, 2),
+        // paid out of a stream of another token first, the withdrawal of stream 1 must not take its token
+        event(&PROXY_TOKEN, transfer, vec![word(&sablier), word(&MINTER)], word(&[0x01]), 3),
+        event(&sablier, withdraw, stream(2, &MINTER), word(&[0x01]), 4),
+        event(&TOKEN, transfer, vec![word(&sablier), word(&MINTER)], word(&[0x64]), 5),
+        event(&sablier, withdraw, stream(1, &MINTER), word(&[0x64]), 6),
+        event(&TOKEN, transfer, vec![word(&SELLER), word(&wallet)], word(&[0x50]), 7),
+        event(&wallet, "ERC20Released(address,uint256)", vec![word(&TOKEN)], word(&[0x10]), 8),
+        event(&PROXY_TOKEN, transfer, vec![word(&SELLER), word(&wallet)], word(&[1]), 9),
+    ];
+    let trx = fixtures::transaction(1, &SELLER, logs, vec![]);
+
+    let streams = vesting::created_streams(&trx);
+    assert_eq!(streams, [(1, vesting::stream_key(&sablier, "1"), TOKEN.to_vec())]);
+    let stream_token = |contract: &[u8], stream_id: &str, _| match stream_id {
+        "1" => Some(TOKEN.to_vec()).filter(|_| contract == sablier),
+        _ => Some(PROXY_TOKEN.to_vec()),
+    };
+    let events = vesting::decode(&trx, |token| token == TOKEN, &[wallet.to_vec()], stream_token);
+    let kinds: Vec<i32> = events.iter().map(|event| event.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            VestingKind::StreamCreated as i32,
+            VestingKind::StreamWithdrawal as i32,
+            VestingKind::WalletFunded as i32,
+            VestingKind::WalletRelease as i32,
+        ]
+    );
+    assert!(events.iter().all(|event| event.token == TOKEN));
+    assert_eq!((events[0].stream_id.as_str(), events[0].start_time, events[0].stop_time), ("1", 100, 200));
+    assert_eq!(events[1].recipient, MINTER);
+    assert_eq!(events[2].contract, wallet);
+
+    let changes = events.iter().filter_map(vesting::locked_change);
+    let locked = changes.fold(BigInt::zero(), |locked, change| locked + change);
+    assert_eq!(locked, BigInt::from(1000 - 100 + 80 - 16));
+    let sablier_only = vesting::decode(&trx, |token| token == TOKEN, &[], stream_token);
+    assert!(sablier_only.iter().all(|event| event.contract == sablier));
+}
+
+#[test]
+fn circulating_supply_leaves_out_burned_escrowed_and_locked_tokens() {
+    let exclusions = supply::Exclusions::from_params(&Params::parse(&format!("escrows=0x{}", Hex(MINTER))));
+    assert_eq!(exclusions.kind(&supply::DEAD_ADDRESS), Some(supply::BURNED));
+    assert_eq!(exclusions.kind(&MINTER), Some(supply::ESCROWED));
+    assert_eq!(exclusions.kind(&SELLER), None);
+
+    let amount = BigInt::from;
+    let circulating = supply::circulating(&TOKEN, 100, amount(1000), amount(100), amount(200), amount(300));
+    assert_eq!((circulating.total_supply.as_str(), circulating.circulating.as_str()), ("1000", "400"));
+    let overcounted = supply::circulating(&TOKEN, 100, amount(1000), amount(1000), amount(0), amount(1));
+    assert_eq!(overcounted.circulating, "0");
+}
+
+#[test]
+fn exchange_flows_leave_out_internal_moves() {
+    use crate::pb::tokens::{NetChange, Transfer, TransferAggregate, Transfers};
+
+    let (hot, deposit) = ([1u8; 20], [2u8; 20]);
+    let params = Params::parse(&format!("exchanges=0x{}:binance,0x{}:binance", Hex(hot), Hex(deposit)));
+    let wallets = exchanges::Wallets::from_params(&params);
+    let transfer = |from: &[u8], to: &[u8], amount: &str| Transfer {
+        token: TOKEN.to_vec(),
+        from: from.to_vec(),
+        to: to.to_vec(),
+        amount: amount.to_string(),
+        ..Default::default()
+    };
+    let transfers = Transfers {
+        transfers: vec![
+            transfer(&SELLER, &deposit, "100"),
+            transfer(&deposit, &hot, "100"),
+            transfer(&hot, &MINTER, "30"),
+            transfer(&MINTER, &hot, "5"),
+        ],
+        ..Default::default()
+    };
+
+    let flows = exchanges::flows(100, &transfers, &wallets);
+    assert_eq!(flows.len(), 1);
+    assert_eq!((flows[0].exchange.as_str(), flows[0].token.as_slice()), ("binance", &TOKEN[..]));
+    assert_eq!((flows[0].inflow.as_str(), flows[0].outflow.as_str()), ("105", "30"));
+    assert_eq!((flows[0].deposits, flows[0].withdrawals), (2, 1));
+
+    // a sampled block keeps one transfer but the aggregates of all of them
+    let change = |holder: &[u8], amount: &str| NetChange { holder: holder.to_vec(), amount: amount.to_string() };
+    let sampled = Transfers {
+        transfers: vec![transfer(&hot, &MINTER, "30")],
+        aggregates: vec![TransferAggregate {
+            token: TOKEN.to_vec(),
+            changes: vec![change(&SELLER, "-100"), change(&deposit, "0"), change(&hot, "75"), change(&MINTER, "25")],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let flows = exchanges::flows(100, &sampled, &wallets);
+    assert_eq!((flows[0].inflow.as_str(), flows[0].outflow.as_str()), ("75", "0"));
+    assert_eq!((flows[0].deposits, flows[0].withdrawals), (0, 0));
+
+    let known = exchanges::Wallets::from_params(&Params::parse(""));
+    assert_eq!(known.exchange(&hex!("28c6c06298d514db089934071355e5743bf21d60")), Some("binance"));
+    let extended = exchanges::Wallets::from_params(&Params::parse(&format!("extra_exchanges=0x{}:bybit", Hex(hot))));
+    assert_eq!(extended.exchange(&hex!("28c6c06298d514db089934071355e5743bf21d60")), Some("binance"));
+    assert_eq!(extended.exchange(&hot), Some("bybit"));
+}
+
+#[test]
+fn flow_signals_on_threshold_crossings() {
+    use crate::pb::tokens::flow_signal::Direction;
+    use crate::pb::tokens::ExchangeFlow;
+
+    let params = Params::parse(&format!("thresholds=0x{}:100,0x{}:0", Hex(TOKEN), Hex(PROXY_TOKEN)));
+    let thresholds = exchanges::thresholds(&params);
+    assert_eq!(thresholds.len(), 1);
+    let threshold = &thresholds[&TOKEN.to_vec()];
+
+    let flow = ExchangeFlow {
+        token: TOKEN.to_vec(),
+        exchange: "binance".to_string(),
+        inflow: "10".to_string(),
+        outflow: "70".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(exchanges::net(&flow), BigInt::from(-60));
+
+    let window = &exchanges::WINDOWS[0];
+    assert_eq!(window.bucket(3_599), 11);
+    let prefix = exchanges::bucket_prefix(window, 11);
+    assert!(exchanges::net_flow_key(&TOKEN, "binance", window, 11).starts_with(&prefix));
+    assert!(!exchanges::net_flow_key(&TOKEN, "binance", window, 110).starts_with(&prefix));
+    let signal = |previous: i64, current: i64| {
+        exchanges::signal(&flow, window, &BigInt::from(previous), BigInt::from(current), threshold)
+    };
+    let outflow = signal(-50, -110).unwrap();
+    assert_eq!((outflow.direction, outflow.net_flow.as_str()), (Direction::Outflow as i32, "-110"));
+    assert_eq!(signal(50, 120).unwrap().direction, Direction::Inflow as i32);
+    assert!(signal(-110, -170).is_none());
+    assert!(signal(120, 60).is_none());
+}
+
+#[test]
+fn token_list_entries_follow_the_schema_patterns() {
+    let token = |name: &str, symbol: &str| crate::pb::tokens::Token {
+        address: TOKEN.to_vec(),
+        name: name.to_string(),
+        symbol: symbol.to_string(),
+        decimals: 18,
+        ..Default::default()
+    };
+    let tokens = [
+        token("Wrapped Ether", "WETH"),
+        token("Café (Old) 2.0 & Co.", "CAFE"),
+        token("Pepe 🐸", "PEPE"),
+        token("Visit example.com!", "SCAM"),
+        token("Pepe", "PE PE"),
+        token("Pepe", "PEPE;"),
+    ];
+
+    let entries = tokenlist::entries(1, &tokens);
+    assert_eq!(entries.len(), 2);
+    assert!(entries[0].contains(r#""symbol":"WETH""#));
+    assert!(entries[1].contains(r#""name":"Café (Old) 2.0 & Co.""#));
+}
@@ -0,0 +1,674 @@
+use std::collections::BTreeMap;
+
+use hex_literal::hex;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::store::{DeltaInt64, Deltas};
+use substreams::Hex;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::NULL_ADDRESS;
+
+use super::fixtures::{self, word, BUYER, MINTER, SELLER};
+use crate::abi;
+use crate::activity::{self, Write};
+use crate::assets;
+use crate::audit;
+use crate::columnar;
+use crate::crypto;
+use crate::erc20;
+use crate::eth_utils;
+use crate::fees;
+use crate::metadata;
+use crate::heartbeat;
+use crate::mints;
+use crate::params::Params;
+use crate::portfolio;
+use crate::projection;
+use crate::selectors;
+use crate::rpc_utils::{self, Recorded, RpcCounters};
+use crate::pb::{self, erc721};
+use crate::pb::erc721::mint_purchase::Phase;
+use crate::pb::portfolio::asset_transfer::Standard;
+use crate::transfers;
+use crate::{block_transfers, failed_transfer_attempts, generate_key, holder_deltas, TRACKED_CONTRACT};
+
+#[test]
+fn mints_of_successful_transactions_only() {
+    let transfers = block_transfers(&fixtures::mint_heavy());
+
+    assert_eq!(transfers.len(), 30);
+    assert!(transfers
+        .iter()
+        .all(|transfer| transfer.from == NULL_ADDRESS && transfer.to == MINTER));
+    assert!(transfers.iter().all(|transfer| transfer.trx_hash == vec![1u8; 32]));
+    assert_eq!(
+        transfers.iter().map(|transfer| transfer.token_id).collect::<Vec<_>>(),
+        (100..130).collect::<Vec<_>>()
+    );
+    assert_eq!(transfers[0].timestamp.as_ref().unwrap().seconds, fixtures::TIMESTAMP);
+}
+
+#[test]
+fn columns_list_every_contract_and_transaction_once() {
+    let transfers = block_transfers(&fixtures::mint_heavy());
+    let columns = columnar::transfers(erc721::Transfers { transfers });
+
+    assert_eq!(columns.contracts, [TRACKED_CONTRACT.to_vec()]);
+    assert_eq!(columns.trx_hashes, [vec![1u8; 32]]);
+    assert_eq!(columns.token_id.len(), 30);
+    assert!(columns.contract_index.iter().chain(&columns.trx_index).all(|index| *index == 0));
+}
+
+#[test]
+fn mints_only_credit_the_receiver() {
+    let transfers = erc721::Transfers {
+        transfers: block_transfers(&fixtures::mint_heavy()),
+    };
+    let deltas = holder_deltas(&transfers);
+
+    let key = format!("total:{}:{}", Hex(MINTER), Hex(TRACKED_CONTRACT));
+    assert_eq!(deltas.len(), 30);
+    assert!(deltas.iter().all(|(_, holder, delta)| *holder == key && *delta == 1));
+}
+
+#[test]
+fn transfers_move_both_holders() {
+    let transfers = erc721::Transfers {
+        transfers: block_transfers(&fixtures::marketplace_sale()),
+    };
+    let deltas = holder_deltas(&transfers);
+
+    assert_eq!(
+        deltas,
+        vec![
+            (0, format!("total:{}:{}", Hex(SELLER), Hex(TRACKED_CONTRACT)), -1),
+            (
+                0,
+                format!("total:{}:{}", Hex(fixtures::BUYER), Hex(TRACKED_CONTRACT)),
+                1
+            ),
+        ]
+    );
+}
+
+#[test]
+fn token_ids_out_of_the_u64_range_are_skipped() {
+    let mut block = fixtures::mint_heavy();
+    let logs = &mut block.transaction_traces[0].receipt.as_mut().unwrap().logs;
+    // hash based ids, as ENS names use, used to abort the whole module
+    logs[0].topics[3] = vec![0xff; 32];
+
+    let transfers = block_transfers(&block);
+    assert_eq!(transfers.len(), 29);
+    assert_eq!(transfers[0].token_id, 101);
+}
+
+#[test]
+fn blocks_without_the_contract_in_their_bloom_are_skipped() {
+    let mut block = fixtures::mint_heavy();
+    block.header.as_mut().unwrap().logs_bloom = fixtures::bloom(&[&eth_utils::TRANSFER_TOPIC]);
+    assert!(block_transfers(&block).is_empty());
+
+    block.header.as_mut().unwrap().logs_bloom = fixtures::bloom(&[&TRACKED_CONTRACT, &eth_utils::TRANSFER_TOPIC]);
+    assert_eq!(block_transfers(&block).len(), 30);
+}
+
+#[test]
+fn transactions_without_any_fungible_topic_in_their_bloom_are_skipped() {
+    let mut block = fixtures::mint_heavy();
+    let topics: Vec<&[u8]> = erc20::TOPICS.iter().map(|topic| topic.as_slice()).collect();
+    let receipt = block.transaction_traces[0].receipt.as_mut().unwrap();
+    receipt.logs_bloom = fixtures::bloom(&[&MINTER]);
+    assert_eq!(eth_utils::transactions_matching_any(&block, &topics).count(), 0);
+
+    // an ERC777 Minted topic is enough for the transaction to be decoded
+    let receipt = block.transaction_traces[0].receipt.as_mut().unwrap();
+    receipt.logs_bloom = fixtures::bloom(&[&MINTER, &erc20::TOPICS[2]]);
+    assert_eq!(eth_utils::transactions_matching_any(&block, &topics).count(), 1);
+}
+
+#[test]
+fn transactions_without_the_contract_in_their_bloom_are_skipped() {
+    let mut block = fixtures::mint_heavy();
+    let receipt = block.transaction_traces[0].receipt.as_mut().unwrap();
+    receipt.logs_bloom = fixtures::bloom(&[&MINTER]);
+    assert!(block_transfers(&block).is_empty());
+
+    let receipt = block.transaction_traces[0].receipt.as_mut().unwrap();
+    receipt.logs_bloom = fixtures::bloom(&[&MINTER, &TRACKED_CONTRACT, &eth_utils::TRANSFER_TOPIC]);
+    assert_eq!(block_transfers(&block).len(), 30);
+}
+
+#[test]
+fn starts_a_new_generation_of_recent_activity_every_size_entries() {
+    let transfer = |from: &[u8], to: &[u8], ordinal| erc721::Transfer {
+        contract: TRACKED_CONTRACT.to_vec(),
+        from: from.to_vec(),
+        to: to.to_vec(),
+        token_id: 7,
+        ordinal,
+        ..Default::default()
+    };
+    let transfers = [transfer(&SELLER, &BUYER, 1), transfer(&BUYER, &SELLER, 2), transfer(&SELLER, &BUYER, 3)];
+    let token = format!("nft:7:{}", Hex(TRACKED_CONTRACT));
+    // 18 transfers of the token before the block, the holders only got it in the block
+    let counts = |subject: &str| if subject == token { 21 } else { 3 };
+
+    let writes = activity::writes(100, &transfers, counts, 20);
+    let appended: Vec<(&str, &str)> = writes
+        .iter()
+        .filter_map(|write| match write {
+            Write::Append { key, entry, .. } => Some((key.as_str(), entry.as_str())),
+            Write::Expire { .. } => None,
+        })
+        .collect();
+    let token_keys: Vec<&str> =
+        appended.iter().map(|(key, _)| *key).filter(|key| key.starts_with("recent.v2:nft:")).collect();
+    let generation = |generation: u64| format!("recent.v2:{}:{:020}", token, generation);
+    assert_eq!(token_keys, [generation(0), generation(0), generation(1)]);
+    let holder_key = format!("recent.v2:holder:{}:{:020}", Hex(SELLER), 0);
+    assert!(appended.iter().any(|(key, _)| *key == holder_key));
+    assert!(!appended[0].1.contains(';'));
+    assert_eq!(appended.len(), writes.len());
+}
+
+#[test]
+fn drops_the_generation_before_the_previous_one() {
+    let transfer = erc721::Transfer {
+        contract: TRACKED_CONTRACT.to_vec(),
+        from: SELLER.to_vec(),
+        to: BUYER.to_vec(),
+        token_id: 7,
+        ordinal: 4,
+        ..Default::default()
+    };
+    let token = format!("nft:7:{}", Hex(TRACKED_CONTRACT));
+    // the 41st transfer of the token starts generation 2, generation 1 stays readable
+    let counts = |subject: &str| if subject == token { 41 } else { 1 };
+
+    let writes = activity::writes(100, &[transfer], counts, 20);
+    let key = |generation: u64| format!("recent.v2:{}:{:020}", token, generation);
+    assert_eq!(writes[0], Write::Expire { ordinal: 4, key: key(0) });
+    assert!(matches!(&writes[1], Write::Append { key: appended, .. } if *appended == key(2)));
+}
+
+#[test]
+fn expires_generations_after_the_entries_of_the_block_they_hold() {
+    let transfers: Vec<erc721::Transfer> = (1..=5)
+        .map(|ordinal| erc721::Transfer {
+            contract: TRACKED_CONTRACT.to_vec(),
+            from: SELLER.to_vec(),
+            to: BUYER.to_vec(),
+            token_id: ordinal,
+            ordinal,
+            ..Default::default()
+        })
+        .collect();
+    let buyer = format!("holder:{}", Hex(BUYER));
+    // the buyer gets its first 5 transfers in the block, more than two generations of 2 entries
+    let counts = |subject: &str| if subject == buyer { 5 } else { 1 };
+
+    let key = |generation: u64| format!("recent.v2:{}:{:020}", buyer, generation);
+    let buyer_writes: Vec<(&str, u64, String)> = activity::writes(100, &transfers, counts, 2)
+        .into_iter()
+        .map(|write| match write {
+            Write::Expire { ordinal, key } => ("expire", ordinal, key),
+            Write::Append { ordinal, key, .. } => ("append", ordinal, key),
+        })
+        .filter(|(_, _, written)| written.starts_with(&format!("recent.v2:{}:", buyer)))
+        .collect();
+    assert_eq!(
+        buyer_writes,
+        [
+            ("append", 1, key(0)),
+            ("append", 2, key(0)),
+            ("append", 3, key(1)),
+            ("append", 4, key(1)),
+            ("expire", 5, key(0)),
+            ("append", 5, key(2)),
+        ]
+    );
+}
+
+#[test]
+fn negative_balances_are_clamped_and_reported_once() {
+    let delta = |old_value, new_value, ordinal| DeltaInt64 {
+        operation: Operation::Update,
+        ordinal,
+        key: generate_key(&SELLER.to_vec()),
+        old_value,
+        new_value,
+    };
+    // the seller got the token before the start block, then sends it twice
+    let deltas = || Deltas { deltas: vec![delta(0, -1, 1), delta(-1, -2, 2)] };
+
+    let anomalies = portfolio::anomalies(deltas(), Deltas { deltas: vec![] }, Deltas { deltas: vec![] });
+    assert_eq!(anomalies.anomalies.len(), 1);
+    assert_eq!(anomalies.anomalies[0].holder, SELLER);
+    assert_eq!(anomalies.anomalies[0].contract, TRACKED_CONTRACT);
+    assert_eq!(anomalies.anomalies[0].previous_balance, "0");
+    assert_eq!(anomalies.anomalies[0].balance, "-1");
+
+    let portfolios =
+        portfolio::build(deltas(), Deltas { deltas: vec![] }, Deltas { deltas: vec![] }, BTreeMap::new(), |_| None);
+    let holding = &portfolios.portfolios[0].holdings[0];
+    assert_eq!(holding.balance, "0");
+    assert!(holding.clamped);
+}
+
+#[test]
+fn holder_audit_recount_matches_incremental_count() {
+    let balances = BTreeMap::from([(SELLER.to_vec(), 0), (BUYER.to_vec(), 2), (MINTER.to_vec(), -1)]);
+    let steps = [(0, 1), (1, 2), (2, 1), (1, 0), (0, 2), (0, -1)];
+    let incremental: i64 = steps.iter().map(|(old, new)| audit::holder_count_change(*old, *new)).sum();
+
+    // the owners of the tokens, one of them burnt, recount the holders without the balances
+    let owners: [&[u8]; 3] = [&BUYER, &BUYER, &NULL_ADDRESS];
+    let holders: Vec<String> = [SELLER, BUYER, MINTER].iter().map(|holder| Hex(holder).to_string()).collect();
+    let recount = audit::recount(owners, &holders, |holder| balances[holder]);
+    assert_eq!(recount.holders, 1);
+    assert_eq!(recount.negative_balances, 1);
+    assert_eq!(recount.mismatched_balances, 1);
+    assert_eq!(incremental, recount.holders);
+}
+
+#[test]
+fn holder_audit_catches_balances_the_owners_do_not_back() {
+    // store_transfers lost BUYER's second token and never credited MINTER, whose token it does not know about
+    let balances = BTreeMap::from([(SELLER.to_vec(), 0), (BUYER.to_vec(), 1)]);
+    let owners: [&[u8]; 3] = [&BUYER, &BUYER, &MINTER];
+    let holders: Vec<String> = [SELLER, BUYER].iter().map(|holder| Hex(holder).to_string()).collect();
+    let recount = audit::recount(owners, &holders, |holder| balances.get(holder).copied().unwrap_or(0));
+    assert_eq!(
+        recount,
+        audit::Recount {
+            holders: 2,
+            negative_balances: 0,
+            mismatched_balances: 2,
+        }
+    );
+}
+
+#[test]
+fn holder_audit_reads_every_recorded_token_id() {
+    // ids past the 10000 tokens the audit used to stop at, one of them burnt, and one owner the store lost
+    let owned = BTreeMap::from([
+        (7, BUYER.to_vec()),
+        (10000, SELLER.to_vec()),
+        (25000, BUYER.to_vec()),
+        (31337, NULL_ADDRESS.to_vec()),
+    ]);
+    let token_ids: Vec<String> = ["7", "10000", "25000", "31337", "40000"].iter().map(|id| id.to_string()).collect();
+    let owners = audit::owners(&token_ids, |token_id| owned.get(&token_id).cloned());
+    assert_eq!(owners.len(), 4);
+
+    let holders: Vec<String> = [SELLER, BUYER].iter().map(|holder| Hex(holder).to_string()).collect();
+    let balances = BTreeMap::from([(SELLER.to_vec(), 1), (BUYER.to_vec(), 2)]);
+    let recount = audit::recount(owners.iter().map(Vec::as_slice), &holders, |holder| balances[holder]);
+    assert_eq!(
+        recount,
+        audit::Recount {
+            holders: 2,
+            negative_balances: 0,
+            mismatched_balances: 0,
+        }
+    );
+}
+
+#[test]
+fn safe_transfers_carry_their_data_payload() {
+    let safe_transfer = abi::erc721::functions::SafeTransferFrom2 {
+        from: SELLER.to_vec(),
+        to: BUYER.to_vec(),
+        token_id: 1234.into(),
+        data: b"list:1 ETH".to_vec(),
+    };
+    let call = |input: Vec<u8>, log| eth::Call {
+        address: TRACKED_CONTRACT.to_vec(),
+        input,
+        logs: vec![log],
+        ..Default::default()
+    };
+    let logs = vec![fixtures::transfer_log(&SELLER, &BUYER, 1234, 0), fixtures::transfer_log(&BUYER, &SELLER, 1234, 1)];
+    let calls = vec![call(safe_transfer.encode(), logs[0].clone()), call(hex!("23b872dd").to_vec(), logs[1].clone())];
+    let block = fixtures::block(14_700_000, vec![fixtures::transaction(1, &SELLER, logs, calls)]);
+
+    let transfers = block_transfers(&block);
+    assert_eq!(transfers[0].data, b"list:1 ETH");
+    assert!(transfers[1].data.is_empty());
+}
+
+#[test]
+fn safe_transfers_rejected_by_the_receiver_hook_are_reported() {
+    let safe_transfer = abi::erc721::functions::SafeTransferFrom1 {
+        from: SELLER.to_vec(),
+        to: BUYER.to_vec(),
+        token_id: 1234.into(),
+    };
+    let mut hook_input = transfers::ERC721_RECEIVED.to_vec();
+    for word in [&SELLER[..], &SELLER[..], &[0x04, 0xd2][..]] {
+        hook_input.extend_from_slice(&[0u8; 32][word.len()..]);
+        hook_input.extend_from_slice(word);
+    }
+    let calls = |hook_reverts: bool| {
+        vec![
+            eth::Call {
+                index: 1,
+                address: TRACKED_CONTRACT.to_vec(),
+                caller: SELLER.to_vec(),
+                input: safe_transfer.encode(),
+                state_reverted: hook_reverts,
+                ..Default::default()
+            },
+            eth::Call {
+                index: 2,
+                parent_index: 1,
+                depth: 1,
+                address: BUYER.to_vec(),
+                caller: TRACKED_CONTRACT.to_vec(),
+                input: hook_input.clone(),
+                return_data: if hook_reverts { vec![] } else { transfers::ERC721_RECEIVED.to_vec() },
+                status_failed: hook_reverts,
+                failure_reason: if hook_reverts { "execution reverted".to_string() } else { String::new() },
+                state_reverted: hook_reverts,
+                ..Default::default()
+            },
+        ]
+    };
+
+    let accepted = fixtures::transaction(1, &SELLER, vec![], calls(false));
+    assert!(transfers::failed_hooks(&accepted, &TRACKED_CONTRACT).is_empty());
+
+    let rejected = fixtures::transaction(2, &SELLER, vec![], calls(true));
+    let failed = transfers::failed_hooks(&rejected, &TRACKED_CONTRACT);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].safe_transfer.caller, SELLER);
+    assert_eq!(failed[0].hook.address, BUYER);
+    assert_eq!(failed[0].from, SELLER);
+    assert_eq!(failed[0].token_id.to_u64(), 1234);
+    assert_eq!(failed[0].reason, "execution reverted");
+    // the attempt is keyed by the index of the hook call in the transaction
+    assert_eq!(failed_transfer_attempts(&rejected)[0].id, format!("0x{}-call-2", "02".repeat(32)));
+}
+
+#[test]
+fn mints_are_grouped_by_the_call_that_paid_for_them() {
+    let call = |selector: [u8; 4], value: u128, begin_ordinal, end_ordinal| eth::Call {
+        address: TRACKED_CONTRACT.to_vec(),
+        caller: MINTER.to_vec(),
+        input: selector.to_vec(),
+        value: Some(eth::BigInt { bytes: value.to_be_bytes().to_vec() }),
+        begin_ordinal,
+        end_ordinal,
+        ..Default::default()
+    };
+    let mut trx = fixtures::mint_heavy().transaction_traces.remove(0);
+    trx.calls = vec![
+        // mint(uint256) of 10 tokens at 0.08 ETH
+        call(hex!("a0712d68"), 800_000_000_000_000_000, 0, 11),
+        // allowlistMint(uint256,bytes32[]) of 20 free tokens, minting through a call to itself
+        call(hex!("7bc9200e"), 0, 11, 31),
+        call(hex!("40c10f19"), 0, 12, 30),
+    ];
+
+    let purchases = mints::purchases(&trx, &TRACKED_CONTRACT, &[]);
+    assert_eq!(purchases.len(), 2);
+    assert_eq!(purchases[1].id, format!("0x{}-call-0", Hex(&trx.hash)));
+    assert_eq!(purchases[0].method, "mint(uint256)");
+    assert_eq!(purchases[0].phase, Phase::Public as i32);
+    assert_eq!(purchases[0].quantity, 10);
+    assert_eq!(purchases[0].token_ids[0], 100);
+    assert_eq!(purchases[0].paid, "800000000000000000");
+    assert_eq!(purchases[0].unit_price, "80000000000000000");
+    assert_eq!(purchases[1].phase, Phase::Allowlist as i32);
+    assert_eq!(purchases[1].quantity, 20);
+    assert_eq!(purchases[1].unit_price, "0");
+
+    assert_eq!(mints::phase(&hex!("a0712d68"), &["a0712d68"]), Phase::Allowlist);
+    assert_eq!(mints::phase(&hex!("deadbeef"), &[]), Phase::Unspecified);
+    // BAYC's own mintApe(uint256)
+    assert_eq!(mints::phase(&hex!("a723533e"), &[]), Phase::Public);
+}
+
+#[test]
+fn selector_table_is_searched_by_selector() {
+    let table = include_str!("../../abi/selectors.txt");
+    let entries: Vec<_> = table.lines().filter(|line| !line.starts_with('#')).collect();
+    assert!(entries.len() > 2_000);
+    // every line resolves to its own signature, which hashes to the selector it is listed under
+    for line in entries {
+        let (selector, signature) = line.split_once(' ').unwrap();
+        assert_eq!(hex::encode(&crypto::keccak256(signature.as_bytes())[..4]), selector, "{}", signature);
+        assert_eq!(selectors::signature(&hex::decode(selector).unwrap()), Some(signature));
+    }
+
+    assert_eq!(selectors::describe(&hex!("a9059cbb")), "transfer(address,uint256)");
+    assert_eq!(selectors::describe(&hex!("ffffffff")), "0xffffffff");
+    assert_eq!(selectors::describe(&hex!("a905")), "fallback");
+}
+
+#[test]
+fn asset_transfers_merge_standards_in_execution_order() {
+    let erc1155 = [7u8; 20];
+    let transfer_single = eth::Log {
+        address: erc1155.to_vec(),
+        topics: vec![
+            crypto::keccak256(b"TransferSingle(address,address,address,uint256,uint256)").to_vec(),
+            word(&SELLER),
+            word(&SELLER),
+            word(&BUYER),
+        ],
+        data: [word(&[9]), word(&[3])].concat(),
+        ordinal: 5,
+        ..Default::default()
+    };
+    let call = |call_type: eth::CallType, begin_ordinal| eth::Call {
+        call_type: call_type as i32,
+        caller: BUYER.to_vec(),
+        address: SELLER.to_vec(),
+        value: Some(eth::BigInt { bytes: vec![1, 0] }),
+        begin_ordinal,
+        ..Default::default()
+    };
+    let logs = vec![fixtures::transfer_log(&SELLER, &BUYER, 1234, 2), transfer_single];
+    let calls = vec![call(eth::CallType::Call, 0), call(eth::CallType::Delegate, 1)];
+    let trx = fixtures::transaction(1, &BUYER, logs, calls);
+
+    let transfers = assets::transfers(&trx, &TRACKED_CONTRACT, &[erc1155.to_vec()], &erc20::topic_bits());
+    let standards: Vec<i32> = transfers.iter().map(|transfer| transfer.standard).collect();
+    assert_eq!(standards, [Standard::Native as i32, Standard::Erc721 as i32, Standard::Erc1155 as i32]);
+    assert_eq!(transfers[0].amount, "256");
+    assert!(transfers[0].contract.is_empty());
+    assert_eq!((transfers[1].token_id.as_str(), transfers[1].amount.as_str()), ("1234", "1"));
+    assert_eq!((transfers[2].token_id.as_str(), transfers[2].amount.as_str()), ("9", "3"));
+    assert_eq!(transfers[2].to, BUYER);
+    // ETH moves are keyed by their call, token moves by their log in the block and the token id
+    let ids: Vec<&str> = transfers.iter().map(|transfer| transfer.id.as_str()).collect();
+    let id = |suffix: &str| format!("0x{}-{}", "01".repeat(32), suffix);
+    assert_eq!(ids, [id("call-0"), id("2-1234"), id("0-9")]);
+}
+
+#[test]
+fn projection_keeps_only_the_listed_fields() {
+    let transfer = || pb::portfolio::AssetTransfer {
+        standard: Standard::Erc721 as i32,
+        contract: TRACKED_CONTRACT.to_vec(),
+        token_id: "1234".to_string(),
+        from: SELLER.to_vec(),
+        to: BUYER.to_vec(),
+        trx_hash: vec![1; 32],
+        ..Default::default()
+    };
+
+    let mut transfers = vec![transfer()];
+    projection::project(&Params::parse("fields=trx_hash,from,to,unknown"), &mut transfers);
+    let expected = pb::portfolio::AssetTransfer {
+        from: SELLER.to_vec(),
+        to: BUYER.to_vec(),
+        trx_hash: vec![1; 32],
+        ..Default::default()
+    };
+    assert_eq!(transfers, [expected]);
+
+    let mut transfers = vec![transfer()];
+    projection::project(&Params::parse(""), &mut transfers);
+    assert_eq!(transfers, [transfer()]);
+}
+
+#[test]
+fn block_range_params_bound_exports() {
+    let range = Params::parse("start_block=100&stop_block=200").block_range();
+    assert!(!range.contains(99));
+    assert!(range.contains(100) && range.contains(199));
+    assert!(!range.contains(200));
+    assert!(range.ends_at(199));
+    assert!(!range.ends_at(200));
+
+    let open = Params::parse("start_block=&stop_block=").block_range();
+    assert!(open.contains(0) && open.contains(u64::MAX));
+    assert!(!open.ends_at(u64::MAX - 1));
+}
+
+#[test]
+fn transfer_ids_are_unique_and_stable() {
+    let ids = |block| block_transfers(block).into_iter().map(|transfer| transfer.id).collect::<Vec<_>>();
+    let block = fixtures::mint_heavy();
+    let mut unique = ids(&block);
+    unique.sort();
+    unique.dedup();
+
+    assert_eq!(unique.len(), 30);
+    assert_eq!(ids(&block), ids(&block));
+    assert_eq!(ids(&block)[0], format!("0x{}-0", "01".repeat(32)));
+}
+
+#[test]
+fn dormant_addresses_are_reported_once_per_dormancy() {
+    let erc721_transfers = erc721::Transfers {
+        transfers: vec![
+            erc721::Transfer {
+                from: NULL_ADDRESS.to_vec(),
+                to: MINTER.to_vec(),
+                ..Default::default()
+            },
+            erc721::Transfer {
+                from: SELLER.to_vec(),
+                to: BUYER.to_vec(),
+                ..Default::default()
+            },
+        ],
+    };
+    let erc20_transfers = pb::tokens::Transfers {
+        transfers: vec![pb::tokens::Transfer {
+            from: BUYER.to_vec(),
+            to: MINTER.to_vec(),
+            amount: "1".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let senders = heartbeat::senders(&erc721_transfers, &erc20_transfers);
+    assert_eq!(senders.len(), 2);
+    assert!(senders.contains(&SELLER.to_vec()) && senders.contains(&BUYER.to_vec()));
+
+    let dormancy = heartbeat::Dormancy::from_params(&Params::parse("dormancy=1000&every=100&min_balance=5"));
+    assert_eq!(dormancy.report_period(1_550), None);
+    // addresses last active in blocks 500 to 599 went dormant by block 1_600
+    assert_eq!(dormancy.report_period(1_600), Some(5));
+    assert_eq!(dormancy.expired_period(1_600), Some(4));
+    assert_eq!(heartbeat::period_key(5), "active:00000000000000000005");
+    let holders = heartbeat::holder_senders(&erc721_transfers, &erc20_transfers, 5, |sender| {
+        if sender == SELLER { 5 } else { 4 }
+    });
+    assert_eq!(holders.into_iter().collect::<Vec<_>>(), [SELLER.to_vec()]);
+    assert_eq!(dormancy.report_period(500), None);
+
+    let dormant = dormancy.dormant(&SELLER, 1_600, 540, 8).unwrap();
+    assert_eq!((dormant.inactive_blocks, dormant.balance), (1_060, 8));
+    assert!(dormancy.dormant(&SELLER, 1_600, 600, 8).is_none());
+    assert!(dormancy.dormant(&SELLER, 1_600, 540, 4).is_none());
+}
+
+#[test]
+fn on_chain_token_metadata_is_decoded() {
+    // {"name":"Punk #1","description":"On-chain","image":"data:image/svg+xml;base64,...","attributes":[...]}
+    let token_uri = concat!(
+        "data:application/json;base64,eyJuYW1lIjoiUHVuayAjMSIsImRlc2NyaXB0aW9uIjoiT24tY2hhaW4iLCJpbWFnZSI6ImR",
+        "hdGE6aW1hZ2Uvc3ZnK3htbDtiYXNlNjQsUEhOMlp5QjRiV3h1Y3owaWFIUjBjRG92TDNkM2R5NTNNeTV2Y21jdk1qQXdNQzl6ZG1",
+        "jaVBqeHlaV04wSUdacGJHdzlJbkpsWkNJdlBqd3ZjM1puUGc9PSIsImF0dHJpYnV0ZXMiOlt7InRyYWl0X3R5cGUiOiJIYXQiLCJ",
+        "2YWx1ZSI6IkNhcCJ9LHsidHJhaXRfdHlwZSI6IkxldmVsIiwidmFsdWUiOjN9LHsidmFsdWUiOiJSYXJlIn1dfQ==",
+    );
+    let token = metadata::token_metadata(token_uri).unwrap();
+    assert_eq!((token.name.as_str(), token.description.as_str()), ("Punk #1", "On-chain"));
+    assert_eq!(token.image_svg, r#"<svg xmlns="http://www.w3.org/2000/svg"><rect fill="red"/></svg>"#);
+    assert!(token.image.is_empty());
+    let traits: Vec<(&str, &str)> =
+        token.attributes.iter().map(|attribute| (attribute.trait_type.as_str(), attribute.value.as_str())).collect();
+    assert_eq!(traits, [("Hat", "Cap"), ("Level", "3"), ("", "Rare")]);
+
+    let plain = metadata::token_metadata(r#"data:application/json,{"name":"Plain","image":"ipfs://image"}"#).unwrap();
+    assert_eq!((plain.name.as_str(), plain.image.as_str()), ("Plain", "ipfs://image"));
+    assert!(metadata::token_metadata("ipfs://metadata/1").is_none());
+}
+
+#[test]
+fn only_top_level_metadata_keys_are_read() {
+    // the description reads like a key, a trait and a nested object hold keys of their own
+    let json = concat!(
+        r#"{"description":"name","attributes":[{"trait_type":"name","value":"Hat"},"#,
+        r#"{"meta":{"value":"nested"},"value":3,"trait_type":"Level"}],"#,
+        r#""properties":{"name":"Nested"},"name":"Punk \ud83d\ude00 \u00e9"}"#,
+    );
+    let token = metadata::token_metadata(&format!("data:application/json,{}", json)).unwrap();
+    assert_eq!(token.name, "Punk 😀 é");
+    assert_eq!(token.description, "name");
+    let traits: Vec<(&str, &str)> =
+        token.attributes.iter().map(|attribute| (attribute.trait_type.as_str(), attribute.value.as_str())).collect();
+    assert_eq!(traits, [("name", "Hat"), ("Level", "3")]);
+
+    let contract_uri = r#"data:application/json,{"collection":{"image":"ipfs://nested"},"image":"ipfs://logo"}"#;
+    assert_eq!(metadata::logo_uri(contract_uri).as_deref(), Some("ipfs://logo"));
+    // a lone surrogate is no character
+    assert_eq!(metadata::token_metadata(r#"data:application/json,{"name":"\ud83d"}"#).unwrap().name, "");
+}
+
+#[test]
+fn token_uris_of_a_block_are_fetched_together() {
+    let call = |token_id: u8| format!("{}{:064x}", rpc_utils::TOKEN_URI, token_id);
+    let client = Recorded::default()
+        .respond(&TRACKED_CONTRACT, &call(1), fixtures::string_return("ipfs://metadata/1"))
+        .revert(&TRACKED_CONTRACT, &call(2));
+
+    let mut rpc = RpcCounters::default();
+    let tokens = [(TRACKED_CONTRACT.to_vec(), 1), (TRACKED_CONTRACT.to_vec(), 2)];
+    let uris = rpc_utils::token_uris(&client, &tokens, &mut rpc);
+    assert_eq!(uris, [Some("ipfs://metadata/1".to_string()), None]);
+    assert_eq!((rpc.calls, rpc.failures), (2, 1));
+}
+
+#[test]
+fn reads_the_blob_gas_eth_block_does_not_decode() {
+    use prost::Message;
+    use crate::pb::blocks::{BigInt, BlobBlock, BlobHeader, BlobReceipt, BlobTrace};
+
+    // the fields BlobBlock shares with eth::Block have the same wire types, so older blocks decode as empty blob gas
+    let older = BlobBlock::decode(fixtures::mint_heavy().encode_to_vec().as_slice()).unwrap();
+    assert_eq!(older.transaction_traces.len(), 2);
+    assert_eq!(fees::blob_gas(&older), pb::blocks::BlobGas::default());
+
+    let receipt = |blob_gas_price: Option<u64>| BlobTrace {
+        receipt: Some(BlobReceipt {
+            blob_gas_used: blob_gas_price.map(|_| 3 << 17),
+            blob_gas_price: blob_gas_price.map(|price| BigInt { bytes: price.to_be_bytes().to_vec() }),
+        }),
+    };
+    let block = BlobBlock {
+        header: Some(BlobHeader {
+            blob_gas_used: Some(6 << 17),
+            excess_blob_gas: Some(4 << 17),
+        }),
+        transaction_traces: vec![receipt(None), receipt(Some(1_000_000_000)), receipt(Some(1_000_000_000))],
+    };
+    let blob_gas = fees::blob_gas(&block);
+    assert_eq!((blob_gas.blob_gas_used, blob_gas.blob_count, blob_gas.excess_blob_gas), (786_432, 6, 524_288));
+    assert_eq!(blob_gas.blob_base_fee, "1000000000");
+}
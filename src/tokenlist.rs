@@ -0,0 +1,89 @@
+use crate::crypto;
+use crate::eth_utils;
+use crate::params::Params;
+use crate::pb::tokens::Token;
+
+/// Largest number of tokens the token list schema allows in a list.
+pub const MAX_TOKENS: usize = 10000;
+
+pub fn chain_id(params: &Params) -> u64 {
+    params.get("chain_id").and_then(|value| value.parse().ok()).unwrap_or(1)
+}
+
+/// Token list entry of the token, None when its metadata breaks the token list schema limits.
+pub fn entry(chain_id: u64, token: &Token) -> Option<String> {
+    let name = token.name.trim();
+    let symbol = token.symbol.trim();
+    if name.is_empty() || name.chars().count() > 40 || symbol.is_empty() || symbol.chars().count() > 20 {
+        return None;
+    }
+    // the schema patterns: `^\S+$` for symbols, word characters, latin letters and a few punctuation signs for names
+    if symbol.contains(char::is_whitespace) || !name.chars().all(name_char) || token.decimals > 255 {
+        return None;
+    }
+    // the separator of store_token_list entries
+    if symbol.contains(';') {
+        return None;
+    }
+
+    Some(format!(
+        r#"{{"chainId":{},"address":"{}","decimals":{},"symbol":{},"name":{}}}"#,
+        chain_id,
+        crypto::checksum_address(&token.address),
+        token.decimals,
+        eth_utils::json_string(symbol),
+        eth_utils::json_string(name),
+    ))
+}
+
+/// Whether the schema pattern of token names, `^[ \w.'+\-%/À-ÖØ-öø-ÿ:&\[\]\(\)]+$`, allows the character.
+fn name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric()
+        || " _.'+-%/:&[]()".contains(c)
+        || ('\u{c0}'..='\u{d6}').contains(&c)
+        || ('\u{d8}'..='\u{f6}').contains(&c)
+        || ('\u{f8}'..='\u{ff}').contains(&c)
+}
+
+/// Token list entries of the tokens, in order, leaving out the ones whose metadata breaks the schema.
+pub fn entries<'a>(chain_id: u64, tokens: impl IntoIterator<Item = &'a Token>) -> Vec<String> {
+    tokens.into_iter().filter_map(|token| entry(chain_id, token)).collect()
+}
+
+/// Token list document holding `entries`, its minor version counts the tokens as every release only adds some.
+pub fn render(name: &str, timestamp: i64, entries: &[String]) -> String {
+    format!(
+        r#"{{"name":{},"timestamp":"{}","version":{{"major":1,"minor":{},"patch":0}},"tokens":[{}]}}"#,
+        eth_utils::json_string(name),
+        iso8601(timestamp),
+        entries.len(),
+        entries.join(","),
+    )
+}
+
+/// UTC ISO 8601 rendering of a unix timestamp.
+fn iso8601(timestamp: i64) -> String {
+    let days = timestamp.div_euclid(86400);
+    let seconds = timestamp.rem_euclid(86400);
+
+    // civil date from days since the epoch, after Howard Hinnant's `civil_from_days`
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
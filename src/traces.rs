@@ -0,0 +1,52 @@
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+
+use crate::pb::traces::{CallEdge, CallGraph};
+
+fn call_type(call: &eth::Call) -> &'static str {
+    match eth::CallType::from_i32(call.call_type) {
+        Some(eth::CallType::Call) => "call",
+        Some(eth::CallType::Callcode) => "callcode",
+        Some(eth::CallType::Delegate) => "delegatecall",
+        Some(eth::CallType::Static) => "staticcall",
+        Some(eth::CallType::Create) => "create",
+        _ => "unspecified",
+    }
+}
+
+fn node(nodes: &mut Vec<Vec<u8>>, address: &[u8]) -> u32 {
+    match nodes.iter().position(|node| node == address) {
+        Some(position) => position as u32,
+        None => {
+            nodes.push(address.to_vec());
+            (nodes.len() - 1) as u32
+        }
+    }
+}
+
+/// Call graph of the transaction, one edge per call in execution order.
+pub fn call_graph(trx: &eth::TransactionTrace) -> CallGraph {
+    let mut nodes = vec![];
+    let mut edges = vec![];
+    for call in &trx.calls {
+        let value = match &call.value {
+            Some(value) => BigInt::from_unsigned_bytes_be(&value.bytes),
+            None => BigInt::zero(),
+        };
+
+        edges.push(CallEdge {
+            from: node(&mut nodes, &call.caller),
+            to: node(&mut nodes, &call.address),
+            value: if value.is_zero() { String::new() } else { value.to_string() },
+            call_type: call_type(call).to_string(),
+            depth: call.depth,
+            reverted: call.state_reverted,
+        });
+    }
+
+    CallGraph {
+        trx_hash: trx.hash.clone(),
+        nodes,
+        edges,
+    }
+}
@@ -0,0 +1,147 @@
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::{Event, Function, NULL_ADDRESS};
+
+use crate::abi;
+use crate::eth_utils;
+
+// CryptoKitties Core
+pub const CRYPTOKITTIES: [u8; 20] = hex!("06012c8cf97bead5deae237070f9587f8e7a266d");
+
+// onERC721Received(address,address,uint256,bytes), also the value receivers return to accept a token
+pub const ERC721_RECEIVED: [u8; 4] = hex!("150b7a02");
+
+pub struct Transfer {
+    pub from: Vec<u8>,
+    pub to: Vec<u8>,
+    pub token_id: BigInt,
+}
+
+/// Decodes a `Transfer(address,address,uint256)` log whatever parameters are indexed. Standard
+/// ERC721 contracts index all three, some index only `from` and `to` and old contracts like
+/// CryptoKitties index none of them.
+pub fn decode(log: &eth::Log) -> Option<Transfer> {
+    if log.address == CRYPTOKITTIES {
+        return cryptokitties(log);
+    }
+
+    if let Some(transfer) = abi::erc721::events::Transfer::match_and_decode(log) {
+        return Some(Transfer {
+            from: transfer.from,
+            to: transfer.to,
+            token_id: transfer.token_id,
+        });
+    }
+
+    if log.topics.is_empty() || log.topics[0] != eth_utils::TRANSFER_TOPIC {
+        return None;
+    }
+
+    match (log.topics.len(), log.data.len()) {
+        (3, 32) => Some(Transfer {
+            from: log.topics[1][12..].to_vec(),
+            to: log.topics[2][12..].to_vec(),
+            token_id: BigInt::from_unsigned_bytes_be(&log.data),
+        }),
+        (1, 96) => Some(Transfer {
+            from: log.data[12..32].to_vec(),
+            to: log.data[44..64].to_vec(),
+            token_id: BigInt::from_unsigned_bytes_be(&log.data[64..96]),
+        }),
+        _ => None,
+    }
+}
+
+/// The `data` passed to `safeTransferFrom(address,address,uint256,bytes)` by the call that emitted `log`, empty for
+/// transfers made by other functions. Proxied collections emit it from the delegate call, which has the same input.
+pub fn safe_transfer_data(trx: &eth::TransactionTrace, log: &eth::Log) -> Vec<u8> {
+    trx.calls
+        .iter()
+        .filter(|call| !call.state_reverted && call.logs.iter().any(|emitted| emitted.ordinal == log.ordinal))
+        .find_map(abi::erc721::functions::SafeTransferFrom2::match_and_decode)
+        .map(|call| call.data)
+        .unwrap_or_default()
+}
+
+/// A safe transfer of `contract` undone because the `onERC721Received` hook of the receiver reverted or did not
+/// accept the token.
+pub struct FailedHook<'a> {
+    pub safe_transfer: &'a eth::Call,
+    pub hook: &'a eth::Call,
+    pub from: Vec<u8>,
+    pub token_id: BigInt,
+    pub reason: String,
+}
+
+/// Hooks called by `contract` that failed the safe transfer calling them, in failed transactions or in reverted
+/// calls of successful ones.
+pub fn failed_hooks<'a>(trx: &'a eth::TransactionTrace, contract: &[u8]) -> Vec<FailedHook<'a>> {
+    let mut failed = vec![];
+    for hook in trx.calls.iter().filter(|call| call.caller == contract && call.input.starts_with(&ERC721_RECEIVED)) {
+        let reason = if hook.status_failed {
+            match hook.failure_reason.as_str() {
+                "" => "reverted".to_string(),
+                reason => reason.to_string(),
+            }
+        } else if !hook.return_data.starts_with(&ERC721_RECEIVED) {
+            format!("unexpected return value 0x{}", hex::encode(&hook.return_data))
+        } else {
+            continue;
+        };
+        if hook.input.len() < 4 + 3 * 32 {
+            continue;
+        }
+
+        // proxied collections make the hook call from the delegate call
+        let mut parent = trx.calls.iter().find(|call| call.index == hook.parent_index);
+        while let Some(call) = parent {
+            if call.address == contract && is_safe_transfer(call) {
+                break;
+            }
+            parent = trx.calls.iter().find(|parent| parent.index == call.parent_index && call.depth > 0);
+        }
+        let safe_transfer = match parent {
+            Some(call) if call.state_reverted => call,
+            _ => continue,
+        };
+
+        failed.push(FailedHook {
+            safe_transfer,
+            hook,
+            from: hook.input[4 + 44..4 + 64].to_vec(),
+            token_id: BigInt::from_unsigned_bytes_be(&hook.input[4 + 64..4 + 96]),
+            reason,
+        });
+    }
+    failed
+}
+
+fn is_safe_transfer(call: &eth::Call) -> bool {
+    use abi::erc721::functions as erc721;
+
+    erc721::SafeTransferFrom1::match_call(call) || erc721::SafeTransferFrom2::match_call(call)
+}
+
+/// CryptoKitties emits `Birth` right before the `Transfer` from the null address of a new kitty.
+/// Mints are taken from `Birth` and those transfers skipped, `Pregnant` changes no owner.
+fn cryptokitties(log: &eth::Log) -> Option<Transfer> {
+    use abi::cryptokitties::events as kitties;
+
+    if let Some(birth) = kitties::Birth::match_and_decode(log) {
+        return Some(Transfer {
+            from: NULL_ADDRESS.to_vec(),
+            to: birth.owner,
+            token_id: birth.kitty_id,
+        });
+    }
+
+    match kitties::Transfer::match_and_decode(log) {
+        Some(transfer) if transfer.from != NULL_ADDRESS => Some(Transfer {
+            from: transfer.from,
+            to: transfer.to,
+            token_id: transfer.token_id,
+        }),
+        _ => None,
+    }
+}
@@ -0,0 +1,145 @@
+use hex_literal::hex;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb::eth::v2 as eth;
+use substreams_ethereum::Event;
+
+use crate::abi::sablier_v1::events as sablier;
+use crate::abi::vesting_wallet::events as wallet;
+use crate::eth_utils;
+use crate::keys;
+use crate::pb::tokens::vesting_event::Kind;
+use crate::pb::tokens::VestingEvent;
+
+/// Sablier V1.0 and V1.1 deployments, the stream events of other contracts are not trusted
+pub const SABLIER_V1: [[u8; 20]; 2] = [
+    hex!("a4fc358455febe425536fd1878be67ffdbdec59a"),
+    hex!("cd18eaa163733da39c232722cbc4e8940b1d8888"),
+];
+
+/// Amount of `token` locked in vesting contracts.
+pub fn locked_key(token: &[u8]) -> String {
+    keys::key(keys::VESTING).hex(token).into()
+}
+
+/// Token streamed by the stream `stream_id` of the Sablier `contract`.
+pub fn stream_key(contract: &[u8], stream_id: &str) -> String {
+    keys::key(keys::STREAM).hex(contract).text(stream_id).into()
+}
+
+/// Streams created on the Sablier deployments by the transaction, as `(ordinal, stream_key, token)`.
+pub fn created_streams(trx: &eth::TransactionTrace) -> Vec<(u64, String, Vec<u8>)> {
+    let logs = trx.receipt.as_ref().map(|receipt| receipt.logs.as_slice()).unwrap_or_default();
+    logs.iter()
+        .filter(|log| SABLIER_V1.iter().any(|sablier| sablier[..] == log.address[..]))
+        .filter_map(|log| {
+            let created = sablier::CreateStream::match_and_decode(log)?;
+            Some((log.ordinal, stream_key(&log.address, &created.stream_id.to_string()), created.token_address))
+        })
+        .collect()
+}
+
+/// Sablier V1 stream events of the `discovered` tokens logged by the Sablier deployments, and the funding and
+/// releases of the OpenZeppelin VestingWallet contracts listed in `wallets`. Withdrawals and cancellations don't log
+/// the token of the stream, `stream_token` looks it up by Sablier contract, stream id and ordinal from the streams
+/// recorded at creation.
+pub fn decode(
+    trx: &eth::TransactionTrace,
+    discovered: impl Fn(&[u8]) -> bool,
+    wallets: &[Vec<u8>],
+    stream_token: impl Fn(&[u8], &str, u64) -> Option<Vec<u8>>,
+) -> Vec<VestingEvent> {
+    let logs = trx.receipt.as_ref().map(|receipt| receipt.logs.as_slice()).unwrap_or_default();
+
+    let mut events = vec![];
+    for log in logs {
+        let event = |kind: Kind, token: Vec<u8>, amount: &BigInt| VestingEvent {
+            kind: kind as i32,
+            contract: log.address.clone(),
+            token,
+            amount: amount.to_string(),
+            trx_hash: trx.hash.clone(),
+            ordinal: log.ordinal,
+            id: eth_utils::record_id(&trx.hash, log.block_index),
+            ..Default::default()
+        };
+
+        let sablier = SABLIER_V1.iter().any(|sablier| sablier[..] == log.address[..]);
+        let token_of = |stream_id: &str| stream_token(&log.address, stream_id, log.ordinal).unwrap_or_default();
+
+        let decoded = if !sablier && !wallets.contains(&log.address) {
+            continue;
+        } else if !sablier {
+            match wallet::Erc20Released::match_and_decode(log) {
+                Some(released) => event(Kind::WalletRelease, released.token, &released.amount),
+                None => continue,
+            }
+        } else if let Some(created) = sablier::CreateStream::match_and_decode(log) {
+            VestingEvent {
+                stream_id: created.stream_id.to_string(),
+                sender: created.sender,
+                recipient: created.recipient,
+                start_time: eth_utils::to_u64(&created.start_time).unwrap_or(u64::MAX),
+                stop_time: eth_utils::to_u64(&created.stop_time).unwrap_or(u64::MAX),
+                ..event(Kind::StreamCreated, created.token_address, &created.deposit)
+            }
+        } else if let Some(withdrawal) = sablier::WithdrawFromStream::match_and_decode(log) {
+            let stream_id = withdrawal.stream_id.to_string();
+            let token = token_of(&stream_id);
+            VestingEvent {
+                stream_id,
+                recipient: withdrawal.recipient,
+                ..event(Kind::StreamWithdrawal, token, &withdrawal.amount)
+            }
+        } else if let Some(canceled) = sablier::CancelStream::match_and_decode(log) {
+            // the balances left to both parties are paid out, nothing of the stream stays locked
+            let remaining = canceled.sender_balance + canceled.recipient_balance;
+            let stream_id = canceled.stream_id.to_string();
+            let token = token_of(&stream_id);
+            VestingEvent {
+                stream_id,
+                sender: canceled.sender,
+                recipient: canceled.recipient,
+                ..event(Kind::StreamCanceled, token, &remaining)
+            }
+        } else {
+            continue;
+        };
+        if discovered(&decoded.token) {
+            events.push(decoded);
+        }
+    }
+
+    for transfer in logs.iter().filter(|log| discovered(&log.address)) {
+        if transfer.topics.len() != 3 || transfer.topics[0] != eth_utils::TRANSFER_TOPIC {
+            continue;
+        }
+        let to = &transfer.topics[2][12..];
+        if !wallets.iter().any(|wallet| wallet[..] == *to) {
+            continue;
+        }
+        events.push(VestingEvent {
+            kind: Kind::WalletFunded as i32,
+            contract: to.to_vec(),
+            token: transfer.address.clone(),
+            sender: transfer.topics[1][12..].to_vec(),
+            amount: BigInt::from_unsigned_bytes_be(&transfer.data).to_string(),
+            trx_hash: trx.hash.clone(),
+            ordinal: transfer.ordinal,
+            id: eth_utils::record_id(&trx.hash, transfer.block_index),
+            ..Default::default()
+        });
+    }
+    events.sort_by_key(|event| event.ordinal);
+    events
+}
+
+/// Change of the locked amount of its token by the event: deposits and funding lock tokens, withdrawals, releases and
+/// cancellations unlock them.
+pub fn locked_change(event: &VestingEvent) -> Option<BigInt> {
+    let amount = event.amount.parse::<BigInt>().ok()?;
+    match Kind::from_i32(event.kind)? {
+        Kind::StreamCreated | Kind::WalletFunded => Some(amount),
+        Kind::StreamWithdrawal | Kind::StreamCanceled | Kind::WalletRelease => Some(amount.neg()),
+        Kind::Unspecified => None,
+    }
+}
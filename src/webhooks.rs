@@ -0,0 +1,111 @@
+use substreams::Hex;
+
+use crate::eth_utils;
+use crate::params::Params;
+use crate::pb::alerts::WebhookEvent;
+use crate::pb::erc721::log_discrepancy::Kind;
+use crate::pb::erc721::{LogDiscrepancy, TaggedTransfer, WhaleAlert};
+use crate::tags;
+
+const WHALE_TEMPLATE: &str = "Whale {holder} sent {amount} tokens of {contract}, holding {balance}";
+const ERC20_WHALE_TEMPLATE: &str = "Whale {holder} sent {amount} of {contract} (${usd_value})";
+const MIXER_TEMPLATE: &str = "{address} used a mixer recently and {action} token {token_id} of {contract} in {trx_hash}";
+const REVERT_AUDIT_TEMPLATE: &str = "Transfer log {block_index} of {trx_hash} {reason}, map_transfers may be off";
+
+pub const WARNING: &str = "warning";
+pub const CRITICAL: &str = "critical";
+
+/// Chat the payloads are rendered for, read from the `format` param.
+pub enum Format {
+    Slack,
+    Discord,
+}
+
+pub fn format(params: &Params) -> Format {
+    match params.get("format") {
+        Some("discord") => Format::Discord,
+        _ => Format::Slack,
+    }
+}
+
+/// `template` with each `{name}` placeholder replaced by its value.
+fn render(template: &str, values: &[(&str, String)]) -> String {
+    let mut message = template.to_string();
+    for (name, value) in values {
+        message = message.replace(&format!("{{{}}}", name), value);
+    }
+    message
+}
+
+fn event(format: &Format, dedupe_key: String, severity: &str, message: String, block: u64) -> WebhookEvent {
+    let text = format!("[{}] {}", severity, message);
+    let payload = match format {
+        Format::Slack => format!(r#"{{"text":{}}}"#, eth_utils::json_string(&text)),
+        Format::Discord => format!(r#"{{"content":{}}}"#, eth_utils::json_string(&text)),
+    };
+
+    WebhookEvent {
+        dedupe_key,
+        severity: severity.to_string(),
+        payload,
+        block,
+    }
+}
+
+pub fn whale_alert(format: &Format, alert: &WhaleAlert) -> WebhookEvent {
+    let dedupe_key = format!("whale:{}:{}:{}", alert.block, Hex(&alert.holder), Hex(&alert.contract));
+    let values = [
+        ("holder", eth_utils::format_address(&alert.holder)),
+        ("contract", eth_utils::format_address(&alert.contract)),
+        ("amount", alert.amount.clone()),
+        ("balance", alert.balance.clone()),
+        ("usd_value", format!("{:.0}", alert.usd_value)),
+    ];
+    let message = if alert.balance.is_empty() {
+        render(ERC20_WHALE_TEMPLATE, &values)
+    } else {
+        render(WHALE_TEMPLATE, &values)
+    };
+    event(format, dedupe_key, WARNING, message, alert.block)
+}
+
+/// Alerts for the sides of a transfer tagged `mixer_interaction`.
+pub fn mixer_alerts(format: &Format, block: u64, tagged: &TaggedTransfer) -> Vec<WebhookEvent> {
+    let transfer = match &tagged.transfer {
+        Some(transfer) => transfer,
+        None => return vec![],
+    };
+
+    let sides = [(&transfer.from, &tagged.from_tags, "sent"), (&transfer.to, &tagged.to_tags, "received")];
+    sides
+        .iter()
+        .filter(|(_, tags, _)| tags.iter().any(|tag| tag == tags::MIXER_INTERACTION))
+        .map(|(address, _, action)| {
+            let dedupe_key = format!("mixer:{}:{}:{}", Hex(&transfer.trx_hash), transfer.ordinal, Hex(address));
+            let values = [
+                ("address", eth_utils::format_address(address)),
+                ("action", action.to_string()),
+                ("token_id", transfer.token_id.to_string()),
+                ("contract", eth_utils::format_address(&transfer.contract)),
+                ("trx_hash", format!("0x{}", Hex(&transfer.trx_hash))),
+            ];
+            event(format, dedupe_key, CRITICAL, render(MIXER_TEMPLATE, &values), block)
+        })
+        .collect()
+}
+
+pub fn revert_audit(format: &Format, block: u64, discrepancy: &LogDiscrepancy) -> WebhookEvent {
+    let reason = match Kind::from_i32(discrepancy.kind) {
+        Some(Kind::RevertedCall) => "was emitted by a reverted call",
+        Some(Kind::MissingCall) => "was not emitted by any call",
+        Some(Kind::FailedTransaction) => "belongs to a failed transaction",
+        _ => "disagrees with the call trace",
+    };
+    let dedupe_key = format!("revert_audit:{}:{}", Hex(&discrepancy.trx_hash), discrepancy.block_index);
+    let values = [
+        ("block_index", discrepancy.block_index.to_string()),
+        ("trx_hash", format!("0x{}", Hex(&discrepancy.trx_hash))),
+        ("reason", reason.to_string()),
+    ];
+    event(format, dedupe_key, CRITICAL, render(REVERT_AUDIT_TEMPLATE, &values), block)
+}
@@ -0,0 +1,46 @@
+use substreams::scalar::BigInt;
+
+use crate::eth_utils;
+use crate::logging;
+use crate::params::Params;
+
+/// An ERC20 token with the USD price used to value its transfers.
+pub struct PricedToken {
+    pub address: Vec<u8>,
+    decimals: u32,
+    usd_price: f64,
+}
+
+impl PricedToken {
+    /// `amount` in whole tokens.
+    pub fn units(&self, amount: &BigInt) -> String {
+        eth_utils::decimal_string(&amount.to_decimal(self.decimals as u64))
+    }
+
+    pub fn usd_value(&self, amount: &BigInt) -> f64 {
+        let amount: f64 = amount.to_string().parse().unwrap_or(0.0);
+        amount / 10f64.powi(self.decimals as i32) * self.usd_price
+    }
+}
+
+/// Reads the `erc20_prices` param, a comma separated list of `<token>:<decimals>:<usd_price>` entries.
+pub fn priced_tokens(params: &Params) -> Vec<PricedToken> {
+    let mut tokens = vec![];
+    for entry in params.list("erc20_prices") {
+        let parts: Vec<&str> = entry.split(':').collect();
+        if parts.len() != 3 {
+            logging::warning!("params", "skipping invalid erc20_prices entry {}", entry);
+            continue;
+        }
+
+        match (eth_utils::parse_address(parts[0]), parts[1].parse(), parts[2].parse()) {
+            (Ok(address), Ok(decimals), Ok(usd_price)) => tokens.push(PricedToken {
+                address,
+                decimals,
+                usd_price,
+            }),
+            _ => logging::warning!("params", "skipping invalid erc20_prices entry {}", entry),
+        }
+    }
+    tokens
+}